@@ -21,6 +21,7 @@ bind_enum!(
     Right,
     Up,
     Down,
+    Click,
     ToggleGrid,
     ToggleTooltips,
     IncreaseGridSize,
@@ -31,11 +32,25 @@ bind_enum!(
     TextureEditor,
     PropertiesEditor,
     Settings,
-    EditsHistory
+    EditsHistory,
+    Inspector,
+    ZoomQuarter,
+    ZoomHalf,
+    ZoomActualSize,
+    ZoomDouble,
+    RotateClockwise,
+    RotateCounterclockwise,
+    RotateHalfTurn
 );
 
 impl Bind
 {
+    /// The key of the config file field storing the bind, also used to refer to it in the other
+    /// config sections.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) const fn config_key(self) -> &'static str { self.config_file_key() }
+
     /// The default `KeyCode`s associated with the [`Bind`] values. It is stored in the default
     /// config file generated on first launch, or any subsequent time when the program is
     /// launched and a config file cannot be found.
@@ -49,6 +64,7 @@ impl Bind
             Self::Right => KeyCode::ArrowRight,
             Self::Up => KeyCode::ArrowUp,
             Self::Down => KeyCode::ArrowDown,
+            Self::Click => KeyCode::NumpadEnter,
             Self::ToggleGrid => KeyCode::Period,
             Self::ToggleTooltips => KeyCode::Quote,
             Self::IncreaseGridSize => KeyCode::BracketLeft,
@@ -81,7 +97,15 @@ impl Bind
             Self::Thing => KeyCode::KeyG,
             Self::PropertiesEditor => KeyCode::KeyO,
             Self::Settings => KeyCode::Comma,
-            Self::EditsHistory => KeyCode::Semicolon
+            Self::EditsHistory => KeyCode::Semicolon,
+            Self::Inspector => KeyCode::KeyB,
+            Self::ZoomQuarter => KeyCode::Numpad1,
+            Self::ZoomHalf => KeyCode::Numpad2,
+            Self::ZoomActualSize => KeyCode::Numpad3,
+            Self::ZoomDouble => KeyCode::Numpad4,
+            Self::RotateClockwise => KeyCode::Numpad9,
+            Self::RotateCounterclockwise => KeyCode::Numpad7,
+            Self::RotateHalfTurn => KeyCode::Numpad8
         }
     }
 
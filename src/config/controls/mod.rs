@@ -1,4 +1,5 @@
 pub mod bind;
+pub mod mouse;
 
 //=======================================================================//
 // IMPORTS
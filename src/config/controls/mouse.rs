@@ -0,0 +1,200 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::input::{mouse::MouseButton, ButtonInput};
+use bevy_egui::egui;
+use configparser::ini::Ini;
+use hill_vacuum_proc_macros::EnumIter;
+
+use super::INI_SECTION;
+use crate::config::{language::Locale, IniConfig};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The config file key of the pan mouse button.
+const PAN_BUTTON_KEY: &str = "pan_button";
+/// The config file key of the mouse wheel behavior.
+const MOUSE_WHEEL_KEY: &str = "mouse_wheel";
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The mouse button that drags the view around while held.
+#[derive(Clone, Copy, Default, PartialEq, EnumIter)]
+pub(crate) enum PanButton
+{
+    /// No button.
+    None,
+    /// The middle mouse button.
+    #[default]
+    Middle,
+    /// The right mouse button.
+    Right
+}
+
+impl PanButton
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "None",
+            Self::Middle => "Middle",
+            Self::Right => "Right"
+        }
+    }
+
+    /// Returns the [`PanButton`] represented by `value`, if any.
+    #[inline]
+    #[must_use]
+    fn from_tag(value: &str) -> Option<Self> { Self::iter().find(|b| b.tag() == value) }
+
+    /// Whether the button is pressed.
+    #[inline]
+    #[must_use]
+    pub fn pressed(self, mouse_buttons: &ButtonInput<MouseButton>) -> bool
+    {
+        match self
+        {
+            Self::None => false,
+            Self::Middle => mouse_buttons.pressed(MouseButton::Middle),
+            Self::Right => mouse_buttons.pressed(MouseButton::Right)
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The action performed by the mouse wheel when no modifier key is pressed.
+#[derive(Clone, Copy, Default, PartialEq, EnumIter)]
+pub(crate) enum WheelAction
+{
+    /// Scroll the view, zoom while ctrl is pressed.
+    #[default]
+    Scroll,
+    /// Zoom on the cursor position, scroll while ctrl is pressed.
+    Zoom
+}
+
+impl WheelAction
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::Scroll => "Scroll",
+            Self::Zoom => "Zoom"
+        }
+    }
+
+    /// Returns the [`WheelAction`] represented by `value`, if any.
+    #[inline]
+    #[must_use]
+    fn from_tag(value: &str) -> Option<Self> { Self::iter().find(|a| a.tag() == value) }
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The behavior of the mouse buttons and wheel used to navigate the map.
+#[must_use]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct MouseBinds
+{
+    /// The button that pans the view.
+    pub pan:   PanButton,
+    /// The action of the mouse wheel.
+    pub wheel: WheelAction
+}
+
+impl MouseBinds
+{
+    /// Returns the default mouse binds, to be appended to the controls section of the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_binds() -> String
+    {
+        format!(
+            "{PAN_BUTTON_KEY} = {}\n{MOUSE_WHEEL_KEY} = {}\n",
+            PanButton::default().tag(),
+            WheelAction::default().tag()
+        )
+    }
+
+    /// Loads the mouse binds stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        if let Some(pan) = config
+            .get(INI_SECTION, PAN_BUTTON_KEY)
+            .and_then(|value| PanButton::from_tag(&value))
+        {
+            self.pan = pan;
+        }
+
+        if let Some(wheel) = config
+            .get(INI_SECTION, MOUSE_WHEEL_KEY)
+            .and_then(|value| WheelAction::from_tag(&value))
+        {
+            self.wheel = wheel;
+        }
+    }
+
+    /// Stores the mouse binds in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(INI_SECTION, PAN_BUTTON_KEY, self.pan.tag().to_owned().into());
+        config
+            .0
+            .set(INI_SECTION, MOUSE_WHEEL_KEY, self.wheel.tag().to_owned().into());
+    }
+
+    /// Resets the binds to their default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+
+    /// Draws the UI elements to edit the binds. Must be called inside an [`egui::Grid`].
+    #[inline]
+    pub fn show(&mut self, ui: &mut egui::Ui, locale: &Locale)
+    {
+        ui.label(locale.tr("Pan button"));
+        egui::ComboBox::from_id_salt(PAN_BUTTON_KEY)
+            .selected_text(locale.tr(self.pan.tag()))
+            .show_ui(ui, |ui| {
+                for b in PanButton::iter()
+                {
+                    ui.selectable_value(&mut self.pan, b, locale.tr(b.tag()));
+                }
+            });
+        ui.end_row();
+
+        ui.label(locale.tr("Mouse wheel"));
+        egui::ComboBox::from_id_salt(MOUSE_WHEEL_KEY)
+            .selected_text(locale.tr(self.wheel.tag()))
+            .show_ui(ui, |ui| {
+                for a in WheelAction::iter()
+                {
+                    ui.selectable_value(&mut self.wheel, a, locale.tr(a.tag()));
+                }
+            });
+        ui.end_row();
+    }
+}
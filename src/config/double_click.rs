@@ -0,0 +1,158 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use configparser::ini::Ini;
+
+use super::{language::Locale, IniConfig};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the double-click settings.
+const DOUBLE_CLICK_SECTION: &str = "DOUBLE_CLICK";
+/// The ini key of the maximum time between two clicks of a double-click.
+const INTERVAL_FIELD: &str = "interval";
+/// The default maximum time between two clicks of a double-click, in milliseconds.
+const DEFAULT_INTERVAL: u16 = 300;
+/// The minimum time between two clicks of a double-click.
+const MIN_INTERVAL: u16 = 100;
+/// The maximum time between two clicks of a double-click.
+const MAX_INTERVAL: u16 = 1000;
+/// The ini key of the double-click selection of the brushes sharing a texture.
+const TEXTURE_FIELD: &str = "texture";
+/// The ini key of the double-click editing of the path nodes.
+const NODE_FIELD: &str = "node";
+/// The ini key of the double-click deselection.
+const DESELECT_FIELD: &str = "deselect";
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The timing of the double-clicks and the actions they trigger.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(crate) struct DoubleClickSettings
+{
+    /// The maximum time between two clicks of a double-click, in milliseconds.
+    pub interval: u16,
+    /// Whether double-clicking a brush with the entity tool selects all the brushes with the same
+    /// texture.
+    pub texture:  bool,
+    /// Whether double-clicking a path node focuses its numeric editor.
+    pub node:     bool,
+    /// Whether double-clicking an empty spot deselects everything.
+    pub deselect: bool
+}
+
+impl Default for DoubleClickSettings
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            interval: DEFAULT_INTERVAL,
+            texture:  true,
+            node:     true,
+            deselect: true
+        }
+    }
+}
+
+impl DoubleClickSettings
+{
+    /// Returns the default double-click settings, to be appended to the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_config() -> String
+    {
+        format!(
+            "[{DOUBLE_CLICK_SECTION}]\n{INTERVAL_FIELD} = {DEFAULT_INTERVAL}\n{TEXTURE_FIELD} = \
+             true\n{NODE_FIELD} = true\n{DESELECT_FIELD} = true\n"
+        )
+    }
+
+    /// Loads the double-click settings stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        if let Some(interval) = config
+            .get(DOUBLE_CLICK_SECTION, INTERVAL_FIELD)
+            .and_then(|value| value.parse::<u16>().ok())
+        {
+            self.interval = interval.clamp(MIN_INTERVAL, MAX_INTERVAL);
+        }
+
+        for (key, field) in [
+            (TEXTURE_FIELD, &mut self.texture),
+            (NODE_FIELD, &mut self.node),
+            (DESELECT_FIELD, &mut self.deselect)
+        ]
+        {
+            if let Some(value) = config
+                .get(DOUBLE_CLICK_SECTION, key)
+                .and_then(|value| value.parse::<bool>().ok())
+            {
+                *field = value;
+            }
+        }
+    }
+
+    /// Stores the double-click settings in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(DOUBLE_CLICK_SECTION, INTERVAL_FIELD, self.interval.to_string().into());
+
+        for (key, value) in [
+            (TEXTURE_FIELD, self.texture),
+            (NODE_FIELD, self.node),
+            (DESELECT_FIELD, self.deselect)
+        ]
+        {
+            config.0.set(DOUBLE_CLICK_SECTION, key, value.to_string().into());
+        }
+    }
+
+    /// The maximum time between two clicks of a double-click, in seconds.
+    #[inline]
+    #[must_use]
+    pub fn seconds(self) -> f32 { f32::from(self.interval) / 1000f32 }
+
+    /// Resets the settings to their default values.
+    #[inline]
+    pub fn reset(&mut self) { *self = Self::default(); }
+
+    /// Draws the UI elements to edit the settings. Must be called inside an [`egui::Grid`].
+    #[inline]
+    pub fn show(&mut self, ui: &mut egui::Ui, locale: &Locale)
+    {
+        ui.label(locale.tr("Double-click interval"));
+        ui.add(
+            egui::DragValue::new(&mut self.interval)
+                .range(MIN_INTERVAL..=MAX_INTERVAL)
+                .suffix(" ms")
+        );
+        ui.end_row();
+
+        ui.label(locale.tr("Double-click selects texture"));
+        ui.checkbox(&mut self.texture, "");
+        ui.end_row();
+
+        ui.label(locale.tr("Double-click edits node"));
+        ui.checkbox(&mut self.node, "");
+        ui.end_row();
+
+        ui.label(locale.tr("Double-click deselects"));
+        ui.checkbox(&mut self.deselect, "");
+        ui.end_row();
+    }
+}
@@ -0,0 +1,630 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Output}
+};
+
+use bevy::{
+    ecs::system::Resource,
+    log::{info, warn}
+};
+use bevy_egui::egui;
+use configparser::ini::Ini;
+use hill_vacuum_shared::continue_if_none;
+use is_executable::IsExecutable;
+
+use super::{language::Locale, IniConfig};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the exporters settings.
+const EXPORTER_SECTION: &str = "EXPORTER";
+/// The legacy exporter executable ini key, loaded as the default profile.
+const EXPORTER_FIELD: &str = "exporter";
+/// The ini key of the name of the selected profile.
+const SELECTED_FIELD: &str = "selected";
+/// The ini key of whether overlapping brushes with collision should be reported before exporting.
+const CHECK_OVERLAPS_FIELD: &str = "check_overlaps";
+/// The prefix of the ini sections of the exporter profiles, followed by the profile name.
+const PROFILE_SECTION_PREFIX: &str = "EXPORTER:";
+/// The ini key of the exporter executable of a profile.
+const PATH_FIELD: &str = "path";
+/// The ini key of the arguments template of a profile.
+const ARGS_FIELD: &str = "args";
+/// The ini key of the output folder of a profile.
+const OUTPUT_FIELD: &str = "output";
+/// The ini key of the working directory of a profile.
+const WORKING_DIR_FIELD: &str = "working_dir";
+/// The prefix of the ini keys of the post-export commands of a profile, followed by their index.
+const POST_EXPORT_FIELD_PREFIX: &str = "post_export_";
+/// The placeholder of the arguments template replaced with the path of the map file.
+const INPUT_PLACEHOLDER: &str = "{input}";
+/// The placeholder of the arguments template replaced with the output folder.
+const OUTPUT_PLACEHOLDER: &str = "{output}";
+/// The placeholder of the arguments template replaced with the name of the map file, without
+/// extension.
+const MAP_NAME_PLACEHOLDER: &str = "{mapname}";
+/// The name of the profile generated from the legacy exporter setting.
+const DEFAULT_PROFILE_NAME: &str = "default";
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A named exporter setup.
+#[must_use]
+#[derive(Clone)]
+pub(crate) struct ExporterProfile
+{
+    /// The name of the profile.
+    pub name:        String,
+    /// The exporter executable.
+    pub path:        Option<PathBuf>,
+    /// The arguments passed to the executable, separated by whitespaces. `{input}` is replaced
+    /// with the path of the map file, `{output}` with the output folder, and `{mapname}` with
+    /// the name of the map file.
+    pub args:        String,
+    /// The folder the exported map should be placed in, if any.
+    pub output:      Option<PathBuf>,
+    /// The directory the executable is run from, if any. Otherwise it is run from the directory
+    /// of the editor.
+    pub working_dir: Option<PathBuf>,
+    /// The shell commands run, in order, after the exporter successfully finishes. The
+    /// placeholders are replaced as in the arguments.
+    pub post_export: Vec<String>
+}
+
+impl ExporterProfile
+{
+    /// Returns a new [`ExporterProfile`] named `name` with the default arguments.
+    #[inline]
+    fn new(name: String) -> Self
+    {
+        Self {
+            name,
+            path: None,
+            args: INPUT_PLACEHOLDER.to_owned(),
+            output: None,
+            working_dir: None,
+            post_export: Vec::new()
+        }
+    }
+
+    /// Whether the exporter executable exists.
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool
+    {
+        self.path
+            .as_ref()
+            .is_some_and(|path| path.exists() && path.is_executable())
+    }
+
+    /// Returns `text` with the placeholders replaced with the values associated with the map at
+    /// `input`. If no output folder is specified `{output}` is replaced with the folder of the
+    /// map.
+    #[inline]
+    #[must_use]
+    fn replace_placeholders(&self, text: &str, input: &Path) -> String
+    {
+        let output = match &self.output
+        {
+            Some(output) => output.to_str().unwrap(),
+            None => input.parent().and_then(Path::to_str).unwrap_or_default()
+        };
+        let map_name = input
+            .file_stem()
+            .and_then(std::ffi::OsStr::to_str)
+            .unwrap_or_default();
+
+        text.replace(INPUT_PLACEHOLDER, input.to_str().unwrap())
+            .replace(OUTPUT_PLACEHOLDER, output)
+            .replace(MAP_NAME_PLACEHOLDER, map_name)
+    }
+
+    /// Returns the arguments to pass to the exporter to export the map at `input`.
+    #[inline]
+    #[must_use]
+    pub fn args(&self, input: &Path) -> Vec<String>
+    {
+        self.args
+            .split_whitespace()
+            .map(|arg| self.replace_placeholders(arg, input))
+            .collect()
+    }
+
+    /// Runs `command` and waits for it to finish, logging its standard output and error with the
+    /// `name` prefix.
+    /// Returns an error describing the failure if the command could not be run or did not exit
+    /// successfully.
+    #[inline]
+    fn run_command(&self, mut command: Command, name: &str) -> Result<(), String>
+    {
+        if let Some(dir) = &self.working_dir
+        {
+            command.current_dir(dir);
+        }
+
+        let Output {
+            status,
+            stdout,
+            stderr
+        } = command
+            .output()
+            .map_err(|err| format!("{name} could not be run: {err}"))?;
+
+        let stdout = String::from_utf8_lossy(&stdout);
+        let stderr = String::from_utf8_lossy(&stderr);
+
+        for line in stdout.lines()
+        {
+            info!("{name}: {line}");
+        }
+
+        for line in stderr.lines()
+        {
+            warn!("{name}: {line}");
+        }
+
+        if status.success()
+        {
+            return Ok(());
+        }
+
+        Err(format!("{name} failed ({status}).\n{}", stderr.trim()))
+    }
+
+    /// Runs the exporter on the map at `input` and waits for it to finish, then runs the
+    /// post-export commands in order, logging their standard output and error.
+    /// Returns an error describing the failure if a command could not be run or did not exit
+    /// successfully, in which case the following ones are not run.
+    #[inline]
+    pub fn run(&self, input: &Path) -> Result<(), String>
+    {
+        let mut command = Command::new(self.path.as_ref().unwrap());
+        command.args(self.args(input));
+        self.run_command(command, &format!("Exporter {}", self.name))?;
+
+        for (i, post_export) in self
+            .post_export
+            .iter()
+            .enumerate()
+            .filter(|(_, command)| !command.trim().is_empty())
+        {
+            let post_export = self.replace_placeholders(post_export, input);
+
+            #[cfg(target_os = "windows")]
+            let command = {
+                let mut command = Command::new("cmd");
+                command.args(["/C", &post_export]);
+                command
+            };
+            #[cfg(not(target_os = "windows"))]
+            let command = {
+                let mut command = Command::new("sh");
+                command.args(["-c", &post_export]);
+                command
+            };
+
+            self.run_command(
+                command,
+                &format!("Post-export command {} of exporter {}", i + 1, self.name)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+//=======================================================================//
+
+/// An exporter profile provided by the host application through
+/// [`HillVacuumPlugin::with_exporter`](crate::HillVacuumPlugin::with_exporter).  
+/// It is added to the profiles stored in the config file at startup, unless there already is
+/// one with the same name, so that the edits made in the settings window are not overwritten.
+#[must_use]
+#[derive(Clone)]
+pub struct ExporterSetup(ExporterProfile);
+
+impl ExporterSetup
+{
+    /// Returns a new [`ExporterSetup`] named `name` that runs the executable at `path`, with
+    /// `{input}` as arguments.
+    #[inline]
+    pub fn new(name: impl Into<String>, path: impl Into<PathBuf>) -> Self
+    {
+        let mut profile = ExporterProfile::new(name.into());
+        profile.path = Some(path.into());
+        Self(profile)
+    }
+
+    /// Sets the arguments passed to the executable, separated by whitespaces. `{input}` is
+    /// replaced with the path of the map file, `{output}` with the output folder, and
+    /// `{mapname}` with the name of the map file.
+    #[inline]
+    pub fn args(mut self, args: impl Into<String>) -> Self
+    {
+        self.0.args = args.into();
+        self
+    }
+
+    /// Sets the folder the exported map should be placed in.
+    #[inline]
+    pub fn output(mut self, folder: impl Into<PathBuf>) -> Self
+    {
+        self.0.output = Some(folder.into());
+        self
+    }
+
+    /// Sets the directory the executable is run from.
+    #[inline]
+    pub fn working_dir(mut self, dir: impl Into<PathBuf>) -> Self
+    {
+        self.0.working_dir = Some(dir.into());
+        self
+    }
+
+    /// Appends `command` to the shell commands run, in order, after the exporter successfully
+    /// finishes. The placeholders are replaced as in the arguments.
+    #[inline]
+    pub fn post_export(mut self, command: impl Into<String>) -> Self
+    {
+        self.0.post_export.push(command.into());
+        self
+    }
+}
+
+//=======================================================================//
+
+/// The [`ExporterSetup`]s added through the `HillVacuumPlugin`.
+#[must_use]
+#[derive(Resource, Default)]
+pub(crate) struct UserExporters(pub Vec<ExporterSetup>);
+
+//=======================================================================//
+
+/// The exporter profiles, each one describing how to export a map to a specific target.
+#[must_use]
+#[derive(Default)]
+pub(crate) struct ExporterProfiles
+{
+    /// The profiles.
+    profiles:       Vec<ExporterProfile>,
+    /// The index of the profile used by the export command.
+    selected:       usize,
+    /// Whether overlapping brushes with collision are reported by the checks run before
+    /// exporting.
+    check_overlaps: bool
+}
+
+impl ExporterProfiles
+{
+    /// Returns the default exporter setting, to be appended to the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_config() -> String
+    {
+        format!("[{EXPORTER_SECTION}]\n{SELECTED_FIELD}\n{CHECK_OVERLAPS_FIELD} = false\n")
+    }
+
+    /// Loads the exporter profiles stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        for section in config.sections()
+        {
+            let name = continue_if_none!(section.strip_prefix(PROFILE_SECTION_PREFIX));
+            let mut post_export = config
+                .get_map_ref()
+                .get(&section)
+                .into_iter()
+                .flat_map(|keys| keys.iter())
+                .filter_map(|(key, value)| {
+                    let index =
+                        key.strip_prefix(POST_EXPORT_FIELD_PREFIX)?.parse::<usize>().ok()?;
+                    Some((index, value.clone()?))
+                })
+                .collect::<Vec<_>>();
+            post_export.sort_by_key(|(index, _)| *index);
+
+            self.profiles.push(ExporterProfile {
+                name:        name.to_owned(),
+                path:        config.get(&section, PATH_FIELD).map(PathBuf::from),
+                args:        config
+                    .get(&section, ARGS_FIELD)
+                    .unwrap_or_else(|| INPUT_PLACEHOLDER.to_owned()),
+                output:      config.get(&section, OUTPUT_FIELD).map(PathBuf::from),
+                working_dir: config.get(&section, WORKING_DIR_FIELD).map(PathBuf::from),
+                post_export: post_export.into_iter().map(|(_, command)| command).collect()
+            });
+        }
+
+        self.profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Single exporter of the previous versions.
+        if self.profiles.is_empty()
+        {
+            if let Some(path) = config.get(EXPORTER_SECTION, EXPORTER_FIELD)
+            {
+                let mut profile = ExporterProfile::new(DEFAULT_PROFILE_NAME.to_owned());
+                profile.path = PathBuf::from(path).into();
+                self.profiles.push(profile);
+            }
+        }
+
+        if let Some(selected) = config.get(EXPORTER_SECTION, SELECTED_FIELD)
+        {
+            self.selected = self
+                .profiles
+                .iter()
+                .position(|profile| profile.name == selected)
+                .unwrap_or_default();
+        }
+
+        self.check_overlaps = config
+            .get(EXPORTER_SECTION, CHECK_OVERLAPS_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+            .unwrap_or_default();
+    }
+
+    /// Adds the profiles of `setups` whose names are not used by the loaded ones.
+    #[inline]
+    pub(in crate::config) fn extend(&mut self, setups: impl IntoIterator<Item = ExporterSetup>)
+    {
+        let selected = self.selected().map(|profile| profile.name.clone());
+
+        for ExporterSetup(profile) in setups
+        {
+            if self.profiles.iter().all(|p| p.name != profile.name)
+            {
+                self.profiles.push(profile);
+            }
+        }
+
+        self.profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        if let Some(selected) = selected
+        {
+            self.selected = self
+                .profiles
+                .iter()
+                .position(|profile| profile.name == selected)
+                .unwrap_or_default();
+        }
+    }
+
+    /// Stores the exporter profiles in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        for section in config.0.sections()
+        {
+            if section.starts_with(PROFILE_SECTION_PREFIX)
+            {
+                _ = config.0.remove_section(&section);
+            }
+        }
+
+        _ = config.0.remove_key(EXPORTER_SECTION, EXPORTER_FIELD);
+        config.0.set(
+            EXPORTER_SECTION,
+            SELECTED_FIELD,
+            self.selected().map(|profile| profile.name.clone())
+        );
+        config.0.set(
+            EXPORTER_SECTION,
+            CHECK_OVERLAPS_FIELD,
+            self.check_overlaps.to_string().into()
+        );
+
+        for profile in self.profiles.iter().filter(|profile| !profile.name.trim().is_empty())
+        {
+            let section = format!("{PROFILE_SECTION_PREFIX}{}", profile.name.trim());
+
+            config.0.set(
+                &section,
+                PATH_FIELD,
+                profile.path.as_ref().map(|path| path.to_str().unwrap().to_owned())
+            );
+            config.0.set(&section, ARGS_FIELD, profile.args.clone().into());
+            config.0.set(
+                &section,
+                OUTPUT_FIELD,
+                profile.output.as_ref().map(|path| path.to_str().unwrap().to_owned())
+            );
+            config.0.set(
+                &section,
+                WORKING_DIR_FIELD,
+                profile
+                    .working_dir
+                    .as_ref()
+                    .map(|path| path.to_str().unwrap().to_owned())
+            );
+
+            for (i, command) in profile
+                .post_export
+                .iter()
+                .filter(|command| !command.trim().is_empty())
+                .enumerate()
+            {
+                config.0.set(
+                    &section,
+                    &format!("{POST_EXPORT_FIELD_PREFIX}{i}"),
+                    command.clone().into()
+                );
+            }
+        }
+    }
+
+    /// Whether there are no profiles.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.profiles.is_empty() }
+
+    /// Returns an iterator to the profiles.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ExporterProfile> { self.profiles.iter() }
+
+    /// Returns the profile used by the export command, if any.
+    #[inline]
+    #[must_use]
+    pub fn selected(&self) -> Option<&ExporterProfile> { self.profiles.get(self.selected) }
+
+    /// Whether overlapping brushes with collision should be reported before exporting.
+    #[inline]
+    #[must_use]
+    pub const fn check_overlaps(&self) -> bool { self.check_overlaps }
+
+    /// Sets the profile used by the export command to the one at `index`.
+    #[inline]
+    pub fn select(&mut self, index: usize)
+    {
+        assert!(index < self.profiles.len(), "Out of bounds exporter profile index.");
+        self.selected = index;
+    }
+
+    /// Draws the UI elements to edit the profiles. Must be called inside an [`egui::Grid`].
+    #[inline]
+    pub fn show(&mut self, ui: &mut egui::Ui, locale: &Locale)
+    {
+        let mut removed = None;
+
+        for (i, profile) in self.profiles.iter_mut().enumerate()
+        {
+            ui.text_edit_singleline(&mut profile.name);
+
+            if ui.button("-").clicked()
+            {
+                removed = i.into();
+            }
+            ui.end_row();
+
+            if ui.button(locale.tr("Pick exporter")).clicked()
+            {
+                match rfd::FileDialog::new()
+                    .set_directory(std::env::current_dir().unwrap())
+                    .set_title(locale.tr("Pick exporter"))
+                    .pick_file()
+                {
+                    Some(file) if file.is_executable() => profile.path = file.into(),
+                    _ => ()
+                }
+            }
+
+            ui.label(match &profile.path
+            {
+                Some(path) => path.file_stem().unwrap().to_str().unwrap(),
+                None => ""
+            });
+            ui.end_row();
+
+            ui.label(locale.tr("Arguments"));
+            ui.text_edit_singleline(&mut profile.args);
+            ui.end_row();
+
+            if ui.button(locale.tr("Pick output folder")).clicked()
+            {
+                if let Some(folder) = rfd::FileDialog::new()
+                    .set_directory(std::env::current_dir().unwrap())
+                    .set_title(locale.tr("Pick output folder"))
+                    .pick_folder()
+                {
+                    profile.output = folder.into();
+                }
+            }
+
+            ui.label(match &profile.output
+            {
+                Some(path) => path.to_str().unwrap(),
+                None => ""
+            });
+            ui.end_row();
+
+            if ui.button(locale.tr("Pick working directory")).clicked()
+            {
+                if let Some(folder) = rfd::FileDialog::new()
+                    .set_directory(std::env::current_dir().unwrap())
+                    .set_title(locale.tr("Pick working directory"))
+                    .pick_folder()
+                {
+                    profile.working_dir = folder.into();
+                }
+            }
+
+            ui.label(match &profile.working_dir
+            {
+                Some(path) => path.to_str().unwrap(),
+                None => ""
+            });
+            ui.end_row();
+
+            let mut removed_command = None;
+
+            for (j, command) in profile.post_export.iter_mut().enumerate()
+            {
+                ui.text_edit_singleline(command);
+
+                if ui.button("-").clicked()
+                {
+                    removed_command = j.into();
+                }
+                ui.end_row();
+            }
+
+            if let Some(j) = removed_command
+            {
+                profile.post_export.remove(j);
+            }
+
+            if ui.button(locale.tr("Add post-export command")).clicked()
+            {
+                profile.post_export.push(String::new());
+            }
+            ui.end_row();
+        }
+
+        if let Some(index) = removed
+        {
+            _ = self.profiles.remove(index);
+
+            if self.selected > index
+            {
+                self.selected -= 1;
+            }
+
+            self.selected = self.selected.min(self.profiles.len().saturating_sub(1));
+        }
+
+        if ui.button(locale.tr("Add exporter")).clicked()
+        {
+            let mut i = self.profiles.len();
+
+            let name = loop
+            {
+                let name = format!("exporter_{i}");
+
+                if self.profiles.iter().all(|profile| profile.name != name)
+                {
+                    break name;
+                }
+
+                i += 1;
+            };
+
+            self.profiles.push(ExporterProfile::new(name));
+        }
+        ui.end_row();
+
+        ui.label(locale.tr("Check overlaps"));
+        ui.checkbox(&mut self.check_overlaps, "");
+        ui.end_row();
+    }
+}
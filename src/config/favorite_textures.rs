@@ -0,0 +1,102 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use configparser::ini::Ini;
+
+use super::IniConfig;
+use crate::utils::collections::{hash_set, HashSet};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the favorite textures.
+const FAVORITE_TEXTURES_SECTION: &str = "FAVORITE_TEXTURES";
+/// The ini key of the names of the favorite textures.
+const TEXTURES_FIELD: &str = "textures";
+/// The separator of the textures listed in the ini value.
+const SEPARATOR: char = ',';
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The textures marked as favorite in the texture editor.
+/// The textures are listed in the config file through their names, separated by commas, i.e.
+/// `textures = brick,grass,stone`, therefore textures with a comma in their name cannot be marked
+/// as favorite.
+#[must_use]
+pub(crate) struct FavoriteTextures(HashSet<String>);
+
+impl Default for FavoriteTextures
+{
+    #[inline]
+    fn default() -> Self { Self(hash_set![]) }
+}
+
+impl FavoriteTextures
+{
+    /// Loads the favorite textures stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        let value = match config.get(FAVORITE_TEXTURES_SECTION, TEXTURES_FIELD)
+        {
+            Some(value) => value,
+            None => return
+        };
+
+        self.0 = value
+            .split(SEPARATOR)
+            .filter(|name| !name.is_empty())
+            .map(str::to_owned)
+            .collect();
+    }
+
+    /// Stores the favorite textures in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        let mut textures = self.0.iter().map(String::as_str).collect::<Vec<_>>();
+        textures.sort_unstable();
+        config.0.set(
+            FAVORITE_TEXTURES_SECTION,
+            TEXTURES_FIELD,
+            textures.join(&SEPARATOR.to_string()).into()
+        );
+    }
+
+    /// Whether the texture named `name` is a favorite.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool { self.0.contains(name) }
+
+    /// Whether the texture named `name` can be marked as favorite.
+    #[inline]
+    #[must_use]
+    pub fn valid_name(name: &str) -> bool { !name.contains(SEPARATOR) }
+
+    /// Marks the texture named `name` as favorite if it is not, otherwise unmarks it.
+    #[inline]
+    pub fn toggle(&mut self, name: &str)
+    {
+        if !self.0.remove(name) && Self::valid_name(name)
+        {
+            self.0.insert(name.to_owned());
+        }
+    }
+
+    /// Replaces the favorite texture `prev`, if any, with `new`.
+    #[inline]
+    pub fn rename(&mut self, prev: &str, new: &str)
+    {
+        if self.0.remove(prev) && Self::valid_name(new)
+        {
+            self.0.insert(new.to_owned());
+        }
+    }
+}
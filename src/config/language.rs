@@ -0,0 +1,200 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use configparser::ini::Ini;
+use hill_vacuum_proc_macros::EnumIter;
+
+use super::IniConfig;
+use crate::utils::collections::{hash_map, HashMap};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the language.
+const LANGUAGE_SECTION: &str = "LANGUAGE";
+/// The language ini key.
+const LANGUAGE_FIELD: &str = "language";
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The languages the UI can be displayed in.
+#[derive(Clone, Copy, Default, PartialEq, EnumIter)]
+pub(crate) enum Language
+{
+    /// English, the language the UI strings are written in.
+    #[default]
+    English,
+    /// Italian.
+    Italian
+}
+
+impl Language
+{
+    /// The name of the language, written in the language itself.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::English => "English",
+            Self::Italian => "Italiano"
+        }
+    }
+
+    /// Returns the [`Language`] represented by `value`, if any.
+    #[inline]
+    #[must_use]
+    fn from_tag(value: &str) -> Option<Self> { Self::iter().find(|l| l.tag() == value) }
+
+    /// The contents of the translations file of the language.
+    /// Each line of the file has the format `English text = Translated text`, empty lines and
+    /// lines starting with `#` are ignored.
+    #[inline]
+    #[must_use]
+    const fn translations(self) -> &'static str
+    {
+        match self
+        {
+            Self::English => "",
+            Self::Italian => include_str!("locales/it.lang")
+        }
+    }
+
+    /// The markdown text of the manual written in the language, if any. The languages without one
+    /// display the English manual.
+    #[inline]
+    #[must_use]
+    pub const fn manual(self) -> Option<&'static str>
+    {
+        match self
+        {
+            Self::English => None,
+            Self::Italian => Some(include_str!("locales/it.md"))
+        }
+    }
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The translations of the UI strings in the selected [`Language`].
+#[must_use]
+pub(crate) struct Locale
+{
+    /// The selected language.
+    language: Language,
+    /// The English strings and their translations.
+    strings:  HashMap<&'static str, &'static str>
+}
+
+impl Default for Locale
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            language: Language::default(),
+            strings:  hash_map![]
+        }
+    }
+}
+
+impl Locale
+{
+    /// Returns the default language setting, to be appended to the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_config() -> String
+    {
+        format!("[{LANGUAGE_SECTION}]\n{LANGUAGE_FIELD} = {}\n", Language::default().tag())
+    }
+
+    /// Loads the language stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        if let Some(language) = config
+            .get(LANGUAGE_SECTION, LANGUAGE_FIELD)
+            .and_then(|value| Language::from_tag(&value))
+        {
+            self.set_language(language);
+        }
+    }
+
+    /// Stores the language in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(LANGUAGE_SECTION, LANGUAGE_FIELD, self.language.tag().to_owned().into());
+    }
+
+    /// Sets the language of the UI, loading its translations.
+    #[inline]
+    fn set_language(&mut self, language: Language)
+    {
+        self.language = language;
+        self.strings.clear();
+
+        for line in language.translations().lines().map(str::trim)
+        {
+            if line.is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+
+            if let Some((english, translation)) = line.split_once(" = ")
+            {
+                self.strings.insert(english.trim(), translation.trim());
+            }
+        }
+    }
+
+    /// Returns the selected [`Language`].
+    #[inline]
+    #[must_use]
+    pub const fn language(&self) -> Language { self.language }
+
+    /// Returns the translation of `text`, or `text` itself if there is none.
+    #[inline]
+    #[must_use]
+    pub fn tr(&self, text: &'static str) -> &'static str
+    {
+        self.strings.get(text).copied().unwrap_or(text)
+    }
+
+    /// Draws the UI elements to pick the language. Must be called inside an [`egui::Grid`].
+    #[inline]
+    pub fn show(&mut self, ui: &mut egui::Ui)
+    {
+        let mut language = self.language;
+
+        ui.label(self.tr("Language"));
+        egui::ComboBox::from_id_salt(LANGUAGE_FIELD)
+            .selected_text(language.tag())
+            .show_ui(ui, |ui| {
+                for l in Language::iter()
+                {
+                    ui.selectable_value(&mut language, l, l.tag());
+                }
+            });
+        ui.end_row();
+
+        if language != self.language
+        {
+            self.set_language(language);
+        }
+    }
+}
@@ -1,4 +1,11 @@
 pub mod controls;
+pub mod double_click;
+pub mod exporters;
+pub mod favorite_textures;
+pub mod language;
+pub mod navigation;
+pub mod toolbar;
+pub mod tools_presets;
 
 //=======================================================================//
 // IMPORTS
@@ -8,26 +15,37 @@ pub mod controls;
 use std::{
     fs::File,
     io::Write,
+    ops::RangeInclusive,
     path::{Path, PathBuf}
 };
 
 use bevy::{
-    app::{App, AppExit, Plugin},
+    app::{App, AppExit, Plugin, Update},
     asset::Assets,
     ecs::{
         event::EventWriter,
-        system::{Res, ResMut, Resource},
+        schedule::IntoSystemConfigs,
+        system::{Local, Res, ResMut, Resource},
         world::{FromWorld, Mut, World}
     },
     sprite::ColorMaterial,
-    state::state::OnEnter,
+    state::{condition::in_state, state::OnEnter},
+    time::Time,
     window::{PrimaryWindow, Window}
 };
 use configparser::ini::Ini;
 use hill_vacuum_shared::FILE_EXTENSION;
-use is_executable::IsExecutable;
 
-use self::controls::{bind::Bind, BindsKeyCodes};
+use self::{
+    controls::{bind::Bind, mouse::MouseBinds, BindsKeyCodes},
+    double_click::DoubleClickSettings,
+    exporters::{ExporterProfiles, UserExporters},
+    favorite_textures::FavoriteTextures,
+    language::Locale,
+    navigation::NavigationSettings,
+    toolbar::ToolbarLayout,
+    tools_presets::ToolsPresets
+};
 use crate::{
     error_message,
     map::drawer::color::{Color, ColorResources},
@@ -50,10 +68,56 @@ const WARNING_FIELD: &str = "displayed";
 const OPEN_FILE_SECTION: &str = "OPEN_FILE";
 /// The open file ini key.
 const OPEN_FILE_FIELD: &str = "file";
-/// The ini section of the exporter key.
-const EXPORTER_SECTION: &str = "EXPORTER";
-/// The exporter executable ini key.
-const EXPORTER_FIELD: &str = "exporter";
+/// The ini section of the recent files.
+const RECENT_FILES_SECTION: &str = "RECENT_FILES";
+/// The prefix of the recent files ini keys.
+const RECENT_FILES_FIELD: &str = "file";
+/// The ini section of the selection outline settings.
+const SELECTION_SECTION: &str = "SELECTION";
+/// The marching ants outline ini key.
+const MARCHING_ANTS_FIELD: &str = "marching_ants";
+/// The ini section of the config file settings.
+const CONFIG_SECTION: &str = "CONFIG";
+/// The ini key of the interval, in seconds, between the checks for config changes to be saved.
+const AUTOSAVE_INTERVAL_FIELD: &str = "autosave_interval";
+/// The default interval, in seconds, between the checks for config changes to be saved.
+const DEFAULT_AUTOSAVE_INTERVAL: f32 = 2f32;
+/// The ini key of the interval, in seconds, between the autosave snapshots of the map being edited.
+const MAP_AUTOSAVE_INTERVAL_FIELD: &str = "map_autosave_interval";
+/// The default interval, in seconds, between the autosave snapshots of the map being edited.
+const DEFAULT_MAP_AUTOSAVE_INTERVAL: f32 = 120f32;
+/// The range of the values the interval between the autosave snapshots can assume, in seconds.
+pub(crate) const MAP_AUTOSAVE_INTERVAL_RANGE: RangeInclusive<f32> = 0f32..=3600f32;
+/// The ini key of the interval, in seconds, between the checks for added or modified texture
+/// files.
+const TEXTURE_WATCH_INTERVAL_FIELD: &str = "texture_watch_interval";
+/// The default interval, in seconds, between the checks for added or modified texture files.
+const DEFAULT_TEXTURE_WATCH_INTERVAL: f32 = 1f32;
+/// The range of the values the interval between the checks for added or modified texture files
+/// can assume, in seconds.
+pub(crate) const TEXTURE_WATCH_INTERVAL_RANGE: RangeInclusive<f32> = 0f32..=60f32;
+/// The config file section containing the camera settings.
+const CAMERA_SECTION: &str = "CAMERA";
+/// The config file field containing the minimum zoom.
+const MIN_ZOOM_FIELD: &str = "min_zoom";
+/// The config file field containing the maximum zoom.
+const MAX_ZOOM_FIELD: &str = "max_zoom";
+/// The default minimum zoom.
+const DEFAULT_MIN_ZOOM: u16 = 5;
+/// The default maximum zoom.
+const DEFAULT_MAX_ZOOM: u16 = 800;
+/// The range of the values the zoom limits can assume, in percentage.
+pub(crate) const ZOOM_LIMITS_RANGE: RangeInclusive<u16> = 1..=1600;
+/// The config file section containing the cursor settings.
+const CURSOR_SECTION: &str = "CURSOR";
+/// The config file field containing the amount of grid squares the cursor is moved by the
+/// directional keys.
+const CURSOR_STEP_FIELD: &str = "step";
+/// The default amount of grid squares the cursor is moved by the directional keys.
+const DEFAULT_CURSOR_STEP: u8 = 1;
+/// The range of the values the amount of grid squares the cursor is moved by the directional keys
+/// can assume.
+pub(crate) const CURSOR_STEP_RANGE: RangeInclusive<u8> = 1..=16;
 
 //=======================================================================//
 // STRUCTS
@@ -70,19 +134,39 @@ impl Plugin for ConfigPlugin
     {
         app.init_resource::<Config>()
             .init_resource::<IniConfig>()
+            .add_systems(Update, autosave_config.run_if(in_state(EditorState::Run)))
             .add_systems(OnEnter(EditorState::ShutDown), save_config);
     }
 }
 
 //=======================================================================//
 
-/// The opened file being edited, if any.
+/// The opened file being edited, if any, and the recently opened files.
 #[must_use]
 #[derive(Clone)]
-pub(crate) struct OpenFile(Option<PathBuf>);
+pub(crate) struct OpenFile
+{
+    /// The file being edited.
+    path:   Option<PathBuf>,
+    /// The recently opened files, the most recent first.
+    recent: Vec<PathBuf>
+}
 
 impl OpenFile
 {
+    /// The maximum amount of recent files that are remembered.
+    const MAX_RECENT_FILES: usize = 8;
+
+    /// Returns a new [`OpenFile`] with no file.
+    #[inline]
+    const fn new() -> Self
+    {
+        Self {
+            path:   None,
+            recent: Vec::new()
+        }
+    }
+
     /// Returns a new [`OpenFile`] from the `path`.
     #[inline]
     pub fn update(&mut self, path: impl Into<PathBuf>, window: &mut Window)
@@ -93,7 +177,8 @@ impl OpenFile
             "Improper file load."
         );
 
-        self.0 = path.into();
+        self.push_recent(path.clone());
+        self.path = path.into();
         self.update_window_title(window);
     }
 
@@ -101,20 +186,82 @@ impl OpenFile
     #[inline]
     pub fn clear(&mut self, window: &mut Window)
     {
-        self.0 = None;
+        self.path = None;
+        self.update_window_title(window);
+    }
+
+    /// Replaces the file path with `path`, without affecting the recently opened files.
+    /// Returns the previous path.
+    #[inline]
+    pub fn replace(&mut self, path: Option<PathBuf>, window: &mut Window) -> Option<PathBuf>
+    {
+        let prev = std::mem::replace(&mut self.path, path);
         self.update_window_title(window);
+        prev
     }
 
     /// Returns the file path, if any.
     #[inline]
     #[must_use]
-    pub const fn path(&self) -> Option<&PathBuf> { self.0.as_ref() }
+    pub const fn path(&self) -> Option<&PathBuf> { self.path.as_ref() }
+
+    /// Returns an iterator to the recently opened files, the most recent first.
+    #[inline]
+    pub fn recent(&self) -> impl ExactSizeIterator<Item = &PathBuf> { self.recent.iter() }
+
+    /// Returns the recently opened file at `index`, if any.
+    #[inline]
+    #[must_use]
+    pub fn recent_at(&self, index: usize) -> Option<&PathBuf> { self.recent.get(index) }
+
+    /// Moves `path` at the top of the recent files.
+    #[inline]
+    fn push_recent(&mut self, path: PathBuf)
+    {
+        self.recent.retain(|p| *p != path);
+        self.recent.insert(0, path);
+        self.recent.truncate(Self::MAX_RECENT_FILES);
+    }
+
+    /// Removes `path` from the recent files.
+    #[inline]
+    pub fn remove_recent(&mut self, path: &Path) { self.recent.retain(|p| p != path); }
+
+    /// The ini key of the recent file at `index`.
+    #[inline]
+    #[must_use]
+    fn recent_field(index: usize) -> String { format!("{RECENT_FILES_FIELD}_{index}") }
+
+    /// Loads the recent files stored in `config`, skipping the ones that do not exist anymore.
+    #[inline]
+    fn load_recent(&mut self, config: &Ini)
+    {
+        self.recent = (0..Self::MAX_RECENT_FILES)
+            .filter_map(|i| config.get(RECENT_FILES_SECTION, &Self::recent_field(i)))
+            .map(PathBuf::from)
+            .filter(|path| path.exists())
+            .collect();
+    }
+
+    /// Stores the recent files in `config`.
+    #[inline]
+    fn save_recent(&self, config: &mut IniConfig)
+    {
+        for i in 0..Self::MAX_RECENT_FILES
+        {
+            config.0.set(
+                RECENT_FILES_SECTION,
+                &Self::recent_field(i),
+                self.recent.get(i).map(|path| path.to_str().unwrap().to_owned())
+            );
+        }
+    }
 
     #[inline]
     fn update_window_title(&self, window: &mut Window)
     {
         window.title = match self
-            .0
+            .path
             .as_ref()
             .map(|path| path.file_stem().unwrap().to_str().unwrap())
         {
@@ -130,15 +277,48 @@ impl OpenFile
 pub(crate) struct Config
 {
     /// The keyboard binds.
-    pub binds:             BindsKeyCodes,
+    pub binds:                  BindsKeyCodes,
+    /// The mouse binds.
+    pub mouse_binds:            MouseBinds,
+    /// The settings of the camera movements.
+    pub navigation:             NavigationSettings,
+    /// The timing and the actions of the double-clicks.
+    pub double_click:           DoubleClickSettings,
     /// The file being edited.
-    pub open_file:         OpenFile,
-    /// The executable to export the map.
-    pub exporter:          Option<PathBuf>,
+    pub open_file:              OpenFile,
+    /// The profiles of the executables to export the map.
+    pub exporters:              ExporterProfiles,
+    /// The named presets of the tools settings.
+    pub tools_presets:          ToolsPresets,
     /// The user defined colors.
-    pub colors:            ColorResources,
+    pub colors:                 ColorResources,
     /// Whether the first boot warning was displayed.
-    pub warning_displayed: bool
+    pub warning_displayed:      bool,
+    /// Whether the selected entities should be outlined with marching ants.
+    pub marching_ants:          bool,
+    /// The translations of the UI strings.
+    pub locale:                 Locale,
+    /// The tools shown in the toolbar.
+    pub toolbar:                ToolbarLayout,
+    /// The textures marked as favorite in the texture editor.
+    pub favorite_textures:      FavoriteTextures,
+    /// The interval, in seconds, between the checks for config changes to be saved to file. If
+    /// zero the config is only saved on shutdown.
+    pub autosave_interval:      f32,
+    /// The interval, in seconds, between the autosave snapshots of the map being edited. If zero
+    /// no snapshots are written.
+    pub map_autosave_interval:  f32,
+    /// The interval, in seconds, between the checks for added or modified files in the textures
+    /// folder, which are then reloaded, and for changes in the things folder, which cause the
+    /// things to be reloaded. If zero the folders are not checked.
+    pub texture_watch_interval: f32,
+    /// The minimum zoom of the camera, in percentage. At 100% a texture pixel takes a screen
+    /// pixel.
+    pub min_zoom:               u16,
+    /// The maximum zoom of the camera, in percentage.
+    pub max_zoom:               u16,
+    /// The amount of grid squares the cursor is moved by shift + the directional keys.
+    pub cursor_step:            u8
 }
 
 impl Default for Config
@@ -147,15 +327,40 @@ impl Default for Config
     fn default() -> Self
     {
         Self {
-            binds:             BindsKeyCodes::default(),
-            open_file:         OpenFile(None),
-            exporter:          None,
-            colors:            ColorResources::default(),
-            warning_displayed: false
+            binds:                  BindsKeyCodes::default(),
+            mouse_binds:            MouseBinds::default(),
+            navigation:             NavigationSettings::default(),
+            double_click:           DoubleClickSettings::default(),
+            open_file:              OpenFile::new(),
+            exporters:              ExporterProfiles::default(),
+            tools_presets:          ToolsPresets::default(),
+            colors:                 ColorResources::default(),
+            warning_displayed:      false,
+            marching_ants:          false,
+            locale:                 Locale::default(),
+            toolbar:                ToolbarLayout::default(),
+            favorite_textures:      FavoriteTextures::default(),
+            autosave_interval:      DEFAULT_AUTOSAVE_INTERVAL,
+            map_autosave_interval:  DEFAULT_MAP_AUTOSAVE_INTERVAL,
+            texture_watch_interval: DEFAULT_TEXTURE_WATCH_INTERVAL,
+            min_zoom:               DEFAULT_MIN_ZOOM,
+            max_zoom:               DEFAULT_MAX_ZOOM,
+            cursor_step:            DEFAULT_CURSOR_STEP
         }
     }
 }
 
+impl Config
+{
+    /// Returns the range of the scales the camera can assume based on the zoom limits.
+    #[inline]
+    #[must_use]
+    pub fn camera_scale_range(&self) -> RangeInclusive<f32>
+    {
+        100f32 / f32::from(self.max_zoom)..=100f32 / f32::from(self.min_zoom)
+    }
+}
+
 //=======================================================================//
 
 /// Wrapper of the ini config parser.
@@ -177,12 +382,18 @@ impl FromWorld for IniConfig
         let mut ini_config = Ini::new_cs();
         ini_config.load(CONFIG_FILE_NAME).unwrap();
 
+        let user_exporters = world
+            .remove_resource::<UserExporters>()
+            .map(|exporters| exporters.0)
+            .unwrap_or_default();
+
         world.resource_scope(|world, mut materials: Mut<Assets<ColorMaterial>>| {
             let open_file = ini_config.get(OPEN_FILE_SECTION, OPEN_FILE_FIELD).and_then(|file| {
                 let path = PathBuf::from(file);
 
                 path.exists().then(|| {
-                    let file = OpenFile(path.into());
+                    let mut file = OpenFile::new();
+                    file.path = path.into();
 
                     file.update_window_title(
                         &mut world
@@ -203,23 +414,71 @@ impl FromWorld for IniConfig
                 config.open_file = file;
             }
 
+            config.open_file.load_recent(&ini_config);
+
             config.warning_displayed = ini_config
                 .get(WARNING_SECTION, WARNING_FIELD)
                 .unwrap_or("false".to_string())
                 .parse()
                 .unwrap_or_default();
 
-            config.binds.load(&ini_config);
+            config.marching_ants = ini_config
+                .get(SELECTION_SECTION, MARCHING_ANTS_FIELD)
+                .unwrap_or("false".to_string())
+                .parse()
+                .unwrap_or_default();
 
-            if let Some(file) = ini_config.get(EXPORTER_SECTION, EXPORTER_FIELD)
+            config.autosave_interval = ini_config
+                .get(CONFIG_SECTION, AUTOSAVE_INTERVAL_FIELD)
+                .and_then(|value| value.parse::<f32>().ok())
+                .filter(|value| value.is_finite() && *value >= 0f32)
+                .unwrap_or(DEFAULT_AUTOSAVE_INTERVAL);
+
+            config.map_autosave_interval = ini_config
+                .get(CONFIG_SECTION, MAP_AUTOSAVE_INTERVAL_FIELD)
+                .and_then(|value| value.parse::<f32>().ok())
+                .filter(|value| MAP_AUTOSAVE_INTERVAL_RANGE.contains(value))
+                .unwrap_or(DEFAULT_MAP_AUTOSAVE_INTERVAL);
+
+            config.texture_watch_interval = ini_config
+                .get(CONFIG_SECTION, TEXTURE_WATCH_INTERVAL_FIELD)
+                .and_then(|value| value.parse::<f32>().ok())
+                .filter(|value| TEXTURE_WATCH_INTERVAL_RANGE.contains(value))
+                .unwrap_or(DEFAULT_TEXTURE_WATCH_INTERVAL);
+
+            let zoom = |field, default| {
+                ini_config
+                    .get(CAMERA_SECTION, field)
+                    .and_then(|value| value.parse::<u16>().ok())
+                    .filter(|value| ZOOM_LIMITS_RANGE.contains(value))
+                    .unwrap_or(default)
+            };
+            (config.min_zoom, config.max_zoom) = match (
+                zoom(MIN_ZOOM_FIELD, DEFAULT_MIN_ZOOM),
+                zoom(MAX_ZOOM_FIELD, DEFAULT_MAX_ZOOM)
+            )
             {
-                let file = PathBuf::from(file);
+                (min, max) if min <= max => (min, max),
+                _ => (DEFAULT_MIN_ZOOM, DEFAULT_MAX_ZOOM)
+            };
 
-                if file.exists() && file.is_executable()
-                {
-                    config.exporter = file.into();
-                }
-            }
+            config.cursor_step = ini_config
+                .get(CURSOR_SECTION, CURSOR_STEP_FIELD)
+                .and_then(|value| value.parse::<u8>().ok())
+                .filter(|value| CURSOR_STEP_RANGE.contains(value))
+                .unwrap_or(DEFAULT_CURSOR_STEP);
+
+            config.binds.load(&ini_config);
+            config.mouse_binds.load(&ini_config);
+            config.navigation.load(&ini_config);
+            config.double_click.load(&ini_config);
+            config.locale.load(&ini_config);
+            config.toolbar.load(&ini_config);
+            config.favorite_textures.load(&ini_config);
+
+            config.exporters.load(&ini_config);
+            config.exporters.extend(user_exporters);
+            config.tools_presets.load(&ini_config);
 
             config.colors.load(&ini_config, &mut materials);
         });
@@ -251,9 +510,15 @@ fn create_default_config_file() -> std::io::Result<()>
 
     #[rustfmt::skip]
     let mut config = format!(
-        "[{WARNING_SECTION}]\n{WARNING_FIELD}\n[{OPEN_FILE_SECTION}]\n{OPEN_FILE_FIELD}\n[{EXPORTER_SECTION}]\n{EXPORTER_FIELD}\n"
+        "[{WARNING_SECTION}]\n{WARNING_FIELD}\n[{OPEN_FILE_SECTION}]\n{OPEN_FILE_FIELD}\n[{SELECTION_SECTION}]\n{MARCHING_ANTS_FIELD}\n[{CONFIG_SECTION}]\n{AUTOSAVE_INTERVAL_FIELD} = {DEFAULT_AUTOSAVE_INTERVAL}\n{MAP_AUTOSAVE_INTERVAL_FIELD} = {DEFAULT_MAP_AUTOSAVE_INTERVAL}\n{TEXTURE_WATCH_INTERVAL_FIELD} = {DEFAULT_TEXTURE_WATCH_INTERVAL}\n[{CAMERA_SECTION}]\n{MIN_ZOOM_FIELD} = {DEFAULT_MIN_ZOOM}\n{MAX_ZOOM_FIELD} = {DEFAULT_MAX_ZOOM}\n[{CURSOR_SECTION}]\n{CURSOR_STEP_FIELD} = {DEFAULT_CURSOR_STEP}\n"
     );
     config.push_str(&Bind::default_binds());
+    config.push_str(&MouseBinds::default_binds());
+    config.push_str(&NavigationSettings::default_config());
+    config.push_str(&DoubleClickSettings::default_config());
+    config.push_str(&Locale::default_config());
+    config.push_str(&ToolbarLayout::default_config());
+    config.push_str(&ExporterProfiles::default_config());
     config.push_str(&Color::default_colors());
 
     file.write_all(config.as_bytes())?;
@@ -262,14 +527,9 @@ fn create_default_config_file() -> std::io::Result<()>
 
 //=======================================================================//
 
-/// Saves `config` to file.
-#[allow(clippy::needless_pass_by_value)]
+/// Stores the values of `config` in `ini_config`.
 #[inline]
-fn save_config(
-    mut ini_config: ResMut<IniConfig>,
-    config: Res<Config>,
-    mut app_exit_events: EventWriter<AppExit>
-)
+fn store_config(ini_config: &mut IniConfig, config: &Config)
 {
     ini_config
         .0
@@ -281,14 +541,109 @@ fn save_config(
         config.open_file.path().map(|path| path.to_str().unwrap().to_string())
     );
 
+    config.open_file.save_recent(ini_config);
+
+    ini_config.0.set(
+        SELECTION_SECTION,
+        MARCHING_ANTS_FIELD,
+        config.marching_ants.to_string().into()
+    );
+
+    ini_config.0.set(
+        CONFIG_SECTION,
+        AUTOSAVE_INTERVAL_FIELD,
+        config.autosave_interval.to_string().into()
+    );
+
+    ini_config.0.set(
+        CONFIG_SECTION,
+        MAP_AUTOSAVE_INTERVAL_FIELD,
+        config.map_autosave_interval.to_string().into()
+    );
+
     ini_config.0.set(
-        EXPORTER_SECTION,
-        EXPORTER_FIELD,
-        config.exporter.as_ref().map(|path| path.to_str().unwrap().to_owned())
+        CONFIG_SECTION,
+        TEXTURE_WATCH_INTERVAL_FIELD,
+        config.texture_watch_interval.to_string().into()
     );
 
-    config.binds.save(&mut ini_config);
-    config.colors.save(&mut ini_config);
+    ini_config
+        .0
+        .set(CAMERA_SECTION, MIN_ZOOM_FIELD, config.min_zoom.to_string().into());
+    ini_config
+        .0
+        .set(CAMERA_SECTION, MAX_ZOOM_FIELD, config.max_zoom.to_string().into());
+    ini_config
+        .0
+        .set(CURSOR_SECTION, CURSOR_STEP_FIELD, config.cursor_step.to_string().into());
+
+    config.binds.save(ini_config);
+    config.mouse_binds.save(ini_config);
+    config.navigation.save(ini_config);
+    config.double_click.save(ini_config);
+    config.locale.save(ini_config);
+    config.toolbar.save(ini_config);
+    config.favorite_textures.save(ini_config);
+    config.exporters.save(ini_config);
+    config.tools_presets.save(ini_config);
+    config.colors.save(ini_config);
+}
+
+//=======================================================================//
+
+/// Periodically saves `config` to file if it was changed since the last save, so that edits to
+/// binds, colors, exporter, etc. etc. are not lost if the application does not shut down properly.
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+fn autosave_config(
+    mut elapsed: Local<f32>,
+    mut last_saved: Local<String>,
+    time: Res<Time>,
+    mut ini_config: ResMut<IniConfig>,
+    config: Res<Config>
+)
+{
+    if config.autosave_interval == 0f32
+    {
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+
+    if *elapsed < config.autosave_interval
+    {
+        return;
+    }
+
+    *elapsed = 0f32;
+    store_config(&mut ini_config, &config);
+    let content = ini_config.0.writes();
+
+    if content == *last_saved
+    {
+        return;
+    }
+
+    if ini_config.0.write(CONFIG_FILE_NAME).is_err()
+    {
+        error_message("Error while saving config file");
+    }
+
+    *last_saved = content;
+}
+
+//=======================================================================//
+
+/// Saves `config` to file.
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+fn save_config(
+    mut ini_config: ResMut<IniConfig>,
+    config: Res<Config>,
+    mut app_exit_events: EventWriter<AppExit>
+)
+{
+    store_config(&mut ini_config, &config);
 
     if ini_config.0.write(CONFIG_FILE_NAME).is_err()
     {
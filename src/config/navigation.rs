@@ -0,0 +1,179 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::input::{keyboard::KeyCode, ButtonInput};
+use bevy_egui::egui;
+use configparser::ini::Ini;
+use glam::Vec2;
+
+use super::{language::Locale, IniConfig};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the navigation settings.
+const NAVIGATION_SECTION: &str = "NAVIGATION";
+/// The ini key of the WASD panning toggle.
+const WASD_PAN_FIELD: &str = "wasd_pan";
+/// The ini key of the speed of the keyboard panning.
+const PAN_SPEED_FIELD: &str = "pan_speed";
+/// The default speed of the keyboard panning, in screen pixels per second.
+const DEFAULT_PAN_SPEED: u16 = 800;
+/// The minimum speed of the keyboard panning.
+const MIN_PAN_SPEED: u16 = 50;
+/// The maximum speed of the keyboard panning.
+const MAX_PAN_SPEED: u16 = 5000;
+/// The ini key of the edge scrolling toggle.
+const EDGE_SCROLL_FIELD: &str = "edge_scroll";
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The settings of the camera movements that do not depend on the mouse binds.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(crate) struct NavigationSettings
+{
+    /// Whether the view is panned while the W, A, S, and D keys are held.
+    pub wasd_pan:    bool,
+    /// The speed of the keyboard panning and of the edge scrolling, in screen pixels per second.
+    pub pan_speed:   u16,
+    /// Whether the view scrolls when the cursor reaches the border of the viewport while the left
+    /// mouse button is held.
+    pub edge_scroll: bool
+}
+
+impl Default for NavigationSettings
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            wasd_pan:    false,
+            pan_speed:   DEFAULT_PAN_SPEED,
+            edge_scroll: false
+        }
+    }
+}
+
+impl NavigationSettings
+{
+    /// Returns the default navigation settings, to be appended to the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_config() -> String
+    {
+        format!(
+            "[{NAVIGATION_SECTION}]\n{WASD_PAN_FIELD} = false\n{PAN_SPEED_FIELD} = \
+             {DEFAULT_PAN_SPEED}\n{EDGE_SCROLL_FIELD} = false\n"
+        )
+    }
+
+    /// Loads the navigation settings stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        if let Some(value) = config
+            .get(NAVIGATION_SECTION, WASD_PAN_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            self.wasd_pan = value;
+        }
+
+        if let Some(speed) = config
+            .get(NAVIGATION_SECTION, PAN_SPEED_FIELD)
+            .and_then(|value| value.parse::<u16>().ok())
+        {
+            self.pan_speed = speed.clamp(MIN_PAN_SPEED, MAX_PAN_SPEED);
+        }
+
+        if let Some(value) = config
+            .get(NAVIGATION_SECTION, EDGE_SCROLL_FIELD)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            self.edge_scroll = value;
+        }
+    }
+
+    /// Stores the navigation settings in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        config
+            .0
+            .set(NAVIGATION_SECTION, WASD_PAN_FIELD, self.wasd_pan.to_string().into());
+        config
+            .0
+            .set(NAVIGATION_SECTION, PAN_SPEED_FIELD, self.pan_speed.to_string().into());
+        config
+            .0
+            .set(NAVIGATION_SECTION, EDGE_SCROLL_FIELD, self.edge_scroll.to_string().into());
+    }
+
+    /// Whether `key` pans the view, and therefore does not trigger the bind it is assigned to.
+    #[inline]
+    #[must_use]
+    pub const fn pans_with(self, key: KeyCode) -> bool
+    {
+        self.wasd_pan &&
+            matches!(key, KeyCode::KeyW | KeyCode::KeyA | KeyCode::KeyS | KeyCode::KeyD)
+    }
+
+    /// Returns the direction the view should be panned based on the held WASD keys, if any.
+    #[inline]
+    #[must_use]
+    pub fn wasd_direction(self, key_inputs: &ButtonInput<KeyCode>) -> Option<Vec2>
+    {
+        if !self.wasd_pan
+        {
+            return None;
+        }
+
+        let mut dir = Vec2::ZERO;
+
+        if key_inputs.pressed(KeyCode::KeyD)
+        {
+            dir.x += 1f32;
+        }
+
+        if key_inputs.pressed(KeyCode::KeyA)
+        {
+            dir.x -= 1f32;
+        }
+
+        if key_inputs.pressed(KeyCode::KeyW)
+        {
+            dir.y += 1f32;
+        }
+
+        if key_inputs.pressed(KeyCode::KeyS)
+        {
+            dir.y -= 1f32;
+        }
+
+        (dir != Vec2::ZERO).then(|| dir.normalize())
+    }
+
+    /// Draws the UI elements to edit the settings. Must be called inside an [`egui::Grid`].
+    #[inline]
+    pub fn show(&mut self, ui: &mut egui::Ui, locale: &Locale)
+    {
+        ui.label(locale.tr("WASD pan"));
+        ui.checkbox(&mut self.wasd_pan, "");
+        ui.end_row();
+
+        ui.label(locale.tr("Pan speed"));
+        ui.add(egui::DragValue::new(&mut self.pan_speed).range(MIN_PAN_SPEED..=MAX_PAN_SPEED));
+        ui.end_row();
+
+        ui.label(locale.tr("Edge scroll"));
+        ui.checkbox(&mut self.edge_scroll, "");
+        ui.end_row();
+    }
+}
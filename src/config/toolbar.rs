@@ -0,0 +1,96 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use configparser::ini::Ini;
+
+use super::{controls::bind::Bind, IniConfig};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The ini section of the toolbar layout.
+const TOOLBAR_SECTION: &str = "TOOLBAR";
+/// The ini key of the tools shown in the toolbar.
+const TOOLS_FIELD: &str = "tools";
+/// The separator of the tools listed in the ini value.
+const SEPARATOR: char = ',';
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The tools shown in the toolbar and their order.
+/// The tools are listed in the config file through the keys of their binds, separated by commas,
+/// i.e. `tools = square, entity, vertex`. Tools that are not listed are hidden and cannot be
+/// enabled. If no tools are listed all of them are shown in their default order.
+#[must_use]
+#[derive(Default)]
+pub(crate) struct ToolbarLayout(Option<Vec<Bind>>);
+
+impl ToolbarLayout
+{
+    /// Returns the default toolbar setting, to be appended to the config file.
+    #[inline]
+    #[must_use]
+    pub(in crate::config) fn default_config() -> String
+    {
+        format!("[{TOOLBAR_SECTION}]\n{TOOLS_FIELD}\n")
+    }
+
+    /// Loads the toolbar layout stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        let value = match config.get(TOOLBAR_SECTION, TOOLS_FIELD)
+        {
+            Some(value) => value,
+            None => return
+        };
+
+        let binds = value
+            .split(SEPARATOR)
+            .filter_map(|key| {
+                let key = key.trim();
+                Bind::iter().find(|bind| bind.config_key() == key)
+            })
+            .collect::<Vec<_>>();
+
+        if !binds.is_empty()
+        {
+            self.0 = binds.into();
+        }
+    }
+
+    /// Stores the toolbar layout in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        let value = self.0.as_ref().map(|binds| {
+            binds
+                .iter()
+                .map(|bind| bind.config_key())
+                .collect::<Vec<_>>()
+                .join(&format!("{SEPARATOR} "))
+        });
+
+        config.0.set(TOOLBAR_SECTION, TOOLS_FIELD, value);
+    }
+
+    /// Returns the position in the toolbar of the tool associated with `bind`, if it is shown.
+    /// `default` is the position of the tool in the default layout.
+    #[inline]
+    #[must_use]
+    pub fn position(&self, bind: Bind, default: usize) -> Option<usize>
+    {
+        match &self.0
+        {
+            Some(binds) => binds.iter().position(|b| *b == bind),
+            None => default.into()
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use configparser::ini::Ini;
+use hill_vacuum_shared::continue_if_none;
+
+use super::IniConfig;
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The prefix of the ini sections of the tools settings presets, followed by the preset name.
+const PRESET_SECTION_PREFIX: &str = "TOOLS_PRESET:";
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A named set of tools settings values, stored as ini keys and values.
+#[must_use]
+#[derive(Clone)]
+pub(crate) struct ToolsPreset
+{
+    /// The name of the preset.
+    name:   String,
+    /// The keys and values of the settings.
+    values: Vec<(String, String)>
+}
+
+impl ToolsPreset
+{
+    /// Returns the name of the preset.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str { &self.name }
+
+    /// Returns the value associated with `key`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str>
+    {
+        self.values.iter().find_map(|(k, v)| (k == key).then_some(v.as_str()))
+    }
+}
+
+//=======================================================================//
+
+/// The named presets of the tools settings.
+#[must_use]
+#[derive(Default)]
+pub(crate) struct ToolsPresets(Vec<ToolsPreset>);
+
+impl ToolsPresets
+{
+    /// Loads the presets stored in `config`.
+    #[inline]
+    pub(in crate::config) fn load(&mut self, config: &Ini)
+    {
+        for section in config.sections()
+        {
+            let name = continue_if_none!(section.strip_prefix(PRESET_SECTION_PREFIX));
+            let values = config
+                .get_map_ref()
+                .get(&section)
+                .into_iter()
+                .flat_map(|keys| keys.iter())
+                .filter_map(|(key, value)| Some((key.clone(), value.clone()?)))
+                .collect();
+
+            self.0.push(ToolsPreset {
+                name: name.to_owned(),
+                values
+            });
+        }
+
+        self.0.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Stores the presets in `config`.
+    #[inline]
+    pub(in crate::config) fn save(&self, config: &mut IniConfig)
+    {
+        for section in config.0.sections()
+        {
+            if section.starts_with(PRESET_SECTION_PREFIX)
+            {
+                _ = config.0.remove_section(&section);
+            }
+        }
+
+        for preset in &self.0
+        {
+            let section = format!("{PRESET_SECTION_PREFIX}{}", preset.name);
+
+            for (key, value) in &preset.values
+            {
+                config.0.set(&section, key, value.clone().into());
+            }
+        }
+    }
+
+    /// Returns an iterator to the presets.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ToolsPreset> { self.0.iter() }
+
+    /// Stores a preset named `name` with the settings `values`, replacing the one with the same
+    /// name, if any.
+    #[inline]
+    pub fn insert<'a>(&mut self, name: &str, values: impl IntoIterator<Item = (&'a str, String)>)
+    {
+        let preset = ToolsPreset {
+            name:   name.trim().to_owned(),
+            values: values.into_iter().map(|(k, v)| (k.to_owned(), v)).collect()
+        };
+
+        match self.0.binary_search_by(|p| p.name.cmp(&preset.name))
+        {
+            Ok(index) => self.0[index] = preset,
+            Err(index) => self.0.insert(index, preset)
+        };
+    }
+
+    /// Removes the preset at `index`.
+    #[inline]
+    pub fn remove(&mut self, index: usize) { _ = self.0.remove(index); }
+}
@@ -29,14 +29,24 @@ mod utils;
 pub use crate::{
     map::{
         brush::{group::GroupViewer as Group, BrushViewer as Brush},
+        builder::MapBuilder,
         drawer::{
             animation::{Animation, Atlas, List, Timing},
             texture::{TextureInterface, TextureSettings}
         },
-        path::nodes::{Movement, NodeViewer as Node},
-        properties::value::Value,
+        path::nodes::{Curve, Easing, Movement, NodeViewer as Node},
+        properties::{
+            schema::{PropertySchema, PropertySchemas, PropertyViolation},
+            value::{Rgba, Value}
+        },
         thing::{Thing, ThingId, ThingViewer as ThingInstance},
-        Exporter
+        BrushMesh,
+        ExportManifest,
+        Exporter,
+        MapMetadata,
+        TessellatedMap,
+        TextureBatch,
+        TmxTexture
     },
     utils::identifiers::Id
 };
@@ -57,7 +67,7 @@ pub(crate) mod ui_mod
     //
     //=======================================================================//
 
-    use std::{collections::HashMap, io::Write, sync::RwLock};
+    use std::{collections::HashMap, io::Write, sync::{RwLock, RwLockWriteGuard}};
 
     pub use bevy;
     use bevy::{
@@ -74,15 +84,28 @@ pub(crate) mod ui_mod
         DefaultPlugins
     };
 
+    pub use crate::{
+        config::exporters::ExporterSetup,
+        map::editor::events::{
+            EditCommitted,
+            EntityDespawned,
+            EntitySpawned,
+            HillVacuumSet,
+            MapSaved
+        }
+    };
     use crate::{
-        config::ConfigPlugin,
+        config::{exporters::UserExporters, ConfigPlugin},
         embedded_assets::EmbeddedPlugin,
         map::{
             properties::{BrushUserProperties, ThingUserProperties},
             thing::HardcodedThings,
-            MapEditorPlugin
+            MapEditorPlugin,
+            UserManualSections,
+            UserPropertySchemas
         },
         utils::misc::TakeValue,
+        PropertySchemas,
         Value
     };
     #[allow(unused_imports)]
@@ -129,6 +152,8 @@ pub(crate) mod ui_mod
         Export,
         /// Select all.
         SelectAll,
+        /// Go to entity.
+        GoToEntity,
         /// Copy.
         Copy,
         /// Paste.
@@ -167,6 +192,7 @@ pub(crate) mod ui_mod
                 Self::Open => "Ctrl+O",
                 Self::Export => "Ctrl+E",
                 Self::SelectAll => "Ctrl+A",
+                Self::GoToEntity => "Ctrl+G",
                 Self::Copy => "Ctrl+C",
                 Self::Paste => "Ctrl+V",
                 Self::Cut => "Ctrl+X",
@@ -195,6 +221,7 @@ pub(crate) mod ui_mod
                 Self::Fullscreen => KeyCode::Enter,
                 Self::ToggleManual => KeyCode::Backquote,
                 Self::SelectAll => KeyCode::KeyA,
+                Self::GoToEntity => KeyCode::KeyG,
                 Self::Copy => KeyCode::KeyC,
                 Self::Paste => KeyCode::KeyV,
                 Self::Cut => KeyCode::KeyX,
@@ -229,21 +256,35 @@ pub(crate) mod ui_mod
     //
     //=======================================================================//
 
+    /// The settings of the editor provided by the host application.
     #[must_use]
-    /// The UI editor plugin.
-    pub struct HillVacuumPlugin(
-        RwLock<(HashMap<&'static str, Value>, HashMap<&'static str, Value>, Vec<Thing>)>
-    );
-
-    impl Default for HillVacuumPlugin
+    #[derive(Default)]
+    struct PluginSettings
     {
-        #[inline]
-        fn default() -> Self
-        {
-            Self(RwLock::new((HashMap::default(), HashMap::default(), Vec::default())))
-        }
+        /// The properties associated with the [`Brush`]es.
+        brush_properties: HashMap<&'static str, Value>,
+        /// The properties associated with the [`ThingInstance`]s.
+        thing_properties: HashMap<&'static str, Value>,
+        /// The [`Thing`]s coded into the engine.
+        hardcoded_things: Vec<Thing>,
+        /// The sections appended to the manual, as pairs of title and markdown text.
+        manual_sections:  Vec<(&'static str, &'static str)>,
+        /// The schemas of the properties.
+        property_schemas: PropertySchemas,
+        /// The exporter profiles added to the ones of the config file.
+        exporters:        Vec<ExporterSetup>
     }
 
+    //=======================================================================//
+
+    #[must_use]
+    /// The UI editor plugin.  
+    /// The editor can be configured when the plugin is created through the builder methods, such
+    /// as [`HillVacuumPlugin::with_things`], [`HillVacuumPlugin::with_default_properties`], and
+    /// [`HillVacuumPlugin::with_exporter`].
+    #[derive(Default)]
+    pub struct HillVacuumPlugin(RwLock<PluginSettings>);
+
     impl bevy::app::Plugin for HillVacuumPlugin
     {
         #[inline]
@@ -280,7 +321,17 @@ pub(crate) mod ui_mod
             };
             window.set_maximized(true);
 
-            let (brush_props, thing_props, things) = self.0.write().unwrap().take_value();
+            let PluginSettings {
+                brush_properties,
+                thing_properties,
+                hardcoded_things,
+                manual_sections,
+                property_schemas,
+                exporters
+            } = self.settings().take_value();
+
+            // Must be inserted before the config is loaded.
+            app.insert_resource(UserExporters(exporters));
 
             app.add_plugins(
                 DefaultPlugins
@@ -309,14 +360,20 @@ pub(crate) mod ui_mod
             )
             .add_plugins((EmbeddedPlugin, ConfigPlugin, MapEditorPlugin))
             .init_state::<EditorState>()
-            .insert_resource(BrushUserProperties(brush_props))
-            .insert_resource(ThingUserProperties(thing_props))
-            .insert_resource(HardcodedThings(things));
+            .insert_resource(BrushUserProperties(brush_properties))
+            .insert_resource(ThingUserProperties(thing_properties))
+            .insert_resource(HardcodedThings(hardcoded_things))
+            .insert_resource(UserManualSections(manual_sections))
+            .insert_resource(UserPropertySchemas(property_schemas));
         }
     }
 
     impl HillVacuumPlugin
     {
+        /// Returns a write guard to the [`PluginSettings`].
+        #[inline]
+        fn settings(&self) -> RwLockWriteGuard<'_, PluginSettings> { self.0.write().unwrap() }
+
         /// Returns a new [`HillVacuumPlugin`].  
         /// `brush_properties`: the properties associated with the [`Brush`]es.  
         /// `thing_properties`: the properties associated with the [`ThingInstance`]s.  
@@ -328,11 +385,77 @@ pub(crate) mod ui_mod
             T: IntoIterator<Item = (&'static str, Value)>,
             H: IntoIterator<Item = Thing>
         {
-            Self(RwLock::new((
-                brush_properties.into_iter().collect(),
-                thing_properties.into_iter().collect(),
-                hardcoded_things.into_iter().collect()
-            )))
+            Self::default()
+                .with_default_properties(brush_properties, thing_properties)
+                .with_things(hardcoded_things)
+        }
+
+        /// Adds `things` to the [`Thing`]s coded into the engine. A [`Thing`] with the same
+        /// [`ThingId`](crate::ThingId) as a previously added one replaces it, and the ones loaded
+        /// from the .ini files in the `assets/things/` folder replace both.
+        #[inline]
+        pub fn with_things<H>(self, things: H) -> Self
+        where
+            H: IntoIterator<Item = Thing>
+        {
+            let mut settings = self.settings();
+
+            for thing in things
+            {
+                settings.hardcoded_things.retain(|t| t.id() != thing.id());
+                settings.hardcoded_things.push(thing);
+            }
+
+            drop(settings);
+            self
+        }
+
+        /// Adds the default properties associated with the [`Brush`]es and the
+        /// [`ThingInstance`]s. A property with the same name as a previously added one replaces
+        /// it.
+        #[inline]
+        pub fn with_default_properties<B, T>(self, brush_properties: B, thing_properties: T) -> Self
+        where
+            B: IntoIterator<Item = (&'static str, Value)>,
+            T: IntoIterator<Item = (&'static str, Value)>
+        {
+            let mut settings = self.settings();
+            settings.brush_properties.extend(brush_properties);
+            settings.thing_properties.extend(thing_properties);
+            drop(settings);
+            self
+        }
+
+        /// Adds the exporter profile described by `exporter`, which can be picked through
+        /// Options->Exporter. It is not added if the config file already contains a profile with
+        /// the same name.
+        #[inline]
+        pub fn with_exporter(self, exporter: ExporterSetup) -> Self
+        {
+            self.settings().exporters.push(exporter);
+            self
+        }
+
+        /// Adds a section titled `title` at the end of the manual, with contents described by the
+        /// markdown `text`.  
+        /// Supported markdown elements are `###` headings, `-` list items, `code` spans, fenced
+        /// code blocks, and images embedded with `![description](path)` lines, where `path` is
+        /// relative to the `assets/` folder.
+        #[inline]
+        pub fn with_manual_section(self, title: &'static str, text: &'static str) -> Self
+        {
+            self.settings().manual_sections.push((title, text));
+            self
+        }
+
+        /// Sets the [`PropertySchemas`] the values of the properties of the [`Brush`]es and
+        /// [`ThingInstance`]s are validated against, both when edited in the properties window
+        /// and when the map is checked or exported.
+        #[inline]
+        pub fn with_property_schemas(self, schemas: PropertySchemas) -> Self
+        {
+            self.settings().property_schemas = schemas;
+            self
         }
     }
 
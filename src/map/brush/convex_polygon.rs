@@ -62,6 +62,8 @@ use crate::{
                 are_vxs_ccw,
                 is_polygon_convex,
                 rotate_point,
+                rotate_point_around_origin,
+                round_corners,
                 sort_vxs_ccw,
                 vertexes_orientation,
                 vxs_center,
@@ -831,6 +833,9 @@ impl<'b> TextureInterface for MovingTextureSettings<'b>
     #[inline]
     fn height_f32(&self) -> f32 { self.texture.height_f32() }
 
+    #[inline]
+    fn opacity(&self) -> u8 { self.texture.opacity() }
+
     #[inline]
     fn angle(&self) -> f32 { self.texture.angle() }
 
@@ -1112,6 +1117,54 @@ impl ConvexPolygon
         self.vertexes.iter().map(|svx| svx.vec)
     }
 
+    /// Returns the area of the polygon.
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32
+    {
+        self.vertexes
+            .pair_iter()
+            .unwrap()
+            .map(|[vx_j, vx_i]| vx_j.vec.perp_dot(vx_i.vec))
+            .sum::<f32>()
+            .abs() /
+            2f32
+    }
+
+    /// Returns the perimeter of the polygon.
+    #[inline]
+    #[must_use]
+    pub fn perimeter(&self) -> f32
+    {
+        self.vertexes
+            .pair_iter()
+            .unwrap()
+            .map(|[vx_j, vx_i]| vx_j.vec.distance(vx_i.vec))
+            .sum()
+    }
+
+    /// Whether `self` and `other` share a stretch of one of their sides.
+    #[inline]
+    #[must_use]
+    pub fn shares_side(&self, other: &Self) -> bool
+    {
+        self.vertexes.pair_iter().unwrap().any(|[vx_j, vx_i]| {
+            let side = [vx_j.vec, vx_i.vec];
+
+            other.vertexes.pair_iter().unwrap().any(|[o_j, o_i]| {
+                let other_side = [o_j.vec, o_i.vec];
+                let mut touching = other_side
+                    .into_iter()
+                    .filter(|vx| is_point_on_segment(&side, *vx))
+                    .chain(side.into_iter().filter(|vx| is_point_on_segment(&other_side, *vx)));
+                let first = return_if_none!(touching.next(), false);
+
+                // A single touching point means the sides only meet at a vertex.
+                touching.any(|vx| !vx.around_equal(&first))
+            })
+        })
+    }
+
     #[inline]
     pub fn take_texture_settings(self) -> Option<TextureSettings> { self.texture }
 
@@ -1217,6 +1270,15 @@ impl ConvexPolygon
             .all(|[vx_j, vx_i]| is_point_inside_clip_edge(&[vx_j.vec, vx_i.vec], p))
     }
 
+    /// Returns an iterator to the vertexes of the polygon paired with their selection status.
+    #[inline]
+    pub(in crate::map::brush) fn vertexes_selection(
+        &self
+    ) -> impl ExactSizeIterator<Item = (Vec2, bool)> + '_
+    {
+        self.vertexes.iter().map(|svx| (svx.vec, svx.selected))
+    }
+
     #[inline]
     pub(in crate::map::brush) fn selected_vertexes(&self) -> Option<impl Iterator<Item = Vec2>>
     {
@@ -1387,6 +1449,36 @@ impl ConvexPolygon
         assert!(self.valid(), "move_vertexes_at_indexes generated an invalid polygon.");
     }
 
+    /// Whether moving the vertexes at the indexes and by the deltas specified in `idxs` generates
+    /// a valid polygon.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn check_vertexes_move_at_indexes(
+        &mut self,
+        idxs: &[(Vec<u8>, Vec2)]
+    ) -> bool
+    {
+        for (idxs, delta) in idxs
+        {
+            for i in idxs
+            {
+                self.vertexes[*i as usize] += *delta;
+            }
+        }
+
+        let valid = !self.vertexes.iter().any(|svx| svx.vec.out_of_bounds()) && self.vxs_valid();
+
+        for (idxs, delta) in idxs
+        {
+            for i in idxs
+            {
+                self.vertexes[*i as usize] -= *delta;
+            }
+        }
+
+        valid
+    }
+
     #[inline]
     pub fn swap_polygon(&mut self, polygon: &mut Self)
     {
@@ -1527,6 +1619,20 @@ impl ConvexPolygon
         self.texture.take_value().unwrap()
     }
 
+    /// Replaces the texture `prev` with `new` in the [`TextureSettings`], if any. Returns whether
+    /// anything was changed.
+    #[inline]
+    #[must_use]
+    pub fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+    {
+        let renamed = self
+            .texture
+            .as_mut()
+            .is_some_and(|texture| texture.rename_texture(prev, new));
+        self.texture_edited |= renamed;
+        renamed
+    }
+
     #[inline]
     pub(in crate::map::brush) fn check_texture_move(
         &self,
@@ -1722,6 +1828,14 @@ impl ConvexPolygon
         self.set_texture_updated(result)
     }
 
+    #[inline]
+    #[must_use]
+    pub(in crate::map::brush) fn set_texture_opacity(&mut self, value: u8) -> Option<u8>
+    {
+        let result = self.texture_settings_mut().set_opacity(value);
+        self.set_texture_updated(result)
+    }
+
     #[inline]
     pub(in crate::map::brush) fn check_texture_sprite(
         &mut self,
@@ -1879,7 +1993,7 @@ impl ConvexPolygon
 
     #[inline]
     #[must_use]
-    pub(in crate::map::brush) fn atlas_animation_max_len(&self) -> usize
+    pub(in crate::map::brush) const fn atlas_animation_max_len(&self) -> usize
     {
         self.texture_settings().unwrap().atlas_animation_max_len()
     }
@@ -2585,6 +2699,24 @@ impl ConvexPolygon
         true
     }
 
+    /// Whether inserting a free draw vertex at `pos` would make the polygon lose convexity.
+    /// Returns false if `pos` is close to an already existing vertex, because no vertex would be
+    /// inserted.
+    #[inline]
+    #[must_use]
+    pub fn free_draw_vertex_breaks_convexity(&self, pos: Vec2, camera_scale: f32) -> bool
+    {
+        if self.nearby_vertex(pos, camera_scale).is_some()
+        {
+            return false;
+        }
+
+        let mut poly = self.clone();
+        poly.vertexes.push(SelectableVector::new(pos));
+        poly.sort_vertexes_ccw();
+        !poly.vxs_valid()
+    }
+
     #[inline]
     pub fn insert_free_draw_vertex(&mut self, p: Vec2)
     {
@@ -3469,6 +3601,38 @@ impl ConvexPolygon
         Some([left_polygon, right_polygon])
     }
 
+    /// Clips `self` against every side of `other`. Returns the piece inside `other` and the pieces
+    /// outside of it, or `None` if the polygons do not overlap.
+    #[inline]
+    pub(in crate::map::brush) fn clip_by(&self, other: &Self) -> Option<(Self, Vec<Self>)>
+    {
+        let mut inside = self.clone();
+        let mut outside = Vec::new();
+
+        for [vx_j, vx_i] in other.vertexes.pair_iter().unwrap()
+        {
+            let side = [vx_j.vec, vx_i.vec];
+
+            match inside.clip(&side)
+            {
+                Some([left, right]) =>
+                {
+                    inside = left;
+                    outside.push(right);
+                },
+                None =>
+                {
+                    if !is_point_inside_clip_edge(&side, inside.center)
+                    {
+                        return None;
+                    }
+                }
+            };
+        }
+
+        (inside, outside).into()
+    }
+
     #[inline]
     fn clip_self(&mut self, clip_segment: &[Vec2; 2]) -> Option<Self>
     {
@@ -3510,6 +3674,30 @@ impl ConvexPolygon
         Some(left_polygon)
     }
 
+    //==============================================================
+    // Slice
+
+    /// Slices `self` along `lines`. Returns the resulting pieces, if any line crosses the shape.
+    #[inline]
+    pub(in crate::map::brush) fn slice(&self, lines: &[[Vec2; 2]]) -> Option<Vec<Self>>
+    {
+        let mut pieces = vec![self.clone()];
+
+        for line in lines
+        {
+            for i in 0..pieces.len()
+            {
+                if let Some([left, right]) = pieces[i].clip(line)
+                {
+                    pieces[i] = left;
+                    pieces.push(right);
+                }
+            }
+        }
+
+        (pieces.len() > 1).then_some(pieces)
+    }
+
     //==============================================================
     // Hollow
 
@@ -3607,6 +3795,27 @@ impl ConvexPolygon
         Some(ShatterResult { main, shards })
     }
 
+    //==============================================================
+    // Round corners
+
+    /// Returns a copy of `self` with the corners replaced by arcs of radius `radius` made of
+    /// `resolution` segments, if the resulting polygon is valid.
+    #[inline]
+    pub(in crate::map::brush) fn rounded_corners(&self, radius: f32, resolution: u8)
+        -> Option<Self>
+    {
+        let vxs = round_corners(&self.vertexes().collect::<Vec<_>>(), radius, resolution);
+
+        if vxs.len() > usize::from(u8::MAX)
+        {
+            return None;
+        }
+
+        let mut poly = Self::new_cleaned_up(vxs)?;
+        poly.texture.clone_from(&self.texture);
+        poly.into()
+    }
+
     //==============================================================
     // Intersect
 
@@ -4358,15 +4567,20 @@ impl ConvexPolygon
     //==============================================================
     // Draw
 
+    /// Returns the vertexes, `animator`, and texture settings required to draw the polygon in
+    /// the map preview, if it has a texture.
     #[inline]
-    pub fn draw_map_preview(
-        &self,
-        camera: &Transform,
-        drawer: &mut MapPreviewDrawer,
-        animator: Option<&Animator>
-    )
+    pub fn map_preview_mesh_data<'a>(
+        &'a self,
+        animator: Option<&'a Animator>
+    ) -> Option<(
+        impl ExactSizeIterator<Item = Vec2> + Clone + Sync + 'a,
+        Option<&'a Animator>,
+        &'a TextureSettings
+    )>
     {
-        drawer.brush(camera, self.vertexes(), animator, return_if_none!(self.texture_settings()));
+        self.texture_settings()
+            .map(|settings| (self.vertexes(), animator, settings))
     }
 
     /// Draws the polygon.
@@ -4376,6 +4590,13 @@ impl ConvexPolygon
         drawer.brush(self.vertexes(), color, self.texture.as_ref(), collision);
     }
 
+    /// Draws the polygon selected, showing the texture previewed in the texture editor, if any.
+    #[inline]
+    pub fn draw_selected(&self, drawer: &mut EditDrawer, collision: bool, color: Color)
+    {
+        drawer.selected_brush(self.vertexes(), color, self.texture.as_ref(), collision);
+    }
+
     #[inline]
     pub fn draw_prop(&self, drawer: &mut EditDrawer, color: Color, delta: Vec2)
     {
@@ -4397,11 +4618,92 @@ impl ConvexPolygon
         }
     }
 
-    /// Draws the polygon.
+    /// Draws a grid aligned to the texture, if any, with cells as big as the scaled texture.
+    #[inline]
+    pub fn draw_texture_grid(&self, drawer: &mut EditDrawer)
+    {
+        /// The maximum amount of lines drawn along each axis.
+        const MAX_LINES: f32 = 128f32;
+
+        let settings = return_if_none!(self.texture_settings());
+
+        if settings.sprite()
+        {
+            return;
+        }
+
+        let cell = drawer.resources().texture_or_error(settings.name()).size().as_vec2() *
+            Vec2::new(settings.scale_x(), settings.scale_y()).abs();
+        let angle = settings.angle().to_radians();
+        let offset = settings.draw_offset();
+        // The vertexes in texture space.
+        let vxs = self
+            .vertexes()
+            .map(|vx| rotate_point_around_origin(vx, angle) + offset)
+            .collect::<Vec<_>>();
+        let hull = Hull::from_points(vxs.iter().copied());
+
+        for (axis, cell, min, max) in [
+            (0, cell.x, hull.left(), hull.right()),
+            (1, cell.y, hull.bottom(), hull.top())
+        ]
+        {
+            if cell <= 0f32 || (max - min) / cell > MAX_LINES
+            {
+                continue;
+            }
+
+            let mut coordinate = (min / cell).ceil() * cell;
+
+            while coordinate <= max
+            {
+                // The extremes of the portion of the line inside the polygon.
+                let mut extremes: Option<(f32, f32)> = None;
+
+                for [a, b] in vxs.pair_iter().unwrap()
+                {
+                    if a[axis] == b[axis] || (a[axis] - coordinate) * (b[axis] - coordinate) > 0f32
+                    {
+                        continue;
+                    }
+
+                    let other = a[1 - axis] +
+                        (b[1 - axis] - a[1 - axis]) * (coordinate - a[axis]) /
+                            (b[axis] - a[axis]);
+                    extremes = extremes
+                        .map_or((other, other), |(lo, hi)| (lo.min(other), hi.max(other)))
+                        .into();
+                }
+
+                if let Some((lo, hi)) = extremes
+                {
+                    let [mut start, mut end] = [Vec2::splat(coordinate); 2];
+                    start[1 - axis] = lo;
+                    end[1 - axis] = hi;
+
+                    drawer.semitransparent_line(
+                        rotate_point_around_origin(start - offset, -angle),
+                        rotate_point_around_origin(end - offset, -angle),
+                        Color::Hull
+                    );
+                }
+
+                coordinate += cell;
+            }
+        }
+    }
+
+    /// Draws the polygon with sides of color `color`.
     #[inline]
-    pub fn draw_free_draw(&self, window: &Window, camera: &Transform, drawer: &mut EditDrawer)
+    pub fn draw_free_draw(
+        &self,
+        window: &Window,
+        camera: &Transform,
+        drawer: &mut EditDrawer,
+        color: Color
+    )
     {
-        drawer.sides(self.vertexes(), Color::CursorPolygon);
+        drawer.sides(self.vertexes(), color);
 
         for vx in self.vertexes()
         {
@@ -4789,7 +5091,7 @@ impl Iterator for NewVertexIterator<'_>
 impl<'a> NewVertexIterator<'a>
 {
     #[inline]
-    fn new(vertexes: &'a [SelectableVector], pos: Vec2, index: usize) -> Self
+    const fn new(vertexes: &'a [SelectableVector], pos: Vec2, index: usize) -> Self
     {
         Self {
             vertexes,
@@ -37,6 +37,38 @@ pub enum GroupViewer
     Attached(Id)
 }
 
+impl GroupViewer
+{
+    /// Removes the references to the [`Brush`]es with [`Id`]s contained in `identifiers`.
+    #[inline]
+    pub(in crate::map) fn remove_brushes(&mut self, identifiers: &Ids)
+    {
+        match self
+        {
+            Self::None => (),
+            Self::Attachments(ids) =>
+            {
+                ids.retain(|id| !identifiers.contains(id));
+
+                if ids.is_empty()
+                {
+                    *self = Self::None;
+                }
+            },
+            Self::Path {
+                attached_brushes, ..
+            } => attached_brushes.retain(|id| !identifiers.contains(id)),
+            Self::Attached(owner) =>
+            {
+                if identifiers.contains(owner)
+                {
+                    *self = Self::None;
+                }
+            }
+        };
+    }
+}
+
 //=======================================================================//
 // UI
 //
@@ -24,15 +24,30 @@ use crate::{utils::collections::HashMap, Group, Id, TextureSettings, Value};
 pub struct BrushViewer
 {
     /// The [`Id`].
-    pub id:         Id,
+    pub id:                Id,
     /// The vertexes.
-    pub vertexes:   Vec<Vec2>,
+    pub vertexes:          Vec<Vec2>,
     /// The texture.
-    pub texture:    Option<TextureSettings>,
+    pub texture:           Option<TextureSettings>,
     /// The group of brushes this brush belong to.
-    pub group:      Group,
+    pub group:             Group,
     /// The associated properties.
-    pub properties: HashMap<String, Value>
+    pub properties:        HashMap<String, Value>,
+    /// Whether the brush is only meant to be seen in the editor. Such brushes are skipped when
+    /// reading a map through the `Exporter`.
+    #[serde(default)]
+    pub editor_only:       bool,
+    /// The RGB color of the outline drawn in the editor instead of the standard one, if any.
+    /// Ignored by the `Exporter` unless requested.
+    #[serde(default)]
+    pub color:             Option<[u8; 3]>,
+    /// The identifiers of the groups the brush belongs to, from the innermost to the outermost.
+    #[serde(default)]
+    pub groups:            Vec<u32>,
+    /// The properties specific to the brush, not associated with any default one, in insertion
+    /// order.
+    #[serde(default)]
+    pub custom_properties: Vec<(String, Value)>
 }
 
 //=======================================================================//
@@ -112,7 +127,7 @@ pub(in crate::map) mod ui_mod
             identifiers::{EntityCenter, EntityId},
             iterators::SlicePairIter,
             math::lines_and_segments::{line_equation, LineEquation},
-            misc::TakeValue
+            misc::{ReplaceValue, TakeValue, Toggle}
         },
         Animation,
         Id,
@@ -501,6 +516,16 @@ pub(in crate::map) mod ui_mod
 
     //=======================================================================//
 
+    #[must_use]
+    pub(in crate::map) struct BrushClipResult
+    {
+        pub id:      Id,
+        pub inside:  ConvexPolygon,
+        pub outside: Vec<ConvexPolygon>
+    }
+
+    //=======================================================================//
+
     #[must_use]
     pub(in crate::map) struct HollowResult
     {
@@ -511,6 +536,16 @@ pub(in crate::map) mod ui_mod
 
     //=======================================================================//
 
+    #[must_use]
+    pub(in crate::map) struct SliceResult
+    {
+        pub id:     Id,
+        pub main:   ConvexPolygon,
+        pub pieces: Vec<ConvexPolygon>
+    }
+
+    //=======================================================================//
+
     #[must_use]
     pub(in crate::map) struct ShatterResult
     {
@@ -528,10 +563,18 @@ pub(in crate::map) mod ui_mod
     #[derive(Serialize, Deserialize)]
     pub(in crate::map) struct BrushDataViewer
     {
-        vertexes:   Vec<Vec2>,
-        texture:    Option<TextureSettings>,
-        group:      GroupViewer,
-        properties: HashMap<String, Value>
+        vertexes:          Vec<Vec2>,
+        texture:           Option<TextureSettings>,
+        group:             GroupViewer,
+        properties:        HashMap<String, Value>,
+        #[serde(default)]
+        editor_only:       bool,
+        #[serde(default)]
+        color:             Option<[u8; 3]>,
+        #[serde(default)]
+        groups:            Vec<u32>,
+        #[serde(default)]
+        custom_properties: Vec<(String, Value)>
     }
 
     //=======================================================================//
@@ -540,11 +583,17 @@ pub(in crate::map) mod ui_mod
     pub(in crate::map) struct BrushData
     {
         /// The polygon of the brush.
-        polygon:    ConvexPolygon,
+        polygon:     ConvexPolygon,
         /// Platform path and attached brushes.
-        group:      Group,
+        group:       Group,
         /// The properties of the brush.
-        properties: BrushProperties
+        properties:  BrushProperties,
+        /// Whether the brush is only shown in the editor and not exported.
+        editor_only: bool,
+        /// The color of the outline drawn instead of the standard non selected one, if any.
+        color:       Option<[u8; 3]>,
+        /// The groups the brush belongs to, from the innermost to the outermost.
+        groups:      Vec<u32>
     }
 
     impl Viewer for BrushData
@@ -558,7 +607,11 @@ pub(in crate::map) mod ui_mod
                 vertexes,
                 texture,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups,
+                custom_properties
             } = value;
 
             let mut polygon = ConvexPolygon::from(vertexes);
@@ -571,7 +624,10 @@ pub(in crate::map) mod ui_mod
             Self {
                 polygon,
                 group: Group::from_viewer(group),
-                properties: BrushProperties::from_parts(properties)
+                properties: BrushProperties::from_parts(properties).with_custom(custom_properties),
+                editor_only,
+                color,
+                groups
             }
         }
 
@@ -581,14 +637,23 @@ pub(in crate::map) mod ui_mod
             let Self {
                 polygon,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups
             } = self;
 
+            let (properties, custom_properties) = properties.take();
+
             Self::Item {
-                vertexes:   polygon.vertexes().collect(),
-                texture:    polygon.take_texture_settings(),
-                group:      group.to_viewer(),
-                properties: properties.take()
+                vertexes: polygon.vertexes().collect(),
+                texture: polygon.take_texture_settings(),
+                group: group.to_viewer(),
+                properties,
+                editor_only,
+                color,
+                groups,
+                custom_properties
             }
         }
     }
@@ -598,6 +663,13 @@ pub(in crate::map) mod ui_mod
         #[inline]
         pub const fn polygon_hull(&self) -> Hull { self.polygon.hull() }
 
+        /// Returns an iterator to the vertexes of the polygon.
+        #[inline]
+        pub fn vertexes(&self) -> impl ExactSizeIterator<Item = Vec2> + Clone + '_
+        {
+            self.polygon.vertexes()
+        }
+
         #[inline]
         #[must_use]
         pub fn sprite_hull<T: TextureSize>(&self, resources: &T, grid: &Grid) -> Option<Hull>
@@ -609,6 +681,25 @@ pub(in crate::map) mod ui_mod
         #[must_use]
         pub fn sprite_pivot(&self) -> Option<Vec2> { self.polygon.sprite_pivot() }
 
+        /// Replaces the identifiers of the groups the brush belongs to with the values returned
+        /// by `f`.
+        #[inline]
+        pub fn remap_groups<F: FnMut(u32) -> u32>(&mut self, mut f: F)
+        {
+            for group in &mut self.groups
+            {
+                *group = f(*group);
+            }
+        }
+
+        /// Replaces the texture `prev` with `new`. Returns whether anything was changed.
+        #[inline]
+        #[must_use]
+        pub fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+        {
+            self.polygon.rename_texture(prev, new)
+        }
+
         #[inline]
         #[must_use]
         pub fn path_hull(&self) -> Option<Hull>
@@ -734,7 +825,11 @@ pub(in crate::map) mod ui_mod
                 vertexes,
                 texture,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups,
+                custom_properties
             } = value;
 
             Self {
@@ -743,7 +838,11 @@ pub(in crate::map) mod ui_mod
                     vertexes,
                     texture,
                     group,
-                    properties
+                    properties,
+                    editor_only,
+                    color,
+                    groups,
+                    custom_properties
                 })
             }
         }
@@ -756,7 +855,11 @@ pub(in crate::map) mod ui_mod
                 vertexes,
                 texture,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups,
+                custom_properties
             } = data.to_viewer();
 
             Self::Item {
@@ -764,7 +867,11 @@ pub(in crate::map) mod ui_mod
                 vertexes,
                 texture,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups,
+                custom_properties
             }
         }
     }
@@ -985,7 +1092,10 @@ pub(in crate::map) mod ui_mod
                         data: BrushData {
                             polygon: polygon.clone(),
                             group: Group::None,
-                            properties
+                            properties,
+                            editor_only: false,
+                            color: None,
+                            groups: Vec::new()
                         },
                         id:   identifier
                     }
@@ -996,7 +1106,10 @@ pub(in crate::map) mod ui_mod
                         data: BrushData {
                             polygon,
                             group: Group::None,
-                            properties
+                            properties,
+                            editor_only: false,
+                            color: None,
+                            groups: Vec::new()
                         },
                         id:   identifier
                     }
@@ -1010,7 +1123,10 @@ pub(in crate::map) mod ui_mod
             let BrushData {
                 polygon,
                 group,
-                properties
+                properties,
+                editor_only,
+                color,
+                groups
             } = data;
             let mut brush = Self::from_polygon(polygon, identifier, properties);
 
@@ -1020,6 +1136,9 @@ pub(in crate::map) mod ui_mod
             }
 
             brush.data.group = group;
+            brush.data.editor_only = editor_only;
+            brush.data.color = color;
+            brush.data.groups = groups;
             brush
         }
 
@@ -1036,6 +1155,14 @@ pub(in crate::map) mod ui_mod
             self.data.polygon.vertexes()
         }
 
+        /// Returns an iterator to the vertexes of the underlying `ConvexPolygon` paired with their
+        /// selection status.
+        #[inline]
+        pub fn vertexes_selection(&self) -> impl ExactSizeIterator<Item = (Vec2, bool)> + '_
+        {
+            self.data.polygon.vertexes_selection()
+        }
+
         /// Returns an iterator to the vertexes of the underlying `ConvexPolygon`.
         #[inline]
         pub fn selected_vertexes(&self) -> Option<impl Iterator<Item = Vec2>>
@@ -1056,6 +1183,25 @@ pub(in crate::map) mod ui_mod
         #[must_use]
         pub fn center(&self) -> Vec2 { self.data.polygon.center() }
 
+        /// Returns the area of the underlying `ConvexPolygon`.
+        #[inline]
+        #[must_use]
+        pub fn area(&self) -> f32 { self.data.polygon.area() }
+
+        /// Returns the perimeter of the underlying `ConvexPolygon`.
+        #[inline]
+        #[must_use]
+        pub fn perimeter(&self) -> f32 { self.data.polygon.perimeter() }
+
+        /// Whether the underlying `ConvexPolygon` shares a stretch of one of its sides with the
+        /// one of `other`.
+        #[inline]
+        #[must_use]
+        pub fn shares_side(&self, other: &Self) -> bool
+        {
+            self.data.polygon.shares_side(&other.data.polygon)
+        }
+
         /// Returns true if 'p' is in the area delimited by the underlying
         /// `ConvexPolygon`.
         #[inline]
@@ -1240,6 +1386,14 @@ pub(in crate::map) mod ui_mod
             self.data.group.attachments_iter()
         }
 
+        /// Removes the reference to the attachment with [`Id`] `identifier`, which does not
+        /// exist.
+        #[inline]
+        pub fn remove_dangling_attachment(&mut self, identifier: Id)
+        {
+            self.data.group.remove_attachment(identifier);
+        }
+
         #[inline]
         #[must_use]
         pub const fn attached(&self) -> Option<Id> { self.data.group.is_attached() }
@@ -1379,6 +1533,14 @@ pub(in crate::map) mod ui_mod
         #[inline]
         pub fn remove_texture(&mut self) -> TextureSettings { self.data.polygon.remove_texture() }
 
+        /// Replaces the texture `prev` with `new`. Returns whether anything was changed.
+        #[inline]
+        #[must_use]
+        pub fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+        {
+            self.data.polygon.rename_texture(prev, new)
+        }
+
         #[inline]
         #[must_use]
         pub fn check_texture_offset_x(
@@ -1523,6 +1685,13 @@ pub(in crate::map) mod ui_mod
             self.data.polygon.set_texture_height(value)
         }
 
+        #[inline]
+        #[must_use]
+        pub fn set_texture_opacity(&mut self, value: u8) -> Option<u8>
+        {
+            self.data.polygon.set_texture_opacity(value)
+        }
+
         #[inline]
         pub fn check_texture_sprite(
             &mut self,
@@ -1634,7 +1803,7 @@ pub(in crate::map) mod ui_mod
 
         #[inline]
         #[must_use]
-        pub fn texture_atlas_animation_max_len(&self) -> usize
+        pub const fn texture_atlas_animation_max_len(&self) -> usize
         {
             self.data.polygon.atlas_animation_max_len()
         }
@@ -1784,6 +1953,19 @@ pub(in crate::map) mod ui_mod
             self.data.properties.set(key, value)
         }
 
+        /// Sets the custom property `key` to `value`, or removes it if `value` is `None`.
+        /// Returns the previous value, if different.
+        #[inline]
+        #[must_use]
+        pub fn set_custom_property(
+            &mut self,
+            key: &str,
+            value: Option<Value>
+        ) -> Option<Option<Value>>
+        {
+            self.data.properties.set_custom(key, value)
+        }
+
         #[inline]
         pub fn refactor_properties(
             &mut self,
@@ -1793,6 +1975,49 @@ pub(in crate::map) mod ui_mod
             self.data.properties.refactor(refactor);
         }
 
+        /// Whether the brush is only shown in the editor and not exported.
+        #[inline]
+        #[must_use]
+        pub const fn editor_only(&self) -> bool { self.data.editor_only }
+
+        /// Toggles whether the brush is only shown in the editor and not exported.
+        #[inline]
+        pub fn toggle_editor_only(&mut self) { self.data.editor_only.toggle(); }
+
+        /// Returns the color of the outline drawn instead of the standard non selected one, if
+        /// any.
+        #[inline]
+        #[must_use]
+        pub const fn color(&self) -> Option<[u8; 3]> { self.data.color }
+
+        /// Sets the color of the outline drawn instead of the standard non selected one to
+        /// `color`. Returns the previous value, if different.
+        #[inline]
+        #[must_use]
+        pub fn set_color(&mut self, color: Option<[u8; 3]>) -> Option<Option<[u8; 3]>>
+        {
+            if color == self.data.color
+            {
+                return None;
+            }
+
+            self.data.color.replace_value(color).into()
+        }
+
+        /// Returns the identifiers of the groups the brush belongs to, from the innermost to the
+        /// outermost.
+        #[inline]
+        #[must_use]
+        pub fn groups(&self) -> &[u32] { &self.data.groups }
+
+        /// Sets the groups the brush belongs to to `groups`, returning the previous ones.
+        #[inline]
+        #[must_use]
+        pub fn set_groups(&mut self, groups: Vec<u32>) -> Vec<u32>
+        {
+            std::mem::replace(&mut self.data.groups, groups)
+        }
+
         //==============================================================
         // Vertex Editing
 
@@ -2069,6 +2294,15 @@ pub(in crate::map) mod ui_mod
             self.data.polygon.move_vertexes_at_indexes(idxs);
         }
 
+        /// Whether moving the vertexes at the indexes and by the deltas specified in `idxs`
+        /// generates a valid polygon.
+        #[inline]
+        #[must_use]
+        pub fn check_vertexes_move_at_indexes(&mut self, idxs: &[(Vec<u8>, Vec2)]) -> bool
+        {
+            self.data.polygon.check_vertexes_move_at_indexes(idxs)
+        }
+
         //==============================================================
         // Side editing
 
@@ -2273,6 +2507,23 @@ pub(in crate::map) mod ui_mod
             })
         }
 
+        /// Clips `self` against every side of `other`, returning the pieces inside and outside of
+        /// it. Returns `None` if the brushes do not overlap.
+        #[inline]
+        pub fn clip_by_brush(&self, other: &Self) -> Option<BrushClipResult>
+        {
+            self.data
+                .polygon
+                .clip_by(&other.data.polygon)
+                .map(|(inside, outside)| {
+                    BrushClipResult {
+                        id: self.id,
+                        inside,
+                        outside
+                    }
+                })
+        }
+
         //==============================================================
         // Shatter
 
@@ -2284,6 +2535,34 @@ pub(in crate::map) mod ui_mod
             self.data.polygon.shatter(cursor_pos, camera_scale)
         }
 
+        //==============================================================
+        // Round corners
+
+        /// Returns the polygon of `self` with the corners replaced by arcs of radius `radius` made
+        /// of `resolution` segments, if valid.
+        #[inline]
+        pub fn rounded_corners(&self, radius: f32, resolution: u8) -> Option<ConvexPolygon>
+        {
+            self.data.polygon.rounded_corners(radius, resolution)
+        }
+
+        //==============================================================
+        // Slice
+
+        /// Slices `self` along `lines`. Returns the resulting pieces, if any line crosses its
+        /// shape.
+        #[inline]
+        pub fn slice(&self, lines: &[[Vec2; 2]]) -> Option<SliceResult>
+        {
+            self.data.polygon.slice(lines).map(|mut pieces| {
+                SliceResult {
+                    id: self.id,
+                    main: pieces.swap_remove(0),
+                    pieces
+                }
+            })
+        }
+
         //==============================================================
         // Hollow
 
@@ -2526,16 +2805,31 @@ pub(in crate::map) mod ui_mod
         //==============================================================
         // Draw
 
-        /// Draws the polygon for the map preview.
+        /// Returns the vertexes, `animator`, and texture settings required to draw the polygon
+        /// in the map preview, if it has a texture.
         #[inline]
-        pub fn draw_map_preview(
-            &self,
-            camera: &Transform,
-            drawer: &mut MapPreviewDrawer,
-            animator: Option<&Animator>
-        )
+        pub fn map_preview_mesh_data<'a>(
+            &'a self,
+            animator: Option<&'a Animator>
+        ) -> Option<(
+            impl ExactSizeIterator<Item = Vec2> + Clone + Sync + 'a,
+            Option<&'a Animator>,
+            &'a TextureSettings
+        )>
+        {
+            self.data.polygon.map_preview_mesh_data(animator)
+        }
+
+        /// Returns the vertexes and the texture settings of the brush, if it has a texture.
+        #[inline]
+        pub fn texture_mesh_data(
+            &self
+        ) -> Option<(impl ExactSizeIterator<Item = Vec2> + Clone + Sync + '_, &TextureSettings)>
         {
-            self.data.polygon.draw_map_preview(camera, drawer, animator);
+            self.data
+                .polygon
+                .texture_settings()
+                .map(|settings| (self.data.polygon.vertexes(), settings))
         }
 
         /// Draws the polygon with the desired `color`.
@@ -2549,21 +2843,46 @@ pub(in crate::map) mod ui_mod
         #[inline]
         pub fn draw_non_selected(&self, drawer: &mut EditDrawer)
         {
-            self.draw_with_color(drawer, Color::NonSelectedEntity);
+            match self.data.color
+            {
+                Some(rgb) =>
+                {
+                    drawer.colored_brush(
+                        self.data.polygon.vertexes(),
+                        rgb,
+                        self.data.polygon.texture_settings(),
+                        self.collision()
+                    );
+                },
+                None => self.draw_with_color(drawer, Color::NonSelectedEntity)
+            };
         }
 
         /// Draws the polygon selected.
         #[inline]
         pub fn draw_selected(&self, drawer: &mut EditDrawer)
         {
-            self.draw_with_color(drawer, Color::SelectedEntity);
+            self.data
+                .polygon
+                .draw_selected(drawer, self.collision(), Color::SelectedEntity);
         }
 
         /// Draws the polygon highlighted selected.
         #[inline]
         pub fn draw_highlighted_selected(&self, drawer: &mut EditDrawer)
         {
-            self.draw_with_color(drawer, Color::HighlightedSelectedEntity);
+            self.data.polygon.draw_selected(
+                drawer,
+                self.collision(),
+                Color::HighlightedSelectedEntity
+            );
+        }
+
+        /// Draws a grid aligned to the texture, if any.
+        #[inline]
+        pub fn draw_texture_grid(&self, drawer: &mut EditDrawer)
+        {
+            self.data.polygon.draw_texture_grid(drawer);
         }
 
         /// Draws the polygon highlighted non selected.
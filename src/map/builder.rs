@@ -0,0 +1,645 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path
+};
+
+use glam::Vec2;
+
+use super::{
+    properties::{
+        DefaultPropertiesViewer,
+        ANGLE_DEFAULT,
+        ANGLE_LABEL,
+        COLLISION_DEFAULT,
+        COLLISION_LABEL,
+        HEIGHT_DEFAULT,
+        HEIGHT_LABEL
+    },
+    FileStructure,
+    GridSettings,
+    MapHeader,
+    MapMetadata,
+    FILE_VERSION,
+    MAP_HALF_SIZE
+};
+use crate::{
+    utils::collections::{hash_map, HashMap, Ids},
+    Brush,
+    Group,
+    Id,
+    Node,
+    TextureSettings,
+    ThingId,
+    ThingInstance,
+    Value
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A builder to create map files without the editor, for example from procedural generation code
+/// or tests. The created maps can be opened in the editor and read through the
+/// [`Exporter`](crate::Exporter).
+/// ```
+/// use glam::Vec2;
+/// use hill_vacuum::{MapBuilder, TextureSettings};
+///
+/// let mut builder = MapBuilder::new();
+/// let texture = TextureSettings::try_new("brick", Vec2::ZERO, Vec2::ONE, 0f32, 0).unwrap();
+/// let square = builder
+///     .add_brush(
+///         [
+///             Vec2::new(64f32, 64f32),
+///             Vec2::new(-64f32, 64f32),
+///             Vec2::new(-64f32, -64f32),
+///             Vec2::new(64f32, -64f32)
+///         ],
+///         texture.into()
+///     )
+///     .unwrap();
+/// # _ = square;
+/// // builder.save("map.hv").unwrap();
+/// ```
+#[must_use]
+pub struct MapBuilder
+{
+    /// The settings of the grid.
+    grid:             GridSettings,
+    /// The title, author, description, and user defined key/value pairs of the map.
+    metadata:         MapMetadata,
+    /// The user defined default properties of the brushes.
+    brush_properties: Vec<(String, Value)>,
+    /// The user defined default properties of the things.
+    thing_properties: Vec<(String, Value)>,
+    /// The brushes.
+    brushes:          HashMap<Id, Brush>,
+    /// The things.
+    things:           HashMap<Id, ThingInstance>,
+    /// The value of the next [`Id`] to assign.
+    next_id:          usize
+}
+
+impl Default for MapBuilder
+{
+    #[inline]
+    fn default() -> Self { Self::with_properties(&[], &[]) }
+}
+
+impl MapBuilder
+{
+    /// Returns a new empty [`MapBuilder`] with no user defined properties.
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns a new empty [`MapBuilder`] whose brushes and things are assigned the default
+    /// properties `brush_properties` and `thing_properties`. They should be the same as the ones
+    /// of the `HillVacuumPlugin` the map is going to be edited with, otherwise the editor asks
+    /// which ones should be used when the map is opened.
+    #[inline]
+    pub fn with_properties(
+        brush_properties: &[(&str, Value)],
+        thing_properties: &[(&str, Value)]
+    ) -> Self
+    {
+        /// Returns the sorted user defined properties, excluding the built-in ones.
+        #[inline]
+        fn user_properties(properties: &[(&str, Value)], builtin: &[&str]) -> Vec<(String, Value)>
+        {
+            let mut properties = properties
+                .iter()
+                .filter(|(k, _)| !builtin.contains(k))
+                .map(|(k, v)| ((*k).to_string(), v.clone()))
+                .collect::<Vec<_>>();
+            properties.sort_by(|a, b| a.0.cmp(&b.0));
+            properties.dedup_by(|a, b| a.0 == b.0);
+            properties
+        }
+
+        Self {
+            grid:             GridSettings::default(),
+            metadata:         MapMetadata::default(),
+            brush_properties: user_properties(brush_properties, &[COLLISION_LABEL]),
+            thing_properties: user_properties(thing_properties, &[ANGLE_LABEL, HEIGHT_LABEL]),
+            brushes:          hash_map![],
+            things:           hash_map![],
+            next_id:          0
+        }
+    }
+
+    //==============================================================
+    // Map
+
+    /// Sets the skew and rotation angle of the grid.
+    /// # Errors
+    /// Returns an error if `skew` is not within -45 and 45 degrees or `angle` is not within -180
+    /// and 180 degrees.
+    #[inline]
+    pub fn set_grid(&mut self, skew: i8, angle: i16) -> Result<(), &'static str>
+    {
+        if !(-45..=45).contains(&skew) || !(-180..=180).contains(&angle)
+        {
+            return Err("Grid skew or angle out of range.");
+        }
+
+        self.grid = match (skew, angle)
+        {
+            (0, 0) => GridSettings::None,
+            (skew, 0) => GridSettings::Skew(skew),
+            (0, angle) => GridSettings::Rotate(angle),
+            (skew, angle) => GridSettings::Isometric { skew, angle }
+        };
+
+        Ok(())
+    }
+
+    /// Sets the title, author, description, and user defined key/value pairs of the map.
+    #[inline]
+    pub fn set_metadata(&mut self, metadata: MapMetadata) { self.metadata = metadata; }
+
+    //==============================================================
+    // Entities
+
+    /// Returns a new unique [`Id`].
+    #[inline]
+    fn new_id(&mut self) -> Id
+    {
+        let id = Id::new(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Adds a brush with vertexes `vertexes` and texture `texture` to the map and returns its
+    /// [`Id`]. The brush is assigned the default brush properties.
+    /// # Errors
+    /// Returns an error if the vertexes are less than three, do not describe a convex polygon in
+    /// counterclockwise order, or are out of the map bounds.
+    #[inline]
+    pub fn add_brush(
+        &mut self,
+        vertexes: impl Into<Vec<Vec2>>,
+        texture: Option<TextureSettings>
+    ) -> Result<Id, &'static str>
+    {
+        let vertexes = Into::<Vec<Vec2>>::into(vertexes);
+        let len = vertexes.len();
+
+        if len < 3
+        {
+            return Err("Brushes must have at least three vertexes.");
+        }
+
+        if vertexes.iter().any(|vx| out_of_bounds(*vx))
+        {
+            return Err("Brush out of the map bounds.");
+        }
+
+        // Every vertex must be strictly on the left of all the sides it does not belong to.
+        for i in 0..len
+        {
+            let (a, b) = (vertexes[i], vertexes[(i + 1) % len]);
+
+            if (0..len)
+                .filter(|j| *j != i && *j != (i + 1) % len)
+                .any(|j| (b - a).perp_dot(vertexes[j] - a) <= 0f32)
+            {
+                return Err("Brush vertexes are not convex and in counterclockwise order.");
+            }
+        }
+
+        let id = self.new_id();
+        let mut properties = hash_map![(COLLISION_LABEL.to_string(), COLLISION_DEFAULT)];
+        properties.extend(self.brush_properties.iter().cloned());
+
+        self.brushes.insert(id, Brush {
+            id,
+            vertexes,
+            texture,
+            group: Group::None,
+            properties,
+            editor_only: false,
+            color: None,
+            groups: Vec::new(),
+            custom_properties: Vec::new()
+        });
+
+        Ok(id)
+    }
+
+    /// Adds a thing with [`ThingId`] `thing` centered at `pos` to the map and returns its [`Id`].
+    /// The thing is assigned the default thing properties.
+    /// # Errors
+    /// Returns an error if `pos` is out of the map bounds.
+    #[inline]
+    pub fn add_thing(&mut self, thing: ThingId, pos: Vec2) -> Result<Id, &'static str>
+    {
+        if out_of_bounds(pos)
+        {
+            return Err("Thing out of the map bounds.");
+        }
+
+        let id = self.new_id();
+        let mut properties = hash_map![
+            (ANGLE_LABEL.to_string(), ANGLE_DEFAULT),
+            (HEIGHT_LABEL.to_string(), HEIGHT_DEFAULT)
+        ];
+        properties.extend(self.thing_properties.iter().cloned());
+
+        self.things.insert(id, ThingInstance {
+            id,
+            thing_id: thing,
+            pos,
+            path: None,
+            properties,
+            flags: 0,
+            color: None,
+            groups: Vec::new(),
+            custom_properties: Vec::new()
+        });
+
+        Ok(id)
+    }
+
+    /// Sets the spawn flags of the thing with [`Id`] `identifier`.
+    /// # Errors
+    /// Returns an error if there is no thing with [`Id`] `identifier`.
+    #[inline]
+    pub fn set_thing_flags(&mut self, identifier: Id, flags: u32) -> Result<(), &'static str>
+    {
+        self.things
+            .get_mut(&identifier)
+            .ok_or("No thing with such Id.")?
+            .flags = flags;
+        Ok(())
+    }
+
+    /// Sets the color of the outline drawn in the editor instead of the standard one for the
+    /// brush or thing with [`Id`] `identifier`.
+    /// # Errors
+    /// Returns an error if there is no entity with [`Id`] `identifier`.
+    #[inline]
+    pub fn set_color(&mut self, identifier: Id, color: Option<[u8; 3]>)
+        -> Result<(), &'static str>
+    {
+        match self.brushes.get_mut(&identifier)
+        {
+            Some(brush) => brush.color = color,
+            None =>
+            {
+                self.things
+                    .get_mut(&identifier)
+                    .ok_or("No entity with such Id.")?
+                    .color = color
+            },
+        };
+
+        Ok(())
+    }
+
+    /// Sets the property `key` of the brush or thing with [`Id`] `identifier` to `value`.
+    /// # Errors
+    /// Returns an error if there is no entity with [`Id`] `identifier`, it has no property `key`,
+    /// or `value` has a different type than the one of the property.
+    #[inline]
+    pub fn set_property(
+        &mut self,
+        identifier: Id,
+        key: &str,
+        value: Value
+    ) -> Result<(), &'static str>
+    {
+        let properties = match self.brushes.get_mut(&identifier)
+        {
+            Some(brush) => &mut brush.properties,
+            None =>
+            {
+                &mut self
+                    .things
+                    .get_mut(&identifier)
+                    .ok_or("No entity with such Id.")?
+                    .properties
+            },
+        };
+
+        let property = properties.get_mut(key).ok_or("No property with such key.")?;
+
+        if std::mem::discriminant(property) != std::mem::discriminant(&value)
+        {
+            return Err("Mismatching property type.");
+        }
+
+        *property = value;
+        Ok(())
+    }
+
+    /// Sets the custom property `key` of the brush or thing with [`Id`] `identifier` to `value`,
+    /// adding it if it is not already present.
+    /// # Errors
+    /// Returns an error if there is no entity with [`Id`] `identifier`, or it has a default
+    /// property `key`.
+    #[inline]
+    pub fn set_custom_property(
+        &mut self,
+        identifier: Id,
+        key: &str,
+        value: Value
+    ) -> Result<(), &'static str>
+    {
+        let (properties, custom) = match self.brushes.get_mut(&identifier)
+        {
+            Some(brush) => (&brush.properties, &mut brush.custom_properties),
+            None =>
+            {
+                let thing = self.things.get_mut(&identifier).ok_or("No entity with such Id.")?;
+                (&thing.properties, &mut thing.custom_properties)
+            }
+        };
+
+        if properties.contains_key(key)
+        {
+            return Err("Default property with such key.");
+        }
+
+        match custom.iter_mut().find(|(k, _)| k == key)
+        {
+            Some((_, v)) => *v = value,
+            None => custom.push((key.to_string(), value))
+        };
+
+        Ok(())
+    }
+
+    /// Sets the path of the brush or thing with [`Id`] `identifier`. The positions of the
+    /// [`Node`]s are relative to the center of the entity, which for the brushes is the average of
+    /// the vertexes.
+    /// # Errors
+    /// Returns an error if there is no entity with [`Id`] `identifier`, the entity is a brush
+    /// attached to another one, there are less than two nodes, two consecutive nodes overlap, or
+    /// the nodes are out of the map bounds.
+    #[inline]
+    pub fn set_path(&mut self, identifier: Id, path: Vec<Node>) -> Result<(), &'static str>
+    {
+        /// Checks whether `path` is valid for an entity centered in `center`.
+        #[inline]
+        fn check_path(path: &[Node], center: Vec2) -> Result<(), &'static str>
+        {
+            if path.len() < 2
+            {
+                return Err("Paths must have at least two nodes.");
+            }
+
+            if path.windows(2).any(|pair| pair[0].pos == pair[1].pos)
+            {
+                return Err("Path has overlapping consecutive nodes.");
+            }
+
+            if path.iter().any(|node| out_of_bounds(center + node.pos)) ||
+                path.iter()
+                    .zip(path.iter().cycle().skip(1))
+                    .flat_map(|(a, b)| a.curve.control_points(a.pos, b.pos))
+                    .any(|point| out_of_bounds(center + point))
+            {
+                return Err("Path out of the map bounds.");
+            }
+
+            Ok(())
+        }
+
+        if let Some(thing) = self.things.get_mut(&identifier)
+        {
+            check_path(&path, thing.pos)?;
+            thing.path = path.into();
+            return Ok(());
+        }
+
+        let brush = self.brushes.get_mut(&identifier).ok_or("No entity with such Id.")?;
+        #[allow(clippy::cast_precision_loss)]
+        let center = brush.vertexes.iter().sum::<Vec2>() / brush.vertexes.len() as f32;
+        check_path(&path, center)?;
+
+        brush.group = match std::mem::replace(&mut brush.group, Group::None)
+        {
+            Group::None =>
+            {
+                Group::Path {
+                    path,
+                    attached_brushes: Ids::default()
+                }
+            },
+            Group::Attachments(attached_brushes) |
+            Group::Path {
+                attached_brushes, ..
+            } =>
+            {
+                Group::Path {
+                    path,
+                    attached_brushes
+                }
+            },
+            group @ Group::Attached(_) =>
+            {
+                brush.group = group;
+                return Err("Attached brushes cannot have a path.");
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Attaches the brush with [`Id`] `attached` to the brush with [`Id`] `owner`, so that it
+    /// moves along with it.
+    /// # Errors
+    /// Returns an error if the [`Id`]s are the same or do not belong to brushes, `owner` is
+    /// attached to another brush, or `attached` is already attached or has attachments or a
+    /// path.
+    #[inline]
+    pub fn attach(&mut self, owner: Id, attached: Id) -> Result<(), &'static str>
+    {
+        if owner == attached
+        {
+            return Err("A brush cannot be attached to itself.");
+        }
+
+        let owner_group = &self.brushes.get(&owner).ok_or("No brush with such owner Id.")?.group;
+
+        if matches!(owner_group, Group::Attached(_))
+        {
+            return Err("Attached brushes cannot have attachments.");
+        }
+
+        let attached_brush = self
+            .brushes
+            .get_mut(&attached)
+            .ok_or("No brush with such attached Id.")?;
+
+        if !matches!(attached_brush.group, Group::None)
+        {
+            return Err("The brush to attach already has a group.");
+        }
+
+        attached_brush.group = Group::Attached(owner);
+
+        let owner_group = match self.brushes.get_mut(&owner)
+        {
+            Some(brush) => &mut brush.group,
+            None => unreachable!()
+        };
+
+        match owner_group
+        {
+            Group::None =>
+            {
+                let mut ids = Ids::default();
+                ids.insert(attached);
+                *owner_group = Group::Attachments(ids);
+            },
+            Group::Attachments(attached_brushes) |
+            Group::Path {
+                attached_brushes, ..
+            } =>
+            {
+                attached_brushes.insert(attached);
+            },
+            Group::Attached(_) => unreachable!()
+        };
+
+        Ok(())
+    }
+
+    //==============================================================
+    // Save
+
+    /// Saves the map to a `.hv` file at `path`.
+    /// # Errors
+    /// Returns an error if there was an issue writing the file.
+    #[inline]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), &'static str>
+    {
+        /// Returns the [`DefaultPropertiesViewer`] of the built-in and user defined properties.
+        #[inline]
+        fn default_properties(
+            builtin: &[(&str, Value)],
+            user: &[(String, Value)]
+        ) -> DefaultPropertiesViewer
+        {
+            DefaultPropertiesViewer(
+                builtin
+                    .iter()
+                    .map(|(k, v)| ((*k).to_string(), v.clone()))
+                    .chain(user.iter().cloned())
+                    .collect()
+            )
+        }
+
+        /// Returns the values of `entities` sorted by [`Id`].
+        #[inline]
+        fn sorted<T>(entities: &HashMap<Id, T>) -> Vec<&T>
+        {
+            let mut ids = entities.keys().copied().collect::<Vec<_>>();
+            ids.sort_unstable_by_key(|id| id.value());
+            ids.into_iter().map(|id| &entities[&id]).collect()
+        }
+
+        let file = File::create(path).map_err(|_| "Could not create the file.")?;
+        let mut writer = BufWriter::new(file);
+
+        for step in FileStructure::iter()
+        {
+            match step
+            {
+                FileStructure::Version =>
+                {
+                    ciborium::ser::into_writer(FILE_VERSION, &mut writer)
+                        .map_err(|_| "Error saving version number.")?;
+                },
+                FileStructure::Header =>
+                {
+                    ciborium::ser::into_writer(
+                        &MapHeader {
+                            brushes:     self.brushes.len(),
+                            things:      self.things.len(),
+                            animations:  0,
+                            props:       0,
+                            quick_props: 0
+                        },
+                        &mut writer
+                    )
+                    .map_err(|_| "Error saving file header.")?;
+                },
+                FileStructure::Grid =>
+                {
+                    ciborium::ser::into_writer(&self.grid, &mut writer)
+                        .map_err(|_| "Error saving grid settings.")?;
+                },
+                FileStructure::Properties =>
+                {
+                    ciborium::ser::into_writer(
+                        &default_properties(
+                            &[(COLLISION_LABEL, COLLISION_DEFAULT)],
+                            &self.brush_properties
+                        ),
+                        &mut writer
+                    )
+                    .map_err(|_| "Error saving Brush default properties.")?;
+                    ciborium::ser::into_writer(
+                        &default_properties(
+                            &[(ANGLE_LABEL, ANGLE_DEFAULT), (HEIGHT_LABEL, HEIGHT_DEFAULT)],
+                            &self.thing_properties
+                        ),
+                        &mut writer
+                    )
+                    .map_err(|_| "Error saving Thing default properties.")?;
+                },
+                FileStructure::Brushes =>
+                {
+                    for brush in sorted(&self.brushes)
+                    {
+                        ciborium::ser::into_writer(brush, &mut writer)
+                            .map_err(|_| "Error saving brushes.")?;
+                    }
+                },
+                FileStructure::Things =>
+                {
+                    for thing in sorted(&self.things)
+                    {
+                        ciborium::ser::into_writer(thing, &mut writer)
+                            .map_err(|_| "Error saving things.")?;
+                    }
+                },
+                FileStructure::Metadata =>
+                {
+                    ciborium::ser::into_writer(&self.metadata, &mut writer)
+                        .map_err(|_| "Error saving map metadata.")?;
+                },
+                // There are no animations and props, and the editor reads the missing recently
+                // used textures and statistics as empty.
+                FileStructure::Animations |
+                FileStructure::Props |
+                FileStructure::RecentTextures |
+                FileStructure::Statistics => ()
+            };
+        }
+
+        writer.flush().map_err(|_| "Error writing file.")
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Whether `point` is out of the map bounds.
+#[inline]
+#[must_use]
+fn out_of_bounds(point: Vec2) -> bool
+{
+    !point.is_finite() || point.abs().max_element() > MAP_HALF_SIZE
+}
@@ -12,6 +12,18 @@ use super::editor::state::{
 };
 use crate::utils::{hull::Hull, misc::Camera};
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The zoom presets, in percentage.
+pub(in crate::map) const ZOOM_PRESETS: [u16; 4] = [25, 50, 100, 200];
+/// The minimum scale the camera can assume, matching the highest zoom limit.
+const MIN_SCALE: f32 = 0.0625;
+/// The maximum scale the camera can assume, matching the lowest zoom limit.
+const MAX_SCALE: f32 = 100f32;
+
 //=======================================================================//
 // STRUCTS
 //
@@ -60,7 +72,7 @@ impl Camera for Transform
     fn change_scale(&mut self, units: f32) -> f32
     {
         let prev_scale = self.scale();
-        self.scale = Vec3::splat((self.scale() - units * 0.125).clamp(0.125, 20f32));
+        self.scale = Vec3::splat((self.scale() - units * 0.125).clamp(MIN_SCALE, MAX_SCALE));
         prev_scale
     }
 
@@ -71,6 +83,14 @@ impl Camera for Transform
         self.translate(-ui_camera_displacement(self.scale() - prev_scale));
     }
 
+    #[inline]
+    fn set_scale(&mut self, scale: f32)
+    {
+        let prev_scale = self.scale();
+        self.scale = Vec3::splat(scale.clamp(MIN_SCALE, MAX_SCALE));
+        self.translate(-ui_camera_displacement(self.scale() - prev_scale));
+    }
+
     #[inline]
     fn scale_viewport_to_hull(&mut self, window: &Window, grid: &Grid, hull: &Hull, padding: f32)
     {
@@ -113,6 +133,13 @@ pub fn scale_viewport(camera: &mut Transform, window_sizes: (f32, f32), hull: &H
 
 //=======================================================================//
 
+/// Returns the scale of the camera at `zoom` percentage.
+#[inline]
+#[must_use]
+pub(in crate::map) fn zoom_scale(zoom: u16) -> f32 { 100f32 / f32::from(zoom) }
+
+//=======================================================================//
+
 /// Returns the position the engine camera should assume to be in the center of the portion of the
 /// screen in which the map is visible.
 #[inline]
@@ -271,6 +271,36 @@ pub(in crate::map) mod ui_mod
             }
             .into()
         }
+
+        /// Returns a new [`Animator`] showing the frame of `animation` drawn `time` seconds after
+        /// its start.
+        #[inline]
+        pub fn at_time(animation: &Animation, time: f32) -> Option<Self>
+        {
+            let mut animator = Self::new(animation)?;
+            let cycle = match animation
+            {
+                Animation::None => unreachable!(),
+                Animation::List(anim) => (0..anim.len()).map(|i| anim.frame(i).1).sum::<f32>(),
+                Animation::Atlas(anim) => (0..anim.len()).map(|i| anim.timing().time(i)).sum()
+            };
+
+            if cycle <= 0f32
+            {
+                return animator.into();
+            }
+
+            let time = if cycle.is_finite() { time % cycle } else { time };
+
+            match (&mut animator, animation)
+            {
+                (Self::List(animator), Animation::List(anim)) => animator.update(anim, time),
+                (Self::Atlas(animator), Animation::Atlas(anim)) => animator.update(anim, time),
+                _ => unreachable!()
+            };
+
+            animator.into()
+        }
     }
 
     //=======================================================================//
@@ -315,6 +345,24 @@ pub(in crate::map) mod ui_mod
         {
             match_or_panic!(self, Self::Atlas(anim), anim)
         }
+
+        /// Replaces the texture `prev` with `new` in the frames of the [`List`] animation.
+        /// Returns whether any frame was changed.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+        {
+            let list = return_if_no_match!(self, Self::List(list), list, false);
+            let mut renamed = false;
+
+            for (texture, _) in list.0.iter_mut().filter(|(texture, _)| texture == prev)
+            {
+                new.clone_into(texture);
+                renamed = true;
+            }
+
+            renamed
+        }
     }
 
     //=======================================================================//
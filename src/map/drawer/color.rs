@@ -127,10 +127,14 @@ pub(crate) enum Color
     SelectedPathNode,
     /// The color of the highlighted path.
     HighlightedPath,
+    /// The color of the handles of the control points of the curved path segments.
+    PathCurveHandle,
     /// The color of the lines showing the brushes tied together.
     BrushAnchor,
     /// The color of the lines showing the brushes tied together.
     SpriteAnchor,
+    /// The color of the arrows showing the things linked together.
+    ThingLink,
     /// The color of the [`Hull`]s' outlines.
     Hull,
     /// The color of the selected brush hull lines extensions.
@@ -171,9 +175,11 @@ impl Color
         SelectedVertex,
         BrushAnchor,
         SpriteAnchor,
+        ThingLink,
         PathNode,
         HighlightedPath,
         SelectedPathNode,
+        PathCurveHandle,
         Hull,
         CursorPolygonHull,
         DefaultCursor,
@@ -239,6 +245,8 @@ impl Color
             Self::OpaqueEntity => BevyColor::srgb(0.6, 0.6, 0.6),
             Self::BrushAnchor => BevyColor::srgb(0.7, 0.34, 0.05),
             Self::SpriteAnchor => BevyColor::srgb(1f32, 0.03, 0.91),
+            Self::ThingLink => BevyColor::Srgba(css::DEEP_SKY_BLUE),
+            Self::PathCurveHandle => BevyColor::Srgba(css::VIOLET),
             Self::Hull => BevyColor::Srgba(css::AQUAMARINE),
             Self::CursorPolygonHull => BevyColor::srgb(0.0, 0.5, 0.0),
             Self::CursorPolygon => BevyColor::Srgba(css::AQUA),
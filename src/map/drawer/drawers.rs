@@ -14,8 +14,9 @@ use bevy::{
         system::{Commands, Query}
     },
     prelude::Mesh2d,
-    render::{mesh::Mesh, render_resource::PrimitiveTopology},
+    render::{mesh::Mesh, render_resource::PrimitiveTopology, view::RenderLayers},
     sprite::ColorMaterial,
+    tasks::ComputeTaskPool,
     transform::components::Transform,
     window::Window
 };
@@ -26,8 +27,8 @@ use hill_vacuum_shared::{return_if_none, NextValue};
 use super::{
     animation::Animator,
     color::{Color, ColorResources},
-    drawing_resources::DrawingResources,
-    texture::TextureInterfaceExtra
+    drawing_resources::{DrawingResources, MeshBuffers},
+    texture::{TextureInterfaceExtra, TEXTURE_OPACITY_RANGE}
 };
 use crate::{
     map::{
@@ -38,7 +39,8 @@ use crate::{
             manager::Animators
         },
         thing::{catalog::ThingsCatalog, ThingInterface},
-        MAP_SIZE
+        MAP_SIZE,
+        SPLIT_VIEW_LAYER
     },
     utils::{
         hull::{CircleIterator, Corner, Hull, Side},
@@ -47,7 +49,8 @@ use crate::{
         misc::{Camera, VX_HGL_SIDE}
     },
     Animation,
-    TextureInterface
+    TextureInterface,
+    TextureSettings
 };
 
 //=======================================================================//
@@ -63,6 +66,12 @@ const TOOLTIP_FONT_SIZE: f32 = 13f32;
 /// certain coordinate.
 const TEXT_WIDTH_X_CENTER_COEFFICIENT: f32 = TOOLTIP_FONT_SIZE / 3.25;
 const TOOLTIP_ROUNDING: f32 = 3f32;
+/// The length of the dashes of the marching ants outline, at camera scale 1.
+const MARCHING_ANTS_DASH_LENGTH: f32 = 8f32;
+/// The speed of the marching ants, in dashes per second.
+const MARCHING_ANTS_SPEED: f32 = 2f32;
+/// The height of the marching ants with respect to the outline they are drawn onto.
+const MARCHING_ANTS_HEIGHT_OFFSET: f32 = 0.1;
 
 //=======================================================================//
 // MACROS
@@ -163,6 +172,84 @@ pub(in crate::map::drawer) type Uv = [f32; 2];
 
 //=======================================================================//
 
+/// The settings of a brush texture with the name replaced by the one of the texture previewed in
+/// the texture editor.
+#[must_use]
+struct PreviewTextureSettings<'b>
+{
+    /// The settings of the brush texture, if any.
+    texture: Option<&'b TextureSettings>,
+    /// The name of the previewed texture.
+    name:    &'b str
+}
+
+impl<'b> TextureInterface for PreviewTextureSettings<'b>
+{
+    #[inline]
+    fn name(&self) -> &'b str { self.name }
+
+    #[inline]
+    fn offset_x(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::offset_x) }
+
+    #[inline]
+    fn offset_y(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::offset_y) }
+
+    #[inline]
+    fn draw_offset(&self) -> Vec2 { self.texture.map_or(Vec2::ZERO, TextureInterface::draw_offset) }
+
+    #[inline]
+    fn draw_offset_with_parallax_and_scroll(&self, camera_pos: Vec2, elapsed_time: f32) -> Vec2
+    {
+        self.texture.map_or(Vec2::ZERO, |texture| {
+            texture.draw_offset_with_parallax_and_scroll(camera_pos, elapsed_time)
+        })
+    }
+
+    #[inline]
+    fn scale_x(&self) -> f32 { self.texture.map_or(1f32, TextureInterface::scale_x) }
+
+    #[inline]
+    fn scale_y(&self) -> f32 { self.texture.map_or(1f32, TextureInterface::scale_y) }
+
+    #[inline]
+    fn scroll_x(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::scroll_x) }
+
+    #[inline]
+    fn scroll_y(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::scroll_y) }
+
+    #[inline]
+    fn parallax_x(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::parallax_x) }
+
+    #[inline]
+    fn parallax_y(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::parallax_y) }
+
+    #[inline]
+    fn height(&self) -> i8 { self.texture.map_or(0, TextureInterface::height) }
+
+    #[inline]
+    fn height_f32(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::height_f32) }
+
+    #[inline]
+    fn opacity(&self) -> u8
+    {
+        self.texture
+            .map_or(*TEXTURE_OPACITY_RANGE.end(), TextureInterface::opacity)
+    }
+
+    #[inline]
+    fn angle(&self) -> f32 { self.texture.map_or(0f32, TextureInterface::angle) }
+
+    // The preview is always mapped onto the polygon.
+    #[inline]
+    fn sprite(&self) -> bool { false }
+
+    // The animation of the brush texture does not apply to the previewed one.
+    #[inline]
+    fn animation(&self) -> &Animation { &Animation::None }
+}
+
+//=======================================================================//
+
 /// The struct handling all the draw calls while editing the map.
 pub(in crate::map) struct EditDrawer<'w, 's, 'a>
 {
@@ -182,8 +269,19 @@ pub(in crate::map) struct EditDrawer<'w, 's, 'a>
     elapsed_time:           f32,
     /// Whether the collision overlay of the brushes should be shown.
     show_collision_overlay: bool,
+    /// Whether the arrows showing the scrolling of the textures should be drawn.
+    show_scroll_arrows:     bool,
+    /// Whether the textures of the drawn brushes were already generated by
+    /// [`EditDrawer::brushes_textures`].
+    textures_batched:       bool,
     parallax_camera_pos:    Vec2,
-    show_tooltips:          bool
+    show_tooltips:          bool,
+    /// The time used to animate the marching ants outline of the selected entities, if enabled.
+    marching_ants_time:     Option<f32>,
+    /// The name of the texture drawn on the selected brushes in place of their own, if any.
+    texture_preview:        Option<String>,
+    /// The render layers of the spawned [`Mesh`]es.
+    layers:                 RenderLayers
 }
 
 impl<'w: 'a, 's: 'a, 'a> Drop for EditDrawer<'w, 's, 'a>
@@ -204,7 +302,7 @@ impl<'w: 'a, 's: 'a, 'a> Drop for EditDrawer<'w, 's, 'a>
             );
         }
 
-        self.resources.spawn_meshes(self.commands);
+        self.resources.spawn_meshes(self.commands, &self.layers);
     }
 }
 
@@ -230,23 +328,100 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         color_resources: &'a ColorResources,
         settings: &ToolsSettings,
         grid: &'a Grid,
-        mut elapsed_time: f32,
+        elapsed_time: f32,
         paint_tool_camera_scale: f32,
         show_collision_overlay: bool,
-        show_tooltips: bool
+        show_tooltips: bool,
+        marching_ants: bool
     ) -> Self
     {
-        let camera_scale = camera.scale();
-
         resources.setup_frame(
             commands,
             prop_cameras,
             meshes,
             meshes_query,
-            camera_scale,
+            camera.scale(),
             paint_tool_camera_scale
         );
 
+        Self::with_layers(
+            commands,
+            camera,
+            meshes,
+            egui_context,
+            resources,
+            color_resources,
+            settings,
+            grid,
+            elapsed_time,
+            show_collision_overlay,
+            show_tooltips,
+            marching_ants,
+            RenderLayers::default()
+        )
+    }
+
+    /// Returns a new [`EditDrawer`] drawing on the render layer of the second viewport.
+    /// Must be created after the [`EditDrawer`] of the main viewport has been dropped.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    pub fn second_viewport(
+        commands: &'a mut Commands<'w, 's>,
+        camera: &Transform,
+        meshes: &'a mut Assets<Mesh>,
+        egui_context: &'a egui::Context,
+        resources: &'a mut DrawingResources,
+        color_resources: &'a ColorResources,
+        settings: &ToolsSettings,
+        grid: &'a Grid,
+        elapsed_time: f32,
+        show_collision_overlay: bool,
+        marching_ants: bool
+    ) -> Self
+    {
+        Self::with_layers(
+            commands,
+            camera,
+            meshes,
+            egui_context,
+            resources,
+            color_resources,
+            settings,
+            grid,
+            elapsed_time,
+            show_collision_overlay,
+            false,
+            marching_ants,
+            RenderLayers::layer(SPLIT_VIEW_LAYER)
+        )
+    }
+
+    /// Returns a new [`EditDrawer`] spawning the [`Mesh`]es on the render `layers`.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    fn with_layers(
+        commands: &'a mut Commands<'w, 's>,
+        camera: &Transform,
+        meshes: &'a mut Assets<Mesh>,
+        egui_context: &'a egui::Context,
+        resources: &'a mut DrawingResources,
+        color_resources: &'a ColorResources,
+        settings: &ToolsSettings,
+        grid: &'a Grid,
+        mut elapsed_time: f32,
+        show_collision_overlay: bool,
+        show_tooltips: bool,
+        marching_ants: bool,
+        layers: RenderLayers
+    ) -> Self
+    {
+        let camera_scale = camera.scale();
+        let marching_ants_time = marching_ants.then_some(elapsed_time);
+        let texture_preview = resources.texture_preview().map(str::to_owned);
+        let show_scroll_arrows = settings.scroll_arrows;
+
         if !settings.scroll_enabled
         {
             elapsed_time = 0f32;
@@ -255,7 +430,7 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         #[allow(clippy::match_bool)]
         let parallax_camera_pos = match settings.parallax_enabled
         {
-            true => camera.pos(),
+            true => camera.pos() * settings.parallax_factor(),
             false => Vec2::ZERO
         };
 
@@ -269,8 +444,13 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
             camera_scale,
             elapsed_time,
             show_collision_overlay,
+            show_scroll_arrows,
+            textures_batched: false,
             parallax_camera_pos,
-            show_tooltips
+            show_tooltips,
+            marching_ants_time,
+            texture_preview,
+            layers
         }
     }
 
@@ -620,6 +800,70 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         self.push_mesh(mesh, self.color_resources.line_material(color), color.line_height());
     }
 
+    /// Draws an animated dashed outline along the sides of the polygon with vertexes
+    /// `vertexes`, if the marching ants are enabled and `color` is the one of a selected entity.
+    #[inline]
+    fn marching_ants(&mut self, vertexes: impl Iterator<Item = Vec2> + Clone, color: Color)
+    {
+        let time = return_if_none!(self.marching_ants_time);
+
+        if !matches!(color, Color::SelectedEntity | Color::HighlightedSelectedEntity)
+        {
+            return;
+        }
+
+        let dash = MARCHING_ANTS_DASH_LENGTH * self.camera_scale;
+        let period = dash * 2f32;
+        let phase = (time * MARCHING_ANTS_SPEED * dash).rem_euclid(period);
+        let mut distance = 0f32;
+        let mut dashes = Vec::new();
+        let mut vertexes = vertexes.peekable();
+        let first = return_if_none!(vertexes.peek().copied());
+        let mut start = first;
+
+        for end in vertexes.skip(1).chain(Some(first))
+        {
+            let side = end - start;
+            let len = side.length();
+            let dir = side / len;
+            let mut s = 0f32;
+
+            while s < len
+            {
+                let local = (distance + s - phase).rem_euclid(period);
+
+                if local < dash
+                {
+                    let dash_end = (s + dash - local).min(len);
+                    dashes.extend([start + dir * s, start + dir * dash_end]);
+                    s = dash_end;
+                }
+                else
+                {
+                    s = (s + period - local).min(len);
+                }
+            }
+
+            distance += len;
+            start = end;
+        }
+
+        if dashes.is_empty()
+        {
+            return;
+        }
+
+        let mut mesh_generator = self.resources.mesh_generator();
+        mesh_generator.push_positions_skewed(self.grid, dashes);
+        let mesh = mesh_generator.mesh(PrimitiveTopology::LineList);
+
+        self.push_mesh(
+            mesh,
+            self.color_resources.solid_white().clone_weak(),
+            color.line_height() + MARCHING_ANTS_HEIGHT_OFFSET
+        );
+    }
+
     /// Draws `hull`.
     #[inline]
     pub fn hull(&mut self, hull: &Hull, color: Color) { self.sides(hull.vertexes(), color); }
@@ -744,6 +988,60 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
             .push_textured_mesh(self.meshes.add(mesh).into(), settings, color);
     }
 
+    /// Draws the non-sprite textures of `brushes`, which are made of the vertexes and the texture
+    /// settings of each brush, with color `color`.
+    /// The vertexes and UV coordinates of the meshes are generated in parallel on the
+    /// [`ComputeTaskPool`], the meshes are then spawned on the main thread.
+    #[inline]
+    pub fn brushes_textures<I, T>(&mut self, brushes: &[(I, &T)], color: Color)
+    where
+        I: ExactSizeIterator<Item = Vec2> + Clone + Sync,
+        T: TextureInterface + Sync
+    {
+        let grid = self.grid;
+        let camera_pos = self.parallax_camera_pos;
+        let elapsed_time = self.elapsed_time;
+
+        let buffers = par_mesh_buffers(
+            self.resources,
+            brushes,
+            |(vertexes, settings), buffers, resources| {
+                if settings.sprite()
+                {
+                    return;
+                }
+
+                buffers.push_positions_skewed(grid, vertexes.clone());
+                buffers.set_texture_uv(resources, camera_pos, *settings, elapsed_time);
+            }
+        );
+
+        for ((vertexes, settings), buffers) in brushes.iter().zip(buffers)
+        {
+            if settings.sprite()
+            {
+                continue;
+            }
+
+            let mut mesh_generator = self.resources.mesh_generator_from_buffers(buffers);
+            mesh_generator.set_indexes(vertexes.len());
+            let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
+
+            self.resources
+                .push_textured_mesh(self.meshes.add(mesh).into(), *settings, color);
+        }
+    }
+
+    /// Executes `f` skipping the generation of the textures of the drawn brushes, which were
+    /// already generated by [`Self::brushes_textures`].
+    #[inline]
+    pub fn with_batched_textures<F: FnOnce(&mut Self)>(&mut self, f: F)
+    {
+        self.textures_batched = true;
+        f(self);
+        self.textures_batched = false;
+    }
+
     /// Draws `settings` as a brush.
     #[inline]
     pub fn sideless_brush<T: TextureInterface>(
@@ -761,7 +1059,7 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
 
         if let Some(texture) = texture
         {
-            if !texture.sprite()
+            if !texture.sprite() && !self.textures_batched
             {
                 self.polygon_texture(self.parallax_camera_pos, vertexes.clone(), color, texture);
             }
@@ -781,10 +1079,118 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         collision: bool
     )
     {
-        self.sides(vertexes.clone(), color);
+        self.brush_with_outline(vertexes, color, None, texture, collision);
+    }
+
+    /// Draws a non selected brush with the sides colored `rgb`.
+    #[inline]
+    pub fn colored_brush<T: TextureInterface>(
+        &mut self,
+        vertexes: impl ExactSizeIterator<Item = Vec2> + Clone,
+        rgb: [u8; 3],
+        texture: Option<&T>,
+        collision: bool
+    )
+    {
+        self.brush_with_outline(vertexes, Color::NonSelectedEntity, rgb.into(), texture, collision);
+    }
+
+    /// Draws a brush, with the sides colored `rgb` instead of `color` if it contains a value.
+    #[inline]
+    fn brush_with_outline<T: TextureInterface>(
+        &mut self,
+        vertexes: impl ExactSizeIterator<Item = Vec2> + Clone,
+        color: Color,
+        rgb: Option<[u8; 3]>,
+        texture: Option<&T>,
+        collision: bool
+    )
+    {
+        self.marching_ants(vertexes.clone(), color);
+
+        match rgb
+        {
+            Some(rgb) => self.rgb_sides(vertexes.clone(), rgb, color),
+            None => self.sides(vertexes.clone(), color)
+        };
+
+        if self.show_scroll_arrows
+        {
+            if let Some(texture) = texture
+            {
+                self.scroll_arrow(vertexes.clone(), texture);
+            }
+        }
+
         self.sideless_brush(vertexes, color, texture, collision);
     }
 
+    /// Draws an arrow starting from the center of `vertexes` showing the direction and speed the
+    /// texture scrolls with, if it does.
+    #[inline]
+    fn scroll_arrow<T: TextureInterface>(
+        &mut self,
+        vertexes: impl ExactSizeIterator<Item = Vec2>,
+        texture: &T
+    )
+    {
+        /// The seconds of scrolling represented by the length of the arrow.
+        const SECONDS: f32 = 0.5;
+        /// The minimum length of the arrow on screen.
+        const MIN_LENGTH: f32 = 16f32;
+        /// The maximum length of the arrow on screen.
+        const MAX_LENGTH: f32 = 128f32;
+
+        let scroll = Vec2::new(texture.scroll_x(), texture.scroll_y());
+
+        if texture.sprite() || scroll == Vec2::ZERO
+        {
+            return;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let len = vertexes.len() as f32;
+        let center = vertexes.sum::<Vec2>() / len;
+        let length = (scroll.length() * SECONDS)
+            .clamp(MIN_LENGTH * self.camera_scale, MAX_LENGTH * self.camera_scale);
+
+        // The texture moves opposite to the offset increase.
+        self.arrowed_line(center, center - scroll.normalize() * length, Color::Hull);
+    }
+
+    /// Draws a selected brush, replacing its texture with the one previewed in the texture editor,
+    /// if any.
+    #[inline]
+    pub fn selected_brush(
+        &mut self,
+        vertexes: impl ExactSizeIterator<Item = Vec2> + Clone,
+        color: Color,
+        texture: Option<&TextureSettings>,
+        collision: bool
+    )
+    {
+        let preview = match self.texture_preview.take()
+        {
+            Some(preview) => preview,
+            None =>
+            {
+                self.brush(vertexes, color, texture, collision);
+                return;
+            }
+        };
+
+        self.brush(
+            vertexes,
+            color,
+            Some(&PreviewTextureSettings {
+                texture,
+                name: &preview
+            }),
+            collision
+        );
+        self.texture_preview = preview.into();
+    }
+
     /// Draws a polygon filled with a solid color.
     #[inline]
     pub fn polygon_with_solid_color(
@@ -797,6 +1203,25 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         self.push_mesh(mesh, self.color_resources.line_material(color), color.polygon_height());
     }
 
+    /// Draws the sides of the polygon with vertexes `vertexes` with the RGB color `rgb`, at the
+    /// draw height of `color`.
+    #[inline]
+    fn rgb_sides(&mut self, vertexes: impl Iterator<Item = Vec2>, rgb: [u8; 3], color: Color)
+    {
+        let rgb = bevy::color::Color::srgb_u8(rgb[0], rgb[1], rgb[2]).as_rgba_f32();
+        let vertexes = vertexes.collect::<Vec<_>>();
+        let mut mesh = self.resources.mesh_generator();
+
+        for [a, b] in return_if_none!(vertexes.pair_iter())
+        {
+            mesh.push_positions_skewed(self.grid, [*a, *b]);
+            mesh.push_colors([rgb; 2]);
+        }
+
+        let mesh = mesh.mesh(PrimitiveTopology::LineList);
+        self.push_mesh(mesh, self.resources.default_material(), color.line_height());
+    }
+
     /// Draws `settings` mapping the texture to `sides` and also drawing colored lines at the sides.
     #[inline]
     pub fn brush_with_sides_colors<T: TextureInterface>(
@@ -807,6 +1232,7 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         collision: bool
     )
     {
+        self.marching_ants(sides.clone().map(|(vx, ..)| vx), body_color);
         self.lines(sides.clone());
         self.sideless_brush(sides.map(|(vx, ..)| vx), body_color, texture, collision);
     }
@@ -844,9 +1270,34 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
     }
 
     /// Draws `thing`.
-    #[allow(clippy::cast_precision_loss)]
     #[inline]
     pub fn thing<T: ThingInterface>(&mut self, catalog: &ThingsCatalog, thing: &T, color: Color)
+    {
+        self.thing_with_outline(catalog, thing, color, None);
+    }
+
+    /// Draws a non selected `thing` with the outline colored `rgb`.
+    #[inline]
+    pub fn colored_thing<T: ThingInterface>(
+        &mut self,
+        catalog: &ThingsCatalog,
+        thing: &T,
+        rgb: [u8; 3]
+    )
+    {
+        self.thing_with_outline(catalog, thing, Color::NonSelectedEntity, rgb.into());
+    }
+
+    /// Draws `thing`, with the outline colored `rgb` instead of `color` if it contains a value.
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    fn thing_with_outline<T: ThingInterface>(
+        &mut self,
+        catalog: &ThingsCatalog,
+        thing: &T,
+        color: Color,
+        rgb: Option<[u8; 3]>
+    )
     {
         /// The resolution of the corners of the [`ThingOutline`].
         const CORNER_RESOLUTION: u8 = 6;
@@ -1011,12 +1462,18 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
 
         // Sides and overlay.
         let iter = ThingOutline::new(catalog, thing);
-        self.sides(iter, color);
+        self.marching_ants(iter, color);
+
+        match rgb
+        {
+            Some(rgb) => self.rgb_sides(iter, rgb, color),
+            None => self.sides(iter, color)
+        };
+
         let mesh = self.polygon_mesh(iter);
         self.push_mesh(mesh, self.color_resources.polygon_material(color), color.entity_height());
 
         // Angle indicator.
-        let preview = catalog.thing_or_error(thing.thing_id()).preview();
         let angle = thing.angle_f32().to_radians();
         let hull = thing.thing_hull(catalog);
         let half_side = (hull.width().min(hull.height()) / 2f32).min(64f32);
@@ -1024,7 +1481,7 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
 
         if self.grid.isometric()
         {
-            center.y += (self.resources.texture_or_error(preview).size().y / 2) as f32;
+            center.y += thing_fitted_texture_size(self.resources, catalog, thing).y / 2f32;
         }
 
         let hull = Hull::new(
@@ -1091,17 +1548,45 @@ impl<'w: 'a, 's: 'a, 'a> EditDrawer<'w, 's, 'a>
         color: Color
     )
     {
-        let preview = catalog.thing_or_error(thing.thing_id()).preview();
-        let vxs = thing_texture_hull(self.resources, self.grid, thing, preview);
+        let preview = catalog.texture(thing.thing_id());
+        let vxs = thing_fitted_texture_hull(self.resources, self.grid, catalog, thing);
+        let resources = unsafe { std::ptr::from_mut(self.resources).as_mut().unwrap() };
 
-        let mut mesh_generator = self.resources.mesh_generator();
+        let mut mesh_generator = resources.mesh_generator();
         mesh_generator.set_indexes(4);
         mesh_generator.push_positions(vxs.rectangle());
-        mesh_generator.set_thing_uv(preview);
-        let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
 
-        self.resources
-            .push_thing(self.meshes.add(mesh).into(), catalog, thing, color);
+        // Animated textures are only animated if the edit view animations are enabled.
+        let texture = match self.resources.thing_animator(preview)
+        {
+            Some(Animator::List(animator)) =>
+            {
+                mesh_generator.set_thing_uv(preview);
+                animator
+                    .texture(
+                        self.resources,
+                        self.resources
+                            .texture_or_error(preview)
+                            .animation()
+                            .get_list_animation()
+                    )
+                    .texture()
+                    .name()
+            },
+            Some(Animator::Atlas(animator)) =>
+            {
+                mesh_generator.set_animated_thing_uv(preview, animator);
+                preview
+            },
+            None =>
+            {
+                mesh_generator.set_thing_uv(preview);
+                preview
+            }
+        };
+
+        let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
+        resources.push_thing(self.meshes.add(mesh).into(), texture, thing, color);
     }
 
     //==============================================================
@@ -1244,14 +1729,16 @@ pub(in crate::map) struct MapPreviewDrawer<'w, 's, 'a>
     /// The resources required to draw things.
     resources:    &'a mut DrawingResources,
     grid:         &'a Grid,
-    /// The time that has passed.
-    elapsed_time: f32
+    /// The time that has passed in the preview.
+    elapsed_time: f32,
+    /// The render layers of the spawned [`Mesh`]es.
+    layers:       RenderLayers
 }
 
 impl<'w: 'a, 's: 'a, 'a> Drop for MapPreviewDrawer<'w, 's, 'a>
 {
     #[inline]
-    fn drop(&mut self) { self.resources.spawn_meshes(self.commands); }
+    fn drop(&mut self) { self.resources.spawn_meshes(self.commands, &self.layers); }
 }
 
 impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
@@ -1265,8 +1752,7 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
         meshes: &'a mut Assets<Mesh>,
         meshes_query: &Query<Entity, With<Mesh2d>>,
         resources: &'a mut DrawingResources,
-        grid: &'a Grid,
-        elapsed_time: f32
+        grid: &'a Grid
     ) -> Self
     {
         resources.setup_frame(commands, prop_cameras, meshes, meshes_query, 1f32, 1f32);
@@ -1276,13 +1762,40 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
             meshes,
             resources,
             grid,
-            elapsed_time
+            elapsed_time: 0f32,
+            layers: RenderLayers::default()
+        }
+    }
+
+    /// Returns a new [`MapPreviewDrawer`] drawing on the render layer of the split view camera.
+    /// Must be created after the [`EditDrawer`] of the frame has been dropped.
+    #[inline]
+    #[must_use]
+    pub fn split_view(
+        commands: &'a mut Commands<'w, 's>,
+        meshes: &'a mut Assets<Mesh>,
+        resources: &'a mut DrawingResources,
+        grid: &'a Grid,
+        elapsed_time: f32
+    ) -> Self
+    {
+        Self {
+            commands,
+            meshes,
+            resources,
+            grid,
+            elapsed_time,
+            layers: RenderLayers::layer(SPLIT_VIEW_LAYER)
         }
     }
 
     #[inline]
     pub const fn grid(&self) -> &Grid { self.grid }
 
+    /// Sets the time that has passed in the preview, used to scroll the textures.
+    #[inline]
+    pub fn set_elapsed_time(&mut self, elapsed_time: f32) { self.elapsed_time = elapsed_time; }
+
     /// Draws `settings` mapping the texture to `vertexes`.
     #[inline]
     pub fn brush<T: TextureInterface + TextureInterfaceExtra>(
@@ -1298,6 +1811,7 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
         let mut mesh_generator = resources.mesh_generator();
         mesh_generator.set_indexes(vertexes.len());
         mesh_generator.push_positions_skewed(self.grid, vertexes);
+        mesh_generator.set_opacity(settings.opacity());
 
         let texture = match animator
         {
@@ -1340,6 +1854,82 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
         resources.push_map_preview_textured_mesh(self.meshes.add(mesh).into(), texture, settings);
     }
 
+    /// Draws the textures of `brushes`, which are made of the vertexes, the [`Animator`], and the
+    /// texture settings of each brush.
+    /// The vertexes and UV coordinates of the meshes are generated in parallel on the
+    /// [`ComputeTaskPool`], the meshes are then spawned on the main thread.
+    #[inline]
+    pub fn brushes<'b, I, T>(
+        &mut self,
+        camera: &Transform,
+        brushes: &[(I, Option<&'b Animator>, &'b T)]
+    ) where
+        I: ExactSizeIterator<Item = Vec2> + Clone + Sync,
+        T: TextureInterface + TextureInterfaceExtra + Sync
+    {
+        let grid = self.grid;
+        let camera_pos = camera.pos();
+        let elapsed_time = self.elapsed_time;
+
+        let buffers = par_mesh_buffers(
+            self.resources,
+            brushes,
+            |(vertexes, animator, settings), buffers, resources| {
+                buffers.push_positions_skewed(grid, vertexes.clone());
+
+                match animator
+                {
+                    Some(Animator::Atlas(animator)) =>
+                    {
+                        buffers.set_animated_texture_uv(
+                            resources,
+                            camera_pos,
+                            *settings,
+                            animator,
+                            elapsed_time
+                        );
+                    },
+                    _ =>
+                    {
+                        buffers.set_texture_uv(resources, camera_pos, *settings, elapsed_time);
+                    }
+                };
+            }
+        );
+
+        let resources = unsafe { std::ptr::from_mut(self.resources).as_mut().unwrap() };
+
+        for ((vertexes, animator, settings), buffers) in brushes.iter().zip(buffers)
+        {
+            let texture = match animator
+            {
+                Some(Animator::List(animator)) =>
+                {
+                    animator.texture(
+                        self.resources,
+                        settings.overall_animation(self.resources).get_list_animation()
+                    )
+                },
+                Some(Animator::Atlas(_)) => self.resources.texture_materials(settings.name()),
+                None =>
+                {
+                    self.resources
+                        .texture_materials(self.resources.texture_or_error(settings.name()).name())
+                },
+            };
+
+            let mut mesh_generator = resources.mesh_generator_from_buffers(buffers);
+            mesh_generator.set_indexes(vertexes.len());
+            mesh_generator.set_opacity(settings.opacity());
+            let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
+            resources.push_map_preview_textured_mesh(
+                self.meshes.add(mesh).into(),
+                texture,
+                *settings
+            );
+        }
+    }
+
     /// Draws `settings` as a sprite.
     #[inline]
     pub fn sprite<T: TextureInterface + TextureInterfaceExtra>(
@@ -1388,6 +1978,7 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
         };
 
         mesh_generator.push_positions(vxs);
+        mesh_generator.set_opacity(settings.opacity());
 
         let mesh = mesh_generator.mesh(PrimitiveTopology::TriangleList);
         resources.push_map_preview_sprite(self.meshes.add(mesh).into(), texture, settings);
@@ -1476,29 +2067,121 @@ impl<'w: 'a, 's: 'a, 'a> MapPreviewDrawer<'w, 's, 'a>
 //
 //=======================================================================//
 
+/// Returns a [`MeshBuffers`] for each element of `items`, filled by `f` in parallel on the
+/// [`ComputeTaskPool`].
+#[inline]
+fn par_mesh_buffers<E, F>(resources: &mut DrawingResources, items: &[E], f: F) -> Vec<MeshBuffers>
+where
+    E: Sync,
+    F: Fn(&E, &mut MeshBuffers, &DrawingResources) + Sync
+{
+    let mut buffers = items.iter().map(|_| resources.mesh_buffers()).collect::<Vec<_>>();
+
+    if items.is_empty()
+    {
+        return buffers;
+    }
+
+    let task_pool = ComputeTaskPool::get();
+    let chunk_size = items.len().div_ceil(task_pool.thread_num().max(1));
+    let resources = &*resources;
+    let f = &f;
+
+    task_pool.scope(|scope| {
+        for (items, buffers) in items.chunks(chunk_size).zip(buffers.chunks_mut(chunk_size))
+        {
+            scope.spawn(async move {
+                for (item, buffers) in items.iter().zip(buffers)
+                {
+                    f(item, buffers, resources);
+                }
+            });
+        }
+    });
+
+    buffers
+}
+
+//=======================================================================//
+
+/// Returns the [`Hull`] of a frame of `texture` centered at the origin.
 #[inline]
 #[allow(clippy::cast_precision_loss)]
-pub(in crate::map::drawer) fn thing_texture_hull<T: ThingInterface>(
-    resources: &DrawingResources,
-    grid: &Grid,
-    thing: &T,
-    texture: &str
-) -> Hull
+fn texture_frame_hull(resources: &DrawingResources, texture: &str) -> Hull
 {
     let texture = resources.texture_or_error(texture);
-    let mut vxs = texture.hull();
+    let vxs = texture.hull();
 
     if let Animation::Atlas(anim) = texture.animation()
     {
         let half_width = (vxs.width() / anim.x_partition() as f32) / 2f32;
         let half_height = (vxs.height() / anim.y_partition() as f32) / 2f32;
 
-        vxs = Hull::from_opposite_vertexes(
+        return Hull::from_opposite_vertexes(
             Vec2::new(half_width, half_height),
             Vec2::new(-half_width, -half_height)
         );
     }
 
+    vxs
+}
+
+//=======================================================================//
+
+/// Returns the size of the preview texture of the [`Thing`] of `thing` scaled to fit the size of
+/// the bounding box defined in the [`ThingsCatalog`].
+///
+/// [`Thing`]: crate::map::thing::Thing
+#[inline]
+#[must_use]
+pub(in crate::map) fn thing_fitted_texture_size<T: ThingInterface>(
+    resources: &DrawingResources,
+    catalog: &ThingsCatalog,
+    thing: &T
+) -> Vec2
+{
+    let definition = catalog.thing_or_error(thing.thing_id());
+    let hull = texture_frame_hull(resources, definition.preview());
+    let size = Vec2::new(hull.width(), hull.height());
+
+    size * (definition.width() / size.x).min(definition.height() / size.y)
+}
+
+//=======================================================================//
+
+/// Returns the [`Hull`] of the preview texture of `thing` scaled to fit the size of the bounding
+/// box defined in the [`ThingsCatalog`].
+#[inline]
+fn thing_fitted_texture_hull<T: ThingInterface>(
+    resources: &DrawingResources,
+    grid: &Grid,
+    catalog: &ThingsCatalog,
+    thing: &T
+) -> Hull
+{
+    let half_size = thing_fitted_texture_size(resources, catalog, thing) / 2f32;
+    position_thing_texture_hull(grid, thing, Hull::from_opposite_vertexes(half_size, -half_size))
+}
+
+//=======================================================================//
+
+#[inline]
+pub(in crate::map::drawer) fn thing_texture_hull<T: ThingInterface>(
+    resources: &DrawingResources,
+    grid: &Grid,
+    thing: &T,
+    texture: &str
+) -> Hull
+{
+    position_thing_texture_hull(grid, thing, texture_frame_hull(resources, texture))
+}
+
+//=======================================================================//
+
+/// Moves `vxs` to the position of `thing`.
+#[inline]
+fn position_thing_texture_hull<T: ThingInterface>(grid: &Grid, thing: &T, mut vxs: Hull) -> Hull
+{
     vxs += grid.transform_point(thing.pos());
     let y_offset = vxs.half_height();
 
@@ -21,7 +21,7 @@ use bevy::{
     render::{
         mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues},
         render_asset::RenderAssetUsages,
-        view::NoFrustumCulling
+        view::{NoFrustumCulling, RenderLayers}
     },
     sprite::{ColorMaterial, MeshMaterial2d},
     transform::components::Transform
@@ -32,11 +32,11 @@ use hill_vacuum_proc_macros::{meshes_indexes, str_array};
 use hill_vacuum_shared::{continue_if_none, match_or_panic, return_if_no_match, return_if_none};
 
 use super::{
-    animation::{Animation, AtlasAnimator},
+    animation::{Animation, Animator, AtlasAnimator},
     color::Color,
     drawers::{Uv, VxColor, VxPos, HULL_HEIGHT_LABEL, HULL_WIDTH_LABEL},
     file_animations,
-    texture::{DefaultAnimation, TextureInterface, TextureInterfaceExtra},
+    texture::{DefaultAnimation, TextureInterface, TextureInterfaceExtra, TEXTURE_OPACITY_RANGE},
     texture_loader::TextureLoader,
     BevyColor,
     TextureSize
@@ -54,7 +54,7 @@ use crate::{
             },
             Placeholder
         },
-        thing::{catalog::ThingsCatalog, ThingInterface}
+        thing::ThingInterface
     },
     utils::{
         collections::{hash_map, hash_set, index_map, HashMap, HashSet, IndexMap},
@@ -147,6 +147,8 @@ impl Materials
 pub(in crate::map) struct TextureMaterials
 {
     texture:          Texture,
+    /// The folder of the image file, relative to the textures folder.
+    folder:           String,
     egui_id:          egui::TextureId,
     repeat_materials: Materials,
     clamp_materials:  Materials
@@ -159,6 +161,7 @@ impl Placeholder for TextureMaterials
     {
         Self {
             texture:          Texture::placeholder(),
+            folder:           String::new(),
             egui_id:          egui::TextureId::default(),
             repeat_materials: Materials::placeholder(),
             clamp_materials:  Materials::placeholder()
@@ -174,11 +177,13 @@ impl TextureMaterials
     #[inline]
     fn new(
         texture: Texture,
+        folder: String,
         egui_id: egui::TextureId,
         materials: &mut Assets<ColorMaterial>
     ) -> Self
     {
         Self {
+            folder,
             egui_id,
             repeat_materials: Materials::new(texture.repeat_handle(), materials),
             clamp_materials: Materials::new(texture.clamp_handle(), materials),
@@ -190,6 +195,12 @@ impl TextureMaterials
     #[inline]
     pub const fn texture(&self) -> &Texture { &self.texture }
 
+    /// Returns the folder of the image file of the texture, relative to the textures folder and
+    /// separated by `/`. Empty if the file is in the textures folder itself.
+    #[inline]
+    #[must_use]
+    pub fn folder(&self) -> &str { &self.folder }
+
     /// Returns the [`egui::TextureId`] of the texture.
     #[inline]
     pub const fn egui_id(&self) -> egui::TextureId { self.egui_id }
@@ -202,6 +213,7 @@ impl TextureMaterials
             repeat_materials: Materials::new(texture.0.repeat_handle(), materials),
             clamp_materials:  Materials::new(texture.0.clamp_handle(), materials),
             texture:          texture.0,
+            folder:           String::new(),
             egui_id:          texture.1
         }
     }
@@ -241,6 +253,10 @@ pub(in crate::map) struct DrawingResources
     default_material: Handle<ColorMaterial>,
     /// The textures loaded from the assets folder.
     textures: IndexMap<String, TextureMaterials>,
+    /// The folders containing the textures, sorted.
+    texture_folders: Vec<String>,
+    /// The names of the textures assigned from the texture editor, from the most recently used.
+    used_textures: Vec<String>,
     /// The error texture.
     error_texture: TextureMaterials,
     /// The clip overlay texture.
@@ -248,7 +264,12 @@ pub(in crate::map) struct DrawingResources
     /// The names of the textures with [`Animations`].
     animated_textures: HashSet<String>,
     /// Whether any default texture animation was changed.
-    default_animation_changed: bool
+    default_animation_changed: bool,
+    /// The [`Animator`]s of the animated textures of the things drawn in the edit view.
+    things_animators: HashMap<String, Animator>,
+    /// The name of the texture hovered in the texture editor, drawn on the selected brushes
+    /// without being applied to them.
+    texture_preview: Option<String>
 }
 
 impl Placeholder for DrawingResources
@@ -266,10 +287,14 @@ impl Placeholder for DrawingResources
             tt_label_gen: TooltipLabelGenerator::default(),
             default_material: Handle::default(),
             textures: index_map![],
+            texture_folders: Vec::new(),
+            used_textures: Vec::new(),
             error_texture: TextureMaterials::placeholder(),
             clip_texture: Handle::default(),
             animated_textures: hash_set![],
-            default_animation_changed: false
+            default_animation_changed: false,
+            things_animators: hash_map![],
+            texture_preview: None
         }
     }
 }
@@ -372,6 +397,7 @@ impl DrawingResources
             Texture::from_parts(ERROR_TEXTURE_NAME, UVec2::splat(64), handle, clamp)
         };
         let err_id = user_textures.add_image(err_tex.repeat_handle());
+        let textures = Self::sort_textures(materials, texture_loader.loaded_textures());
 
         Self {
             brush_meshes: Meshes::default(),
@@ -382,12 +408,16 @@ impl DrawingResources
             sprite_highlight_mesh: meshes.add(highlight_mesh!(sprite_highlight_vxs)),
             tt_label_gen: TooltipLabelGenerator::default(),
             default_material: materials.add(ColorMaterial::default()),
-            textures: Self::sort_textures(materials, texture_loader.loaded_textures()),
+            texture_folders: Self::texture_folders_tree(&textures),
+            used_textures: Vec::new(),
+            textures,
             error_texture: TextureMaterials::error((err_tex, err_id), materials),
             clip_texture: materials
                 .add(asset_server.load(embedded_asset_path(CLIP_OVERLAY_TEXTURE_NAME))),
             animated_textures: hash_set![],
-            default_animation_changed: false
+            default_animation_changed: false,
+            things_animators: hash_map![],
+            texture_preview: None
         }
     }
 
@@ -398,6 +428,7 @@ impl DrawingResources
         for label in [
             CursorDelta::X_DELTA,
             CursorDelta::Y_DELTA,
+            CursorDelta::DISTANCE,
             NEW_VX,
             HULL_WIDTH_LABEL,
             HULL_HEIGHT_LABEL
@@ -456,10 +487,32 @@ impl DrawingResources
         self.reset_default_animation_changed();
     }
 
+    /// Replaces the default texture animations with `animations`, and the changed flag with
+    /// `changed`. Returns the previous animations and flag.
+    #[inline]
+    pub fn swap_animations(
+        &mut self,
+        animations: HashMap<String, Animation>,
+        changed: bool
+    ) -> (HashMap<String, Animation>, bool)
+    {
+        let prev = self
+            .animated_textures
+            .iter()
+            .map(|tex| (tex.clone(), self.texture(tex).unwrap().animation().clone()))
+            .collect();
+        let prev_changed = self.default_animation_changed;
+
+        self.replace_animations(animations);
+        self.default_animation_changed = changed;
+
+        (prev, prev_changed)
+    }
+
     /// Exports the default texture animations to `writer`.
     #[inline]
     pub fn export_animations(
-        &mut self,
+        &self,
         mut writer: &mut BufWriter<&mut Vec<u8>>
     ) -> Result<(), &'static str>
     {
@@ -490,6 +543,50 @@ impl DrawingResources
     #[inline]
     pub fn reset_default_animation_changed(&mut self) { self.default_animation_changed = false; }
 
+    /// Returns the name of the texture to draw on the selected brushes in place of their own, if
+    /// any.
+    #[inline]
+    #[must_use]
+    pub fn texture_preview(&self) -> Option<&str> { self.texture_preview.as_deref() }
+
+    /// Sets the texture to draw on the selected brushes in place of their own.
+    #[inline]
+    pub fn set_texture_preview(&mut self, texture: Option<&str>)
+    {
+        if self.texture_preview.as_deref() == texture
+        {
+            return;
+        }
+
+        self.texture_preview = texture.map(str::to_owned);
+    }
+
+    /// Marks the texture named `name` as the most recently used one.
+    #[inline]
+    pub fn push_used_texture(&mut self, name: &str)
+    {
+        if let Some(index) = self.used_textures.iter().position(|t| t == name)
+        {
+            let texture = self.used_textures.remove(index);
+            self.used_textures.insert(0, texture);
+            return;
+        }
+
+        self.used_textures.insert(0, name.to_owned());
+    }
+
+    /// Returns the position of the texture named `name` in the most recently used order, or
+    /// [`usize::MAX`] if it was never used.
+    #[inline]
+    #[must_use]
+    pub fn texture_use_rank(&self, name: &str) -> usize
+    {
+        self.used_textures
+            .iter()
+            .position(|t| t == name)
+            .unwrap_or(usize::MAX)
+    }
+
     //==============================================================
     // Info
 
@@ -615,10 +712,54 @@ impl DrawingResources
         }
     }
 
+    /// Returns the folders containing the textures, relative to the textures folder and sorted,
+    /// so that each folder is followed by its subfolders.
+    #[inline]
+    #[must_use]
+    pub fn texture_folders(&self) -> &[String] { &self.texture_folders }
+
     #[inline]
     #[must_use]
     pub fn is_animated(&self, texture: &str) -> bool { self.animated_textures.contains(texture) }
 
+    /// Sets the [`Animator`]s of the animated textures among `textures` to show the frames drawn
+    /// `time` seconds after the start of their animations, and removes the others.
+    #[inline]
+    pub fn update_things_animators<'a>(
+        &mut self,
+        textures: impl Iterator<Item = &'a str>,
+        time: f32
+    )
+    {
+        self.things_animators.clear();
+
+        for texture in textures
+        {
+            if self.things_animators.contains_key(texture) || !self.is_animated(texture)
+            {
+                continue;
+            }
+
+            let animator = continue_if_none!(Animator::at_time(
+                self.texture_or_error(texture).animation(),
+                time
+            ));
+            self.things_animators.asserted_insert((texture.to_owned(), animator));
+        }
+    }
+
+    /// Removes the [`Animator`]s of the textures of the things.
+    #[inline]
+    pub fn clear_things_animators(&mut self) { self.things_animators.clear(); }
+
+    /// Returns the [`Animator`] of the thing texture `texture`, if any.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::drawer) fn thing_animator(&self, texture: &str) -> Option<&Animator>
+    {
+        self.things_animators.get(texture)
+    }
+
     //==============================================================
     // Texture loading
 
@@ -629,13 +770,38 @@ impl DrawingResources
         mut textures: Vec<(Texture, egui::TextureId)>
     ) -> IndexMap<String, TextureMaterials>
     {
+        let mut folders = TextureLoader::texture_folders();
+
         textures.sort_by(|a, b| a.0.name().cmp(b.0.name()));
         textures
             .into_iter()
-            .map(|(tex, id)| (tex.name().to_string(), TextureMaterials::new(tex, id, materials)))
+            .map(|(tex, id)| {
+                let folder = folders.remove(tex.name()).unwrap_or_default();
+                (tex.name().to_string(), TextureMaterials::new(tex, folder, id, materials))
+            })
             .collect()
     }
 
+    /// Returns the sorted folders containing `textures` along with all their parent folders.
+    #[inline]
+    #[must_use]
+    fn texture_folders_tree(textures: &IndexMap<String, TextureMaterials>) -> Vec<String>
+    {
+        let mut folders = hash_set![];
+
+        for mut folder in textures.values().map(TextureMaterials::folder)
+        {
+            while !folder.is_empty() && folders.insert(folder)
+            {
+                folder = folder.rsplit_once('/').map_or("", |(parent, _)| parent);
+            }
+        }
+
+        let mut folders = folders.into_iter().map(str::to_owned).collect::<Vec<_>>();
+        folders.sort_unstable_by(|a, b| a.split('/').cmp(b.split('/')));
+        folders
+    }
+
     /// Reloads the textures.
     #[inline]
     pub fn reload_textures(
@@ -669,6 +835,81 @@ impl DrawingResources
         }
 
         self.textures = textures;
+        self.texture_folders = Self::texture_folders_tree(&self.textures);
+        self.used_textures.retain(|name| self.textures.contains_key(name));
+    }
+
+    /// Stores `textures`, replacing the ones with the same names and retaining their default
+    /// animations.
+    #[inline]
+    pub fn update_textures(
+        &mut self,
+        materials: &mut Assets<ColorMaterial>,
+        textures: Vec<(Texture, egui::TextureId)>
+    )
+    {
+        for (name, mut texture) in Self::sort_textures(materials, textures)
+        {
+            if let Some(prev) = self.textures.get_mut(&name)
+            {
+                if self.animated_textures.contains(&name)
+                {
+                    *texture.texture.animation_mut_set_dirty() =
+                        std::mem::take(prev.texture.animation_mut());
+                }
+
+                *prev = texture;
+                continue;
+            }
+
+            _ = self.textures.insert(name, texture);
+        }
+
+        self.textures.sort_keys();
+        self.texture_folders = Self::texture_folders_tree(&self.textures);
+        self.things_animators.clear();
+    }
+
+    /// Renames the texture `prev` to `new`, also replacing it in the frames of the default list
+    /// animations. Returns false if there is no texture named `prev` or there already is one
+    /// named `new`.
+    #[inline]
+    #[must_use]
+    pub fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+    {
+        if self.textures.contains_key(new)
+        {
+            return false;
+        }
+
+        let mut texture = return_if_none!(self.textures.shift_remove(prev), false);
+        texture.texture.rename(new);
+        _ = self.textures.insert(new.to_owned(), texture);
+        self.textures.sort_keys();
+
+        if self.animated_textures.remove(prev)
+        {
+            self.animated_textures.asserted_insert(new.to_owned());
+        }
+
+        for name in &self.animated_textures
+        {
+            let texture = &mut self.textures.get_mut(name).unwrap().texture;
+
+            if texture.animation_mut().rename_texture(prev, new)
+            {
+                _ = texture.animation_mut_set_dirty();
+                self.default_animation_changed = true;
+            }
+        }
+
+        if let Some(name) = self.used_textures.iter_mut().find(|name| *name == prev)
+        {
+            new.clone_into(name);
+        }
+
+        self.things_animators.clear();
+        true
     }
 
     //==============================================================
@@ -756,6 +997,27 @@ impl DrawingResources
         MeshGenerator::new(self)
     }
 
+    /// Returns new [`MeshBuffers`] made of reused vectors.
+    #[inline]
+    pub(in crate::map::drawer) fn mesh_buffers(&mut self) -> MeshBuffers
+    {
+        MeshBuffers {
+            pos: self.brush_meshes.parts.pop_pos(),
+            uv:  self.brush_meshes.parts.pop_uv()
+        }
+    }
+
+    /// Returns a new [`MeshGenerator`] initialized with the content of `buffers`.
+    #[inline]
+    pub(in crate::map::drawer) fn mesh_generator_from_buffers(
+        &mut self,
+        buffers: MeshBuffers
+    ) -> MeshGenerator
+    {
+        let MeshBuffers { pos, uv } = buffers;
+        MeshGenerator(pos, self.brush_meshes.parts.pop_color(), 0, uv, self)
+    }
+
     /// Returns a [`Transform`] for the [`Mesh`] entities.
     #[inline]
     #[must_use]
@@ -843,14 +1105,14 @@ impl DrawingResources
     pub(in crate::map::drawer) fn push_thing<T: ThingInterface>(
         &mut self,
         mesh: Mesh2d,
-        catalog: &ThingsCatalog,
+        texture: &str,
         thing: &T,
         color: Color
     )
     {
         self.push_mesh(
             mesh,
-            self.texture_materials(self.texture_or_error(catalog.texture(thing.thing_id())).name())
+            self.texture_materials(self.texture_or_error(texture).name())
                 .clamp_material(color),
             color.entity_height() + thing.draw_height_f32()
         );
@@ -952,11 +1214,15 @@ impl DrawingResources
         });
     }
 
-    /// Spawns all the queued [`Mesh`]es.
+    /// Spawns all the queued [`Mesh`]es on the render `layers`.
     #[inline]
-    pub(in crate::map::drawer) fn spawn_meshes(&mut self, commands: &mut Commands)
+    pub(in crate::map::drawer) fn spawn_meshes(
+        &mut self,
+        commands: &mut Commands,
+        layers: &RenderLayers
+    )
     {
-        self.brush_meshes.spawn_batch(commands);
+        self.brush_meshes.spawn_batch(commands, layers);
     }
 
     /// Renders one tooltip for each label that has not been utilized this frame, to fix an egui
@@ -1146,16 +1412,18 @@ impl Meshes
         self.grid = mesh.into();
     }
 
-    /// Spawns all the [`MaterialMesh2dBundle`] as entities into the map.
+    /// Spawns all the [`MaterialMesh2dBundle`] as entities into the map, visible to the cameras
+    /// rendering `layers`.
     #[inline]
-    pub fn spawn_batch(&mut self, commands: &mut Commands)
+    pub fn spawn_batch(&mut self, commands: &mut Commands, layers: &RenderLayers)
     {
         commands.spawn_batch(
             self.spawn
                 .take_value()
                 .into_iter()
-                .map(|mesh| (mesh, NoFrustumCulling))
-                .chain(self.grid.take_value().map(|mesh| (mesh, NoFrustumCulling)))
+                .chain(self.grid.take_value())
+                .map(|mesh| (mesh, NoFrustumCulling, layers.clone()))
+                .collect::<Vec<_>>()
         );
     }
 
@@ -1338,6 +1606,22 @@ impl<'a> MeshGenerator<'a>
     #[inline]
     pub fn push_colors(&mut self, iter: impl IntoIterator<Item = VxColor>) { self.1.extend(iter); }
 
+    /// Makes the texture drawn on the vertexes added so far as translucent as `opacity`, a
+    /// percentage, if it is not opaque.
+    #[inline]
+    pub fn set_opacity(&mut self, opacity: u8)
+    {
+        if opacity >= *TEXTURE_OPACITY_RANGE.end()
+        {
+            return;
+        }
+
+        let alpha = f32::from(opacity) / f32::from(*TEXTURE_OPACITY_RANGE.end());
+        self.1.clear();
+        self.1
+            .extend(std::iter::repeat([1f32, 1f32, 1f32, alpha]).take(self.0.len()));
+    }
+
     /// Returns the UV of the sprite.
     #[allow(clippy::cast_precision_loss)]
     #[inline]
@@ -1436,51 +1720,6 @@ impl<'a> MeshGenerator<'a>
         self.3.extend(uvs);
     }
 
-    /// Sets the UV coordinates based on `f`.
-    #[inline]
-    fn texture_uv<T: TextureInterface, F>(
-        &mut self,
-        camera_pos: Vec2,
-        settings: &T,
-        elapsed_time: f32,
-        f: F
-    ) where
-        F: Fn([f32; 2], Vec2, Vec2) -> Uv
-    {
-        let offset = settings.draw_offset_with_parallax_and_scroll(camera_pos, elapsed_time);
-        let size_scale_mod = self.4.texture_or_error(settings.name()).size().as_vec2() *
-            Vec2::new(settings.scale_x(), settings.scale_y());
-        let angle = settings.angle();
-
-        if angle != 0f32
-        {
-            let angle = angle.to_radians();
-
-            self.3.extend(self.0.iter().map(|vx| {
-                f(
-                    rotate_point_around_origin([vx[0], vx[1]].into(), angle).to_array(),
-                    offset,
-                    size_scale_mod
-                )
-            }));
-
-            return;
-        }
-
-        self.3
-            .extend(self.0.iter().map(|vx| f([vx[0], vx[1]], offset, size_scale_mod)));
-    }
-
-    #[inline]
-    #[must_use]
-    fn common_texture_uv_coordinate(vx: [f32; 2], offset: Vec2, size_scale_mod: Vec2) -> Uv
-    {
-        [
-            (vx[0] + offset.x) / size_scale_mod.x,
-            (-(vx[1] + offset.y)) / size_scale_mod.y
-        ]
-    }
-
     /// Sets the texture UV.
     #[inline]
     pub fn set_texture_uv<T: TextureInterface>(
@@ -1490,7 +1729,15 @@ impl<'a> MeshGenerator<'a>
         elapsed_time: f32
     )
     {
-        self.texture_uv(camera_pos, settings, elapsed_time, Self::common_texture_uv_coordinate);
+        push_texture_uv(
+            self.4,
+            &self.0,
+            &mut self.3,
+            camera_pos,
+            settings,
+            elapsed_time,
+            common_texture_uv_coordinate
+        );
     }
 
     /// Sets the UV to the one of an animated texture.
@@ -1503,21 +1750,15 @@ impl<'a> MeshGenerator<'a>
         elapsed_time: f32
     )
     {
-        /// Returns the UV coordinates of a vertex.
-        #[inline]
-        #[must_use]
-        fn uv_coordinate(vx: [f32; 2], offset: Vec2, size_scale_mod: Vec2, pivot: Uv) -> Uv
-        {
-            let mut uv = MeshGenerator::common_texture_uv_coordinate(vx, offset, size_scale_mod);
-            uv.translate(&pivot);
-            uv
-        }
-
-        let pivot = animator.pivot();
-
-        self.texture_uv(camera_pos, settings, elapsed_time, |vx, offset, size_scale_mod| {
-            uv_coordinate(vx, offset, size_scale_mod, pivot)
-        });
+        push_animated_texture_uv(
+            self.4,
+            &self.0,
+            &mut self.3,
+            camera_pos,
+            settings,
+            animator,
+            elapsed_time
+        );
     }
 
     /// Sets the UV to the one of the clip texture.
@@ -1598,6 +1839,73 @@ impl<'a> MeshGenerator<'a>
 
 //=======================================================================//
 
+/// The vectors of the vertexes and UV coordinates of a [`Mesh`] which can be filled outside of the
+/// main thread, and later turned into a [`MeshGenerator`].
+#[must_use]
+pub(in crate::map::drawer) struct MeshBuffers
+{
+    /// The vertexes.
+    pos: Vec<VxPos>,
+    /// The UV coordinates.
+    uv:  Vec<Uv>
+}
+
+impl MeshBuffers
+{
+    /// Adds the vertexes in `iter`.
+    #[inline]
+    pub fn push_positions_skewed(&mut self, grid: &Grid, iter: impl IntoIterator<Item = Vec2>)
+    {
+        self.pos
+            .extend(iter.into_iter().map(|vx| grid.transform_point(vx).as_f32x3()));
+    }
+
+    /// Sets the texture UV.
+    #[inline]
+    pub fn set_texture_uv<T: TextureInterface>(
+        &mut self,
+        resources: &DrawingResources,
+        camera_pos: Vec2,
+        settings: &T,
+        elapsed_time: f32
+    )
+    {
+        push_texture_uv(
+            resources,
+            &self.pos,
+            &mut self.uv,
+            camera_pos,
+            settings,
+            elapsed_time,
+            common_texture_uv_coordinate
+        );
+    }
+
+    /// Sets the UV to the one of an animated texture.
+    #[inline]
+    pub fn set_animated_texture_uv<T: TextureInterface>(
+        &mut self,
+        resources: &DrawingResources,
+        camera_pos: Vec2,
+        settings: &T,
+        animator: &AtlasAnimator,
+        elapsed_time: f32
+    )
+    {
+        push_animated_texture_uv(
+            resources,
+            &self.pos,
+            &mut self.uv,
+            camera_pos,
+            settings,
+            animator,
+            elapsed_time
+        );
+    }
+}
+
+//=======================================================================//
+
 /// A wrapper to a mutable reference to a [`Texture`].
 #[must_use]
 pub(in crate::map) struct TextureMut<'a>
@@ -1705,3 +2013,89 @@ where
         }
     }
 }
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Pushes into `uv` the UV coordinates of the vertexes in `pos` based on `f`.
+#[inline]
+fn push_texture_uv<T: TextureInterface, F>(
+    resources: &DrawingResources,
+    pos: &[VxPos],
+    uv: &mut Vec<Uv>,
+    camera_pos: Vec2,
+    settings: &T,
+    elapsed_time: f32,
+    f: F
+) where
+    F: Fn([f32; 2], Vec2, Vec2) -> Uv
+{
+    let offset = settings.draw_offset_with_parallax_and_scroll(camera_pos, elapsed_time);
+    let size_scale_mod = resources.texture_or_error(settings.name()).size().as_vec2() *
+        Vec2::new(settings.scale_x(), settings.scale_y());
+    let angle = settings.angle();
+
+    if angle != 0f32
+    {
+        let angle = angle.to_radians();
+
+        uv.extend(pos.iter().map(|vx| {
+            f(
+                rotate_point_around_origin([vx[0], vx[1]].into(), angle).to_array(),
+                offset,
+                size_scale_mod
+            )
+        }));
+
+        return;
+    }
+
+    uv.extend(pos.iter().map(|vx| f([vx[0], vx[1]], offset, size_scale_mod)));
+}
+
+//=======================================================================//
+
+/// Pushes into `uv` the UV coordinates of the vertexes in `pos` mapped to the current frame of
+/// `animator`.
+#[inline]
+fn push_animated_texture_uv<T: TextureInterface>(
+    resources: &DrawingResources,
+    pos: &[VxPos],
+    uv: &mut Vec<Uv>,
+    camera_pos: Vec2,
+    settings: &T,
+    animator: &AtlasAnimator,
+    elapsed_time: f32
+)
+{
+    let pivot = animator.pivot();
+
+    push_texture_uv(
+        resources,
+        pos,
+        uv,
+        camera_pos,
+        settings,
+        elapsed_time,
+        |vx, offset, size_scale_mod| {
+            let mut uv = common_texture_uv_coordinate(vx, offset, size_scale_mod);
+            uv.translate(&pivot);
+            uv
+        }
+    );
+}
+
+//=======================================================================//
+
+/// Returns the UV coordinate of the vertex `vx`.
+#[inline]
+#[must_use]
+fn common_texture_uv_coordinate(vx: [f32; 2], offset: Vec2, size_scale_mod: Vec2) -> Uv
+{
+    [
+        (vx[0] + offset.x) / size_scale_mod.x,
+        (-(vx[1] + offset.y)) / size_scale_mod.y
+    ]
+}
@@ -130,6 +130,7 @@ pub(in crate::map) struct OverallTextureSettings
     offset_y:  OverallValue<f32>,
     angle:     OverallValue<f32>,
     height:    OverallValue<i8>,
+    opacity:   OverallValue<i8>,
     sprite:    OverallSprite,
     animation: OverallAnimation
 }
@@ -151,6 +152,7 @@ impl From<Option<&TextureSettings>> for OverallTextureSettings
                     offset_x:  value.offset_x().into(),
                     offset_y:  value.offset_y().into(),
                     height:    value.height().into(),
+                    opacity:   i8::try_from(value.opacity()).unwrap().into(),
                     angle:     value.angle().into(),
                     sprite:    value.sprite_struct().into(),
                     animation: value.animation().into()
@@ -165,6 +167,7 @@ impl From<Option<&TextureSettings>> for OverallTextureSettings
                     offset_x:  OverallValue::None,
                     offset_y:  OverallValue::None,
                     height:    OverallValue::None,
+                    opacity:   OverallValue::None,
                     angle:     OverallValue::None,
                     sprite:    OverallSprite::None,
                     animation: OverallAnimation::NoSelection
@@ -238,6 +241,7 @@ impl OverallValueInterface<Option<&TextureSettings>> for OverallTextureSettings
         }
 
         uniform |= !self.height.merge_override(other.height) |
+            !self.opacity.merge_override(other.opacity) |
             !self.sprite.merge(other.sprite) |
             !self.animation.merge(other.animation);
 
@@ -253,6 +257,7 @@ impl OverallValueInterface<Option<&TextureSettings>> for OverallTextureSettings
             self.offset_x.is_not_uniform() &&
             self.offset_y.is_not_uniform() &&
             self.height.is_not_uniform() &&
+            self.opacity.is_not_uniform() &&
             self.angle.is_not_uniform() &&
             self.sprite.is_not_uniform() &&
             self.animation.is_not_uniform()
@@ -282,6 +287,7 @@ pub(in crate::map) struct UiOverallTextureSettings
     pub scroll_x:   Option<UiOverallValue<f32>>,
     pub scroll_y:   Option<UiOverallValue<f32>>,
     pub height:     UiOverallValue<i8>,
+    pub opacity:    UiOverallValue<i8>,
     pub angle:      UiOverallValue<f32>,
     pub sprite:     OverallValue<bool>,
     pub parallax_x: Option<UiOverallValue<f32>>,
@@ -326,6 +332,7 @@ impl From<OverallTextureSettings> for UiOverallTextureSettings
             scroll_x,
             scroll_y,
             height: value.height.ui(),
+            opacity: value.opacity.ui(),
             angle: value.angle.ui(),
             sprite,
             parallax_x,
@@ -3,11 +3,22 @@
 //
 //=======================================================================//
 
+use std::ops::RangeInclusive;
+
 use glam::Vec2;
+use hill_vacuum_shared::TEXTURE_HEIGHT_RANGE;
 use serde::{Deserialize, Serialize};
 
 use crate::{utils::math::points::rotate_point_around_origin, Animation};
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The range of the values the opacity of a texture can assume, in percentage.
+pub(crate) const TEXTURE_OPACITY_RANGE: RangeInclusive<u8> = 0..=100;
+
 //=======================================================================//
 // MACROS
 //
@@ -113,6 +124,10 @@ pub trait TextureInterface
     #[must_use]
     fn height_f32(&self) -> f32;
 
+    /// The opacity, in percentage, from 0 (invisible) to 100 (opaque).
+    #[must_use]
+    fn opacity(&self) -> u8;
+
     /// Whether the texture should be rendered like a sprite
     #[must_use]
     fn sprite(&self) -> bool;
@@ -179,6 +194,8 @@ pub struct TextureSettings
     offset_y:  f32,
     angle:     f32,
     height:    i8,
+    #[serde(default = "full_opacity")]
+    opacity:   u8,
     sprite:    Sprite,
     animation: Animation
 }
@@ -243,6 +260,9 @@ impl TextureInterface for TextureSettings
     #[inline]
     fn height_f32(&self) -> f32 { f32::from(self.height) }
 
+    #[inline]
+    fn opacity(&self) -> u8 { self.opacity }
+
     #[inline]
     fn angle(&self) -> f32 { self.angle }
 
@@ -255,6 +275,75 @@ impl TextureInterface for TextureSettings
 
 impl TextureSettings
 {
+    /// Returns a new [`TextureSettings`] with texture `texture`, which is drawn filling the
+    /// surface of the brush, and the requested parameters.
+    /// # Errors
+    /// Returns an error if `offset` is not finite, either component of `scale` is zero or not
+    /// finite, `angle` is not within 0 and 360 degrees, or `height` is out of the allowed range.
+    #[inline]
+    pub fn try_new(
+        texture: impl Into<String>,
+        offset: Vec2,
+        scale: Vec2,
+        angle: f32,
+        height: i8
+    ) -> Result<Self, &'static str>
+    {
+        if !offset.is_finite()
+        {
+            return Err("Texture offset is not finite.");
+        }
+
+        if !scale.is_finite() || scale.x == 0f32 || scale.y == 0f32
+        {
+            return Err("Texture scale is zero or not finite.");
+        }
+
+        if !(0f32..360f32).contains(&angle)
+        {
+            return Err("Texture angle is not within 0 and 360 degrees.");
+        }
+
+        if !TEXTURE_HEIGHT_RANGE.contains(&height)
+        {
+            return Err("Texture height is out of range.");
+        }
+
+        Ok(Self {
+            texture: texture.into(),
+            scale_x: scale.x,
+            scale_y: scale.y,
+            offset_x: offset.x,
+            offset_y: offset.y,
+            angle,
+            height,
+            opacity: *TEXTURE_OPACITY_RANGE.end(),
+            sprite: Sprite::False {
+                parallax_x:       0f32,
+                parallax_y:       0f32,
+                scroll_x:         0f32,
+                scroll_y:         0f32,
+                offset_auxiliary: OffsetAuxiliary::default()
+            },
+            animation: Animation::None
+        })
+    }
+
+    /// Returns `self` with the opacity, in percentage, set to `opacity`.
+    /// # Errors
+    /// Returns an error if `opacity` is greater than 100.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: u8) -> Result<Self, &'static str>
+    {
+        if !TEXTURE_OPACITY_RANGE.contains(&opacity)
+        {
+            return Err("Texture opacity is out of range.");
+        }
+
+        self.opacity = opacity;
+        Ok(self)
+    }
+
     /// Sets the [`Animation`] without checking the map bounds.
     #[inline]
     pub(in crate::map) unsafe fn unsafe_set_animation(&mut self, animation: Animation)
@@ -306,6 +395,16 @@ pub(in crate::map) struct DefaultAnimation
     pub animation: Animation
 }
 
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// The opacity of the textures stored in the files saved before it was introduced.
+#[inline]
+#[must_use]
+const fn full_opacity() -> u8 { *TEXTURE_OPACITY_RANGE.end() }
+
 //=======================================================================//
 // UI
 //
@@ -326,7 +425,7 @@ pub(in crate::map) mod ui_mod
     use glam::{UVec2, Vec2};
     use hill_vacuum_shared::{match_or_panic, return_if_none, TEXTURE_HEIGHT_RANGE};
 
-    use super::{OffsetAuxiliary, RotationOffset, Sprite};
+    use super::{OffsetAuxiliary, RotationOffset, Sprite, TEXTURE_OPACITY_RANGE};
     use crate::{
         map::{
             brush::convex_polygon::ScaleInfo,
@@ -938,6 +1037,14 @@ pub(in crate::map) mod ui_mod
         #[must_use]
         pub fn label(&self) -> &str { &self.label }
 
+        /// Renames the texture to `name`.
+        #[inline]
+        pub(in crate::map::drawer) fn rename(&mut self, name: &str)
+        {
+            name.clone_into(&mut self.name);
+            self.label = Self::format_label(name, self.size);
+        }
+
         /// The size of the texture.
         #[inline]
         #[must_use]
@@ -1002,6 +1109,7 @@ pub(in crate::map) mod ui_mod
                 offset_y:  0f32,
                 angle:     0f32,
                 height:    0,
+                opacity:   *TEXTURE_OPACITY_RANGE.end(),
                 sprite:    Sprite::default(),
                 animation: Animation::None
             }
@@ -1090,6 +1198,33 @@ pub(in crate::map) mod ui_mod
     {
         xy!(x, y);
 
+        /// Returns a new [`TextureSettings`] with texture `texture`, which is not required to be
+        /// loaded, and the requested parameters.
+        #[inline]
+        pub(in crate::map) fn from_parts(
+            texture: String,
+            offset: Vec2,
+            scale: Vec2,
+            angle: f32,
+            height: i8
+        ) -> Self
+        {
+            angle.assert_normalized_degrees_angle();
+
+            Self {
+                texture,
+                scale_x: scale.x,
+                scale_y: scale.y,
+                offset_x: offset.x,
+                offset_y: offset.y,
+                angle,
+                height,
+                opacity: *TEXTURE_OPACITY_RANGE.end(),
+                sprite: Sprite::default(),
+                animation: Animation::None
+            }
+        }
+
         #[inline]
         pub(in crate::map::drawer) const fn sprite_struct(&self) -> &Sprite { &self.sprite }
 
@@ -1119,7 +1254,7 @@ pub(in crate::map) mod ui_mod
         ) -> bool
         {
             self.sprite_vxs(drawing_resources, grid, new_center)
-                .map_or(false, |hull| {
+                .is_some_and(|hull| {
                     !hull.into_iter().any(|vx| grid.point_projection(vx).out_of_bounds())
                 })
         }
@@ -1235,6 +1370,23 @@ pub(in crate::map) mod ui_mod
             result
         }
 
+        /// Replaces the texture `prev` with `new`, both as the drawn texture and in the frames of
+        /// the list animation. Returns whether anything was changed.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+        {
+            let mut renamed = self.animation.rename_texture(prev, new);
+
+            if self.texture == prev
+            {
+                new.clone_into(&mut self.texture);
+                renamed = true;
+            }
+
+            renamed
+        }
+
         /// Sets the texture, returns the previous value if different.
         #[inline]
         pub(in crate::map) fn set_texture(
@@ -1283,6 +1435,21 @@ pub(in crate::map) mod ui_mod
             self.height.replace_value(value).into()
         }
 
+        /// Sets the opacity, returns the previous value if different.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn set_opacity(&mut self, value: u8) -> Option<u8>
+        {
+            assert!(TEXTURE_OPACITY_RANGE.contains(&value), "Invalid opacity value.");
+
+            if value == self.opacity
+            {
+                return None;
+            }
+
+            self.opacity.replace_value(value).into()
+        }
+
         /// Whether the new angle is valid.
         #[inline]
         pub(in crate::map) fn check_angle(
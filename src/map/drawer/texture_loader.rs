@@ -5,7 +5,8 @@
 
 use std::{
     path::{Path, PathBuf},
-    sync::{Arc, Mutex}
+    sync::{Arc, Mutex},
+    time::SystemTime
 };
 
 use arrayvec::ArrayVec;
@@ -18,12 +19,16 @@ use bevy::{
     window::Window
 };
 use bevy_egui::{egui, EguiUserTextures};
+use hill_vacuum_shared::continue_if_none;
 use threadpool::ThreadPool;
 
 use super::texture::Texture;
 use crate::{
     map::editor::state::ui::centered_window,
-    utils::misc::{ReplaceValue, TakeValue}
+    utils::{
+        collections::{hash_map, HashMap},
+        misc::{ReplaceValue, TakeValue}
+    }
 };
 
 //=======================================================================//
@@ -104,7 +109,11 @@ pub(in crate::map) struct TextureLoader
     /// The frames required to read the files.
     file_reading_cycles: usize,
     total_cycles:        f32,
-    first_load:          bool
+    first_load:          bool,
+    /// The modification times of the files in the textures folder at the time of the last check.
+    files:               HashMap<PathBuf, SystemTime>,
+    /// The files added or modified since the previous load, if only those are being loaded.
+    changed_files:       Option<Vec<PathBuf>>
 }
 
 impl Default for TextureLoader
@@ -123,7 +132,9 @@ impl Default for TextureLoader
             cycles:              0,
             file_reading_cycles: 0,
             total_cycles:        0f32,
-            first_load:          true
+            first_load:          true,
+            files:               hash_map![],
+            changed_files:       None
         }
     }
 }
@@ -148,9 +159,194 @@ impl TextureLoader
     pub fn loaded_textures(&mut self) -> Vec<(Texture, egui::TextureId)>
     {
         assert!(matches!(self.images, LoadedImages::Empty), "Texture load in progress.");
+        self.changed_files = None;
         self.textures.take_value()
     }
 
+    /// Whether the textures being loaded are only the ones generated from the files added or
+    /// modified since the previous load.
+    #[inline]
+    #[must_use]
+    pub const fn partial_load(&self) -> bool { self.changed_files.is_some() }
+
+    /// Returns the paths of the files in the textures folder and its subfolders, associated with
+    /// their modification time.
+    #[inline]
+    #[must_use]
+    fn files_modification_times() -> HashMap<PathBuf, SystemTime>
+    {
+        /// Iterates through the subfolders collecting the modification times of the files.
+        #[inline]
+        fn collect_times_recursive(path: &Path, times: &mut HashMap<PathBuf, SystemTime>)
+        {
+            for child_path in std::fs::read_dir(path)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            {
+                if child_path.is_dir()
+                {
+                    collect_times_recursive(&child_path, times);
+                    continue;
+                }
+
+                let time = continue_if_none!(std::fs::metadata(&child_path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok());
+                times.insert(child_path, time);
+            }
+        }
+
+        let mut times = hash_map![];
+        collect_times_recursive(Path::new(TEXTURES_PATH), &mut times);
+        times
+    }
+
+    /// Checks whether files were added to, or modified in, the textures folder since the last
+    /// check. If that is the case the next load only involves those files.
+    /// Returns whether there are files to load.
+    #[inline]
+    #[must_use]
+    pub fn check_changed_files(&mut self) -> bool
+    {
+        let files = Self::files_modification_times();
+        let changed = files
+            .iter()
+            .filter(|(path, time)| self.files.get(*path) != Some(*time))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        self.files = files;
+
+        if changed.is_empty()
+        {
+            return false;
+        }
+
+        self.changed_files = changed.into();
+        true
+    }
+
+    /// Returns the absolute paths of the image files in the textures folder, associated with the
+    /// names of the textures generated from them.
+    #[inline]
+    #[must_use]
+    pub fn texture_paths() -> HashMap<String, PathBuf>
+    {
+        /// Iterates through the subfolders collecting the paths of the textures.
+        #[inline]
+        fn collect_paths_recursive<P: AsRef<Path>>(path: P, paths: &mut HashMap<String, PathBuf>)
+        {
+            for child_path in std::fs::read_dir(path)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            {
+                if child_path.is_dir()
+                {
+                    collect_paths_recursive(child_path, paths);
+                    continue;
+                }
+
+                let name = continue_if_none!(child_path.file_stem().and_then(|name| name.to_str()))
+                    .to_owned();
+                paths.insert(name, std::fs::canonicalize(&child_path).unwrap_or(child_path));
+            }
+        }
+
+        let mut paths = hash_map![];
+        collect_paths_recursive(TEXTURES_PATH, &mut paths);
+        paths
+    }
+
+    /// Returns the folders containing the image files in the textures folder, relative to it and
+    /// separated by `/`, associated with the names of the textures generated from them. The
+    /// textures in the textures folder itself are associated with an empty string.
+    #[inline]
+    #[must_use]
+    pub fn texture_folders() -> HashMap<String, String>
+    {
+        /// Iterates through the subfolders collecting the folders of the textures.
+        #[inline]
+        fn collect_folders_recursive(
+            path: &Path,
+            folder: &str,
+            folders: &mut HashMap<String, String>
+        )
+        {
+            for child_path in std::fs::read_dir(path)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            {
+                if child_path.is_dir()
+                {
+                    let name = continue_if_none!(child_path.file_name().and_then(|n| n.to_str()));
+                    let child_folder = if folder.is_empty()
+                    {
+                        name.to_owned()
+                    }
+                    else
+                    {
+                        format!("{folder}/{name}")
+                    };
+
+                    collect_folders_recursive(&child_path, &child_folder, folders);
+                    continue;
+                }
+
+                let name = continue_if_none!(child_path.file_stem().and_then(|name| name.to_str()))
+                    .to_owned();
+                folders.insert(name, folder.to_owned());
+            }
+        }
+
+        let mut folders = hash_map![];
+        collect_folders_recursive(Path::new(TEXTURES_PATH), "", &mut folders);
+        folders
+    }
+
+    /// Renames the image file of the texture `prev` so that the texture generated from it is
+    /// named `new`. The folder and the extension of the file are retained.
+    /// # Errors
+    /// Returns an error if the file could not be found or renamed, or if there already is a
+    /// texture named `new`.
+    #[inline]
+    pub fn rename_texture_file(prev: &str, new: &str) -> Result<(), &'static str>
+    {
+        let paths = Self::texture_paths();
+
+        if paths.contains_key(new)
+        {
+            return Err("A texture with the same name already exists.");
+        }
+
+        let path = paths.get(prev).ok_or("Could not find the texture file.")?;
+        let mut new_path = path.with_file_name(new);
+
+        if let Some(extension) = path.extension()
+        {
+            new_path.set_extension(extension);
+        }
+
+        std::fs::rename(path, new_path).map_err(|_| "Error renaming the texture file.")
+    }
+
+    /// Reads the [`Image`] stored in the file at `path`, if possible.
+    #[inline]
+    #[must_use]
+    fn read_image(path: &Path) -> Option<Image>
+    {
+        Image::from_buffer(
+            &std::fs::read(path).ok()?,
+            ImageType::Extension(path.extension()?.to_str()?),
+            CompressedImageFormats::all(),
+            true,
+            ImageSampler::default(),
+            RenderAssetUsages::all()
+        )
+        .ok()
+    }
+
     /// Extracts the vector inside `images`.
     #[inline]
     fn extract_images(mut images: PartialImages) -> Vec<(String, Image)>
@@ -189,7 +385,24 @@ impl TextureLoader
 
         let mut paths = Self::DEFAULT_PATHS;
         let mut textures_len = 0;
-        collect_paths_recursive(TEXTURES_PATH, &mut paths, &mut textures_len);
+
+        match &self.changed_files
+        {
+            Some(files) =>
+            {
+                for path in files
+                {
+                    paths[textures_len % Self::THREADS_AMOUNT].push(path.clone());
+                    textures_len += 1;
+                }
+            },
+            None =>
+            {
+                collect_paths_recursive(TEXTURES_PATH, &mut paths, &mut textures_len);
+                self.files = Self::files_modification_times();
+            }
+        };
+
         self.active_workers = 0;
 
         for vec in &paths
@@ -213,6 +426,7 @@ impl TextureLoader
 
     /// Loads the textures.
     #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_precision_loss)]
     #[allow(clippy::cast_sign_loss)]
     #[inline]
     pub fn load(
@@ -222,6 +436,8 @@ impl TextureLoader
         load_state: &mut NextState<TextureLoadingProgress>
     )
     {
+        let partial = self.partial_load();
+
         match &mut self.images
         {
             LoadedImages::Empty =>
@@ -254,20 +470,18 @@ impl TextureLoader
                         for j in range
                         {
                             let path = &paths[i][j];
+                            let image = match Self::read_image(path)
+                            {
+                                Some(image) => image,
+                                // Files that are being written or are not images are skipped
+                                // during partial loads, they are loaded once modified again.
+                                None if partial => continue,
+                                None => panic!("Error loading texture file {}.", path.display())
+                            };
 
                             textures.push((
                                 path.file_stem().unwrap().to_str().unwrap().to_owned(),
-                                Image::from_buffer(
-                                    &std::fs::read(path).unwrap(),
-                                    ImageType::Extension(
-                                        path.extension().unwrap().to_str().unwrap()
-                                    ),
-                                    CompressedImageFormats::all(),
-                                    true,
-                                    ImageSampler::default(),
-                                    RenderAssetUsages::all()
-                                )
-                                .unwrap()
+                                image
                             ));
                         }
 
@@ -280,7 +494,12 @@ impl TextureLoader
 
                 if self.cycles == self.file_reading_cycles
                 {
-                    self.images = LoadedImages::Loaded(Self::extract_images(std::mem::take(vec)));
+                    let vec = Self::extract_images(std::mem::take(vec));
+                    // Skipped files do not generate textures.
+                    self.total_cycles = (self.cycles +
+                        vec.len().div_ceil(Self::PER_FRAME_TEXTURE_GENERATION).max(1))
+                        as f32;
+                    self.images = LoadedImages::Loaded(vec);
                     load_state.set(TextureLoadingProgress::GeneratingTextures);
                 }
             },
@@ -315,6 +534,12 @@ impl TextureLoader
     #[inline]
     pub fn ui(&self, window: &Window, egui_context: &egui::Context)
     {
+        // Partial loads only take a few frames.
+        if self.partial_load()
+        {
+            return;
+        }
+
         let mut ui_window = centered_window(window, "Loading textures...");
 
         if self.first_load
@@ -5,8 +5,9 @@
 
 use bevy::{transform::components::Transform, window::Window};
 use glam::Vec2;
+use hill_vacuum_shared::return_if_none;
 
-use super::state::editor_state::State;
+use super::state::{editor_state::State, manager::EntitiesManager};
 use crate::{
     map::{editor::state::grid::Grid, BoundToMap, MAP_HALF_SIZE},
     utils::{hull::Hull, misc::Camera}
@@ -36,10 +37,18 @@ pub(in crate::map::editor) struct Cursor
     grid_square:            Hull,
     /// Whether the cursor is set to be snapped to the grid.
     snap:                   bool,
+    /// The granularity the cursor is snapped with.
+    snap_size:              i16,
+    /// The position of the cursor on the map snapped according to the path nodes snap setting.
+    world_path_snapped:     Vec2,
+    /// The granularity the path nodes are snapped with, if any.
+    path_snap_size:         Option<i16>,
     /// The position of the cursor on the map in the previous frame.
     previous_world:         Vec2,
     /// The position of the cursor on the map snapped to the grid in the previous frame.
-    previous_world_snapped: Vec2
+    previous_world_snapped: Vec2,
+    /// Whether the cursor was last moved with the directional keys.
+    keyboard_moved:         bool
 }
 
 impl Default for Cursor
@@ -61,8 +70,12 @@ impl Default for Cursor
             delta_ui:               Vec2::ZERO,
             grid_square:            Grid::default().square(START_POS),
             snap:                   true,
+            snap_size:              Grid::default().size(),
+            world_path_snapped:     START_POS,
+            path_snap_size:         Some(Grid::default().size()),
             previous_world:         START_POS,
-            previous_world_snapped: START_POS
+            previous_world_snapped: START_POS,
+            keyboard_moved:         false
         }
     }
 }
@@ -74,6 +87,11 @@ impl Cursor
     #[must_use]
     pub fn moved(&self) -> bool { self.delta_ui != Vec2::ZERO }
 
+    /// Returns the position of the cursor with respect to the application window.
+    #[inline]
+    #[must_use]
+    pub const fn ui(&self) -> Vec2 { self.ui }
+
     /// Returns the position of the cursor on the map.
     #[inline]
     #[must_use]
@@ -126,11 +144,36 @@ impl Cursor
     #[must_use]
     pub const fn delta_ui(&self) -> Vec2 { self.delta_ui }
 
+    /// Whether the cursor was last moved with the directional keys.
+    #[inline]
+    #[must_use]
+    pub const fn keyboard_moved(&self) -> bool { self.keyboard_moved }
+
+    /// Sets the cursor as moved with the directional keys, until it is moved with the mouse.
+    #[inline]
+    pub fn set_keyboard_moved(&mut self) { self.keyboard_moved = true; }
+
     /// Whether grid snap is enabled.
     #[inline]
     #[must_use]
     pub const fn snap(&self) -> bool { self.snap }
 
+    /// The granularity the cursor is snapped with.
+    #[inline]
+    #[must_use]
+    pub const fn snap_size(&self) -> i16 { self.snap_size }
+
+    /// Returns the position of the cursor on the map snapped according to the path nodes snap
+    /// setting.
+    #[inline]
+    #[must_use]
+    pub const fn world_path_snapped(&self) -> Vec2 { self.world_path_snapped }
+
+    /// The granularity the path nodes are snapped with, if any.
+    #[inline]
+    #[must_use]
+    pub const fn path_snap_size(&self) -> Option<i16> { self.path_snap_size }
+
     /// Updates the values of `self` based on the `window` size, the `camera` position and scale,
     /// and the current editor state. Whenever space is being pressed, and therefore the camera
     /// is being dragged around, only the UI position is updated.
@@ -141,6 +184,7 @@ impl Cursor
         window: &Window,
         camera: &Transform,
         state: &State,
+        manager: &EntitiesManager,
         grid: &Grid,
         space_pressed: bool
     )
@@ -150,6 +194,11 @@ impl Cursor
         self.delta_ui = ui - self.ui;
         self.ui = ui;
 
+        if self.moved()
+        {
+            self.keyboard_moved = false;
+        }
+
         if space_pressed
         {
             return;
@@ -160,10 +209,41 @@ impl Cursor
         self.world = camera.to_world_coordinates(window, grid, ui).bound();
         self.world_no_grid = camera.to_world_coordinates(window, &grid.absolute(), ui).bound();
 
-        self.grid_square = grid.square(self.world.clamp(-SQUARE_BOUND, SQUARE_BOUND));
+        self.snap_size = state.tools_settings().snap_size(grid, camera.scale());
+        self.grid_square = grid
+            .with_size(self.snap_size)
+            .square(self.world.clamp(-SQUARE_BOUND, SQUARE_BOUND));
         self.world_grid_snapped = self.grid_square.nearest_corner_to_point(self.world);
         let p = camera.to_egui_coordinates(window, grid, self.world_grid_snapped);
         self.ui_grid_snapped = Vec2::new(p.x, p.y);
         self.snap = state.cursor_snap();
+
+        self.path_snap_size =
+            state.tools_settings().path_snap_size(grid, camera.scale(), self.snap);
+        self.world_path_snapped = match self.path_snap_size
+        {
+            Some(size) if size == self.snap_size => self.world_grid_snapped,
+            Some(size) =>
+            {
+                grid.with_size(size)
+                    .square(self.world.clamp(-SQUARE_BOUND, SQUARE_BOUND))
+                    .nearest_corner_to_point(self.world)
+            },
+            None => self.world
+        };
+
+        let target = return_if_none!(state.tools_settings().path_snap_target(
+            manager,
+            self.world,
+            camera.scale()
+        ));
+        self.world_grid_snapped = target;
+        let p = camera.to_egui_coordinates(window, grid, target);
+        self.ui_grid_snapped = Vec2::new(p.x, p.y);
+
+        if self.path_snap_size.is_some()
+        {
+            self.world_path_snapped = target;
+        }
     }
 }
@@ -0,0 +1,100 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::path::PathBuf;
+
+use bevy::ecs::{event::Event, schedule::SystemSet};
+
+use crate::Id;
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The [`SystemSet`]s of the editor, running in the `Update` schedule while the editor is
+/// running. Systems reacting to the editor events should be ordered after
+/// [`HillVacuumSet::Update`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HillVacuumSet
+{
+    /// The update of the editor state, after which the events of the frame are sent.
+    Update,
+    /// The drawing of the visible portion of the map.
+    Draw
+}
+
+//=======================================================================//
+
+/// An event involving an entity of the map being edited, waiting to be sent.
+#[derive(Clone, Copy)]
+pub(in crate::map::editor) enum EntityEvent
+{
+    /// The entity was spawned.
+    Spawned(Id),
+    /// The entity was despawned.
+    Despawned(Id)
+}
+
+//=======================================================================//
+
+/// An event involving the history of the map being edited, waiting to be sent.
+pub(in crate::map::editor) enum HistoryEvent
+{
+    /// An edit with the contained tag was committed.
+    EditCommitted(String),
+    /// The map was saved to the contained path.
+    MapSaved(PathBuf)
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// Sent when a brush or a [`ThingInstance`](crate::ThingInstance) is added to the map being
+/// edited, also through undo and redo.
+#[must_use]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct EntitySpawned
+{
+    /// The [`Id`] of the entity.
+    pub id: Id
+}
+
+//=======================================================================//
+
+/// Sent when a brush or a [`ThingInstance`](crate::ThingInstance) is removed from the map being
+/// edited, also through undo and redo.
+#[must_use]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct EntityDespawned
+{
+    /// The [`Id`] of the entity.
+    pub id: Id
+}
+
+//=======================================================================//
+
+/// Sent when an edit which is not a mere selection change is added to the history of the map
+/// being edited.
+#[must_use]
+#[derive(Event, Clone, Debug)]
+pub struct EditCommitted
+{
+    /// The tag of the edit, as shown in the edits history window.
+    pub tag: String
+}
+
+//=======================================================================//
+
+/// Sent when the map being edited is saved to file.
+#[must_use]
+#[derive(Event, Clone, Debug)]
+pub struct MapSaved
+{
+    /// The path of the file.
+    pub path: PathBuf
+}
@@ -1,4 +1,5 @@
 mod cursor;
+pub mod events;
 pub mod state;
 
 //=======================================================================//
@@ -6,11 +7,13 @@ pub mod state;
 //
 //=======================================================================//
 
+use std::ops::RangeInclusive;
+
 use bevy::{
     asset::{AssetServer, Assets},
     ecs::{
         entity::Entity,
-        event::EventReader,
+        event::{EventReader, EventWriter},
         query::With,
         system::{Commands, Query}
     },
@@ -21,7 +24,7 @@ use bevy::{
         ButtonInput
     },
     prelude::{Mesh2d, Resource},
-    render::mesh::Mesh,
+    render::{camera::Viewport, mesh::Mesh},
     sprite::ColorMaterial,
     state::state::NextState,
     time::Time,
@@ -29,18 +32,22 @@ use bevy::{
     window::Window
 };
 use bevy_egui::{egui, EguiUserTextures};
-use glam::Vec2;
+use events::{EditCommitted, EntityDespawned, EntityEvent, EntitySpawned, HistoryEvent, MapSaved};
+use glam::{UVec2, Vec2};
+use hill_vacuum_shared::return_if_none;
 use state::{
     clipboard::Clipboard,
     edits_history::EditsHistory,
     grid::Grid,
     inputs_presses::InputsPresses,
     manager::EntitiesManager,
-    ui::{ui_camera_displacement, UiFocus}
+    tabs::EditedMap,
+    ui::{ui_camera_displacement, SplitView, UiFocus}
 };
 
 use self::state::clipboard::{PropCameras, PropCamerasMut};
 use super::{
+    camera::{zoom_scale, ZOOM_PRESETS},
     drawer::{
         color::ColorResources,
         drawers::{EditDrawer, MapPreviewDrawer},
@@ -59,17 +66,28 @@ use super::{
         ThingUserProperties
     },
     thing::{catalog::ThingsCatalog, HardcodedThings},
-    BoundToMap
+    BoundToMap,
+    UserManualSections,
+    UserPropertySchemas
 };
 use crate::{
-    config::{controls::BindsKeyCodes, Config},
+    config::{
+        controls::{
+            bind::Bind,
+            mouse::{MouseBinds, WheelAction},
+            BindsKeyCodes
+        },
+        navigation::NavigationSettings,
+        Config
+    },
     map::editor::{cursor::Cursor, state::editor_state::State},
     utils::{
         math::AroundEqual,
         misc::{Camera, TakeValue}
     },
     EditorState,
-    HardcodedActions
+    HardcodedActions,
+    PropertySchemas
 };
 
 //=======================================================================//
@@ -124,6 +142,7 @@ struct StateUpdateBundle<'world, 'state, 'a, 'b, 'c>
     things_catalog:     &'b mut ThingsCatalog,
     drawing_resources:  &'b mut DrawingResources,
     default_properties: &'b mut AllDefaultProperties<'b>,
+    property_schemas:   &'b PropertySchemas,
     manager:            &'b mut EntitiesManager,
     clipboard:          &'b mut Clipboard,
     edits_history:      &'b mut EditsHistory,
@@ -175,7 +194,8 @@ struct DrawBundle<'world, 'state, 'w, 's, 'a, 'b, 'c>
     things_catalog:    &'b ThingsCatalog,
     cursor:            &'b Cursor,
     manager:           &'b mut EntitiesManager,
-    clipboard:         &'b Clipboard
+    clipboard:         &'b Clipboard,
+    edits_history:     &'b EditsHistory
 }
 
 //=======================================================================//
@@ -216,6 +236,8 @@ pub(in crate::map) struct Editor
     map_default_brush_properties: DefaultBrushProperties,
     /// The defined default [`ThingInstance`] properties to be used for the currently opened map.
     map_default_thing_properties: DefaultThingProperties,
+    /// The schemas the values of the properties are validated against.
+    property_schemas: PropertySchemas,
     /// The manager of all entities.
     manager: EntitiesManager,
     /// The clipboard used for copy paste and prop spawning.
@@ -225,7 +247,14 @@ pub(in crate::map) struct Editor
     /// The state of all necessary input presses.
     inputs: InputsPresses,
     /// The grid of the map.
-    grid: Grid
+    grid: Grid,
+    /// The camera of the second viewport, if shown. Like the main camera, it is placed as if it
+    /// covered the whole window.
+    viewport_camera: Option<Transform>,
+    /// Whether the second viewport is the one receiving the inputs.
+    viewport_focused: bool,
+    /// Whether the view is being dragged around with the pan mouse button.
+    panning: bool
 }
 
 impl Placeholder for Editor
@@ -243,11 +272,15 @@ impl Placeholder for Editor
                 engine_default_thing_properties: EngineDefaultThingProperties::default(),
                 map_default_brush_properties: DefaultBrushProperties::default(),
                 map_default_thing_properties: DefaultThingProperties::default(),
+                property_schemas: PropertySchemas::default(),
                 manager: EntitiesManager::new(),
                 clipboard: Clipboard::new(),
                 edits_history: EditsHistory::default(),
                 inputs: InputsPresses::default(),
-                grid: Grid::default()
+                grid: Grid::default(),
+                viewport_camera: None,
+                viewport_focused: false,
+                panning: false
             }
         }
     }
@@ -270,7 +303,9 @@ impl Editor
         texture_loader: &mut TextureLoader,
         hardcoded_things: &mut HardcodedThings,
         brush_properties: &mut BrushUserProperties,
-        thing_properties: &mut ThingUserProperties
+        thing_properties: &mut ThingUserProperties,
+        manual_sections: &UserManualSections,
+        property_schemas: &mut UserPropertySchemas
     ) -> Self
     {
         let mut drawing_resources = DrawingResources::new(
@@ -312,6 +347,7 @@ impl Editor
             &drawing_resources,
             &things_catalog,
             &mut default_properties,
+            manual_sections,
             path
         );
 
@@ -332,11 +368,15 @@ impl Editor
             engine_default_thing_properties: default_thing_properties,
             map_default_brush_properties,
             map_default_thing_properties,
+            property_schemas: property_schemas.0.take_value(),
             manager,
             clipboard,
             edits_history,
             inputs: InputsPresses::default(),
-            grid
+            grid,
+            viewport_camera: None,
+            viewport_focused: false,
+            panning: false
         }
     }
 
@@ -401,6 +441,7 @@ impl Editor
                 map_brushes:    &mut self.map_default_brush_properties,
                 map_things:     &mut self.map_default_thing_properties
             },
+            property_schemas: &self.property_schemas,
             manager: &mut self.manager,
             clipboard: &mut self.clipboard,
             edits_history: &mut self.edits_history,
@@ -416,8 +457,10 @@ impl Editor
             camera,
             egui_context,
             key_inputs,
-            &config.binds,
+            config,
+            mouse_buttons,
             mouse_wheel,
+            time.delta_secs(),
             ui_hovered
         );
     }
@@ -435,7 +478,7 @@ impl Editor
         user_textures: &mut EguiUserTextures
     )
     {
-        self.state.update_active_tool(&mut ToolUpdateBundle {
+        self.state.update_active_tool(self.panning, &mut ToolUpdateBundle {
             window,
             images,
             delta_time: time.delta_secs(),
@@ -456,30 +499,189 @@ impl Editor
         });
     }
 
+    /// Selects the viewport receiving the inputs of the frame, the one under the cursor. If it is
+    /// the second viewport its camera is swapped with `camera`, so that it is the one being
+    /// updated.
+    #[inline]
+    pub fn begin_viewport_update(
+        &mut self,
+        window: &Window,
+        camera: &mut Transform,
+        mouse_buttons: &ButtonInput<MouseButton>
+    )
+    {
+        let rect = match self.state.split_view()
+        {
+            Some((SplitView::Viewport, rect)) => rect,
+            _ =>
+            {
+                self.viewport_camera = None;
+                self.viewport_focused = false;
+                return;
+            }
+        };
+
+        let viewport_camera = self.viewport_camera.get_or_insert(*camera);
+
+        // The focus does not change while a button is held so that drags can cross the border
+        // between the viewports.
+        if mouse_buttons.get_pressed().next().is_none()
+        {
+            if let Some(pos) = window.cursor_position()
+            {
+                self.viewport_focused = rect.contains(egui::Pos2::new(pos.x, pos.y));
+            }
+        }
+
+        if self.viewport_focused
+        {
+            std::mem::swap(camera, viewport_camera);
+        }
+    }
+
+    /// Restores the main camera swapped by [`Self::begin_viewport_update`].
+    #[inline]
+    pub fn end_viewport_update(&mut self, camera: &mut Transform)
+    {
+        if self.viewport_focused
+        {
+            std::mem::swap(camera, self.viewport_camera.as_mut().unwrap());
+        }
+    }
+
+    /// Updates the camera of the split view. In preview mode it shows, in the right half of the
+    /// map area, the same region of the map visible in the left half. In viewport mode it shows
+    /// the region framed by the camera of the second viewport.
+    #[inline]
+    pub fn update_split_view_camera(
+        &self,
+        window: &Window,
+        camera: &Transform,
+        split_view_camera: (&mut bevy::render::camera::Camera, &mut Transform)
+    )
+    {
+        let (split_view_camera, transform) = split_view_camera;
+
+        let (split_view, rect) = match self.state.split_view()
+        {
+            Some(value) if value.1.width() >= 1f32 && value.1.height() >= 1f32 => value,
+            _ =>
+            {
+                split_view_camera.is_active = false;
+                return;
+            }
+        };
+
+        let scale_factor = window.scale_factor();
+        let window_size = UVec2::new(window.physical_width(), window.physical_height());
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let physical_position = (Vec2::new(rect.min.x, rect.min.y) * scale_factor)
+            .as_uvec2()
+            .min(window_size);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let physical_size = (Vec2::new(rect.width(), rect.height()) * scale_factor)
+            .as_uvec2()
+            .min(window_size - physical_position);
+
+        if physical_size.x == 0 || physical_size.y == 0
+        {
+            split_view_camera.is_active = false;
+            return;
+        }
+
+        split_view_camera.is_active = true;
+        split_view_camera.viewport = Some(Viewport {
+            physical_position,
+            physical_size,
+            ..Default::default()
+        });
+        *transform = match split_view
+        {
+            SplitView::None => unreachable!(),
+            SplitView::Preview => split_view_camera_transform(window, camera, preview_center(rect)),
+            SplitView::Viewport =>
+            {
+                split_view_camera_transform(
+                    window,
+                    &self.viewport_camera.unwrap_or(*camera),
+                    rect.center()
+                )
+            },
+        };
+    }
+
+    /// Returns the area of the window where the map is shown by the viewport receiving the
+    /// inputs.
+    #[inline]
+    #[must_use]
+    fn focused_viewport_rect(&self, egui_context: &egui::Context) -> egui::Rect
+    {
+        match self.state.split_view()
+        {
+            Some((SplitView::Viewport, rect)) if self.viewport_focused => rect,
+            // The area of the window not covered by the UI panels.
+            _ => egui_context.available_rect()
+        }
+    }
+
     /// Update the position and scale of the camera based on the user inputs.
     #[inline]
     fn update_view(
         &mut self,
-        window: &Window,
+        window: &mut Window,
         camera: &mut Transform,
         egui_context: &egui::Context,
         key_inputs: &ButtonInput<KeyCode>,
-        binds: &BindsKeyCodes,
+        config: &Config,
+        mouse_buttons: &ButtonInput<MouseButton>,
         mouse_wheel: &mut EventReader<MouseWheel>,
+        delta_time: f32,
         ui_hovered: bool
     )
     {
-        let moved_with_keyboard = self.update_view_keyboard(window, camera, key_inputs, binds);
+        let scale_range = config.camera_scale_range();
+
+        // A pan started on the map continues even if the cursor moves over the UI.
+        self.panning =
+            config.mouse_binds.pan.pressed(mouse_buttons) && (self.panning || !ui_hovered);
+
+        let moved_with_keyboard =
+            self.update_view_keyboard(window, camera, key_inputs, &config.binds);
+        self.pan_view_wasd(camera, key_inputs, config.navigation, delta_time);
+        let nudge = self.inputs.directional_keys_cursor_delta();
+        let mut cursor_pos = window.cursor_position();
 
-        if let Some(cursor_pos) = window.cursor_position()
+        if let Some(delta) = nudge
         {
+            cursor_pos = self.nudge_cursor(window, camera, delta).into();
+        }
+
+        if let Some(cursor_pos) = cursor_pos
+        {
+            Self::edge_scroll(
+                camera,
+                egui_context,
+                self.focused_viewport_rect(egui_context),
+                mouse_buttons,
+                config.navigation,
+                cursor_pos,
+                delta_time
+            );
+
             if ui_hovered
             {
                 egui_context.set_cursor_icon(egui::CursorIcon::Default);
             }
-            else if !self.update_view_mouse(window, camera, mouse_wheel) && !moved_with_keyboard
+            else if !self.update_view_mouse(
+                window,
+                camera,
+                mouse_wheel,
+                config.mouse_binds,
+                &scale_range,
+                cursor_pos
+            ) && !moved_with_keyboard
             {
-                self.drag_view(camera, egui_context);
+                self.drag_view(camera, egui_context, self.panning);
             }
 
             self.cursor.update(
@@ -487,16 +689,47 @@ impl Editor
                 window,
                 camera,
                 &self.state,
+                &self.manager,
                 &self.grid,
                 self.inputs.space_pressed()
             );
+
+            if nudge.is_some()
+            {
+                self.cursor.set_keyboard_moved();
+            }
         }
 
+        camera.set_scale(camera.scale().clamp(*scale_range.start(), *scale_range.end()));
+
         let ui_displacement = ui_camera_displacement(camera.scale());
         let pos = self.grid.point_projection(camera.pos() + ui_displacement);
         camera.set_pos(self.grid.transform_point(pos.bound()) - ui_displacement);
     }
 
+    /// Moves the cursor by `delta` and returns its new position on the window. If the new position
+    /// is outside the window the camera is moved instead.
+    #[inline]
+    #[must_use]
+    fn nudge_cursor(&self, window: &mut Window, camera: &mut Transform, delta: Vec2) -> Vec2
+    {
+        let pos = camera.to_egui_coordinates(
+            window,
+            &self.grid,
+            (self.cursor.world_snapped() + delta).bound()
+        );
+        let mut pos = Vec2::new(pos.x, pos.y);
+
+        if pos.x < 0f32 || pos.y < 0f32 || pos.x > window.width() || pos.y > window.height()
+        {
+            camera.translate(delta);
+            pos = self.cursor.ui_snapped();
+        }
+
+        window.set_cursor_position(pos.into());
+        pos
+    }
+
     /// Update the position and scale of the camera based on the keyboard inputs.
     #[inline]
     #[must_use]
@@ -536,6 +769,22 @@ impl Editor
             return false;
         }
 
+        for (bind, zoom) in [
+            Bind::ZoomQuarter,
+            Bind::ZoomHalf,
+            Bind::ZoomActualSize,
+            Bind::ZoomDouble
+        ]
+        .into_iter()
+        .zip(ZOOM_PRESETS)
+        {
+            if bind.just_pressed(key_inputs, binds)
+            {
+                camera.set_scale(zoom_scale(zoom));
+                return true;
+            }
+        }
+
         if let Some(hull) = State::quick_zoom_hull(
             key_inputs,
             &self.drawing_resources,
@@ -553,6 +802,81 @@ impl Editor
         false
     }
 
+    /// Pans the view while the WASD keys are held, if enabled. The speed is constant on screen
+    /// regardless of the zoom.
+    #[inline]
+    fn pan_view_wasd(
+        &self,
+        camera: &mut Transform,
+        key_inputs: &ButtonInput<KeyCode>,
+        navigation: NavigationSettings,
+        delta_time: f32
+    )
+    {
+        if self.inputs.ctrl_pressed() || self.inputs.alt_pressed()
+        {
+            return;
+        }
+
+        let dir = return_if_none!(navigation.wasd_direction(key_inputs));
+        camera.translate(dir * f32::from(navigation.pan_speed) * camera.scale() * delta_time);
+    }
+
+    /// Scrolls the view toward the border of the viewport the cursor is close to while the left
+    /// mouse button is held, if enabled, so that entities can be dragged beyond the visible area.
+    #[inline]
+    fn edge_scroll(
+        camera: &mut Transform,
+        egui_context: &egui::Context,
+        viewport: egui::Rect,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        navigation: NavigationSettings,
+        cursor_pos: Vec2,
+        delta_time: f32
+    )
+    {
+        /// The distance from the border of the viewport within which the view is scrolled.
+        const EDGE_SCROLL_MARGIN: f32 = 24f32;
+
+        if !navigation.edge_scroll ||
+            !mouse_buttons.pressed(MouseButton::Left) ||
+            egui_context.is_using_pointer()
+        {
+            return;
+        }
+
+        let viewport = viewport.shrink(EDGE_SCROLL_MARGIN);
+        let mut dir = Vec2::ZERO;
+
+        if cursor_pos.x < viewport.left()
+        {
+            dir.x -= 1f32;
+        }
+        else if cursor_pos.x > viewport.right()
+        {
+            dir.x += 1f32;
+        }
+
+        // The window coordinates grow downward.
+        if cursor_pos.y < viewport.top()
+        {
+            dir.y += 1f32;
+        }
+        else if cursor_pos.y > viewport.bottom()
+        {
+            dir.y -= 1f32;
+        }
+
+        if dir == Vec2::ZERO
+        {
+            return;
+        }
+
+        camera.translate(
+            dir.normalize() * f32::from(navigation.pan_speed) * camera.scale() * delta_time
+        );
+    }
+
     /// Update the position and scale of the camera based on the mouse inputs.
     #[inline]
     #[must_use]
@@ -560,14 +884,12 @@ impl Editor
         &mut self,
         window: &Window,
         camera: &mut Transform,
-        mouse_wheel: &mut EventReader<MouseWheel>
+        mouse_wheel: &mut EventReader<MouseWheel>,
+        mouse_binds: MouseBinds,
+        scale_range: &RangeInclusive<f32>,
+        cursor_pos: Vec2
     ) -> bool
     {
-        if self.inputs.space_pressed()
-        {
-            return false;
-        }
-
         let mouse_wheel_scroll = {
             let mut scroll = 0f32;
 
@@ -592,7 +914,37 @@ impl Editor
             return false;
         }
 
-        if self.inputs.ctrl_pressed()
+        // Alt changes the radius of the soft selection.
+        if self.inputs.alt_pressed() && self.state.soft_selection_radius_scroll(mouse_wheel_scroll)
+        {
+            return true;
+        }
+
+        let scale = camera.scale();
+        let zoom = self.inputs.space_pressed() ||
+            self.inputs.ctrl_pressed() != (mouse_binds.wheel == WheelAction::Zoom);
+
+        // Do not zoom past the limits, otherwise the clamping would move the zoomed position.
+        if zoom &&
+            ((mouse_wheel_scroll > 0f32 && scale <= *scale_range.start()) ||
+                (mouse_wheel_scroll < 0f32 && scale >= *scale_range.end()))
+        {
+            return true;
+        }
+
+        if self.inputs.space_pressed()
+        {
+            // Temporary pan mode, the cursor world position is frozen so it must be recalculated.
+            camera.zoom_on_ui_pos(
+                window,
+                &self.grid,
+                camera.to_world_coordinates(window, &self.grid, cursor_pos),
+                cursor_pos,
+                mouse_wheel_scroll
+            );
+        }
+        // Ctrl swaps the wheel behavior.
+        else if self.inputs.ctrl_pressed() != (mouse_binds.wheel == WheelAction::Zoom)
         {
             camera.zoom_on_ui_pos(
                 window,
@@ -621,12 +973,12 @@ impl Editor
         true
     }
 
-    /// Drags the camera around, CAD software-like.
+    /// Drags the camera around, CAD software-like, while space or the pan mouse button are pressed.
     #[inline]
-    fn drag_view(&mut self, camera: &mut Transform, egui_context: &egui::Context)
+    fn drag_view(&mut self, camera: &mut Transform, egui_context: &egui::Context, pan_pressed: bool)
     {
         // Drag the view around.
-        if !self.inputs.space_pressed()
+        if !self.inputs.space_pressed() && !pan_pressed
         {
             egui_context.set_cursor_icon(egui::CursorIcon::Default);
             return;
@@ -643,24 +995,28 @@ impl Editor
     pub fn quit(
         &mut self,
         window: &mut Window,
+        camera: &mut Transform,
         config: &mut Config,
         next_editor_state: &mut NextState<EditorState>
     ) -> bool
     {
-        State::quit(
-            window,
-            config,
-            &AllDefaultProperties {
-                engine_brushes: &self.engine_default_brush_properties,
-                engine_things:  &self.engine_default_thing_properties,
-                map_brushes:    &mut self.map_default_brush_properties,
-                map_things:     &mut self.map_default_thing_properties
+        self.state.quit(
+            EditedMap {
+                window,
+                config,
+                camera,
+                default_properties: &mut AllDefaultProperties {
+                    engine_brushes: &self.engine_default_brush_properties,
+                    engine_things:  &self.engine_default_thing_properties,
+                    map_brushes:    &mut self.map_default_brush_properties,
+                    map_things:     &mut self.map_default_thing_properties
+                },
+                drawing_resources: &mut self.drawing_resources,
+                manager: &mut self.manager,
+                clipboard: &mut self.clipboard,
+                edits_history: &mut self.edits_history,
+                grid: &mut self.grid
             },
-            &mut self.drawing_resources,
-            &mut self.manager,
-            &mut self.clipboard,
-            &mut self.edits_history,
-            &mut self.grid,
             next_editor_state
         )
     }
@@ -682,10 +1038,12 @@ impl Editor
         meshes: &mut Assets<Mesh>,
         egui_context: &egui::Context,
         meshes_query: &Query<Entity, With<Mesh2d>>,
-        color_resources: &ColorResources
+        color_resources: &ColorResources,
+        marching_ants: bool
     )
     {
         let elapsed_time = time.elapsed_secs();
+        self.manager.update_visible_entities(window, camera, &self.grid);
 
         if self.state.map_preview()
         {
@@ -698,8 +1056,7 @@ impl Editor
                     meshes,
                     meshes_query,
                     &mut self.drawing_resources,
-                    &self.grid,
-                    elapsed_time
+                    &self.grid
                 ),
                 camera,
                 things_catalog: &self.things_catalog,
@@ -726,7 +1083,8 @@ impl Editor
                 elapsed_time,
                 paint_tool_camera.scale(),
                 self.state.show_collision_overlay(),
-                self.state.show_tooltips()
+                self.state.show_tooltips(),
+                marching_ants
             ),
             camera,
             prop_cameras,
@@ -734,14 +1092,137 @@ impl Editor
             things_catalog: &self.things_catalog,
             cursor: &self.cursor,
             manager: &mut self.manager,
-            clipboard: &self.clipboard
+            clipboard: &self.clipboard,
+            edits_history: &self.edits_history
         });
+
+        let (split_view, rect) = return_if_none!(self.state.split_view());
+
+        match split_view
+        {
+            SplitView::None => unreachable!(),
+            SplitView::Preview =>
+            {
+                State::draw_split_view(&mut DrawBundleMapPreview {
+                    window,
+                    egui_context,
+                    drawer: MapPreviewDrawer::split_view(
+                        commands,
+                        meshes,
+                        &mut self.drawing_resources,
+                        &self.grid,
+                        elapsed_time
+                    ),
+                    camera: &split_view_camera_transform(window, camera, preview_center(rect)),
+                    things_catalog: &self.things_catalog,
+                    manager: &self.manager
+                });
+            },
+            SplitView::Viewport =>
+            {
+                let camera = &self.viewport_camera.unwrap_or(*camera);
+                self.manager.update_visible_entities(window, camera, &self.grid);
+
+                // Time related effects already advanced while drawing the main viewport.
+                self.state.draw(&mut DrawBundle {
+                    window,
+                    delta_time: 0f32,
+                    drawer: &mut EditDrawer::second_viewport(
+                        commands,
+                        camera,
+                        meshes,
+                        egui_context,
+                        &mut self.drawing_resources,
+                        color_resources,
+                        self.state.tools_settings(),
+                        &self.grid,
+                        elapsed_time,
+                        self.state.show_collision_overlay(),
+                        marching_ants
+                    ),
+                    camera,
+                    prop_cameras,
+                    paint_tool_camera,
+                    things_catalog: &self.things_catalog,
+                    cursor: &self.cursor,
+                    manager: &mut self.manager,
+                    clipboard: &self.clipboard,
+                    edits_history: &self.edits_history
+                });
+            }
+        };
     }
 
     //==============================================================
     // Misc
 
-    /// Reloads the stored textures.
+    /// Sends the events concerning the map being edited that occurred since the last call.
+    #[inline]
+    pub fn send_events(
+        &mut self,
+        spawned: &mut EventWriter<EntitySpawned>,
+        despawned: &mut EventWriter<EntityDespawned>,
+        edits: &mut EventWriter<EditCommitted>,
+        saves: &mut EventWriter<MapSaved>
+    )
+    {
+        for event in self.manager.drain_entity_events()
+        {
+            match event
+            {
+                EntityEvent::Spawned(id) => _ = spawned.send(EntitySpawned { id }),
+                EntityEvent::Despawned(id) => _ = despawned.send(EntityDespawned { id })
+            };
+        }
+
+        for event in self.edits_history.drain_events()
+        {
+            match event
+            {
+                HistoryEvent::EditCommitted(tag) => _ = edits.send(EditCommitted { tag }),
+                HistoryEvent::MapSaved(path) => _ = saves.send(MapSaved { path })
+            };
+        }
+    }
+
+    /// Whether a texture reload is in progress.
+    #[inline]
+    #[must_use]
+    pub const fn reloading_textures(&self) -> bool { self.state.reloading_textures() }
+
+    /// Starts the reload of the textures whose files were added or modified.
+    #[inline]
+    pub fn start_texture_hot_reload(&mut self) { self.state.start_texture_hot_reload(); }
+
+    /// Reloads the things if files were added to, modified in, or removed from the things folder.
+    #[inline]
+    pub fn hot_reload_things(
+        &mut self,
+        prop_cameras: &mut PropCamerasMut,
+        images: &mut Assets<Image>,
+        user_textures: &mut EguiUserTextures
+    )
+    {
+        if !self.things_catalog.check_changed_files()
+        {
+            return;
+        }
+
+        State::execute_things_reload(
+            images,
+            user_textures,
+            prop_cameras,
+            &self.drawing_resources,
+            &mut self.things_catalog,
+            &mut self.manager,
+            &mut self.clipboard,
+            &mut self.edits_history,
+            &self.grid
+        );
+    }
+
+    /// Reloads the stored textures. If `partial` only the textures in `textures` are replaced or
+    /// added.
     #[inline]
     pub fn reload_textures(
         &mut self,
@@ -749,10 +1230,19 @@ impl Editor
         images: &mut Assets<Image>,
         materials: &mut Assets<ColorMaterial>,
         user_textures: &mut EguiUserTextures,
-        textures: Vec<(Texture, egui::TextureId)>
+        textures: Vec<(Texture, egui::TextureId)>,
+        partial: bool
     )
     {
-        self.drawing_resources.reload_textures(materials, textures);
+        if partial
+        {
+            self.drawing_resources.update_textures(materials, textures);
+        }
+        else
+        {
+            self.drawing_resources.reload_textures(materials, textures);
+        }
+
         self.state.finish_textures_reload(
             prop_cameras,
             images,
@@ -770,3 +1260,34 @@ impl Editor
     #[inline]
     pub fn cleanup(&self, meshes: &mut Assets<Mesh>) { self.drawing_resources.cleanup(meshes); }
 }
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the point of the window at the center of the left half of the map area, given the
+/// `rect` of the right half.
+#[inline]
+#[must_use]
+fn preview_center(rect: egui::Rect) -> egui::Pos2
+{
+    egui::Pos2::new(rect.center().x - rect.width(), rect.center().y)
+}
+
+//=======================================================================//
+
+/// Returns the [`Transform`] of the split view camera, which shows at the center of its viewport
+/// the region of the map that `camera`, covering the whole window, shows at `center`.
+#[inline]
+#[must_use]
+fn split_view_camera_transform(window: &Window, camera: &Transform, center: egui::Pos2)
+    -> Transform
+{
+    let scale = camera.scale();
+    let delta = Vec2::new(center.x, center.y) - Vec2::new(window.width(), window.height()) / 2f32;
+
+    let mut transform = *camera;
+    transform.translate(Vec2::new(delta.x, -delta.y) * scale);
+    transform
+}
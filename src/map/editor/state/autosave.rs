@@ -0,0 +1,364 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use bevy::tasks::{block_on, IoTaskPool, Task};
+use hill_vacuum_shared::{continue_if_none, return_if_none};
+
+use crate::{
+    error_message,
+    utils::collections::{hash_map, HashMap}
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The extension of the autosave snapshots.
+const AUTOSAVE_EXTENSION: &str = "hv.autosave";
+/// The folder the autosave snapshots are stored in.
+const AUTOSAVE_FOLDER: &str = "autosave";
+/// The file whose existence at startup means the previous session did not shut down properly.
+const SESSION_FILE_NAME: &str = "hill_vacuum.session";
+/// The amount of rolling snapshots kept for each map.
+const SNAPSHOTS: usize = 3;
+/// The name of the snapshots of a map that was never saved.
+const UNTITLED: &str = "untitled";
+
+//=======================================================================//
+// STATICS
+//
+//=======================================================================//
+
+/// The counter used to generate the identifiers of the [`Autosave`]s.
+static AUTOSAVE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The periodic writer of the snapshots of a map. Each open map has its own.
+pub(in crate::map::editor::state) struct Autosave
+{
+    /// The identifier used in the name of the snapshots of the map if it was never saved.
+    id:      u64,
+    /// The name of the snapshots last written, without the index and the extension.
+    name:    Option<String>,
+    /// The time elapsed since the last snapshot check.
+    elapsed: f32,
+    /// The index of the next rolling snapshot to write.
+    index:   usize,
+    /// The hash of the content of the last snapshot written.
+    hash:    Option<u64>,
+    /// The background task writing the last snapshot, if any.
+    task:    Option<Task<bool>>
+}
+
+impl Default for Autosave
+{
+    #[inline]
+    fn default() -> Self
+    {
+        // The time is included so that the snapshots of the never saved maps of a previous session
+        // are not overwritten.
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| u64::try_from(time.as_nanos()).unwrap_or(u64::MAX));
+
+        Self {
+            id:      time ^ AUTOSAVE_COUNTER.fetch_add(1, Ordering::Relaxed),
+            name:    None,
+            elapsed: 0f32,
+            index:   0,
+            hash:    None,
+            task:    None
+        }
+    }
+}
+
+impl Autosave
+{
+    /// Writes a snapshot every `interval` seconds in a background task if the content returned by
+    /// `data` changed since the last one. `data` returns `None` if there is nothing to save.
+    /// `map` is the path of the map file, if any.
+    #[inline]
+    pub fn update<F>(&mut self, delta_time: f32, interval: f32, map: Option<&PathBuf>, data: F)
+    where
+        F: FnOnce() -> Option<Vec<u8>>
+    {
+        self.check_task();
+
+        if interval == 0f32
+        {
+            return;
+        }
+
+        self.elapsed += delta_time;
+
+        if self.elapsed < interval || self.task.is_some()
+        {
+            return;
+        }
+
+        self.write(map, data);
+    }
+
+    /// Immediately writes a snapshot if the content returned by `data` changed since the last one,
+    /// unless autosaving is disabled because `interval` is zero. Used before the map stops being
+    /// edited, so that its latest changes are not lost.
+    #[inline]
+    pub fn flush<F>(&mut self, interval: f32, map: Option<&PathBuf>, data: F)
+    where
+        F: FnOnce() -> Option<Vec<u8>>
+    {
+        if interval == 0f32
+        {
+            return;
+        }
+
+        if let Some(task) = self.task.take()
+        {
+            if !block_on(task)
+            {
+                error_message("Error writing autosave snapshot.");
+            }
+        }
+
+        self.write(map, data);
+    }
+
+    /// Reports an error if the task writing the last snapshot has finished unsuccessfully.
+    #[inline]
+    fn check_task(&mut self)
+    {
+        if let Some(task) = self.task.take_if(|task| task.is_finished())
+        {
+            if !block_on(task)
+            {
+                error_message("Error writing autosave snapshot.");
+            }
+        }
+    }
+
+    /// Writes the snapshot returned by `data` in a background task, if it changed since the last
+    /// one.
+    #[inline]
+    fn write<F>(&mut self, map: Option<&PathBuf>, data: F)
+    where
+        F: FnOnce() -> Option<Vec<u8>>
+    {
+        self.elapsed = 0f32;
+        let data = return_if_none!(data());
+
+        // The map was saved to a different file, or another one was opened, so the rolling
+        // snapshots start over.
+        let name = snapshot_name(map, self.id);
+
+        if self.name.as_ref() != Some(&name)
+        {
+            self.index = 0;
+            self.hash = None;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if self.hash == Some(hash)
+        {
+            return;
+        }
+
+        self.hash = hash.into();
+        let path =
+            Path::new(AUTOSAVE_FOLDER).join(format!("{name}.{}.{AUTOSAVE_EXTENSION}", self.index));
+        self.index = (self.index + 1) % SNAPSHOTS;
+        self.name = name.into();
+
+        self.task = IoTaskPool::get()
+            .spawn(async move {
+                std::fs::create_dir_all(AUTOSAVE_FOLDER).is_ok() &&
+                    File::create(path)
+                        .and_then(|file| BufWriter::new(file).write_all(&data))
+                        .is_ok()
+            })
+            .into();
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the name of the snapshots of the map stored at `map`, without the index and the
+/// extension. The name contains the hash of the full path of the map, so that maps with the same
+/// name stored in different folders do not share their snapshots. If the map was never saved `id`
+/// is used instead.
+#[inline]
+#[must_use]
+fn snapshot_name(map: Option<&PathBuf>, id: u64) -> String
+{
+    match map
+    {
+        Some(path) =>
+        {
+            let mut hasher = DefaultHasher::new();
+            std::fs::canonicalize(path)
+                .unwrap_or_else(|_| path.clone())
+                .hash(&mut hasher);
+
+            format!(
+                "{}-{:016x}",
+                path.file_stem().and_then(|stem| stem.to_str()).unwrap_or(UNTITLED),
+                hasher.finish()
+            )
+        },
+        None => format!("{UNTITLED}-{id:016x}")
+    }
+}
+
+//=======================================================================//
+
+/// Returns an iterator to the paths of the stored snapshots.
+#[inline]
+fn snapshots() -> impl Iterator<Item = PathBuf>
+{
+    std::fs::read_dir(AUTOSAVE_FOLDER)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(AUTOSAVE_EXTENSION))
+        })
+}
+
+//=======================================================================//
+
+/// Checks whether the previous session did not shut down properly and, if that is the case, asks
+/// for each map with snapshots whether its latest one should be restored.
+/// Returns the paths of the snapshots to load, from the least to the most recently written.
+#[inline]
+#[must_use]
+pub(in crate::map::editor::state) fn recover() -> Vec<PathBuf>
+{
+    if !Path::new(SESSION_FILE_NAME).exists()
+    {
+        return Vec::new();
+    }
+
+    let mut latest: HashMap<String, (PathBuf, SystemTime)> = hash_map![];
+
+    for (path, time) in snapshots().filter_map(|path| {
+        std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(|time| (path, time))
+    })
+    {
+        // Strip the index and the extension to get the name of the map.
+        let name = continue_if_none!(path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(AUTOSAVE_EXTENSION))
+            .and_then(|name| name.trim_end_matches('.').rsplit_once('.'))
+            .map(|(name, _)| name.to_owned()));
+
+        match latest.get_mut(&name)
+        {
+            Some(snapshot) =>
+            {
+                if time > snapshot.1
+                {
+                    *snapshot = (path, time);
+                }
+            },
+            None => _ = latest.insert(name, (path, time))
+        };
+    }
+
+    let mut latest = latest.into_values().collect::<Vec<_>>();
+    latest.sort_by_key(|(_, time)| *time);
+
+    latest
+        .into_iter()
+        .filter_map(|(path, _)| {
+            matches!(
+                rfd::MessageDialog::new()
+                    .set_title("WARNING")
+                    .set_description(format!(
+                        "The previous session did not shut down properly, do you wish to restore \
+                         the latest autosave snapshot {}?",
+                        path.file_name().unwrap().to_str().unwrap()
+                    ))
+                    .set_level(rfd::MessageLevel::Warning)
+                    .set_buttons(rfd::MessageButtons::YesNo)
+                    .show(),
+                rfd::MessageDialogResult::Yes
+            )
+            .then_some(path)
+        })
+        .collect()
+}
+
+//=======================================================================//
+
+/// Marks the start of a session, so that an improper shut down can be detected at the next
+/// startup.
+#[inline]
+pub(in crate::map::editor::state) fn start_session()
+{
+    if File::create(SESSION_FILE_NAME).is_err()
+    {
+        error_message("Error creating session file.");
+    }
+}
+
+//=======================================================================//
+
+/// Marks the proper end of the session, and removes the snapshots since they are not needed
+/// anymore. If `discarded` is true, some maps were closed without saving their changes, so the
+/// user is asked whether their snapshots should rather be kept to be restored at the next startup.
+#[inline]
+pub(in crate::map::editor::state) fn end_session(discarded: bool)
+{
+    if discarded &&
+        snapshots().next().is_some() &&
+        matches!(
+            rfd::MessageDialog::new()
+                .set_title("WARNING")
+                .set_description(
+                    "Some maps were closed without saving their changes, do you wish to keep \
+                     their autosave snapshots so that they can be restored at the next startup?"
+                )
+                .set_level(rfd::MessageLevel::Warning)
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .show(),
+            rfd::MessageDialogResult::Yes
+        )
+    {
+        // The session file is retained so that the restore is offered at the next startup.
+        return;
+    }
+
+    for path in snapshots()
+    {
+        _ = std::fs::remove_file(path);
+    }
+
+    _ = std::fs::remove_file(SESSION_FILE_NAME);
+}
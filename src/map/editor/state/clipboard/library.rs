@@ -0,0 +1,128 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf}
+};
+
+use super::prop::PropViewer;
+use crate::map::{version_number, FILE_VERSION};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The folder the [`Prop`]s of the library are stored in.
+const LIBRARY_FOLDER: &str = "assets/props/";
+/// The extension of the files of the [`Prop`]s of the library.
+const LIBRARY_EXTENSION: &str = "hvp";
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Whether `name` can be used as the name of a [`Prop`] of the library.
+#[inline]
+#[must_use]
+pub(in crate::map::editor::state) fn valid_name(name: &str) -> bool
+{
+    !name.is_empty() && name.trim() == name && !name.contains(['.', '/', '\\'])
+}
+
+//=======================================================================//
+
+/// Returns the path of the file of the [`Prop`] of the library named `name`.
+#[inline]
+#[must_use]
+fn path(name: &str) -> PathBuf
+{
+    Path::new(LIBRARY_FOLDER).join(format!("{name}.{LIBRARY_EXTENSION}"))
+}
+
+//=======================================================================//
+
+/// Loads the [`Prop`]s of the library, sorted by name.
+/// Returns the loaded [`Prop`]s and the names of the files that could not be loaded, along with
+/// the reason.
+#[inline]
+#[must_use]
+pub(in crate::map::editor::state::clipboard) fn load() -> (Vec<(String, PropViewer)>, Vec<String>)
+{
+    /// Loads the [`Prop`] stored at `path`.
+    #[inline]
+    fn load_prop(path: &Path) -> Result<PropViewer, &'static str>
+    {
+        let mut file = BufReader::new(File::open(path).map_err(|_| "Could not open the file")?);
+
+        if version_number(&mut file)? != FILE_VERSION
+        {
+            return Err("Unsupported file version");
+        }
+
+        ciborium::from_reader(&mut file).map_err(|_| "Error reading the prop")
+    }
+
+    let mut props = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in std::fs::read_dir(LIBRARY_FOLDER)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == LIBRARY_EXTENSION))
+    {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+
+        match load_prop(&path)
+        {
+            Ok(prop) => props.push((name, prop)),
+            Err(err) => errors.push(format!("{name}: {err}"))
+        };
+    }
+
+    props.sort_by(|(a, _), (b, _)| a.cmp(b));
+    (props, errors)
+}
+
+//=======================================================================//
+
+/// Stores `prop` in the library with name `name`, overwriting the [`Prop`] with the same name, if
+/// any.
+/// # Errors
+/// Returns an error if the file could not be written.
+#[inline]
+pub(in crate::map::editor::state::clipboard) fn save(
+    name: &str,
+    prop: &PropViewer
+) -> Result<(), &'static str>
+{
+    let mut data = Vec::new();
+    let mut writer = BufWriter::new(&mut data);
+
+    ciborium::ser::into_writer(FILE_VERSION, &mut writer)
+        .map_err(|_| "Error writing version number.")?;
+    ciborium::ser::into_writer(prop, &mut writer).map_err(|_| "Error writing library prop.")?;
+    drop(writer);
+
+    std::fs::create_dir_all(LIBRARY_FOLDER).map_err(|_| "Error creating props library folder.")?;
+    File::create(path(name))
+        .and_then(|mut file| file.write_all(&data))
+        .map_err(|_| "Error writing library prop file.")
+}
+
+//=======================================================================//
+
+/// Removes the [`Prop`] named `name` from the library.
+/// # Errors
+/// Returns an error if the file could not be removed.
+#[inline]
+pub(in crate::map::editor::state::clipboard) fn delete(name: &str) -> Result<(), &'static str>
+{
+    std::fs::remove_file(path(name)).map_err(|_| "Error removing library prop file.")
+}
@@ -1,3 +1,4 @@
+pub(in crate::map::editor::state) mod library;
 pub(in crate::map) mod prop;
 
 //=======================================================================//
@@ -38,6 +39,7 @@ use prop::{Prop, PropScreenshotTimer, PropViewer};
 use serde::{Deserialize, Serialize};
 
 use super::{
+    editor_state::PasteSnap,
     edits_history::EditsHistory,
     grid::Grid,
     inputs_presses::InputsPresses,
@@ -45,6 +47,7 @@ use super::{
     ui::singleline_textedit
 };
 use crate::{
+    error_message,
     map::{
         brush::{BrushData, BrushDataViewer},
         camera::scale_viewport,
@@ -71,6 +74,10 @@ use crate::{
 
 /// The size of the image of the prop screenshot.
 pub(in crate::map) const PROP_SCREENSHOT_SIZE: UVec2 = UVec2::new(196, 196);
+/// The maximum amount of copied entities groups stored in the copy history.
+const COPY_HISTORY_LENGTH: usize = 8;
+/// The amount of quick [`Prop`] slots, each one bound to a digit key.
+pub(in crate::map::editor::state) const QUICK_PROPS_AMOUNT: usize = 10;
 
 //=======================================================================//
 // MACROS
@@ -227,6 +234,31 @@ impl ClipboardData
     }
 }
 
+//=======================================================================//
+
+/// The location of a [`Prop`] stored in the [`Clipboard`] which can have a screenshot.
+#[must_use]
+#[derive(Clone, Copy, PartialEq)]
+enum PropSlot
+{
+    /// A slotted [`Prop`].
+    Slotted(usize),
+    /// A [`Prop`] of the copy history.
+    History(usize),
+    /// A quick [`Prop`].
+    Quick(usize),
+    /// A [`Prop`] of the library.
+    Library(usize)
+}
+
+impl PropSlot
+{
+    /// Whether `self` is the slot of a [`Prop`] stored in the map file.
+    #[inline]
+    #[must_use]
+    const fn is_map_prop(self) -> bool { matches!(self, Self::Slotted(_) | Self::Quick(_)) }
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -285,14 +317,84 @@ pub(in crate::map) struct UiProp
 
 //=======================================================================//
 
+/// The UI representation of a [`Prop`] of the library.
+#[derive(Clone, Copy)]
+pub(in crate::map) struct UiLibraryProp<'a>
+{
+    /// The index of the [`Prop`].
+    pub index:  usize,
+    /// The name of the [`Prop`].
+    pub name:   &'a str,
+    /// The screenshot of the [`Prop`], if already taken.
+    pub tex_id: Option<egui::TextureId>
+}
+
+//=======================================================================//
+
+/// The [`Prop`]s stored in a map file, along with their queued screenshots, set aside while
+/// another map is being edited.
+pub(in crate::map::editor::state) struct MapProps
+{
+    /// The quick [`Prop`]s.
+    quick_props:         [Prop; QUICK_PROPS_AMOUNT],
+    /// The index of the selected quick [`Prop`].
+    selected_quick_prop: usize,
+    /// The slotted [`Prop`]s.
+    props:               Vec<Prop>,
+    /// The index of the selected slotted [`Prop`], if any.
+    selected_prop:       Option<usize>,
+    /// Whether the [`Prop`]s were edited.
+    props_changed:       bool,
+    /// The queued screenshots, along with the timer of the assigned camera, if any.
+    screenshots:         Vec<(Option<PropScreenshotTimer>, PropSlot)>
+}
+
+impl Default for MapProps
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            quick_props:         std::array::from_fn(|_| Prop::default()),
+            selected_quick_prop: 0,
+            props:               Vec::new(),
+            selected_prop:       None,
+            props_changed:       false,
+            screenshots:         Vec::new()
+        }
+    }
+}
+
+impl MapProps
+{
+    /// Replaces the texture `prev` with `new` in the brushes of the [`Prop`]s.
+    #[inline]
+    pub(in crate::map::editor::state) fn rename_texture(&mut self, prev: &str, new: &str)
+    {
+        for prop in self.quick_props.iter_mut().chain(&mut self.props)
+        {
+            self.props_changed |= prop.rename_texture(prev, new);
+        }
+    }
+
+    /// Whether the [`Prop`]s were edited.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn props_changed(&self) -> bool { self.props_changed }
+}
+
+//=======================================================================//
+
 /// A clipboard where data to be pasted around the map is stored.
 pub(in crate::map) struct Clipboard
 {
     /// The copy-paste stored entities.
     copy_paste: Prop,
     duplicate: Prop,
-    /// The quick prop created with the paint tool.
-    quick_prop: Prop,
+    /// The quick props created with the paint tool.
+    quick_props: [Prop; QUICK_PROPS_AMOUNT],
+    /// The index of the quick [`Prop`] spawned by the paint tool.
+    selected_quick_prop: usize,
     /// The slotted [`Prop`]s.
     props: Vec<Prop>,
     /// The index of the [`Prop`] selected in the UI, if any.
@@ -301,12 +403,22 @@ pub(in crate::map) struct Clipboard
     ui_text: String,
     /// The copied platform path, if any.
     platform_path: Option<Path>,
+    /// The last copied entities, used as a ring buffer.
+    copy_history: Vec<Prop>,
+    /// The index of `copy_history` where the next copied entities will be stored.
+    copy_history_next: usize,
     /// Whether the stored [`Prop`]s were edited.
     props_changed: bool,
+    /// The [`Prop`]s of the on-disk library, sorted by name.
+    library: Vec<(String, Prop)>,
+    /// The index of the library [`Prop`] selected in the UI, if any.
+    selected_library_prop: Option<usize>,
+    /// Whether the library was loaded.
+    library_loaded: bool,
     /// The [`Prop`]s which have an assigned camera to take their screenshot.
-    props_with_assigned_camera: ArrayVec<(PropScreenshotTimer, usize), PROP_CAMERAS_AMOUNT>,
+    props_with_assigned_camera: ArrayVec<(PropScreenshotTimer, PropSlot), PROP_CAMERAS_AMOUNT>,
     /// The [`Prop`]s with no assigned camera to take their screenshot.
-    props_with_no_camera: Vec<usize>,
+    props_with_no_camera: Vec<PropSlot>,
     /// The frames that must pass before the [`Prop`] screenshots can be taken.
     props_import_wait_frames: usize,
     /// The function used to run the frame update.
@@ -337,12 +449,18 @@ impl Clipboard
         Self {
             copy_paste: Prop::default(),
             duplicate: Prop::default(),
-            quick_prop: Prop::default(),
+            quick_props: std::array::from_fn(|_| Prop::default()),
+            selected_quick_prop: 0,
             props: Vec::new(),
             selected_prop: None,
             ui_text: String::new(),
             platform_path: None,
+            copy_history: Vec::new(),
+            copy_history_next: 0,
             props_changed: false,
+            library: Vec::new(),
+            selected_library_prop: None,
+            library_loaded: false,
             props_with_assigned_camera: ArrayVec::new(),
             props_with_no_camera: Vec::new(),
             props_import_wait_frames: Self::IMPORTS_WAIT_FRAMES,
@@ -366,19 +484,25 @@ impl Clipboard
         let mut clip = Self {
             copy_paste: Prop::default(),
             duplicate: Prop::default(),
-            quick_prop: Prop::default(),
+            quick_props: std::array::from_fn(|_| Prop::default()),
+            selected_quick_prop: 0,
             props: Vec::new(),
             selected_prop: None,
             ui_text: String::new(),
             platform_path: None,
+            copy_history: Vec::new(),
+            copy_history_next: 0,
             props_changed: false,
+            library: Vec::new(),
+            selected_library_prop: None,
+            library_loaded: false,
             props_with_assigned_camera: ArrayVec::new(),
             props_with_no_camera: Vec::new(),
             props_import_wait_frames: Self::IMPORTS_WAIT_FRAMES,
             update_func: Self::delay_update
         };
 
-        match clip.import_props(
+        clip.import_props(
             images,
             prop_cameras,
             user_textures,
@@ -387,11 +511,19 @@ impl Clipboard
             grid,
             header.props,
             file
-        )
-        {
-            Ok(()) => Ok(clip),
-            Err(err) => Err(err)
-        }
+        )?;
+        clip.import_quick_props(
+            images,
+            prop_cameras,
+            user_textures,
+            resources,
+            catalog,
+            grid,
+            header.quick_props,
+            file
+        )?;
+
+        Ok(clip)
     }
 
     /// Import the [`Prop`]s in `file`.
@@ -435,7 +567,66 @@ impl Clipboard
                 resources,
                 things_catalog,
                 grid,
-                index
+                PropSlot::Slotted(index)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Import the quick [`Prop`]s in `file`, each one preceded by the index of its slot.
+    #[inline]
+    pub(in crate::map::editor::state) fn import_quick_props<T: TextureSize>(
+        &mut self,
+        images: &mut Assets<Image>,
+        prop_cameras: &mut PropCamerasMut,
+        user_textures: &mut EguiUserTextures,
+        resources: &T,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        quick_props_amount: usize,
+        file: &mut BufReader<File>
+    ) -> Result<(), &'static str>
+    {
+        let mut quick_props = Vec::new();
+
+        for _ in 0..quick_props_amount
+        {
+            let (index, prop) = ciborium::from_reader::<(usize, PropViewer), _>(&mut *file)
+                .map_err(|_| "Error loading quick props")?;
+
+            if index >= QUICK_PROPS_AMOUNT
+            {
+                return Err("Error loading quick props");
+            }
+
+            let mut prop = Prop::from_viewer(prop);
+            _ = prop.reload_things(resources, things_catalog, grid);
+            quick_props.push((index, prop));
+        }
+
+        if quick_props.is_empty()
+        {
+            return Ok(());
+        }
+
+        self.props_changed = true;
+
+        for (index, prop) in quick_props
+        {
+            let slot = PropSlot::Quick(index);
+            self.dequeue_prop_screenshot(prop_cameras, slot);
+            self.quick_props[index] = prop;
+
+            let camera = prop_cameras.iter_mut().find(|camera| !camera.1.is_active);
+            self.queue_prop_screenshot(
+                images,
+                user_textures,
+                camera,
+                resources,
+                things_catalog,
+                grid,
+                slot
             );
         }
 
@@ -452,7 +643,7 @@ impl Clipboard
         resources: &T,
         things_catalog: &ThingsCatalog,
         grid: &Grid,
-        index: usize
+        slot: PropSlot
     )
     {
         if self.props_with_assigned_camera.is_full()
@@ -461,11 +652,20 @@ impl Clipboard
                 camera.is_none(),
                 "Assigned cameras vector is full but there are still available cameras."
             );
-            self.props_with_no_camera.push(index);
+            self.props_with_no_camera.push(slot);
             return;
         }
 
-        let mut camera = camera.unwrap();
+        // The cameras could be taking the screenshots of the props of the maps in the other tabs.
+        let mut camera = match camera
+        {
+            Some(camera) => camera,
+            None =>
+            {
+                self.props_with_no_camera.push(slot);
+                return;
+            }
+        };
 
         Self::assign_camera_to_prop(
             images,
@@ -474,10 +674,10 @@ impl Clipboard
             resources,
             things_catalog,
             grid,
-            &mut self.props[index]
+            self.prop_mut(slot)
         );
         self.props_with_assigned_camera
-            .push((PropScreenshotTimer::new(camera.0.into()), index));
+            .push((PropScreenshotTimer::new(camera.0.into()), slot));
     }
 
     #[inline]
@@ -493,7 +693,7 @@ impl Clipboard
     {
         let mut prop_cameras = prop_cameras.iter_mut().filter(|camera| !camera.1.is_active);
 
-        for i in 0..self.props.len()
+        for slot in self.prop_slots()
         {
             self.queue_prop_screenshot(
                 images,
@@ -502,7 +702,7 @@ impl Clipboard
                 drawing_resources,
                 things_catalog,
                 grid,
-                i
+                slot
             );
         }
     }
@@ -525,6 +725,19 @@ impl Clipboard
     #[must_use]
     pub(in crate::map::editor::state) fn props_amount(&self) -> usize { self.props.len() }
 
+    /// The amount of [`Prop`]s of the library.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn library_props_amount(&self) -> usize { self.library.len() }
+
+    /// The index of the selected library [`Prop`], if any.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn selected_library_prop_index(&self) -> Option<usize>
+    {
+        self.selected_library_prop
+    }
+
     /// The index of the selected [`Prop`], if any.
     #[inline]
     #[must_use]
@@ -533,12 +746,28 @@ impl Clipboard
         self.selected_prop
     }
 
-    /// Whether the quick prop stored contains entities.
+    /// Whether the selected quick prop contains entities.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn has_quick_prop(&self) -> bool
     {
-        self.quick_prop.has_data()
+        self.quick_props[self.selected_quick_prop].has_data()
+    }
+
+    /// The amount of quick [`Prop`]s containing entities.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn quick_props_amount(&self) -> usize
+    {
+        self.quick_props.iter().filter(|prop| prop.has_data()).count()
+    }
+
+    /// The index of the selected quick [`Prop`].
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn selected_quick_prop_index(&self) -> usize
+    {
+        self.selected_quick_prop
     }
 
     /// Whether there are no [`Prop`]s stored.
@@ -546,7 +775,59 @@ impl Clipboard
     #[must_use]
     pub(in crate::map::editor::state) fn no_props(&self) -> bool
     {
-        self.props.is_empty() && !self.has_quick_prop()
+        self.props.is_empty() && self.library.is_empty() && self.quick_props_amount() == 0
+    }
+
+    /// Whether the copy history contains entities.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn has_copy_history(&self) -> bool
+    {
+        !self.copy_history.is_empty()
+    }
+
+    /// Returns the [`PropSlot`]s of all the stored [`Prop`]s which can have a screenshot.
+    #[inline]
+    fn prop_slots(&self) -> impl Iterator<Item = PropSlot>
+    {
+        let quick_props = self
+            .quick_props
+            .iter()
+            .enumerate()
+            .filter_map(|(i, prop)| prop.has_data().then_some(PropSlot::Quick(i)))
+            .collect::<Vec<_>>();
+
+        (0..self.props.len())
+            .map(PropSlot::Slotted)
+            .chain((0..self.copy_history.len()).map(PropSlot::History))
+            .chain((0..self.library.len()).map(PropSlot::Library))
+            .chain(quick_props)
+    }
+
+    /// Returns a reference to the [`Prop`] at `slot`.
+    #[inline]
+    fn prop(&self, slot: PropSlot) -> &Prop
+    {
+        match slot
+        {
+            PropSlot::Slotted(index) => &self.props[index],
+            PropSlot::History(index) => &self.copy_history[index],
+            PropSlot::Quick(index) => &self.quick_props[index],
+            PropSlot::Library(index) => &self.library[index].1
+        }
+    }
+
+    /// Returns a mutable reference to the [`Prop`] at `slot`.
+    #[inline]
+    fn prop_mut(&mut self, slot: PropSlot) -> &mut Prop
+    {
+        match slot
+        {
+            PropSlot::Slotted(index) => &mut self.props[index],
+            PropSlot::History(index) => &mut self.copy_history[index],
+            PropSlot::Quick(index) => &mut self.quick_props[index],
+            PropSlot::Library(index) => &mut self.library[index].1
+        }
     }
 
     //==============================================================
@@ -586,6 +867,18 @@ impl Clipboard
         grid: &Grid
     )
     {
+        if !self.library_loaded.replace_value(true)
+        {
+            self.load_library(
+                images,
+                prop_cameras,
+                user_textures,
+                drawing_resources,
+                things_catalog,
+                grid
+            );
+        }
+
         let mut i = 0;
 
         while i < self.props_with_assigned_camera.len()
@@ -599,13 +892,13 @@ impl Clipboard
             i += 1;
         }
 
-        for mut camera in prop_cameras.iter_mut().take(
+        for mut camera in prop_cameras.iter_mut().filter(|camera| !camera.1.is_active).take(
             self.props_with_no_camera
                 .len()
                 .min(PROP_CAMERAS_AMOUNT - self.props_with_assigned_camera.len())
         )
         {
-            let index = self.props_with_no_camera.pop().unwrap();
+            let slot = self.props_with_no_camera.pop().unwrap();
 
             Self::assign_camera_to_prop(
                 images,
@@ -614,11 +907,51 @@ impl Clipboard
                 drawing_resources,
                 things_catalog,
                 grid,
-                &mut self.props[index]
+                self.prop_mut(slot)
             );
 
             self.props_with_assigned_camera
-                .push((PropScreenshotTimer::new(camera.0.into()), index));
+                .push((PropScreenshotTimer::new(camera.0.into()), slot));
+        }
+    }
+
+    /// Loads the [`Prop`]s of the library and queues their screenshots.
+    #[inline]
+    fn load_library(
+        &mut self,
+        images: &mut Assets<Image>,
+        prop_cameras: &mut PropCamerasMut,
+        user_textures: &mut EguiUserTextures,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid
+    )
+    {
+        let (props, errors) = library::load();
+
+        if !errors.is_empty()
+        {
+            error_message(&format!("Error loading library props:\n{}", errors.join("\n")));
+        }
+
+        let mut prop_cameras = prop_cameras.iter_mut().filter(|camera| !camera.1.is_active);
+
+        for (name, prop) in props
+        {
+            let mut prop = Prop::from_viewer(prop);
+            _ = prop.reload_things(drawing_resources, things_catalog, grid);
+
+            let index = self.library.len();
+            self.library.push((name, prop));
+            self.queue_prop_screenshot(
+                images,
+                user_textures,
+                prop_cameras.next(),
+                drawing_resources,
+                things_catalog,
+                grid,
+                PropSlot::Library(index)
+            );
         }
     }
 
@@ -692,6 +1025,27 @@ impl Clipboard
         Ok(())
     }
 
+    /// Writes the serialized quick [`Prop`]s containing entities in `writer`, each one preceded
+    /// by the index of its slot.
+    #[inline]
+    pub(in crate::map::editor::state) fn export_quick_props(
+        &self,
+        writer: &mut BufWriter<&mut Vec<u8>>
+    ) -> Result<(), &'static str>
+    {
+        for (i, prop) in self
+            .quick_props
+            .iter()
+            .enumerate()
+            .filter(|(_, prop)| prop.has_data())
+        {
+            ciborium::ser::into_writer(&(i, prop.clone().to_viewer()), &mut *writer)
+                .map_err(|_| "Error saving quick prop")?;
+        }
+
+        Ok(())
+    }
+
     /// Queues the screenshots of the [`Prop`]s that must be retaken after a things reload.
     #[inline]
     pub(in crate::map::editor::state) fn reload_things(
@@ -706,11 +1060,11 @@ impl Clipboard
     {
         let mut prop_cameras = prop_cameras.iter_mut();
 
-        for i in 0..self.props.len()
+        for slot in self.prop_slots()
         {
-            let prop = &mut self.props[i];
-
-            if prop.reload_things(drawing_resources, things_catalog, grid)
+            if self
+                .prop_mut(slot)
+                .reload_things(drawing_resources, things_catalog, grid)
             {
                 self.queue_prop_screenshot(
                     images,
@@ -719,7 +1073,7 @@ impl Clipboard
                     drawing_resources,
                     things_catalog,
                     grid,
-                    i
+                    slot
                 );
             }
         }
@@ -739,11 +1093,11 @@ impl Clipboard
     {
         let mut prop_cameras = prop_cameras.iter_mut();
 
-        for i in 0..self.props.len()
+        for slot in self.prop_slots()
         {
-            let prop = &mut self.props[i];
-
-            if prop.reload_textures(drawing_resources, things_catalog, grid)
+            if self
+                .prop_mut(slot)
+                .reload_textures(drawing_resources, things_catalog, grid)
             {
                 self.queue_prop_screenshot(
                     images,
@@ -752,12 +1106,122 @@ impl Clipboard
                     drawing_resources,
                     things_catalog,
                     grid,
-                    i
+                    slot
                 );
             }
         }
     }
 
+    /// Replaces the texture `prev` with `new` in the brushes of the stored [`Prop`]s.
+    #[inline]
+    pub(in crate::map::editor::state) fn rename_texture(&mut self, prev: &str, new: &str)
+    {
+        _ = self.copy_paste.rename_texture(prev, new);
+        _ = self.duplicate.rename_texture(prev, new);
+
+        for slot in self.prop_slots()
+        {
+            if !self.prop_mut(slot).rename_texture(prev, new)
+            {
+                continue;
+            }
+
+            match slot
+            {
+                PropSlot::History(_) => (),
+                PropSlot::Library(index) =>
+                {
+                    let (name, prop) = &self.library[index];
+
+                    if let Err(err) = library::save(name, &prop.clone().to_viewer())
+                    {
+                        error_message(err);
+                    }
+                },
+                _ => self.props_changed = true
+            };
+        }
+    }
+
+    /// Swaps the [`Prop`]s of the map being edited, along with their queued screenshots, with
+    /// `map_props`.
+    #[inline]
+    pub(in crate::map::editor::state) fn swap_map_props(&mut self, map_props: &mut MapProps)
+    {
+        std::mem::swap(&mut self.quick_props, &mut map_props.quick_props);
+        std::mem::swap(&mut self.selected_quick_prop, &mut map_props.selected_quick_prop);
+        std::mem::swap(&mut self.props, &mut map_props.props);
+        std::mem::swap(&mut self.selected_prop, &mut map_props.selected_prop);
+        std::mem::swap(&mut self.props_changed, &mut map_props.props_changed);
+
+        if self.selected_prop.is_some()
+        {
+            self.selected_library_prop = None;
+        }
+
+        let mut screenshots = Vec::new();
+        let mut i = 0;
+
+        while i < self.props_with_assigned_camera.len()
+        {
+            if self.props_with_assigned_camera[i].1.is_map_prop()
+            {
+                let (timer, slot) = self.props_with_assigned_camera.remove(i);
+                screenshots.push((Some(timer), slot));
+                continue;
+            }
+
+            i += 1;
+        }
+
+        self.props_with_no_camera.retain(|slot| {
+            if slot.is_map_prop()
+            {
+                screenshots.push((None, *slot));
+                return false;
+            }
+
+            true
+        });
+
+        for (timer, slot) in std::mem::replace(&mut map_props.screenshots, screenshots)
+        {
+            match timer
+            {
+                Some(timer) => self.props_with_assigned_camera.push((timer, slot)),
+                None => self.props_with_no_camera.push(slot)
+            };
+        }
+    }
+
+    /// Replaces the [`Prop`]s of the map being edited with the ones of `clipboard`, keeping the
+    /// copied entities, the copy history, and the library.
+    #[inline]
+    pub(in crate::map::editor::state) fn replace_map_props(
+        &mut self,
+        prop_cameras: &mut PropCamerasMut,
+        mut clipboard: Clipboard
+    )
+    {
+        let mut map_props = MapProps::default();
+        clipboard.swap_map_props(&mut map_props);
+        self.swap_map_props(&mut map_props);
+        Self::discard_map_props(prop_cameras, map_props);
+    }
+
+    /// Disables the cameras taking the screenshots of `map_props`.
+    #[inline]
+    pub(in crate::map::editor::state) fn discard_map_props(
+        prop_cameras: &mut PropCamerasMut,
+        map_props: MapProps
+    )
+    {
+        for timer in map_props.screenshots.into_iter().filter_map(|(timer, _)| timer)
+        {
+            prop_cameras.get_mut(timer.id()).unwrap().1.is_active = false;
+        }
+    }
+
     /// Resets the state changed flag.
     #[inline]
     pub(in crate::map::editor::state) fn reset_props_changed(&mut self)
@@ -765,6 +1229,16 @@ impl Clipboard
         self.props_changed = false;
     }
 
+    /// Sets the index of the selected quick [`Prop`].
+    /// # Panics
+    /// Panics if `slot` is equal or higher than [`QUICK_PROPS_AMOUNT`].
+    #[inline]
+    pub(in crate::map::editor::state) fn set_selected_quick_prop_index(&mut self, slot: usize)
+    {
+        assert!(slot < QUICK_PROPS_AMOUNT, "Slot {slot} is out of bounds for the quick props.");
+        self.selected_quick_prop = slot;
+    }
+
     /// Sets the index of the selected slotted [`Prop`].
     /// # Panics
     /// Panics if `slot` is equal or higher than the length of the slotted [`Prop`]s.
@@ -777,15 +1251,34 @@ impl Clipboard
             self.props.len()
         );
         self.selected_prop = Some(slot);
+        self.selected_library_prop = None;
+    }
+
+    /// Sets the index of the selected library [`Prop`].
+    /// # Panics
+    /// Panics if `index` is equal or higher than the amount of [`Prop`]s of the library.
+    #[inline]
+    pub(in crate::map::editor::state) fn set_selected_library_prop_index(&mut self, index: usize)
+    {
+        assert!(
+            index < self.library.len(),
+            "Index {index} is out of bounds, length of the library is {}",
+            self.library.len()
+        );
+        self.selected_library_prop = Some(index);
+        self.selected_prop = None;
     }
 
     //==============================================================
     // Entities
 
-    /// Stores the entities in `iter` as a copy-paste [`Prop`].
+    /// Stores the entities in `iter` as a copy-paste [`Prop`], and adds them to the copy history.
     #[inline]
     pub(in crate::map::editor::state) fn copy<'a, E>(
         &mut self,
+        images: &mut Assets<Image>,
+        prop_cameras: &mut PropCamerasMut,
+        user_textures: &mut EguiUserTextures,
         drawing_resources: &DrawingResources,
         things_catalog: &ThingsCatalog,
         grid: &Grid,
@@ -799,6 +1292,45 @@ impl Clipboard
             grid,
             iter.map(|e| e.copy_to_clipboard())
         );
+
+        if !self.copy_paste.has_data()
+        {
+            return;
+        }
+
+        let index = self.copy_history_next;
+        let mut prop = self.copy_paste.clone();
+        prop.screenshot = None;
+
+        if index == self.copy_history.len()
+        {
+            self.copy_history.push(prop);
+        }
+        else
+        {
+            self.dequeue_prop_screenshot(prop_cameras, PropSlot::History(index));
+            self.copy_history[index] = prop;
+        }
+
+        self.copy_history_next = (index + 1) % COPY_HISTORY_LENGTH;
+
+        let camera = prop_cameras.iter_mut().find(|camera| !camera.1.is_active);
+        self.queue_prop_screenshot(
+            images,
+            user_textures,
+            camera,
+            drawing_resources,
+            things_catalog,
+            grid,
+            PropSlot::History(index)
+        );
+    }
+
+    /// Replaces the copy-paste [`Prop`] with the entry of the copy history at `index`.
+    #[inline]
+    pub(in crate::map::editor::state) fn restore_copy_from_history(&mut self, index: usize)
+    {
+        self.copy_paste = self.copy_history[index].clone();
     }
 
     /// Pastes the copied entities.
@@ -810,7 +1342,8 @@ impl Clipboard
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         grid: &Grid,
-        cursor_pos: Vec2
+        cursor_pos: Vec2,
+        snap: PasteSnap
     )
     {
         self.copy_paste.spawn_copy(
@@ -819,7 +1352,8 @@ impl Clipboard
             manager,
             edits_history,
             grid,
-            cursor_pos
+            cursor_pos,
+            snap
         );
     }
 
@@ -831,7 +1365,9 @@ impl Clipboard
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         grid: &Grid,
-        delta: Vec2
+        delta: Vec2,
+        snap: PasteSnap,
+        move_texture: bool
     )
     {
         self.duplicate.fill(
@@ -842,6 +1378,9 @@ impl Clipboard
         );
 
         manager.deselect_selected_entities(edits_history);
+        let delta =
+            self.duplicate
+                .snapped_delta(drawing_resources, things_catalog, grid, delta, snap);
 
         self.duplicate.spawn(
             drawing_resources,
@@ -849,15 +1388,77 @@ impl Clipboard
             manager,
             edits_history,
             grid,
-            delta
+            delta,
+            move_texture
         );
     }
 
-    /// Stores `prop` as the quick [`Prop`].
+    /// Spawns a copy of the selected entities centered at each one of `positions`, and deselects
+    /// the originals. Nothing is spawned if any of the copies would be out of bounds.
     #[inline]
-    pub(in crate::map::editor::state) fn create_quick_prop(&mut self, prop: Prop)
+    pub(in crate::map::editor::state) fn distribute(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        positions: &[Vec2]
+    )
     {
-        self.quick_prop = prop;
+        self.duplicate.fill(
+            drawing_resources,
+            things_catalog,
+            grid,
+            manager.selected_entities().map(|e| e.copy_to_clipboard())
+        );
+
+        let center = self.duplicate.center();
+
+        if positions.iter().any(|pos| {
+            self.duplicate.out_of_bounds_moved(
+                drawing_resources,
+                things_catalog,
+                grid,
+                *pos - center
+            )
+        })
+        {
+            error_message("Cannot distribute copies: out of bounds");
+            return;
+        }
+
+        edits_history.with_group("Entities distribution", |edits_history| {
+            manager.deselect_selected_entities(edits_history);
+
+            for pos in positions
+            {
+                self.duplicate.spawn(
+                    drawing_resources,
+                    things_catalog,
+                    manager,
+                    edits_history,
+                    grid,
+                    *pos - center,
+                    true
+                );
+            }
+        });
+    }
+
+    /// Stores `prop` in the selected quick [`Prop`] slot.
+    #[inline]
+    pub(in crate::map::editor::state) fn create_quick_prop(
+        &mut self,
+        prop_cameras: &mut PropCamerasMut,
+        prop: Prop
+    )
+    {
+        assert!(prop.screenshot.is_some(), "Tried to insert quick prop without a screenshot.");
+
+        self.props_changed = true;
+        self.dequeue_prop_screenshot(prop_cameras, PropSlot::Quick(self.selected_quick_prop));
+        self.quick_props[self.selected_quick_prop] = prop;
     }
 
     /// Inserts a slotted [`Prop`] at the specified `slot`.
@@ -876,6 +1477,8 @@ impl Clipboard
 
         self.props[slot] = prop;
 
+        let slot = PropSlot::Slotted(slot);
+
         if let Some(i) = self
             .props_with_assigned_camera
             .iter()
@@ -885,82 +1488,165 @@ impl Clipboard
         }
         else if let Some(i) = self.props_with_no_camera.iter().position(|idx| *idx == slot)
         {
-            self.props_with_no_camera.remove(i);
+            _ = self.props_with_no_camera.remove(i);
         }
     }
 
-    /// Deletes the [`Prop`] stored at the selected index, if any.
+    /// Stores `prop` in the library with name `name`, replacing the [`Prop`] with the same name, if
+    /// any.
     #[inline]
-    pub(in crate::map::editor::state) fn delete_selected_prop(
+    pub(in crate::map::editor::state) fn save_prop_to_library(
         &mut self,
-        prop_cameras: &mut PropCamerasMut
+        prop_cameras: &mut PropCamerasMut,
+        name: &str,
+        prop: Prop
     )
     {
-        let selected_prop = return_if_none!(self.selected_prop);
-        self.props_changed = true;
-        let no_screenshot = self.props.remove(selected_prop).screenshot.is_none();
+        assert!(prop.screenshot.is_some(), "Tried to store library prop without a screenshot.");
 
-        self.selected_prop = if self.props.is_empty()
+        if let Err(err) = library::save(name, &prop.clone().to_viewer())
         {
-            None
+            error_message(err);
+            return;
         }
-        else
+
+        match self.library.binary_search_by(|(n, _)| n.as_str().cmp(name))
         {
-            selected_prop.min(self.props.len() - 1).into()
+            Ok(index) =>
+            {
+                self.dequeue_prop_screenshot(prop_cameras, PropSlot::Library(index));
+                self.library[index].1 = prop;
+            },
+            Err(index) =>
+            {
+                self.library.insert(index, (name.to_owned(), prop));
+                self.shift_library_slots(index, true);
+
+                if let Some(selected) = &mut self.selected_library_prop
+                {
+                    if *selected >= index
+                    {
+                        *selected += 1;
+                    }
+                }
+            }
         };
+    }
 
-        if !no_screenshot
+    /// Deletes the selected library [`Prop`], if any, along with its file.
+    #[inline]
+    pub(in crate::map::editor::state) fn delete_selected_library_prop(
+        &mut self,
+        prop_cameras: &mut PropCamerasMut
+    )
+    {
+        let selected = return_if_none!(self.selected_library_prop);
+
+        if let Err(err) = library::delete(&self.library[selected].0)
         {
+            error_message(err);
             return;
         }
 
-        if let Some((i, (timer, _))) = self
-            .props_with_assigned_camera
-            .iter()
-            .copied()
-            .enumerate()
-            .find(|(_, (_, idx))| *idx == selected_prop)
-        {
-            prop_cameras.get_mut(timer.id()).unwrap().1.is_active = false;
+        self.dequeue_prop_screenshot(prop_cameras, PropSlot::Library(selected));
+        _ = self.library.remove(selected);
+        self.shift_library_slots(selected, false);
 
-            _ = self.props_with_assigned_camera.remove(i);
+        self.selected_library_prop = if self.library.is_empty()
+        {
+            None
+        }
+        else
+        {
+            selected.min(self.library.len() - 1).into()
+        };
+    }
 
-            for (_, idx) in self.props_with_assigned_camera.iter_mut().skip(i)
+    /// Shifts by one the indexes of the queued library [`Prop`] screenshots equal or higher than
+    /// `index`, forward if `forward` is true, backward otherwise.
+    #[inline]
+    fn shift_library_slots(&mut self, index: usize, forward: bool)
+    {
+        for slot in self
+            .props_with_assigned_camera
+            .iter_mut()
+            .map(|(_, slot)| slot)
+            .chain(&mut self.props_with_no_camera)
+        {
+            if let PropSlot::Library(idx) = slot
             {
-                *idx -= 1;
+                if *idx < index
+                {
+                    continue;
+                }
+
+                if forward
+                {
+                    *idx += 1;
+                }
+                else
+                {
+                    *idx -= 1;
+                }
             }
+        }
+    }
 
-            let i = return_if_none!(self
-                .props_with_no_camera
-                .iter()
-                .position(|idx| *idx > selected_prop));
-
-            for idx in self.props_with_no_camera.iter_mut().skip(i)
-            {
-                *idx -= 1;
-            }
+    /// Deletes the [`Prop`] stored at the selected index, if any.
+    #[inline]
+    pub(in crate::map::editor::state) fn delete_selected_prop(
+        &mut self,
+        prop_cameras: &mut PropCamerasMut
+    )
+    {
+        let selected_prop = return_if_none!(self.selected_prop);
+        self.props_changed = true;
+        _ = self.props.remove(selected_prop);
 
-            return;
+        self.selected_prop = if self.props.is_empty()
+        {
+            None
         }
+        else
+        {
+            selected_prop.min(self.props.len() - 1).into()
+        };
 
-        let i =
-            return_if_none!(self.props_with_no_camera.iter().position(|idx| *idx == selected_prop));
-
-        self.props_with_no_camera.remove(i);
+        self.dequeue_prop_screenshot(prop_cameras, PropSlot::Slotted(selected_prop));
 
-        for idx in self.props_with_no_camera.iter_mut().skip(i)
+        // Shift the indexes of the slotted props that came after the removed one.
+        for slot in self
+            .props_with_assigned_camera
+            .iter_mut()
+            .map(|(_, slot)| slot)
+            .chain(&mut self.props_with_no_camera)
         {
-            *idx -= 1;
+            if let PropSlot::Slotted(idx) = slot
+            {
+                if *idx > selected_prop
+                {
+                    *idx -= 1;
+                }
+            }
         }
+    }
 
-        let i = return_if_none!(self
+    /// Removes the [`Prop`] at `slot` from the ones waiting for their screenshot, disabling the
+    /// assigned camera if any.
+    #[inline]
+    fn dequeue_prop_screenshot(&mut self, prop_cameras: &mut PropCamerasMut, slot: PropSlot)
+    {
+        if let Some(i) = self
             .props_with_assigned_camera
             .iter()
-            .position(|(_, idx)| *idx > selected_prop));
-
-        for (_, idx) in self.props_with_assigned_camera.iter_mut().skip(i)
+            .position(|(_, idx)| *idx == slot)
         {
-            *idx -= 1;
+            let (timer, _) = self.props_with_assigned_camera.remove(i);
+            prop_cameras.get_mut(timer.id()).unwrap().1.is_active = false;
+        }
+        else if let Some(i) = self.props_with_no_camera.iter().position(|idx| *idx == slot)
+        {
+            _ = self.props_with_no_camera.remove(i);
         }
     }
 
@@ -979,7 +1665,7 @@ impl Clipboard
     {
         if self.has_quick_prop()
         {
-            self.quick_prop.paint_copy(
+            self.quick_props[self.selected_quick_prop].paint_copy(
                 drawing_resources,
                 things_catalog,
                 manager,
@@ -994,7 +1680,7 @@ impl Clipboard
         false
     }
 
-    /// Spawns the selected [`Prop`] on the map.
+    /// Spawns the selected slotted or library [`Prop`] on the map.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn spawn_selected_prop(
@@ -1007,7 +1693,13 @@ impl Clipboard
         cursor_pos: Vec2
     ) -> bool
     {
-        self.props[return_if_none!(self.selected_prop, false)].paint_copy(
+        let prop = match self.selected_library_prop
+        {
+            Some(index) => &mut self.library[index].1,
+            None => &mut self.props[return_if_none!(self.selected_prop, false)]
+        };
+
+        prop.paint_copy(
             drawing_resources,
             things_catalog,
             manager,
@@ -1231,13 +1923,55 @@ impl Clipboard
         })
     }
 
+    /// Returns an iterator to the [`Prop`]s of the library.
+    #[inline]
+    pub(in crate::map::editor::state) fn library_ui_iter(
+        &self
+    ) -> impl ExactSizeIterator<Item = UiLibraryProp> + '_
+    {
+        self.library.iter().enumerate().map(|(index, (name, prop))| {
+            UiLibraryProp {
+                index,
+                name,
+                tex_id: prop.screenshot
+            }
+        })
+    }
+
+    /// Returns an iterator to the screenshots of the quick [`Prop`]s, `None` if the slot is empty
+    /// or the screenshot is yet to be taken.
+    #[inline]
+    pub(in crate::map::editor::state) fn quick_props_ui_iter(
+        &self
+    ) -> impl ExactSizeIterator<Item = Option<egui::TextureId>> + '_
+    {
+        self.quick_props
+            .iter()
+            .map(|prop| prop.screenshot.filter(|_| prop.has_data()))
+    }
+
+    /// Returns an iterator to the index and screenshot of the entries of the copy history, from
+    /// the most recent to the oldest.
+    #[inline]
+    pub(in crate::map::editor::state) fn copy_history_iter(
+        &self
+    ) -> impl Iterator<Item = (usize, Option<egui::TextureId>)> + '_
+    {
+        let len = self.copy_history.len();
+
+        (1..=len).map(move |i| {
+            let index = (self.copy_history_next + len - i) % len;
+            (index, self.copy_history[index].screenshot)
+        })
+    }
+
     /// Draws the [`Prop`]s to photograph for the preview.
     #[inline]
     pub(in crate::map::editor::state) fn draw_props_to_photograph(&self, bundle: &mut DrawBundle)
     {
-        for (timer, idx) in &self.props_with_assigned_camera
+        for (timer, slot) in &self.props_with_assigned_camera
         {
-            self.props[*idx].draw(bundle, (timer.id()).into());
+            self.prop(*slot).draw(bundle, (timer.id()).into());
         }
     }
 }
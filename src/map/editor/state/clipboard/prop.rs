@@ -33,13 +33,22 @@ use crate::{
     map::{
         drawer::{color::Color, drawing_resources::DrawingResources, TextureSize},
         editor::{
-            state::{edits_history::EditsHistory, grid::Grid, manager::EntitiesManager},
+            state::{
+                editor_state::PasteSnap,
+                edits_history::EditsHistory,
+                grid::Grid,
+                manager::EntitiesManager
+            },
             DrawBundle
         },
         thing::catalog::ThingsCatalog,
         Viewer
     },
-    utils::{hull::Hull, identifiers::EntityId}
+    utils::{
+        collections::{hash_map, HashMap},
+        hull::Hull,
+        identifiers::EntityId
+    }
 };
 
 //=======================================================================//
@@ -57,6 +66,31 @@ pub(in crate::map) struct PropViewer
     center:           Vec2
 }
 
+impl PropViewer
+{
+    /// Replaces the texture `prev` with `new` in the brushes. Returns whether anything was
+    /// changed.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn rename_texture(&mut self, prev: &str, new: &str) -> bool
+    {
+        let mut entities = std::mem::take(&mut self.entities)
+            .into_iter()
+            .map(ClipboardData::from_viewer)
+            .collect::<Vec<_>>();
+        let renamed = entities.iter_mut().fold(false, |renamed, data| {
+            match data
+            {
+                ClipboardData::Brush(data, _) => data.rename_texture(prev, new) || renamed,
+                ClipboardData::Thing(..) => renamed
+            }
+        });
+
+        self.entities = entities.into_iter().map(ClipboardData::to_viewer).collect();
+        renamed
+    }
+}
+
 //=======================================================================//
 
 /// An agglomeration of entities that can be spawned around the map.
@@ -229,7 +263,7 @@ impl Prop
     /// Panics if `self` has no stored screenshot.
     #[inline]
     #[must_use]
-    pub(in crate::map::editor::state) fn screenshot(&self) -> egui::TextureId
+    pub(in crate::map::editor::state) const fn screenshot(&self) -> egui::TextureId
     {
         self.screenshot.unwrap()
     }
@@ -240,6 +274,48 @@ impl Prop
     #[must_use]
     fn spawn_delta(&self, cursor_pos: Vec2) -> Vec2 { cursor_pos - self.center + self.pivot }
 
+    /// Returns the center of the area covered by the entities.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state::clipboard) const fn center(&self) -> Vec2 { self.center }
+
+    /// Whether any of the entities would be out of bounds if moved by `delta`.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state::clipboard) fn out_of_bounds_moved(
+        &self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        delta: Vec2
+    ) -> bool
+    {
+        self.entities
+            .iter()
+            .any(|item| item.out_of_bounds_moved(drawing_resources, things_catalog, grid, delta))
+    }
+
+    /// Returns `delta` adjusted so that the reference point of the spawned entities, as defined by
+    /// `snap`, lies on the grid.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state::clipboard) fn snapped_delta<T: TextureSize>(
+        &self,
+        resources: &T,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        delta: Vec2,
+        snap: PasteSnap
+    ) -> Vec2
+    {
+        if snap == PasteSnap::None || !self.has_data()
+        {
+            return delta;
+        }
+
+        delta + snap.snap_delta(grid, &(self.hull(resources, things_catalog, grid) + delta))
+    }
+
     //==============================================================
     // Update
 
@@ -406,10 +482,30 @@ impl Prop
         false
     }
 
+    /// Replaces the texture `prev` with `new` in the contained brushes. Returns whether anything
+    /// was changed.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state::clipboard) fn rename_texture(
+        &mut self,
+        prev: &str,
+        new: &str
+    ) -> bool
+    {
+        self.entities.iter_mut().fold(false, |renamed, data| {
+            match data
+            {
+                ClipboardData::Brush(data, _) => data.rename_texture(prev, new) || renamed,
+                ClipboardData::Thing(..) => renamed
+            }
+        })
+    }
+
     //==============================================================
     // Spawn
 
-    /// Spawns a copy of `self` moved by `delta`.
+    /// Spawns a copy of `self` moved by `delta`. If `move_texture` is false the textures of the
+    /// brushes, except the sprites, are not moved.
     #[inline]
     pub(in crate::map::editor::state::clipboard) fn spawn(
         &mut self,
@@ -418,7 +514,8 @@ impl Prop
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         grid: &Grid,
-        delta: Vec2
+        delta: Vec2,
+        move_texture: bool
     )
     {
         /// Spawns the entities stored in `prop`.
@@ -431,7 +528,9 @@ impl Prop
             edits_history: &mut EditsHistory,
             grid: &Grid,
             range: Rev<Range<usize>>,
-            delta: Vec2
+            delta: Vec2,
+            move_texture: bool,
+            groups: &mut HashMap<u32, u32>
         )
         {
             for i in range
@@ -443,7 +542,9 @@ impl Prop
                     edits_history,
                     grid,
                     item.clone(),
-                    delta
+                    delta,
+                    move_texture,
+                    groups
                 );
 
                 match item
@@ -455,15 +556,14 @@ impl Prop
 
         assert!(self.has_data(), "Prop contains no entities.");
 
-        if self
-            .entities
-            .iter()
-            .any(|item| item.out_of_bounds_moved(drawing_resources, things_catalog, grid, delta))
+        if self.out_of_bounds_moved(drawing_resources, things_catalog, grid, delta)
         {
             error_message("Cannot spawn copy: out of bounds");
             return;
         }
 
+        let mut groups = hash_map![];
+
         spawn_regular(
             self,
             drawing_resources,
@@ -472,7 +572,9 @@ impl Prop
             edits_history,
             grid,
             (self.attached_brushes.end..self.entities.len()).rev(),
-            delta
+            delta,
+            move_texture,
+            &mut groups
         );
 
         for i in self.attached_brushes.clone().rev()
@@ -485,7 +587,9 @@ impl Prop
                 edits_history,
                 grid,
                 item.clone(),
-                delta
+                delta,
+                move_texture,
+                &mut groups
             );
 
             match item
@@ -512,7 +616,9 @@ impl Prop
             edits_history,
             grid,
             (0..self.attached_brushes.start).rev(),
-            delta
+            delta,
+            move_texture,
+            &mut groups
         );
     }
 
@@ -525,7 +631,8 @@ impl Prop
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         grid: &Grid,
-        cursor_pos: Vec2
+        cursor_pos: Vec2,
+        snap: PasteSnap
     )
     {
         let mut delta = self.spawn_delta(cursor_pos);
@@ -541,7 +648,8 @@ impl Prop
             }
         }
 
-        self.spawn(drawing_resources, things_catalog, manager, edits_history, grid, delta);
+        let delta = self.snapped_delta(drawing_resources, things_catalog, grid, delta, snap);
+        self.spawn(drawing_resources, things_catalog, manager, edits_history, grid, delta, true);
     }
 
     /// Spawns a copy of `self` as if it were a brush of a image editing software.
@@ -562,7 +670,8 @@ impl Prop
             manager,
             edits_history,
             grid,
-            self.spawn_delta(cursor_pos)
+            self.spawn_delta(cursor_pos),
+            true
         );
     }
 
@@ -611,7 +720,7 @@ impl PropScreenshotTimer
     /// Returns the [`Entity`] of the assigned camera.
     #[inline]
     #[must_use]
-    pub fn id(&self) -> Entity { self.1.unwrap() }
+    pub const fn id(&self) -> Entity { self.1.unwrap() }
 
     /// Updates the assigned camera, deactivating it once the time has finished. Returns whether the
     /// camera has been disabled.
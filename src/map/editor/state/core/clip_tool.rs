@@ -9,13 +9,14 @@ use hill_vacuum_proc_macros::{EnumFromUsize, EnumSize};
 use hill_vacuum_shared::{continue_if_none, match_or_panic, return_if_no_match, return_if_none};
 
 use super::{
+    cursor_delta::CursorDelta,
     draw_selected_and_non_selected_brushes,
     tool::{subtools_buttons, DisableSubtool, EnabledTool, OngoingMultiframeChange, SubTool},
     ActiveTool
 };
 use crate::{
     map::{
-        brush::{convex_polygon::ConvexPolygon, ClipResult},
+        brush::{convex_polygon::ConvexPolygon, BrushClipResult, ClipResult},
         drawer::{color::Color, drawers::EditDrawer},
         editor::{
             cursor::Cursor,
@@ -96,6 +97,32 @@ impl PickedPolygons
 
 //=======================================================================//
 
+/// The pieces to use to spawn the new brushes when clipping by brush.
+#[derive(Clone, Copy, Default, EnumSize, EnumFromUsize)]
+enum PickedPieces
+{
+    /// Both inside and outside the cutter brush.
+    #[default]
+    Both,
+    /// Inside the cutter brush.
+    Inside,
+    /// Outside the cutter brush.
+    Outside
+}
+
+impl PickedPieces
+{
+    /// Returns the previous value of `self`.
+    #[inline]
+    fn previous(&mut self) { *self = prev(*self as usize, Self::SIZE).into(); }
+
+    /// Returns the next value of `self`.
+    #[inline]
+    fn next(&mut self) { *self = next(*self as usize, Self::SIZE).into(); }
+}
+
+//=======================================================================//
+
 /// The state of the clip tool.
 #[must_use]
 enum Status
@@ -111,6 +138,15 @@ enum Status
         pick:    PickedPolygons,
         results: HashMap<Id, ClipResult>
     },
+    /// Choosing the pieces to spawn after clipping by brush.
+    PostBrushClip
+    {
+        /// The pieces picked to spawn the brushes.
+        pick:    PickedPieces,
+        /// The [`Id`] of the cutter brush.
+        cutter:  Id,
+        results: HashMap<Id, BrushClipResult>
+    },
     /// Choosing the side to clip the brushes.
     PickSideUi(Option<ClipSide>)
 }
@@ -172,7 +208,10 @@ impl DisableSubtool for ClipTool
 impl OngoingMultiframeChange for ClipTool
 {
     #[inline]
-    fn ongoing_multi_frame_change(&self) -> bool { matches!(self.0, Status::PostClip { .. }) }
+    fn ongoing_multi_frame_change(&self) -> bool
+    {
+        matches!(self.0, Status::PostClip { .. } | Status::PostBrushClip { .. })
+    }
 }
 
 impl ClipTool
@@ -221,6 +260,12 @@ impl ClipTool
 
                     self.clip_brushes_with_side(bundle.manager);
                 }
+                else if left_mouse_just_pressed &&
+                    bundle.inputs.ctrl_pressed() &&
+                    bundle.manager.selected_brushes_amount() > 1
+                {
+                    self.clip_brushes_with_brush(bundle.manager, cursor_pos);
+                }
                 else if left_mouse_just_pressed
                 {
                     self.0 = Status::Active(cursor_pos, None);
@@ -258,6 +303,24 @@ impl ClipTool
                     self.spawn_clipped_brushes(bundle);
                 }
             },
+            Status::PostBrushClip { pick, .. } =>
+            {
+                if bundle.inputs.tab.just_pressed()
+                {
+                    if bundle.inputs.alt_pressed()
+                    {
+                        pick.previous();
+                    }
+                    else
+                    {
+                        pick.next();
+                    }
+                }
+                else if bundle.inputs.enter.just_pressed()
+                {
+                    self.spawn_brush_clipped_brushes(bundle);
+                }
+            },
             Status::PickSideUi(clip_side) =>
             {
                 *clip_side = None;
@@ -324,6 +387,36 @@ impl ClipTool
         clip_brushes!(self, clip_segment, manager.selected_brushes());
     }
 
+    /// Clips the selected brushes against every side of the selected brush beneath `cursor_pos`,
+    /// if any.
+    #[inline]
+    fn clip_brushes_with_brush(&mut self, manager: &EntitiesManager, cursor_pos: Vec2)
+    {
+        let cutter = return_if_none!(manager
+            .selected_brushes_at_pos(cursor_pos, None)
+            .iter()
+            .find_map(|brush| brush.contains_point(cursor_pos).then_some(brush.id())));
+        let cutter_brush = manager.brush(cutter);
+
+        let results = manager
+            .selected_brushes()
+            .filter_set_with_predicate(cutter, |brush| brush.id())
+            .filter_map(|brush| brush.clip_by_brush(cutter_brush))
+            .map(|result| (result.id, result))
+            .collect::<HashMap<_, _>>();
+
+        if results.is_empty()
+        {
+            return;
+        }
+
+        self.0 = Status::PostBrushClip {
+            pick: PickedPieces::default(),
+            cutter,
+            results
+        };
+    }
+
     /// Spawns the generated brushes.
     #[inline]
     fn spawn_clipped_brushes(&mut self, bundle: &mut ToolUpdateBundle)
@@ -392,6 +485,65 @@ impl ClipTool
         self.0 = Status::default();
     }
 
+    /// Spawns the brushes generated by the clip by brush.
+    #[inline]
+    fn spawn_brush_clipped_brushes(&mut self, bundle: &mut ToolUpdateBundle)
+    {
+        let (pick, results) = match_or_panic!(
+            &mut self.0,
+            Status::PostBrushClip { pick, results, .. },
+            (*pick, results.take_value())
+        );
+
+        for (id, result) in results
+        {
+            let BrushClipResult {
+                inside,
+                mut outside,
+                ..
+            } = result;
+
+            let (main, mut others) = match pick
+            {
+                PickedPieces::Both => (inside, outside),
+                PickedPieces::Inside => (inside, Vec::new()),
+                PickedPieces::Outside =>
+                {
+                    if outside.is_empty()
+                    {
+                        bundle.manager.despawn_brush(
+                            bundle.drawing_resources,
+                            bundle.edits_history,
+                            bundle.grid,
+                            id
+                        );
+                        continue;
+                    }
+
+                    let main = outside.swap_remove(0);
+                    (main, outside)
+                }
+            };
+
+            for polygon in others.iter_mut().filter(|polygon| polygon.has_sprite())
+            {
+                _ = polygon.remove_texture();
+            }
+
+            _ = bundle.manager.replace_brush_with_partition(
+                bundle.drawing_resources,
+                bundle.edits_history,
+                bundle.grid,
+                others.into_iter(),
+                id,
+                |brush| brush.set_polygon(main)
+            );
+        }
+
+        bundle.edits_history.override_edit_tag("Brushes Clip");
+        self.0 = Status::default();
+    }
+
     //==============================================================
     // Draw
 
@@ -427,6 +579,13 @@ impl ClipTool
                 if let Some(ce) = ce
                 {
                     bundle.drawer.infinite_line(*co, *ce, Color::ToolCursor);
+                    CursorDelta::draw_distance_between(
+                        bundle.window,
+                        bundle.camera,
+                        bundle.drawer,
+                        *co,
+                        *ce
+                    );
                 }
             },
             Status::PostClip { pick, results } =>
@@ -523,6 +682,63 @@ impl ClipTool
                         }
                     }
                 };
+            },
+            Status::PostBrushClip {
+                pick,
+                cutter,
+                results
+            } =>
+            {
+                let DrawBundle {
+                    window,
+                    drawer,
+                    camera,
+                    manager,
+                    ..
+                } = bundle;
+
+                for brush in manager.visible_brushes(window, camera, drawer.grid()).iter()
+                {
+                    let id = brush.id();
+
+                    if results.contains_key(&id) || id == *cutter
+                    {
+                        continue;
+                    }
+
+                    if manager.is_selected(id)
+                    {
+                        brush.draw_selected(drawer);
+                    }
+                    else
+                    {
+                        brush.draw_non_selected(drawer);
+                    }
+                }
+
+                manager.brush(*cutter).draw_with_color(drawer, Color::SubtractorBrush);
+
+                let (inside, outside) = match pick
+                {
+                    PickedPieces::Both =>
+                    {
+                        (Color::ClippedPolygonsToSpawn, Color::ClippedPolygonsToSpawn)
+                    },
+                    PickedPieces::Inside => (Color::ClippedPolygonsToSpawn, Color::OpaqueEntity),
+                    PickedPieces::Outside => (Color::OpaqueEntity, Color::ClippedPolygonsToSpawn)
+                };
+
+                for result in results.values()
+                {
+                    let collision = manager.brush(result.id).collision();
+
+                    result.inside.draw(drawer, collision, inside);
+
+                    for polygon in &result.outside
+                    {
+                        polygon.draw(drawer, collision, outside);
+                    }
+                }
             }
         };
     }
@@ -531,6 +747,12 @@ impl ClipTool
     #[inline]
     pub fn ui(&mut self, ui: &mut egui::Ui)
     {
+        if let Status::PostBrushClip { pick, .. } = &mut self.0
+        {
+            Self::brush_clip_ui(ui, pick);
+            return;
+        }
+
         let pick = return_if_no_match!(&mut self.0, Status::PostClip { pick, .. }, pick);
 
         ui.label(egui::RichText::new("CLIP TOOL"));
@@ -569,6 +791,46 @@ impl ClipTool
         });
     }
 
+    /// Draws the UI of the clip by brush.
+    #[inline]
+    fn brush_clip_ui(ui: &mut egui::Ui, pick: &mut PickedPieces)
+    {
+        ui.label(egui::RichText::new("CLIP TOOL"));
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Keep:"));
+
+            let both_button = ui.button(egui::RichText::new("Both"));
+            let inside_button = ui.button(egui::RichText::new("Inside"));
+            let outside_button = ui.button(egui::RichText::new("Outside"));
+
+            for b in [&both_button, &inside_button, &outside_button]
+            {
+                b.surrender_focus();
+            }
+
+            if both_button.clicked()
+            {
+                *pick = PickedPieces::Both;
+            }
+            else if inside_button.clicked()
+            {
+                *pick = PickedPieces::Inside;
+            }
+            else if outside_button.clicked()
+            {
+                *pick = PickedPieces::Outside;
+            }
+
+            match pick
+            {
+                PickedPieces::Both => both_button.highlight(),
+                PickedPieces::Inside => inside_button.highlight(),
+                PickedPieces::Outside => outside_button.highlight()
+            };
+        });
+    }
+
     /// Draws the subtools.
     #[inline]
     pub fn draw_subtools(
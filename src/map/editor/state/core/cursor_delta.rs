@@ -3,18 +3,33 @@
 //
 //=======================================================================//
 
+use bevy::{transform::components::Transform, window::Window};
 use bevy_egui::egui;
 use glam::Vec2;
 use hill_vacuum_shared::return_if_none;
 
 use crate::{
     map::{
-        drawer::color::Color,
-        editor::{cursor::Cursor, state::grid::Grid, DrawBundle}
+        drawer::{color::Color, drawers::EditDrawer},
+        editor::{
+            cursor::Cursor,
+            state::{grid::Grid, inputs_presses::InputsPresses, manager::EntitiesManager},
+            DrawBundle
+        }
     },
-    utils::math::AroundEqual
+    utils::math::{AroundEqual, NecessaryPrecisionValue}
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The color of the tooltips.
+const TOOLTIP_TEXT_COLOR: egui::Color32 = egui::Color32::from_rgb(127, 255, 212);
+/// The increment, in degrees, the angle of a constrained edge is snapped to.
+const ANGLE_STEP: f32 = 15f32;
+
 //=======================================================================//
 // STRUCTS
 //
@@ -32,6 +47,8 @@ pub(in crate::map) struct CursorDelta
 
 impl CursorDelta
 {
+    /// The label of the drag distance tooltip.
+    pub(in crate::map) const DISTANCE: &'static str = "drag_distance";
     /// The label of the horizontal delta tooltip.
     pub(in crate::map) const X_DELTA: &'static str = "x_delta";
     /// The label of the vertical delta tooltip.
@@ -58,9 +75,31 @@ impl CursorDelta
     ) -> Option<Self>
     {
         let drag = Self::new(origin);
-        drag.overall_and_frame_drag_delta_from_origin(cursor, grid)
-            .is_some()
-            .then_some(drag)
+        drag.overall_and_frame_drag_delta_from_origin(
+            cursor.world_snapped(),
+            cursor.snap().then_some(*grid)
+        )
+        .is_some()
+        .then_some(drag)
+    }
+
+    /// Tries to create a new [`Drag`] from the parameters, snapping the cursor position according
+    /// to the path nodes snap setting.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state::core) fn try_new_path(
+        cursor: &Cursor,
+        grid: &Grid,
+        origin: Vec2
+    ) -> Option<Self>
+    {
+        let drag = Self::new(origin);
+        drag.overall_and_frame_drag_delta_from_origin(
+            cursor.world_path_snapped(),
+            cursor.path_snap_size().map(|size| grid.with_size(size))
+        )
+        .is_some()
+        .then_some(drag)
     }
 
     /// Returns the delta.
@@ -78,8 +117,11 @@ impl CursorDelta
         dragger: F
     )
     {
-        let (overall_delta, frame_delta) =
-            return_if_none!(self.overall_and_frame_drag_delta_from_origin(cursor, grid));
+        let (overall_delta, frame_delta) = return_if_none!(self
+            .overall_and_frame_drag_delta_from_origin(
+                cursor.world_snapped(),
+                cursor.snap().then_some(*grid)
+            ));
 
         if dragger(frame_delta)
         {
@@ -87,6 +129,53 @@ impl CursorDelta
         }
     }
 
+    /// Same as [`Self::conditional_update`], except the cursor position is snapped according to
+    /// the path nodes snap setting.
+    #[inline]
+    pub(in crate::map::editor::state::core) fn conditional_update_path<F: FnOnce(Vec2) -> bool>(
+        &mut self,
+        cursor: &Cursor,
+        grid: &Grid,
+        dragger: F
+    )
+    {
+        let (overall_delta, frame_delta) = return_if_none!(self
+            .overall_and_frame_drag_delta_from_origin(
+                cursor.world_path_snapped(),
+                cursor.path_snap_size().map(|size| grid.with_size(size))
+            ));
+
+        if dragger(frame_delta)
+        {
+            self.delta = overall_delta;
+        }
+    }
+
+    /// Updates `self` moving the dragged vertex as dictated by `constraint` and executes `dragger`
+    /// if the delta changed. `dragger` is fed the delta of the current frame. The new overall
+    /// delta is stored if `dragger` returns true.
+    #[inline]
+    pub(in crate::map::editor::state::core) fn constrained_update<F: FnOnce(Vec2) -> bool>(
+        &mut self,
+        cursor: &Cursor,
+        constraint: &EdgeConstraint,
+        dragger: F
+    )
+    {
+        let overall_delta =
+            return_if_none!(constraint.constrained_delta(cursor.world_snapped() - self.origin));
+
+        if overall_delta.around_equal_narrow(&self.delta)
+        {
+            return;
+        }
+
+        if dragger(overall_delta - self.delta)
+        {
+            self.delta = overall_delta;
+        }
+    }
+
     /// Updates `self` and executes `dragger` if the delta changed. `dragger` is fed the delta of
     /// the current frame.
     #[inline]
@@ -97,27 +186,29 @@ impl CursorDelta
         dragger: F
     )
     {
-        let (overall_delta, frame_delta) =
-            return_if_none!(self.overall_and_frame_drag_delta_from_origin(cursor, grid));
+        let (overall_delta, frame_delta) = return_if_none!(self
+            .overall_and_frame_drag_delta_from_origin(
+                cursor.world_snapped(),
+                cursor.snap().then_some(*grid)
+            ));
 
         dragger(frame_delta);
         self.delta = overall_delta;
     }
 
-    /// Returns the overall delta and the delta of the current frame.
+    /// Returns the overall delta and the delta of the current frame given the cursor position
+    /// `cursor_pos`. If `grid` is `Some` the delta is snapped to it.
     #[allow(clippy::cast_possible_truncation)]
     #[inline]
     fn overall_and_frame_drag_delta_from_origin(
         &self,
-        cursor: &Cursor,
-        grid: &Grid
+        cursor_pos: Vec2,
+        grid: Option<Grid>
     ) -> Option<(Vec2, Vec2)>
     {
-        let snap = cursor.snap();
-        let cursor_pos = cursor.world_snapped();
         let prev_step = self.origin + self.delta;
 
-        let delta = if snap
+        let delta = if let Some(grid) = grid
         {
             let delta = cursor_pos - prev_step;
             let target = prev_step + grid.square(delta).nearest_corner_to_point(delta);
@@ -147,9 +238,6 @@ impl CursorDelta
     #[inline]
     pub(in crate::map::editor::state::core) fn draw(&self, bundle: &mut DrawBundle)
     {
-        /// The color of the tooltip.
-        const TOOLTIP_TEXT_COLOR: egui::Color32 = egui::Color32::from_rgb(127, 255, 212);
-
         if self.delta.around_equal_narrow(&Vec2::ZERO)
         {
             return;
@@ -172,8 +260,7 @@ impl CursorDelta
                 window,
                 camera,
                 Self::X_DELTA,
-                #[allow(clippy::cast_possible_truncation)]
-                &format!("{}", self.delta.x as i16),
+                &format!("{}", self.delta.x.necessary_precision_value()),
                 Vec2::new(self.origin.x + self.delta.x / 2f32, self.origin.y),
                 Vec2::new(0f32, -4f32),
                 TOOLTIP_TEXT_COLOR,
@@ -181,7 +268,49 @@ impl CursorDelta
             );
         }
 
-        if self.delta.y == 0f32
+        if self.delta.y != 0f32
+        {
+            drawer.draw_tooltip_y_centered(
+                window,
+                camera,
+                Self::Y_DELTA,
+                format!("{}", self.delta.y.necessary_precision_value()).as_str(),
+                Vec2::new(p.x, p.y - self.delta.y / 2f32),
+                Vec2::new(4f32, 0f32),
+                TOOLTIP_TEXT_COLOR,
+                egui::Color32::from_black_alpha(0)
+            );
+        }
+
+        self.draw_distance(window, camera, drawer);
+    }
+
+    /// Draws a tooltip next to the dragged position showing the delta and the distance from the
+    /// origin.
+    #[inline]
+    pub(in crate::map::editor::state::core) fn draw_distance(
+        &self,
+        window: &Window,
+        camera: &Transform,
+        drawer: &EditDrawer
+    )
+    {
+        Self::draw_distance_between(window, camera, drawer, self.origin, self.origin + self.delta);
+    }
+
+    /// Draws a tooltip next to `end` showing the delta and the distance from `origin`.
+    #[inline]
+    pub(in crate::map::editor::state::core) fn draw_distance_between(
+        window: &Window,
+        camera: &Transform,
+        drawer: &EditDrawer,
+        origin: Vec2,
+        end: Vec2
+    )
+    {
+        let delta = end - origin;
+
+        if delta.around_equal_narrow(&Vec2::ZERO)
         {
             return;
         }
@@ -189,13 +318,144 @@ impl CursorDelta
         drawer.draw_tooltip_y_centered(
             window,
             camera,
-            Self::Y_DELTA,
-            #[allow(clippy::cast_possible_truncation)]
-            format!("{}", self.delta.y as i8).as_str(),
-            Vec2::new(p.x, p.y - self.delta.y / 2f32),
-            Vec2::new(4f32, 0f32),
+            Self::DISTANCE,
+            &format!("{} | {:.2}", delta.necessary_precision_value(), delta.length()),
+            end,
+            Vec2::new(16f32, 16f32),
+            TOOLTIP_TEXT_COLOR,
+            egui::Color32::from_black_alpha(160)
+        );
+    }
+}
+
+//=======================================================================//
+
+/// The constraint applied to the edge connecting a dragged vertex to its previous vertex.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(in crate::map::editor::state::core) struct EdgeConstraint
+{
+    /// The fixed end of the edge.
+    anchor: Vec2,
+    /// The position of the dragged end of the edge when the drag started.
+    start:  Vec2,
+    /// Whether the angle of the edge is snapped to multiples of [`ANGLE_STEP`].
+    angle:  bool,
+    /// Whether the length of the edge is preserved.
+    length: bool
+}
+
+impl EdgeConstraint
+{
+    /// The label of the edge tooltip.
+    const EDGE: &'static str = "edge";
+
+    /// Returns a new [`EdgeConstraint`] if there is a single selected brush with a single selected
+    /// vertex, or side, whose edge with the previous vertex can be constrained.
+    #[inline]
+    #[must_use]
+    pub fn new(manager: &EntitiesManager) -> Option<Self>
+    {
+        if manager.selected_brushes_amount() != 1
+        {
+            return None;
+        }
+
+        let brush = manager.selected_brushes().next().unwrap();
+        let mut selected = brush
+            .vertexes_selection()
+            .enumerate()
+            .filter_map(|(i, (_, selected))| selected.then_some(i));
+        let index = selected.next()?;
+
+        if selected.next().is_some()
+        {
+            return None;
+        }
+
+        let len = brush.vertexes().len();
+
+        Some(Self {
+            anchor: brush.vertex_at_index((index + len - 1) % len),
+            start:  brush.vertex_at_index(index),
+            angle:  false,
+            length: false
+        })
+    }
+
+    /// Updates the applied constraints based on the pressed modifiers, Shift snaps the angle and
+    /// Ctrl preserves the length. Returns whether they changed.
+    #[inline]
+    pub fn update(&mut self, inputs: &InputsPresses) -> bool
+    {
+        let (angle, length) = (inputs.shift_pressed(), inputs.ctrl_pressed());
+        let changed = angle != self.angle || length != self.length;
+        (self.angle, self.length) = (angle, length);
+        changed
+    }
+
+    /// Whether the constraint is currently applied.
+    #[inline]
+    #[must_use]
+    pub const fn active(&self) -> bool { self.angle || self.length }
+
+    /// Returns the delta by which the dragged vertex must be moved from its starting position to
+    /// satisfy the constraint, given the unconstrained `delta`.
+    #[inline]
+    #[must_use]
+    fn constrained_delta(&self, delta: Vec2) -> Option<Vec2>
+    {
+        let edge = self.start + delta - self.anchor;
+
+        let direction = if self.angle
+        {
+            let angle = (edge.y.atan2(edge.x).to_degrees() / ANGLE_STEP).round() * ANGLE_STEP;
+            Vec2::from_angle(angle.to_radians())
+        }
+        else
+        {
+            edge.try_normalize()?
+        };
+
+        let length =
+            if self.length { self.start.distance(self.anchor) } else { edge.dot(direction) };
+
+        (length > 0f32).then(|| self.anchor + direction * length - self.start)
+    }
+
+    /// Draws the constrained edge along with a tooltip showing its angle and length.
+    #[inline]
+    pub fn draw(&self, bundle: &mut DrawBundle, delta: Vec2)
+    {
+        if !self.active()
+        {
+            return;
+        }
+
+        let DrawBundle {
+            window,
+            drawer,
+            camera,
+            ..
+        } = bundle;
+
+        let end = self.start + delta;
+        let edge = end - self.anchor;
+        drawer.line(self.anchor, end, Color::Hull);
+
+        drawer.draw_tooltip_x_centered_above_pos(
+            window,
+            camera,
+            Self::EDGE,
+            &format!(
+                "{:.0}° {:.2}",
+                edge.y.atan2(edge.x).to_degrees().rem_euclid(360f32),
+                edge.length()
+            ),
+            self.anchor + edge / 2f32,
+            Vec2::new(0f32, -4f32),
             TOOLTIP_TEXT_COLOR,
-            egui::Color32::from_black_alpha(0)
+            egui::Color32::TRANSPARENT
         );
     }
 }
@@ -32,7 +32,13 @@ use crate::{
         collections::Ids,
         hull::{CircleIterator, Hull, TriangleOrientation},
         math::{
-            points::{sort_vxs_ccw, vertexes_orientation, vxs_center, VertexesOrientation},
+            points::{
+                round_corners,
+                sort_vxs_ccw,
+                vertexes_orientation,
+                vxs_center,
+                VertexesOrientation
+            },
             AroundEqual
         },
         misc::{next, Camera, PointInsideUiHighlight, ReplaceValues, TakeValue}
@@ -336,7 +342,7 @@ enum Status
 //
 //=======================================================================//
 
-shape_cursor_brush!((Square), (Triangle, orientation), (Circle | settings));
+shape_cursor_brush!((Square | settings), (Triangle, orientation), (Circle | settings));
 
 /// A cursor to draw a square.
 #[derive(Default)]
@@ -347,16 +353,27 @@ impl SquareCursorPolygon
     /// Returns a new [`SquareCursorPolygon`].
     #[inline]
     #[must_use]
-    pub fn new(cursor: &Cursor) -> Self { Self(DrawMode::new(cursor, Self::vertex_gen)) }
+    pub fn new(cursor: &Cursor, settings: &ToolsSettings) -> Self
+    {
+        Self(DrawMode::new(cursor, |hull| Self::vertex_gen(hull, settings)))
+    }
 
-    /// Returns an iterator returning the vertexes of the square.
+    /// Returns the vertexes of the square, with rounded corners if the corner radius is not zero.
     #[inline]
-    fn vertex_gen(hull: &Hull) -> std::array::IntoIter<Vec2, 4> { hull.rectangle().into_iter() }
+    fn vertex_gen(hull: &Hull, settings: &ToolsSettings) -> Vec<Vec2>
+    {
+        if settings.corner_radius == 0f32
+        {
+            return hull.rectangle().to_vec();
+        }
+
+        round_corners(&hull.rectangle(), settings.corner_radius, settings.corner_resolution)
+    }
 
     /// Updates the state of `self`.
     #[allow(clippy::unused_self)]
     #[inline]
-    fn state_update(&mut self, _: &InputsPresses, _: &Cursor) {}
+    fn state_update(&mut self, _: &InputsPresses, _: &Cursor, _: &mut ToolsSettings) {}
 }
 
 //=======================================================================//
@@ -551,6 +568,9 @@ impl DisableSubtool for FreeDrawCursorPolygon
 
 impl FreeDrawCursorPolygon
 {
+    /// The label of the vertexes count tooltip.
+    const VERTEXES_COUNT: &'static str = "free_draw_vertexes";
+
     /// Returns a new [`FreeDrawCursorPolygon`].
     #[inline]
     #[must_use]
@@ -761,6 +781,30 @@ impl FreeDrawCursorPolygon
         }
     }
 
+    /// Whether a vertex placed at `cursor_pos` would generate a valid shape.
+    #[inline]
+    #[must_use]
+    fn next_vertex_valid(&self, cursor_pos: Vec2, camera_scale: f32) -> bool
+    {
+        match &self.0
+        {
+            Status::None | Status::Point(_) => true,
+            Status::Line(l) =>
+            {
+                l.iter()
+                    .any(|p| p.is_point_inside_ui_highlight(cursor_pos, camera_scale)) ||
+                    !matches!(
+                        vertexes_orientation(&[l[0], l[1], cursor_pos]),
+                        VertexesOrientation::Collinear
+                    )
+            },
+            Status::Polygon(poly) =>
+            {
+                !poly.free_draw_vertex_breaks_convexity(cursor_pos, camera_scale)
+            },
+        }
+    }
+
     /// Draws the polygon being drawn.
     #[inline]
     pub fn draw(&self, bundle: &mut DrawBundle)
@@ -769,9 +813,39 @@ impl FreeDrawCursorPolygon
             window,
             drawer,
             camera,
+            cursor,
             ..
         } = bundle;
 
+        let vertexes = match &self.0
+        {
+            Status::None => return,
+            Status::Point(_) => 1,
+            Status::Line(_) => 2,
+            Status::Polygon(poly) => poly.sides()
+        };
+
+        let cursor_pos = cursor.world_snapped();
+        let color = if self.next_vertex_valid(cursor_pos, camera.scale())
+        {
+            Color::CursorPolygon
+        }
+        else
+        {
+            Color::ErrorHighlight
+        };
+
+        drawer.draw_tooltip_x_centered_above_pos(
+            window,
+            camera,
+            Self::VERTEXES_COUNT,
+            &vertexes.to_string(),
+            cursor_pos,
+            Vec2::new(0f32, -4f32),
+            drawer.tooltip_text_color(),
+            drawer.egui_color(color)
+        );
+
         match &self.0
         {
             Status::None => (),
@@ -783,7 +857,7 @@ impl FreeDrawCursorPolygon
             },
             Status::Line([start, end]) =>
             {
-                drawer.line(*start, *end, Color::CursorPolygon);
+                drawer.line(*start, *end, color);
                 drawer.square_highlight(*start, Color::CursorPolygon);
                 drawer.square_highlight(*end, Color::CursorPolygon);
 
@@ -795,7 +869,7 @@ impl FreeDrawCursorPolygon
                     free_draw_tooltip(window, camera, drawer, *vx, label, &mut text);
                 }
             },
-            Status::Polygon(poly) => poly.draw_free_draw(window, camera, drawer)
+            Status::Polygon(poly) => poly.draw_free_draw(window, camera, drawer, color)
         };
     }
 }
@@ -158,7 +158,7 @@ impl OngoingMultiframeChange for DrawTool
 impl DrawTool
 {
     draw_tools!(
-        (square, Square, cursor),
+        (square, Square, cursor, settings),
         (triangle, Triangle, cursor),
         (circle, Circle, cursor, settings),
         (free, FreeDraw)
@@ -221,7 +221,7 @@ impl DrawTool
 
         match &mut self.shape
         {
-            Shape::Square(cb) => cb.update(bundle, &mut self.drawn_brushes),
+            Shape::Square(cb) => cb.update(bundle, settings, &mut self.drawn_brushes),
             Shape::Triangle(cb) => cb.update(bundle, &mut self.drawn_brushes),
             Shape::Circle(cb) => cb.update(bundle, settings, &mut self.drawn_brushes),
             Shape::FreeDraw(cb) => cb.update(bundle, &mut self.drawn_brushes)
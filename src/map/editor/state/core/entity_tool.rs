@@ -19,7 +19,7 @@ use super::{
 };
 use crate::{
     map::{
-        drawer::{color::Color, drawing_resources::DrawingResources},
+        drawer::{color::Color, drawing_resources::DrawingResources, texture::TextureInterface},
         editor::{
             cursor::Cursor,
             state::{
@@ -52,12 +52,15 @@ enum Status
 {
     /// Inactive.
     Inactive(RectHighlightedEntity<ItemBeneathCursor>),
-    /// Dragging entities.
-    Drag(CursorDelta, bool),
+    /// Dragging entities. The [`Vec2`] is the portion of the drag delta already stored in the
+    /// edits history by the smear of drag spawned entities.
+    Drag(CursorDelta, bool, Vec2),
     /// Preparing for drag.
     PreDrag(Vec2, ItemBeneathCursor, bool),
     /// Anchoring a brush to another.
     Attach(Id, Option<Id>),
+    /// Linking a thing to another.
+    Link(Id, Option<Id>),
     /// Attempting a drag spawn from the UI.
     DragSpawnUi(Option<ItemBeneathCursor>)
 }
@@ -387,7 +390,7 @@ impl DisableSubtool for EntityTool
     #[inline]
     fn disable_subtool(&mut self)
     {
-        if matches!(self.0, Status::Attach(..) | Status::DragSpawnUi(_))
+        if matches!(self.0, Status::Attach(..) | Status::Link(..) | Status::DragSpawnUi(_))
         {
             self.0 = Status::default();
         }
@@ -452,6 +455,35 @@ impl EntityTool
                     cursor_pos,
                     (),
                     |rect, bundle, _| {
+                        if bundle.inputs.alt_pressed() && bundle.inputs.right_mouse.just_pressed()
+                        {
+                            // Continue the texture of the brush onto the adjacent selected ones.
+                            if let Some(ItemBeneathCursor::Polygon(id)) = item_beneath_cursor
+                            {
+                                bundle.manager.continue_texture(
+                                    bundle.drawing_resources,
+                                    bundle.edits_history,
+                                    bundle.grid,
+                                    id
+                                );
+                            }
+
+                            return LeftMouse::NotPressed;
+                        }
+
+                        if settings.show_links &&
+                            settings.entity_editing() &&
+                            bundle.inputs.right_mouse.just_pressed()
+                        {
+                            if let Some(ItemBeneathCursor::Thing(id)) = item_beneath_cursor
+                            {
+                                if bundle.manager.thing(id).linkable()
+                                {
+                                    return LeftMouse::Value(Status::Link(id, None));
+                                }
+                            }
+                        }
+
                         if settings.entity_editing() && bundle.inputs.right_mouse.just_pressed()
                         {
                             let id = return_if_no_match!(
@@ -483,6 +515,30 @@ impl EntityTool
 
                         if bundle.inputs.left_mouse.just_pressed()
                         {
+                            if item_beneath_cursor.is_none() &&
+                                bundle.inputs.double_click_deselect()
+                            {
+                                bundle.manager.deselect_selected_entities(bundle.edits_history);
+                                return LeftMouse::NotPressed;
+                            }
+
+                            if let Some(ItemBeneathCursor::Polygon(id)) = item_beneath_cursor
+                            {
+                                if bundle.inputs.double_click_texture()
+                                {
+                                    if let Some(texture) =
+                                        bundle.manager.brush(id).texture_settings()
+                                    {
+                                        let texture = texture.name().to_owned();
+                                        bundle.manager.select_brushes_with_texture(
+                                            &texture,
+                                            bundle.edits_history
+                                        );
+                                        return LeftMouse::NotPressed;
+                                    }
+                                }
+                            }
+
                             let item = return_if_no_match!(
                                 item_beneath_cursor,
                                 Some(item),
@@ -563,7 +619,8 @@ impl EntityTool
                                     bundle.clipboard,
                                     bundle.edits_history,
                                     bundle.grid,
-                                    delta
+                                    delta,
+                                    !settings.continue_duplicated_textures
                                 );
                             }
 
@@ -636,11 +693,12 @@ impl EntityTool
                         bundle.clipboard,
                         bundle.edits_history,
                         bundle.grid,
-                        drag.delta()
+                        drag.delta(),
+                        true
                     )
                     {
                         bundle.edits_history.start_multiframe_edit();
-                        self.0 = Status::Drag(drag, true);
+                        self.0 = Status::Drag(drag, true, Vec2::ZERO);
                     }
                     else
                     {
@@ -650,15 +708,28 @@ impl EntityTool
                     return;
                 }
 
-                self.0 = Status::Drag(drag, false);
+                self.0 = Status::Drag(drag, false, Vec2::ZERO);
             },
-            Status::Drag(drag, drag_spawn) =>
+            Status::Drag(drag, drag_spawn, smeared) =>
             {
                 if bundle.cursor.moved()
                 {
+                    let overall_delta = drag.delta();
+
                     drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
                         if *drag_spawn
                         {
+                            if !bundle.inputs.shift_pressed()
+                            {
+                                return Self::move_selected_entities(bundle, delta, true);
+                            }
+
+                            if !Self::selected_entities_move_valid(bundle, delta, true)
+                            {
+                                return false;
+                            }
+
+                            Self::smear(bundle, overall_delta, smeared);
                             return Self::move_selected_entities(bundle, delta, true);
                         }
 
@@ -704,6 +775,35 @@ impl EntityTool
 
                 self.0 = Status::Inactive(brush_beneath_cursor.into());
             },
+            Status::Link(id, hgl_e) =>
+            {
+                *hgl_e = match self.1.entity_beneath_cursor(bundle)
+                {
+                    Some(ItemBeneathCursor::Thing(thing_id))
+                        if thing_id != *id && bundle.manager.thing(thing_id).linkable() =>
+                    {
+                        thing_id.into()
+                    },
+                    _ => None
+                };
+
+                if bundle.inputs.right_mouse.pressed()
+                {
+                    return;
+                }
+
+                if let Some(target) = *hgl_e
+                {
+                    bundle.manager.link_things(
+                        bundle.things_catalog,
+                        bundle.edits_history,
+                        *id,
+                        target
+                    );
+                }
+
+                self.0 = Status::default();
+            },
             Status::DragSpawnUi(hgl_e) =>
             {
                 if let Some(delta) = bundle.inputs.directional_keys_delta()
@@ -714,7 +814,8 @@ impl EntityTool
                         bundle.clipboard,
                         bundle.edits_history,
                         bundle.grid,
-                        delta
+                        delta,
+                        !settings.continue_duplicated_textures
                     );
                     self.0 = Status::default();
                     return;
@@ -744,40 +845,46 @@ impl EntityTool
     #[inline]
     fn finalize_entities_drag(&mut self, bundle: &mut ToolUpdateBundle, settings: &ToolsSettings)
     {
-        let (drag_delta, drag_spawn) =
-            match_or_panic!(&self.0, Status::Drag(drag, drag_spawn), (drag.delta(), *drag_spawn));
+        let (drag_delta, drag_spawn, smeared) = match_or_panic!(
+            &self.0,
+            Status::Drag(drag, drag_spawn, smeared),
+            (drag.delta(), *drag_spawn, *smeared)
+        );
 
-        if drag_delta != Vec2::ZERO
+        if drag_spawn
         {
-            if drag_spawn
-            {
-                bundle
-                    .edits_history
-                    .entity_move_cluster(bundle.manager, drag_delta, true);
-            }
-            else
-            {
-                settings.target_switch().edit_target(
-                    bundle,
-                    (),
-                    |bundle, move_texture, _| {
-                        bundle.edits_history.entity_move_cluster(
+            // The duplication, the smearing, and the move are undone in a single step.
+            bundle
+                .edits_history
+                .with_group("Entities Drag Spawn", |edits_history| {
+                    if drag_delta != smeared
+                    {
+                        edits_history.entity_move_cluster(
                             bundle.manager,
-                            drag_delta,
-                            move_texture
+                            drag_delta - smeared,
+                            true
                         );
-                    },
-                    |bundle, _| {
-                        bundle.edits_history.texture_move_cluster(bundle.manager, drag_delta);
                     }
-                );
-            }
-        }
 
-        if bundle.edits_history.multiframe_edit()
+                    edits_history.end_multiframe_edit();
+                });
+        }
+        else if drag_delta != smeared
         {
-            bundle.edits_history.end_multiframe_edit();
-            bundle.edits_history.override_edit_tag("Entities Drag Spawn");
+            settings.target_switch().edit_target(
+                bundle,
+                (),
+                |bundle, move_texture, _| {
+                    bundle.edits_history.entity_move_cluster(
+                        bundle.manager,
+                        drag_delta,
+                        move_texture
+                    );
+                },
+                |bundle, _| {
+                    bundle.edits_history.texture_move_cluster(bundle.manager, drag_delta);
+                }
+            );
         }
 
         self.0 = Status::default();
@@ -849,19 +956,7 @@ impl EntityTool
         move_texture: bool
     ) -> bool
     {
-        let valid = bundle.manager.test_operation_validity(|manager| {
-            manager
-                .selected_brushes()
-                .find_map(|brush| {
-                    (!brush.check_move(bundle.drawing_resources, bundle.grid, delta, move_texture))
-                        .then_some(brush.id())
-                })
-                .or(manager.selected_things().find_map(|thing| {
-                    (!thing.check_move(bundle.things_catalog, delta)).then_some(thing.id())
-                }))
-        });
-
-        if !valid
+        if !Self::selected_entities_move_valid(bundle, delta, move_texture)
         {
             return false;
         }
@@ -881,6 +976,55 @@ impl EntityTool
         true
     }
 
+    /// Whether the selected entities can be moved by `delta`.
+    #[inline]
+    #[must_use]
+    fn selected_entities_move_valid(
+        bundle: &mut ToolUpdateBundle,
+        delta: Vec2,
+        move_texture: bool
+    ) -> bool
+    {
+        bundle.manager.test_operation_validity(|manager| {
+            manager
+                .selected_brushes()
+                .find_map(|brush| {
+                    (!brush.check_move(bundle.drawing_resources, bundle.grid, delta, move_texture))
+                        .then_some(brush.id())
+                })
+                .or(manager.selected_things().find_map(|thing| {
+                    (!thing.check_move(bundle.things_catalog, delta)).then_some(thing.id())
+                }))
+        })
+    }
+
+    /// Leaves a copy of the drag spawned entities at their current position and selects it, so
+    /// that the drag continues with the copy. `overall_delta` is the overall drag delta and
+    /// `smeared` the portion of it already stored in the edits history.
+    #[inline]
+    fn smear(bundle: &mut ToolUpdateBundle, overall_delta: Vec2, smeared: &mut Vec2)
+    {
+        if overall_delta == *smeared
+        {
+            return;
+        }
+
+        bundle
+            .edits_history
+            .entity_move_cluster(bundle.manager, overall_delta - *smeared, true);
+        *smeared = overall_delta;
+
+        _ = bundle.manager.duplicate_selected_entities(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.clipboard,
+            bundle.edits_history,
+            bundle.grid,
+            Vec2::ZERO,
+            true
+        );
+    }
+
     /// Moves the selected textures.
     #[inline]
     fn move_selected_textures(bundle: &mut ToolUpdateBundle, delta: Vec2) -> bool
@@ -943,7 +1087,7 @@ impl EntityTool
 
                 rect.highlighted_entity()
             },
-            Status::Drag(drag, _) =>
+            Status::Drag(drag, ..) =>
             {
                 drag.draw(bundle);
                 None
@@ -995,6 +1139,56 @@ impl EntityTool
 
                 return;
             },
+            Status::Link(id, hgl_e) =>
+            {
+                /// Draws the highlighted thing with [`Id`] `id`.
+                macro_rules! highlight {
+                    ($id:expr) => {{
+                        let thing = bundle.manager.thing($id);
+
+                        if bundle.manager.is_selected($id)
+                        {
+                            thing.draw_highlighted_selected(
+                                bundle.window,
+                                bundle.camera,
+                                bundle.drawer,
+                                bundle.things_catalog
+                            );
+                        }
+                        else
+                        {
+                            thing.draw_highlighted_non_selected(
+                                bundle.window,
+                                bundle.camera,
+                                bundle.drawer,
+                                bundle.things_catalog
+                            );
+                        }
+
+                        thing.pos()
+                    }};
+                }
+
+                let end = if let Some(hgl_e) = *hgl_e
+                {
+                    draw_selected_and_non_selected!(bundle, [*id, hgl_e]);
+                    highlight!(hgl_e)
+                }
+                else
+                {
+                    draw_selected_and_non_selected!(bundle, *id);
+                    Self::cursor_pos(bundle.cursor)
+                };
+
+                let start = highlight!(*id);
+
+                if start != end
+                {
+                    bundle.drawer.arrowed_line(start, end, Color::ThingLink);
+                }
+
+                return;
+            },
             Status::DragSpawnUi(hgl_e) => *hgl_e
         };
 
@@ -3,16 +3,23 @@
 //
 //=======================================================================//
 
+use bevy_egui::egui;
+
 use super::{tool::ActiveTool, PreviousActiveTool};
 use crate::{
     map::{
+        drawer::drawing_resources::DrawingResources,
         editor::{
-            state::manager::{Animators, EntitiesManager},
+            state::{
+                manager::{Animators, EntitiesManager},
+                ui::UiBundle
+            },
             DrawBundleMapPreview,
             StateUpdateBundle,
             ToolUpdateBundle
         },
-        path::MovementSimulator
+        path::MovementSimulator,
+        thing::catalog::ThingsCatalog
     },
     utils::identifiers::{EntityId, Id}
 };
@@ -22,6 +29,100 @@ use crate::{
 //
 //=======================================================================//
 
+/// A virtual clock advancing with a fixed timestep, which makes the map preview playback
+/// independent from the framerate.
+#[must_use]
+struct PreviewClock
+{
+    /// The virtual time that has passed since the start of the preview.
+    elapsed_time: f32,
+    /// The real time that has not been converted into steps yet.
+    accumulator:  f32,
+    /// Whether the clock is paused.
+    paused:       bool,
+    /// Whether a single step should be executed while paused.
+    step:         bool,
+    /// The multiplier of the speed of the playback.
+    speed:        f32
+}
+
+impl Default for PreviewClock
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            elapsed_time: 0f32,
+            accumulator:  0f32,
+            paused:       false,
+            step:         false,
+            speed:        1f32
+        }
+    }
+}
+
+impl PreviewClock
+{
+    /// The duration of a step.
+    const FIXED_DELTA_TIME: f32 = 1f32 / 60f32;
+    /// The maximum speed multiplier.
+    const MAX_SPEED: f32 = 4f32;
+    /// The maximum amount of steps executed in a single frame, to avoid stalling after a long
+    /// frame.
+    const MAX_STEPS: u8 = 8;
+    /// The minimum speed multiplier.
+    const MIN_SPEED: f32 = 0.1;
+
+    /// Returns the virtual time that has passed since the start of the preview.
+    #[inline]
+    #[must_use]
+    const fn elapsed_time(&self) -> f32 { self.elapsed_time }
+
+    /// Returns the amount of steps to be executed based on the real `delta_time`.
+    #[inline]
+    #[must_use]
+    fn steps(&mut self, delta_time: f32) -> u8
+    {
+        if self.paused
+        {
+            return u8::from(std::mem::take(&mut self.step));
+        }
+
+        self.accumulator += delta_time * self.speed;
+        let mut steps = 0;
+
+        while self.accumulator >= Self::FIXED_DELTA_TIME && steps < Self::MAX_STEPS
+        {
+            self.accumulator -= Self::FIXED_DELTA_TIME;
+            steps += 1;
+        }
+
+        if steps == Self::MAX_STEPS
+        {
+            self.accumulator = 0f32;
+        }
+
+        steps
+    }
+
+    /// Advances the virtual time by a step.
+    #[inline]
+    fn tick(&mut self) { self.elapsed_time += Self::FIXED_DELTA_TIME; }
+
+    /// Resets the virtual time, keeping the pause state and the speed.
+    #[inline]
+    fn restart(&mut self)
+    {
+        *self = Self {
+            paused: self.paused,
+            speed: self.speed,
+            ..Default::default()
+        };
+    }
+}
+
+//=======================================================================//
+
 /// The map preview tool.
 pub(in crate::map::editor::state::core) struct MapPreviewTool
 {
@@ -30,7 +131,9 @@ pub(in crate::map::editor::state::core) struct MapPreviewTool
     /// The movement simulators.
     movement:  Vec<MovementSimulator>,
     /// The texture animators.
-    animators: Animators
+    animators: Animators,
+    /// The clock of the playback.
+    clock:     PreviewClock
 }
 
 impl MapPreviewTool
@@ -42,7 +145,10 @@ impl MapPreviewTool
         ActiveTool::MapPreview(MapPreviewTool {
             prev_tool: Box::new(std::mem::take(active_tool)),
             movement:  bundle.manager.movement_simulators(),
-            animators: bundle.manager.texture_animators(bundle)
+            animators: bundle
+                .manager
+                .texture_animators(bundle.drawing_resources, bundle.things_catalog),
+            clock:     PreviewClock::default()
         })
     }
 
@@ -50,16 +156,35 @@ impl MapPreviewTool
     #[inline]
     pub fn prev_tool(&mut self) -> &mut ActiveTool { &mut self.prev_tool }
 
+    /// Restarts the playback from the beginning, resetting the movements and the texture
+    /// animations.
+    #[inline]
+    fn restart(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        manager: &EntitiesManager
+    )
+    {
+        self.movement = manager.movement_simulators();
+        self.animators = manager.texture_animators(drawing_resources, things_catalog);
+        self.clock.restart();
+    }
+
     /// Updates the tool.
     #[inline]
     pub fn update(&mut self, bundle: &ToolUpdateBundle)
     {
-        for sim in &mut self.movement
+        for _ in 0..self.clock.steps(bundle.delta_time)
         {
-            sim.update(bundle.manager.moving(sim.id()), bundle.delta_time);
-        }
+            for sim in &mut self.movement
+            {
+                sim.update(bundle.manager.moving(sim.id()), PreviewClock::FIXED_DELTA_TIME);
+            }
 
-        self.animators.update(bundle);
+            self.animators.update(bundle, PreviewClock::FIXED_DELTA_TIME);
+            self.clock.tick();
+        }
     }
 
     /// Draws the tool.
@@ -67,7 +192,6 @@ impl MapPreviewTool
     pub fn draw(&self, bundle: &mut DrawBundleMapPreview)
     {
         let DrawBundleMapPreview {
-            window,
             drawer,
             camera,
             things_catalog,
@@ -75,6 +199,7 @@ impl MapPreviewTool
             ..
         } = bundle;
         let brushes = manager.brushes();
+        drawer.set_elapsed_time(self.clock.elapsed_time());
 
         for simulator in &self.movement
         {
@@ -88,29 +213,63 @@ impl MapPreviewTool
             );
         }
 
-        for brush in manager
-            .visible_brushes(window, camera, drawer.grid())
-            .iter()
-            .filter(|brush| !is_moving(manager, brush.id()) && !brush.has_sprite())
-        {
-            brush.draw_map_preview(camera, drawer, self.animators.get_brush_animator(brush.id()));
-        }
+        draw_still_entities(bundle, &self.animators, true);
+    }
 
-        for brush in manager
-            .visible_sprites(window, camera, drawer.grid())
-            .iter()
-            .filter(|brush| !is_moving(manager, brush.id()))
-        {
-            brush.draw_map_preview_sprite(drawer, self.animators.get_brush_animator(brush.id()));
-        }
+    /// Draws the live map preview of the split view, with the entities standing still at their
+    /// current position and the textures not animated.
+    #[inline]
+    pub fn draw_split_view(bundle: &mut DrawBundleMapPreview)
+    {
+        draw_still_entities(bundle, &Animators::default(), false);
+    }
 
-        for thing in manager
-            .visible_things(window, camera, drawer.grid())
-            .iter()
-            .filter(|brush| !is_moving(manager, brush.id()))
-        {
-            thing.draw_map_preview(drawer, things_catalog, &self.animators);
-        }
+    /// Draws the UI.
+    #[inline]
+    pub fn ui(&mut self, ui: &mut egui::Ui, bundle: &UiBundle)
+    {
+        ui.label(egui::RichText::new("MAP PREVIEW"));
+
+        ui.horizontal(|ui| {
+            let locale = &bundle.config.locale;
+            let pause = ui.button(locale.tr(if self.clock.paused { "Resume" } else { "Pause" }));
+            let step = ui.add_enabled(self.clock.paused, egui::Button::new(locale.tr("Step")));
+            let restart = ui.button(locale.tr("Restart"));
+
+            for b in [&pause, &step, &restart]
+            {
+                b.surrender_focus();
+            }
+
+            if pause.clicked()
+            {
+                self.clock.paused = !self.clock.paused;
+                self.clock.accumulator = 0f32;
+            }
+            else if step.clicked()
+            {
+                self.clock.step = true;
+            }
+            else if restart.clicked()
+            {
+                self.restart(bundle.drawing_resources, bundle.things_catalog, bundle.manager);
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(bundle.config.locale.tr("Speed"));
+            ui.add(
+                egui::Slider::new(
+                    &mut self.clock.speed,
+                    PreviewClock::MIN_SPEED..=PreviewClock::MAX_SPEED
+                )
+                .logarithmic(true)
+                .max_decimals(2)
+                .suffix("x")
+            );
+        });
+
+        ui.label(format!("{}: {:.3}", bundle.config.locale.tr("Time"), self.clock.elapsed_time()));
     }
 }
 
@@ -119,6 +278,48 @@ impl MapPreviewTool
 //
 //=======================================================================//
 
+/// Draws the visible entities that are not moving, unless `skip_moving` is false, in which case
+/// all the visible entities are drawn.
+#[inline]
+fn draw_still_entities(bundle: &mut DrawBundleMapPreview, animators: &Animators, skip_moving: bool)
+{
+    let DrawBundleMapPreview {
+        window,
+        drawer,
+        camera,
+        things_catalog,
+        manager,
+        ..
+    } = bundle;
+    let still = |identifier: Id| !skip_moving || !is_moving(manager, identifier);
+
+    let visible_brushes = manager.visible_brushes(window, camera, drawer.grid());
+    let brushes = visible_brushes
+        .iter()
+        .filter(|brush| still(brush.id()) && !brush.has_sprite() && !brush.editor_only())
+        .filter_map(|brush| brush.map_preview_mesh_data(animators.get_brush_animator(brush.id())))
+        .collect::<Vec<_>>();
+    drawer.brushes(camera, &brushes);
+
+    for brush in manager
+        .visible_sprites(window, camera, drawer.grid())
+        .iter()
+        .filter(|brush| still(brush.id()) && !brush.editor_only())
+    {
+        brush.draw_map_preview_sprite(drawer, animators.get_brush_animator(brush.id()));
+    }
+
+    for thing in manager
+        .visible_things(window, camera, drawer.grid())
+        .iter()
+        .filter(|thing| still(thing.id()))
+    {
+        thing.draw_map_preview(drawer, things_catalog, animators);
+    }
+}
+
+//=======================================================================//
+
 /// Whether the entity with [`Id`] `identifier` moves.
 #[inline]
 #[must_use]
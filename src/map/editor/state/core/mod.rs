@@ -30,6 +30,7 @@ use hill_vacuum_shared::{match_or_panic, return_if_no_match};
 
 use self::{
     cursor_delta::CursorDelta,
+    map_preview::MapPreviewTool,
     tool::{
         ActiveTool,
         ChangeConditions,
@@ -37,27 +38,37 @@ use self::{
         EditingTarget,
         EnabledTool,
         OngoingMultiframeChange,
-        Tool
+        Tool,
+        ToolInterface
     }
 };
 use super::{
-    editor_state::ToolsSettings,
+    editor_state::{PasteSnap, ToolsSettings},
     edits_history::{edit_type::BrushType, EditsHistory},
     manager::{BrushMut, EntitiesManager, MovingMut, ThingMut},
+    texture_rename,
     ui::{ToolsButtons, Ui, UiBundle}
 };
 use crate::{
+    error_message,
     map::{
         brush::{
             convex_polygon::{ConvexPolygon, TextureSetResult},
+            Brush,
             BrushData
         },
         drawer::{
+            color::Color,
+            drawers::EditDrawer,
             drawing_resources::DrawingResources,
             texture::{TextureSettings, TextureSpriteSet}
         },
         editor::{
-            state::{core::zoom_tool::ZoomTool, grid::Grid, ui::texture_per_row},
+            state::{
+                core::zoom_tool::ZoomTool,
+                grid::{Grid, GridSlice},
+                ui::texture_per_row
+            },
             DrawBundle,
             DrawBundleMapPreview,
             StateUpdateBundle,
@@ -77,16 +88,39 @@ use crate::{
 
 /// Draws the selected and non selected brushes.
 macro_rules! draw_selected_and_non_selected_brushes {
-    ($bundle:ident $(, $filters:expr)?) => {
+    ($bundle:ident $(, $filters:expr)?) => {{
+        {
+            let brushes = $bundle.manager.visible_brushes(
+                $bundle.window,
+                $bundle.camera,
+                $bundle.drawer.grid()
+            );
+            crate::map::editor::state::core::draw_non_selected_brushes_textures(
+                $bundle.drawer,
+                $bundle.manager,
+                brushes.iter()$(.filter_set_with_predicate($filters, |brush| brush.id()))?
+            );
+        }
+
         crate::map::editor::state::core::draw_selected_and_non_selected!(
             brushes,
             $bundle,
-            |brush: &crate::map::brush::Brush, drawer, color, _| {
-                brush.draw_with_color(drawer, color);
+            |brush: &crate::map::brush::Brush,
+             drawer: &mut crate::map::drawer::drawers::EditDrawer,
+             color,
+             _| {
+                if color == crate::map::drawer::color::Color::NonSelectedEntity
+                {
+                    drawer.with_batched_textures(|drawer| brush.draw_with_color(drawer, color));
+                }
+                else
+                {
+                    brush.draw_with_color(drawer, color);
+                }
             }
             $(, $filters)?
         );
-    };
+    }};
 }
 
 use draw_selected_and_non_selected_brushes;
@@ -601,6 +635,19 @@ impl<'a> UndoRedoInterface<'a>
             .unwrap()
     }
 
+    /// Sets the spawn flags of the [`ThingInstance`] with [`Id`] `identifier`.
+    /// Returns the previous flags.
+    #[inline]
+    pub fn set_thing_flags(
+        &mut self,
+        things_catalog: &ThingsCatalog,
+        identifier: Id,
+        flags: u32
+    ) -> u32
+    {
+        self.thing_mut(things_catalog, identifier).set_flags(flags).unwrap()
+    }
+
     /// Returns the [`ThingMut`] with [`Id`] `identifier`.
     #[inline]
     pub fn thing_mut<'b>(
@@ -652,6 +699,65 @@ impl<'a> UndoRedoInterface<'a>
     #[inline]
     pub fn schedule_overall_node_update(&mut self) { self.manager.schedule_overall_node_update(); }
 
+    /// Sets the outline color of the entity with [`Id`] `identifier` to `color`.
+    /// Returns the previous color.
+    #[inline]
+    pub fn set_entity_color(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        identifier: Id,
+        color: Option<[u8; 3]>
+    ) -> Option<[u8; 3]>
+    {
+        if self.manager.is_thing(identifier)
+        {
+            self.manager
+                .thing_mut(things_catalog, identifier)
+                .set_color(color)
+                .unwrap()
+        }
+        else
+        {
+            self.manager
+                .brush_mut(drawing_resources, grid, identifier)
+                .set_color(color)
+                .unwrap()
+        }
+    }
+
+    /// Sets the groups the entity with [`Id`] `identifier` belongs to to `groups`.
+    /// Returns the previous groups.
+    #[inline]
+    pub fn set_entity_groups(&mut self, identifier: Id, groups: Vec<u32>) -> Vec<u32>
+    {
+        self.manager.set_entity_groups(identifier, groups)
+    }
+
+    /// Renames the texture `prev` to `new`, in its image file as well as in the brushes.
+    /// Returns whether the rename was successful.
+    #[inline]
+    #[must_use]
+    pub fn rename_texture(
+        &mut self,
+        drawing_resources: &mut DrawingResources,
+        grid: &Grid,
+        prev: &str,
+        new: &str
+    ) -> bool
+    {
+        match texture_rename::rename_texture(drawing_resources, self.manager, grid, prev, new)
+        {
+            Ok(()) => true,
+            Err(err) =>
+            {
+                error_message(err);
+                false
+            }
+        }
+    }
+
     /// Sets the property with key `k` of the entity with [`Id`] `identifier` to `value`.
     #[inline]
     pub fn set_property(
@@ -681,6 +787,37 @@ impl<'a> UndoRedoInterface<'a>
                 .unwrap()
         }
     }
+
+    /// Sets the custom property with key `k` of the entity with [`Id`] `identifier` to `value`,
+    /// or removes it if `value` is `None`. Returns the previous value.
+    #[inline]
+    pub fn set_custom_property(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        identifier: Id,
+        k: &str,
+        value: Option<Value>
+    ) -> Option<Value>
+    {
+        if self.manager.is_thing(identifier)
+        {
+            self.manager.schedule_overall_things_property_update(k);
+            self.manager
+                .thing_mut(things_catalog, identifier)
+                .set_custom_property(k, value)
+                .unwrap()
+        }
+        else
+        {
+            self.manager.schedule_overall_brushes_property_update(k);
+            self.manager
+                .brush_mut(drawing_resources, grid, identifier)
+                .set_custom_property(k, value)
+                .unwrap()
+        }
+    }
 }
 
 //=======================================================================//
@@ -731,6 +868,15 @@ impl Core
     #[must_use]
     pub const fn map_preview(&self) -> bool { self.active_tool.map_preview() }
 
+    /// Changes the soft selection radius by `scroll` if the vertex tool is active and the soft
+    /// selection is enabled. Returns whether that was the case.
+    #[inline]
+    #[must_use]
+    pub fn soft_selection_radius_scroll(&self, settings: &mut ToolsSettings, scroll: f32) -> bool
+    {
+        self.active_tool.soft_selection_radius_scroll(settings, scroll)
+    }
+
     //==============================================================
     // Save
 
@@ -754,6 +900,27 @@ impl Core
         self.active_tool.select_all(bundle, settings);
     }
 
+    /// Exclusively selects the entity with [`Id`] `identifier`, enabling the entity tool first if
+    /// possible.
+    #[inline]
+    pub fn go_to_entity(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        settings: &mut ToolsSettings,
+        tool_change_conditions: &ChangeConditions,
+        identifier: Id
+    )
+    {
+        if !self.active_tool.is_tool_enabled(Tool::Entity) &&
+            Tool::Entity.change_conditions_met(tool_change_conditions)
+        {
+            self.change_tool(Tool::Entity, bundle, settings, tool_change_conditions);
+        }
+
+        self.active_tool
+            .exclusively_select_entity(bundle, settings, identifier);
+    }
+
     //==============================================================
     // Undo/Redo
 
@@ -824,12 +991,81 @@ impl Core
 
     /// Pastes the copied entities.
     #[inline]
-    pub fn paste(&mut self, bundle: &mut StateUpdateBundle) { self.active_tool.paste(bundle); }
+    pub fn paste(&mut self, bundle: &mut StateUpdateBundle, snap: PasteSnap)
+    {
+        self.active_tool.paste(bundle, snap);
+    }
+
+    /// Pastes the entities stored in the copy history at `index`.
+    #[inline]
+    pub fn paste_from_history(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        index: usize,
+        snap: PasteSnap
+    )
+    {
+        self.active_tool.paste_from_history(bundle, index, snap);
+    }
+
+    #[inline]
+    pub fn duplicate(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        delta: Vec2,
+        snap: PasteSnap,
+        move_texture: bool
+    )
+    {
+        self.active_tool.duplicate(bundle, delta, snap, move_texture);
+    }
+
+    /// Spawns a brush matching the footprint of each selected thing, enlarged by `margin`.
+    #[inline]
+    pub fn things_footprint(&mut self, bundle: &mut StateUpdateBundle, margin: f32)
+    {
+        self.active_tool.things_footprint(bundle, margin);
+    }
+
+    /// Rounds the corners of the selected brushes.
+    #[inline]
+    pub fn round_corners(&mut self, bundle: &mut StateUpdateBundle, settings: &ToolsSettings)
+    {
+        self.active_tool.round_corners(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.manager,
+            bundle.edits_history,
+            bundle.grid,
+            settings
+        );
+    }
+
+    /// Slices the selected brushes along the grid lines selected by `slice`.
+    #[inline]
+    pub fn slice_to_grid(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        settings: &ToolsSettings,
+        slice: GridSlice
+    )
+    {
+        self.active_tool.slice_to_grid(bundle, settings, slice);
+    }
 
+    /// Spawns copies of the selected entities centered at `positions`.
     #[inline]
-    pub fn duplicate(&mut self, bundle: &mut StateUpdateBundle, delta: Vec2)
+    pub fn distribute(&mut self, bundle: &mut StateUpdateBundle, positions: &[Vec2])
     {
-        self.active_tool.duplicate(bundle, delta);
+        self.active_tool.distribute(bundle, positions);
+    }
+
+    /// Runs the script `source`, returning its output.
+    #[inline]
+    #[must_use]
+    pub fn run_script(&mut self, bundle: &mut StateUpdateBundle, source: &str) -> String
+    {
+        self.active_tool.run_script(bundle, source)
     }
 
     //==============================================================
@@ -841,7 +1077,7 @@ impl Core
 
     /// Toggles the map preview.
     #[inline]
-    pub fn toggle_map_preview(&mut self, bundle: &StateUpdateBundle)
+    pub fn toggle_map_preview(&mut self, bundle: &mut StateUpdateBundle)
     {
         self.active_tool.toggle_map_preview(bundle);
     }
@@ -894,7 +1130,7 @@ impl Core
         &mut self,
         tool: Tool,
         bundle: &mut StateUpdateBundle,
-        settings: &ToolsSettings,
+        settings: &mut ToolsSettings,
         tool_change_conditions: &ChangeConditions
     )
     {
@@ -959,6 +1195,27 @@ impl Core
         );
     }
 
+    /// Rotates the selected brushes by `angle` degrees without the need to enable the rotate
+    /// tool.
+    #[inline]
+    pub fn quick_rotate(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        settings: &ToolsSettings,
+        angle: f32
+    )
+    {
+        self.active_tool.quick_rotate(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.manager,
+            bundle.edits_history,
+            bundle.grid,
+            settings,
+            angle
+        );
+    }
+
     //==============================================================
     // Draw
 
@@ -976,6 +1233,13 @@ impl Core
         self.active_tool.draw_map_preview(bundle);
     }
 
+    /// Draws the live map preview of the split view.
+    #[inline]
+    pub fn draw_split_view(bundle: &mut DrawBundleMapPreview)
+    {
+        MapPreviewTool::draw_split_view(bundle);
+    }
+
     /// Draws the bottom panel.
     #[inline]
     pub fn bottom_panel(&mut self, egui_context: &egui::Context, bundle: &mut UiBundle)
@@ -1042,31 +1306,54 @@ fn draw_non_selected_brushes(bundle: &mut DrawBundle)
     let selected_entities_len = manager.selected_brushes_amount();
 
     let brushes = manager.visible_brushes(window, camera, drawer.grid());
-    let mut brushes = brushes.iter();
+    draw_non_selected_brushes_textures(drawer, manager, brushes.iter());
 
-    for brush in &mut brushes
-    {
-        let id = brush.id();
+    drawer.with_batched_textures(|drawer| {
+        let mut brushes = brushes.iter();
 
-        if manager.is_selected(id)
+        for brush in &mut brushes
         {
-            selected_entities_iterated += 1;
+            let id = brush.id();
 
-            if selected_entities_iterated == selected_entities_len
+            if manager.is_selected(id)
             {
-                break;
+                selected_entities_iterated += 1;
+
+                if selected_entities_iterated == selected_entities_len
+                {
+                    break;
+                }
+
+                continue;
             }
 
-            continue;
+            brush.draw_non_selected(drawer);
         }
 
-        brush.draw_non_selected(drawer);
-    }
+        for brush in brushes
+        {
+            brush.draw_non_selected(drawer);
+        }
+    });
+}
 
-    for brush in brushes
-    {
-        brush.draw_non_selected(drawer);
-    }
+//=======================================================================//
+
+/// Draws the textures of the non selected brushes among `brushes` in a single batch, whose meshes
+/// are generated in parallel.
+#[inline]
+fn draw_non_selected_brushes_textures<'a>(
+    drawer: &mut EditDrawer,
+    manager: &EntitiesManager,
+    brushes: impl Iterator<Item = &'a Brush>
+)
+{
+    let textures = brushes
+        .filter(|brush| !manager.is_selected(brush.id()))
+        .filter_map(Brush::texture_mesh_data)
+        .collect::<Vec<_>>();
+
+    drawer.brushes_textures(&textures, Color::NonSelectedEntity);
 }
 
 //=======================================================================//
@@ -14,21 +14,24 @@ use super::{
     tool::{ActiveTool, DisableSubtool, EnabledTool, OngoingMultiframeChange, SubTool}
 };
 use crate::{
+    config::language::Locale,
     map::{
         drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{
             cursor::Cursor,
             state::{
                 clipboard::{
+                    library,
                     prop::{Prop, PropScreenshotTimer},
                     Clipboard,
+                    UiLibraryProp,
                     PROP_SCREENSHOT_SIZE
                 },
                 core::{bottom_panel, tool::subtools_buttons},
                 edits_history::EditsHistory,
                 grid::Grid,
                 manager::EntitiesManager,
-                ui::{centered_window, ToolsButtons, UiBundle}
+                ui::{centered_window, AccessibleName, ToolsButtons, UiBundle}
             },
             DrawBundle,
             ToolUpdateBundle
@@ -94,7 +97,7 @@ enum PaintingProp
 {
     /// Quick [`Prop`].
     Quick,
-    /// Slotted [`Prop`].
+    /// Slotted or library [`Prop`].
     Slotted
 }
 
@@ -133,11 +136,15 @@ impl PaintingProp
 pub(in crate::map::editor::state::core) struct PaintTool
 {
     /// The slot where to store the created [`Prop`].
-    slot:                    String,
+    slot:                     String,
+    /// The name of the created [`Prop`] in the library.
+    library_name:             String,
     /// The state of the tool.
-    status:                  Status,
+    status:                   Status,
     /// The maximum height of the bottom panel.
-    max_bottom_panel_height: f32
+    max_bottom_panel_height:  f32,
+    /// The maximum height of the library bottom panel.
+    max_library_panel_height: f32
 }
 
 impl DisableSubtool for PaintTool
@@ -165,9 +172,11 @@ impl PaintTool
     pub fn tool() -> ActiveTool
     {
         ActiveTool::Paint(PaintTool {
-            slot:                    String::new(),
-            status:                  Status::default(),
-            max_bottom_panel_height: 0f32
+            slot:                     String::new(),
+            library_name:             String::new(),
+            status:                   Status::default(),
+            max_bottom_panel_height:  0f32,
+            max_library_panel_height: 0f32
         })
     }
 
@@ -234,7 +243,14 @@ impl PaintTool
             {
                 if inputs.back.just_pressed()
                 {
-                    clipboard.delete_selected_prop(prop_cameras);
+                    if clipboard.selected_library_prop_index().is_none()
+                    {
+                        clipboard.delete_selected_prop(prop_cameras);
+                    }
+                    else if Self::confirm_library_prop_deletion()
+                    {
+                        clipboard.delete_selected_library_prop(prop_cameras);
+                    }
                 }
 
                 if inputs.enter.just_pressed() && manager.any_selected_entities()
@@ -243,6 +259,21 @@ impl PaintTool
                         Status::SetPivot(Self::outline(things_catalog, manager, grid).unwrap());
                 }
 
+                // Stamp the quick prop bound to the pressed digit.
+                if let Some(slot) = inputs.just_pressed_digit()
+                {
+                    clipboard.set_selected_quick_prop_index(slot);
+                    _ = clipboard.spawn_quick_prop(
+                        drawing_resources,
+                        things_catalog,
+                        manager,
+                        edits_history,
+                        grid,
+                        cursor_pos
+                    );
+                    return;
+                }
+
                 if !inputs.left_mouse.just_pressed()
                 {
                     return;
@@ -318,9 +349,26 @@ impl PaintTool
                     return;
                 }
 
+                if !self.library_name.is_empty()
+                {
+                    if !library::valid_name(&self.library_name)
+                    {
+                        return;
+                    }
+
+                    clipboard.save_prop_to_library(prop_cameras, &self.library_name, prop.clone());
+                    self.library_name.clear();
+
+                    if self.slot.is_empty()
+                    {
+                        self.status = Status::default();
+                        return;
+                    }
+                }
+
                 if self.slot.is_empty()
                 {
-                    clipboard.create_quick_prop(std::mem::take(prop));
+                    clipboard.create_quick_prop(prop_cameras, std::mem::take(prop));
                     self.status = Status::QuickPropSetup;
                     return;
                 }
@@ -375,6 +423,22 @@ impl PaintTool
         };
     }
 
+    /// Asks for confirmation before deleting the selected library [`Prop`] from disk.
+    #[inline]
+    #[must_use]
+    fn confirm_library_prop_deletion() -> bool
+    {
+        matches!(
+            rfd::MessageDialog::new()
+                .set_level(rfd::MessageLevel::Warning)
+                .set_title("WARNING")
+                .set_description("Delete the selected library prop file?")
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .show(),
+            rfd::MessageDialogResult::Yes
+        )
+    }
+
     /// Returns the selected entities' outline.
     #[inline]
     #[must_use]
@@ -436,12 +500,56 @@ impl PaintTool
         );
 
         let UiBundle {
-            window, clipboard, ..
+            window,
+            config,
+            clipboard,
+            ..
         } = bundle;
 
         if let Status::PropCreationUi(prop) = &self.status
         {
-            self.prop_creation_window(window, egui_context, clipboard, prop.screenshot());
+            self.prop_creation_window(
+                window,
+                egui_context,
+                clipboard,
+                &config.locale,
+                prop.screenshot()
+            );
+        }
+
+        if clipboard.library_props_amount() != 0
+        {
+            if let Some(clicked) = bottom_panel(
+                egui_context,
+                "prop_library",
+                &mut self.max_library_panel_height,
+                PREVIEW_SIZE,
+                clipboard.selected_library_prop_index(),
+                clipboard.library_ui_iter(),
+                |ui, prop: UiLibraryProp| {
+                    (
+                        ui.vertical(|ui| {
+                            let response = match prop.tex_id
+                            {
+                                Some(tex_id) =>
+                                {
+                                    ui.add(egui::ImageButton::new((tex_id, PREVIEW_SIZE)))
+                                },
+                                None => ui.add_sized(PREVIEW_SIZE, egui::Button::new(prop.name))
+                            };
+
+                            response.image_button_name(true, || prop.name.to_owned());
+                            ui.label(prop.name);
+                            response
+                        })
+                        .inner,
+                        prop.index
+                    )
+                }
+            )
+            {
+                clipboard.set_selected_library_prop_index(clicked);
+            }
         }
 
         if clipboard.props_amount() == 0
@@ -461,6 +569,8 @@ impl PaintTool
                     ui.vertical(|ui| {
                         let response =
                             ui.add(egui::ImageButton::new((texture.tex_id, PREVIEW_SIZE)));
+                        response
+                            .image_button_name(true, || format!("Prop {}", INDEXES[texture.index]));
                         ui.label(INDEXES[texture.index]);
                         response
                     })
@@ -472,6 +582,58 @@ impl PaintTool
         clipboard.set_selected_prop_index(clicked);
     }
 
+    /// Draws the quick [`Prop`] slots in the left panel, allowing to select the one spawned by
+    /// the quick subtool.
+    #[inline]
+    pub fn left_panel(ui: &mut egui::Ui, clipboard: &mut Clipboard, locale: &Locale)
+    {
+        /// The size of the frame of the quick [`Prop`]s previews.
+        const QUICK_PREVIEW_SIZE: egui::Vec2 = egui::Vec2::splat(40f32);
+
+        ui.label(locale.tr("Quick props"));
+
+        let selected = clipboard.selected_quick_prop_index();
+        let mut clicked = None;
+
+        egui::Grid::new("quick_props_grid").num_columns(5).show(ui, |ui| {
+            for (i, screenshot) in clipboard.quick_props_ui_iter().enumerate()
+            {
+                let response = match screenshot
+                {
+                    Some(tex_id) =>
+                    {
+                        ui.add(
+                            egui::ImageButton::new((tex_id, QUICK_PREVIEW_SIZE))
+                                .selected(i == selected)
+                        )
+                    },
+                    None =>
+                    {
+                        ui.add_sized(
+                            QUICK_PREVIEW_SIZE,
+                            egui::SelectableLabel::new(i == selected, INDEXES[i])
+                        )
+                    },
+                };
+
+                if response.on_hover_text(format!("Key {}", INDEXES[i])).clicked()
+                {
+                    clicked = i.into();
+                }
+
+                if i % 5 == 4
+                {
+                    ui.end_row();
+                }
+            }
+        });
+
+        if let Some(i) = clicked
+        {
+            clipboard.set_selected_quick_prop_index(i);
+        }
+    }
+
     /// Draws the prop creation window.
     #[allow(clippy::cast_precision_loss)]
     #[inline]
@@ -480,6 +642,7 @@ impl PaintTool
         window: &Window,
         egui_context: &egui::Context,
         clipboard: &Clipboard,
+        locale: &Locale,
         texture: egui::TextureId
     )
     {
@@ -500,6 +663,37 @@ impl PaintTool
                         .has_focus()
                     });
 
+                    if self.library_name.is_empty()
+                    {
+                        ui.label(format!(
+                            "{} {}",
+                            locale.tr("Leave empty to store in quick slot"),
+                            INDEXES[clipboard.selected_quick_prop_index()]
+                        ));
+                    }
+                    else
+                    {
+                        ui.label(locale.tr("Leave empty to only store in the library"));
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.add_space(8f32);
+                        ui.label(locale.tr("Library name:"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.library_name)
+                                .desired_width(f32::INFINITY)
+                        );
+                    });
+
+                    if self.library_name.is_empty()
+                    {
+                        ui.label(locale.tr("Leave empty to not store in the library"));
+                    }
+                    else if !library::valid_name(&self.library_name)
+                    {
+                        ui.label(locale.tr("Invalid library name"));
+                    }
+
                     if clipboard.props_amount() == 0
                     {
                         ui.label("No used slots");
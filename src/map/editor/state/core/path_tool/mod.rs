@@ -8,7 +8,7 @@ pub(in crate::map::editor::state::core) mod path_creation;
 
 use bevy_egui::egui;
 use glam::Vec2;
-use hill_vacuum_shared::{match_or_panic, return_if_no_match, return_if_none};
+use hill_vacuum_shared::{continue_if_none, match_or_panic, return_if_no_match, return_if_none};
 
 use self::{nodes_editor::NodesEditor, path_creation::PathCreation};
 use super::{
@@ -26,11 +26,13 @@ use super::{
 };
 use crate::{
     map::{
+        brush::convex_polygon::ConvexPolygon,
         drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{
             cursor::Cursor,
             state::{
                 core::tool::subtools_buttons,
+                editor_state::ToolsSettings,
                 grid::Grid,
                 inputs_presses::InputsPresses,
                 manager::EntitiesManager,
@@ -41,6 +43,7 @@ use crate::{
             ToolUpdateBundle
         },
         path::{
+            nodes::Curve,
             EditPath,
             IdNodesDeletionResult,
             IdNodesMoveResult,
@@ -56,8 +59,10 @@ use crate::{
         hull::Hull,
         identifiers::{EntityCenter, EntityId, Id},
         iterators::FilterSet,
+        math::polygons::convex_decomposition,
         misc::{Camera, TakeValue, Toggle}
-    }
+    },
+    warning_message
 };
 
 //=======================================================================//
@@ -75,6 +80,8 @@ enum Status
     PreDrag(Vec2, Option<ItemBeneathCursor>),
     /// Dragging [`Node`]s.
     Drag(CursorDelta, Vec<(Id, Vec<NodesMove>)>),
+    /// Dragging the handle of a curve control point, with the [`Curve`] before the drag.
+    CurveHandleDrag(Id, u8, bool, Curve),
     /// Editing an existing [`Path`].
     SingleEditing(Id, PathEditing),
     /// Attaching a [`Path`] to an entity.
@@ -138,7 +145,10 @@ enum ItemBeneathCursor
     /// An entity that could have a [`Path`].
     PossibleMoving(Id),
     /// A [`Path`] [`Node`].
-    PathNode(Id, u8)
+    PathNode(Id, u8),
+    /// The handle of a control point of the curved segment starting at a [`Path`] [`Node`], and
+    /// whether it is attached to the end [`Node`].
+    CurveHandle(Id, u8, bool)
 }
 
 impl EntityId for ItemBeneathCursor
@@ -149,7 +159,10 @@ impl EntityId for ItemBeneathCursor
     #[inline]
     fn id_as_ref(&self) -> &Id
     {
-        let (Self::SelectedMoving(id) | Self::PossibleMoving(id) | Self::PathNode(id, _)) = self;
+        let (Self::SelectedMoving(id) |
+        Self::PossibleMoving(id) |
+        Self::PathNode(id, _) |
+        Self::CurveHandle(id, ..)) = self;
         id
     }
 }
@@ -184,6 +197,12 @@ impl Selector
             {
                 let id = entity.id();
 
+                if let Some((idx, at_end)) =
+                    entity.path_curve_handle_beneath_cursor(cursor_pos, camera_scale)
+                {
+                    items.push(ItemBeneathCursor::CurveHandle(id, idx, at_end), true);
+                }
+
                 for (idx, selected) in entity.path_nodes_nearby_cursor_pos(cursor_pos, camera_scale)
                 {
                     items.push(ItemBeneathCursor::PathNode(id, idx), selected);
@@ -264,7 +283,9 @@ pub(in crate::map::editor::state::core) struct PathTool
     /// The [`Node`]s parameters editor.
     nodes_editor: NodesEditor,
     /// The items selector.
-    selector:     Selector
+    selector:     Selector,
+    /// The UI subtool the ongoing [`Path`] editing was started from, if any.
+    ui_subtool:   Option<SubTool>
 }
 
 impl DisableSubtool for PathTool
@@ -293,6 +314,7 @@ impl OngoingMultiframeChange for PathTool
         matches!(
             self.status,
             Status::Drag(..) |
+                Status::CurveHandleDrag(..) |
                 Status::Simulation(..) |
                 Status::SingleEditing(_, PathEditing::InsertNode { .. })
         )
@@ -324,7 +346,8 @@ impl PathTool
         PathTool {
             status:       Status::Inactive(drag_selection.into()),
             nodes_editor: NodesEditor::default(),
-            selector:     Selector::new()
+            selector:     Selector::new(),
+            ui_subtool:   None
         }
     }
 
@@ -386,7 +409,7 @@ impl PathTool
         let value = match status
         {
             Status::PreDrag(..) | Status::Drag(..) | Status::Inactive(_) => cursor.world(),
-            Status::SingleEditing(..) | Status::InsertNodeUi(_) => cursor.world_snapped(),
+            Status::SingleEditing(..) | Status::InsertNodeUi(_) => cursor.world_path_snapped(),
             _ => return None
         };
 
@@ -453,7 +476,7 @@ impl PathTool
 
     /// Updates the tool.
     #[inline]
-    pub fn update(&mut self, bundle: &mut ToolUpdateBundle)
+    pub fn update(&mut self, bundle: &mut ToolUpdateBundle, settings: &ToolsSettings)
     {
         let item_beneath_cursor = self.selector.item_beneath_cursor(
             bundle.drawing_resources,
@@ -470,6 +493,15 @@ impl PathTool
         {
             Status::Inactive(rect) =>
             {
+                self.ui_subtool = None;
+
+                if bundle.inputs.double_click_node() &&
+                    !bundle.inputs.shift_pressed() &&
+                    matches!(item_beneath_cursor, Some(ItemBeneathCursor::PathNode(..)))
+                {
+                    self.nodes_editor.request_focus();
+                }
+
                 let value = rect.drag_selection(
                     bundle,
                     cursor_pos.unwrap(),
@@ -481,8 +513,19 @@ impl PathTool
                         {
                             if bundle.inputs.enter.just_pressed()
                             {
+                                if bundle.inputs.ctrl_pressed()
+                                {
+                                    if bundle.inputs.shift_pressed()
+                                    {
+                                        Self::polygons_to_paths(bundle);
+                                    }
+                                    else
+                                    {
+                                        Self::paths_to_polygons(bundle);
+                                    }
+                                }
                                 // Initiate paths simulation.
-                                if bundle.manager.selected_moving_amount() != 0
+                                else if bundle.manager.selected_moving_amount() != 0
                                 {
                                     if let Some(value) =
                                         Self::enable_simulation(bundle.manager, nodes_editor)
@@ -522,7 +565,8 @@ impl PathTool
                             // See if we should enable node insertion.
                             return match return_if_none!(item_beneath_cursor, LeftMouse::Pressed)
                             {
-                                ItemBeneathCursor::SelectedMoving(_) => LeftMouse::Pressed,
+                                ItemBeneathCursor::SelectedMoving(_) |
+                                ItemBeneathCursor::CurveHandle(..) => LeftMouse::Pressed,
                                 ItemBeneathCursor::PossibleMoving(id) =>
                                 {
                                     LeftMouse::Value(Status::SingleEditing(
@@ -537,6 +581,17 @@ impl PathTool
                             };
                         }
 
+                        if let Some(ItemBeneathCursor::CurveHandle(id, idx, at_end)) =
+                            item_beneath_cursor
+                        {
+                            return LeftMouse::Value(Status::CurveHandleDrag(
+                                id,
+                                idx,
+                                at_end,
+                                bundle.manager.moving(id).path_curve_at_index(usize::from(idx))
+                            ));
+                        }
+
                         let (id, idx) = return_if_no_match!(
                             item_beneath_cursor,
                             Some(ItemBeneathCursor::PathNode(id, idx)),
@@ -607,7 +662,7 @@ impl PathTool
                 }
 
                 self.status = Status::Drag(
-                    return_if_none!(CursorDelta::try_new(bundle.cursor, bundle.grid, *pos)),
+                    return_if_none!(CursorDelta::try_new_path(bundle.cursor, bundle.grid, *pos)),
                     Vec::new()
                 );
                 bundle.edits_history.start_multiframe_edit();
@@ -622,11 +677,39 @@ impl PathTool
                 }
                 else if bundle.cursor.moved()
                 {
-                    drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
+                    drag.conditional_update_path(bundle.cursor, bundle.grid, |delta| {
                         Self::move_nodes(bundle, delta, cumulative_drag)
                     });
                 }
             },
+            Status::CurveHandleDrag(id, idx, at_end, curve) =>
+            {
+                if !bundle.inputs.left_mouse.pressed()
+                {
+                    if bundle.manager.moving(*id).path_curve_at_index(usize::from(*idx)) != *curve
+                    {
+                        bundle.edits_history.path_nodes_curve(*id, vec![(*idx, *curve)]);
+                    }
+
+                    self.status = Status::default();
+                }
+                else if bundle.cursor.moved()
+                {
+                    _ = bundle
+                        .manager
+                        .moving_mut(
+                            bundle.drawing_resources,
+                            bundle.things_catalog,
+                            bundle.grid,
+                            *id
+                        )
+                        .set_path_curve_handle(
+                            usize::from(*idx),
+                            *at_end,
+                            bundle.cursor.world_path_snapped()
+                        );
+                }
+            },
             status @ Status::SingleEditing(..) =>
             {
                 if !Self::single_editing(bundle, status)
@@ -634,6 +717,21 @@ impl PathTool
                     return;
                 }
 
+                match self.ui_subtool.take().filter(|subtool| settings.is_sticky(*subtool))
+                {
+                    Some(SubTool::PathFreeDraw) =>
+                    {
+                        self.status = Status::FreeDrawUi(None);
+                        return;
+                    },
+                    Some(SubTool::PathInsertNode) =>
+                    {
+                        self.status = Status::InsertNodeUi(None);
+                        return;
+                    },
+                    _ => ()
+                };
+
                 self.status = Status::Inactive(
                     self.selector
                         .item_beneath_cursor(
@@ -681,6 +779,7 @@ impl PathTool
                         hgl_e.unwrap(),
                         PathEditing::FreeDraw(PathCreation::default())
                     );
+                    self.ui_subtool = SubTool::PathFreeDraw.into();
                 }
             },
             Status::InsertNodeUi(hgl_e) =>
@@ -699,6 +798,7 @@ impl PathTool
                 );
 
                 self.status = Self::add_node_status(bundle.cursor, id, idx);
+                self.ui_subtool = SubTool::PathInsertNode.into();
             },
             Status::PathConnection(path, hgl_e) =>
             {
@@ -745,7 +845,10 @@ impl PathTool
                             std::mem::take(path).unwrap()
                         );
                     },
-                    ItemBeneathCursor::PathNode(..) => unreachable!()
+                    ItemBeneathCursor::PathNode(..) | ItemBeneathCursor::CurveHandle(..) =>
+                    {
+                        unreachable!()
+                    }
                 };
 
                 self.status = Status::Inactive(Some(item_beneath_cursor).into());
@@ -760,7 +863,7 @@ impl PathTool
     {
         Status::SingleEditing(identifier, PathEditing::InsertNode {
             index: index + 1,
-            pos:   cursor.world_snapped()
+            pos:   cursor.world_path_snapped()
         })
     }
 
@@ -961,6 +1064,116 @@ impl PathTool
         false
     }
 
+    /// Spawns brushes shaped like the [`Path`]s of the selected entities. If a [`Path`] does not
+    /// describe a convex polygon it is decomposed in multiple convex brushes. The brushes spawned
+    /// from the [`Path`] of a brush inherit its properties and texture.
+    #[inline]
+    fn paths_to_polygons(bundle: &mut ToolUpdateBundle)
+    {
+        let mut polygons = Vec::new();
+        let mut invalid = false;
+
+        for moving in bundle.manager.selected_moving()
+        {
+            let path = continue_if_none!(moving.path());
+            let center = moving.center();
+            let vxs = (0..path.len())
+                .map(|i| path.node_at_index_pos(i) + center)
+                .collect::<Vec<_>>();
+
+            let (properties, texture) = if bundle.manager.is_thing(moving.id())
+            {
+                (bundle.default_brush_properties.instance(), None)
+            }
+            else
+            {
+                let brush = bundle.manager.brush(moving.id());
+                (brush.properties(), brush.texture_settings().cloned())
+            };
+
+            match convex_decomposition(&vxs)
+            {
+                Some(decomposition) =>
+                {
+                    polygons.extend(decomposition.into_iter().map(|vxs| {
+                        (ConvexPolygon::from((vxs, texture.as_ref())), properties.clone())
+                    }));
+                },
+                None => invalid = true
+            };
+        }
+
+        if invalid
+        {
+            warning_message("Some paths do not describe a valid polygon and were not converted.");
+        }
+
+        if polygons.is_empty()
+        {
+            return;
+        }
+
+        bundle.edits_history.with_group("Paths to Polygons", |edits_history| {
+            bundle.manager.deselect_selected_entities(edits_history);
+
+            for (polygon, properties) in polygons
+            {
+                bundle.manager.spawn_brush(
+                    bundle.drawing_resources,
+                    edits_history,
+                    bundle.grid,
+                    polygon,
+                    properties
+                );
+            }
+        });
+    }
+
+    /// Gives the selected brushes that can have a [`Path`] one whose [`Node`]s are placed on their
+    /// vertexes, so that [`paths_to_polygons`](Self::paths_to_polygons) spawns a copy of them.
+    /// The brushes keep their shape, properties, and texture.
+    #[inline]
+    fn polygons_to_paths(bundle: &mut ToolUpdateBundle)
+    {
+        let paths = bundle
+            .manager
+            .selected_possible_moving_ids()
+            .filter(|id| !bundle.manager.is_thing(**id))
+            .map(|id| {
+                let brush = bundle.manager.brush(*id);
+                let center = brush.center();
+                let vxs = brush.vertexes().collect::<Vec<_>>();
+                let mut path = Path::new(&[vxs[0], vxs[1]], center);
+
+                for (index, vx) in vxs.into_iter().enumerate().skip(2)
+                {
+                    path.insert_node_at_index(vx, index, center);
+                }
+
+                (*id, path)
+            })
+            .collect::<Vec<_>>();
+
+        if paths.is_empty()
+        {
+            return;
+        }
+
+        bundle.edits_history.with_group("Polygons to Paths", |edits_history| {
+            for (id, path) in paths
+            {
+                bundle.manager.create_path(
+                    bundle.drawing_resources,
+                    bundle.things_catalog,
+                    edits_history,
+                    bundle.grid,
+                    id,
+                    path
+                );
+            }
+        });
+    }
+
     /// Deletes the selected [`Node`]s or [`Path`]s depending on whether alt is pressed.
     #[inline]
     #[must_use]
@@ -1160,7 +1373,8 @@ impl PathTool
 
                     match hgl_e
                     {
-                        ItemBeneathCursor::SelectedMoving(id) =>
+                        ItemBeneathCursor::SelectedMoving(id) |
+                        ItemBeneathCursor::CurveHandle(id, ..) =>
                         {
                             manager.moving(id).draw_highlighted_with_path_nodes(
                                 window,
@@ -1212,6 +1426,21 @@ impl PathTool
             );
         }
 
+        if matches!(
+            self.status,
+            Status::Inactive(_) |
+                Status::PreDrag(..) |
+                Status::Drag(..) |
+                Status::CurveHandleDrag(..) |
+                Status::InsertNodeUi(_)
+        )
+        {
+            for moving in manager.selected_moving()
+            {
+                moving.draw_path_curve_handles(drawer);
+            }
+        }
+
         match &self.status
         {
             Status::Inactive(rect) =>
@@ -1223,7 +1452,23 @@ impl PathTool
             {
                 draw_entities_with_highlight!(*hgl_e);
             },
-            Status::Drag(..) => draw_entities!(),
+            Status::Drag(drag, _) =>
+            {
+                draw_entities!();
+                drag.draw_distance(window, camera, drawer);
+            },
+            Status::CurveHandleDrag(id, ..) =>
+            {
+                manager.moving(*id).draw_highlighted_with_path_nodes(
+                    window,
+                    camera,
+                    brushes,
+                    things_catalog,
+                    drawer
+                );
+
+                draw_entities!(*id);
+            },
             Status::SingleEditing(id, editing) =>
             {
                 match editing
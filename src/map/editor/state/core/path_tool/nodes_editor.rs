@@ -22,10 +22,22 @@ use crate::{
     },
     utils::{
         identifiers::EntityId,
-        overall_value::{OverallValueInterface, OverallValueToUi, UiOverallValue}
+        overall_value::{OverallValue, OverallValueInterface, OverallValueToUi, UiOverallValue}
     }
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The names of the shapes the segments of a [`Path`] can have.
+const CURVES: [&str; 3] = ["Straight", "Quadratic", "Cubic"];
+/// The names of the easings of the [`Node`]s.
+const EASINGS: [&str; 6] = ["Linear", "Ease in", "Ease out", "Sine", "Cubic", "Custom"];
+/// The labels of the control values of the custom easings.
+const EASING_CONTROLS: [&str; 2] = ["Easing control 1", "Easing control 2"];
+
 //=======================================================================//
 // MACROS
 //
@@ -67,6 +79,8 @@ macro_rules! movement_values {
         {
             let mut overall = OverallMovement::new();
 
+            // Only the first field receives the requested focus.
+            let focus = std::mem::take(&mut self.focus_requested);
             let interacting = Self::textedit(
                 ui,
                 &mut self.selected_nodes_movement.$value,
@@ -74,6 +88,7 @@ macro_rules! movement_values {
                 bundle.inputs,
                 $label,
                 simulation_active,
+                focus,
                 |new_value| {
                     Self::[< set_ $value >](
                         bundle.drawing_resources,
@@ -111,7 +126,9 @@ pub(in crate::map::editor::state::core) struct NodesEditor
     /// The overall [`Node`]s movement parameters.
     selected_nodes_movement: UiOverallMovement,
     /// The index of the UI element being interacted with.
-    interacting:             [bool; 5]
+    interacting:             [bool; 7],
+    /// Whether the first UI element should be focused.
+    focus_requested:         bool
 }
 
 impl NodesEditor
@@ -141,6 +158,10 @@ impl NodesEditor
     #[must_use]
     pub fn interacting(&self) -> bool { self.interacting.iter().any(|b| *b) }
 
+    /// Requests the keyboard focus for the first UI element the next time it is shown.
+    #[inline]
+    pub fn request_focus(&mut self) { self.focus_requested = true; }
+
     /// The textedit widget. Returns its [`egui::Response`].
     #[inline]
     fn textedit<F: FnOnce(f32) -> Option<f32>>(
@@ -150,21 +171,176 @@ impl NodesEditor
         inputs: &InputsPresses,
         label: &str,
         simulation_active: bool,
+        focus: bool,
         f: F
     ) -> bool
     {
         ui.label(label);
-        let interacting =
-            OverallValueField::show(ui, clipboard, inputs, value, !simulation_active, f);
+        let interacting = OverallValueField::show_focusable(
+            ui,
+            clipboard,
+            inputs,
+            value,
+            !simulation_active,
+            focus,
+            f
+        );
         ui.end_row();
         interacting
     }
 
+    /// The UI element to set the shape of the segments starting at the selected [`Node`]s.
+    #[inline]
+    fn curve(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle, simulation_active: bool)
+    {
+        ui.label(bundle.config.locale.tr("Curve"));
+
+        ui.add_enabled_ui(
+            !simulation_active && self.selected_nodes_movement.curve.is_some(),
+            |ui| {
+                ui.horizontal(|ui| {
+                    for tag in CURVES
+                    {
+                        let selected =
+                            self.selected_nodes_movement.curve == OverallValue::Uniform(tag);
+
+                        if !ui.selectable_label(selected, tag).clicked() || selected
+                        {
+                            continue;
+                        }
+
+                        bundle.edits_history.path_nodes_curve_cluster(
+                            bundle
+                                .manager
+                                .selected_movings_mut(
+                                    bundle.drawing_resources,
+                                    bundle.things_catalog,
+                                    bundle.grid
+                                )
+                                .filter_map(|mut entity| {
+                                    entity
+                                        .set_selected_path_nodes_curve(tag)
+                                        .map(|edit| (entity.id(), edit))
+                                })
+                        );
+
+                        self.selected_nodes_movement.curve = tag.into();
+                    }
+                });
+            }
+        );
+
+        ui.end_row();
+    }
+
+    /// The UI element to set the [`Easing`] of the selected [`Node`]s.
+    #[inline]
+    fn easing(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle, simulation_active: bool)
+    {
+        ui.label(bundle.config.locale.tr("Easing"));
+
+        ui.add_enabled_ui(
+            !simulation_active && self.selected_nodes_movement.easing.is_some(),
+            |ui| {
+                let selected_text = match self.selected_nodes_movement.easing
+                {
+                    OverallValue::Uniform(tag) => tag,
+                    _ => ""
+                };
+
+                egui::ComboBox::from_id_salt("path_easing")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        for tag in EASINGS
+                        {
+                            let selected = selected_text == tag;
+
+                            if !ui.selectable_label(selected, tag).clicked() || selected
+                            {
+                                continue;
+                            }
+
+                            let mut overall = OverallMovement::new();
+
+                            bundle.edits_history.path_nodes_easing_cluster(
+                                bundle
+                                    .manager
+                                    .selected_movings_mut(
+                                        bundle.drawing_resources,
+                                        bundle.things_catalog,
+                                        bundle.grid
+                                    )
+                                    .filter_map(|mut entity| {
+                                        entity.set_selected_path_nodes_easing(tag).map(|edit| {
+                                            _ = overall.merge(
+                                                entity.overall_selected_path_nodes_movement()
+                                            );
+                                            (entity.id(), edit)
+                                        })
+                                    })
+                            );
+
+                            self.selected_nodes_movement.easing = tag.into();
+
+                            if overall.is_some()
+                            {
+                                self.selected_nodes_movement.easing_controls =
+                                    overall.easing_controls.map(Into::into);
+                            }
+                        }
+                    });
+            }
+        );
+
+        ui.end_row();
+    }
+
+    /// The UI elements to set the control values of the custom [`Easing`]s of the selected
+    /// [`Node`]s.
+    #[inline]
+    fn easing_controls(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle, simulation_active: bool)
+    {
+        for (i, label) in EASING_CONTROLS.into_iter().enumerate()
+        {
+            ui.label(label);
+
+            self.interacting[5 + i] = OverallValueField::show(
+                ui,
+                bundle.clipboard,
+                bundle.inputs,
+                &mut self.selected_nodes_movement.easing_controls[i],
+                !simulation_active,
+                |value| {
+                    let value = value.clamp(0f32, 1f32);
+
+                    bundle.edits_history.path_nodes_easing_cluster(
+                        bundle
+                            .manager
+                            .selected_movings_mut(
+                                bundle.drawing_resources,
+                                bundle.things_catalog,
+                                bundle.grid
+                            )
+                            .filter_map(|mut entity| {
+                                entity
+                                    .set_selected_path_nodes_easing_control(i, value)
+                                    .map(|edit| (entity.id(), edit))
+                            })
+                    );
+
+                    value.into()
+                }
+            );
+
+            ui.end_row();
+        }
+    }
+
     /// Shows the UI elements.
     #[inline]
     pub fn show(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle, simulation_active: bool)
     {
-        self.interacting = [false; 5];
+        self.interacting = [false; 7];
         ui.label(egui::RichText::new("PLATFORM TOOL"));
 
         egui::Grid::new("nodes_editor")
@@ -177,6 +353,9 @@ impl NodesEditor
                 self.min_speed(ui, bundle, simulation_active);
                 self.accel_travel_percentage(ui, bundle, simulation_active);
                 self.decel_travel_percentage(ui, bundle, simulation_active);
+                self.curve(ui, bundle, simulation_active);
+                self.easing(ui, bundle, simulation_active);
+                self.easing_controls(ui, bundle, simulation_active);
             });
     }
 
@@ -257,18 +436,18 @@ impl NodesEditor
 /// Returns the maximum value between `speed` and 1.
 #[inline]
 #[must_use]
-fn one_clamp(speed: f32) -> f32 { speed.max(1f32) }
+const fn one_clamp(speed: f32) -> f32 { speed.max(1f32) }
 
 //=======================================================================//
 
 /// Returns the maximum value between `speed` and 0.
 #[inline]
 #[must_use]
-fn zero_clamp(speed: f32) -> f32 { speed.max(0f32) }
+const fn zero_clamp(speed: f32) -> f32 { speed.max(0f32) }
 
 //=======================================================================//
 
 /// Clamps `value` to the 0..100 range.
 #[inline]
 #[must_use]
-fn travel_percentage_clamp(value: f32) -> f32 { value.clamp(0f32, 100f32) }
+const fn travel_percentage_clamp(value: f32) -> f32 { value.clamp(0f32, 100f32) }
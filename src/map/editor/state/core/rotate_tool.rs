@@ -44,7 +44,7 @@ use crate::{
             AroundEqual,
             FastNormalize
         },
-        misc::{TakeValue, Toggle}
+        misc::{Camera, TakeValue, Toggle}
     }
 };
 
@@ -64,7 +64,9 @@ enum Status
     /// Moving the pivot through the UI.
     MovePivotUi,
     /// Dragging the mouse to rotate.
-    Drag(Vec2, Vec<(Id, ConvexPolygon)>, f32)
+    Drag(Vec2, Vec<(Id, ConvexPolygon)>, f32),
+    /// Aligning a side of the selected brushes to a line.
+    Align(Align)
 }
 
 impl Default for Status
@@ -90,6 +92,20 @@ impl EnabledTool for Status
 
 //=======================================================================//
 
+/// The steps of the alignment of a side of the selected brushes to a line.
+#[derive(Clone, Copy)]
+enum Align
+{
+    /// Picking the side of a selected brush to align.
+    Side,
+    /// Picking the first point of the line, or the side of a non selected brush to align to.
+    FirstPoint([Vec2; 2]),
+    /// Picking the second point of the line.
+    SecondPoint([Vec2; 2], Vec2)
+}
+
+//=======================================================================//
+
 /// The rotation angle.
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
 pub(in crate::map::editor::state) enum RotateAngle
@@ -235,7 +251,7 @@ impl DisableSubtool for RotateTool
     #[inline]
     fn disable_subtool(&mut self)
     {
-        if matches!(self.status, Status::MovePivotUi)
+        if matches!(self.status, Status::MovePivotUi | Status::Align(_))
         {
             self.status = Status::default();
         }
@@ -254,9 +270,15 @@ impl RotateTool
     #[inline]
     pub fn tool(manager: &EntitiesManager, settings: &ToolsSettings) -> ActiveTool
     {
+        let center = Self::center(manager, settings).unwrap();
+
         ActiveTool::Rotate(RotateTool {
             status: Status::Inactive(()),
-            pivot:  Self::pivot(manager, settings)
+            pivot:  match settings.rotate_pivot
+            {
+                Some((prev_center, pivot)) if prev_center.around_equal_narrow(&center) => pivot,
+                _ => center
+            }
         })
     }
 
@@ -267,14 +289,27 @@ impl RotateTool
     /// otherwise the center of the textures.
     #[inline]
     #[must_use]
-    fn pivot(manager: &EntitiesManager, settings: &ToolsSettings) -> Vec2
+    fn center(manager: &EntitiesManager, settings: &ToolsSettings) -> Option<Vec2>
     {
         if settings.entity_editing()
         {
-            return manager.selected_brushes_center().unwrap();
+            return manager.selected_brushes_center();
         }
 
-        manager.selected_textured_brushes_center().unwrap()
+        manager.selected_textured_brushes_center()
+    }
+
+    /// Returns the center of the selection and the pivot, to be restored when the tool is enabled
+    /// again with the same selection.
+    #[inline]
+    #[must_use]
+    pub fn stored_pivot(
+        &self,
+        manager: &EntitiesManager,
+        settings: &ToolsSettings
+    ) -> Option<(Vec2, Vec2)>
+    {
+        Self::center(manager, settings).map(|center| (center, self.pivot))
     }
 
     /// Returns the cursor position used by the tool.
@@ -284,8 +319,8 @@ impl RotateTool
     {
         match &self.status
         {
-            Status::Inactive(()) | Status::Drag(..) => cursor.world(),
-            Status::MovePivot | Status::MovePivotUi => cursor.world_snapped()
+            Status::Inactive(()) | Status::Drag(..) | Status::Align(Align::Side) => cursor.world(),
+            Status::MovePivot | Status::MovePivotUi | Status::Align(_) => cursor.world_snapped()
         }
     }
 
@@ -324,6 +359,19 @@ impl RotateTool
                 }
                 else if bundle.inputs.left_mouse.just_pressed()
                 {
+                    if bundle.inputs.ctrl_pressed()
+                    {
+                        if settings.entity_editing()
+                        {
+                            if let Some(side) = Self::selected_brushes_side(bundle, cursor_pos)
+                            {
+                                self.status = Status::Align(Align::FirstPoint(side));
+                            }
+                        }
+
+                        return;
+                    }
+
                     self.status = Status::Drag(cursor_pos, Vec::new(), 0f32);
                 }
                 else if let Some(dir) = bundle.inputs.directional_keys_delta()
@@ -395,10 +443,144 @@ impl RotateTool
                 }
 
                 self.status = Status::default();
+            },
+            Status::Align(align) =>
+            {
+                if !bundle.inputs.left_mouse.just_pressed()
+                {
+                    return;
+                }
+
+                let camera_scale = bundle.camera.scale();
+
+                match *align
+                {
+                    Align::Side =>
+                    {
+                        *align = Align::FirstPoint(return_if_none!(Self::selected_brushes_side(
+                            bundle, cursor_pos
+                        )));
+                    },
+                    Align::FirstPoint(side) =>
+                    {
+                        let world_pos = bundle.cursor.world();
+                        let target = bundle
+                            .manager
+                            .brushes_at_pos(world_pos, camera_scale.into())
+                            .iter()
+                            .filter(|brush| !bundle.manager.is_selected(brush.id()))
+                            .find_map(|brush| {
+                                brush.nearby_side(world_pos, camera_scale).map(|(side, _)| side)
+                            });
+
+                        // The brushes are placed against the picked side, so its direction is
+                        // inverted.
+                        if let Some([a, b]) = target
+                        {
+                            Self::align_brushes(bundle, settings, side, [b, a]);
+                            self.status = Status::default();
+                            return;
+                        }
+
+                        *align = Align::SecondPoint(side, cursor_pos);
+                    },
+                    Align::SecondPoint(side, start) =>
+                    {
+                        if cursor_pos.around_equal_narrow(&start)
+                        {
+                            return;
+                        }
+
+                        Self::align_brushes(bundle, settings, side, [start, cursor_pos]);
+                        self.status = Status::default();
+                    }
+                };
             }
         };
     }
 
+    /// Returns the side of the selected brushes near `cursor_pos`, if any.
+    #[inline]
+    #[must_use]
+    fn selected_brushes_side(bundle: &ToolUpdateBundle, cursor_pos: Vec2) -> Option<[Vec2; 2]>
+    {
+        let camera_scale = bundle.camera.scale();
+
+        bundle
+            .manager
+            .selected_brushes_at_pos(cursor_pos, camera_scale)
+            .iter()
+            .find_map(|brush| brush.nearby_side(cursor_pos, camera_scale).map(|(side, _)| side))
+    }
+
+    /// Rotates and moves the selected brushes so that `side` lies on the line passing through
+    /// `line`, with its first vertex on the first point of `line`.
+    #[inline]
+    fn align_brushes(
+        bundle: &mut ToolUpdateBundle,
+        settings: &ToolsSettings,
+        side: [Vec2; 2],
+        line: [Vec2; 2]
+    )
+    {
+        let move_texture = settings.texture_editing();
+        let (side_dir, line_dir) = (side[1] - side[0], line[1] - line[0]);
+        let angle = (line_dir.y.atan2(line_dir.x) - side_dir.y.atan2(side_dir.x))
+            .to_degrees()
+            .rem_euclid(360f32);
+        let mut backup_polygons = Vec::new();
+
+        if !angle.around_equal_narrow(&0f32) &&
+            !angle.around_equal_narrow(&360f32) &&
+            !Self::rotate_brushes(bundle, side[0], angle, move_texture, &mut backup_polygons)
+        {
+            return;
+        }
+
+        let delta = line[0] - side[0];
+
+        let valid = bundle.manager.test_operation_validity(|manager| {
+            manager.selected_brushes().find_map(|brush| {
+                (!brush.check_move(bundle.drawing_resources, bundle.grid, delta, move_texture))
+                    .then_some(brush.id())
+            })
+        });
+
+        if !valid
+        {
+            // Revert the rotation.
+            for (id, polygon) in &backup_polygons
+            {
+                bundle
+                    .manager
+                    .brush_mut(bundle.drawing_resources, bundle.grid, *id)
+                    .swap_polygon(&mut polygon.clone());
+            }
+
+            return;
+        }
+
+        if delta != Vec2::ZERO
+        {
+            fill_backup_polygons(bundle.manager, &mut backup_polygons);
+
+            for mut brush in bundle
+                .manager
+                .selected_brushes_mut(bundle.drawing_resources, bundle.grid)
+            {
+                brush.move_polygon(delta, move_texture);
+            }
+        }
+
+        if backup_polygons.is_empty()
+        {
+            return;
+        }
+
+        bundle.edits_history.polygon_edit_cluster(backup_polygons);
+        bundle.edits_history.override_edit_tag("Brushes alignment");
+    }
+
     /// Rotates the selected brushes.
     #[inline]
     fn rotate_brushes_with_keyboard(
@@ -655,6 +837,21 @@ impl RotateTool
 
         drawer.square_highlight(self.pivot, Color::ToolCursor);
 
+        if let Status::Align(align) = &self.status
+        {
+            match align
+            {
+                Align::Side => (),
+                Align::FirstPoint(side) => drawer.line(side[0], side[1], Color::ToolCursor),
+                Align::SecondPoint(side, start) =>
+                {
+                    drawer.line(side[0], side[1], Color::ToolCursor);
+                    drawer.square_highlight(*start, Color::ToolCursor);
+                    drawer.line(*start, cursor.world_snapped(), Color::ToolCursor);
+                }
+            };
+        }
+
         if !matches!(self.status, Status::Drag(..))
         {
             drawer.square_highlight(cursor.world_snapped(), Color::ToolCursor);
@@ -698,6 +895,18 @@ impl RotateTool
                     .integer()
             );
         });
+
+        let aligning = matches!(self.status, Status::Align(_));
+
+        if ui
+            .add_enabled(
+                settings.entity_editing() && !self.ongoing_multi_frame_change(),
+                egui::SelectableLabel::new(aligning, "Align side")
+            )
+            .clicked()
+        {
+            self.status = if aligning { Status::default() } else { Status::Align(Align::Side) };
+        }
     }
 
     /// Draws the subtools.
@@ -198,10 +198,15 @@ impl ScaleTool
                 settings
             )
             .unwrap(),
-            selected_corner: Corner::TopLeft
+            selected_corner: settings.scale_corner
         })
     }
 
+    /// Returns the selected [`Corner`].
+    #[inline]
+    #[must_use]
+    pub const fn selected_corner(&self) -> Corner { self.selected_corner }
+
     //==============================================================
     // Info
 
@@ -21,7 +21,12 @@ use crate::{
         },
         drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{
-            state::{edits_history::EditsHistory, grid::Grid, manager::EntitiesManager},
+            state::{
+                editor_state::ToolsSettings,
+                edits_history::EditsHistory,
+                grid::Grid,
+                manager::EntitiesManager
+            },
             DrawBundle,
             StateUpdateBundle,
             ToolUpdateBundle
@@ -75,15 +80,20 @@ impl ShearTool
 {
     /// Return an [`ActiveTool`] in its shear tool variant.
     #[inline]
-    pub fn tool(bundle: &StateUpdateBundle) -> ActiveTool
+    pub fn tool(bundle: &StateUpdateBundle, settings: &ToolsSettings) -> ActiveTool
     {
         ActiveTool::Shear(ShearTool {
             status:        Status::Keyboard,
             outline:       Self::outline(bundle.manager, bundle.grid),
-            selected_side: Side::Top
+            selected_side: settings.shear_side
         })
     }
 
+    /// Returns the selected [`Side`].
+    #[inline]
+    #[must_use]
+    pub const fn selected_side(&self) -> Side { self.selected_side }
+
     //==============================================================
     // Update
 
@@ -8,7 +8,7 @@ use glam::Vec2;
 use hill_vacuum_shared::{match_or_panic, return_if_no_match, return_if_none};
 
 use super::{
-    cursor_delta::CursorDelta,
+    cursor_delta::{CursorDelta, EdgeConstraint},
     deselect_vertexes,
     draw_non_selected_brushes,
     rect::{LeftMouse, Rect},
@@ -142,8 +142,8 @@ enum Status
     Inactive(Rect),
     /// Preparing to drag sides.
     PreDrag(Vec2),
-    /// Dragging sides.
-    Drag(CursorDelta, Vec<(Id, Vec<VertexesMove>)>),
+    /// Dragging sides, with the constraint applicable to the edge preceding the dragged side.
+    Drag(CursorDelta, Vec<(Id, Vec<VertexesMove>)>, Option<EdgeConstraint>),
     /// Xtruding.
     Xtrusion
     {
@@ -566,11 +566,12 @@ impl SideTool
 
                 self.status = Status::Drag(
                     return_if_none!(CursorDelta::try_new(bundle.cursor, bundle.grid, *pos)),
-                    Vec::new()
+                    Vec::new(),
+                    EdgeConstraint::new(bundle.manager)
                 );
                 bundle.edits_history.start_multiframe_edit();
             },
-            Status::Drag(drag, cumulative_drag) =>
+            Status::Drag(drag, cumulative_drag, constraint) =>
             {
                 if !bundle.inputs.left_mouse.pressed()
                 {
@@ -581,13 +582,33 @@ impl SideTool
 
                     bundle.edits_history.end_multiframe_edit();
                     self.status = Status::default();
+                    return;
                 }
-                else if bundle.cursor.moved()
+
+                let constraint_changed = constraint
+                    .as_mut()
+                    .is_some_and(|constraint| constraint.update(bundle.inputs));
+
+                if !bundle.cursor.moved() && !constraint_changed
                 {
-                    drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
-                        Self::move_sides(bundle, delta, cumulative_drag)
-                    });
+                    return;
                 }
+
+                match constraint
+                {
+                    Some(constraint) if constraint.active() =>
+                    {
+                        drag.constrained_update(bundle.cursor, constraint, |delta| {
+                            Self::move_sides(bundle, delta, cumulative_drag)
+                        });
+                    },
+                    _ =>
+                    {
+                        drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
+                            Self::move_sides(bundle, delta, cumulative_drag)
+                        });
+                    }
+                };
             },
             Status::Xtrusion {
                 mode,
@@ -1205,7 +1226,20 @@ impl SideTool
                     bundle.drawer.hull(&hull, Color::Hull);
                 }
             },
-            Status::Drag(..) | Status::PreDrag(_) | Status::XtrusionUi =>
+            Status::Drag(drag, _, constraint) =>
+            {
+                draw_selected_brushes(bundle);
+
+                match constraint
+                {
+                    Some(constraint) if constraint.active() =>
+                    {
+                        constraint.draw(bundle, drag.delta());
+                    },
+                    _ => drag.draw_distance(bundle.window, bundle.camera, bundle.drawer)
+                };
+            },
+            Status::PreDrag(_) | Status::XtrusionUi =>
             {
                 draw_selected_brushes(bundle);
             },
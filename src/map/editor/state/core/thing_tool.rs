@@ -3,24 +3,28 @@
 //
 //=======================================================================//
 
+use std::cell::Cell;
+
 use bevy_egui::egui;
 use hill_vacuum_shared::return_if_none;
 
 use super::tool::{ActiveTool, DisableSubtool, EnabledTool, SubTool};
 use crate::{
+    config::language::Locale,
     map::{
-        drawer::color::Color,
+        drawer::{color::Color, drawing_resources::DrawingResources},
         editor::{
             state::{
                 core::{bottom_panel, tool::subtools_buttons},
                 editor_state::ToolsSettings,
                 format_texture_preview,
                 manager::EntitiesManager,
-                ui::{ToolsButtons, UiBundle}
+                ui::{AccessibleName, ToolsButtons, UiBundle}
             },
             DrawBundle,
             ToolUpdateBundle
-        }
+        },
+        thing::{catalog::ThingsCatalog, ThingInterface}
     },
     utils::{
         collections::{hash_set, Ids},
@@ -29,6 +33,16 @@ use crate::{
     }
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The minimum zoom of the thing preview.
+const MIN_PREVIEW_ZOOM: f32 = 0.25;
+/// The maximum zoom of the thing preview.
+const MAX_PREVIEW_ZOOM: f32 = 8f32;
+
 //=======================================================================//
 // ENUMS
 //
@@ -70,6 +84,116 @@ impl EnabledTool for Status
 //
 //=======================================================================//
 
+/// The popup showing a zoomable preview of a [`Thing`] of the gallery.
+///
+/// [`Thing`]: crate::map::thing::Thing
+#[must_use]
+struct ThingPreview
+{
+    /// The index of the [`Thing`](crate::map::thing::Thing) in the gallery.
+    index: usize,
+    /// The zoom.
+    zoom:  f32
+}
+
+impl ThingPreview
+{
+    /// Shows the popup.
+    /// Returns whether it should be kept open.
+    #[inline]
+    #[must_use]
+    fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        things_catalog: &ThingsCatalog,
+        drawing_resources: &DrawingResources,
+        locale: &Locale
+    ) -> bool
+    {
+        /// The padding around the bounding box.
+        const PADDING: f32 = 16f32;
+        /// The maximum size of the drawing area.
+        const MAX_SIZE: f32 = 512f32;
+
+        // The things may have been reloaded.
+        if self.index >= things_catalog.len()
+        {
+            return false;
+        }
+
+        let thing = things_catalog.thing_at_index(self.index);
+        let (tex_id, tex_size, _) = drawing_resources.egui_texture(thing.preview());
+        let mut open = true;
+
+        egui::Window::new(locale.tr("Thing preview"))
+            .id("thing_preview".into())
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(egui_context, |ui| {
+                ui.label(format!("{} ({}x{})", thing.name(), thing.width(), thing.height()));
+                ui.add(
+                    egui::Slider::new(&mut self.zoom, MIN_PREVIEW_ZOOM..=MAX_PREVIEW_ZOOM)
+                        .logarithmic(true)
+                        .text(locale.tr("Zoom"))
+                );
+
+                egui::ScrollArea::both()
+                    .max_width(MAX_SIZE)
+                    .max_height(MAX_SIZE)
+                    .show(ui, |ui| {
+                        let bounds = egui::vec2(thing.width(), thing.height()) * self.zoom;
+                        let (rect, response) = ui.allocate_exact_size(
+                            bounds + egui::Vec2::splat(2f32 * PADDING),
+                            egui::Sense::hover()
+                        );
+
+                        // Ctrl + mouse wheel zoom.
+                        if response.hovered()
+                        {
+                            self.zoom = (self.zoom * ui.input(egui::InputState::zoom_delta))
+                                .clamp(MIN_PREVIEW_ZOOM, MAX_PREVIEW_ZOOM);
+                        }
+
+                        let center = rect.center();
+                        let tex_size = tex_size.as_vec2();
+                        let tex_size = egui::vec2(tex_size.x, tex_size.y);
+                        let painter = ui.painter();
+                        let stroke = egui::Stroke::new(1f32, egui::Color32::WHITE);
+
+                        painter.image(
+                            tex_id,
+                            egui::Rect::from_center_size(
+                                center,
+                                tex_size * (bounds.x / tex_size.x).min(bounds.y / tex_size.y)
+                            ),
+                            egui::Rect::from_min_max(
+                                egui::pos2(0f32, 0f32),
+                                egui::pos2(1f32, 1f32)
+                            ),
+                            egui::Color32::WHITE
+                        );
+                        painter.rect_stroke(
+                            egui::Rect::from_center_size(center, bounds),
+                            4f32,
+                            stroke
+                        );
+
+                        // Angle indicator.
+                        painter.arrow(
+                            center,
+                            egui::vec2(bounds.min_elem().min(128f32) * 0.375, 0f32),
+                            stroke
+                        );
+                    });
+            });
+
+        open
+    }
+}
+
+//=======================================================================//
+
 /// The thing tool.
 pub(in crate::map::editor::state::core) struct ThingTool
 {
@@ -78,7 +202,9 @@ pub(in crate::map::editor::state::core) struct ThingTool
     /// The maximum height of the bottom panel.
     max_bottom_panel_height: f32,
     /// The state of the tool.
-    status:                  Status
+    status:                  Status,
+    /// The preview of a [`Thing`](crate::map::thing::Thing) of the gallery, if open.
+    preview:                 Option<ThingPreview>
 }
 
 impl DisableSubtool for ThingTool
@@ -102,7 +228,8 @@ impl ThingTool
         ActiveTool::Thing(ThingTool {
             drawn_things:            hash_set![],
             max_bottom_panel_height: 0f32,
-            status:                  Status::default()
+            status:                  Status::default(),
+            preview:                 None
         })
     }
 
@@ -226,7 +353,7 @@ impl ThingTool
 
     /// The left UI panel.
     #[inline]
-    pub fn left_panel(ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    pub fn left_panel(ui: &mut egui::Ui, bundle: &mut UiBundle)
     {
         /// The width of the label.
         const LABEL_WIDTH: f32 = 50f32;
@@ -245,10 +372,76 @@ impl ThingTool
                         .size(egui_extras::Size::exact(LABEL_WIDTH))
                         .size(egui_extras::Size::remainder())
                         .horizontal(|mut strip| {
-                            settings.thing_pivot.ui(&mut strip);
+                            bundle.settings.thing_pivot.ui(&mut strip);
                         });
                 });
             });
+
+        Self::flags(ui, bundle);
+    }
+
+    /// The checkboxes of the spawn flags of the selected things, shown if they all represent the
+    /// same [`Thing`](crate::Thing).
+    #[inline]
+    fn flags(ui: &mut egui::Ui, bundle: &mut UiBundle)
+    {
+        let UiBundle {
+            config,
+            things_catalog,
+            manager,
+            edits_history,
+            ..
+        } = bundle;
+
+        let thing_id = {
+            let mut selected = manager.selected_things();
+            let thing_id = return_if_none!(selected.next()).thing_id();
+
+            if selected.any(|thing| thing.thing_id() != thing_id)
+            {
+                return;
+            }
+
+            thing_id
+        };
+
+        let names = return_if_none!(things_catalog.thing(thing_id)).flags();
+
+        if names.is_empty()
+        {
+            return;
+        }
+
+        let (all, any) = manager
+            .selected_things()
+            .fold((u32::MAX, 0), |(all, any), thing| (all & thing.flags(), any | thing.flags()));
+        let mut changed = None;
+
+        ui.separator();
+        ui.label(config.locale.tr("FLAGS"));
+
+        for (i, name) in names.iter().enumerate()
+        {
+            let bit = 1 << i;
+            let mut value = all & bit != 0;
+            let indeterminate = !value && any & bit != 0;
+
+            if ui
+                .add(egui::Checkbox::new(&mut value, name).indeterminate(indeterminate))
+                .clicked()
+            {
+                changed = (bit, value).into();
+            }
+        }
+
+        let (bit, value) = return_if_none!(changed);
+
+        edits_history.thing_flags_cluster(manager.selected_things_mut(things_catalog).filter_map(
+            |mut thing| {
+                let flags = if value { thing.flags() | bit } else { thing.flags() & !bit };
+                thing.set_flags(flags).map(|prev| (thing.id(), prev))
+            }
+        ));
     }
 
     /// Bottom UI panel.
@@ -260,6 +453,7 @@ impl ThingTool
         const PREVIEW_SIZE: egui::Vec2 = egui::Vec2::splat(128f32);
 
         let UiBundle {
+            config,
             things_catalog,
             drawing_resources,
             manager,
@@ -268,6 +462,8 @@ impl ThingTool
             ..
         } = bundle;
 
+        let previewed = Cell::new(None);
+
         let clicked = bottom_panel(
             egui_context,
             "things",
@@ -287,9 +483,22 @@ impl ThingTool
                             texture.tex_size,
                             PREVIEW_SIZE.x
                         );
+                        response.image_button_name(true, || texture.name.to_owned());
                         ui.vertical_centered(|ui| {
                             ui.label(texture.name);
+                            ui.label(format!("{}x{}", texture.width, texture.height));
+
+                            if let Some(category) = texture.category
+                            {
+                                ui.weak(category);
+                            }
                         });
+
+                        if response.secondary_clicked()
+                        {
+                            previewed.set(texture.index.into());
+                        }
+
                         response
                     })
                     .inner,
@@ -297,6 +506,20 @@ impl ThingTool
                 )
             }
         );
+
+        if let Some(index) = previewed.get()
+        {
+            self.preview = ThingPreview { index, zoom: 1f32 }.into();
+        }
+
+        if let Some(preview) = &mut self.preview
+        {
+            if !preview.show(egui_context, things_catalog, drawing_resources, &config.locale)
+            {
+                self.preview = None;
+            }
+        }
+
         let clicked = return_if_none!(clicked);
 
         if !inputs.alt_pressed() && !matches!(self.status, Status::ChangeUi)
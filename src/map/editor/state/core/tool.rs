@@ -31,19 +31,25 @@ use super::{
     Core
 };
 use crate::{
-    config::controls::{bind::Bind, BindsKeyCodes},
+    config::{
+        controls::{bind::Bind, BindsKeyCodes},
+        language::Locale,
+        toolbar::ToolbarLayout
+    },
+    error_message,
     map::{
-        brush::{convex_polygon::ConvexPolygon, Brush},
+        brush::{convex_polygon::ConvexPolygon, Brush, RotateResult},
         drawer::drawing_resources::DrawingResources,
         editor::{
             state::{
                 clipboard::Clipboard,
                 core::{deselect_vertexes, draw_selected_and_non_selected_sprites},
-                editor_state::ToolsSettings,
+                editor_state::{PasteSnap, ToolsSettings},
                 edits_history::EditsHistory,
-                grid::Grid,
+                grid::{Grid, GridSlice},
                 inputs_presses::InputsPresses,
                 manager::EntitiesManager,
+                script,
                 ui::{ToolsButtons, UiBundle}
             },
             DrawBundle,
@@ -51,14 +57,16 @@ use crate::{
             StateUpdateBundle,
             ToolUpdateBundle
         },
+        path::{calc_path_hull, Moving},
         properties::DefaultBrushProperties,
-        thing::catalog::ThingsCatalog
+        thing::{catalog::ThingsCatalog, ThingInterface},
+        OutOfBounds
     },
     utils::{
         collections::hash_set,
         identifiers::{EntityId, Id},
         iterators::FilterSet,
-        math::{polygons::convex_hull, HashVec2},
+        math::{points::rotate_point, polygons::convex_hull, HashVec2},
         misc::FromToStr
     }
 };
@@ -128,6 +136,15 @@ where
     #[must_use]
     fn tooltip_label(self, binds: &BindsKeyCodes) -> String;
 
+    /// The tooltip text with the tool label translated according to `locale`.
+    #[inline]
+    #[must_use]
+    fn localized_tooltip_label(self, binds: &BindsKeyCodes, locale: &Locale) -> String
+    {
+        let label = self.label();
+        self.tooltip_label(binds).replacen(label, locale.tr(label), 1)
+    }
+
     /// Whether the tool can be enabled.
     #[must_use]
     fn change_conditions_met(self, change_conditions: &ChangeConditions) -> bool;
@@ -471,6 +488,21 @@ impl ActiveTool
         }
     }
 
+    /// Changes the soft selection radius by `scroll` if the vertex tool is active and the soft
+    /// selection is enabled. Returns whether that was the case.
+    #[inline]
+    #[must_use]
+    pub fn soft_selection_radius_scroll(&self, settings: &mut ToolsSettings, scroll: f32) -> bool
+    {
+        if !settings.soft_selection || !matches!(self, Self::Vertex(_))
+        {
+            return false;
+        }
+
+        VertexTool::scroll_soft_selection_radius(settings, scroll);
+        true
+    }
+
     /// Whether the split subtoon is available.
     #[inline]
     #[must_use]
@@ -533,6 +565,9 @@ impl ActiveTool
         }
 
         bundle.clipboard.copy(
+            bundle.images,
+            bundle.prop_cameras,
+            bundle.user_textures,
             bundle.drawing_resources,
             bundle.things_catalog,
             bundle.grid,
@@ -566,6 +601,9 @@ impl ActiveTool
         };
 
         bundle.clipboard.copy(
+            bundle.images,
+            bundle.prop_cameras,
+            bundle.user_textures,
             bundle.drawing_resources,
             bundle.things_catalog,
             bundle.grid,
@@ -581,7 +619,7 @@ impl ActiveTool
 
     /// Pastes the copied entities.
     #[inline]
-    pub fn paste(&mut self, bundle: &mut StateUpdateBundle)
+    pub fn paste(&mut self, bundle: &mut StateUpdateBundle, snap: PasteSnap)
     {
         assert!(self.copy_paste_available(), "Paste is not available.");
 
@@ -621,13 +659,40 @@ impl ActiveTool
             bundle.manager,
             bundle.edits_history,
             bundle.grid,
-            bundle.cursor.world_snapped()
+            bundle.cursor.world_snapped(),
+            snap
         );
         bundle.manager.schedule_outline_update();
     }
 
+    /// Pastes the entities stored in the copy history at `index`, which also become the copied
+    /// ones. If the path tool is active they are only stored as the copied entities.
+    #[inline]
+    pub fn paste_from_history(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        index: usize,
+        snap: PasteSnap
+    )
+    {
+        assert!(self.copy_paste_available(), "Paste is not available.");
+
+        bundle.clipboard.restore_copy_from_history(index);
+
+        if !matches!(self, Self::Path(_))
+        {
+            self.paste(bundle, snap);
+        }
+    }
+
     #[inline]
-    pub fn duplicate(&mut self, bundle: &mut StateUpdateBundle, delta: Vec2)
+    pub fn duplicate(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        delta: Vec2,
+        snap: PasteSnap,
+        move_texture: bool
+    )
     {
         assert!(self.copy_paste_available(), "Duplicate is not available.");
 
@@ -647,11 +712,105 @@ impl ActiveTool
             bundle.manager,
             bundle.edits_history,
             bundle.grid,
-            delta
+            delta,
+            snap,
+            move_texture
+        );
+        bundle.manager.schedule_outline_update();
+    }
+
+    /// Spawns a brush matching the footprint of each selected thing, enlarged by `margin`.
+    #[inline]
+    pub fn things_footprint(&mut self, bundle: &mut StateUpdateBundle, margin: f32)
+    {
+        assert!(self.copy_paste_available(), "Things footprint is not available.");
+
+        let hulls = bundle
+            .manager
+            .selected_things()
+            .map(|thing| thing.thing_hull(bundle.things_catalog).bumped(margin))
+            .collect::<Vec<_>>();
+
+        if hulls.iter().any(OutOfBounds::out_of_bounds)
+        {
+            error_message("Cannot spawn footprint: out of bounds");
+            return;
+        }
+
+        bundle.edits_history.with_group("Things footprint", |edits_history| {
+            if let Self::Vertex(_) | Self::Side(_) = self
+            {
+                deselect_vertexes(
+                    bundle.drawing_resources,
+                    bundle.manager,
+                    edits_history,
+                    bundle.grid
+                );
+            }
+
+            bundle.manager.deselect_selected_entities(edits_history);
+
+            for hull in hulls
+            {
+                bundle.manager.spawn_brush(
+                    bundle.drawing_resources,
+                    edits_history,
+                    bundle.grid,
+                    ConvexPolygon::from(hull.rectangle().to_vec()),
+                    bundle.default_properties.map_brushes.instance()
+                );
+            }
+        });
+
+        bundle.manager.schedule_outline_update();
+    }
+
+    /// Spawns copies of the selected entities centered at `positions`.
+    #[inline]
+    pub fn distribute(&mut self, bundle: &mut StateUpdateBundle, positions: &[Vec2])
+    {
+        assert!(self.copy_paste_available(), "Distribute is not available.");
+
+        if let Self::Vertex(_) | Self::Side(_) = self
+        {
+            deselect_vertexes(
+                bundle.drawing_resources,
+                bundle.manager,
+                bundle.edits_history,
+                bundle.grid
+            );
+        }
+
+        bundle.clipboard.distribute(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.manager,
+            bundle.edits_history,
+            bundle.grid,
+            positions
         );
         bundle.manager.schedule_outline_update();
     }
 
+    /// Runs the script `source`, returning its output.
+    #[inline]
+    pub fn run_script(&mut self, bundle: &mut StateUpdateBundle, source: &str) -> String
+    {
+        assert!(self.copy_paste_available(), "Script is not available.");
+
+        if let Self::Vertex(_) | Self::Side(_) = self
+        {
+            deselect_vertexes(
+                bundle.drawing_resources,
+                bundle.manager,
+                bundle.edits_history,
+                bundle.grid
+            );
+        }
+
+        script::run(bundle, source)
+    }
+
     /// Updates the outline of certain tools.
     #[inline]
     pub fn update_outline(
@@ -769,6 +928,31 @@ impl ActiveTool
         );
     }
 
+    /// Deselects all entities and selects the one with [`Id`] `identifier`.
+    #[inline]
+    pub fn exclusively_select_entity(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        settings: &ToolsSettings,
+        identifier: Id
+    )
+    {
+        assert!(self.select_all_available(), "Entity selection is not available.");
+
+        bundle.manager.deselect_selected_entities(bundle.edits_history);
+        bundle
+            .manager
+            .select_entity(identifier, bundle.inputs, bundle.edits_history);
+
+        self.update_outline(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.manager,
+            bundle.grid,
+            settings
+        );
+    }
+
     //==============================================================
     // Undo/Redo
 
@@ -780,15 +964,38 @@ impl ActiveTool
     //==============================================================
     // Update
 
-    /// Toggles the map preview.
+    /// Toggles the map preview. Any ongoing multiframe change is concluded before the preview is
+    /// enabled.
     #[inline]
-    pub fn toggle_map_preview(&mut self, bundle: &StateUpdateBundle)
+    pub fn toggle_map_preview(&mut self, bundle: &mut StateUpdateBundle)
     {
-        *self = match self
+        if let Self::MapPreview(t) = self
         {
-            Self::MapPreview(t) => std::mem::take(t.prev_tool()),
-            _ => MapPreviewTool::tool(bundle, self)
-        };
+            *self = std::mem::take(t.prev_tool());
+            return;
+        }
+
+        self.conclude_multiframe_change(bundle);
+        *self = MapPreviewTool::tool(bundle, self);
+    }
+
+    /// Concludes the ongoing multiframe change, if any. The edits executed so far are stored in
+    /// the history and the tool is reset.
+    #[inline]
+    fn conclude_multiframe_change(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        self.disable_subtool();
+
+        if bundle.edits_history.multiframe_edit()
+        {
+            bundle.edits_history.end_multiframe_edit();
+            bundle.edits_history.push_frame_edit();
+        }
+
+        if self.ongoing_multi_frame_change()
+        {
+            *self = Self::default();
+        }
     }
 
     /// Updates the tool.
@@ -801,7 +1008,7 @@ impl ActiveTool
             Self::Entity(t) => t.update(bundle, settings),
             Self::Vertex(t) =>
             {
-                let path = return_if_none!(t.update(bundle));
+                let path = return_if_none!(t.update(bundle, settings));
                 *self = PathTool::path_connection(bundle, path);
             },
             Self::Side(t) => t.update(bundle),
@@ -823,7 +1030,7 @@ impl ActiveTool
             {
                 *self = std::mem::take(return_if_none!(t.update(bundle)));
             },
-            Self::Path(t) => t.update(bundle),
+            Self::Path(t) => t.update(bundle, settings),
             Self::Paint(t) => t.update(bundle),
             Self::Thing(t) => t.update(bundle, settings),
             Self::MapPreview(t) => t.update(bundle)
@@ -836,7 +1043,7 @@ impl ActiveTool
         &mut self,
         tool: Tool,
         bundle: &mut StateUpdateBundle,
-        settings: &ToolsSettings,
+        settings: &mut ToolsSettings,
         tool_change_conditions: &ChangeConditions
     )
     {
@@ -856,9 +1063,11 @@ impl ActiveTool
         }
 
         // Tool change.
+        self.store_state(bundle.manager, settings);
+
         *self = match tool
         {
-            Tool::Square => DrawTool::square(self, bundle.cursor),
+            Tool::Square => DrawTool::square(self, bundle.cursor, settings),
             Tool::Triangle => DrawTool::triangle(self, bundle.cursor),
             Tool::Circle => DrawTool::circle(self, bundle.cursor, settings),
             Tool::FreeDraw => DrawTool::free(self),
@@ -887,7 +1096,7 @@ impl ActiveTool
                 return;
             },
             Tool::Scale => ScaleTool::tool(bundle, settings),
-            Tool::Shear => ShearTool::tool(bundle),
+            Tool::Shear => ShearTool::tool(bundle, settings),
             Tool::Rotate => RotateTool::tool(bundle.manager, settings),
             Tool::Flip => FlipTool::tool(bundle),
             Tool::Intersection =>
@@ -897,7 +1106,7 @@ impl ActiveTool
             },
             Tool::Merge =>
             {
-                self.merge_tool(bundle);
+                self.merge_tool(bundle, settings);
                 return;
             },
             Tool::Path => PathTool::tool(self.drag_selection()),
@@ -906,6 +1115,20 @@ impl ActiveTool
         };
     }
 
+    /// Stores in `settings` the state of the tool that is restored when it is enabled again.
+    #[inline]
+    fn store_state(&self, manager: &EntitiesManager, settings: &mut ToolsSettings)
+    {
+        match self
+        {
+            Self::Zoom(t) => t.previous_active_tool.store_state(manager, settings),
+            Self::Scale(t) => settings.scale_corner = t.selected_corner(),
+            Self::Shear(t) => settings.shear_side = t.selected_side(),
+            Self::Rotate(t) => settings.rotate_pivot = t.stored_pivot(manager, settings),
+            _ => ()
+        };
+    }
+
     /// Snaps the selected entities and [`Path`]s to the `grid` based on `grid` and the currently
     /// selected tool.
     #[inline]
@@ -1020,6 +1243,210 @@ impl ActiveTool
         }
     }
 
+    /// Rotates the selected brushes by `angle` degrees around the center of their [`Hull`],
+    /// along with their [`Path`]s, and their textures if the editing target includes them.
+    #[inline]
+    pub fn quick_rotate(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        settings: &ToolsSettings,
+        angle: f32
+    )
+    {
+        if self.ongoing_multi_frame_change() ||
+            !manager.any_selected_brushes() ||
+            !settings.entity_editing() ||
+            matches!(self, Self::Thing(_) | Self::Vertex(_) | Self::Side(_) | Self::Path(_))
+        {
+            return;
+        }
+
+        let pivot = manager.selected_brushes_polygon_hull().unwrap().center();
+        let rotate_texture = settings.target_switch().texture_editing();
+        let mut payloads = Vec::new();
+        let mut paths = Vec::new();
+
+        let valid = manager.test_operation_validity(|manager| {
+            manager
+                .selected_brushes_mut(drawing_resources, grid)
+                .find_map(|mut brush| {
+                    let payload = match brush.check_rotation(
+                        drawing_resources,
+                        grid,
+                        pivot,
+                        angle,
+                        rotate_texture
+                    )
+                    {
+                        RotateResult::Invalid => return brush.id().into(),
+                        RotateResult::Valid(payload) => payload
+                    };
+
+                    if let Some(path) = brush.path()
+                    {
+                        let mut path = path.clone();
+                        path.rotate(angle.to_radians());
+
+                        if calc_path_hull(
+                            &path,
+                            rotate_point(brush.center(), pivot, angle.to_radians())
+                        )
+                        .out_of_bounds()
+                        {
+                            return brush.id().into();
+                        }
+
+                        paths.push((brush.id(), path));
+                    }
+
+                    payloads.push(payload);
+                    None
+                })
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        edits_history.polygon_edit_cluster(
+            manager.selected_brushes().map(|brush| (brush.id(), brush.polygon()))
+        );
+
+        for payload in payloads
+        {
+            manager
+                .brush_mut(drawing_resources, grid, payload.id())
+                .set_rotation_coordinates(payload);
+        }
+
+        for (id, path) in paths
+        {
+            manager.replace_selected_path(
+                drawing_resources,
+                things_catalog,
+                edits_history,
+                grid,
+                id,
+                path
+            );
+        }
+
+        edits_history.override_edit_tag("Brushes rotation");
+        self.update_outline(drawing_resources, things_catalog, manager, grid, settings);
+    }
+
+    /// Replaces the corners of the selected brushes with arcs of the corner radius and resolution
+    /// of `settings`. If it's not possible to round the corners of all the brushes the process
+    /// will be aborted.
+    #[inline]
+    pub fn round_corners(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        settings: &ToolsSettings
+    )
+    {
+        if self.ongoing_multi_frame_change() ||
+            !manager.any_selected_brushes() ||
+            !settings.entity_editing() ||
+            settings.corner_radius == 0f32 ||
+            matches!(self, Self::Thing(_) | Self::Vertex(_) | Self::Side(_) | Self::Path(_))
+        {
+            return;
+        }
+
+        let mut polygons = Vec::new();
+        let valid = manager.test_operation_validity(|manager| {
+            manager.selected_brushes().find_map(|brush| {
+                match brush.rounded_corners(settings.corner_radius, settings.corner_resolution)
+                {
+                    Some(polygon) =>
+                    {
+                        polygons.push((brush.id(), polygon));
+                        None
+                    },
+                    None => brush.id().into()
+                }
+            })
+        });
+
+        if !valid
+        {
+            return;
+        }
+
+        edits_history.with_group("Brushes Corners Rounding", |edits_history| {
+            for (id, polygon) in polygons
+            {
+                let polygon = manager.brush_mut(drawing_resources, grid, id).set_polygon(polygon);
+                edits_history.polygon_edit(id, polygon);
+            }
+        });
+
+        self.update_outline(drawing_resources, things_catalog, manager, grid, settings);
+    }
+
+    /// Slices the selected brushes along the grid lines selected by `slice`.
+    #[inline]
+    pub fn slice_to_grid(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        settings: &ToolsSettings,
+        slice: GridSlice
+    )
+    {
+        if self.ongoing_multi_frame_change() ||
+            !bundle.manager.any_selected_brushes() ||
+            !settings.entity_editing() ||
+            matches!(self, Self::Thing(_) | Self::Vertex(_) | Self::Side(_) | Self::Path(_))
+        {
+            return;
+        }
+
+        let slices = bundle
+            .manager
+            .selected_brushes()
+            .filter_map(|brush| brush.slice(&bundle.grid.slicing_lines(brush.vertexes(), slice)))
+            .collect::<Vec<_>>();
+
+        if slices.is_empty()
+        {
+            return;
+        }
+
+        bundle
+            .edits_history
+            .with_group("Brushes grid slice", |edits_history| {
+                for result in slices
+                {
+                    _ = bundle.manager.replace_brush_with_partition(
+                        bundle.drawing_resources,
+                        edits_history,
+                        bundle.grid,
+                        result.pieces.into_iter(),
+                        result.id,
+                        |brush| brush.set_polygon(result.main)
+                    );
+                }
+            });
+
+        self.update_outline(
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.manager,
+            bundle.grid,
+            settings
+        );
+    }
+
     /// Replaces each selected brushes with four others.
     /// These four brushes create a room with wall thickness equal to the grid size as big as the
     /// brush they replaced. If it's not possible to create rooms for all the brushes the
@@ -1103,6 +1530,22 @@ impl ActiveTool
             }
         }
 
+        let mut properties = bundle.default_properties.map_brushes.instance();
+
+        if success
+        {
+            if let Some((texture, props)) =
+                settings.merge_rule.resolve(bundle.manager.selected_brushes())
+            {
+                if let Some(texture) = texture
+                {
+                    intersection_polygon.set_texture_settings(texture);
+                }
+
+                properties = props;
+            }
+        }
+
         // Spawn the intersection brush.
         self.draw_tool_despawn(bundle, |bundle| {
             bundle.manager.despawn_selected_brushes(
@@ -1118,7 +1561,7 @@ impl ActiveTool
                     bundle.edits_history,
                     bundle.grid,
                     Some(intersection_polygon).into_iter(),
-                    bundle.default_properties.map_brushes.instance()
+                    properties
                 );
             }
         });
@@ -1178,7 +1621,7 @@ impl ActiveTool
 
     /// Executes a vertexes merge based on the active tool.
     #[inline]
-    fn merge_tool(&mut self, bundle: &mut StateUpdateBundle)
+    fn merge_tool(&mut self, bundle: &mut StateUpdateBundle, settings: &ToolsSettings)
     {
         if bundle.inputs.alt_pressed()
         {
@@ -1252,6 +1695,14 @@ impl ActiveTool
             vertexes.extend(brush.vertexes().map(HashVec2));
         }
 
+        let mut properties = bundle.default_properties.map_brushes.instance();
+
+        if let Some((tex, props)) = settings.merge_rule.resolve(bundle.manager.selected_brushes())
+        {
+            texture = tex;
+            properties = props;
+        }
+
         self.draw_tool_despawn(bundle, |bundle| {
             let mut poly = ConvexPolygon::from(convex_hull(vertexes).unwrap().collect::<Vec<_>>());
 
@@ -1265,7 +1716,7 @@ impl ActiveTool
                 bundle.edits_history,
                 bundle.grid,
                 Some(poly).into_iter(),
-                bundle.default_properties.map_brushes.instance()
+                properties
             );
         });
     }
@@ -1322,7 +1773,9 @@ impl ActiveTool
             },
             Self::Paint(_) =>
             {
-                if bundle.manager.any_selected_entities() || bundle.clipboard.props_amount() != 0
+                if bundle.manager.any_selected_entities() ||
+                    bundle.clipboard.props_amount() != 0 ||
+                    bundle.clipboard.library_props_amount() != 0
                 {
                     return;
                 }
@@ -1435,7 +1888,7 @@ impl ActiveTool
                     paths(bundle);
                     return;
                 },
-                ActiveTool::Vertex(t) => t.draw(bundle),
+                ActiveTool::Vertex(t) => t.draw(bundle, settings),
                 ActiveTool::Side(t) => t.draw(bundle),
                 ActiveTool::Clip(t) => t.draw(bundle),
                 ActiveTool::Shatter(t) => t.draw(bundle),
@@ -1506,14 +1959,20 @@ impl ActiveTool
         {
             match tool
             {
-                ActiveTool::Thing(_) => ThingTool::left_panel(ui, bundle.settings),
+                ActiveTool::Thing(_) => ThingTool::left_panel(ui, bundle),
                 ActiveTool::Entity(t) => t.ui(ui, bundle.settings),
+                ActiveTool::Vertex(t) => t.ui(ui, bundle.settings),
                 ActiveTool::Rotate(t) => t.ui(ui, bundle.settings),
                 ActiveTool::Draw(t) => t.ui(ui, bundle.settings),
                 ActiveTool::Clip(t) => t.ui(ui),
                 ActiveTool::Scale(t) => t.ui(ui, bundle.settings),
                 ActiveTool::Shear(t) => t.ui(ui),
                 ActiveTool::Flip(_) => FlipTool::ui(ui, bundle.settings),
+                ActiveTool::Paint(_) =>
+                {
+                    PaintTool::left_panel(ui, bundle.clipboard, &bundle.config.locale)
+                },
+                ActiveTool::MapPreview(t) => t.ui(ui, bundle),
                 ActiveTool::Path(t) =>
                 {
                     t.ui(ui, bundle);
@@ -1618,6 +2077,26 @@ impl Tool
         }
     }
 
+    /// Returns the tools shown in the toolbar, in the order they should be drawn.
+    #[inline]
+    #[must_use]
+    pub fn toolbar(layout: &ToolbarLayout) -> Vec<Self>
+    {
+        let mut tools = Self::iter()
+            .filter_map(|tool| layout.position(tool.bind(), tool as usize).map(|i| (i, tool)))
+            .collect::<Vec<_>>();
+        tools.sort_by_key(|(i, _)| *i);
+        tools.into_iter().map(|(_, tool)| tool).collect()
+    }
+
+    /// Whether the tool is shown in the toolbar.
+    #[inline]
+    #[must_use]
+    pub fn shown(self, layout: &ToolbarLayout) -> bool
+    {
+        layout.position(self.bind(), self as usize).is_some()
+    }
+
     #[inline]
     #[must_use]
     const fn conditions_met(self, change_conditions: &ChangeConditions) -> bool
@@ -14,7 +14,7 @@ use hill_vacuum_shared::{
 };
 
 use super::{
-    cursor_delta::CursorDelta,
+    cursor_delta::{CursorDelta, EdgeConstraint},
     deselect_vertexes,
     draw_non_selected_brushes,
     path_tool::path_creation::PathCreation,
@@ -44,6 +44,7 @@ use crate::{
             cursor::Cursor,
             state::{
                 core::VertexesToggle,
+                editor_state::ToolsSettings,
                 edits_history::EditsHistory,
                 grid::Grid,
                 manager::EntitiesManager,
@@ -65,6 +66,18 @@ use crate::{
     }
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The minimum radius of the soft selection.
+const MIN_SOFT_SELECTION_RADIUS: f32 = 8f32;
+/// The maximum radius of the soft selection.
+const MAX_SOFT_SELECTION_RADIUS: f32 = 1024f32;
+/// The amount the radius of the soft selection is changed by with every scroll of the mouse wheel.
+const SOFT_SELECTION_RADIUS_STEP: f32 = 8f32;
+
 //=======================================================================//
 // ENUMS
 //
@@ -78,8 +91,10 @@ enum Status
     Inactive(Rect),
     /// Preparing for dragging vertexes.
     PreDrag(Vec2),
-    /// Dragging vertexes.
-    Drag(CursorDelta, Vec<(Id, Vec<VertexesMove>)>),
+    /// Dragging vertexes, with the constraint applicable to the edge of the dragged vertex.
+    Drag(CursorDelta, Vec<(Id, Vec<VertexesMove>)>, Option<EdgeConstraint>),
+    /// Dragging vertexes along with the nearby non selected ones.
+    SoftDrag(CursorDelta, Vec<(Id, Vec<SoftVertex>)>),
     /// Inserting a new vertex.
     NewVertex
     {
@@ -88,7 +103,9 @@ enum Status
         /// The index where the vertex is being inserted.
         index:      usize,
         /// The position of the vertex.
-        vx:         Vec2
+        vx:         Vec2,
+        /// Whether the insertion was started from the UI button.
+        ui:         bool
     },
     /// Selecting the brush where to insert a new vertex after having enabled it from the UI
     /// button.
@@ -128,6 +145,20 @@ selected_vertexes!(selected_vertexes_amount);
 
 //=======================================================================//
 
+/// A vertex dragged by the soft selection.
+#[must_use]
+struct SoftVertex
+{
+    /// The index of the vertex.
+    index:  u8,
+    /// The fraction of the drag applied to the vertex.
+    weight: f32,
+    /// The amount the vertex has been moved.
+    delta:  Vec2
+}
+
+//=======================================================================//
+
 /// An extended record of the selected brushes' selected vertexes.
 #[must_use]
 struct BrushesWithSelectedVertexes
@@ -384,7 +415,11 @@ impl VertexTool
     /// Updates the tool.
     #[inline]
     #[must_use]
-    pub fn update(&mut self, bundle: &mut ToolUpdateBundle) -> Option<Path>
+    pub fn update(
+        &mut self,
+        bundle: &mut ToolUpdateBundle,
+        settings: &ToolsSettings
+    ) -> Option<Path>
     {
         let cursor_pos = Self::cursor_pos(bundle.cursor);
 
@@ -489,13 +524,21 @@ impl VertexTool
                     return None;
                 }
 
-                self.0 = Status::Drag(
-                    return_if_none!(CursorDelta::try_new(bundle.cursor, bundle.grid, *pos), None),
-                    Vec::new()
-                );
+                let drag =
+                    return_if_none!(CursorDelta::try_new(bundle.cursor, bundle.grid, *pos), None);
+
+                self.0 = if settings.soft_selection
+                {
+                    Status::SoftDrag(drag, Self::soft_selection(bundle.manager, settings))
+                }
+                else
+                {
+                    Status::Drag(drag, Vec::new(), EdgeConstraint::new(bundle.manager))
+                };
+
                 bundle.edits_history.start_multiframe_edit();
             },
-            Status::Drag(drag, cumulative_drag) =>
+            Status::Drag(drag, cumulative_drag, constraint) =>
             {
                 if !bundle.inputs.left_mouse.pressed()
                 {
@@ -504,13 +547,62 @@ impl VertexTool
                         bundle.edits_history.vertexes_move(cumulative_drag.take_value());
                     }
 
+                    bundle.edits_history.end_multiframe_edit();
+                    self.0 = Status::default();
+                    return None;
+                }
+
+                let constraint_changed = constraint
+                    .as_mut()
+                    .is_some_and(|constraint| constraint.update(bundle.inputs));
+
+                if !bundle.cursor.moved() && !constraint_changed
+                {
+                    return None;
+                }
+
+                match constraint
+                {
+                    Some(constraint) if constraint.active() =>
+                    {
+                        drag.constrained_update(bundle.cursor, constraint, |delta| {
+                            Self::move_vertexes(bundle, delta, cumulative_drag)
+                        });
+                    },
+                    _ =>
+                    {
+                        drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
+                            Self::move_vertexes(bundle, delta, cumulative_drag)
+                        });
+                    }
+                };
+            },
+            Status::SoftDrag(drag, soft_selection) =>
+            {
+                if !bundle.inputs.left_mouse.pressed()
+                {
+                    if drag.delta() != Vec2::ZERO
+                    {
+                        for (id, vxs) in &*soft_selection
+                        {
+                            bundle.edits_history.vertexes_snap(
+                                *id,
+                                vxs.iter().map(|svx| (vec![svx.index], svx.delta)).collect()
+                            );
+                        }
+
+                        bundle.edits_history.override_edit_tag("Vertexes soft move");
+                    }
+
                     bundle.edits_history.end_multiframe_edit();
                     self.0 = Status::default();
                 }
                 else if bundle.cursor.moved()
                 {
+                    let overall_delta = drag.delta();
+
                     drag.conditional_update(bundle.cursor, bundle.grid, |delta| {
-                        Self::move_vertexes(bundle, delta, cumulative_drag)
+                        Self::soft_move_vertexes(bundle, soft_selection, overall_delta + delta)
                     });
                 }
             },
@@ -522,12 +614,18 @@ impl VertexTool
                         Self::alt_left_mouse(bundle.manager, cursor_pos, bundle.camera.scale()),
                         None
                     );
+
+                    if let Status::NewVertex { ui, .. } = &mut self.0
+                    {
+                        *ui = true;
+                    }
                 }
             },
             Status::NewVertex {
                 identifier,
                 index,
-                vx
+                vx,
+                ui
             } =>
             {
                 let mut brush =
@@ -544,7 +642,15 @@ impl VertexTool
                         bundle.edits_history.vertex_insertion(&brush, (*vx, idx));
                     }
 
-                    self.0 = Status::default();
+                    self.0 = if *ui && settings.is_sticky(SubTool::VertexInsert)
+                    {
+                        Status::NewVertexUi
+                    }
+                    else
+                    {
+                        Status::default()
+                    };
+
                     return None;
                 }
 
@@ -618,7 +724,8 @@ impl VertexTool
         Status::NewVertex {
             identifier: id,
             index,
-            vx: cursor_pos
+            vx: cursor_pos,
+            ui: false
         }
         .into()
     }
@@ -816,6 +923,136 @@ impl VertexTool
         true
     }
 
+    /// Returns the vertexes of the selected brushes dragged by the soft selection.
+    #[inline]
+    fn soft_selection(
+        manager: &EntitiesManager,
+        settings: &ToolsSettings
+    ) -> Vec<(Id, Vec<SoftVertex>)>
+    {
+        /// Returns the vertexes of `brush` dragged along with `anchors`.
+        #[inline]
+        fn soft_vertexes(brush: &Brush, anchors: &[Vec2], radius: f32) -> Vec<SoftVertex>
+        {
+            brush
+                .vertexes_selection()
+                .enumerate()
+                .filter_map(|(index, (vx, selected))| {
+                    let weight = if selected
+                    {
+                        1f32
+                    }
+                    else
+                    {
+                        let t = anchors
+                            .iter()
+                            .map(|anchor| anchor.distance(vx))
+                            .fold(f32::MAX, f32::min) /
+                            radius;
+
+                        if t >= 1f32
+                        {
+                            return None;
+                        }
+
+                        // Smooth falloff from the anchors to the edge of the radius.
+                        1f32 - t * t * (3f32 - 2f32 * t)
+                    };
+
+                    SoftVertex {
+                        index: u8::try_from(index).unwrap(),
+                        weight,
+                        delta: Vec2::ZERO
+                    }
+                    .into()
+                })
+                .collect()
+        }
+
+        let radius = settings.soft_selection_radius;
+        let anchors = manager
+            .selected_brushes()
+            .filter_map(|brush| brush.selected_vertexes())
+            .flatten()
+            .collect::<Vec<_>>();
+
+        manager
+            .selected_brushes()
+            .filter_map(|brush| {
+                let vxs = if settings.soft_selection_other_brushes
+                {
+                    soft_vertexes(brush, &anchors, radius)
+                }
+                else
+                {
+                    soft_vertexes(brush, &brush.selected_vertexes()?.collect::<Vec<_>>(), radius)
+                };
+
+                (!vxs.is_empty()).then(|| (brush.id(), vxs))
+            })
+            .collect()
+    }
+
+    /// Moves the vertexes of the soft selection so that they are displaced by their weight
+    /// times `delta`, if possible.
+    #[inline]
+    fn soft_move_vertexes(
+        bundle: &mut ToolUpdateBundle,
+        soft_selection: &mut [(Id, Vec<SoftVertex>)],
+        delta: Vec2
+    ) -> bool
+    {
+        let moves = soft_selection
+            .iter()
+            .map(|(id, vxs)| {
+                (
+                    *id,
+                    vxs.iter()
+                        .map(|svx| (vec![svx.index], svx.weight * delta - svx.delta))
+                        .collect::<Vec<_>>()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let valid = bundle.manager.test_operation_validity(|manager| {
+            moves.iter().find_map(|(id, mov)| {
+                (!manager
+                    .brush_mut(bundle.drawing_resources, bundle.grid, *id)
+                    .check_vertexes_move_at_indexes(mov))
+                .then_some(*id)
+            })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        for (id, mov) in &moves
+        {
+            bundle
+                .manager
+                .brush_mut(bundle.drawing_resources, bundle.grid, *id)
+                .move_vertexes_at_indexes(mov.iter().map(|(idxs, delta)| (idxs.iter(), *delta)));
+        }
+
+        for svx in soft_selection.iter_mut().flat_map(|(_, vxs)| vxs)
+        {
+            svx.delta = svx.weight * delta;
+        }
+
+        true
+    }
+
+    /// Changes the soft selection radius by one step in the direction of `scroll`.
+    #[inline]
+    pub fn scroll_soft_selection_radius(settings: &mut ToolsSettings, scroll: f32)
+    {
+        settings.soft_selection_radius = (settings.soft_selection_radius +
+            scroll.signum() * SOFT_SELECTION_RADIUS_STEP)
+            .clamp(MIN_SOFT_SELECTION_RADIUS, MAX_SOFT_SELECTION_RADIUS);
+    }
+
     /// Deletes the selected vertexes, if possible.
     #[inline]
     fn delete_selected_vertexes(bundle: &mut ToolUpdateBundle)
@@ -902,8 +1139,19 @@ impl VertexTool
 
     /// Draws the tool.
     #[inline]
-    pub fn draw(&self, bundle: &mut DrawBundle)
+    pub fn draw(&self, bundle: &mut DrawBundle, settings: &ToolsSettings)
     {
+        if settings.soft_selection &&
+            matches!(self.0, Status::Inactive(_) | Status::PreDrag(_) | Status::SoftDrag(..))
+        {
+            bundle.drawer.circle(
+                bundle.cursor.world(),
+                64,
+                settings.soft_selection_radius,
+                Color::ToolCursor
+            );
+        }
+
         /// Draws the selected and non selected brushes.
         #[inline]
         fn draw_selected_and_non_selected_brushes(bundle: &mut DrawBundle)
@@ -936,14 +1184,33 @@ impl VertexTool
                 draw_selected_and_non_selected_brushes(bundle);
                 bundle.drawer.hull(&return_if_none!(rect.hull()), Color::Hull);
             },
-            Status::Drag(..) | Status::PreDrag(_) | Status::NewVertexUi =>
+            Status::Drag(drag, _, constraint) =>
+            {
+                draw_selected_and_non_selected_brushes(bundle);
+
+                match constraint
+                {
+                    Some(constraint) if constraint.active() =>
+                    {
+                        constraint.draw(bundle, drag.delta());
+                    },
+                    _ => drag.draw_distance(bundle.window, bundle.camera, bundle.drawer)
+                };
+            },
+            Status::SoftDrag(drag, _) =>
+            {
+                draw_selected_and_non_selected_brushes(bundle);
+                drag.draw_distance(bundle.window, bundle.camera, bundle.drawer);
+            },
+            Status::PreDrag(_) | Status::NewVertexUi =>
             {
                 draw_selected_and_non_selected_brushes(bundle);
             },
             Status::NewVertex {
                 identifier,
                 index,
-                vx
+                vx,
+                ..
             } =>
             {
                 draw_non_selected_brushes(bundle);
@@ -993,6 +1260,29 @@ impl VertexTool
         };
     }
 
+    /// Draws the UI.
+    #[inline]
+    pub fn ui(&self, ui: &mut egui::Ui, settings: &mut ToolsSettings)
+    {
+        ui.label(egui::RichText::new("VERTEX TOOL"));
+
+        ui.add_enabled_ui(!self.ongoing_multi_frame_change(), |ui| {
+            ui.checkbox(&mut settings.soft_selection, "Soft selection");
+
+            ui.add_enabled_ui(settings.soft_selection, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Radius:"));
+                    ui.add(egui::Slider::new(
+                        &mut settings.soft_selection_radius,
+                        MIN_SOFT_SELECTION_RADIUS..=MAX_SOFT_SELECTION_RADIUS
+                    ));
+                });
+
+                ui.checkbox(&mut settings.soft_selection_other_brushes, "Other brushes");
+            });
+        });
+    }
+
     /// Draws the subtools.
     #[inline]
     pub fn draw_subtools(
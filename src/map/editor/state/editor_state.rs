@@ -6,6 +6,7 @@
 use std::{
     fs::{File, OpenOptions},
     io::{BufReader, BufWriter, Write},
+    ops::RangeInclusive,
     path::{Path, PathBuf}
 };
 
@@ -19,41 +20,56 @@ use bevy::{
 use bevy_egui::{egui, EguiUserTextures};
 use glam::{UVec2, Vec2};
 use hill_vacuum_proc_macros::{EnumFromUsize, EnumIter, EnumSize};
-use hill_vacuum_shared::{return_if_no_match, return_if_none, NextValue, FILE_EXTENSION};
-use is_executable::IsExecutable;
+use hill_vacuum_shared::{
+    continue_if_none,
+    return_if_no_match,
+    return_if_none,
+    NextValue,
+    FILE_EXTENSION
+};
 
 use super::{
+    autosave,
     clipboard::{Clipboard, PropCamerasMut},
     core::{
+        draw_tool::cursor_polygon::CircleCursorPolygon,
         rotate_tool::RotateAngle,
         tool::{ChangeConditions, Tool}
     },
-    edits_history::EditsHistory,
+    edits_history::{statistics::MapStatistics, EditsHistory},
     grid::Grid,
     inputs_presses::InputsPresses,
+    integrity::IntegrityReport,
     manager::EntitiesManager,
-    ui::{Interaction, UiFocus}
+    tabs::{edited_map, EditedMap, Tabs},
+    ui::{Interaction, UiFocus},
+    validation::{Severity, ValidationReport}
 };
 use crate::{
     config::{
         controls::{bind::Bind, BindsKeyCodes},
+        tools_presets::ToolsPreset,
         Config
     },
     error_message,
     map::{
+        brush::{convex_polygon::ConvexPolygon, Brush},
         drawer::{
             color::Color,
             drawing_resources::DrawingResources,
             file_animations,
-            texture_loader::TextureLoadingProgress,
+            texture_loader::{TextureLoader, TextureLoadingProgress},
             TextureSize
         },
         editor::{
             state::{
-                core::{tool::ToolInterface, Core},
+                core::{
+                    tool::{SubTool, ToolInterface},
+                    Core
+                },
                 dialog_if_error,
                 test_writer,
-                ui::{Command, Ui}
+                ui::{Command, SplitView, Ui}
             },
             AllDefaultProperties,
             DrawBundle,
@@ -63,27 +79,39 @@ use crate::{
             ToolUpdateBundle
         },
         properties::{
+            BrushProperties,
             DefaultBrushProperties,
             DefaultThingProperties,
             EngineDefaultBrushProperties,
             EngineDefaultThingProperties
         },
-        thing::{catalog::ThingsCatalog, Thing},
+        quake::{read_quake_map, QuakeSlice},
+        thing::{catalog::ThingsCatalog, Thing, ThingInstance, ThingInterface},
         version_number,
+        ExportManifest,
+        Exporter,
         FileStructure,
         MapHeader,
+        MapMetadata,
+        TessellatedMap,
+        TmxTexture,
+        UserManualSections,
         Viewer,
         FILE_VERSION,
+        PREVIOUS_FILE_VERSION,
         UPGRADE_WARNING
     },
     utils::{
-        collections::{hash_map, HashMap},
-        hull::Hull,
+        collections::{hash_map, hash_set, HashMap},
+        hull::{Corner, Hull, Side},
+        identifiers::EntityId,
         misc::{next, prev, Camera, TakeValue, Toggle}
     },
+    warning_message,
     Animation,
     EditorState,
     HardcodedActions,
+    Id,
     TextureInterface,
     TextureSettings
 };
@@ -100,9 +128,25 @@ const ANIMATIONS_FILTER_NAME: &str = "Animations files (.anms)";
 /// The filter of the props files.
 const PROPS_FILTER_NAME: &str = "Props files (.prps)";
 /// The animations file extension.
-const ANIMATIONS_EXTENSION: &str = "anms";
+pub(in crate::map::editor::state) const ANIMATIONS_EXTENSION: &str = "anms";
 /// The props file extension.
-const PROPS_EXTENSION: &str = "prps";
+pub(in crate::map::editor::state) const PROPS_EXTENSION: &str = "prps";
+/// The filter of the tessellated meshes files.
+const MESHES_FILTER_NAME: &str = "Meshes files (.hvms)";
+/// The tessellated meshes file extension.
+const MESHES_EXTENSION: &str = "hvms";
+/// The filter of the glTF files.
+const GLTF_FILTER_NAME: &str = "glTF files (.gltf)";
+/// The glTF file extension.
+const GLTF_EXTENSION: &str = "gltf";
+/// The filter of the Tiled map files.
+const TMX_FILTER_NAME: &str = "Tiled map files (.tmx)";
+/// The Tiled map file extension.
+const TMX_EXTENSION: &str = "tmx";
+/// The filter of the Quake map files.
+const QUAKE_MAP_FILTER_NAME: &str = "Quake map files (.map)";
+/// The Quake map file extension.
+const QUAKE_MAP_EXTENSION: &str = "map";
 
 //=======================================================================//
 // ENUMS
@@ -180,16 +224,32 @@ impl TargetSwitch
     }
 
     /// Resets the value of `self` to `TargetSwitch::Entity` if the map state does not allow texture
-    /// editing.
+    /// editing, storing the previous value in `suspended`. The stored value is restored once
+    /// texture editing is allowed again.
     #[inline]
-    fn update(&mut self, core: &Core, manager: &EntitiesManager) -> bool
+    fn update(
+        &mut self,
+        suspended: &mut Option<Self>,
+        core: &Core,
+        manager: &EntitiesManager
+    ) -> bool
     {
         if !Self::can_cycle(core, manager)
         {
+            if *self != Self::Entity
+            {
+                *suspended = (*self).into();
+            }
+
             *self = Self::Entity;
             return false;
         }
 
+        if let Some(value) = suspended.take()
+        {
+            *self = value;
+        }
+
         true
     }
 
@@ -360,6 +420,248 @@ impl ThingPivot
     }
 }
 
+//=======================================================================//
+
+/// The point of the bounding box of the pasted or duplicated entities that is snapped to the grid.
+#[derive(Default, Clone, Copy, PartialEq, EnumIter, EnumSize)]
+pub(in crate::map::editor::state) enum PasteSnap
+{
+    /// No snap, the entities are spawned at the raw position.
+    #[default]
+    None,
+    /// Top left corner.
+    Corner,
+    /// Center.
+    Center
+}
+
+impl std::fmt::Display for PasteSnap
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", self.tag())
+    }
+}
+
+impl PasteSnap
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "None",
+            Self::Corner => "Corner",
+            Self::Center => "Center"
+        }
+    }
+
+    /// Returns the vector that needs to be added to the position of the entities contained in
+    /// `hull` to snap its reference point to the grid.
+    #[inline]
+    #[must_use]
+    pub fn snap_delta(self, grid: &Grid, hull: &Hull) -> Vec2
+    {
+        let point = match self
+        {
+            Self::None => return Vec2::ZERO,
+            Self::Corner => hull.top_left(),
+            Self::Center => hull.center()
+        };
+
+        grid.snap_point(point).map_or(Vec2::ZERO, |snapped| snapped - point)
+    }
+
+    /// Draws an UI combobox that allows to change the value of `self`.
+    #[inline]
+    pub fn ui(&mut self, ui: &mut egui::Ui)
+    {
+        egui::ComboBox::from_id_salt("paste_snap")
+            .selected_text(format!("{self}"))
+            .show_ui(ui, |ui| {
+                for s in Self::iter()
+                {
+                    ui.selectable_value(self, s, s.tag());
+                }
+            });
+    }
+}
+
+//=======================================================================//
+
+/// The snap applied to the path nodes.
+#[derive(Default, Clone, Copy, PartialEq)]
+pub(in crate::map::editor::state) enum PathSnap
+{
+    /// The same snap of the brush vertexes.
+    #[default]
+    Brushes,
+    /// No snap.
+    Free,
+    /// Snap to a grid with squares of the contained size, regardless of the cursor snap.
+    Custom(i16)
+}
+
+impl std::fmt::Display for PathSnap
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", self.tag())
+    }
+}
+
+impl PathSnap
+{
+    /// The range of the possible custom snap sizes.
+    const CUSTOM_RANGE: RangeInclusive<i16> = 1..=256;
+
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::Brushes => "Brushes",
+            Self::Free => "Free",
+            Self::Custom(_) => "Custom"
+        }
+    }
+
+    /// Draws an UI combobox that allows to change the value of `self`, along with the size of the
+    /// custom snap if selected.
+    #[inline]
+    pub fn ui(&mut self, ui: &mut egui::Ui, grid_size: i16)
+    {
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("path_snap")
+                .selected_text(format!("{self}"))
+                .show_ui(ui, |ui| {
+                    for s in [Self::Brushes, Self::Free]
+                    {
+                        ui.selectable_value(self, s, s.tag());
+                    }
+
+                    if ui
+                        .selectable_label(matches!(self, Self::Custom(_)), "Custom")
+                        .clicked() &&
+                        !matches!(self, Self::Custom(_))
+                    {
+                        *self = Self::Custom(grid_size);
+                    }
+                });
+
+            if let Self::Custom(size) = self
+            {
+                ui.add(egui::DragValue::new(size).range(Self::CUSTOM_RANGE));
+            }
+        });
+    }
+}
+
+//=======================================================================//
+
+/// The rule used to pick the texture and properties of the brush generated by the merge and
+/// intersection tools.
+#[derive(Default, Clone, Copy, PartialEq, EnumIter, EnumSize)]
+pub(in crate::map::editor::state) enum MergeRule
+{
+    /// The texture is kept only if shared by all brushes, the properties are the default ones.
+    #[default]
+    Shared,
+    /// Texture and properties of the brush with the highest draw height.
+    Topmost,
+    /// Texture and properties of the brush with the highest draw height, with the draw height set
+    /// to the rounded average of the ones of the textured brushes.
+    Average
+}
+
+impl std::fmt::Display for MergeRule
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", self.tag())
+    }
+}
+
+impl MergeRule
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::Shared => "Shared",
+            Self::Topmost => "Topmost",
+            Self::Average => "Average"
+        }
+    }
+
+    /// Returns the texture and properties the brush resulting from the merge or intersection of
+    /// `brushes` should have, or `None` if the default behavior should be used.
+    #[inline]
+    #[must_use]
+    pub fn resolve<'a>(
+        self,
+        brushes: impl Iterator<Item = &'a Brush>
+    ) -> Option<(Option<TextureSettings>, BrushProperties)>
+    {
+        if self == Self::Shared
+        {
+            return None;
+        }
+
+        let mut heights = Vec::new();
+
+        // Ties are broken by picking the oldest brush, so that the result is deterministic.
+        let topmost = brushes
+            .inspect(|brush| {
+                if let Some(texture) = brush.texture_settings()
+                {
+                    heights.push(i32::from(texture.height()));
+                }
+            })
+            .max_by_key(|brush| {
+                (
+                    brush.texture_settings().map_or(i8::MIN, TextureInterface::height),
+                    std::cmp::Reverse(brush.id().value())
+                )
+            })?;
+
+        let mut texture = topmost.texture_settings().cloned();
+
+        if let (Self::Average, Some(texture)) = (self, &mut texture)
+        {
+            let len = i32::try_from(heights.len()).unwrap();
+            let average = (heights.iter().sum::<i32>() * 2 + len).div_euclid(len * 2);
+            _ = texture.set_height(i8::try_from(average).unwrap());
+        }
+
+        (texture, topmost.properties()).into()
+    }
+
+    /// Draws an UI combobox that allows to change the value of `self`.
+    #[inline]
+    pub fn ui(&mut self, ui: &mut egui::Ui)
+    {
+        egui::ComboBox::from_id_salt("merge_rule")
+            .selected_text(format!("{self}"))
+            .show_ui(ui, |ui| {
+                for r in Self::iter()
+                {
+                    ui.selectable_value(self, r, r.tag());
+                }
+            });
+    }
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -374,6 +676,7 @@ struct FileRead
     map_default_thing_properties: DefaultThingProperties,
     clipboard: Clipboard,
     grid: Grid,
+    statistics: MapStatistics,
     path: PathBuf
 }
 
@@ -388,16 +691,74 @@ pub(in crate::map) struct ToolsSettings
     target_switch: TargetSwitch,
     /// Whether the [`TargetSwitch`] can be changed in value.
     can_switch: bool,
+    /// The [`TargetSwitch`] value to restore once it can be changed again.
+    suspended_target_switch: Option<TargetSwitch>,
     /// The resolution of the circle drawing tool (how many sides the circle has).
     pub(in crate::map::editor::state) circle_draw_resolution: u8,
+    /// The radius of the corners of the drawn rectangles and of the rounded corners.
+    pub(in crate::map::editor::state) corner_radius: f32,
+    /// The amount of segments the rounded corners are made of.
+    pub(in crate::map::editor::state) corner_resolution: u8,
     /// The minimum angle the entities can be rotated when using the rotate tool.
     pub(in crate::map::editor::state) rotate_angle: RotateAngle,
     /// Whether texture scrolling is enabled while editing the map.
     pub scroll_enabled: bool,
     /// Whether texture parallax is enabled while editing the map.
     pub parallax_enabled: bool,
+    /// Whether arrows showing the direction and speed of the texture scrolling are drawn on the
+    /// brushes.
+    pub scroll_arrows: bool,
+    /// Whether a grid aligned to the texture is drawn on the selected brushes while a tool with
+    /// texture editing capabilities is active.
+    pub texture_grid: bool,
     /// The spawn pivot of the [`ThingInstance`] used by the thing tool.
-    pub(in crate::map::editor::state) thing_pivot: ThingPivot
+    pub(in crate::map::editor::state) thing_pivot: ThingPivot,
+    /// The point of the pasted and duplicated entities that is snapped to the grid.
+    pub(in crate::map::editor::state) paste_snap: PasteSnap,
+    /// Whether dragging the selected vertexes also drags the nearby non selected ones.
+    pub(in crate::map::editor::state) soft_selection: bool,
+    /// The distance within which the non selected vertexes are dragged by the soft selection.
+    pub(in crate::map::editor::state) soft_selection_radius: f32,
+    /// Whether the soft selection also drags the vertexes of the other selected brushes.
+    pub(in crate::map::editor::state) soft_selection_other_brushes: bool,
+    /// The distance the brushes generated from the footprint of the things extend beyond it.
+    pub(in crate::map::editor::state) footprint_margin: f32,
+    /// The rule used to pick the texture and properties of the merged and intersected brushes.
+    pub(in crate::map::editor::state) merge_rule: MergeRule,
+    /// Whether the cursor snap granularity depends on the zoom rather than the grid size.
+    pub(in crate::map::editor::state) adaptive_snap: bool,
+    /// The approximate size on screen, in pixels, of the adaptive snap granularity.
+    pub(in crate::map::editor::state) adaptive_snap_pixels: u8,
+    /// The snap applied to the path nodes.
+    pub(in crate::map::editor::state) path_snap: PathSnap,
+    /// Whether the cursor is snapped to the nearby path nodes and to the centers of the entities
+    /// the paths belong to.
+    pub(in crate::map::editor::state) snap_to_paths: bool,
+    /// Whether the parallax is exaggerated and the parallax values of the brushes are shown.
+    pub(in crate::map::editor::state) parallax_calibration: bool,
+    /// The factor the parallax is multiplied by during the parallax calibration.
+    pub(in crate::map::editor::state) parallax_calibration_factor: f32,
+    /// Whether the animated textures of the things are animated in the edit view.
+    pub(in crate::map::editor::state) animate_things: bool,
+    /// Whether the arrows showing the links between the things are drawn, and links can be
+    /// created with the entity tool.
+    pub(in crate::map::editor::state) show_links: bool,
+    /// The bitfield of the subtools which remain active after use.
+    sticky_subtools: u32,
+    /// Whether the textures of the brushes duplicated through the keyboard continue the ones of
+    /// the originals rather than being moved along with them.
+    pub(in crate::map::editor::state) continue_duplicated_textures: bool,
+    /// The height at which the brushes of the imported Quake maps are sliced.
+    pub(in crate::map::editor::state) quake_slice_height: f32,
+    /// The thickness of the layers the imported Quake maps are cut into. If zero the brushes are
+    /// sliced only at [`Self::quake_slice_height`].
+    pub(in crate::map::editor::state) quake_layer_thickness: f32,
+    /// The [`Corner`] last selected in the scale tool.
+    pub(in crate::map::editor::state) scale_corner: Corner,
+    /// The [`Side`] last selected in the shear tool.
+    pub(in crate::map::editor::state) shear_side: Side,
+    /// The center of the selection and the pivot of the rotate tool when it was last disabled.
+    pub(in crate::map::editor::state) rotate_pivot: Option<(Vec2, Vec2)>
 }
 
 impl Default for ToolsSettings
@@ -407,19 +768,289 @@ impl Default for ToolsSettings
     fn default() -> Self
     {
         Self {
-            target_switch:          TargetSwitch::default(),
-            can_switch:             false,
-            circle_draw_resolution: 2,
-            rotate_angle:           RotateAngle::default(),
-            scroll_enabled:         true,
-            parallax_enabled:       true,
-            thing_pivot:            ThingPivot::default()
+            target_switch:                TargetSwitch::default(),
+            can_switch:                   false,
+            suspended_target_switch:      None,
+            circle_draw_resolution:       2,
+            corner_radius:                0f32,
+            corner_resolution:            4,
+            rotate_angle:                 RotateAngle::default(),
+            scroll_enabled:               true,
+            parallax_enabled:             true,
+            scroll_arrows:                false,
+            texture_grid:                 false,
+            thing_pivot:                  ThingPivot::default(),
+            paste_snap:                   PasteSnap::default(),
+            soft_selection:               false,
+            soft_selection_radius:        64f32,
+            soft_selection_other_brushes: false,
+            footprint_margin:             0f32,
+            merge_rule:                   MergeRule::default(),
+            adaptive_snap:                false,
+            adaptive_snap_pixels:         16,
+            path_snap:                    PathSnap::default(),
+            snap_to_paths:                false,
+            parallax_calibration:         false,
+            parallax_calibration_factor:  4f32,
+            animate_things:               false,
+            show_links:                   false,
+            sticky_subtools:              0,
+            continue_duplicated_textures: false,
+            quake_slice_height:           0f32,
+            quake_layer_thickness:        0f32,
+            scale_corner:                 Corner::TopLeft,
+            shear_side:                   Side::Top,
+            rotate_pivot:                 None
         }
     }
 }
 
 impl ToolsSettings
 {
+    /// Returns the granularity the cursor is snapped with. If the adaptive snap is enabled it is
+    /// the power of two closest to the adaptive snap pixels at the current `camera_scale`,
+    /// otherwise it is the grid size.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor) fn snap_size(&self, grid: &Grid, camera_scale: f32) -> i16
+    {
+        if !self.adaptive_snap
+        {
+            return grid.size();
+        }
+
+        let size = f32::from(self.adaptive_snap_pixels) * camera_scale;
+        2i16.pow(size.log2().round().clamp(1f32, 8f32) as u32)
+    }
+
+    /// Returns the granularity the path nodes are snapped with, or `None` if they are not
+    /// snapped. `cursor_snap` is whether the cursor snap is enabled.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor) fn path_snap_size(
+        &self,
+        grid: &Grid,
+        camera_scale: f32,
+        cursor_snap: bool
+    ) -> Option<i16>
+    {
+        match self.path_snap
+        {
+            PathSnap::Brushes => cursor_snap.then(|| self.snap_size(grid, camera_scale)),
+            PathSnap::Free => None,
+            PathSnap::Custom(size) => Some(size)
+        }
+    }
+
+    /// Returns the factor the camera position is multiplied by to compute the texture parallax.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) const fn parallax_factor(&self) -> f32
+    {
+        if self.parallax_calibration
+        {
+            self.parallax_calibration_factor
+        }
+        else
+        {
+            1f32
+        }
+    }
+
+    /// Whether `subtool` remains active after use.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn is_sticky(&self, subtool: SubTool) -> bool
+    {
+        self.sticky_subtools & (1 << subtool as u32) != 0
+    }
+
+    /// Sets whether `subtool` remains active after use.
+    #[inline]
+    pub(in crate::map::editor::state) fn set_sticky(&mut self, subtool: SubTool, value: bool)
+    {
+        if value
+        {
+            self.sticky_subtools |= 1 << subtool as u32;
+        }
+        else
+        {
+            self.sticky_subtools &= !(1 << subtool as u32);
+        }
+    }
+
+    /// Returns how the brushes of the imported Quake maps are sliced.
+    #[inline]
+    #[must_use]
+    fn quake_slice(&self) -> QuakeSlice
+    {
+        if self.quake_layer_thickness > 0f32
+        {
+            return QuakeSlice::Layers {
+                height:    self.quake_slice_height,
+                thickness: self.quake_layer_thickness
+            };
+        }
+
+        QuakeSlice::Height(self.quake_slice_height)
+    }
+
+    /// Returns the position of the path node or of the center of the entity with a path the
+    /// cursor at `pos` should be snapped to, if the snap to the paths is enabled and there is one
+    /// nearby.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor) fn path_snap_target(
+        &self,
+        manager: &EntitiesManager,
+        pos: Vec2,
+        camera_scale: f32
+    ) -> Option<Vec2>
+    {
+        if !self.snap_to_paths
+        {
+            return None;
+        }
+
+        manager.path_snap_target(pos, camera_scale)
+    }
+
+    /// Returns the ini keys and values of the settings stored in the presets.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn preset_values(&self) -> [(&'static str, String); 25]
+    {
+        [
+            ("circle_draw_resolution", self.circle_draw_resolution.to_string()),
+            ("corner_radius", self.corner_radius.to_string()),
+            ("corner_resolution", self.corner_resolution.to_string()),
+            (
+                "rotate_angle",
+                match self.rotate_angle
+                {
+                    RotateAngle::Free => 0,
+                    RotateAngle::Fixed(angle) => angle
+                }
+                .to_string()
+            ),
+            ("scroll_enabled", self.scroll_enabled.to_string()),
+            ("parallax_enabled", self.parallax_enabled.to_string()),
+            ("scroll_arrows", self.scroll_arrows.to_string()),
+            ("texture_grid", self.texture_grid.to_string()),
+            ("thing_pivot", self.thing_pivot.tag().to_owned()),
+            ("paste_snap", self.paste_snap.tag().to_owned()),
+            ("soft_selection", self.soft_selection.to_string()),
+            ("soft_selection_radius", self.soft_selection_radius.to_string()),
+            ("soft_selection_other_brushes", self.soft_selection_other_brushes.to_string()),
+            ("footprint_margin", self.footprint_margin.to_string()),
+            ("merge_rule", self.merge_rule.tag().to_owned()),
+            ("adaptive_snap", self.adaptive_snap.to_string()),
+            ("adaptive_snap_pixels", self.adaptive_snap_pixels.to_string()),
+            ("path_snap", match self.path_snap
+            {
+                PathSnap::Custom(size) => size.to_string(),
+                snap => snap.tag().to_owned()
+            }),
+            ("snap_to_paths", self.snap_to_paths.to_string()),
+            ("animate_things", self.animate_things.to_string()),
+            ("show_links", self.show_links.to_string()),
+            ("sticky_subtools", self.sticky_subtools.to_string()),
+            ("continue_duplicated_textures", self.continue_duplicated_textures.to_string()),
+            ("quake_slice_height", self.quake_slice_height.to_string()),
+            ("quake_layer_thickness", self.quake_layer_thickness.to_string())
+        ]
+    }
+
+    /// Sets the settings to the values stored in `preset`. The missing or invalid values are left
+    /// unchanged.
+    #[inline]
+    pub(in crate::map::editor::state) fn apply_preset(&mut self, preset: &ToolsPreset)
+    {
+        /// Returns the value associated with `key` parsed as `T`, if any.
+        #[inline]
+        #[must_use]
+        fn parse<T: std::str::FromStr>(preset: &ToolsPreset, key: &str) -> Option<T>
+        {
+            preset.get(key)?.parse().ok()
+        }
+
+        macro_rules! set {
+            ($($field:ident $(, $filter:expr)?);+) => { $(
+                if let Some(value) = parse(preset, stringify!($field))$(.filter($filter))?
+                {
+                    self.$field = value;
+                }
+            )+};
+        }
+
+        macro_rules! set_tag {
+            ($($field:ident, $t:ty);+) => { $(
+                if let Some(value) = preset
+                    .get(stringify!($field))
+                    .and_then(|tag| <$t>::iter().find(|value| value.tag() == tag))
+                {
+                    self.$field = value;
+                }
+            )+};
+        }
+
+        set!(
+            circle_draw_resolution, |resolution: &u8| {
+                CircleCursorPolygon::circle_resolution_range().contains(resolution)
+            };
+            corner_radius, |radius: &f32| radius.is_finite() && *radius >= 0f32;
+            corner_resolution, |resolution: &u8| (1..=16).contains(resolution);
+            scroll_enabled;
+            parallax_enabled;
+            scroll_arrows;
+            texture_grid;
+            soft_selection;
+            soft_selection_radius, |radius: &f32| radius.is_finite() && *radius > 0f32;
+            soft_selection_other_brushes;
+            footprint_margin, |margin: &f32| margin.is_finite() && *margin >= 0f32;
+            adaptive_snap;
+            adaptive_snap_pixels, |pixels: &u8| (4..=128).contains(pixels);
+            snap_to_paths;
+            animate_things;
+            show_links;
+            sticky_subtools;
+            continue_duplicated_textures;
+            quake_slice_height, |height: &f32| height.is_finite();
+            quake_layer_thickness, |thickness: &f32| thickness.is_finite() && *thickness >= 0f32
+        );
+
+        set_tag!(thing_pivot, ThingPivot; paste_snap, PasteSnap; merge_rule, MergeRule);
+
+        if let Some(angle) = parse::<u16>(preset, "rotate_angle")
+        {
+            if angle == 0
+            {
+                self.rotate_angle = RotateAngle::Free;
+            }
+            else if angle <= 90
+            {
+                self.rotate_angle = RotateAngle::Fixed(angle);
+            }
+        }
+
+        if let Some(snap) = preset.get("path_snap")
+        {
+            if let Some(snap) = [PathSnap::Brushes, PathSnap::Free]
+                .into_iter()
+                .find(|s| s.tag() == snap)
+                .or_else(|| {
+                    snap.parse::<i16>()
+                        .ok()
+                        .filter(|size| PathSnap::CUSTOM_RANGE.contains(size))
+                        .map(PathSnap::Custom)
+                })
+            {
+                self.path_snap = snap;
+            }
+        }
+    }
+
     /// Cycles the value of the [`TargetSwitch`], but only if the current tool has texture editing
     /// capabilities and there are no ongoing changes.
     #[inline]
@@ -444,7 +1075,9 @@ impl ToolsSettings
     fn update(&mut self, core: &Core, manager: &mut EntitiesManager)
     {
         let prev = self.target_switch;
-        self.can_switch = self.target_switch.update(core, manager);
+        self.can_switch =
+            self.target_switch
+                .update(&mut self.suspended_target_switch, core, manager);
 
         if prev != self.target_switch
         {
@@ -500,21 +1133,27 @@ impl ToolsSettings
 pub(in crate::map::editor) struct State
 {
     /// The core of the editor.
-    core:               Core,
+    core:                   Core,
     /// The retained settings of the tools.
-    tools_settings:     ToolsSettings,
+    tools_settings:         ToolsSettings,
     /// The UI of the editor.
-    ui:                 Ui,
+    ui:                     Ui,
     /// Whether tooltips should be shown (ex. coordinates of the vertexes).
-    show_tooltips:      bool,
+    show_tooltips:          bool,
     /// Whether the cursor should be snapped to the grid.
-    cursor_snap:        bool,
+    cursor_snap:            bool,
     /// Whether a grey semitransparent rectangle should be drawn on the map beneath the cursor.
-    show_cursor:        bool,
+    show_cursor:            bool,
     /// Whether the "clip" texture should be drawn on top of the brushes with collision enabled.
-    show_collision:     bool,
+    show_collision:         bool,
     /// Whether textures are currently being reloaded.
-    reloading_textures: bool
+    reloading_textures:     bool,
+    /// The time elapsed since the last update of the animations of the things in the edit view.
+    things_animation_timer: f32,
+    /// The autosave snapshots restored at startup that still need to be opened in their own tab.
+    snapshots:              Vec<PathBuf>,
+    /// The maps open in the tabs.
+    tabs:                   Tabs
 }
 
 impl Placeholder for State
@@ -523,14 +1162,17 @@ impl Placeholder for State
     unsafe fn placeholder() -> Self
     {
         Self {
-            core:               Core::default(),
-            tools_settings:     ToolsSettings::default(),
-            ui:                 Ui::placeholder(),
-            show_tooltips:      true,
-            cursor_snap:        true,
-            show_cursor:        true,
-            show_collision:     true,
-            reloading_textures: false
+            core:                   Core::default(),
+            tools_settings:         ToolsSettings::default(),
+            ui:                     Ui::placeholder(),
+            show_tooltips:          true,
+            cursor_snap:            true,
+            show_cursor:            true,
+            show_collision:         true,
+            reloading_textures:     false,
+            things_animation_timer: 0f32,
+            snapshots:              Vec::new(),
+            tabs:                   Tabs::default()
         }
     }
 }
@@ -550,6 +1192,7 @@ impl State
         drawing_resources: &DrawingResources,
         things_catalog: &ThingsCatalog,
         default_properties: &mut AllDefaultProperties,
+        manual_sections: &UserManualSections,
         file: Option<PathBuf>
     ) -> (
         Self,
@@ -562,40 +1205,62 @@ impl State
     )
     {
         /// The [`State`] to default to in case of errors in the file load or if there is no file to
-        /// load.
+        /// load. The start screen is shown so that a map can be picked.
         #[inline]
         fn default(
             asset_server: &AssetServer,
             user_textures: &mut EguiUserTextures,
             default_brush_properties: &DefaultBrushProperties,
-            default_thing_properties: &DefaultThingProperties
+            default_thing_properties: &DefaultThingProperties,
+            manual_sections: &UserManualSections
         ) -> State
         {
+            let mut ui = Ui::new(
+                asset_server,
+                user_textures,
+                default_brush_properties,
+                default_thing_properties,
+                manual_sections
+            );
+            ui.open_start_screen();
+
             State {
-                core:               Core::default(),
-                ui:                 Ui::new(
-                    asset_server,
-                    user_textures,
-                    default_brush_properties,
-                    default_thing_properties
-                ),
-                tools_settings:     ToolsSettings::default(),
-                show_tooltips:      true,
-                cursor_snap:        true,
-                show_cursor:        true,
-                show_collision:     true,
-                reloading_textures: false
+                core: Core::default(),
+                ui,
+                tools_settings: ToolsSettings::default(),
+                show_tooltips: true,
+                cursor_snap: true,
+                show_cursor: true,
+                show_collision: true,
+                reloading_textures: false,
+                things_animation_timer: 0f32,
+                snapshots: Vec::new(),
+                tabs: Tabs::default()
             }
         }
 
-        let file = return_if_none!(
-            file,
+        // Offer to restore the latest snapshots if the previous session did not end properly.
+        // The first one is opened here, the others in their own tab at the first update.
+        let mut snapshots = autosave::recover();
+        let (file, restored) = if snapshots.is_empty()
+        {
+            (file, false)
+        }
+        else
+        {
+            (Some(snapshots.remove(0)), true)
+        };
+        autosave::start_session();
+
+        let file = return_if_none!(
+            file,
             (
                 default(
                     asset_server,
                     user_textures,
                     default_properties.map_brushes,
-                    default_properties.map_things
+                    default_properties.map_things,
+                    manual_sections
                 ),
                 hash_map![],
                 EntitiesManager::new(),
@@ -606,7 +1271,7 @@ impl State
             )
         );
 
-        match Self::process_map_file(
+        let mut result = match Self::process_map_file(
             images,
             prop_cameras,
             user_textures,
@@ -617,25 +1282,43 @@ impl State
             default_properties.engine_things
         )
         {
-            Ok(file_read) =>
+            Ok(mut file_read) =>
             {
                 *default_properties.map_brushes = file_read.map_default_brush_properties;
                 *default_properties.map_things = file_read.map_default_thing_properties;
 
+                let mut edits_history = EditsHistory::default();
+                edits_history.load_statistics(file_read.statistics);
+
                 let state = Self {
-                    core:               Core::default(),
-                    ui:                 Ui::new(
+                    core:                   Core::default(),
+                    ui:                     Ui::new(
                         asset_server,
                         user_textures,
                         default_properties.map_brushes,
-                        default_properties.map_things
+                        default_properties.map_things,
+                        manual_sections
                     ),
-                    tools_settings:     ToolsSettings::default(),
-                    show_tooltips:      true,
-                    cursor_snap:        true,
-                    show_cursor:        true,
-                    show_collision:     true,
-                    reloading_textures: false
+                    tools_settings:         ToolsSettings::default(),
+                    show_tooltips:          true,
+                    cursor_snap:            true,
+                    show_cursor:            true,
+                    show_collision:         true,
+                    reloading_textures:     false,
+                    things_animation_timer: 0f32,
+                    snapshots:              Vec::new(),
+                    tabs:                   Tabs::default()
+                };
+
+                // A restored snapshot is not a map file, so it must be saved as a new one.
+                let path = if restored
+                {
+                    file_read.manager.set_loaded_file_modified();
+                    None
+                }
+                else
+                {
+                    file_read.path.into()
                 };
 
                 (
@@ -643,9 +1326,9 @@ impl State
                     file_read.animations,
                     file_read.manager,
                     file_read.clipboard,
-                    EditsHistory::default(),
+                    edits_history,
                     file_read.grid,
-                    file_read.path.into()
+                    path
                 )
             },
             Err(err) =>
@@ -657,7 +1340,8 @@ impl State
                         asset_server,
                         user_textures,
                         default_properties.map_brushes,
-                        default_properties.map_things
+                        default_properties.map_things,
+                        manual_sections
                     ),
                     hash_map![],
                     EntitiesManager::new(),
@@ -667,7 +1351,10 @@ impl State
                     None
                 )
             }
-        }
+        };
+
+        result.0.snapshots = snapshots;
+        result
     }
 
     //==============================================================
@@ -688,6 +1375,12 @@ impl State
     #[must_use]
     pub const fn map_preview(&self) -> bool { self.core.map_preview() }
 
+    /// Returns the content of the right half of the map area and the area of the window it takes,
+    /// if shown.
+    #[inline]
+    #[must_use]
+    pub fn split_view(&self) -> Option<(SplitView, egui::Rect)> { self.ui.split_view() }
+
     /// Whether the brushes collision overlay should be drawn.
     #[inline]
     #[must_use]
@@ -710,7 +1403,7 @@ impl State
                 return false;
             }
 
-            dialog_if_error!(self.new_file(bundle));
+            dialog_if_error!(self.new_file(bundle, Grid::default()));
             return true;
         }
 
@@ -753,7 +1446,7 @@ impl State
                 return false;
             }
 
-            Self::export(bundle);
+            self.export(bundle, None);
             return true;
         }
 
@@ -764,6 +1457,13 @@ impl State
             return true;
         }
 
+        if HardcodedActions::GoToEntity.pressed(bundle.key_inputs) &&
+            self.core.select_all_available()
+        {
+            self.ui.open_go_to_entity();
+            return true;
+        }
+
         if HardcodedActions::Undo.pressed(bundle.key_inputs)
         {
             if !self.core.undo_redo_available()
@@ -797,7 +1497,7 @@ impl State
         }
         else if bundle.inputs.paste_just_pressed()
         {
-            self.core.paste(bundle);
+            self.core.paste(bundle, self.tools_settings.paste_snap);
         }
         else if bundle.inputs.cut_just_pressed()
         {
@@ -872,10 +1572,10 @@ impl State
         }
     }
 
-    /// Creates a new file, initiates save procedure if the map currently being edited has unsaved
-    /// edits.
+    /// Creates a new file with `grid`, initiates save procedure if the map currently being edited
+    /// has unsaved edits.
     #[inline]
-    fn new_file(&mut self, bundle: &mut StateUpdateBundle) -> Result<(), &'static str>
+    fn new_file(&mut self, bundle: &mut StateUpdateBundle, grid: Grid) -> Result<(), &'static str>
     {
         if !Self::save_unsaved_changes(
             bundle.window,
@@ -893,10 +1593,12 @@ impl State
 
         self.core = Core::default();
         *bundle.manager = EntitiesManager::new();
-        *bundle.clipboard = Clipboard::new();
+        bundle
+            .clipboard
+            .replace_map_props(bundle.prop_cameras, Clipboard::new());
         *bundle.edits_history = EditsHistory::default();
         *bundle.inputs = InputsPresses::default();
-        *bundle.grid = Grid::default();
+        *bundle.grid = grid;
         bundle.config.open_file.clear(bundle.window);
 
         Ok(())
@@ -921,6 +1623,7 @@ impl State
             bundle.grid,
             &mut self.ui
         );
+        self.rename_texture_references(bundle);
     }
 
     /// Executes the redo procedure.
@@ -939,6 +1642,21 @@ impl State
             bundle.grid,
             &mut self.ui
         );
+        self.rename_texture_references(bundle);
+    }
+
+    /// Replaces the textures renamed since the last call in the references to them that are not
+    /// part of the map being edited.
+    #[inline]
+    fn rename_texture_references(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        for (prev, new) in self.ui.take_renamed_textures()
+        {
+            bundle.clipboard.rename_texture(&prev, &new);
+            bundle.things_catalog.rename_texture(&prev, &new);
+            bundle.config.favorite_textures.rename(&prev, &new);
+            self.tabs.rename_texture(bundle.drawing_resources, &prev, &new);
+        }
     }
 
     //==============================================================
@@ -959,6 +1677,7 @@ impl State
             !clipboard.props_changed() &&
             !drawing_resources.default_animations_changed() &&
             !manager.loaded_file_modified() &&
+            !manager.metadata_changed() &&
             !grid.changed()
     }
 
@@ -1050,6 +1769,124 @@ impl State
             return Ok(());
         }
 
+        let data = Self::map_file_data(
+            &MapHeader {
+                brushes:     manager.brushes_amount(),
+                things:      manager.things_amount(),
+                animations:  drawing_resources.animations_amount(),
+                props:       clipboard.props_amount(),
+                quick_props: clipboard.quick_props_amount()
+            },
+            default_properties,
+            drawing_resources,
+            clipboard,
+            grid,
+            manager.metadata(),
+            manager.recent_textures(),
+            &edits_history.statistics().total(),
+            manager.brushes().iter().cloned(),
+            manager.things().cloned()
+        )?;
+
+        let mut file = OpenOptions::new();
+        let mut file = file.write(true);
+
+        let path = match &target
+        {
+            SaveTarget::None => unreachable!(),
+            SaveTarget::New(path) =>
+            {
+                file = file.create(true);
+                path
+            },
+            SaveTarget::Opened => config.open_file.path().unwrap()
+        };
+
+        let file = match file.open(path)
+        {
+            Ok(file) => file,
+            Err(_) =>
+            {
+                if target.is_new()
+                {
+                    _ = std::fs::remove_file(path);
+                }
+
+                return Err("Error opening file.");
+            }
+        };
+
+        test_writer!(BufWriter::new(file).write_all(&data), "Error writing file.");
+
+        edits_history.reset_last_save_edit(path.clone());
+
+        if target.is_new()
+        {
+            config.open_file.update(path.clone(), window);
+        }
+
+        clipboard.reset_props_changed();
+        manager.reset_loaded_file_modified();
+        manager.reset_metadata_changed();
+        drawing_resources.reset_default_animation_changed();
+        grid.reset_changed();
+
+        Ok(())
+    }
+
+    /// Returns the content of the map file of the map being edited, if there are unsaved changes.
+    #[inline]
+    #[must_use]
+    fn snapshot_data(bundle: &StateUpdateBundle) -> Option<Vec<u8>>
+    {
+        if Self::no_edits(
+            bundle.drawing_resources,
+            bundle.manager,
+            bundle.clipboard,
+            bundle.edits_history,
+            bundle.grid
+        )
+        {
+            return None;
+        }
+
+        Self::map_file_data(
+            &MapHeader {
+                brushes:     bundle.manager.brushes_amount(),
+                things:      bundle.manager.things_amount(),
+                animations:  bundle.drawing_resources.animations_amount(),
+                props:       bundle.clipboard.props_amount(),
+                quick_props: bundle.clipboard.quick_props_amount()
+            },
+            bundle.default_properties,
+            bundle.drawing_resources,
+            bundle.clipboard,
+            bundle.grid,
+            bundle.manager.metadata(),
+            bundle.manager.recent_textures(),
+            &bundle.edits_history.statistics().total(),
+            bundle.manager.brushes().iter().cloned(),
+            bundle.manager.things().cloned()
+        )
+        .ok()
+    }
+
+    /// Returns the content of a map file storing `brushes` and `things`, described by `header`.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    fn map_file_data(
+        header: &MapHeader,
+        default_properties: &AllDefaultProperties,
+        drawing_resources: &DrawingResources,
+        clipboard: &Clipboard,
+        grid: &Grid,
+        metadata: &MapMetadata,
+        recent_textures: &[String],
+        statistics: &MapStatistics,
+        mut brushes: impl Iterator<Item = Brush>,
+        mut things: impl Iterator<Item = ThingInstance>
+    ) -> Result<Vec<u8>, &'static str>
+    {
         let mut data = Vec::new();
         let mut writer = BufWriter::new(&mut data);
 
@@ -1063,16 +1900,7 @@ impl State
                 },
                 FileStructure::Header =>
                 {
-                    test_writer!(
-                        &MapHeader {
-                            brushes:    manager.brushes_amount(),
-                            things:     manager.things_amount(),
-                            animations: drawing_resources.animations_amount(),
-                            props:      clipboard.props_amount()
-                        },
-                        &mut writer,
-                        "Error saving file header"
-                    );
+                    test_writer!(header, &mut writer, "Error saving file header");
                 },
                 FileStructure::Grid =>
                 {
@@ -1097,74 +1925,52 @@ impl State
                 },
                 FileStructure::Brushes =>
                 {
-                    for brush in manager.brushes().iter()
+                    for brush in brushes.by_ref()
                     {
-                        test_writer!(
-                            &brush.clone().to_viewer(),
-                            &mut writer,
-                            "Error saving brushes."
-                        );
+                        test_writer!(&brush.to_viewer(), &mut writer, "Error saving brushes.");
                     }
                 },
                 FileStructure::Things =>
                 {
-                    for thing in manager.things()
+                    for thing in things.by_ref()
                     {
-                        test_writer!(
-                            &thing.clone().to_viewer(),
-                            &mut writer,
-                            "Error saving things."
-                        );
+                        test_writer!(&thing.to_viewer(), &mut writer, "Error saving things.");
                     }
                 },
-                FileStructure::Props => clipboard.export_props(&mut writer)?
-            }
-        }
-
-        drop(writer);
-
-        let mut file = OpenOptions::new();
-        let mut file = file.write(true);
-
-        let path = match &target
-        {
-            SaveTarget::None => unreachable!(),
-            SaveTarget::New(path) =>
-            {
-                file = file.create(true);
-                path
-            },
-            SaveTarget::Opened => config.open_file.path().unwrap()
-        };
+                FileStructure::Metadata =>
+                {
+                    test_writer!(metadata, &mut writer, "Error saving map metadata.");
+                },
+                FileStructure::Props =>
+                {
+                    if header.props != 0
+                    {
+                        clipboard.export_props(&mut writer)?;
+                    }
 
-        let file = match file.open(path)
-        {
-            Ok(file) => file,
-            Err(_) =>
-            {
-                if target.is_new()
+                    if header.quick_props != 0
+                    {
+                        clipboard.export_quick_props(&mut writer)?;
+                    }
+                },
+                FileStructure::RecentTextures =>
                 {
-                    _ = std::fs::remove_file(path);
+                    test_writer!(
+                        recent_textures,
+                        &mut writer,
+                        "Error saving recently used textures."
+                    );
+                },
+                FileStructure::Statistics =>
+                {
+                    test_writer!(statistics, &mut writer, "Error saving map statistics.");
                 }
-
-                return Err("Error opening file.");
             }
-        };
-
-        test_writer!(BufWriter::new(file).write_all(&data), "Error writing file.");
-
-        if target.is_new()
-        {
-            config.open_file.update(path.clone(), window);
         }
 
-        edits_history.reset_last_save_edit();
-        clipboard.reset_props_changed();
-        manager.reset_loaded_file_modified();
-        drawing_resources.reset_default_animation_changed();
-        grid.reset_changed();
+        drop(writer);
 
-        Ok(())
+        Ok(data)
     }
 
     //==============================================================
@@ -1410,8 +2216,9 @@ impl State
 
         let mut file = match version_number
         {
-            // PREVIOUS_FILE_VERSION => convert(version_number, &mut path, reader, convert_09)?,
-            FILE_VERSION => reader,
+            // The previous version lacks the metadata, the quick props, the recent textures, and
+            // the statistics, which are read as their defaults. It is upgraded on save.
+            FILE_VERSION | PREVIOUS_FILE_VERSION => reader,
             _ => return Err(UPGRADE_WARNING)
         };
 
@@ -1433,7 +2240,8 @@ impl State
             animations
         };
 
-        let (manager, map_default_brush_properties, map_default_thing_properties) =
+        let mut report = IntegrityReport::default();
+        let (mut manager, map_default_brush_properties, map_default_thing_properties) =
             EntitiesManager::from_file(
                 &header,
                 &mut file,
@@ -1442,22 +2250,62 @@ impl State
                 &grid,
                 engine_default_brush_properties,
                 engine_default_thing_properties,
-                &mut steps
+                &mut steps,
+                &mut report
             )?;
 
+        // The sections following a truncation are replaced by their defaults.
+        steps.next_value().assert(FileStructure::Metadata);
+
+        if !report.truncated()
+        {
+            match MapMetadata::from_file(&mut file, version_number)
+            {
+                Ok(metadata) => manager.load_metadata(metadata),
+                Err(_) => report.set_truncated()
+            };
+        }
+
         steps.next_value().assert(FileStructure::Props);
-        let mut clipboard = Clipboard::from_file(
-            images,
-            prop_cameras,
-            user_textures,
-            &drawing_resources,
-            things_catalog,
-            &grid,
-            &header,
-            &mut file
-        )?;
+
+        let mut clipboard = if report.truncated()
+        {
+            Clipboard::new()
+        }
+        else
+        {
+            Clipboard::from_file(
+                images,
+                prop_cameras,
+                user_textures,
+                &drawing_resources,
+                things_catalog,
+                &grid,
+                &header,
+                &mut file
+            )
+            .unwrap_or_else(|_| {
+                report.set_truncated();
+                Clipboard::new()
+            })
+        };
         clipboard.reset_props_changed();
 
+        report.warn_damage();
+
+        if report.truncated()
+        {
+            manager.set_loaded_file_modified();
+        }
+
+        // Files saved before the recently used textures were stored simply end here.
+        steps.next_value().assert(FileStructure::RecentTextures);
+        manager.load_recent_textures(ciborium::from_reader(&mut file).unwrap_or_default());
+
+        // Same for the statistics.
+        steps.next_value().assert(FileStructure::Statistics);
+        let statistics = ciborium::from_reader(&mut file).unwrap_or_default();
+
         Ok(FileRead {
             animations: drawing_resources.animations,
             manager,
@@ -1465,6 +2313,7 @@ impl State
             map_default_thing_properties,
             clipboard,
             grid,
+            statistics,
             path
         })
     }
@@ -1503,7 +2352,83 @@ impl State
         }
 
         let file_to_open = return_if_none!(Self::open_file("Open", HV_FILTER_NAME, FILE_EXTENSION));
+        self.open_path(bundle, file_to_open, false);
+    }
+
+    /// Opens the recently opened map file at `index`, unless the file cannot be properly read. If
+    /// there are unsaved changes in the currently open map the save procedure is initiated.
+    #[inline]
+    fn open_recent(&mut self, bundle: &mut StateUpdateBundle, index: usize)
+    {
+        let file_to_open = return_if_none!(bundle.config.open_file.recent_at(index)).clone();
+
+        if !file_to_open.exists()
+        {
+            bundle.config.open_file.remove_recent(&file_to_open);
+            error_message("The file does not exist anymore.");
+            return;
+        }
 
+        if !dialog_if_error!(
+            ret;
+            Self::save_unsaved_changes(
+                bundle.window,
+                bundle.config,
+                bundle.default_properties,
+                bundle.drawing_resources,
+                bundle.manager,
+                bundle.clipboard,
+                bundle.edits_history,
+                bundle.grid
+            )
+        )
+        {
+            return;
+        }
+
+        self.open_path(bundle, file_to_open, false);
+    }
+
+    /// Creates a new map with the content of the template map file at `index`, unless the file
+    /// cannot be properly read. If there are unsaved changes in the currently open map the save
+    /// procedure is initiated.
+    #[inline]
+    fn new_from_template(&mut self, bundle: &mut StateUpdateBundle, index: usize)
+    {
+        let template = return_if_none!(self.ui.template(index)).clone();
+
+        if !dialog_if_error!(
+            ret;
+            Self::save_unsaved_changes(
+                bundle.window,
+                bundle.config,
+                bundle.default_properties,
+                bundle.drawing_resources,
+                bundle.manager,
+                bundle.clipboard,
+                bundle.edits_history,
+                bundle.grid
+            )
+        )
+        {
+            return;
+        }
+
+        self.open_path(bundle, template, true);
+    }
+
+    /// Opens the map file at `file_to_open`, unless the file cannot be properly read.
+    /// If `template` is true the map is treated as a new unsaved one, so that the file is not
+    /// overwritten on save.
+    /// Returns whether the file was opened.
+    #[inline]
+    fn open_path(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        file_to_open: PathBuf,
+        template: bool
+    ) -> bool
+    {
         match Self::process_map_file(
             bundle.images,
             bundle.prop_cameras,
@@ -1522,66 +2447,557 @@ impl State
                 map_default_thing_properties,
                 clipboard,
                 grid,
+                statistics,
                 path
             }) =>
             {
                 bundle.drawing_resources.replace_animations(animations);
                 *bundle.manager = manager;
-                *bundle.clipboard = clipboard;
+                bundle.clipboard.replace_map_props(bundle.prop_cameras, clipboard);
                 *bundle.grid = grid;
                 *bundle.inputs = InputsPresses::default();
                 *bundle.edits_history = EditsHistory::default();
-                bundle.config.open_file.update(path, bundle.window);
                 *bundle.default_properties.map_brushes = map_default_brush_properties;
                 *bundle.default_properties.map_things = map_default_thing_properties;
 
+                if template
+                {
+                    bundle.config.open_file.clear(bundle.window);
+                }
+                else
+                {
+                    bundle.edits_history.load_statistics(statistics);
+                    bundle.config.open_file.update(path, bundle.window);
+                }
+
                 self.ui.regenerate_properties_window(
                     bundle.default_properties.map_brushes,
                     bundle.default_properties.map_things
                 );
                 self.core = Core::default();
+
+                let missing = bundle.manager.missing_textures(bundle.drawing_resources);
+
+                if !missing.is_empty()
+                {
+                    self.ui.open_missing_textures(missing);
+                }
+
+                true
             },
-            Err(err) => error_message(err)
-        };
+            Err(err) =>
+            {
+                error_message(err);
+                false
+            }
+        }
+    }
+
+    //==============================================================
+    // Tabs
+
+    /// Resets the state of the editor after the map being edited was switched with the one of
+    /// another tab.
+    #[inline]
+    fn map_switched(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        self.core = Core::default();
+        *bundle.inputs = InputsPresses::default();
+        self.ui.regenerate_properties_window(
+            bundle.default_properties.map_brushes,
+            bundle.default_properties.map_things
+        );
+    }
+
+    /// Writes the autosave snapshot of the map being edited before it is stored in its tab, so
+    /// that its latest changes are not lost.
+    #[inline]
+    fn flush_autosave(&mut self, bundle: &StateUpdateBundle)
+    {
+        self.tabs.flush_autosave(
+            bundle.config.map_autosave_interval,
+            bundle.config.open_file.path(),
+            || Self::snapshot_data(bundle)
+        );
+    }
+
+    /// Opens a new tab with an empty map.
+    #[inline]
+    fn new_tab(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        self.flush_autosave(bundle);
+        self.tabs.open_new(edited_map!(bundle));
+        self.map_switched(bundle);
+    }
+
+    /// Opens a map file in a new tab. The tab is closed if the file cannot be properly read.
+    #[inline]
+    fn open_in_new_tab(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let file_to_open = return_if_none!(Self::open_file("Open", HV_FILTER_NAME, FILE_EXTENSION));
+        self.new_tab(bundle);
+
+        if !self.open_path(bundle, file_to_open, false)
+        {
+            self.close_tab(bundle, self.tabs.active());
+        }
+    }
+
+    /// Opens each one of the autosave snapshots restored at startup, except the first one, in a
+    /// new tab. The tab is closed if the snapshot cannot be properly read.
+    #[inline]
+    fn open_restored_snapshots(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        for snapshot in std::mem::take(&mut self.snapshots)
+        {
+            self.new_tab(bundle);
+
+            // A restored snapshot is not a map file, so it must be saved as a new one.
+            if self.open_path(bundle, snapshot, true)
+            {
+                bundle.manager.set_loaded_file_modified();
+            }
+            else
+            {
+                self.close_tab(bundle, self.tabs.active());
+            }
+        }
+    }
+
+    /// Switches to the map of the tab at `index`.
+    #[inline]
+    fn select_tab(&mut self, bundle: &mut StateUpdateBundle, index: usize)
+    {
+        if index != self.tabs.active()
+        {
+            self.flush_autosave(bundle);
+        }
+
+        if self.tabs.select(edited_map!(bundle), index)
+        {
+            self.map_switched(bundle);
+        }
     }
 
-    //==============================================================
-    // Export
+    /// Closes the tab at `index`, unless it is the only one. If there are unsaved changes in its
+    /// map the save procedure is initiated.
+    #[inline]
+    fn close_tab(&mut self, bundle: &mut StateUpdateBundle, index: usize)
+    {
+        if self.tabs.len() == 1
+        {
+            return;
+        }
+
+        self.select_tab(bundle, index);
+
+        if !dialog_if_error!(
+            ret;
+            Self::save_unsaved_changes(
+                bundle.window,
+                bundle.config,
+                bundle.default_properties,
+                bundle.drawing_resources,
+                bundle.manager,
+                bundle.clipboard,
+                bundle.edits_history,
+                bundle.grid
+            )
+        )
+        {
+            return;
+        }
+
+        let map_props = return_if_none!(self.tabs.close_active(edited_map!(bundle)));
+        Clipboard::discard_map_props(bundle.prop_cameras, map_props);
+        self.map_switched(bundle);
+    }
+
+    //==============================================================
+    // Export
+
+    /// Initiates the map export procedure with the exporter profile at index `profile`, or the
+    /// selected one if `None`, and writes the [`ExportManifest`] of the map next to it.
+    /// If there are unsaved changes in the currently open map the save procedure is initiated.
+    /// The export is aborted if the map checks find errors, and requires confirmation if they
+    /// find warnings.
+    #[inline]
+    fn export(&mut self, bundle: &mut StateUpdateBundle, profile: Option<usize>)
+    {
+        if let Some(index) = profile
+        {
+            bundle.config.exporters.select(index);
+        }
+
+        if !dialog_if_error!(ret; Self::save_unsaved_changes(
+            bundle.window,
+            bundle.config,
+            bundle.default_properties,
+            bundle.drawing_resources,
+            bundle.manager,
+            bundle.clipboard,
+            bundle.edits_history,
+            bundle.grid
+        ))
+        {
+            return;
+        }
+
+        let file = return_if_none!(bundle.config.open_file.path());
+        let exporter = return_if_none!(bundle.config.exporters.selected());
+
+        if !exporter.valid()
+        {
+            error_message("Exporter executable does not exist.");
+            return;
+        }
+
+        let report = Self::validation_report(bundle);
+
+        if !report.is_empty()
+        {
+            let errors = report.count(Severity::Error);
+
+            let proceed = if errors != 0
+            {
+                error_message(&format!("Export aborted, the map has {errors} error(s)."));
+                false
+            }
+            else
+            {
+                matches!(
+                    rfd::MessageDialog::new()
+                        .set_title("Export")
+                        .set_description(format!(
+                            "The map has {} warning(s), export anyway?",
+                            report.count(Severity::Warning)
+                        ))
+                        .set_buttons(rfd::MessageButtons::YesNo)
+                        .show(),
+                    rfd::MessageDialogResult::Yes
+                )
+            };
+
+            if !proceed
+            {
+                self.ui.open_validation(report);
+                return;
+            }
+        }
+
+        if let Err(err) = exporter.run(file)
+        {
+            error_message(&err);
+            return;
+        }
+
+        dialog_if_error!(ExportManifest::new(file).and_then(|manifest| manifest.save(file)));
+    }
+
+    /// Saves the map, if there are unsaved changes, and returns the [`Exporter`] of the saved
+    /// file, if any.
+    #[inline]
+    fn saved_map_exporter(bundle: &mut StateUpdateBundle) -> Option<Exporter>
+    {
+        if !dialog_if_error!(default; Self::save_unsaved_changes(
+            bundle.window,
+            bundle.config,
+            bundle.default_properties,
+            bundle.drawing_resources,
+            bundle.manager,
+            bundle.clipboard,
+            bundle.edits_history,
+            bundle.grid
+        ), None)
+        {
+            return None;
+        }
+
+        let file = bundle.config.open_file.path()?;
+        Some(dialog_if_error!(default; Exporter::new(file), None))
+    }
+
+    /// Saves the map, if there are unsaved changes, and writes its brushes triangulated into
+    /// vertex and index buffers to a [`TessellatedMap`] file.
+    #[inline]
+    fn export_meshes(bundle: &mut StateUpdateBundle)
+    {
+        let exporter = return_if_none!(Self::saved_map_exporter(bundle));
+        let path = check_path_extension(
+            return_if_none!(Self::save_file("Export meshes", MESHES_FILTER_NAME, MESHES_EXTENSION)),
+            MESHES_EXTENSION
+        );
+
+        dialog_if_error!(TessellatedMap::new(&exporter).save(path));
+    }
+
+    /// Saves the map, if there are unsaved changes, and exports it as a glTF scene.
+    #[inline]
+    fn export_gltf(bundle: &mut StateUpdateBundle)
+    {
+        let exporter = return_if_none!(Self::saved_map_exporter(bundle));
+        let path = check_path_extension(
+            return_if_none!(Self::save_file("Export glTF", GLTF_FILTER_NAME, GLTF_EXTENSION)),
+            GLTF_EXTENSION
+        );
+
+        dialog_if_error!(exporter.save_gltf(path));
+    }
+
+    /// Saves the map, if there are unsaved changes, and writes it to a Tiled TMX file, with the
+    /// loaded textures used by the map stored in its tileset.
+    #[inline]
+    fn export_tmx(bundle: &mut StateUpdateBundle)
+    {
+        let exporter = return_if_none!(Self::saved_map_exporter(bundle));
+        let path = check_path_extension(
+            return_if_none!(Self::save_file("Export TMX", TMX_FILTER_NAME, TMX_EXTENSION)),
+            TMX_EXTENSION
+        );
+
+        let textures = TextureLoader::texture_paths()
+            .into_iter()
+            .filter_map(|(name, source)| {
+                let size = bundle.drawing_resources.texture(&name)?.size();
+                Some(TmxTexture { name, source, size })
+            })
+            .collect::<Vec<_>>();
+
+        dialog_if_error!(exporter.save_tmx(
+            path,
+            bundle.grid.size().unsigned_abs(),
+            &textures,
+            |thing| Some(bundle.things_catalog.texture(thing))
+        ));
+    }
+
+    /// Imports the brushes of a Quake map file, sliced as set in the tools settings, and
+    /// selects them. If some of their textures are not loaded the window to remap them is opened.
+    #[inline]
+    fn import_quake_map(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        let path = return_if_none!(Self::open_file(
+            "Import Quake map",
+            QUAKE_MAP_FILTER_NAME,
+            QUAKE_MAP_EXTENSION
+        ));
+        let (brushes, skipped) =
+            dialog_if_error!(ret; read_quake_map(&path, self.tools_settings.quake_slice()));
+
+        if skipped != 0
+        {
+            warning_message(&format!(
+                "{skipped} brushes could not be imported because they are not supported, invalid, \
+                 or out of the map bounds."
+            ));
+        }
+
+        if brushes.is_empty()
+        {
+            return;
+        }
+
+        bundle.edits_history.with_group("Quake Map Import", |edits_history| {
+            bundle.manager.deselect_selected_entities(edits_history);
+
+            for brush in brushes
+            {
+                _ = bundle.manager.spawn_brush(
+                    bundle.drawing_resources,
+                    edits_history,
+                    bundle.grid,
+                    ConvexPolygon::from((brush.vertexes, Some(&brush.texture))),
+                    bundle.default_properties.map_brushes.instance()
+                );
+            }
+        });
+
+        let missing = bundle.manager.missing_textures(bundle.drawing_resources);
+
+        if !missing.is_empty()
+        {
+            self.ui.open_missing_textures(missing);
+        }
+    }
+
+    /// Returns the [`ValidationReport`] of the map.
+    #[inline]
+    fn validation_report(bundle: &StateUpdateBundle) -> ValidationReport
+    {
+        ValidationReport::new(
+            bundle.manager,
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.default_properties,
+            bundle.property_schemas,
+            bundle.config.exporters.check_overlaps()
+        )
+    }
+
+    /// Runs the map checks and lists the problems found.
+    #[inline]
+    fn check_map(&mut self, bundle: &StateUpdateBundle)
+    {
+        self.ui.open_validation(Self::validation_report(bundle));
+    }
+
+    /// Saves the selected entities, and the brushes attached to the selected ones, to a standalone
+    /// map file. The user is asked whether the entities should be moved so that the top left
+    /// corner of their bounding box is the origin of the new map. If an exporter is selected the
+    /// user is asked whether the selection should be exported in its format instead, in which
+    /// case the exporter is run on the map file, which is then removed.
+    #[inline]
+    fn export_selection(bundle: &mut StateUpdateBundle) -> Result<(), &'static str>
+    {
+        if !bundle.manager.any_selected_entities()
+        {
+            return Ok(());
+        }
+
+        let exporter = match bundle.config.exporters.selected()
+        {
+            Some(exporter) =>
+            {
+                match rfd::MessageDialog::new()
+                    .set_title("Export selection")
+                    .set_description(format!(
+                        "Export the selection in the format of the exporter \"{}\"? Choosing \
+                         \"No\" only saves a standalone map file.",
+                        exporter.name
+                    ))
+                    .set_buttons(rfd::MessageButtons::YesNoCancel)
+                    .show()
+                {
+                    rfd::MessageDialogResult::Yes => exporter.clone().into(),
+                    rfd::MessageDialogResult::No => None,
+                    _ => return Ok(())
+                }
+            },
+            None => None
+        };
+
+        if exporter.as_ref().is_some_and(|exporter| !exporter.valid())
+        {
+            error_message("Exporter executable does not exist.");
+            return Ok(());
+        }
+
+        let path = check_path_extension(
+            return_if_none!(
+                Self::save_file("Export selection", HV_FILTER_NAME, FILE_EXTENSION),
+                Ok(())
+            ),
+            FILE_EXTENSION
+        );
+
+        let mut ids = hash_set![];
+
+        for brush in bundle.manager.selected_brushes()
+        {
+            ids.insert(brush.id());
+
+            if let Some(attachments) = brush.attachments_iter()
+            {
+                ids.extend(attachments.copied());
+            }
+        }
+
+        let mut brushes = ids
+            .iter()
+            .map(|id| {
+                let mut brush = bundle.manager.brush(*id).clone();
+
+                // Detach the brushes whose owner is not exported.
+                if let Some(owner) = brush.attached()
+                {
+                    if !ids.contains(&owner)
+                    {
+                        brush.detach();
+                    }
+                }
+
+                brush
+            })
+            .collect::<Vec<_>>();
+        let mut things = bundle.manager.selected_things().cloned().collect::<Vec<_>>();
+
+        if matches!(
+            rfd::MessageDialog::new()
+                .set_title("Export selection")
+                .set_description(
+                    "Move the exported entities so that the top left corner of their bounding box \
+                     is the origin?"
+                )
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .show(),
+            rfd::MessageDialogResult::Yes
+        )
+        {
+            let delta = -Hull::from_hulls_iter(
+                brushes
+                    .iter()
+                    .map(|brush| brush.hull(bundle.drawing_resources, bundle.grid))
+                    .chain(things.iter().map(|thing| thing.hull(bundle.things_catalog)))
+            )
+            .unwrap()
+            .top_left();
+
+            if brushes
+                .iter()
+                .any(|brush| !brush.check_move(bundle.drawing_resources, bundle.grid, delta, true)) ||
+                things
+                    .iter()
+                    .any(|thing| !thing.check_move(bundle.things_catalog, delta))
+            {
+                return Err("The selection is too large to be moved to the origin.");
+            }
+
+            for brush in &mut brushes
+            {
+                brush.move_by_delta(delta, true);
+            }
 
-    /// Initiates the map export procedure if an exporter executable is specified.
-    /// If there are unsaved changes in the currently open map the save procedure is initiated.
-    #[inline]
-    fn export(bundle: &mut StateUpdateBundle)
-    {
-        if !dialog_if_error!(ret; Self::save_unsaved_changes(
-            bundle.window,
-            bundle.config,
+            for thing in &mut things
+            {
+                thing.move_by_delta(delta);
+            }
+        }
+
+        let data = Self::map_file_data(
+            &MapHeader {
+                brushes:     brushes.len(),
+                things:      things.len(),
+                animations:  bundle.drawing_resources.animations_amount(),
+                props:       0,
+                quick_props: 0
+            },
             bundle.default_properties,
             bundle.drawing_resources,
-            bundle.manager,
             bundle.clipboard,
-            bundle.edits_history,
-            bundle.grid
-        ))
-        {
-            return;
-        }
+            bundle.grid,
+            bundle.manager.metadata(),
+            &[],
+            &MapStatistics::default(),
+            brushes.into_iter(),
+            things.into_iter()
+        )?;
 
-        let file = return_if_none!(bundle.config.open_file.path());
-        let exporter = return_if_none!(bundle.config.exporter.as_ref());
+        test_writer!(std::fs::write(&path, data), "Error writing file.");
 
-        if !exporter.exists() || !exporter.is_executable()
+        let exporter = return_if_none!(exporter, Ok(()));
+        let result = match exporter.run(&path)
         {
-            error_message("Exporter executable does not exist.");
-            bundle.config.exporter = None;
-            return;
-        }
+            Ok(()) => ExportManifest::new(&path).and_then(|manifest| manifest.save(&path)),
+            Err(err) =>
+            {
+                error_message(&err);
+                Ok(())
+            }
+        };
 
-        dialog_if_error!(
-            map;
-            std::process::Command::new(exporter).arg(file).output(),
-            "Error exporting map"
-        );
+        // The map file is only the input of the exporter.
+        _ = std::fs::remove_file(&path);
+        result
     }
 
     //==============================================================
@@ -1594,6 +3010,44 @@ impl State
         self.core.select_all(bundle, &self.tools_settings);
     }
 
+    //==============================================================
+    // Go to entity
+
+    /// Exclusively selects the entity with [`Id`] `identifier` and frames the camera on it.
+    #[inline]
+    fn go_to_entity(
+        &mut self,
+        bundle: &mut StateUpdateBundle,
+        tool_change_conditions: &ChangeConditions,
+        identifier: Id
+    )
+    {
+        if !bundle.manager.entity_exists(identifier) || !self.core.select_all_available()
+        {
+            return;
+        }
+
+        self.core.go_to_entity(
+            bundle,
+            &mut self.tools_settings,
+            tool_change_conditions,
+            identifier
+        );
+
+        let hull = bundle.manager.entity_hull(
+            identifier,
+            bundle.drawing_resources,
+            bundle.things_catalog,
+            bundle.grid
+        );
+        bundle.camera.scale_viewport_to_hull(
+            bundle.window,
+            bundle.grid,
+            &hull,
+            bundle.grid.size_f32()
+        );
+    }
+
     //==============================================================
     // Copy/Paste
 
@@ -1606,33 +3060,76 @@ impl State
     #[inline]
     fn duplicate(&mut self, bundle: &mut StateUpdateBundle)
     {
-        self.core.duplicate(bundle, Vec2::new(bundle.grid.size_f32(), 0f32));
+        self.core.duplicate(
+            bundle,
+            Vec2::new(bundle.grid.size_f32(), 0f32),
+            self.tools_settings.paste_snap,
+            !self.tools_settings.continue_duplicated_textures
+        );
     }
 
     //==============================================================
     // Update
 
+    /// Updates the animations of the textures of the things drawn in the edit view, at a reduced
+    /// rate. The map preview animates the things on its own.
+    #[inline]
+    fn update_things_animators(&mut self, bundle: &mut StateUpdateBundle)
+    {
+        /// The time between two updates of the animations.
+        const INTERVAL: f32 = 0.1;
+
+        if !self.tools_settings.animate_things || self.map_preview()
+        {
+            self.things_animation_timer = INTERVAL;
+            bundle.drawing_resources.clear_things_animators();
+            return;
+        }
+
+        self.things_animation_timer += bundle.delta_time;
+
+        if self.things_animation_timer < INTERVAL
+        {
+            return;
+        }
+
+        self.things_animation_timer = 0f32;
+        bundle.drawing_resources.update_things_animators(
+            bundle
+                .manager
+                .things()
+                .map(|thing| bundle.things_catalog.texture(thing.thing_id())),
+            bundle.elapsed_time
+        );
+    }
+
     /// Updates `self`.
     #[inline]
     #[must_use]
     pub fn update(&mut self, bundle: &mut StateUpdateBundle) -> bool
     {
         if HardcodedActions::Quit.pressed(bundle.key_inputs) &&
-            Self::quit(
-                bundle.window,
-                bundle.config,
-                bundle.default_properties,
-                bundle.drawing_resources,
-                bundle.manager,
-                bundle.clipboard,
-                bundle.edits_history,
-                bundle.grid,
-                bundle.next_editor_state
-            )
+            self.quit(edited_map!(bundle), bundle.next_editor_state)
         {
             return false;
         }
 
+        if bundle.window.focused
+        {
+            bundle.edits_history.add_session_time(bundle.delta_time);
+        }
+
+        self.open_restored_snapshots(bundle);
+
+        self.tabs.autosave(
+            bundle.delta_time,
+            bundle.config.map_autosave_interval,
+            bundle.config.open_file.path(),
+            || Self::snapshot_data(bundle)
+        );
+
+        self.update_things_animators(bundle);
+
         // Reactive update to previous frame's changes.
         bundle.manager.update_tool_and_overall_values(
             bundle.drawing_resources,
@@ -1664,8 +3161,10 @@ impl State
             bundle,
             &mut self.core,
             &mut self.tools_settings,
+            &self.tabs,
             &tool_change_conditions
         );
+        self.rename_texture_references(bundle);
 
         if self.reloading_textures
         {
@@ -1677,6 +3176,12 @@ impl State
             bundle.inputs.left_mouse.clear();
         }
 
+        bundle.inputs.update_double_click(
+            bundle.elapsed_time,
+            bundle.cursor.ui(),
+            bundle.config.double_click
+        );
+
         if self.map_preview()
         {
             self.map_preview_update(bundle, &ui_interaction)
@@ -1810,7 +3315,8 @@ impl State
             {
                 self.change_tool(tool, bundle, tool_change_conditions);
             },
-            Command::New => dialog_if_error!(self.new_file(bundle)),
+            Command::New => dialog_if_error!(self.new_file(bundle, Grid::default())),
+            Command::NewWithGrid(grid) => dialog_if_error!(self.new_file(bundle, grid)),
             Command::Save =>
             {
                 dialog_if_error!(Self::save(
@@ -1840,7 +3346,25 @@ impl State
                 ));
             },
             Command::Open => self.open(bundle),
-            Command::Export => Self::export(bundle),
+            Command::OpenRecent(index) => self.open_recent(bundle, index),
+            Command::NewTab => self.new_tab(bundle),
+            Command::OpenInNewTab => self.open_in_new_tab(bundle),
+            Command::SelectTab(index) => self.select_tab(bundle, index),
+            Command::CloseTab(index) => self.close_tab(bundle, index),
+            Command::NewFromTemplate(index) => self.new_from_template(bundle, index),
+            Command::Export => self.export(bundle, None),
+            Command::ExportWith(index) => self.export(bundle, index.into()),
+            Command::ExportSelection => dialog_if_error!(Self::export_selection(bundle)),
+            Command::ExportMeshes => Self::export_meshes(bundle),
+            Command::ExportGltf => Self::export_gltf(bundle),
+            Command::ExportTmx => Self::export_tmx(bundle),
+            Command::CheckMap => self.check_map(bundle),
+            Command::RunScript =>
+            {
+                let output = self.core.run_script(bundle, self.ui.script());
+                self.ui.set_script_output(output);
+            },
+            Command::ImportQuakeMap => self.import_quake_map(bundle),
             Command::ImportAnimations =>
             {
                 import(
@@ -1885,6 +3409,21 @@ impl State
                             bundle.grid,
                             len,
                             file
+                        )?;
+
+                        // Files exported by the previous versions do not contain the quick props.
+                        let quick_props_amount =
+                            ciborium::from_reader::<usize, _>(&mut *file).unwrap_or_default();
+
+                        bundle.clipboard.import_quick_props(
+                            bundle.images,
+                            bundle.prop_cameras,
+                            bundle.user_textures,
+                            bundle.drawing_resources,
+                            bundle.things_catalog,
+                            bundle.grid,
+                            quick_props_amount,
+                            file
                         )
                     }
                 );
@@ -1896,14 +3435,45 @@ impl State
                     PROPS_FILTER_NAME,
                     PROPS_EXTENSION,
                     bundle.clipboard.props_amount(),
-                    |writer| bundle.clipboard.export_props(writer)
+                    |writer| {
+                        bundle.clipboard.export_props(writer)?;
+                        ciborium::ser::into_writer(
+                            &bundle.clipboard.quick_props_amount(),
+                            &mut *writer
+                        )
+                        .map_err(|_| "Error writing quick props amount.")?;
+                        bundle.clipboard.export_quick_props(writer)
+                    }
                 );
             },
             Command::SelectAll => self.select_all(bundle),
+            Command::GoToEntity(id) => self.go_to_entity(bundle, tool_change_conditions, id),
             Command::Copy => self.core.copy(bundle),
-            Command::Paste => self.core.paste(bundle),
+            Command::Paste => self.core.paste(bundle, self.tools_settings.paste_snap),
+            Command::PasteFromHistory(index) =>
+            {
+                self.core
+                    .paste_from_history(bundle, index, self.tools_settings.paste_snap);
+            },
             Command::Cut => self.core.cut(bundle),
             Command::Duplicate => self.duplicate(bundle),
+            Command::ThingsFootprint =>
+            {
+                self.core
+                    .things_footprint(bundle, self.tools_settings.footprint_margin);
+            },
+            Command::RoundCorners => self.core.round_corners(bundle, &self.tools_settings),
+            Command::SliceToGrid(slice) =>
+            {
+                self.core.slice_to_grid(bundle, &self.tools_settings, slice);
+            },
+            Command::Group => bundle.manager.group_selected_entities(bundle.edits_history),
+            Command::Ungroup => bundle.manager.ungroup_selected_entities(bundle.edits_history),
+            Command::Distribute(distribution) =>
+            {
+                let positions = distribution.positions(bundle.manager);
+                self.core.distribute(bundle, &positions);
+            },
             Command::Undo => self.undo(bundle),
             Command::Redo => self.redo(bundle),
             Command::ToggleGrid => Self::toggle_grid(bundle.grid),
@@ -1912,8 +3482,19 @@ impl State
             Command::ShiftGrid => Self::shift_grid(bundle),
             Command::ToggleTooltips => self.toggle_tooltips(),
             Command::ToggleCursorSnap => self.toggle_cursor_snap(),
+            Command::ToggleAdaptiveSnap => self.tools_settings.adaptive_snap.toggle(),
             Command::ToggleMapPreview => self.toggle_map_preview(bundle),
             Command::ToggleCollision => self.toggle_collision(),
+            Command::ToggleEditorOnly =>
+            {
+                bundle.manager.toggle_selected_brushes_editor_only(
+                    bundle.drawing_resources,
+                    bundle.edits_history,
+                    bundle.grid
+                );
+            },
+            Command::ToggleEditorOnlyVisibility => bundle.manager.toggle_editor_only_visibility(),
+            Command::ToggleLinks => self.tools_settings.show_links.toggle(),
             Command::ReloadTextures => self.start_texture_reload(bundle),
             Command::ReloadThings => Self::reload_things(bundle),
             Command::QuickZoom =>
@@ -1931,17 +3512,7 @@ impl State
             Command::QuickSnap => self.quick_snap(bundle),
             Command::Quit =>
             {
-                _ = Self::quit(
-                    bundle.window,
-                    bundle.config,
-                    bundle.default_properties,
-                    bundle.drawing_resources,
-                    bundle.manager,
-                    bundle.clipboard,
-                    bundle.edits_history,
-                    bundle.grid,
-                    bundle.next_editor_state
-                );
+                _ = self.quit(edited_map!(bundle), bundle.next_editor_state);
                 return true;
             }
         };
@@ -1981,10 +3552,19 @@ impl State
                 bundle.window.mode.toggle();
             }
             else if Bind::Snap.alt_just_pressed(bundle.key_inputs, &bundle.config.binds) &&
+                Tool::Snap.shown(&bundle.config.toolbar) &&
                 Tool::Snap.change_conditions_met(tool_change_conditions)
             {
                 self.quick_snap(bundle);
             }
+            else if let Some(angle) = Self::quick_rotation_angle(bundle)
+            {
+                if Tool::Rotate.shown(&bundle.config.toolbar) &&
+                    Tool::Rotate.change_conditions_met(tool_change_conditions)
+                {
+                    self.core.quick_rotate(bundle, &self.tools_settings, angle);
+                }
+            }
             else if bundle.inputs.esc.just_pressed()
             {
                 self.core.disable_subtool();
@@ -2002,12 +3582,15 @@ impl State
                 // Update tool based on key presses.
                 for tool in Tool::iter()
                 {
-                    if !tool.just_pressed(bundle.key_inputs, &bundle.config.binds)
+                    if !tool.just_pressed(bundle.key_inputs, &bundle.config.binds) ||
+                        tool.keycode(&bundle.config.binds)
+                            .is_some_and(|key| bundle.config.navigation.pans_with(key))
                     {
                         continue;
                     }
 
-                    if tool.change_conditions_met(tool_change_conditions)
+                    if tool.shown(&bundle.config.toolbar) &&
+                        tool.change_conditions_met(tool_change_conditions)
                     {
                         self.change_tool(tool, bundle, tool_change_conditions);
                     }
@@ -2057,17 +3640,7 @@ impl State
             Command::ReloadTextures => self.start_texture_reload(bundle),
             Command::Quit =>
             {
-                _ = Self::quit(
-                    bundle.window,
-                    bundle.config,
-                    bundle.default_properties,
-                    bundle.drawing_resources,
-                    bundle.manager,
-                    bundle.clipboard,
-                    bundle.edits_history,
-                    bundle.grid,
-                    bundle.next_editor_state
-                );
+                _ = self.quit(edited_map!(bundle), bundle.next_editor_state);
             },
             _ => ()
         };
@@ -2081,18 +3654,44 @@ impl State
         ui_interaction.hovered
     }
 
-    /// Updates the active tool.
+    /// Updates the active tool. `panning` is whether the view is being dragged with the pan mouse
+    /// button.
     #[inline]
-    pub fn update_active_tool(&mut self, bundle: &mut ToolUpdateBundle)
+    pub fn update_active_tool(&mut self, panning: bool, bundle: &mut ToolUpdateBundle)
     {
         if self.reloading_textures
         {
             return;
         }
 
+        // Temporary pan mode, the tool is frozen so that any ongoing edit resumes once space is
+        // released.
+        if bundle.inputs.space_pressed() && !self.map_preview()
+        {
+            return;
+        }
+
+        // Same as above, the right mouse button can be the pan button so its presses must not
+        // reach the tool.
+        if panning
+        {
+            bundle.inputs.right_mouse.clear();
+            return;
+        }
+
         self.core.update(bundle, &mut self.tools_settings);
     }
 
+    /// Changes the soft selection radius of the vertex tool by `scroll`, if it is active and the
+    /// soft selection is enabled. Returns whether that was the case.
+    #[inline]
+    #[must_use]
+    pub fn soft_selection_radius_scroll(&mut self, scroll: f32) -> bool
+    {
+        self.core
+            .soft_selection_radius_scroll(&mut self.tools_settings, scroll)
+    }
+
     /// Changes the active tool.
     #[inline]
     fn change_tool(
@@ -2108,7 +3707,7 @@ impl State
         }
 
         self.core
-            .change_tool(tool, bundle, &self.tools_settings, tool_change_conditions);
+            .change_tool(tool, bundle, &mut self.tools_settings, tool_change_conditions);
     }
 
     /// Toggles the grid visibiity.
@@ -2143,7 +3742,7 @@ impl State
 
     /// Toggles the map preview mode.
     #[inline]
-    fn toggle_map_preview(&mut self, bundle: &StateUpdateBundle)
+    fn toggle_map_preview(&mut self, bundle: &mut StateUpdateBundle)
     {
         self.core.toggle_map_preview(bundle);
     }
@@ -2181,53 +3780,100 @@ impl State
             return;
         }
 
-        bundle.things_catalog.reload_things();
-        bundle.edits_history.purge_thing_edits();
-        bundle.clipboard.reload_things(
+        Self::execute_things_reload(
             bundle.images,
             bundle.user_textures,
             bundle.prop_cameras,
             bundle.drawing_resources,
             bundle.things_catalog,
+            bundle.manager,
+            bundle.clipboard,
+            bundle.edits_history,
             bundle.grid
         );
-        bundle.manager.finish_things_reload(bundle.things_catalog);
     }
 
-    /// Starts the application shutdown procedure.  
-    /// Returns whether the application should actually be closed.
+    /// Reloads the things, without asking for confirmation.
+    #[allow(clippy::too_many_arguments)]
     #[inline]
-    #[must_use]
-    pub fn quit(
-        window: &mut Window,
-        config: &mut Config,
-        default_properties: &AllDefaultProperties,
-        drawing_resources: &mut DrawingResources,
+    pub fn execute_things_reload(
+        images: &mut Assets<Image>,
+        user_textures: &mut EguiUserTextures,
+        prop_cameras: &mut PropCamerasMut,
+        drawing_resources: &DrawingResources,
+        things_catalog: &mut ThingsCatalog,
         manager: &mut EntitiesManager,
         clipboard: &mut Clipboard,
         edits_history: &mut EditsHistory,
-        grid: &mut Grid,
+        grid: &Grid
+    )
+    {
+        things_catalog.reload_things();
+        edits_history.purge_thing_edits();
+        clipboard.reload_things(
+            images,
+            user_textures,
+            prop_cameras,
+            drawing_resources,
+            things_catalog,
+            grid
+        );
+        manager.finish_things_reload(things_catalog);
+    }
+
+    /// Starts the application shutdown procedure, asking to save the unsaved changes of the maps
+    /// of all the tabs, the one being edited last.  
+    /// Returns whether the application should actually be closed.
+    #[inline]
+    #[must_use]
+    pub fn quit(
+        &mut self,
+        mut map: EditedMap,
         next_editor_state: &mut NextState<EditorState>
     ) -> bool
     {
-        if !dialog_if_error!(
-            default;
-            Self::save_unsaved_changes(
-                window,
-                config,
-                default_properties,
-                drawing_resources,
-                manager,
-                clipboard,
-                edits_history,
-                grid
-            ),
-            true
-        )
+        let active = self.tabs.active();
+        let unsaved = (0..self.tabs.len())
+            .filter(|index| *index != active && self.tabs.unsaved_edits(*index))
+            .chain(Some(active))
+            .collect::<Vec<_>>();
+
+        for index in unsaved
         {
-            return false;
+            _ = self.tabs.select(map.reborrow(), index);
+
+            if !dialog_if_error!(
+                default;
+                Self::save_unsaved_changes(
+                    map.window,
+                    map.config,
+                    map.default_properties,
+                    map.drawing_resources,
+                    map.manager,
+                    map.clipboard,
+                    map.edits_history,
+                    map.grid
+                ),
+                true
+            )
+            {
+                _ = self.tabs.select(map, active);
+                return false;
+            }
         }
 
+        // The maps still having unsaved changes were discarded.
+        let discarded = (0..self.tabs.len())
+            .any(|index| index != active && self.tabs.unsaved_edits(index)) ||
+            !Self::no_edits(
+                map.drawing_resources,
+                map.manager,
+                map.clipboard,
+                map.edits_history,
+                map.grid
+            );
+
+        autosave::end_session(discarded);
         next_editor_state.set(EditorState::ShutDown);
         true
     }
@@ -2265,6 +3911,23 @@ impl State
         self.core.quick_snap(bundle, &self.tools_settings);
     }
 
+    /// Returns the angle, in degrees, of the quick rotation bind that was just pressed, if any.
+    #[inline]
+    #[must_use]
+    fn quick_rotation_angle(bundle: &StateUpdateBundle) -> Option<f32>
+    {
+        [
+            (Bind::RotateClockwise, 270f32),
+            (Bind::RotateCounterclockwise, 90f32),
+            (Bind::RotateHalfTurn, 180f32)
+        ]
+        .into_iter()
+        .find_map(|(bind, angle)| {
+            bind.just_pressed(bundle.key_inputs, &bundle.config.binds)
+                .then_some(angle)
+        })
+    }
+
     //==============================================================
     // Texture reload
 
@@ -2291,6 +3954,20 @@ impl State
         bundle.next_tex_load.set(TextureLoadingProgress::Initiated);
     }
 
+    /// Whether a texture reload is in progress.
+    #[inline]
+    #[must_use]
+    pub const fn reloading_textures(&self) -> bool { self.reloading_textures }
+
+    /// Starts the reload of the textures whose files were added or modified, which does not
+    /// require confirmation.
+    #[inline]
+    pub fn start_texture_hot_reload(&mut self)
+    {
+        assert!(!self.reloading_textures, "Texture reload already in progress.");
+        self.reloading_textures = true;
+    }
+
     /// Concludes the texture reload.
     #[inline]
     pub fn finish_textures_reload(
@@ -2331,12 +4008,40 @@ impl State
         bundle.clipboard.draw_props_to_photograph(bundle);
         bundle.drawer.grid_lines(bundle.window, bundle.camera);
         self.core.draw_active_tool(bundle, &self.tools_settings);
+
+        if self.tools_settings.show_links
+        {
+            bundle.manager.draw_thing_links(bundle.drawer);
+        }
+
+        if self.tools_settings.texture_grid && self.core.texture_tool()
+        {
+            for brush in bundle.manager.selected_brushes()
+            {
+                brush.draw_texture_grid(bundle.drawer);
+            }
+        }
+
+        if self.tools_settings.parallax_calibration
+        {
+            Self::draw_parallax_labels(bundle);
+        }
         bundle.manager.draw_error_highlight(
             bundle.things_catalog,
             bundle.drawer,
             bundle.delta_time
         );
 
+        if let Some(index) = self.ui.hovered_edit()
+        {
+            bundle.edits_history.draw_undo_preview(
+                bundle.drawer,
+                bundle.manager,
+                bundle.things_catalog,
+                index
+            );
+        }
+
         if self.show_cursor
         {
             bundle
@@ -2344,9 +4049,57 @@ impl State
                 .square_highlight(bundle.cursor.world_snapped(), Color::DefaultCursor);
         }
 
+        if bundle.cursor.keyboard_moved()
+        {
+            /// The radius of the focus ring on screen.
+            const FOCUS_RING_RADIUS: f32 = 12f32;
+
+            // Focus ring around the cursor moved with the keyboard.
+            let radius = FOCUS_RING_RADIUS * bundle.drawer.camera_scale();
+            bundle
+                .drawer
+                .circle(bundle.cursor.world_snapped(), 16, radius, Color::DefaultCursor);
+        }
+
         self.ui.frame_end_update(bundle.drawer.egui_context());
     }
 
+    /// Draws the parallax values of the textures of the visible brushes.
+    #[inline]
+    fn draw_parallax_labels(bundle: &mut DrawBundle)
+    {
+        let DrawBundle {
+            window,
+            drawer,
+            camera,
+            manager,
+            ..
+        } = bundle;
+
+        for brush in manager.visible_brushes(window, camera, drawer.grid()).iter()
+        {
+            let texture = continue_if_none!(brush.texture_settings());
+            let parallax = Vec2::new(texture.parallax_x(), texture.parallax_y());
+
+            if parallax == Vec2::ZERO
+            {
+                continue;
+            }
+
+            let label = return_if_none!(drawer.tooltip_label());
+            drawer.draw_tooltip_x_centered_above_pos(
+                window,
+                camera,
+                label,
+                &format!("{:.2}, {:.2}", parallax.x, parallax.y),
+                brush.center(),
+                Vec2::ZERO,
+                drawer.tooltip_text_color(),
+                drawer.egui_color(Color::HighlightedSelectedEntity)
+            );
+        }
+    }
+
     /// Draws the map preview.
     #[inline]
     pub fn draw_map_preview(&mut self, bundle: &mut DrawBundleMapPreview)
@@ -2354,6 +4107,10 @@ impl State
         self.core.draw_map_preview(bundle);
         self.ui.frame_end_update(bundle.egui_context);
     }
+
+    /// Draws the live map preview of the split view.
+    #[inline]
+    pub fn draw_split_view(bundle: &mut DrawBundleMapPreview) { Core::draw_split_view(bundle); }
 }
 
 //=======================================================================//
@@ -3,17 +3,22 @@
 //
 //=======================================================================//
 
+use glam::Vec2;
 use smallvec::smallvec;
 
 use super::edit_type::EditType;
 use crate::{
     map::{
         drawer::drawing_resources::DrawingResources,
-        editor::state::{core::UndoRedoInterface, grid::Grid, ui::Ui},
+        editor::state::{core::UndoRedoInterface, grid::Grid, manager::EntitiesManager, ui::Ui},
         properties::value::Value,
         thing::catalog::ThingsCatalog
     },
-    utils::{collections::HvVec, identifiers::Id, misc::ReplaceValue}
+    utils::{
+        collections::{HashMap, HvVec},
+        identifiers::Id,
+        misc::ReplaceValue
+    }
 };
 
 //=======================================================================//
@@ -92,6 +97,18 @@ impl Edit
         })
     }
 
+    /// Returns the amount of brushes spawned by `self`.
+    #[inline]
+    #[must_use]
+    pub fn spawned_brushes(&self) -> usize
+    {
+        self.edits
+            .iter()
+            .filter(|(_, et)| matches!(et, EditType::DrawnBrush(_) | EditType::BrushSpawn(..)))
+            .map(|(ids, _)| ids.len())
+            .sum()
+    }
+
     /// Whether `self` only contains entity selection sub-edits.
     #[inline]
     #[must_use]
@@ -178,7 +195,9 @@ impl Edit
                 EditType::ListAnimationFrameRemoval(..) |
                 EditType::ListAnimationFrameMoveDown(..) |
                 EditType::ListAnimationFrameMoveUp(..) |
-                EditType::PropertyChange(..)
+                EditType::PropertyChange(..) |
+                EditType::CustomPropertyChange(..) |
+                EditType::EditorOnlyToggle
         )
         {
             assert!(
@@ -222,6 +241,28 @@ impl Edit
         }
     }
 
+    /// Pushes a custom property sub-edit.
+    #[inline]
+    pub fn push_custom_property(
+        &mut self,
+        key: &str,
+        iter: impl IntoIterator<Item = (Id, Option<Value>)>
+    )
+    {
+        assert!(
+            self.property.replace_value(key.to_owned().into()).is_none(),
+            "Property edit already stored."
+        );
+
+        self.push_tag("Custom Property Change");
+
+        for (id, value) in iter
+        {
+            self.edits
+                .push((smallvec![id], EditType::CustomPropertyChange(value)));
+        }
+    }
+
     #[inline]
     fn push_tag(&mut self, tag: &str)
     {
@@ -320,6 +361,22 @@ impl Edit
         self.edits.is_empty()
     }
 
+    /// Stores in `ghosts` the outlines the entities had before the sub-edits took place, going
+    /// through them in the reverse order they were stored.
+    #[inline]
+    pub fn undo_preview(
+        &self,
+        manager: &EntitiesManager,
+        things_catalog: &ThingsCatalog,
+        ghosts: &mut HashMap<Id, Option<Vec<Vec2>>>
+    )
+    {
+        for (ids, ed_type) in self.edits.iter().rev()
+        {
+            ed_type.undo_preview(manager, things_catalog, ids, ghosts);
+        }
+    }
+
     /// Triggers the undo procedures of the sub-edits in the reverse order they were stored.
     #[inline]
     pub fn undo(
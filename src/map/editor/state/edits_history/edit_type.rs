@@ -6,6 +6,7 @@
 use glam::Vec2;
 use hill_vacuum_shared::NextValue;
 
+use super::current_outline;
 use crate::{
     map::{
         brush::{
@@ -25,11 +26,17 @@ use crate::{
                 TextureSpriteSet
             }
         },
-        editor::state::{core::UndoRedoInterface, grid::Grid, ui::Ui},
-        path::{MovementValueEdit, NodesMove, Path, StandbyValueEdit},
-        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData}
+        editor::state::{core::UndoRedoInterface, grid::Grid, manager::EntitiesManager, ui::Ui},
+        path::{
+            nodes::{Curve, Easing},
+            MovementValueEdit,
+            NodesMove,
+            Path,
+            StandbyValueEdit
+        },
+        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData, ThingInterface}
     },
-    utils::{hull::Flip, identifiers::Id},
+    utils::{collections::HashMap, hull::Flip, identifiers::Id},
     Value
 };
 
@@ -156,6 +163,10 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     PathNodesDeletion(Vec<(Vec2, u8)>),
     /// Path nodes grid snap.
     PathNodesSnap(Vec<(Vec<u8>, Vec2)>),
+    /// Changed path nodes curve.
+    PathNodesCurve(Vec<(u8, Curve)>),
+    /// Changed path nodes easing.
+    PathNodesEasing(Vec<(u8, Easing)>),
     /// Changed path node standby time.
     PathNodeStandby(StandbyValueEdit),
     /// Changed path node acceleration percentage.
@@ -182,6 +193,8 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     ThingMove(Vec2),
     /// Thing changed to new ID.
     ThingChange(ThingId),
+    /// Thing spawn flags changed.
+    ThingFlags(u32),
     /// Brush texture change.
     TextureChange(Option<String>),
     /// Brush texture removed.
@@ -214,6 +227,8 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     TextureRotation(TextureRotation),
     /// Texture draw height change.
     TextureHeight(i8),
+    /// Texture opacity change.
+    TextureOpacity(u8),
     /// Texture animation change.
     AnimationChange(Animation),
     /// Texture reset.
@@ -268,8 +283,18 @@ pub(in crate::map::editor::state::edits_history) enum EditType
     TAtlasAnimationUniformTime(String, f32),
     /// Default atlas animation frame time change.
     TAtlasAnimationFrameTime(String, usize, f32),
+    /// Texture rename, the previous and the new name.
+    TextureRename(String, String),
     /// Entity property change.
-    PropertyChange(Value)
+    PropertyChange(Value),
+    /// Entity custom property change, insertion, or removal.
+    CustomPropertyChange(Option<Value>),
+    /// Brushes editor only flag toggle.
+    EditorOnlyToggle,
+    /// Entity outline color change.
+    EntityColor(Option<[u8; 3]>),
+    /// Entity groups change.
+    EntityGroups(Vec<u32>)
 }
 
 impl std::fmt::Debug for EditType
@@ -304,6 +329,8 @@ impl std::fmt::Debug for EditType
             Self::PathNodesMove(_) => "PathNodesMove",
             Self::PathNodesDeletion(_) => "PathNodesDeletion",
             Self::PathNodesSnap(_) => "PathNodesSnap",
+            Self::PathNodesCurve(_) => "PathNodesCurve",
+            Self::PathNodesEasing(_) => "PathNodesEasing",
             Self::PathNodeStandby(_) => "PathNodeStandby",
             Self::PathNodeAcceleration(_) => "PathNodeAcceleration",
             Self::PathNodeDeceleration(_) => "PathNodeDeceleration",
@@ -317,6 +344,7 @@ impl std::fmt::Debug for EditType
             Self::ThingDespawn(_) => "ThingDespawn",
             Self::ThingMove(_) => "ThingMove",
             Self::ThingChange(_) => "ThingChange",
+            Self::ThingFlags(_) => "ThingFlags",
             Self::TextureChange(_) => "TextureChange",
             Self::TextureRemoval(_) => "TextureRemoval",
             Self::SpriteToggle(_) => "SpriteToggle",
@@ -333,6 +361,7 @@ impl std::fmt::Debug for EditType
             Self::TextureMove(_) => "TextureMove",
             Self::TextureRotation(_) => "TextureRotation",
             Self::TextureHeight(_) => "TextureHeight",
+            Self::TextureOpacity(_) => "TextureOpacity",
             Self::AnimationChange(_) => "AnimationChange",
             Self::TextureReset(_) => "TextureReset",
             Self::ListAnimationFrameMoveUp(..) => "ListAnimationFrameMoveUp",
@@ -360,7 +389,12 @@ impl std::fmt::Debug for EditType
             Self::TAtlasAnimationTiming(..) => "TAtlasAnimationTiming",
             Self::TAtlasAnimationUniformTime(..) => "TAtlasAnimationUniformTime",
             Self::TAtlasAnimationFrameTime(..) => "TAtlasAnimationFrameTime",
-            Self::PropertyChange(_) => "PropertyChange"
+            Self::TextureRename(..) => "TextureRename",
+            Self::PropertyChange(_) => "PropertyChange",
+            Self::CustomPropertyChange(_) => "CustomPropertyChange",
+            Self::EditorOnlyToggle => "EditorOnlyToggle",
+            Self::EntityColor(_) => "EntityColor",
+            Self::EntityGroups(_) => "EntityGroups"
         };
 
         write!(f, "{str}")
@@ -397,6 +431,8 @@ impl EditType
             Self::PathNodesMove(..) => "Path nodes move",
             Self::PathNodesDeletion(..) => "Path nodes deletion",
             Self::PathNodesSnap(..) => "Path nodes snap",
+            Self::PathNodesCurve(..) => "Path nodes curve",
+            Self::PathNodesEasing(..) => "Path nodes easing",
             Self::PathNodeStandby(..) => "Path node standby",
             Self::PathNodeAcceleration(..) => "Path node acceleration",
             Self::PathNodeDeceleration(..) => "Path node deceleration",
@@ -408,6 +444,7 @@ impl EditType
             Self::ThingDespawn(..) | Self::DrawnThingDespawn(..) => "Things despawn",
             Self::ThingMove(..) => "Thing move",
             Self::ThingChange(..) => "Things change",
+            Self::ThingFlags(..) => "Things flags",
             Self::TextureChange(..) => "Textures change",
             Self::TextureRemoval(..) => "Textures removal",
             Self::SpriteToggle(..) => "Sprites toggle",
@@ -423,6 +460,7 @@ impl EditType
             Self::TextureMove(..) => "Textures move",
             Self::TextureRotation(..) => "Textures rotation",
             Self::TextureHeight(..) => "Textures height",
+            Self::TextureOpacity(..) => "Textures opacity",
             Self::AnimationChange(..) => "Animations change",
             Self::TextureReset(..) => "Textures reset",
             Self::ListAnimationFrameMoveUp(..) => "List animation frame move up",
@@ -450,7 +488,12 @@ impl EditType
             Self::TAtlasAnimationTiming(..) => "Texture default atlas animation timing",
             Self::TAtlasAnimationUniformTime(..) => "Texture default atlas animation uniform time",
             Self::TAtlasAnimationFrameTime(..) => "Texture default atlas animation frame time",
-            Self::PropertyChange(..) => "Properties change"
+            Self::TextureRename(..) => "Texture rename",
+            Self::PropertyChange(..) => "Properties change",
+            Self::CustomPropertyChange(..) => "Custom properties change",
+            Self::EditorOnlyToggle => "Editor only toggle",
+            Self::EntityColor(_) => "Outline colors change",
+            Self::EntityGroups(_) => "Groups change"
         }
     }
 
@@ -497,6 +540,7 @@ impl EditType
                 Self::TextureMove(_) |
                 Self::TextureRotation(_) |
                 Self::TextureHeight(_) |
+                Self::TextureOpacity(_) |
                 Self::AnimationChange(_) |
                 Self::ListAnimationFrameMoveUp(..) |
                 Self::ListAnimationFrameMoveDown(..) |
@@ -509,7 +553,8 @@ impl EditType
                 Self::AtlasAnimationLen(_) |
                 Self::AtlasAnimationTiming(_) |
                 Self::AtlasAnimationUniformTime(_) |
-                Self::AtlasAnimationFrameTime(..)
+                Self::AtlasAnimationFrameTime(..) |
+                Self::TextureRename(..)
         )
     }
 
@@ -521,6 +566,7 @@ impl EditType
         matches!(
             self,
             Self::ThingChange(_) |
+                Self::ThingFlags(_) |
                 Self::DrawnThing(..) |
                 Self::ThingSpawn(..) |
                 Self::DrawnThingDespawn(..) |
@@ -689,6 +735,13 @@ impl EditType
                         .undo_redo_texture_reset(value);
                 }
             },
+            Self::EditorOnlyToggle =>
+            {
+                for id in identifiers
+                {
+                    interface.brush_mut(drawing_resources, grid, *id).toggle_editor_only();
+                }
+            },
             _ => return false
         };
 
@@ -775,6 +828,7 @@ impl EditType
             (TextureScaleX, scale_x),
             (TextureScaleY, scale_y),
             (TextureHeight, height),
+            (TextureOpacity, opacity),
             (AtlasAnimationColumns, atlas_animation_x_partition),
             (AtlasAnimationRows, atlas_animation_y_partition),
             (AtlasAnimationLen, atlas_animation_len),
@@ -824,6 +878,10 @@ impl EditType
         match self
         {
             Self::ThingChange(id) => *id = interface.set_thing(things_catalog, identifier, *id),
+            Self::ThingFlags(flags) =>
+            {
+                *flags = interface.set_thing_flags(things_catalog, identifier, *flags);
+            },
             _ => return false
         };
 
@@ -854,6 +912,55 @@ impl EditType
                     .moving_mut(drawing_resources, things_catalog, grid, identifier)
                     .move_path_nodes_at_indexes(snap);
             },
+            Self::PathNodesCurve(curves) =>
+            {
+                interface.schedule_overall_node_update();
+                interface
+                    .moving_mut(drawing_resources, things_catalog, grid, identifier)
+                    .swap_path_nodes_curves(curves);
+            },
+            Self::PathNodesEasing(easings) =>
+            {
+                interface.schedule_overall_node_update();
+                interface
+                    .moving_mut(drawing_resources, things_catalog, grid, identifier)
+                    .swap_path_nodes_easings(easings);
+            },
+            _ => return false
+        };
+
+        true
+    }
+
+    /// Actions common to both the undo and redo procedures that apply to a single brush or
+    /// [`Thing`]. Returns whether the edit was undone/redone.
+    #[inline]
+    #[must_use]
+    fn entity_common(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid,
+        interface: &mut UndoRedoInterface,
+        identifier: Id
+    ) -> bool
+    {
+        match self
+        {
+            Self::EntityColor(color) =>
+            {
+                *color = interface.set_entity_color(
+                    drawing_resources,
+                    things_catalog,
+                    grid,
+                    identifier,
+                    *color
+                );
+            },
+            Self::EntityGroups(groups) =>
+            {
+                *groups = interface.set_entity_groups(identifier, std::mem::take(groups));
+            },
             _ => return false
         };
 
@@ -960,20 +1067,105 @@ impl EditType
         key: Option<&String>
     ) -> bool
     {
-        if let Self::PropertyChange(value) = self
+        match self
         {
-            *value = interface.set_property(
-                drawing_resources,
-                things_catalog,
-                grid,
-                identifiers[0],
-                key.unwrap(),
-                value
-            );
-            return true;
-        }
+            Self::PropertyChange(value) =>
+            {
+                *value = interface.set_property(
+                    drawing_resources,
+                    things_catalog,
+                    grid,
+                    identifiers[0],
+                    key.unwrap(),
+                    value
+                );
+            },
+            Self::CustomPropertyChange(value) =>
+            {
+                *value = interface.set_custom_property(
+                    drawing_resources,
+                    things_catalog,
+                    grid,
+                    identifiers[0],
+                    key.unwrap(),
+                    value.take()
+                );
+            },
+            _ => return false
+        };
 
-        false
+        true
+    }
+
+    //==============================================================
+    // Undo preview
+
+    /// Stores in `ghosts` the outlines the entities edited by `self` had before the edit took
+    /// place. `None` if the entity did not exist.
+    #[inline]
+    pub fn undo_preview(
+        &self,
+        manager: &EntitiesManager,
+        things_catalog: &ThingsCatalog,
+        identifiers: &[Id],
+        ghosts: &mut HashMap<Id, Option<Vec<Vec2>>>
+    )
+    {
+        match self
+        {
+            Self::BrushMove(delta, _) | Self::ThingMove(delta) =>
+            {
+                for id in identifiers
+                {
+                    for vx in ghosts
+                        .entry(*id)
+                        .or_insert_with(|| current_outline(manager, things_catalog, *id))
+                        .iter_mut()
+                        .flatten()
+                    {
+                        *vx -= *delta;
+                    }
+                }
+            },
+            Self::PolygonEdit(polygon) =>
+            {
+                _ = ghosts.insert(identifiers[0], Some(polygon.vertexes().collect()));
+            },
+            Self::DrawnBrush(_) |
+            Self::BrushSpawn(..) |
+            Self::DrawnThing(_) |
+            Self::ThingSpawn(_) => _ = ghosts.insert(identifiers[0], None),
+            Self::DrawnBrushDespawn(Some(data)) | Self::BrushDespawn(Some(data), _) =>
+            {
+                _ = ghosts.insert(identifiers[0], Some(data.vertexes().collect()));
+            },
+            Self::DrawnThingDespawn(Some(thing)) | Self::ThingDespawn(Some(thing)) =>
+            {
+                _ = ghosts.insert(
+                    identifiers[0],
+                    Some(thing.thing_hull(things_catalog).vertexes().collect())
+                );
+            },
+            Self::VertexInsertion(_) |
+            Self::VertexesDeletion(_) |
+            Self::VertexesMove(_) |
+            Self::SidesDeletion(_) |
+            Self::VertexesSnap(_) |
+            Self::BrushFlip(..) |
+            Self::ThingChange(_) |
+            Self::TextureChange(_) |
+            Self::TextureRemoval(_) =>
+            {
+                // The previous shape is not rebuilt, the entity is outlined as it is.
+                for id in identifiers
+                {
+                    _ = ghosts
+                        .entry(*id)
+                        .or_insert_with(|| current_outline(manager, things_catalog, *id));
+                }
+            },
+            _ => ()
+        };
     }
 
     //==============================================================
@@ -1109,6 +1301,13 @@ impl EditType
             },
             Self::FreeDrawPointInsertion(p, idx) => interface.delete_free_draw_point(*p, *idx as usize),
             Self::FreeDrawPointDeletion(p, idx) => interface.insert_free_draw_point(*p, *idx as usize),
+            Self::TextureRename(prev, new) =>
+            {
+                if interface.rename_texture(drawing_resources, grid, new, prev)
+                {
+                    ui.schedule_texture_rename(new, prev);
+                }
+            },
             Self::BrushFlip(flip, flip_texture) =>
             {
                 let func = match flip
@@ -1237,7 +1436,8 @@ impl EditType
 
         if self.thing_common(things_catalog, interface, id) ||
             self.brush_common(drawing_resources, grid, interface, id) ||
-            self.moving_common(drawing_resources, things_catalog, grid, interface, id)
+            self.moving_common(drawing_resources, things_catalog, grid, interface, id) ||
+            self.entity_common(drawing_resources, things_catalog, grid, interface, id)
         {
             return;
         }
@@ -1403,6 +1603,13 @@ impl EditType
             },
             Self::FreeDrawPointInsertion(p, idx) => interface.insert_free_draw_point(*p, *idx as usize),
             Self::FreeDrawPointDeletion(p, idx) => interface.delete_free_draw_point(*p, *idx as usize),
+            Self::TextureRename(prev, new) =>
+            {
+                if interface.rename_texture(drawing_resources, grid, prev, new)
+                {
+                    ui.schedule_texture_rename(prev, new);
+                }
+            },
             Self::BrushFlip(flip, flip_texture) =>
             {
                 let func = match flip
@@ -1523,7 +1730,8 @@ impl EditType
 
         if self.thing_common(things_catalog, interface, id) ||
             self.brush_common(drawing_resources, grid, interface, id) ||
-            self.moving_common(drawing_resources, things_catalog, grid, interface, id)
+            self.moving_common(drawing_resources, things_catalog, grid, interface, id) ||
+            self.entity_common(drawing_resources, things_catalog, grid, interface, id)
         {
             return;
         }
@@ -1,17 +1,24 @@
 mod edit;
 pub(in crate::map::editor::state) mod edit_type;
+pub(in crate::map::editor::state) mod statistics;
 
 //=======================================================================//
 // IMPORTS
 //
 //=======================================================================//
 
+use std::path::PathBuf;
+
 use bevy_egui::egui;
 use glam::Vec2;
 use hill_vacuum_shared::{continue_if_none, return_if_none};
 use smallvec::smallvec;
 
-use self::{edit::Edit, edit_type::EditType};
+use self::{
+    edit::Edit,
+    edit_type::EditType,
+    statistics::{MapStatistics, Statistics}
+};
 use super::{
     core::{draw_tool::cursor_polygon::FreeDrawStatus, tool::EditingTarget, Core},
     grid::Grid,
@@ -26,6 +33,8 @@ use crate::{
         },
         drawer::{
             animation::{Animation, Timing},
+            color::Color,
+            drawers::EditDrawer,
             drawing_resources::DrawingResources,
             texture::{
                 Texture,
@@ -36,13 +45,19 @@ use crate::{
                 TextureSpriteSet
             }
         },
-        editor::state::core::UndoRedoInterface,
-        path::{MovementValueEdit, NodesMove, Path, StandbyValueEdit},
+        editor::{events::HistoryEvent, state::core::UndoRedoInterface},
+        path::{
+            nodes::{Curve, Easing},
+            MovementValueEdit,
+            NodesMove,
+            Path,
+            StandbyValueEdit
+        },
         properties::value::Value,
-        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData}
+        thing::{catalog::ThingsCatalog, ThingId, ThingInstanceData, ThingInterface}
     },
     utils::{
-        collections::HvVec,
+        collections::{hash_map, HvVec},
         hull::Flip,
         identifiers::{EntityId, Id},
         misc::TakeValue
@@ -204,7 +219,11 @@ pub(in crate::map::editor) struct EditsHistory
     /// because it only contains selection edits
     selections_only_edit_halted: bool,
     /// The index of the edit where the file was saved the last time, if any.
-    last_save_edit: Option<usize>
+    last_save_edit: Option<usize>,
+    /// The statistics of the work done on the map.
+    statistics: Statistics,
+    /// The commits of edits and the saves that were not sent as events yet.
+    events: Vec<HistoryEvent>
 }
 
 impl Default for EditsHistory
@@ -221,7 +240,9 @@ impl Default for EditsHistory
             earliest_thing_edit: None,
             earliest_texture_edit: None,
             selections_only_edit_halted: false,
-            last_save_edit: 0.into()
+            last_save_edit: 0.into(),
+            statistics: Statistics::default(),
+            events: Vec::new()
         }
     }
 }
@@ -240,6 +261,7 @@ impl EditsHistory
         (path_creation, (identifier: Id), (smallvec![identifier], EditType::PathCreation(None))),
         (free_draw_point_insertion, (p: Vec2, index: u8), (HvVec::new(), EditType::FreeDrawPointInsertion(p, index))),
         (free_draw_point_deletion, (p: Vec2, index: u8), (HvVec::new(), EditType::FreeDrawPointDeletion(p, index))),
+        (texture_rename, (prev: &str, new: &str), (HvVec::new(), EditType::TextureRename(prev.to_owned(), new.to_owned()))),
         (entity_selection, (identifier: Id), (smallvec![identifier], EditType::EntitySelection)),
         (entity_deselection, (identifier: Id), (smallvec![identifier], EditType::EntityDeselection)),
 		(vertex_insertion, (brush: &Brush, vx: (Vec2, u8)), (smallvec![brush.id()], EditType::VertexInsertion(vx))),
@@ -257,6 +279,8 @@ impl EditsHistory
         (path_nodes_min_speed, (identifier: Id, edit: MovementValueEdit), (smallvec![identifier], EditType::PathNodeMinSpeed(edit))),
         (path_nodes_accel_travel_percentage, (identifier: Id, edit: MovementValueEdit), (smallvec![identifier], EditType::PathNodeAcceleration(edit))),
         (path_nodes_decel_travel_percentage, (identifier: Id, edit: MovementValueEdit), (smallvec![identifier], EditType::PathNodeDeceleration(edit))),
+        (path_nodes_curve, (identifier: Id, curves: Vec<(u8, Curve)>), (smallvec![identifier], EditType::PathNodesCurve(curves))),
+        (path_nodes_easing, (identifier: Id, easings: Vec<(u8, Easing)>), (smallvec![identifier], EditType::PathNodesEasing(easings))),
         (attach, (identifier: Id, attachment: Id), (smallvec![identifier], EditType::BrushAttachment(attachment))),
         (detach, (identifier: Id, attachment: Id), (smallvec![identifier], EditType::BrushDetachment(attachment))),
         (thing_draw, (identifier: Id, thing: ThingInstanceData), (smallvec![identifier], EditType::DrawnThing(thing.into()))),
@@ -264,6 +288,9 @@ impl EditsHistory
         (thing_spawn, (identifier: Id, thing: ThingInstanceData), (smallvec![identifier], EditType::ThingSpawn(thing.into()))),
         (thing_despawn, (identifier: Id, thing: ThingInstanceData), (smallvec![identifier], EditType::ThingDespawn(thing.into()))),
         (thing_change, (identifier: Id, thing: ThingId), (smallvec![identifier], EditType::ThingChange(thing))),
+        (thing_flags, (identifier: Id, flags: u32), (smallvec![identifier], EditType::ThingFlags(flags))),
+        (entity_color, (identifier: Id, color: Option<[u8; 3]>), (smallvec![identifier], EditType::EntityColor(color))),
+        (entity_groups, (identifier: Id, groups: Vec<u32>), (smallvec![identifier], EditType::EntityGroups(groups))),
         (texture, (identifier: Id, texture: Option<String>), (smallvec![identifier], EditType::TextureChange(texture))),
         (texture_removal, (identifier: Id, texture: TextureSettings), (smallvec![identifier], EditType::TextureRemoval(Some(texture)))),
         (texture_offset_x, (identifier: Id, value: f32), (smallvec![identifier], EditType::TextureOffsetX(value))),
@@ -278,6 +305,7 @@ impl EditsHistory
         (texture_angle, (identifier: Id, value: TextureRotation), (smallvec![identifier], EditType::TextureRotation(value))),
         (texture_rotation, (identifier: Id, value: TextureRotation), (smallvec![identifier], EditType::TextureRotation(value))),
         (texture_height, (identifier: Id, value: i8), (smallvec![identifier], EditType::TextureHeight(value))),
+        (texture_opacity, (identifier: Id, value: u8), (smallvec![identifier], EditType::TextureOpacity(value))),
         (sprite, (identifier: Id, value: TextureSpriteSet), (smallvec![identifier], EditType::SpriteToggle(value))),
         (texture_reset, (identifier: Id, value: TextureReset), (smallvec![identifier], EditType::TextureReset(value))),
         (animation, (identifier: Id, animation: Animation), (smallvec![identifier], EditType::AnimationChange(animation))),
@@ -299,8 +327,12 @@ impl EditsHistory
         (path_nodes_min_speed, MovementValueEdit),
         (path_nodes_accel_travel_percentage, MovementValueEdit),
         (path_nodes_decel_travel_percentage, MovementValueEdit),
+        (path_nodes_curve, Vec<(u8, Curve)>),
+        (path_nodes_easing, Vec<(u8, Easing)>),
         (sides_deletion, Vec<(Vec2, u8, bool)>),
         (thing_change, ThingId),
+        (thing_flags, u32),
+        (entity_color, Option<[u8; 3]>),
         (texture, Option<String>),
         (texture_removal, TextureSettings),
         (texture_offset_x, f32),
@@ -314,6 +346,7 @@ impl EditsHistory
         (texture_parallax_y, f32),
         (texture_angle, TextureRotation),
         (texture_height, i8),
+        (texture_opacity, u8),
         (texture_rotation, TextureRotation),
         (texture_reset, TextureReset),
         (animation, Animation),
@@ -410,6 +443,16 @@ impl EditsHistory
         self.push_onto_current_edit(identifiers, edit_type);
     }
 
+    /// Pushes the toggle of the editor only flag of the brushes with [`Id`]s in `identifiers`.
+    #[inline]
+    pub(in crate::map::editor::state) fn editor_only_toggle(
+        &mut self,
+        identifiers: impl IntoIterator<Item = Id>
+    )
+    {
+        self.push_with_amount_assertion(identifiers, EditType::EditorOnlyToggle);
+    }
+
     #[allow(clippy::missing_docs_in_private_items)]
     #[inline]
     pub(in crate::map::editor::state) fn brush_despawn(&mut self, brush: Brush, selected: bool)
@@ -462,6 +505,21 @@ impl EditsHistory
         self.push_onto_current_edit(identifiers, EditType::ThingMove(delta));
     }
 
+    #[allow(clippy::missing_docs_in_private_items)]
+    #[inline]
+    pub(in crate::map::editor::state) fn brush_move(
+        &mut self,
+        identifier: Id,
+        delta: Vec2,
+        move_texture: bool
+    )
+    {
+        self.push_onto_current_edit(
+            smallvec![identifier],
+            EditType::BrushMove(delta, move_texture)
+        );
+    }
+
     #[allow(clippy::missing_docs_in_private_items)]
     #[inline]
     pub(in crate::map::editor::state) fn thing_move(&mut self, identifier: Id, delta: Vec2)
@@ -541,6 +599,22 @@ impl EditsHistory
         self.current_edit.push_property(key, iter);
     }
 
+    #[allow(clippy::missing_docs_in_private_items)]
+    #[inline]
+    pub(in crate::map::editor::state) fn custom_property(
+        &mut self,
+        key: &str,
+        iter: impl IntoIterator<Item = (Id, Option<Value>)>
+    )
+    {
+        if self.selections_only_edit_halted
+        {
+            self.force_push_frame_edit();
+        }
+
+        self.current_edit.push_custom_property(key, iter);
+    }
+
     /// Pushes the current [`Edit`] on the history.
     /// The history is truncated if any edits were undone.
     /// # Panics
@@ -625,6 +699,13 @@ impl EditsHistory
             }
         }
 
+        if !self.current_edit.only_contains_selection_edits()
+        {
+            self.statistics.add_edit(self.current_edit.spawned_brushes());
+            self.events
+                .push(HistoryEvent::EditCommitted(self.current_edit.tag().to_owned()));
+        }
+
         self.stack.push(self.current_edit.take_value());
         self.index += 1;
     }
@@ -765,6 +846,24 @@ impl EditsHistory
         self.multiframe_edit = false;
     }
 
+    /// Returns the statistics of the work done on the map.
+    #[inline]
+    pub(in crate::map::editor::state) const fn statistics(&self) -> &Statistics { &self.statistics }
+
+    /// Sets the statistics of the previous editing sessions to `statistics`.
+    #[inline]
+    pub(in crate::map::editor::state) fn load_statistics(&mut self, statistics: MapStatistics)
+    {
+        self.statistics = Statistics::new(statistics);
+    }
+
+    /// Adds `delta_time` seconds to the time spent editing the map.
+    #[inline]
+    pub(in crate::map::editor::state) fn add_session_time(&mut self, delta_time: f32)
+    {
+        self.statistics.add_time(delta_time);
+    }
+
     /// Whether there are no unsaved edits.
     #[inline]
     #[must_use]
@@ -792,11 +891,28 @@ impl EditsHistory
         (idx..self.index).all(|i| self.stack[i].contains_free_draw_edit())
     }
 
-    /// Sets the current edit to be the one of the last save.
+    /// Sets the current edit to be the one of the last save, the one of the map saved at `path`.
     #[inline]
-    pub(in crate::map::editor::state) fn reset_last_save_edit(&mut self)
+    pub(in crate::map::editor::state) fn reset_last_save_edit(&mut self, path: PathBuf)
     {
         self.last_save_edit = self.index.into();
+        self.events.push(HistoryEvent::MapSaved(path));
+    }
+
+    /// Returns an iterator to the commits of edits and the saves that were not sent as events yet,
+    /// removing them.
+    #[inline]
+    pub(in crate::map::editor) fn drain_events(&mut self) -> std::vec::Drain<'_, HistoryEvent>
+    {
+        self.events.drain(..)
+    }
+
+    /// Moves the commits of edits and the saves of `other` that were not sent as events yet into
+    /// `self`.
+    #[inline]
+    pub(in crate::map::editor::state) fn take_events(&mut self, other: &mut Self)
+    {
+        self.events.append(&mut other.events);
     }
 
     #[inline]
@@ -805,6 +921,39 @@ impl EditsHistory
         self.current_edit.override_tag(tag);
     }
 
+    /// Executes `f` collapsing all the edits it pushes into a single [`Edit`] tagged `label`,
+    /// regardless of how many there are. Used by batch and programmatic operations so that they
+    /// can be undone in a single step. If there is an ongoing multiframe edit the edits are
+    /// simply collapsed into it.
+    #[inline]
+    pub(in crate::map::editor::state) fn with_group<T, F>(&mut self, label: &str, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T
+    {
+        if !self.concluded_edit()
+        {
+            let value = f(self);
+            self.override_edit_tag(label);
+            return value;
+        }
+
+        self.push_frame_edit();
+        // Selections whose push was halted are merged into the group.
+        self.selections_only_edit_halted = false;
+        self.multiframe_edit = true;
+        let len = self.current_edit.len();
+        let value = f(self);
+        self.multiframe_edit = false;
+
+        if self.current_edit.len() != len
+        {
+            self.override_edit_tag(label);
+            self.push_frame_edit();
+        }
+
+        value
+    }
+
     //=======================================================================//
     // Info
 
@@ -895,13 +1044,16 @@ impl EditsHistory
         self.index += 1;
     }
 
+    /// Shows the edits history, highlighting the entry of the current state of the map. Returns the
+    /// amount of edits the history should be rewound or fast forwarded to if an entry was clicked,
+    /// and the one associated with the hovered entry, if any.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn show(
         &self,
         ui: &mut egui::Ui,
         core: &Core
-    ) -> Option<usize>
+    ) -> (Option<usize>, Option<usize>)
     {
         #[inline]
         fn set_post_index_visuals(ui: &mut egui::Ui)
@@ -913,80 +1065,148 @@ impl EditsHistory
         // Cannot undo/redo.
         if !core.undo_redo_available()
         {
-            ui.add_enabled(false, egui::Button::new("Opened Map"));
+            ui.add_enabled(false, egui::Button::new("Opened map"));
 
             for ed in &self.stack
             {
                 ui.add_enabled(false, egui::Button::new(ed.tag()));
             }
 
-            return None;
+            return (None, None);
         }
 
-        // Clicked the first, no point in doing special handling.
-        if ui.add(egui::Button::new("Opened map")).clicked()
-        {
-            set_post_index_visuals(ui);
+        let mut clicked = None;
+        let mut hovered = None;
+        let current = self.index;
+        let mut button = |ui: &mut egui::Ui, tag: &str, index: usize| {
+            let response = match index.cmp(&current)
+            {
+                std::cmp::Ordering::Less =>
+                {
+                    ui.button(tag)
+                        .on_hover_text(format!("Undo {} edit(s)", current - index))
+                },
+                std::cmp::Ordering::Equal => ui.button(tag).highlight(),
+                std::cmp::Ordering::Greater =>
+                {
+                    ui.button(tag)
+                        .on_hover_text(format!("Redo {} edit(s)", index - current))
+                },
+            };
 
-            for edit in &self.stack
+            if response.hovered()
             {
-                _ = ui.button(edit.tag());
+                hovered = index.into();
             }
 
-            return 0.into();
-        }
-
-        let mut clicked = None;
-        let mut i = 0;
-
-        while i < self.index
-        {
-            if ui.button(self.stack[i].tag()).clicked()
+            if clicked.is_none() && response.clicked()
             {
-                clicked = (i + 1).into();
-                i += 1;
-                break;
+                clicked = index.into();
             }
+        };
 
-            i += 1;
-        }
+        button(ui, "Opened map", 0);
 
-        while i < self.index
+        for (i, ed) in self.stack.iter().enumerate().take(self.index)
         {
-            _ = ui.button(self.stack[i].tag());
-            i += 1;
+            button(ui, ed.tag(), i + 1);
         }
 
         set_post_index_visuals(ui);
 
-        // One button was clicked, quickly draw the others.
-        if clicked.is_some()
+        for (i, ed) in self.stack.iter().enumerate().skip(self.index)
+        {
+            button(ui, ed.tag(), i + 1);
+        }
+
+        (clicked, hovered)
+    }
+
+    /// Draws semitransparent outlines of the entities as they were after the first `index` edits,
+    /// previewing the result of undoing the edits that followed.
+    #[inline]
+    pub(in crate::map::editor::state) fn draw_undo_preview(
+        &self,
+        drawer: &mut EditDrawer,
+        manager: &EntitiesManager,
+        things_catalog: &ThingsCatalog,
+        index: usize
+    )
+    {
+        /// Draws the closed outline described by `vertexes`.
+        #[inline]
+        fn outline(drawer: &mut EditDrawer, vertexes: &[Vec2], color: Color)
         {
-            for ed in self.stack.iter().skip(self.index)
+            for (a, b) in vertexes.iter().zip(vertexes.iter().cycle().skip(1))
             {
-                _ = ui.button(ed.tag());
+                drawer.semitransparent_line(*a, *b, color);
             }
         }
-        else
+
+        if index >= self.index
         {
-            // No button clicked, check the remaining ones.
-            let mut iter = self.stack.iter().enumerate().skip(self.index);
+            return;
+        }
 
-            for (i, ed) in &mut iter
-            {
-                if ui.button(ed.tag()).clicked()
-                {
-                    clicked = (i + 1).into();
-                    break;
-                }
-            }
+        let mut ghosts = hash_map![];
+
+        for edit in self.stack[index..self.index].iter().rev()
+        {
+            edit.undo_preview(manager, things_catalog, &mut ghosts);
+        }
 
-            for (_, ed) in iter
+        for (id, ghost) in ghosts
+        {
+            match ghost
             {
-                _ = ui.button(ed.tag());
-            }
+                Some(vertexes) =>
+                {
+                    outline(drawer, &vertexes, Color::HighlightedSelectedEntity);
+                },
+                None if manager.entity_exists(id) =>
+                {
+                    // The entity will be removed by the undo.
+                    outline(
+                        drawer,
+                        &current_outline(manager, things_catalog, id).unwrap(),
+                        Color::ErrorHighlight
+                    );
+                },
+                None => ()
+            };
         }
+    }
+}
 
-        clicked
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Returns the outline of the entity with [`Id`] `identifier` as it currently is on the map, if it
+/// exists.
+#[inline]
+#[must_use]
+fn current_outline(
+    manager: &EntitiesManager,
+    things_catalog: &ThingsCatalog,
+    identifier: Id
+) -> Option<Vec<Vec2>>
+{
+    if !manager.entity_exists(identifier)
+    {
+        return None;
     }
+
+    if manager.is_thing(identifier)
+    {
+        return manager
+            .thing(identifier)
+            .thing_hull(things_catalog)
+            .vertexes()
+            .collect::<Vec<_>>()
+            .into();
+    }
+
+    manager.brush(identifier).vertexes().collect::<Vec<_>>().into()
 }
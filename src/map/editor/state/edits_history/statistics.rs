@@ -0,0 +1,104 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use serde::{Deserialize, Serialize};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The statistics of the work done on a map.
+#[must_use]
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+pub(in crate::map::editor::state) struct MapStatistics
+{
+    /// The time spent editing the map, in seconds.
+    time_spent:      f64,
+    /// The amount of edits made.
+    edits:           u64,
+    /// The amount of brushes created.
+    brushes_created: u64
+}
+
+impl MapStatistics
+{
+    /// The time spent editing the map, in seconds.
+    #[inline]
+    #[must_use]
+    pub const fn time_spent(&self) -> f64 { self.time_spent }
+
+    /// The amount of edits made.
+    #[inline]
+    #[must_use]
+    pub const fn edits(&self) -> u64 { self.edits }
+
+    /// The amount of brushes created.
+    #[inline]
+    #[must_use]
+    pub const fn brushes_created(&self) -> u64 { self.brushes_created }
+}
+
+//=======================================================================//
+
+/// The statistics of the current editing session along with the ones accumulated by the map in
+/// all the previous sessions.
+#[must_use]
+#[derive(Clone, Copy, Default)]
+pub(in crate::map::editor::state) struct Statistics
+{
+    /// The statistics stored in the map file when it was opened.
+    previous: MapStatistics,
+    /// The statistics of the current session.
+    session:  MapStatistics
+}
+
+impl Statistics
+{
+    /// Returns new [`Statistics`] accumulating the ones of the current session on top of
+    /// `previous`.
+    #[inline]
+    pub const fn new(previous: MapStatistics) -> Self
+    {
+        Self {
+            previous,
+            session: MapStatistics {
+                time_spent:      0f64,
+                edits:           0,
+                brushes_created: 0
+            }
+        }
+    }
+
+    /// The statistics of the current session.
+    #[inline]
+    pub const fn session(&self) -> MapStatistics { self.session }
+
+    /// The statistics of all the sessions, current one included.
+    #[inline]
+    pub fn total(&self) -> MapStatistics
+    {
+        MapStatistics {
+            time_spent:      self.previous.time_spent + self.session.time_spent,
+            edits:           self.previous.edits + self.session.edits,
+            brushes_created: self.previous.brushes_created + self.session.brushes_created
+        }
+    }
+
+    /// Adds `delta_time` seconds to the time spent editing the map.
+    #[inline]
+    pub fn add_time(&mut self, delta_time: f32)
+    {
+        self.session.time_spent += f64::from(delta_time);
+    }
+
+    /// Adds an edit that created `brushes` brushes.
+    #[inline]
+    pub fn add_edit(&mut self, brushes: usize)
+    {
+        self.session.edits += 1;
+        self.session.brushes_created += brushes as u64;
+    }
+}
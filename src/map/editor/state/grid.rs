@@ -31,6 +31,20 @@ enum Change
     Changed
 }
 
+//=======================================================================//
+
+/// The grid lines along which the brushes are sliced.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(in crate::map::editor::state) enum GridSlice
+{
+    /// Both the vertical and horizontal lines, generating cell sized pieces.
+    Cells,
+    /// Only the vertical lines, generating columns.
+    Columns,
+    /// Only the horizontal lines, generating rows.
+    Rows
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -113,7 +127,7 @@ impl Grid
     }
 
     #[inline]
-    pub(in crate::map::editor::state) const fn with_size(&self, size: i16) -> Self
+    pub(in crate::map::editor) const fn with_size(&self, size: i16) -> Self
     {
         let mut grid = *self;
         grid.size = size;
@@ -466,6 +480,59 @@ impl Grid
         Hull::new(top, bottom, left, right).unwrap()
     }
 
+    //==============================================================
+    // Slice
+
+    /// Returns the grid lines selected by `slice` that cross the area encompassing `points`.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn slicing_lines(
+        &self,
+        points: impl Iterator<Item = Vec2>,
+        slice: GridSlice
+    ) -> Vec<[Vec2; 2]>
+    {
+        /// Returns the coordinates of the grid lines strictly between `min` and `max`.
+        #[inline]
+        fn coordinates(min: f32, max: f32, size: f32, offset: f32) -> impl Iterator<Item = f32>
+        {
+            let first = (((min - offset) / size).floor() + 1f32).mul_add(size, offset);
+            std::iter::successors(Some(first), move |c| Some(c + size))
+                .take_while(move |c| *c < max)
+        }
+
+        let size = self.size_f32();
+        let offset = if self.shifted { size / 2f32 } else { 0f32 };
+        let (min, max) = points
+            .map(|p| self.point_projection(p))
+            .fold((Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)), |(min, max), p| {
+                (min.min(p), max.max(p))
+            });
+        let mut lines = Vec::new();
+
+        if slice != GridSlice::Rows
+        {
+            lines.extend(coordinates(min.x, max.x, size, offset).map(|x| {
+                [
+                    self.transform_point(Vec2::new(x, 0f32)),
+                    self.transform_point(Vec2::new(x, 1f32))
+                ]
+            }));
+        }
+
+        if slice != GridSlice::Columns
+        {
+            lines.extend(coordinates(min.y, max.y, size, offset).map(|y| {
+                [
+                    self.transform_point(Vec2::new(0f32, y)),
+                    self.transform_point(Vec2::new(1f32, y))
+                ]
+            }));
+        }
+
+        lines
+    }
+
     //==============================================================
     // Draw
 
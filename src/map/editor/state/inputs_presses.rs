@@ -11,10 +11,21 @@ use glam::Vec2;
 use hill_vacuum_shared::return_if_none;
 
 use crate::{
-    config::controls::{bind::Bind, BindsKeyCodes},
+    config::{
+        controls::{bind::Bind, BindsKeyCodes},
+        double_click::DoubleClickSettings
+    },
     HardcodedActions
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum distance, in window pixels, between the two clicks of a double-click.
+const DOUBLE_CLICK_DISTANCE: f32 = 4f32;
+
 //=======================================================================//
 // MACROS
 //
@@ -33,7 +44,10 @@ macro_rules! input_presses {
 		{
 			$(pub(in crate::map::editor::state) $name: $input_type,)+
             directional_keys_vector: Option<Vec2>,
-            view_directional_keys_vector: Option<Vec2>
+            view_directional_keys_vector: Option<Vec2>,
+            cursor_directional_keys_vector: Option<Vec2>,
+            last_click: Option<(f32, Vec2)>,
+            double_click: Option<DoubleClickSettings>
 		}
 
         impl Default for InputsPresses
@@ -44,7 +58,10 @@ macro_rules! input_presses {
 				Self {
 					$($name: <$input_type>::new($key),)+
                     directional_keys_vector: None,
-                    view_directional_keys_vector: None
+                    view_directional_keys_vector: None,
+                    cursor_directional_keys_vector: None,
+                    last_click: None,
+                    double_click: None
 				}
 			}
         }
@@ -63,7 +80,7 @@ macro_rules! input_presses {
 			{
                 #[inline]
                 #[must_use]
-                pub fn directional_keys_vector(inputs: &InputsPresses, grid_size: i16) -> Option<Vec2>
+                pub fn directional_keys_vector(inputs: &InputsPresses) -> Option<Vec2>
                 {
                     let mut dir = Vec2::ZERO;
 
@@ -87,21 +104,33 @@ macro_rules! input_presses {
                         dir.y -= 1f32;
                     }
 
-                    (dir != Vec2::ZERO).then(|| dir * f32::from(grid_size))
+                    (dir != Vec2::ZERO).then_some(dir)
                 }
 
 				$(self.$name.update($source $(, &config.$binds)?);)+
 
-                let dir = directional_keys_vector(self, grid_size);
+                // The keyboard click acts as the left mouse button.
+                if !self.left_mouse.pressed()
+                {
+                    self.left_mouse.state = self.click.state;
+                }
+
+                let dir = directional_keys_vector(self).map(|dir| dir * f32::from(grid_size));
+                self.directional_keys_vector = None;
+                self.view_directional_keys_vector = None;
+                self.cursor_directional_keys_vector = None;
 
                 if self.ctrl_pressed()
                 {
-                    self.directional_keys_vector = None;
                     self.view_directional_keys_vector = dir;
                 }
+                else if self.shift_pressed()
+                {
+                    self.cursor_directional_keys_vector =
+                        dir.map(|dir| dir * f32::from(config.cursor_step));
+                }
                 else
                 {
-                    self.view_directional_keys_vector = None;
                     self.directional_keys_vector = dir;
                 }
 			}
@@ -127,6 +156,7 @@ macro_rules! input_presses {
                 self.right.clear();
                 self.up.clear();
                 self.down.clear();
+                self.click.clear();
             }
 		}
 	);
@@ -138,7 +168,7 @@ macro_rules! input_presses {
 //=======================================================================//
 
 /// The state of the button of an I/O device.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 enum State
 {
     /// Not pressed.
@@ -175,13 +205,24 @@ input_presses!(
     (right_mouse, InputStateHardCoded<MouseButton>, MouseButton::Right, mouse_buttons),
     (esc, InputStateHardCoded<KeyCode>, KeyCode::Escape, key_inputs),
     (f4, InputStateHardCoded<KeyCode>, KeyCode::F4, key_inputs),
+    (digit_0, InputStateHardCoded<KeyCode>, KeyCode::Digit0, key_inputs),
+    (digit_1, InputStateHardCoded<KeyCode>, KeyCode::Digit1, key_inputs),
+    (digit_2, InputStateHardCoded<KeyCode>, KeyCode::Digit2, key_inputs),
+    (digit_3, InputStateHardCoded<KeyCode>, KeyCode::Digit3, key_inputs),
+    (digit_4, InputStateHardCoded<KeyCode>, KeyCode::Digit4, key_inputs),
+    (digit_5, InputStateHardCoded<KeyCode>, KeyCode::Digit5, key_inputs),
+    (digit_6, InputStateHardCoded<KeyCode>, KeyCode::Digit6, key_inputs),
+    (digit_7, InputStateHardCoded<KeyCode>, KeyCode::Digit7, key_inputs),
+    (digit_8, InputStateHardCoded<KeyCode>, KeyCode::Digit8, key_inputs),
+    (digit_9, InputStateHardCoded<KeyCode>, KeyCode::Digit9, key_inputs),
     (copy, InputStateHardCoded<KeyCode>, HardcodedActions::Copy.key(), key_inputs),
     (paste, InputStateHardCoded<KeyCode>, HardcodedActions::Paste.key(), key_inputs),
     (cut, InputStateHardCoded<KeyCode>, HardcodedActions::Cut.key(), key_inputs),
     (left, InputState, Bind::Left, key_inputs, binds),
     (right, InputState, Bind::Right, key_inputs, binds),
     (up, InputState, Bind::Up, key_inputs, binds),
-    (down, InputState, Bind::Down, key_inputs, binds)
+    (down, InputState, Bind::Down, key_inputs, binds),
+    (click, InputState, Bind::Click, key_inputs, binds)
 );
 
 impl InputsPresses
@@ -206,6 +247,27 @@ impl InputsPresses
     #[must_use]
     pub const fn space_pressed(&self) -> bool { self.space.pressed() }
 
+    /// Returns the digit whose key was just pressed, if any.
+    #[inline]
+    #[must_use]
+    pub fn just_pressed_digit(&self) -> Option<usize>
+    {
+        [
+            &self.digit_0,
+            &self.digit_1,
+            &self.digit_2,
+            &self.digit_3,
+            &self.digit_4,
+            &self.digit_5,
+            &self.digit_6,
+            &self.digit_7,
+            &self.digit_8,
+            &self.digit_9
+        ]
+        .into_iter()
+        .position(InputStateHardCoded::just_pressed)
+    }
+
     /// Whether the copy key combo was just pressed.
     #[inline]
     #[must_use]
@@ -227,6 +289,62 @@ impl InputsPresses
     #[must_use]
     pub const fn cut_just_pressed(&self) -> bool { self.ctrl_pressed() && self.cut.just_pressed() }
 
+    /// Updates the double-click state based on the press of the left mouse button at
+    /// `elapsed_time` with the cursor at the window position `cursor_pos`.
+    #[inline]
+    pub(in crate::map::editor::state) fn update_double_click(
+        &mut self,
+        elapsed_time: f32,
+        cursor_pos: Vec2,
+        settings: DoubleClickSettings
+    )
+    {
+        self.double_click = None;
+
+        if !self.left_mouse.just_pressed()
+        {
+            return;
+        }
+
+        match self.last_click.take()
+        {
+            Some((time, pos))
+                if elapsed_time - time <= settings.seconds() &&
+                    pos.distance(cursor_pos) <= DOUBLE_CLICK_DISTANCE =>
+            {
+                self.double_click = settings.into();
+            },
+            _ => self.last_click = (elapsed_time, cursor_pos).into()
+        }
+    }
+
+    /// Whether the left mouse button was just double-clicked and the double-click action
+    /// enabled in the [`DoubleClickSettings`] by `f` should be executed.
+    #[inline]
+    #[must_use]
+    fn double_clicked<F: Fn(&DoubleClickSettings) -> bool>(&self, f: F) -> bool
+    {
+        self.double_click.as_ref().is_some_and(f)
+    }
+
+    /// Whether the brushes with the same texture as the double-clicked one should be selected.
+    #[inline]
+    #[must_use]
+    pub fn double_click_texture(&self) -> bool { self.double_clicked(|settings| settings.texture) }
+
+    /// Whether the numeric editor of the double-clicked path node should be focused.
+    #[inline]
+    #[must_use]
+    pub fn double_click_node(&self) -> bool { self.double_clicked(|settings| settings.node) }
+
+    /// Whether everything should be deselected if an empty spot was double-clicked.
+    #[inline]
+    #[must_use]
+    pub fn double_click_deselect(&self) -> bool
+    {
+        self.double_clicked(|settings| settings.deselect)
+    }
+
     #[inline]
     #[must_use]
     pub const fn directional_keys_delta(&self) -> Option<Vec2> { self.directional_keys_vector }
@@ -237,6 +355,14 @@ impl InputsPresses
     {
         self.view_directional_keys_vector
     }
+
+    /// The amount the cursor should be moved by the directional keys, if any.
+    #[inline]
+    #[must_use]
+    pub const fn directional_keys_cursor_delta(&self) -> Option<Vec2>
+    {
+        self.cursor_directional_keys_vector
+    }
 }
 
 //=======================================================================//
@@ -0,0 +1,119 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use crate::warning_message;
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The problems found while loading a map file.
+#[must_use]
+#[derive(Clone, Copy, Default)]
+pub(in crate::map::editor::state) struct IntegrityReport
+{
+    /// Whether the file ended before all of its content could be read.
+    truncated:            bool,
+    /// The amount of entities discarded because their [`Id`](crate::Id) was already in use.
+    duplicate_ids:        usize,
+    /// The amount of references to attached brushes that do not exist.
+    dangling_attachments: usize
+}
+
+impl IntegrityReport
+{
+    /// Whether the file ended before all of its content could be read.
+    #[inline]
+    #[must_use]
+    pub const fn truncated(&self) -> bool { self.truncated }
+
+    /// Marks the file as truncated.
+    #[inline]
+    pub fn set_truncated(&mut self) { self.truncated = true; }
+
+    /// Reports an entity discarded because its [`Id`](crate::Id) was already in use.
+    #[inline]
+    pub fn push_duplicate_id(&mut self) { self.duplicate_ids += 1; }
+
+    /// Reports a reference to an attached brush that does not exist.
+    #[inline]
+    pub fn push_dangling_attachment(&mut self) { self.dangling_attachments += 1; }
+
+    /// Whether no problems were found.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool
+    {
+        !self.truncated && self.duplicate_ids == 0 && !self.has_dangling_references()
+    }
+
+    /// Whether there are references to entities that do not exist.
+    #[inline]
+    #[must_use]
+    pub const fn has_dangling_references(&self) -> bool { self.dangling_attachments != 0 }
+
+    /// Asks whether the dangling references should be removed. Returns true if the user agreed,
+    /// false if the loading should be cancelled.
+    #[inline]
+    #[must_use]
+    pub fn confirm_repair(&self) -> bool
+    {
+        let mut description =
+            "The map file contains references to things that do not exist:\n".to_owned();
+
+        if self.dangling_attachments != 0
+        {
+            description.push_str(&format!(
+                "- {} attachments to missing brushes;\n",
+                self.dangling_attachments
+            ));
+        }
+
+        description.push_str(
+            "\nThe attachments would be removed.\n- Press YES to fix them and load the map;\n- \
+             Press NO to cancel the loading."
+        );
+
+        matches!(
+            rfd::MessageDialog::new()
+                .set_level(rfd::MessageLevel::Warning)
+                .set_title("WARNING")
+                .set_description(&description)
+                .set_buttons(rfd::MessageButtons::YesNo)
+                .show(),
+            rfd::MessageDialogResult::Yes
+        )
+    }
+
+    /// Shows a summary of the damage found in the file, if any.
+    #[inline]
+    pub fn warn_damage(&self)
+    {
+        if !self.truncated && self.duplicate_ids == 0
+        {
+            return;
+        }
+
+        let mut description = "The map file is damaged:\n".to_owned();
+
+        if self.truncated
+        {
+            description
+                .push_str("- the file ends prematurely, only its first part could be loaded;\n");
+        }
+
+        if self.duplicate_ids != 0
+        {
+            description.push_str(&format!(
+                "- {} entities shared the id of another one and have been removed;\n",
+                self.duplicate_ids
+            ));
+        }
+
+        description.push_str("\nBe careful before saving the file.");
+        warning_message(&description);
+    }
+}
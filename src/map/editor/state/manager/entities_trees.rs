@@ -5,7 +5,7 @@
 
 use std::sync::{RwLock, RwLockReadGuard};
 
-use bevy::{transform::components::Transform, window::Window};
+use bevy::{tasks::ComputeTaskPool, transform::components::Transform, window::Window};
 use glam::Vec2;
 
 use super::quad_tree::InsertResult;
@@ -51,6 +51,20 @@ macro_rules! visible_iters {
     )+}}
 }
 
+//=======================================================================//
+
+/// Spawns the tasks updating the visible `entities` in `scope`.
+macro_rules! spawn_visible_updates {
+    ($trees:ident, $scope:ident, $viewport:ident, $($entities:ident),+) => { paste::paste! { $(
+        $scope.spawn(async move {
+            $trees.[< visible_ $entities >].write().unwrap().update_with_viewport($viewport, |ids, viewport| {
+                $trees.[< $entities _tree >]
+                    .entities_intersect_range(ids, viewport);
+            });
+        });
+    )+}}
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -125,13 +139,30 @@ impl Trees
         }
     }
 
+    /// Updates the [`Id`]s of all the visible entities, culling each kind of entity in a separate
+    /// task of the [`ComputeTaskPool`].
+    /// The following calls to the `visible_` functions with the same viewport return the cached
+    /// values.
+    #[inline]
+    pub fn update_visible_ids(&self, camera: &Transform, window: &Window, grid: &Grid)
+    {
+        let trees = self;
+        let viewport = &camera.viewport(window, grid);
+
+        ComputeTaskPool::get().scope(|scope| {
+            spawn_visible_updates!(
+                trees, scope, viewport, brushes, paths, sprites, anchors, things
+            );
+        });
+    }
+
     /// Inserts the anchor [`Hull`] of `brush`.
     #[inline]
     #[must_use]
     pub fn insert_anchor_hull(&mut self, brush: &Brush, hull: &Hull) -> InsertResult
     {
-        self.set_anchors_dirty();
-        self.anchors_tree.insert_entity(brush, |_| *hull)
+        let result = self.anchors_tree.insert_entity(brush, |_| *hull);
+        hull_inserted(&self.anchors_tree, None, &self.visible_anchors, brush.id(), result)
     }
 
     /// Removes the anchor [`Hull`] of the `brush`.
@@ -139,8 +170,8 @@ impl Trees
     #[must_use]
     pub fn remove_anchor_hull(&mut self, brush: &Brush) -> bool
     {
-        self.set_anchors_dirty();
-        self.anchors_tree.remove_entity(brush)
+        let removed = self.anchors_tree.remove_entity(brush);
+        hull_removed(None, &self.visible_anchors, brush.id(), removed)
     }
 
     /// Inserts the [`Hull`] of `brush`.
@@ -148,8 +179,14 @@ impl Trees
     #[must_use]
     pub fn insert_brush_hull(&mut self, brush: &Brush) -> InsertResult
     {
-        self.set_brushes_dirty();
-        self.brushes_tree.insert_entity(brush, Brush::polygon_hull)
+        let result = self.brushes_tree.insert_entity(brush, Brush::polygon_hull);
+        hull_inserted(
+            &self.brushes_tree,
+            Some(&self.brushes_at_pos),
+            &self.visible_brushes,
+            brush.id(),
+            result
+        )
     }
 
     /// Removes the [`Hull`] of `brush`.
@@ -157,8 +194,8 @@ impl Trees
     #[must_use]
     pub fn remove_brush_hull(&mut self, brush: &Brush) -> bool
     {
-        self.set_brushes_dirty();
-        self.brushes_tree.remove_entity(brush)
+        let removed = self.brushes_tree.remove_entity(brush);
+        hull_removed(Some(&self.brushes_at_pos), &self.visible_brushes, brush.id(), removed)
     }
 
     /// Inserts the [`Path`] [`Hull`] of `entity`.
@@ -166,9 +203,16 @@ impl Trees
     #[must_use]
     pub fn insert_path_hull<T: EntityId + Moving>(&mut self, entity: &T) -> InsertResult
     {
-        self.set_paths_dirty();
-        self.paths_tree
-            .insert_entity(entity, |entity| entity.path_hull().unwrap())
+        let result = self
+            .paths_tree
+            .insert_entity(entity, |entity| entity.path_hull().unwrap());
+        hull_inserted(
+            &self.paths_tree,
+            Some(&self.paths_at_pos),
+            &self.visible_paths,
+            entity.id(),
+            result
+        )
     }
 
     /// Removes the [`Path`] [`Hull`] of `entity`.
@@ -176,8 +220,8 @@ impl Trees
     #[must_use]
     pub fn remove_path_hull<T: EntityId + ?Sized>(&mut self, entity: &T) -> bool
     {
-        self.set_paths_dirty();
-        self.paths_tree.remove_entity(entity)
+        let removed = self.paths_tree.remove_entity(entity);
+        hull_removed(Some(&self.paths_at_pos), &self.visible_paths, entity.id(), removed)
     }
 
     #[inline]
@@ -189,6 +233,8 @@ impl Trees
     )
     {
         self.sprites_tree.clear();
+        self.sprites_at_pos.write().unwrap().set_dirty();
+        self.visible_sprites.write().unwrap().set_dirty();
 
         for brush in iter
         {
@@ -209,9 +255,16 @@ impl Trees
         brush: &Brush
     ) -> InsertResult
     {
-        self.set_sprites_dirty();
-        self.sprites_tree
-            .insert_entity(brush, |brush| brush.sprite_and_anchor_hull(resources, grid).unwrap())
+        let result = self
+            .sprites_tree
+            .insert_entity(brush, |brush| brush.sprite_and_anchor_hull(resources, grid).unwrap());
+        hull_inserted(
+            &self.sprites_tree,
+            Some(&self.sprites_at_pos),
+            &self.visible_sprites,
+            brush.id(),
+            result
+        )
     }
 
     /// Removes the [`Hull`] of the sprite of `brush`.
@@ -219,8 +272,8 @@ impl Trees
     #[must_use]
     pub fn remove_sprite_hull(&mut self, brush: &Brush) -> bool
     {
-        self.set_sprites_dirty();
-        self.sprites_tree.remove_entity(brush)
+        let removed = self.sprites_tree.remove_entity(brush);
+        hull_removed(Some(&self.sprites_at_pos), &self.visible_sprites, brush.id(), removed)
     }
 
     /// Inserts the [`Hull`] of `thing`.
@@ -232,9 +285,16 @@ impl Trees
         thing: &ThingInstance
     ) -> InsertResult
     {
-        self.set_things_dirty();
-        self.things_tree
-            .insert_entity(thing, |thing| thing.thing_hull(things_catalog))
+        let result = self
+            .things_tree
+            .insert_entity(thing, |thing| thing.thing_hull(things_catalog));
+        hull_inserted(
+            &self.things_tree,
+            Some(&self.things_at_pos),
+            &self.visible_things,
+            thing.id(),
+            result
+        )
     }
 
     /// Removes the [`Hull`] of `thing`.
@@ -242,46 +302,10 @@ impl Trees
     #[must_use]
     pub fn remove_thing_hull(&mut self, thing: &ThingInstance) -> bool
     {
-        self.set_things_dirty();
-        self.things_tree.remove_entity(thing)
-    }
-
-    /// Marks the brush [`DirtyQuadTreeIdsNearPos`]es as dirty.
-    #[inline]
-    fn set_brushes_dirty(&mut self)
-    {
-        self.brushes_at_pos.write().unwrap().set_dirty();
-        self.visible_brushes.write().unwrap().set_dirty();
+        let removed = self.things_tree.remove_entity(thing);
+        hull_removed(Some(&self.things_at_pos), &self.visible_things, thing.id(), removed)
     }
 
-    /// Marks the paths [`DirtyQuadTreeIdsNearPos`]es as dirty.
-    #[inline]
-    fn set_paths_dirty(&mut self)
-    {
-        self.paths_at_pos.write().unwrap().set_dirty();
-        self.visible_paths.write().unwrap().set_dirty();
-    }
-
-    /// Marks the sprites [`DirtyQuadTreeIdsNearPos`]es as dirty.
-    #[inline]
-    fn set_sprites_dirty(&mut self)
-    {
-        self.sprites_at_pos.write().unwrap().set_dirty();
-        self.visible_sprites.write().unwrap().set_dirty();
-    }
-
-    /// Marks the things [`DirtyQuadTreeIdsNearPos`]es as dirty.
-    #[inline]
-    fn set_things_dirty(&mut self)
-    {
-        self.things_at_pos.write().unwrap().set_dirty();
-        self.visible_things.write().unwrap().set_dirty();
-    }
-
-    /// Sets the attachments [`DirtyQuadTreeIdsNearPos`] as dirty.
-    #[inline]
-    pub fn set_anchors_dirty(&mut self) { self.visible_anchors.write().unwrap().set_dirty(); }
-
     /// Stores the [`Id`]s of the brushes at `cursor_pos` (or near it if `camera_scale` contains
     /// a value) and returns their container.
     #[inline]
@@ -495,6 +519,30 @@ impl VisibleQuadTreeIds
     #[inline]
     fn set_dirty(&mut self) { self.dirty = true; }
 
+    /// Updates the visibility of the entity with [`Id`] `identifier` and [`Hull`] `hull` without
+    /// querying the whole tree again, unless a full update is already pending.
+    #[inline]
+    fn insert(&mut self, identifier: Id, hull: &Hull)
+    {
+        if self.dirty
+        {
+            return;
+        }
+
+        if self.last_viewport.overlaps(hull)
+        {
+            self.ids.insert(identifier, hull);
+        }
+        else
+        {
+            self.ids.remove(identifier);
+        }
+    }
+
+    /// Removes the entity with [`Id`] `identifier` from the visible ones.
+    #[inline]
+    fn remove(&mut self, identifier: Id) { self.ids.remove(identifier); }
+
     /// Updates the contained [`Id`]s if necessary.
     #[inline]
     fn update<F: FnOnce(&mut QuadTreeIds, &Hull)>(
@@ -505,17 +553,82 @@ impl VisibleQuadTreeIds
         f: F
     )
     {
-        let viewport = camera.viewport(window, grid);
+        self.update_with_viewport(&camera.viewport(window, grid), f);
+    }
 
-        if !self.dirty && self.last_viewport.around_equal_narrow(&viewport)
+    /// Updates the contained [`Id`]s if necessary, based on the already computed `viewport`.
+    #[inline]
+    fn update_with_viewport<F: FnOnce(&mut QuadTreeIds, &Hull)>(&mut self, viewport: &Hull, f: F)
+    {
+        if !self.dirty && self.last_viewport.around_equal_narrow(viewport)
         {
             return;
         }
 
-        self.last_viewport = viewport;
+        self.last_viewport = *viewport;
         self.ids.clear();
 
         f(&mut self.ids, &self.last_viewport);
         self.dirty = false;
     }
 }
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Updates the cached [`Id`]s after the [`Hull`] of the entity with [`Id`] `identifier` has been
+/// inserted in `tree` with outcome `result`. The visible [`Id`]s are edited in place, whereas the
+/// [`Id`]s at the cursor position are marked dirty since they are cheap to query again.
+#[inline]
+fn hull_inserted(
+    tree: &QuadTree,
+    at_pos: Option<&RwLock<QuadTreeIdsNearPos>>,
+    visible: &RwLock<VisibleQuadTreeIds>,
+    identifier: Id,
+    result: InsertResult
+) -> InsertResult
+{
+    if matches!(result, InsertResult::Unchanged)
+    {
+        return result;
+    }
+
+    if let Some(at_pos) = at_pos
+    {
+        at_pos.write().unwrap().set_dirty();
+    }
+
+    visible
+        .write()
+        .unwrap()
+        .insert(identifier, tree.hull(identifier).unwrap());
+    result
+}
+
+//=======================================================================//
+
+/// Updates the cached [`Id`]s after the removal of the [`Hull`] of the entity with [`Id`]
+/// `identifier`, if `removed` is true. Returns `removed`.
+#[inline]
+fn hull_removed(
+    at_pos: Option<&RwLock<QuadTreeIdsNearPos>>,
+    visible: &RwLock<VisibleQuadTreeIds>,
+    identifier: Id,
+    removed: bool
+) -> bool
+{
+    if !removed
+    {
+        return false;
+    }
+
+    if let Some(at_pos) = at_pos
+    {
+        at_pos.write().unwrap().set_dirty();
+    }
+
+    visible.write().unwrap().remove(identifier);
+    true
+}
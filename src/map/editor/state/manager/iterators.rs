@@ -53,9 +53,13 @@ macro_rules! brushes_iter {
                 Self(manager, ids)
             }
 
-            /// Returns an iterator to the brushes whose [`Id`] are contained in `self`.
+            /// Returns an iterator to the brushes whose [`Id`] are contained in `self`, except the
+            /// hidden editor only ones.
             #[inline]
-            pub fn iter(&self) -> impl Iterator<Item = &Brush> { self.1.ids().map(|id| self.0.brush(*id)) }
+            pub fn iter(&self) -> impl Iterator<Item = &Brush>
+            {
+                self.1.ids().map(|id| self.0.brush(*id)).filter(|brush| self.0.brush_shown(brush))
+            }
         }
     )+}};
 }
@@ -38,10 +38,11 @@ use self::{
 use super::{
     clipboard::{Clipboard, ClipboardData, CopyToClipboard},
     core::Core,
-    editor_state::ToolsSettings,
+    editor_state::{PasteSnap, ToolsSettings},
     edits_history::EditsHistory,
     grid::Grid,
     inputs_presses::InputsPresses,
+    integrity::IntegrityReport,
     ui::Ui
 };
 use crate::{
@@ -60,8 +61,8 @@ use crate::{
             TextureSize
         },
         editor::{
+            events::EntityEvent,
             state::{editor_state::TargetSwitch, manager::quad_tree::QuadTreeIds},
-            StateUpdateBundle,
             ToolUpdateBundle
         },
         path::{EditPath, MovementSimulator, Moving, Path},
@@ -73,13 +74,22 @@ use crate::{
             EngineDefaultBrushProperties,
             EngineDefaultProperties,
             EngineDefaultThingProperties,
-            PropertiesRefactor
+            PropertiesRefactor,
+            TARGETNAME_LABEL,
+            TARGET_LABEL
+        },
+        thing::{
+            catalog::ThingsCatalog,
+            ThingId,
+            ThingInstance,
+            ThingInstanceData,
+            ThingInterface
         },
-        thing::{catalog::ThingsCatalog, ThingInstance, ThingInstanceData, ThingInterface},
         AssertedInsertRemove,
         FileStructure,
         HashMap,
         MapHeader,
+        MapMetadata,
         OutOfBounds,
         Viewer
     },
@@ -88,11 +98,20 @@ use crate::{
         hull::Hull,
         identifiers::{EntityCenter, EntityId, Id, IdGenerator},
         math::AroundEqual,
-        misc::{Blinker, ReplaceValues, TakeValue}
+        misc::{Blinker, PointInsideUiHighlight, ReplaceValues, SeededRandom, TakeValue, Toggle}
     },
-    warning_message
+    warning_message,
+    Value
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum amount of recently applied textures that are remembered.
+const RECENT_TEXTURES_AMOUNT: usize = 10;
+
 //=======================================================================//
 // TRAIT
 //
@@ -203,6 +222,19 @@ impl Entity<'_>
             Entity::Thing(thing) => thing.hull(things_catalog)
         }
     }
+
+    /// Returns the identifiers of the groups the entity belongs to, from the innermost to the
+    /// outermost.
+    #[inline]
+    #[must_use]
+    fn groups(&self) -> &[u32]
+    {
+        match self
+        {
+            Entity::Brush(brush) => brush.groups(),
+            Entity::Thing(thing) => thing.groups()
+        }
+    }
 }
 
 //=======================================================================//
@@ -468,6 +500,7 @@ impl ErrorHighlight
 
 /// A collection of [`Animator`] that animate the textures on screen during the map preview.
 #[must_use]
+#[derive(Default)]
 pub(in crate::map) struct Animators
 {
     brushes: HashMap<Id, Animator>,
@@ -479,16 +512,11 @@ impl Animators
     /// Returns a new [`Animators`].
     #[inline]
     pub(in crate::map::editor::state) fn new(
-        bundle: &StateUpdateBundle,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
         manager: &EntitiesManager
     ) -> Self
     {
-        let StateUpdateBundle {
-            drawing_resources,
-            things_catalog,
-            ..
-        } = bundle;
-
         let previews = manager
             .things()
             .filter_map(|thing| {
@@ -535,7 +563,11 @@ impl Animators
 
     /// Updates the contained [`Animator`]s based of the time that has passed since the last update.
     #[inline]
-    pub(in crate::map::editor::state) fn update(&mut self, bundle: &ToolUpdateBundle)
+    pub(in crate::map::editor::state) fn update(
+        &mut self,
+        bundle: &ToolUpdateBundle,
+        delta_time: f32
+    )
     {
         for (id, a) in &mut self.brushes
         {
@@ -551,7 +583,7 @@ impl Animators
                             .unwrap()
                             .overall_animation(bundle.drawing_resources)
                             .get_list_animation(),
-                        bundle.delta_time
+                        delta_time
                     );
                 },
                 Animator::Atlas(a) =>
@@ -564,7 +596,7 @@ impl Animators
                             .unwrap()
                             .overall_animation(bundle.drawing_resources)
                             .get_atlas_animation(),
-                        bundle.delta_time
+                        delta_time
                     );
                 }
             };
@@ -582,7 +614,7 @@ impl Animators
                             .texture_or_error(name)
                             .animation()
                             .get_list_animation(),
-                        bundle.delta_time
+                        delta_time
                     );
                 },
                 Animator::Atlas(a) =>
@@ -593,7 +625,7 @@ impl Animators
                             .texture_or_error(name)
                             .animation()
                             .get_atlas_animation(),
-                        bundle.delta_time
+                        delta_time
                     );
                 }
             };
@@ -633,6 +665,10 @@ struct Innards
     brushes_with_attachments: HashMap<Id, Hull>,
     /// The generator of the [`Id`]s of the new entities.
     id_generator: IdGenerator,
+    /// The [`Id`]s of the entities belonging to each group.
+    groups: HashMap<u32, Ids>,
+    /// The identifier of the next created group.
+    next_group: u32,
     /// The error drawer.
     error_highlight: ErrorHighlight,
     /// Whether the tool outline should be updated.
@@ -653,7 +689,9 @@ struct Innards
     /// Whether the overall properties of the [`ThingInstance`]s should be updated.
     overall_things_properties_update: PropertyUpdate,
     /// Whether the properties where refactored after loading a map file.
-    loaded_file_modified: bool
+    loaded_file_modified: bool,
+    /// The spawns and despawns of entities that were not sent as events yet.
+    entity_events: Vec<EntityEvent>
 }
 
 impl Innards
@@ -676,6 +714,8 @@ impl Innards
             selected_sprites: SelectedSprites::default(),
             brushes_with_attachments: hash_map![],
             id_generator: IdGenerator::default(),
+            groups: hash_map![],
+            next_group: 0,
             error_highlight: ErrorHighlight::new(),
             outline_update: false,
             selected_vertexes_update: hash_set![capacity; 10],
@@ -685,12 +725,16 @@ impl Innards
             overall_brushes_properties_update: PropertyUpdate::default(),
             overall_things_info_update: false,
             overall_things_properties_update: PropertyUpdate::default(),
-            loaded_file_modified: false
+            loaded_file_modified: false,
+            entity_events: Vec::new()
         }
     }
 
-    /// Reads the brushes and [`Thing`]s from `file`.
+    /// Reads the brushes and [`Thing`]s from `file`, storing in `report` the problems found.
+    /// Entities following a truncation of the file are not loaded, and dangling references are
+    /// removed if the user agrees.
     /// Returns an error if it occurred.
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(in crate::map::editor::state) fn load<I, D>(
         &mut self,
@@ -702,7 +746,8 @@ impl Innards
         engine_default_brush_properties: &EngineDefaultBrushProperties,
         engine_default_thing_properties: &EngineDefaultThingProperties,
         steps: &mut I,
-        quad_trees: &mut Trees
+        quad_trees: &mut Trees,
+        report: &mut IntegrityReport
     ) -> Result<(DefaultBrushProperties, DefaultThingProperties), &'static str>
     where
         I: Iterator<Item = FileStructure>,
@@ -763,6 +808,7 @@ impl Innards
         }
 
         let mut max_id = Id::ZERO;
+        let mut ids = hash_set![];
         let mut brushes = Vec::new();
         let mut with_attachments = Vec::new();
 
@@ -787,10 +833,21 @@ impl Innards
 
         for _ in 0..header.brushes
         {
-            let mut brush = Brush::from_viewer(
-                ciborium::from_reader::<BrushViewer, _>(&mut *file)
-                    .map_err(|_| "Error reading brushes")?
-            );
+            let mut brush = match ciborium::from_reader::<BrushViewer, _>(&mut *file)
+            {
+                Ok(brush) => Brush::from_viewer(brush),
+                Err(_) =>
+                {
+                    report.set_truncated();
+                    break;
+                }
+            };
+
+            if !ids.insert(brush.id())
+            {
+                report.push_duplicate_id();
+                continue;
+            }
 
             if brush.hull(drawing_resources, grid).out_of_bounds()
             {
@@ -838,10 +895,26 @@ impl Innards
 
         for _ in 0..header.things
         {
-            let thing = ThingInstance::from_viewer(
-                ciborium::from_reader::<ThingViewer, _>(&mut *file)
-                    .map_err(|_| "Error reading things")?
-            );
+            if report.truncated()
+            {
+                break;
+            }
+
+            let thing = match ciborium::from_reader::<ThingViewer, _>(&mut *file)
+            {
+                Ok(thing) => ThingInstance::from_viewer(thing),
+                Err(_) =>
+                {
+                    report.set_truncated();
+                    break;
+                }
+            };
+
+            if !ids.insert(thing.id())
+            {
+                report.push_duplicate_id();
+                continue;
+            }
 
             if thing.hull(things_catalog).out_of_bounds()
             {
@@ -866,6 +939,42 @@ impl Innards
             }
         }
 
+        // Look for references to attached brushes that do not exist, or that cannot be attached.
+        let mut attachable = brushes
+            .iter()
+            .filter_map(|brush| (!brush.has_path()).then_some(brush.id()))
+            .collect::<HashSet<_>>();
+        let mut dangling_attachments = Vec::new();
+
+        for brush in &with_attachments
+        {
+            for id in brush.attachments_iter().unwrap()
+            {
+                if !attachable.remove(id)
+                {
+                    dangling_attachments.push((brush.id(), *id));
+                    report.push_dangling_attachment();
+                }
+            }
+        }
+
+        if report.has_dangling_references()
+        {
+            if !report.confirm_repair()
+            {
+                return Err("Map loading cancelled.");
+            }
+
+            for (owner, id) in dangling_attachments
+            {
+                with_attachments
+                    .iter_mut()
+                    .find(|brush| brush.id() == owner)
+                    .unwrap()
+                    .remove_dangling_attachment(id);
+            }
+        }
+
         for brush in brushes
         {
             self.insert_brush(drawing_resources, grid, quad_trees, brush, false);
@@ -881,10 +990,15 @@ impl Innards
             self.insert_thing(things_catalog, thing, quad_trees, false);
         }
 
+        // The entities of a loaded map are not reported as spawned.
+        self.entity_events.clear();
         self.id_generator.reset(max_id);
         _ = self.id_generator.new_id();
-        self.loaded_file_modified =
-            b_refactor.is_some() || t_refactor.is_some() || brushes_removed || things_removed;
+        self.loaded_file_modified = b_refactor.is_some() ||
+            t_refactor.is_some() ||
+            brushes_removed ||
+            things_removed ||
+            !report.is_empty();
 
         Ok((
             b_refactor
@@ -927,7 +1041,9 @@ impl Innards
             .unwrap()
     }
 
-    /// Spawns an entity pasted from the [`Clipboard`].
+    /// Spawns an entity pasted from the [`Clipboard`]. If `move_texture` is false the texture of
+    /// a pasted brush is not moved along with it, unless it is a sprite. The groups of the entity
+    /// are replaced with the ones they are mapped to in `groups`, which are created if missing.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn spawn_pasted_entity(
@@ -937,18 +1053,27 @@ impl Innards
         edits_history: &mut EditsHistory,
         grid: &Grid,
         quad_trees: &mut Trees,
-        data: ClipboardData,
-        delta: Vec2
+        mut data: ClipboardData,
+        delta: Vec2,
+        move_texture: bool,
+        groups: &mut HashMap<u32, u32>
     ) -> Id
     {
         let id = self.new_id();
+        let mut remap = |group| *groups.entry(group).or_insert_with(|| self.new_group());
+
+        match &mut data
+        {
+            ClipboardData::Brush(data, _) => data.remap_groups(&mut remap),
+            ClipboardData::Thing(data, _) => data.remap_groups(&mut remap)
+        };
 
         match data
         {
             ClipboardData::Brush(data, _) =>
             {
                 let mut brush = Brush::from_parts(data, id);
-                brush.move_by_delta(delta, true);
+                brush.move_by_delta(delta, move_texture || brush.has_sprite());
                 edits_history.brush_spawn(brush.id(), true);
                 self.insert_brush(drawing_resources, grid, quad_trees, brush, true);
             },
@@ -963,6 +1088,78 @@ impl Innards
         id
     }
 
+    //==============================================================
+    // Groups
+
+    /// Returns the identifier of a new group.
+    #[inline]
+    #[must_use]
+    fn new_group(&mut self) -> u32
+    {
+        let group = self.next_group;
+        self.next_group += 1;
+        group
+    }
+
+    /// Stores `identifier` as a member of `groups`.
+    #[inline]
+    fn insert_group_members(&mut self, identifier: Id, groups: &[u32])
+    {
+        for group in groups
+        {
+            self.next_group = self.next_group.max(*group + 1);
+            self.groups
+                .entry(*group)
+                .or_insert_with(|| hash_set![])
+                .asserted_insert(identifier);
+        }
+    }
+
+    /// Removes `identifier` from the members of `groups`.
+    #[inline]
+    fn remove_group_members(&mut self, identifier: Id, groups: &[u32])
+    {
+        for group in groups
+        {
+            let members = self.groups.get_mut(group).unwrap();
+            members.asserted_remove(&identifier);
+
+            if members.is_empty()
+            {
+                _ = self.groups.remove(group);
+            }
+        }
+    }
+
+    /// Sets the groups the entity with [`Id`] `identifier` belongs to to `groups`, returning the
+    /// previous ones.
+    #[inline]
+    fn set_entity_groups(&mut self, identifier: Id, groups: Vec<u32>) -> Vec<u32>
+    {
+        let members = groups.clone();
+        let prev = match self.brushes.get_mut(&identifier)
+        {
+            Some(brush) => brush.set_groups(groups),
+            None => self.things.get_mut(&identifier).unwrap().set_groups(groups)
+        };
+
+        self.remove_group_members(identifier, &prev);
+        self.insert_group_members(identifier, &members);
+        prev
+    }
+
+    /// Returns the [`Id`]s of the entities belonging to the outermost group of the entity with
+    /// [`Id`] `identifier`, if any.
+    #[inline]
+    #[must_use]
+    fn outermost_group_members(&self, identifier: Id) -> Option<&Ids>
+    {
+        self.entity(identifier)
+            .groups()
+            .last()
+            .map(|group| self.groups.get(group).unwrap())
+    }
+
     //==============================================================
     // Selected entities
 
@@ -1386,7 +1583,9 @@ impl Innards
                 .insert_attachment(&brush);
         }
 
+        self.insert_group_members(id, brush.groups());
         self.brushes.asserted_insert((id, brush));
+        self.entity_events.push(EntityEvent::Spawned(id));
 
         if selected
         {
@@ -1452,6 +1651,8 @@ impl Innards
 
         let brush = self.brushes.remove(&identifier).unwrap();
         assert!(quad_trees.remove_brush_hull(&brush), "Brush hull was not in the quad tree.");
+        self.entity_events.push(EntityEvent::Despawned(identifier));
+        self.remove_group_members(identifier, brush.groups());
 
         if brush.has_selected_vertexes()
         {
@@ -1838,7 +2039,9 @@ impl Innards
             self.selected_things.asserted_insert(id);
         }
 
+        self.insert_group_members(id, thing.groups());
         self.things.asserted_insert((id, thing));
+        self.entity_events.push(EntityEvent::Spawned(id));
     }
 
     /// Removes a [`ThingInstance`] from the map and returns it.
@@ -1860,6 +2063,8 @@ impl Innards
         );
         let thing = self.things.asserted_remove(&identifier);
         self.selected_things.asserted_remove(&identifier);
+        self.entity_events.push(EntityEvent::Despawned(identifier));
+        self.remove_group_members(identifier, thing.groups());
 
         if thing.has_path()
         {
@@ -1957,13 +2162,22 @@ impl Innards
 pub(in crate::map::editor) struct EntitiesManager
 {
     /// The core of the manager.
-    innards:         Innards,
+    innards:          Innards,
     /// The [`QuadTree`]s used for spacial partitioning.
-    quad_trees:      Trees,
+    quad_trees:       Trees,
     /// The auxiliary container used to avoid using unsafe code in certain procedures.
-    auxiliary:       AuxiliaryIds,
+    auxiliary:        AuxiliaryIds,
     /// Vector to help in the despawn of the selected brushes.
-    brushes_despawn: Vec<Id>
+    brushes_despawn:  Vec<Id>,
+    /// The metadata of the map.
+    metadata:         MapMetadata,
+    /// Whether the metadata was changed since the last save.
+    metadata_changed: bool,
+    /// The names of the textures most recently applied through the texture editor, from the most
+    /// recent to the oldest.
+    recent_textures:  Vec<String>,
+    /// Whether the editor only brushes are shown.
+    show_editor_only: bool
 }
 
 impl EntitiesManager
@@ -1974,10 +2188,14 @@ impl EntitiesManager
     pub fn new() -> Self
     {
         Self {
-            innards:         Innards::new(),
-            quad_trees:      Trees::new(),
-            auxiliary:       AuxiliaryIds::new(),
-            brushes_despawn: Vec::new()
+            innards:          Innards::new(),
+            quad_trees:       Trees::new(),
+            auxiliary:        AuxiliaryIds::new(),
+            brushes_despawn:  Vec::new(),
+            metadata:         MapMetadata::default(),
+            metadata_changed: false,
+            recent_textures:  Vec::new(),
+            show_editor_only: true
         }
     }
 
@@ -1992,7 +2210,8 @@ impl EntitiesManager
         grid: &Grid,
         engine_default_brush_properties: &EngineDefaultBrushProperties,
         engine_default_thing_properties: &EngineDefaultThingProperties,
-        steps: &mut I
+        steps: &mut I,
+        report: &mut IntegrityReport
     ) -> Result<(Self, DefaultBrushProperties, DefaultThingProperties), &'static str>
     where
         I: Iterator<Item = FileStructure>,
@@ -2009,7 +2228,8 @@ impl EntitiesManager
             engine_default_brush_properties,
             engine_default_thing_properties,
             steps,
-            &mut manager.quad_trees
+            &mut manager.quad_trees,
+            report
         )
         {
             Ok(value) => Ok((manager, value.0, value.1)),
@@ -2028,6 +2248,14 @@ impl EntitiesManager
         self.innards.loaded_file_modified
     }
 
+    /// Turns on the refactored properties flag, so that the repairs made to the loaded file are
+    /// saved.
+    #[inline]
+    pub(in crate::map::editor::state) fn set_loaded_file_modified(&mut self)
+    {
+        self.innards.loaded_file_modified = true;
+    }
+
     /// Turns off the refactored properties flag.
     #[inline]
     pub(in crate::map::editor::state) fn reset_loaded_file_modified(&mut self)
@@ -2035,6 +2263,111 @@ impl EntitiesManager
         self.innards.loaded_file_modified = false;
     }
 
+    /// Returns an iterator to the spawns and despawns of entities that were not sent as events
+    /// yet, removing them.
+    #[inline]
+    pub(in crate::map::editor) fn drain_entity_events(&mut self)
+        -> std::vec::Drain<'_, EntityEvent>
+    {
+        self.innards.entity_events.drain(..)
+    }
+
+    /// Moves the spawns and despawns of entities of `other` that were not sent as events yet into
+    /// `self`.
+    #[inline]
+    pub(in crate::map::editor::state) fn take_entity_events(&mut self, other: &mut Self)
+    {
+        self.innards.entity_events.append(&mut other.innards.entity_events);
+    }
+
+    /// Returns the metadata of the map.
+    #[inline]
+    pub(in crate::map::editor::state) const fn metadata(&self) -> &MapMetadata { &self.metadata }
+
+    /// Sets the metadata of the map to the one read from a map file.
+    #[inline]
+    pub(in crate::map::editor::state) fn load_metadata(&mut self, metadata: MapMetadata)
+    {
+        self.metadata = metadata;
+        self.metadata_changed = false;
+    }
+
+    /// Sets the metadata of the map.
+    #[inline]
+    pub(in crate::map::editor::state) fn set_metadata(&mut self, metadata: MapMetadata)
+    {
+        if self.metadata == metadata
+        {
+            return;
+        }
+
+        self.metadata = metadata;
+        self.metadata_changed = true;
+    }
+
+    /// Whether the metadata was changed since the last save.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn metadata_changed(&self) -> bool
+    {
+        self.metadata_changed
+    }
+
+    /// Turns off the changed metadata flag.
+    #[inline]
+    pub(in crate::map::editor::state) fn reset_metadata_changed(&mut self)
+    {
+        self.metadata_changed = false;
+    }
+
+    /// Returns the names of the most recently applied textures, from the most recent to the
+    /// oldest.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn recent_textures(&self) -> &[String]
+    {
+        &self.recent_textures
+    }
+
+    /// Sets the recently applied textures to the ones read from a map file.
+    #[inline]
+    pub(in crate::map::editor::state) fn load_recent_textures(&mut self, textures: Vec<String>)
+    {
+        self.recent_textures = textures;
+        self.recent_textures.truncate(RECENT_TEXTURES_AMOUNT);
+    }
+
+    /// Marks the texture named `name` as the most recently applied one.
+    #[inline]
+    pub(in crate::map::editor::state) fn push_recent_texture(&mut self, name: &str)
+    {
+        if let Some(i) = self.recent_textures.iter().position(|n| n == name)
+        {
+            let name = self.recent_textures.remove(i);
+            self.recent_textures.insert(0, name);
+            return;
+        }
+
+        self.recent_textures.insert(0, name.to_owned());
+        self.recent_textures.truncate(RECENT_TEXTURES_AMOUNT);
+    }
+
+    /// Toggles the visibility of the editor only brushes.
+    #[inline]
+    pub(in crate::map::editor::state) fn toggle_editor_only_visibility(&mut self)
+    {
+        self.show_editor_only.toggle();
+    }
+
+    /// Whether `brush` should be drawn and interacted with, which is not the case for editor only
+    /// brushes while they are hidden.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) const fn brush_shown(&self, brush: &Brush) -> bool
+    {
+        self.show_editor_only || !brush.editor_only()
+    }
+
     /// Whether an entity with [`Id`] `identifier` exists.
     #[inline]
     #[must_use]
@@ -2060,6 +2393,19 @@ impl EntitiesManager
         self.innards.entity(identifier)
     }
 
+    /// Returns the [`Hull`] of the entity with [`Id`] `identifier`.
+    #[inline]
+    pub(in crate::map::editor::state) fn entity_hull(
+        &self,
+        identifier: Id,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        grid: &Grid
+    ) -> Hull
+    {
+        self.entity(identifier).hull(drawing_resources, things_catalog, grid)
+    }
+
     /// Schedule a tool outline update.
     #[inline]
     pub(in crate::map::editor::state) fn schedule_outline_update(&mut self)
@@ -2195,6 +2541,7 @@ impl EntitiesManager
     {
         let thing = self.insert_entity_selection(identifier);
         edits_history.entity_selection(identifier);
+        self.select_group_members(identifier, edits_history);
 
         if thing || !inputs.ctrl_pressed()
         {
@@ -2227,6 +2574,7 @@ impl EntitiesManager
     {
         let thing = self.remove_entity_selection(identifier);
         edits_history.entity_deselection(identifier);
+        self.deselect_group_members(identifier, edits_history);
 
         if thing || !inputs.ctrl_pressed()
         {
@@ -2276,6 +2624,23 @@ impl EntitiesManager
         edits_history.entity_deselection_cluster(self.auxiliary.iter());
     }
 
+    /// Selects all the brushes with the texture named `texture`.
+    #[inline]
+    pub(in crate::map::editor::state) fn select_brushes_with_texture(
+        &mut self,
+        texture: &str,
+        edits_history: &mut EditsHistory
+    )
+    {
+        self.auxiliary
+            .replace_values(self.innards.textured.iter().filter(|id| {
+                !self.innards.is_selected(**id) &&
+                    self.innards.brush(**id).texture_settings().unwrap().name() == texture
+            }));
+
+        self.innards.select_cluster(edits_history, self.auxiliary.iter());
+    }
+
     /// Selects all entities.
     #[inline]
     pub(in crate::map::editor::state) fn select_all_entities(
@@ -2306,72 +2671,298 @@ impl EntitiesManager
     }
 
     //==============================================================
-    // Brushes
+    // Groups
 
-    /// Returns the amount of brushes.
+    /// Selects the non selected entities belonging to the outermost group of the entity with
+    /// [`Id`] `identifier`.
     #[inline]
-    #[must_use]
-    pub(in crate::map::editor::state) fn brushes_amount(&self) -> usize
+    fn select_group_members(&mut self, identifier: Id, edits_history: &mut EditsHistory)
     {
-        self.innards.brushes.len()
+        let members = return_if_none!(self.innards.outermost_group_members(identifier));
+        self.auxiliary
+            .replace_values(members.iter().filter(|id| !self.innards.is_selected(**id)));
+        self.innards.select_cluster(edits_history, self.auxiliary.iter());
     }
 
-    /// Returns the amount of selected brushes.
+    /// Deselects the selected entities belonging to the outermost group of the entity with
+    /// [`Id`] `identifier`.
     #[inline]
-    #[must_use]
-    pub(in crate::map::editor::state) fn selected_brushes_amount(&self) -> usize
+    fn deselect_group_members(&mut self, identifier: Id, edits_history: &mut EditsHistory)
     {
-        self.innards.selected_brushes_amount()
+        let members = return_if_none!(self.innards.outermost_group_members(identifier));
+        self.auxiliary
+            .replace_values(members.iter().filter(|id| self.innards.is_selected(**id)));
+        self.innards.deselect_cluster(edits_history, self.auxiliary.iter());
     }
 
-    /// Whether there are any currently selected brushes.
+    /// Selects the non selected entities belonging to the outermost groups of the selected
+    /// entities.
     #[inline]
-    #[must_use]
-    pub(in crate::map::editor::state) fn any_selected_brushes(&self) -> bool
+    fn select_selected_entities_groups(&mut self, edits_history: &mut EditsHistory)
     {
-        self.selected_brushes_amount() != 0
+        self.auxiliary.replace_values(
+            self.innards
+                .selected_entities_ids()
+                .filter_map(|id| self.innards.outermost_group_members(*id))
+                .flatten()
+                .filter(|id| !self.innards.is_selected(**id))
+        );
+
+        self.innards.select_cluster(edits_history, self.auxiliary.iter());
     }
 
-    /// Returns a reference to the brush with [`Id`] identifier.
+    /// Whether any of the selected entities belongs to a group.
     #[inline]
-    pub(in crate::map::editor::state) fn brush(&self, identifier: Id) -> &Brush
+    #[must_use]
+    pub(in crate::map::editor::state) fn any_selected_grouped_entities(&self) -> bool
     {
-        self.innards.brush(identifier)
+        self.innards
+            .selected_entities_ids()
+            .any(|id| !self.innards.entity(*id).groups().is_empty())
     }
 
-    /// Returns an iterator to the [`Id`]s of the selected brushes.
+    /// Sets the groups the entity with [`Id`] `identifier` belongs to to `groups`, returning the
+    /// previous ones.
     #[inline]
-    pub(in crate::map::editor::state) fn selected_brushes_ids(
-        &self
-    ) -> impl ExactSizeIterator<Item = &Id> + Clone
+    pub(in crate::map::editor::state) fn set_entity_groups(
+        &mut self,
+        identifier: Id,
+        groups: Vec<u32>
+    ) -> Vec<u32>
     {
-        self.innards.selected_brushes.iter()
+        self.innards.set_entity_groups(identifier, groups)
     }
 
-    /// Returns a [`SelectedBrushesIter`] created from `ids`
+    /// Groups the selected entities. The groups the selected entities already belong to are
+    /// nested in the new one.
     #[inline]
-    const fn selected_brushes_iter<'a>(
-        &'a self,
-        ids: RwLockReadGuard<'a, QuadTreeIdsNearPos>
-    ) -> SelectedBrushesIter<'a>
+    pub(in crate::map::editor::state) fn group_selected_entities(
+        &mut self,
+        edits_history: &mut EditsHistory
+    )
     {
-        SelectedBrushesIter::new(self, ids)
-    }
+        self.select_selected_entities_groups(edits_history);
 
-    /// Returns a [`Brushes`] instance.
-    #[inline]
-    pub(in crate::map::editor::state) const fn brushes(&self) -> Brushes { self.innards.brushes() }
+        {
+            let mut outermost = self
+                .innards
+                .selected_entities_ids()
+                .map(|id| self.innards.entity(*id).groups().last().copied());
+            let first = return_if_none!(outermost.next());
 
-    /// Returns a [`BrushMut`] wrapping the brush with [`Id`] `identifier`.
-    #[inline]
-    pub(in crate::map::editor::state) fn brush_mut<'a, T: TextureSize>(
-        &'a mut self,
-        drawing_resources: &'a T,
-        grid: &'a Grid,
-        identifier: Id
-    ) -> BrushMut<'a, T>
-    {
-        BrushMut::new(drawing_resources, &mut self.innards, grid, &mut self.quad_trees, identifier)
+            if first.is_some() && outermost.all(|group| group == first)
+            {
+                return;
+            }
+        }
+
+        if self.innards.selected_brushes_amount() + self.innards.selected_things_amount() < 2
+        {
+            return;
+        }
+
+        let group = self.innards.new_group();
+        self.auxiliary.replace_values(self.innards.selected_entities_ids());
+
+        edits_history.with_group("Entities grouping", |edits_history| {
+            for id in &self.auxiliary
+            {
+                let mut groups = self.innards.entity(*id).groups().to_vec();
+                groups.push(group);
+                edits_history.entity_groups(*id, self.innards.set_entity_groups(*id, groups));
+            }
+        });
+    }
+
+    /// Dissolves the outermost groups of the selected entities.
+    #[inline]
+    pub(in crate::map::editor::state) fn ungroup_selected_entities(
+        &mut self,
+        edits_history: &mut EditsHistory
+    )
+    {
+        if !self.any_selected_grouped_entities()
+        {
+            return;
+        }
+
+        self.select_selected_entities_groups(edits_history);
+        self.auxiliary.replace_values(
+            self.innards
+                .selected_entities_ids()
+                .filter(|id| !self.innards.entity(**id).groups().is_empty())
+        );
+
+        edits_history.with_group("Entities ungrouping", |edits_history| {
+            for id in &self.auxiliary
+            {
+                let mut groups = self.innards.entity(*id).groups().to_vec();
+                _ = groups.pop();
+                edits_history.entity_groups(*id, self.innards.set_entity_groups(*id, groups));
+            }
+        });
+    }
+
+    //==============================================================
+    // Links
+
+    /// Links the [`ThingInstance`] with [`Id`] `source` to the one with [`Id`] `target`, setting
+    /// the target property of the former to the targetname of the latter. If the latter has no
+    /// targetname a new unique one is assigned to it.
+    #[inline]
+    pub(in crate::map::editor::state) fn link_things(
+        &mut self,
+        things_catalog: &ThingsCatalog,
+        edits_history: &mut EditsHistory,
+        source: Id,
+        target: Id
+    )
+    {
+        assert!(source != target, "Source and target are the same thing.");
+
+        let mut edited = false;
+        let name = match self.thing(target).targetname()
+        {
+            Some(name) => name.to_owned(),
+            None =>
+            {
+                let name = {
+                    let names = self
+                        .things()
+                        .filter_map(ThingInstance::targetname)
+                        .collect::<HashSet<_>>();
+
+                    (1..)
+                        .map(|i| format!("t{i}"))
+                        .find(|name| !names.contains(name.as_str()))
+                        .unwrap()
+                };
+
+                let prev = self
+                    .thing_mut(things_catalog, target)
+                    .set_property(TARGETNAME_LABEL, &Value::String(name.clone()))
+                    .unwrap();
+                edits_history.property(TARGETNAME_LABEL, Some((target, prev)));
+                edited = true;
+                name
+            }
+        };
+
+        if let Some(prev) = self
+            .thing_mut(things_catalog, source)
+            .set_property(TARGET_LABEL, &Value::String(name))
+        {
+            edits_history.property(TARGET_LABEL, Some((source, prev)));
+            edited = true;
+        }
+
+        if edited
+        {
+            self.innards.overall_things_properties_update = PropertyUpdate::Total;
+            edits_history.override_edit_tag("Things link");
+        }
+    }
+
+    /// Draws arrows from the [`ThingInstance`]s to the ones they target.
+    #[inline]
+    pub(in crate::map::editor::state) fn draw_thing_links(&self, drawer: &mut EditDrawer)
+    {
+        let mut targets = hash_map![];
+
+        for thing in self.things()
+        {
+            targets
+                .entry(continue_if_none!(thing.targetname()))
+                .or_insert_with(Vec::new)
+                .push(thing.pos());
+        }
+
+        if targets.is_empty()
+        {
+            return;
+        }
+
+        for thing in self.things()
+        {
+            let start = thing.pos();
+
+            for end in continue_if_none!(targets.get(continue_if_none!(thing.target())))
+            {
+                if *end != start
+                {
+                    drawer.arrowed_line(start, *end, Color::ThingLink);
+                }
+            }
+        }
+    }
+
+    //==============================================================
+    // Brushes
+
+    /// Returns the amount of brushes.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn brushes_amount(&self) -> usize
+    {
+        self.innards.brushes.len()
+    }
+
+    /// Returns the amount of selected brushes.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn selected_brushes_amount(&self) -> usize
+    {
+        self.innards.selected_brushes_amount()
+    }
+
+    /// Whether there are any currently selected brushes.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn any_selected_brushes(&self) -> bool
+    {
+        self.selected_brushes_amount() != 0
+    }
+
+    /// Returns a reference to the brush with [`Id`] identifier.
+    #[inline]
+    pub(in crate::map::editor::state) fn brush(&self, identifier: Id) -> &Brush
+    {
+        self.innards.brush(identifier)
+    }
+
+    /// Returns an iterator to the [`Id`]s of the selected brushes.
+    #[inline]
+    pub(in crate::map::editor::state) fn selected_brushes_ids(
+        &self
+    ) -> impl ExactSizeIterator<Item = &Id> + Clone
+    {
+        self.innards.selected_brushes.iter()
+    }
+
+    /// Returns a [`SelectedBrushesIter`] created from `ids`
+    #[inline]
+    const fn selected_brushes_iter<'a>(
+        &'a self,
+        ids: RwLockReadGuard<'a, QuadTreeIdsNearPos>
+    ) -> SelectedBrushesIter<'a>
+    {
+        SelectedBrushesIter::new(self, ids)
+    }
+
+    /// Returns a [`Brushes`] instance.
+    #[inline]
+    pub(in crate::map::editor::state) const fn brushes(&self) -> Brushes { self.innards.brushes() }
+
+    /// Returns a [`BrushMut`] wrapping the brush with [`Id`] `identifier`.
+    #[inline]
+    pub(in crate::map::editor::state) fn brush_mut<'a, T: TextureSize>(
+        &'a mut self,
+        drawing_resources: &'a T,
+        grid: &'a Grid,
+        identifier: Id
+    ) -> BrushMut<'a, T>
+    {
+        BrushMut::new(drawing_resources, &mut self.innards, grid, &mut self.quad_trees, identifier)
     }
 
     /// Returns an iterator to the non selected brushes.
@@ -2411,6 +3002,30 @@ impl EntitiesManager
         )
     }
 
+    /// Marks the selected brushes as editor only, or clears the flag if they are all already
+    /// editor only.
+    #[inline]
+    pub(in crate::map::editor::state) fn toggle_selected_brushes_editor_only(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid
+    )
+    {
+        let value = !self.selected_brushes().all(Brush::editor_only);
+        let identifiers = self
+            .selected_brushes()
+            .filter_map(|brush| (brush.editor_only() != value).then_some(brush.id()))
+            .collect::<Vec<_>>();
+
+        for id in &identifiers
+        {
+            self.brush_mut(drawing_resources, grid, *id).toggle_editor_only();
+        }
+
+        edits_history.editor_only_toggle(identifiers);
+    }
+
     /// Returns a [`BrushesIter`] that returns the brushes near `cursor_pos`.
     /// If `camera_scale` contains a value it wraps brushes within the cursor highlight.
     #[inline]
@@ -2423,6 +3038,19 @@ impl EntitiesManager
         BrushesNearPosIter::new(self, self.quad_trees.brushes_at_pos(cursor_pos, camera_scale))
     }
 
+    /// Updates the visible entities of all kinds in parallel, so that the draw calls of the frame
+    /// do not need to cull them one kind at a time.
+    #[inline]
+    pub(in crate::map::editor) fn update_visible_entities(
+        &self,
+        window: &Window,
+        camera: &Transform,
+        grid: &Grid
+    )
+    {
+        self.quad_trees.update_visible_ids(camera, window, grid);
+    }
+
     /// Returns an iterator to the visible brushes.
     #[inline]
     pub(in crate::map::editor::state) fn visible_brushes(
@@ -2536,6 +3164,8 @@ impl EntitiesManager
             },
             TargetSwitch::Texture => select!(sprites_in_range, ctrl_pressed)
         };
+
+        self.select_selected_entities_groups(edits_history);
     }
 
     /// Exclusively selects all entities that are fully within `range`.
@@ -2615,6 +3245,8 @@ impl EntitiesManager
             }
         };
 
+        self.select_selected_entities_groups(edits_history);
+
         if !inputs.ctrl_pressed()
         {
             return;
@@ -2647,6 +3279,68 @@ impl EntitiesManager
         self.innards.deselect_cluster(edits_history, self.auxiliary.iter());
     }
 
+    /// Moves the brush with [`Id`] `identifier`, and its texture, by `delta`, if possible.
+    /// Returns whether the brush was moved.
+    #[inline]
+    pub(in crate::map::editor::state) fn move_brush(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        identifier: Id,
+        delta: Vec2
+    ) -> bool
+    {
+        let valid = self.test_operation_validity(|manager| {
+            (!manager
+                .brush(identifier)
+                .check_move(drawing_resources, grid, delta, true))
+            .then_some(identifier)
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        self.brush_mut(drawing_resources, grid, identifier)
+            .move_by_delta(delta, true);
+        edits_history.brush_move(identifier, delta, true);
+        true
+    }
+
+    /// Moves the vertex at `index` of the brush with [`Id`] `identifier` by `delta`, if the
+    /// resulting polygon is valid. Returns whether the vertex was moved.
+    #[inline]
+    pub(in crate::map::editor::state) fn move_brush_vertex(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        identifier: Id,
+        index: u8,
+        delta: Vec2
+    ) -> bool
+    {
+        let mov = vec![(vec![index], delta)];
+        let valid = self.test_operation_validity(|manager| {
+            (!manager
+                .brush_mut(drawing_resources, grid, identifier)
+                .check_vertexes_move_at_indexes(&mov))
+            .then_some(identifier)
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        self.brush_mut(drawing_resources, grid, identifier)
+            .move_vertexes_at_indexes(mov.iter().map(|(idxs, delta)| (idxs.iter(), *delta)));
+        edits_history.vertexes_snap(identifier, mov);
+        true
+    }
+
     /// Returns the center of the rectangle encompassing the brushes with [`Id`]s returned by
     /// `ids`, if any.
     #[inline]
@@ -2835,7 +3529,9 @@ impl EntitiesManager
         edits_history: &mut EditsHistory,
         grid: &Grid,
         data: ClipboardData,
-        delta: Vec2
+        delta: Vec2,
+        move_texture: bool,
+        groups: &mut HashMap<u32, u32>
     ) -> Id
     {
         self.innards.spawn_pasted_entity(
@@ -2845,7 +3541,9 @@ impl EntitiesManager
             grid,
             &mut self.quad_trees,
             data,
-            delta
+            delta,
+            move_texture,
+            groups
         )
     }
 
@@ -2948,13 +3646,13 @@ impl EntitiesManager
             ids
         }
 
-        let (properties, path_status) = {
+        let (properties, path_status, groups) = {
             let mut brush = self.brush_mut(drawing_resources, grid, identifier);
             edits_history.polygon_edit(identifier, f(&mut brush));
-            (brush.properties(), path_status(&brush))
+            (brush.properties(), path_status(&brush), brush.groups().to_vec())
         };
 
-        match path_status
+        let ids = match path_status
         {
             PathStatus::None =>
             {
@@ -3003,8 +3701,17 @@ impl EntitiesManager
 
                 ids
             }
+        };
+
+        if !groups.is_empty()
+        {
+            for id in &ids
+            {
+                edits_history.entity_groups(*id, self.set_entity_groups(*id, groups.clone()));
+            }
         }
-        .into_iter()
+
+        ids.into_iter()
     }
 
     /// Spawns a brush created with a draw tool.
@@ -3141,7 +3848,8 @@ impl EntitiesManager
         self.spawn_brushes(drawing_resources, edits_history, grid, polygons, properties);
     }
 
-    /// Duplicates the selected entities crating copies displaced by `delta`.
+    /// Duplicates the selected entities crating copies displaced by `delta`. If `move_texture` is
+    /// false the textures of the copies continue the ones of the originals.
     #[inline]
     #[must_use]
     pub(in crate::map::editor::state) fn duplicate_selected_entities(
@@ -3151,7 +3859,8 @@ impl EntitiesManager
         clipboard: &mut Clipboard,
         edits_history: &mut EditsHistory,
         grid: &Grid,
-        delta: Vec2
+        delta: Vec2,
+        move_texture: bool
     ) -> bool
     {
         let valid = self.test_operation_validity(|manager| {
@@ -3167,7 +3876,16 @@ impl EntitiesManager
             return false;
         }
 
-        clipboard.duplicate(drawing_resources, things_catalog, self, edits_history, grid, delta);
+        clipboard.duplicate(
+            drawing_resources,
+            things_catalog,
+            self,
+            edits_history,
+            grid,
+            delta,
+            PasteSnap::None,
+            move_texture
+        );
         true
     }
 
@@ -3574,6 +4292,320 @@ impl EntitiesManager
         TextureResult::Valid
     }
 
+    /// Assigns to each selected brush a texture picked pseudo-randomly from `textures`. The same
+    /// selection and `seed` always generate the same result. Returns a [`TextureResult`]
+    /// describing the result of the procedure.
+    #[inline]
+    pub(in crate::map::editor::state) fn randomize_selected_brushes_texture(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        textures: &[String],
+        seed: u64
+    ) -> TextureResult
+    {
+        if textures.is_empty() || !self.any_selected_brushes()
+        {
+            return TextureResult::Invalid;
+        }
+
+        let mut ids = self.innards.selected_brushes.iter().copied().collect::<Vec<_>>();
+        ids.sort_unstable_by_key(|id| id.value());
+        let mut random = SeededRandom::new(seed);
+        let assignments = ids
+            .into_iter()
+            .map(|id| (id, textures[random.index(textures.len())].as_str()))
+            .collect::<Vec<_>>();
+
+        let valid = self.test_operation_validity(|manager| {
+            assignments.iter().find_map(|(id, texture)| {
+                (!manager.brush_mut(drawing_resources, grid, *id).check_texture_change(
+                    drawing_resources,
+                    grid,
+                    texture
+                ))
+                .then_some(*id)
+            })
+        });
+
+        if !valid
+        {
+            return TextureResult::Invalid;
+        }
+
+        let sprite = assignments.iter().any(|(id, _)| self.brush(*id).has_sprite());
+
+        edits_history.texture_cluster(assignments.into_iter().filter_map(|(id, texture)| {
+            match self.innards.set_texture(
+                drawing_resources,
+                grid,
+                &mut self.quad_trees,
+                id,
+                texture
+            )
+            {
+                TextureSetResult::Unchanged => None,
+                TextureSetResult::Changed(prev) => (id, prev.into()).into(),
+                TextureSetResult::Set => (id, None).into()
+            }
+        }));
+
+        if sprite
+        {
+            return TextureResult::ValidRefreshOutline;
+        }
+
+        TextureResult::Valid
+    }
+
+    /// Returns the sorted names of the textures of the brushes which are not loaded.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn missing_textures(
+        &self,
+        drawing_resources: &DrawingResources
+    ) -> Vec<String>
+    {
+        let mut missing = self
+            .innards
+            .textured
+            .iter()
+            .map(|id| self.innards.brush(*id).texture_settings().unwrap().name())
+            .filter(|name| drawing_resources.texture(name).is_none())
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Replaces the textures of the brushes according to `remap`, a list of pairs of texture names
+    /// where the first is replaced by the second. The affected brushes are selected.
+    /// Returns a [`TextureResult`] describing the result of the procedure.
+    #[inline]
+    pub(in crate::map::editor::state) fn remap_textures(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        remap: &[(String, String)]
+    ) -> TextureResult
+    {
+        let assignments = self
+            .innards
+            .textured
+            .iter()
+            .filter_map(|id| {
+                let name = self.innards.brush(*id).texture_settings().unwrap().name();
+
+                remap
+                    .iter()
+                    .find_map(|(prev, new)| (prev == name).then_some((*id, new.as_str())))
+            })
+            .collect::<Vec<_>>();
+
+        self.set_brushes_textures(drawing_resources, edits_history, grid, &assignments)
+    }
+
+    /// Replaces the texture `prev` with `new` in the brushes, frames of their list animations
+    /// included, and in the recently applied textures. The changes are not stored in the edits
+    /// history.
+    #[inline]
+    pub(in crate::map::editor::state) fn rename_texture(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        grid: &Grid,
+        prev: &str,
+        new: &str
+    )
+    {
+        let mut renamed = false;
+        self.auxiliary.replace_values(&self.innards.textured);
+
+        for id in &self.auxiliary
+        {
+            if !self
+                .innards
+                .brush_mut(drawing_resources, grid, &mut self.quad_trees, *id)
+                .rename_texture(prev, new)
+            {
+                continue;
+            }
+
+            renamed = true;
+
+            if self.innards.is_selected(*id)
+            {
+                self.innards.overall_texture_update = true;
+            }
+        }
+
+        if let Some(name) = self.recent_textures.iter_mut().find(|name| *name == prev)
+        {
+            new.clone_into(name);
+            renamed = true;
+        }
+
+        if renamed
+        {
+            self.innards.loaded_file_modified = true;
+        }
+    }
+
+    /// Sets the textures of the brushes according to `assignments`, a list of pairs of brush
+    /// [`Id`]s and texture names. The affected brushes are selected.
+    /// Returns a [`TextureResult`] describing the result of the procedure.
+    #[inline]
+    pub(in crate::map::editor::state) fn set_brushes_textures(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        assignments: &[(Id, &str)]
+    ) -> TextureResult
+    {
+        if assignments.is_empty()
+        {
+            return TextureResult::Valid;
+        }
+
+        self.auxiliary.replace_values(
+            assignments
+                .iter()
+                .map(|(id, _)| id)
+                .filter(|id| !self.innards.is_selected(**id))
+        );
+        self.innards.select_cluster(edits_history, self.auxiliary.iter());
+
+        let valid = self.test_operation_validity(|manager| {
+            assignments.iter().find_map(|(id, texture)| {
+                (!manager.brush_mut(drawing_resources, grid, *id).check_texture_change(
+                    drawing_resources,
+                    grid,
+                    texture
+                ))
+                .then_some(*id)
+            })
+        });
+
+        if !valid
+        {
+            return TextureResult::Invalid;
+        }
+
+        let sprite = assignments.iter().any(|(id, _)| self.brush(*id).has_sprite());
+
+        edits_history.texture_cluster(assignments.iter().filter_map(|&(id, texture)| {
+            match self.innards.set_texture(
+                drawing_resources,
+                grid,
+                &mut self.quad_trees,
+                id,
+                texture
+            )
+            {
+                TextureSetResult::Unchanged => None,
+                TextureSetResult::Changed(prev) => (id, prev.into()).into(),
+                TextureSetResult::Set => (id, None).into()
+            }
+        }));
+
+        if sprite
+        {
+            return TextureResult::ValidRefreshOutline;
+        }
+
+        TextureResult::Valid
+    }
+
+    /// Continues the texture of the brush with [`Id`] `source` onto the selected brushes sharing
+    /// a side with it, so that it flows seamlessly across the shared sides. Brushes with a sprite
+    /// are left untouched.
+    #[inline]
+    pub(in crate::map::editor::state) fn continue_texture(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        source: Id
+    )
+    {
+        let brush = self.innards.brush(source);
+        let settings = return_if_none!(brush.texture_settings());
+
+        if settings.sprite()
+        {
+            return;
+        }
+
+        let texture = settings.name().to_owned();
+        let (scale_x, scale_y) = (settings.scale_x(), settings.scale_y());
+        let (parallax_x, parallax_y) = (settings.parallax_x(), settings.parallax_y());
+        let (scroll_x, scroll_y) = (settings.scroll_x(), settings.scroll_y());
+        let angle = settings.angle();
+        let offset = Vec2::new(settings.offset_x(), settings.offset_y());
+        // The UV coordinates are a function of the world position, so the texture repeats itself
+        // every time the offset changes by the scaled size.
+        let period = (drawing_resources.texture_or_error(&texture).size().as_vec2() *
+            Vec2::new(scale_x, scale_y))
+        .abs();
+
+        self.auxiliary
+            .replace_values(self.innards.selected_brushes_ids().filter(|id| {
+                let target = self.innards.brush(**id);
+                **id != source && !target.has_sprite() && target.shares_side(brush)
+            }));
+
+        for id in &self.auxiliary
+        {
+            match self.innards.set_texture(
+                drawing_resources,
+                grid,
+                &mut self.quad_trees,
+                *id,
+                &texture
+            )
+            {
+                TextureSetResult::Unchanged => (),
+                TextureSetResult::Changed(prev) => edits_history.texture(*id, prev.into()),
+                TextureSetResult::Set => edits_history.texture(*id, None)
+            };
+
+            let mut brush =
+                self.innards
+                    .brush_mut(drawing_resources, grid, &mut self.quad_trees, *id);
+
+            /// Sets the value and stores the edit.
+            macro_rules! set {
+                ($($value:ident),+) => { paste::paste! { $(
+                    if let Some(prev) = brush.[< set_texture_ $value >]($value)
+                    {
+                        edits_history.[< texture_ $value >](*id, prev);
+                    }
+                )+}};
+            }
+
+            if let Some(rotation) = brush.set_texture_angle(drawing_resources, grid, angle)
+            {
+                edits_history.texture_angle(*id, rotation);
+            }
+
+            set!(scale_x, scale_y, parallax_x, parallax_y, scroll_x, scroll_y);
+
+            // Pick the offset equivalent to the one of the source closest to the current one, to
+            // keep the values tidy.
+            let current = {
+                let settings = brush.texture_settings().unwrap();
+                Vec2::new(settings.offset_x(), settings.offset_y())
+            };
+            let offset_x = offset.x + ((current.x - offset.x) / period.x).round() * period.x;
+            let offset_y = offset.y + ((current.y - offset.y) / period.y).round() * period.y;
+
+            set!(offset_x, offset_y);
+        }
+    }
+
     /// Removes the textures from the selected brushes.
     #[inline]
     pub(in crate::map::editor::state) fn remove_selected_textures(
@@ -3856,7 +4888,7 @@ impl EntitiesManager
             things_catalog,
             ThingInstance::new(
                 id,
-                things_catalog.selected_thing().id(),
+                things_catalog.selected_thing(),
                 settings
                     .thing_pivot
                     .spawn_pos(things_catalog.selected_thing(), cursor_pos),
@@ -3869,6 +4901,61 @@ impl EntitiesManager
         id
     }
 
+    /// Spawns a selected [`ThingInstance`] of [`Thing`] `thing` at `pos`, if it is not out of
+    /// bounds. Returns its [`Id`], if it was spawned.
+    #[inline]
+    pub(in crate::map::editor::state) fn spawn_thing(
+        &mut self,
+        things_catalog: &ThingsCatalog,
+        default_thing_properties: &DefaultThingProperties,
+        edits_history: &mut EditsHistory,
+        thing: ThingId,
+        pos: Vec2
+    ) -> Option<Id>
+    {
+        let id = self.innards.new_id();
+        let thing = ThingInstance::new(
+            id,
+            things_catalog.thing_or_error(thing),
+            pos,
+            default_thing_properties
+        );
+
+        if !thing.check_move(things_catalog, Vec2::ZERO)
+        {
+            return None;
+        }
+
+        self.innards
+            .spawn_thing(things_catalog, thing, &mut self.quad_trees, edits_history);
+        id.into()
+    }
+
+    /// Moves the [`ThingInstance`] with [`Id`] `identifier` by `delta`, if possible.
+    /// Returns whether the thing was moved.
+    #[inline]
+    pub(in crate::map::editor::state) fn move_thing(
+        &mut self,
+        things_catalog: &ThingsCatalog,
+        edits_history: &mut EditsHistory,
+        identifier: Id,
+        delta: Vec2
+    ) -> bool
+    {
+        let valid = self.test_operation_validity(|manager| {
+            (!manager.thing(identifier).check_move(things_catalog, delta)).then_some(identifier)
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        self.thing_mut(things_catalog, identifier).move_by_delta(delta);
+        edits_history.thing_move(identifier, delta);
+        true
+    }
+
     /// Despawns the drawn [`ThingInstance`]s with [`Id`]s contained in `drawn_things`.
     #[inline]
     pub(in crate::map::editor::state) fn despawn_drawn_things(
@@ -4005,6 +5092,16 @@ impl EntitiesManager
         self.innards.selected_moving.iter()
     }
 
+    /// Returns an iterator to the [`Id`]s of the selected entities that don't have a [`Path`] but
+    /// could have one.
+    #[inline]
+    pub(in crate::map::editor::state) fn selected_possible_moving_ids(
+        &self
+    ) -> impl Iterator<Item = &Id>
+    {
+        self.innards.selected_possible_moving.iter()
+    }
+
     /// Returns the amount of selected moving brushes.
     #[inline]
     #[must_use]
@@ -4106,6 +5203,43 @@ impl EntitiesManager
         SelectedMovingsIter::new(self, self.quad_trees.paths_at_pos(cursor_pos, camera_scale))
     }
 
+    /// Returns the position of the non selected [`Path`] node or of the center of the non selected
+    /// entity with a [`Path`] closest to `pos` among the ones whose highlight contains it, if any.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::editor::state) fn path_snap_target(
+        &self,
+        pos: Vec2,
+        camera_scale: f32
+    ) -> Option<Vec2>
+    {
+        let ids = self.quad_trees.paths_at_pos(pos, camera_scale);
+        let mut target = None;
+        let mut distance = f32::INFINITY;
+
+        for id in ids.ids()
+        {
+            let moving = self.moving(*id);
+            let center = moving.center();
+
+            for p in continue_if_none!(moving.path())
+                .unselected_nodes_world_pos(center)
+                .chain((!self.is_selected(*id)).then_some(center))
+                .filter(|p| p.is_point_inside_ui_highlight(pos, camera_scale))
+            {
+                let d = p.distance_squared(pos);
+
+                if d < distance
+                {
+                    distance = d;
+                    target = p.into();
+                }
+            }
+        }
+
+        target
+    }
+
     /// Returns a [`MovingsIter`] returning an iterator to the entities with visible [`Path`]s.
     #[inline]
     pub(in crate::map::editor::state) fn visible_paths(
@@ -4125,10 +5259,11 @@ impl EntitiesManager
     #[inline]
     pub(in crate::map::editor::state) fn texture_animators(
         &self,
-        bundle: &StateUpdateBundle
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog
     ) -> Animators
     {
-        Animators::new(bundle, self)
+        Animators::new(drawing_resources, things_catalog, self)
     }
 
     /// Draws the UI error highlight.
@@ -154,6 +154,11 @@ impl QuadTree
     #[must_use]
     fn node_mut(&mut self, index: usize) -> &mut Node { self.nodes[index].as_mut().unwrap() }
 
+    /// Returns the [`Hull`] of the entity with [`Id`] `identifier`, if it is stored in the tree.
+    #[inline]
+    #[must_use]
+    pub fn hull(&self, identifier: Id) -> Option<&Hull> { self.entities.get(&identifier) }
+
     /// Stores the ids of the entities at pos in `entities`.
     #[inline]
     pub fn entities_at_pos(&self, entities: &mut QuadTreeIds, pos: Vec2)
@@ -377,7 +382,11 @@ impl QuadTreeIds
 
     /// Inserts a ([`Id`], [`Hull`]) pair.
     #[inline]
-    fn insert(&mut self, identifier: Id, hull: &Hull) { self.0.insert(identifier, *hull); }
+    pub fn insert(&mut self, identifier: Id, hull: &Hull) { self.0.insert(identifier, *hull); }
+
+    /// Removes the ([`Id`], [`Hull`]) pair with [`Id`] `identifier`, if any.
+    #[inline]
+    pub fn remove(&mut self, identifier: Id) { _ = self.0.remove(&identifier); }
 
     /// Retains only the elements specified by the predicate.
     #[inline]
@@ -1,11 +1,17 @@
+pub(in crate::map) mod autosave;
 pub(in crate::map) mod clipboard;
 pub(in crate::map) mod core;
 pub(in crate::map) mod editor_state;
 pub(in crate::map) mod edits_history;
 pub mod grid;
 pub(in crate::map) mod inputs_presses;
+pub(in crate::map) mod integrity;
 pub(in crate::map) mod manager;
+pub(in crate::map) mod script;
+pub(in crate::map) mod tabs;
+pub(in crate::map) mod texture_rename;
 pub(in crate::map) mod ui;
+pub(in crate::map) mod validation;
 
 //=======================================================================//
 // MACROS
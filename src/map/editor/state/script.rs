@@ -0,0 +1,554 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{cell::RefCell, fmt::Write, rc::Rc};
+
+use glam::Vec2;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, INT};
+
+use super::manager::{EntitiesManager, TextureResult};
+use crate::{
+    map::{
+        editor::StateUpdateBundle,
+        thing::{catalog::ThingsCatalog, ThingId, ThingInterface}
+    },
+    utils::{
+        collections::{hash_map, HashMap},
+        identifiers::{EntityId, Id}
+    },
+    TextureInterface
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The maximum amount of operations a script can perform, so that endless loops do not freeze
+/// the editor.
+const MAX_OPERATIONS: u64 = 50_000_000;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The result of a function exposed to the scripts.
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// An edit requested by a script, applied to the map once the script has run successfully.
+#[must_use]
+enum Operation
+{
+    /// Set the texture of a brush.
+    SetTexture(Id, String),
+    /// Move a brush.
+    MoveBrush(Id, Vec2),
+    /// Move a vertex of a brush.
+    MoveVertex(Id, u8, Vec2),
+    /// Spawn a thing at a position.
+    SpawnThing(ThingId, Vec2),
+    /// Move a thing.
+    MoveThing(Id, Vec2),
+    /// Select an entity.
+    Select(Id),
+    /// Deselect an entity.
+    Deselect(Id)
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A brush as seen by the scripts.
+#[must_use]
+struct ScriptBrush
+{
+    /// The name of the texture, empty if there is none.
+    texture:  String,
+    /// The vertexes.
+    vertexes: Vec<Vec2>,
+    /// Whether it is selected.
+    selected: bool
+}
+
+//=======================================================================//
+
+/// A [`ThingInstance`] as seen by the scripts.
+#[must_use]
+struct ScriptThing
+{
+    /// The [`ThingId`].
+    thing:    ThingId,
+    /// The name of the [`Thing`].
+    name:     String,
+    /// The position.
+    pos:      Vec2,
+    /// Whether it is selected.
+    selected: bool
+}
+
+//=======================================================================//
+
+/// The state shared by the functions exposed to the scripts. The functions read a snapshot of
+/// the map, which is updated as they queue the edits to be applied.
+#[must_use]
+struct Context
+{
+    /// The brushes.
+    brushes:    HashMap<Id, ScriptBrush>,
+    /// The [`Id`]s of the brushes, sorted.
+    brush_ids:  Vec<Id>,
+    /// The things.
+    things:     HashMap<Id, ScriptThing>,
+    /// The [`Id`]s of the things, sorted.
+    thing_ids:  Vec<Id>,
+    /// The queued edits.
+    operations: Vec<Operation>,
+    /// The printed text.
+    output:     String
+}
+
+impl Context
+{
+    /// Returns a new [`Context`] describing the entities of `manager`.
+    #[inline]
+    fn new(things_catalog: &ThingsCatalog, manager: &EntitiesManager) -> Self
+    {
+        let mut brushes = hash_map![capacity; manager.brushes_amount()];
+        let mut things = hash_map![capacity; manager.things_amount()];
+
+        for brush in manager.brushes().iter()
+        {
+            brushes.insert(brush.id(), ScriptBrush {
+                texture:  brush
+                    .texture_settings()
+                    .map(|settings| settings.name().to_owned())
+                    .unwrap_or_default(),
+                vertexes: brush.vertexes().collect(),
+                selected: manager.is_selected(brush.id())
+            });
+        }
+
+        for thing in manager.things()
+        {
+            things.insert(thing.id(), ScriptThing {
+                thing:    thing.thing_id(),
+                name:     things_catalog.thing_or_error(thing.thing_id()).name().to_owned(),
+                pos:      thing.pos(),
+                selected: manager.is_selected(thing.id())
+            });
+        }
+
+        let mut brush_ids = brushes.keys().copied().collect::<Vec<_>>();
+        brush_ids.sort_unstable_by_key(|id| id.value());
+        let mut thing_ids = things.keys().copied().collect::<Vec<_>>();
+        thing_ids.sort_unstable_by_key(|id| id.value());
+
+        Self {
+            brushes,
+            brush_ids,
+            things,
+            thing_ids,
+            operations: Vec::new(),
+            output: String::new()
+        }
+    }
+
+    /// Returns the [`Id`]s in `ids` of the entities for which `f` returns true, as an [`Array`].
+    #[inline]
+    fn ids(ids: &[Id], f: impl Fn(&Id) -> bool) -> Array
+    {
+        ids.iter()
+            .filter(|id| f(id))
+            .map(|id| Dynamic::from(id_to_int(*id)))
+            .collect()
+    }
+
+    /// Returns the brush with [`Id`] `id`.
+    /// # Errors
+    /// Returns an error if there is no such brush.
+    #[inline]
+    fn brush(&mut self, id: INT) -> ScriptResult<(Id, &mut ScriptBrush)>
+    {
+        let id = int_to_id(id)?;
+
+        match self.brushes.get_mut(&id)
+        {
+            Some(brush) => Ok((id, brush)),
+            None => Err(format!("There is no brush with id {}", id.value()).into())
+        }
+    }
+
+    /// Returns the thing with [`Id`] `id`.
+    /// # Errors
+    /// Returns an error if there is no such thing.
+    #[inline]
+    fn thing(&mut self, id: INT) -> ScriptResult<(Id, &mut ScriptThing)>
+    {
+        let id = int_to_id(id)?;
+
+        match self.things.get_mut(&id)
+        {
+            Some(thing) => Ok((id, thing)),
+            None => Err(format!("There is no thing with id {}", id.value()).into())
+        }
+    }
+
+    /// Returns the selection status of the entity with [`Id`] `id`.
+    /// # Errors
+    /// Returns an error if there is no such entity.
+    #[inline]
+    fn selected(&mut self, id: INT) -> ScriptResult<(Id, &mut bool)>
+    {
+        let id = int_to_id(id)?;
+
+        if let Some(brush) = self.brushes.get_mut(&id)
+        {
+            return Ok((id, &mut brush.selected));
+        }
+
+        match self.things.get_mut(&id)
+        {
+            Some(thing) => Ok((id, &mut thing.selected)),
+            None => Err(format!("There is no entity with id {}", id.value()).into())
+        }
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Converts `id` to the integer used by the scripts.
+#[inline]
+#[must_use]
+fn id_to_int(id: Id) -> INT { INT::try_from(id.value()).unwrap() }
+
+/// Converts `id` to an [`Id`].
+/// # Errors
+/// Returns an error if `id` is negative.
+#[inline]
+fn int_to_id(id: INT) -> ScriptResult<Id>
+{
+    usize::try_from(id)
+        .map(Id::new)
+        .map_err(|_| format!("{id} is not a valid id").into())
+}
+
+/// Converts `value` to an [`f32`].
+/// # Errors
+/// Returns an error if `value` is not a number.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+#[inline]
+fn number(value: &Dynamic) -> ScriptResult<f32>
+{
+    if let Ok(value) = value.as_float()
+    {
+        return Ok(value as f32);
+    }
+
+    value
+        .as_int()
+        .map(|value| value as f32)
+        .map_err(|ty| format!("Expected a number, found {ty}").into())
+}
+
+/// Converts `x` and `y` to a [`Vec2`].
+/// # Errors
+/// Returns an error if either value is not a number.
+#[inline]
+fn vector(x: &Dynamic, y: &Dynamic) -> ScriptResult<Vec2> { Ok(Vec2::new(number(x)?, number(y)?)) }
+
+/// Converts `vec` to an [`Array`] of two numbers.
+#[inline]
+fn array(vec: Vec2) -> Dynamic
+{
+    Dynamic::from_array(vec![
+        Dynamic::from_float(vec.x.into()),
+        Dynamic::from_float(vec.y.into()),
+    ])
+}
+
+/// Returns an [`Engine`] exposing the functions to read and edit the map which operate on
+/// `context`.
+#[inline]
+fn engine(context: &Rc<RefCell<Context>>) -> Engine
+{
+    /// Registers the functions that receive the [`Context`] as their first argument.
+    macro_rules! register {
+        (
+            $engine:ident,
+            $(($name:literal, |$ctx:ident $(, $arg:ident: $t:ty)*| -> $ret:ty $body:block)),+
+        ) => {$({
+            let context = Rc::clone(context);
+
+            $engine.register_fn($name, move |$($arg: $t),*| -> $ret {
+                let $ctx = &mut *context.borrow_mut();
+                $body
+            });
+        })+};
+    }
+
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+
+    let print_context = Rc::clone(context);
+    engine.on_print(move |text| {
+        let output = &mut print_context.borrow_mut().output;
+        output.push_str(text);
+        output.push('\n');
+    });
+
+    let debug_context = Rc::clone(context);
+    engine.on_debug(move |text, _, pos| {
+        _ = writeln!(debug_context.borrow_mut().output, "[{pos}] {text}");
+    });
+
+    register!(
+        engine,
+        ("brushes", |ctx| -> Array { Context::ids(&ctx.brush_ids, |_| true) }),
+        ("selected_brushes", |ctx| -> Array {
+            Context::ids(&ctx.brush_ids, |id| ctx.brushes[id].selected)
+        }),
+        ("things", |ctx| -> Array { Context::ids(&ctx.thing_ids, |_| true) }),
+        ("selected_things", |ctx| -> Array {
+            Context::ids(&ctx.thing_ids, |id| ctx.things[id].selected)
+        }),
+        ("is_selected", |ctx, id: INT| -> ScriptResult<bool> {
+            ctx.selected(id).map(|(_, selected)| *selected)
+        }),
+        ("select", |ctx, id: INT| -> ScriptResult<()> {
+            let (id, selected) = ctx.selected(id)?;
+
+            if !*selected
+            {
+                *selected = true;
+                ctx.operations.push(Operation::Select(id));
+            }
+
+            Ok(())
+        }),
+        ("deselect", |ctx, id: INT| -> ScriptResult<()> {
+            let (id, selected) = ctx.selected(id)?;
+
+            if *selected
+            {
+                *selected = false;
+                ctx.operations.push(Operation::Deselect(id));
+            }
+
+            Ok(())
+        }),
+        ("texture", |ctx, id: INT| -> ScriptResult<String> {
+            ctx.brush(id).map(|(_, brush)| brush.texture.clone())
+        }),
+        ("set_texture", |ctx, id: INT, texture: &str| -> ScriptResult<()> {
+            let (id, brush) = ctx.brush(id)?;
+
+            if brush.texture != texture
+            {
+                texture.clone_into(&mut brush.texture);
+                ctx.operations.push(Operation::SetTexture(id, texture.to_owned()));
+            }
+
+            Ok(())
+        }),
+        ("vertexes", |ctx, id: INT| -> ScriptResult<Array> {
+            ctx.brush(id)
+                .map(|(_, brush)| brush.vertexes.iter().map(|vx| array(*vx)).collect())
+        }),
+        ("move_brush", |ctx, id: INT, x: Dynamic, y: Dynamic| -> ScriptResult<()> {
+            let delta = vector(&x, &y)?;
+            let (id, brush) = ctx.brush(id)?;
+
+            for vx in &mut brush.vertexes
+            {
+                *vx += delta;
+            }
+
+            ctx.operations.push(Operation::MoveBrush(id, delta));
+            Ok(())
+        }),
+        (
+            "move_vertex",
+            |ctx, id: INT, index: INT, x: Dynamic, y: Dynamic| -> ScriptResult<()> {
+                let delta = vector(&x, &y)?;
+                let (id, brush) = ctx.brush(id)?;
+                let len = brush.vertexes.len();
+                let index = u8::try_from(index)
+                    .ok()
+                    .filter(|i| usize::from(*i) < len)
+                    .ok_or_else(|| format!("Vertex index {index} is out of bounds"))?;
+
+                brush.vertexes[usize::from(index)] += delta;
+                ctx.operations.push(Operation::MoveVertex(id, index, delta));
+                Ok(())
+            }
+        ),
+        ("thing_id", |ctx, id: INT| -> ScriptResult<INT> {
+            ctx.thing(id).map(|(_, thing)| INT::from(thing.thing.value()))
+        }),
+        ("thing_name", |ctx, id: INT| -> ScriptResult<String> {
+            ctx.thing(id).map(|(_, thing)| thing.name.clone())
+        }),
+        ("position", |ctx, id: INT| -> ScriptResult<Dynamic> {
+            ctx.thing(id).map(|(_, thing)| array(thing.pos))
+        }),
+        ("move_thing", |ctx, id: INT, x: Dynamic, y: Dynamic| -> ScriptResult<()> {
+            let delta = vector(&x, &y)?;
+            let (id, thing) = ctx.thing(id)?;
+            thing.pos += delta;
+            ctx.operations.push(Operation::MoveThing(id, delta));
+            Ok(())
+        }),
+        ("spawn_thing", |ctx, thing: INT, x: Dynamic, y: Dynamic| -> ScriptResult<()> {
+            let pos = vector(&x, &y)?;
+            let thing =
+                u16::try_from(thing).map_err(|_| format!("{thing} is not a valid thing id"))?;
+            ctx.operations.push(Operation::SpawnThing(ThingId::new(thing), pos));
+            Ok(())
+        })
+    );
+
+    engine
+}
+
+/// Runs the script `source`. If it runs successfully, the edits it requested are applied to the
+/// map and collapsed into a single edit of the history, so that they can be undone in a single
+/// step. Returns the text printed by the script followed by a summary of the outcome.
+#[inline]
+pub(in crate::map::editor::state) fn run(bundle: &mut StateUpdateBundle, source: &str) -> String
+{
+    let context = Rc::new(RefCell::new(Context::new(bundle.things_catalog, bundle.manager)));
+    let result = engine(&context).run(source);
+    let Context {
+        operations,
+        mut output,
+        ..
+    } = Rc::into_inner(context).unwrap().into_inner();
+
+    if let Err(err) = result
+    {
+        _ = writeln!(output, "Error: {err}\nThe map was not edited.");
+        return output;
+    }
+
+    let amount = operations.len();
+    let mut skipped = Vec::new();
+
+    bundle.edits_history.with_group("Script", |edits_history| {
+        for (i, operation) in operations.into_iter().enumerate()
+        {
+            let applied = match operation
+            {
+                Operation::SetTexture(id, texture) =>
+                {
+                    if bundle.drawing_resources.texture(&texture).is_none()
+                    {
+                        skipped.push((i, format!("texture {texture} does not exist")));
+                        continue;
+                    }
+
+                    !matches!(
+                        bundle.manager.set_brushes_textures(
+                            bundle.drawing_resources,
+                            edits_history,
+                            bundle.grid,
+                            &[(id, &texture)]
+                        ),
+                        TextureResult::Invalid
+                    )
+                },
+                Operation::MoveBrush(id, delta) =>
+                {
+                    bundle.manager.move_brush(
+                        bundle.drawing_resources,
+                        edits_history,
+                        bundle.grid,
+                        id,
+                        delta
+                    )
+                },
+                Operation::MoveVertex(id, index, delta) =>
+                {
+                    bundle.manager.move_brush_vertex(
+                        bundle.drawing_resources,
+                        edits_history,
+                        bundle.grid,
+                        id,
+                        index,
+                        delta
+                    )
+                },
+                Operation::SpawnThing(thing, pos) =>
+                {
+                    if bundle.things_catalog.thing(thing).is_none()
+                    {
+                        skipped.push((i, format!("there is no thing with id {}", thing.value())));
+                        continue;
+                    }
+
+                    bundle
+                        .manager
+                        .spawn_thing(
+                            bundle.things_catalog,
+                            bundle.default_properties.map_things,
+                            edits_history,
+                            thing,
+                            pos
+                        )
+                        .is_some()
+                },
+                Operation::MoveThing(id, delta) =>
+                {
+                    bundle
+                        .manager
+                        .move_thing(bundle.things_catalog, edits_history, id, delta)
+                },
+                Operation::Select(id) =>
+                {
+                    if !bundle.manager.is_selected(id)
+                    {
+                        bundle.manager.select_entity(id, bundle.inputs, edits_history);
+                    }
+
+                    true
+                },
+                Operation::Deselect(id) =>
+                {
+                    if bundle.manager.is_selected(id)
+                    {
+                        bundle.manager.deselect_entity(id, bundle.inputs, edits_history);
+                    }
+
+                    true
+                }
+            };
+
+            if !applied
+            {
+                skipped.push((i, "the entity would be invalid or out of bounds".to_owned()));
+            }
+        }
+    });
+
+    bundle.manager.schedule_outline_update();
+
+    for (i, reason) in &skipped
+    {
+        _ = writeln!(output, "Edit {} skipped: {reason}.", i + 1);
+    }
+
+    _ = writeln!(output, "{} of {amount} edits applied.", amount - skipped.len());
+    output
+}
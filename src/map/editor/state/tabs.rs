@@ -0,0 +1,362 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::path::PathBuf;
+
+use bevy::{transform::components::Transform, window::Window};
+
+use super::{
+    autosave::Autosave,
+    clipboard::{Clipboard, MapProps},
+    edits_history::EditsHistory,
+    grid::Grid,
+    manager::EntitiesManager
+};
+use crate::{
+    config::Config,
+    map::{
+        camera::init_camera_transform,
+        drawer::drawing_resources::DrawingResources,
+        editor::AllDefaultProperties,
+        properties::{DefaultBrushProperties, DefaultThingProperties, EngineDefaultProperties}
+    },
+    utils::collections::{hash_map, HashMap},
+    Animation
+};
+
+//=======================================================================//
+// MACROS
+//
+//=======================================================================//
+
+/// Returns an [`EditedMap`] referencing the resources of the map being edited stored in `$bundle`.
+macro_rules! edited_map {
+    ($bundle:expr) => {
+        $crate::map::editor::state::tabs::EditedMap {
+            window:             $bundle.window,
+            config:             $bundle.config,
+            camera:             $bundle.camera,
+            default_properties: $bundle.default_properties,
+            drawing_resources:  $bundle.drawing_resources,
+            manager:            $bundle.manager,
+            clipboard:          $bundle.clipboard,
+            edits_history:      $bundle.edits_history,
+            grid:               $bundle.grid
+        }
+    };
+}
+
+pub(in crate::map::editor) use edited_map;
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A bundle of references to the resources storing the map being edited.
+#[allow(clippy::missing_docs_in_private_items)]
+#[must_use]
+pub(in crate::map::editor) struct EditedMap<'a, 'b>
+{
+    pub window:             &'a mut Window,
+    pub config:             &'a mut Config,
+    pub camera:             &'a mut Transform,
+    pub default_properties: &'a mut AllDefaultProperties<'b>,
+    pub drawing_resources:  &'a mut DrawingResources,
+    pub manager:            &'a mut EntitiesManager,
+    pub clipboard:          &'a mut Clipboard,
+    pub edits_history:      &'a mut EditsHistory,
+    pub grid:               &'a mut Grid
+}
+
+impl<'b> EditedMap<'_, 'b>
+{
+    /// Returns a shorter lived copy of `self`.
+    #[inline]
+    pub(in crate::map::editor::state) fn reborrow(&mut self) -> EditedMap<'_, 'b>
+    {
+        EditedMap {
+            window:             self.window,
+            config:             self.config,
+            camera:             self.camera,
+            default_properties: self.default_properties,
+            drawing_resources:  self.drawing_resources,
+            manager:            self.manager,
+            clipboard:          self.clipboard,
+            edits_history:      self.edits_history,
+            grid:               self.grid
+        }
+    }
+}
+
+//=======================================================================//
+
+/// A map open in a tab other than the one being edited.
+#[must_use]
+struct MapTab
+{
+    /// The entities.
+    manager:                  EntitiesManager,
+    /// The [`Prop`]s stored in the map file.
+    map_props:                MapProps,
+    /// The history of the edits.
+    edits_history:            EditsHistory,
+    /// The grid.
+    grid:                     Grid,
+    /// The transform of the camera.
+    camera:                   Transform,
+    /// The path of the file, if any.
+    path:                     Option<PathBuf>,
+    /// The default properties of the brushes.
+    default_brush_properties: DefaultBrushProperties,
+    /// The default properties of the things.
+    default_thing_properties: DefaultThingProperties,
+    /// The default texture animations.
+    animations:               HashMap<String, Animation>,
+    /// Whether the default texture animations were edited.
+    animations_changed:       bool,
+    /// The writer of the autosave snapshots.
+    autosave:                 Autosave
+}
+
+impl MapTab
+{
+    /// Returns a new [`MapTab`] storing an empty map.
+    #[inline]
+    fn new(default_properties: &AllDefaultProperties) -> Self
+    {
+        Self {
+            manager:                  EntitiesManager::new(),
+            map_props:                MapProps::default(),
+            edits_history:            EditsHistory::default(),
+            grid:                     Grid::default(),
+            camera:                   init_camera_transform(),
+            path:                     None,
+            default_brush_properties: default_properties.engine_brushes.inner(),
+            default_thing_properties: default_properties.engine_things.inner(),
+            animations:               hash_map![],
+            animations_changed:       false,
+            autosave:                 Autosave::default()
+        }
+    }
+
+    /// The name of the map with file at `path` to be shown in the tab bar, if any.
+    #[inline]
+    #[must_use]
+    fn name(path: Option<&PathBuf>) -> Option<String>
+    {
+        path.and_then(|path| path.file_stem())
+            .map(|stem| stem.to_string_lossy().into_owned())
+    }
+
+    /// Whether the map has no unsaved edits.
+    #[inline]
+    #[must_use]
+    fn no_edits(&self) -> bool
+    {
+        self.edits_history.no_unsaved_edits() &&
+            !self.map_props.props_changed() &&
+            !self.animations_changed &&
+            !self.manager.loaded_file_modified() &&
+            !self.manager.metadata_changed() &&
+            !self.grid.changed()
+    }
+
+    /// Swaps the map stored in `self` with the one being edited, whose autosave writer is
+    /// `autosave`.
+    #[inline]
+    fn swap(&mut self, map: EditedMap, autosave: &mut Autosave)
+    {
+        // The events not sent yet belong to the map being edited before the swap.
+        self.manager.take_entity_events(map.manager);
+        self.edits_history.take_events(map.edits_history);
+        std::mem::swap(map.manager, &mut self.manager);
+        map.clipboard.swap_map_props(&mut self.map_props);
+        std::mem::swap(map.edits_history, &mut self.edits_history);
+        std::mem::swap(map.grid, &mut self.grid);
+        std::mem::swap(map.camera, &mut self.camera);
+        std::mem::swap(map.default_properties.map_brushes, &mut self.default_brush_properties);
+        std::mem::swap(map.default_properties.map_things, &mut self.default_thing_properties);
+
+        (self.animations, self.animations_changed) = map
+            .drawing_resources
+            .swap_animations(std::mem::take(&mut self.animations), self.animations_changed);
+        self.path = map.config.open_file.replace(self.path.take(), map.window);
+        std::mem::swap(autosave, &mut self.autosave);
+    }
+
+    /// Replaces the texture `prev` with `new` in the brushes, the [`Prop`]s, and the default
+    /// animations. The texture edits are purged from the history since they reference `prev`.
+    #[inline]
+    fn rename_texture(&mut self, drawing_resources: &DrawingResources, prev: &str, new: &str)
+    {
+        self.manager.rename_texture(drawing_resources, &self.grid, prev, new);
+        self.map_props.rename_texture(prev, new);
+
+        if let Some(animation) = self.animations.remove(prev)
+        {
+            _ = self.animations.insert(new.to_owned(), animation);
+            self.animations_changed = true;
+        }
+
+        for animation in self.animations.values_mut()
+        {
+            self.animations_changed |= animation.rename_texture(prev, new);
+        }
+
+        self.edits_history.purge_texture_edits();
+    }
+}
+
+//=======================================================================//
+
+/// The maps open in the editor, one per tab.
+#[must_use]
+pub(in crate::map::editor::state) struct Tabs
+{
+    /// The maps of the tabs, `None` at the index of the one being edited.
+    tabs:     Vec<Option<MapTab>>,
+    /// The index of the tab of the map being edited.
+    active:   usize,
+    /// The writer of the autosave snapshots of the map being edited.
+    autosave: Autosave
+}
+
+impl Default for Tabs
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            tabs:     vec![None],
+            active:   0,
+            autosave: Autosave::default()
+        }
+    }
+}
+
+impl Tabs
+{
+    /// Returns the amount of tabs.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize { self.tabs.len() }
+
+    /// Returns the index of the tab of the map being edited.
+    #[inline]
+    #[must_use]
+    pub const fn active(&self) -> usize { self.active }
+
+    /// Returns an iterator to the names of the maps of the tabs, `None` for the ones that were not
+    /// saved to file yet. `path` is the path of the file of the map being edited.
+    #[inline]
+    pub fn names<'a>(
+        &'a self,
+        path: Option<&'a PathBuf>
+    ) -> impl Iterator<Item = Option<String>> + 'a
+    {
+        self.tabs.iter().map(move |tab| {
+            match tab
+            {
+                Some(tab) => MapTab::name(tab.path.as_ref()),
+                None => MapTab::name(path)
+            }
+        })
+    }
+
+    /// Whether the map of the tab at `index`, which is not the one being edited, has unsaved
+    /// edits.
+    #[inline]
+    #[must_use]
+    pub fn unsaved_edits(&self, index: usize) -> bool
+    {
+        !self.tabs[index].as_ref().unwrap().no_edits()
+    }
+
+    /// Writes the autosave snapshots of the map being edited, whose file is at `path`, if any.
+    /// See [`Autosave::update`].
+    #[inline]
+    pub fn autosave<F>(&mut self, delta_time: f32, interval: f32, path: Option<&PathBuf>, data: F)
+    where
+        F: FnOnce() -> Option<Vec<u8>>
+    {
+        self.autosave.update(delta_time, interval, path, data);
+    }
+
+    /// Immediately writes the autosave snapshot of the map being edited, whose file is at `path`,
+    /// if any. See [`Autosave::flush`].
+    #[inline]
+    pub fn flush_autosave<F>(&mut self, interval: f32, path: Option<&PathBuf>, data: F)
+    where
+        F: FnOnce() -> Option<Vec<u8>>
+    {
+        self.autosave.flush(interval, path, data);
+    }
+
+    /// Stores the map being edited in its tab and opens a new tab with an empty map.
+    #[inline]
+    pub fn open_new(&mut self, map: EditedMap)
+    {
+        let mut tab = MapTab::new(map.default_properties);
+        tab.swap(map, &mut self.autosave);
+        self.tabs[self.active] = Some(tab);
+        self.tabs.push(None);
+        self.active = self.tabs.len() - 1;
+    }
+
+    /// Switches to the map of the tab at `index`.
+    /// Returns whether the map being edited changed.
+    #[inline]
+    pub fn select(&mut self, map: EditedMap, index: usize) -> bool
+    {
+        if index == self.active
+        {
+            return false;
+        }
+
+        let mut tab = self.tabs[index].take().unwrap();
+        tab.swap(map, &mut self.autosave);
+        self.tabs[self.active] = Some(tab);
+        self.active = index;
+        true
+    }
+
+    /// Closes the tab of the map being edited, discarding the map, and switches to the one of a
+    /// neighboring tab.
+    /// Returns the [`Prop`]s of the discarded map, if a tab was closed.
+    #[inline]
+    pub fn close_active(&mut self, map: EditedMap) -> Option<MapProps>
+    {
+        if self.tabs.len() == 1
+        {
+            return None;
+        }
+
+        let index = if self.active == self.tabs.len() - 1
+        {
+            self.active - 1
+        }
+        else
+        {
+            self.active + 1
+        };
+        let mut tab = self.tabs[index].take().unwrap();
+        tab.swap(map, &mut self.autosave);
+        self.tabs.remove(self.active);
+        self.active = index.min(self.active);
+        Some(tab.map_props)
+    }
+
+    /// Replaces the texture `prev` with `new` in the maps stored in the tabs other than the one
+    /// being edited.
+    #[inline]
+    pub fn rename_texture(&mut self, drawing_resources: &DrawingResources, prev: &str, new: &str)
+    {
+        for tab in self.tabs.iter_mut().flatten()
+        {
+            tab.rename_texture(drawing_resources, prev, new);
+        }
+    }
+}
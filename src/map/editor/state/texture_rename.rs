@@ -0,0 +1,351 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf}
+};
+
+use ciborium::Value;
+use hill_vacuum_shared::FILE_EXTENSION;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    clipboard::prop::PropViewer,
+    editor_state::{ANIMATIONS_EXTENSION, PROPS_EXTENSION},
+    grid::Grid,
+    manager::EntitiesManager
+};
+use crate::map::{
+    brush::BrushViewer,
+    drawer::{
+        drawing_resources::DrawingResources,
+        texture::DefaultAnimation,
+        texture_loader::TextureLoader
+    },
+    version_number,
+    MapHeader,
+    FILE_VERSION,
+    PREVIOUS_FILE_VERSION
+};
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Renames the texture `prev` to `new` in its image file, in the loaded textures, and in the
+/// brushes of the map being edited. The changes are not stored in the edits history.
+/// # Errors
+/// Returns an error if the image file could not be renamed.
+#[inline]
+pub(in crate::map::editor::state) fn rename_texture(
+    drawing_resources: &mut DrawingResources,
+    manager: &mut EntitiesManager,
+    grid: &Grid,
+    prev: &str,
+    new: &str
+) -> Result<(), &'static str>
+{
+    TextureLoader::rename_texture_file(prev, new)?;
+
+    assert!(
+        drawing_resources.rename_texture(prev, new),
+        "Texture {prev} could not be renamed."
+    );
+    manager.rename_texture(drawing_resources, grid, prev, new);
+    Ok(())
+}
+
+//=======================================================================//
+
+/// Reads a value of type `T` from `file`.
+#[inline]
+fn read<T: DeserializeOwned>(file: &mut BufReader<File>) -> Result<T, &'static str>
+{
+    ciborium::from_reader(&mut *file).map_err(|_| "Error reading the file")
+}
+
+//=======================================================================//
+
+/// Writes `value` at the end of `data`.
+#[inline]
+fn write<T: Serialize + ?Sized>(value: &T, data: &mut Vec<u8>) -> Result<(), &'static str>
+{
+    ciborium::ser::into_writer(value, data).map_err(|_| "Error writing the file")
+}
+
+//=======================================================================//
+
+/// Copies `amount` values of type `T` from `file` to `data`, calling `rename` on each one of them.
+/// Returns whether `rename` changed any of them.
+#[inline]
+fn copy_sections<T, F>(
+    file: &mut BufReader<File>,
+    data: &mut Vec<u8>,
+    amount: usize,
+    mut rename: F
+) -> Result<bool, &'static str>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnMut(&mut T) -> bool
+{
+    let mut renamed = false;
+
+    for _ in 0..amount
+    {
+        let mut value = read::<T>(file)?;
+        renamed |= rename(&mut value);
+        write(&value, data)?;
+    }
+
+    Ok(renamed)
+}
+
+//=======================================================================//
+
+/// Copies `amount` values from `file` to `data` without editing them.
+#[inline]
+fn copy_untouched(
+    file: &mut BufReader<File>,
+    data: &mut Vec<u8>,
+    amount: usize
+) -> Result<(), &'static str>
+{
+    copy_sections::<Value, _>(file, data, amount, |_| false).map(|_| ())
+}
+
+//=======================================================================//
+
+/// Whether the end of `file` was reached.
+#[inline]
+fn end_reached(file: &mut BufReader<File>) -> Result<bool, &'static str>
+{
+    Ok(file.fill_buf().map_err(|_| "Error reading the file")?.is_empty())
+}
+
+//=======================================================================//
+
+/// Replaces the texture `prev` with `new` in `animation`, both as the texture it belongs to and in
+/// the frames of its list animation. Returns whether anything was changed.
+#[inline]
+#[must_use]
+fn rename_in_animation(animation: &mut DefaultAnimation, prev: &str, new: &str) -> bool
+{
+    let mut renamed = animation.animation.rename_texture(prev, new);
+
+    if animation.texture == prev
+    {
+        new.clone_into(&mut animation.texture);
+        renamed = true;
+    }
+
+    renamed
+}
+
+//=======================================================================//
+
+/// Replaces the texture `prev` with `new` in the [`TextureSettings`] of `brush`, if any. Returns
+/// whether anything was changed.
+#[inline]
+#[must_use]
+fn rename_in_brush(brush: &mut BrushViewer, prev: &str, new: &str) -> bool
+{
+    brush
+        .texture
+        .as_mut()
+        .is_some_and(|texture| texture.rename_texture(prev, new))
+}
+
+//=======================================================================//
+
+/// Copies the sections of the map file `file` following the version number to `data`, replacing
+/// the texture `prev` with `new` in the default animations, the brushes, the props, and the
+/// recently used textures. Returns whether anything was changed.
+#[inline]
+fn rename_in_map(
+    file: &mut BufReader<File>,
+    data: &mut Vec<u8>,
+    version: &str,
+    prev: &str,
+    new: &str
+) -> Result<bool, &'static str>
+{
+    let header = read::<MapHeader>(file)?;
+    write(&header, data)?;
+
+    // Grid.
+    copy_untouched(file, data, 1)?;
+
+    let mut renamed = copy_sections(file, data, header.animations, |animation| {
+        rename_in_animation(animation, prev, new)
+    })?;
+
+    // Default properties.
+    copy_untouched(file, data, 2)?;
+
+    renamed |=
+        copy_sections(file, data, header.brushes, |brush| rename_in_brush(brush, prev, new))?;
+
+    // Things.
+    copy_untouched(file, data, header.things)?;
+
+    // Metadata.
+    if version != PREVIOUS_FILE_VERSION
+    {
+        copy_untouched(file, data, 1)?;
+    }
+
+    renamed |= copy_sections(file, data, header.props, |prop: &mut PropViewer| {
+        prop.rename_texture(prev, new)
+    })?;
+    renamed |=
+        copy_sections(file, data, header.quick_props, |(_, prop): &mut (usize, PropViewer)| {
+            prop.rename_texture(prev, new)
+        })?;
+
+    // Files saved before the recently used textures were stored simply end here.
+    if end_reached(file)?
+    {
+        return Ok(renamed);
+    }
+
+    renamed |= copy_sections(file, data, 1, |textures: &mut Vec<String>| {
+        let mut renamed = false;
+
+        for name in textures.iter_mut().filter(|name| *name == prev)
+        {
+            new.clone_into(name);
+            renamed = true;
+        }
+
+        renamed
+    })?;
+
+    // Statistics.
+    while !end_reached(file)?
+    {
+        copy_untouched(file, data, 1)?;
+    }
+
+    Ok(renamed)
+}
+
+//=======================================================================//
+
+/// Replaces the texture `prev` with `new` in the map, animations, or props file at `path`.
+/// The file is rewritten only if it references `prev`.
+/// Returns whether the file was rewritten.
+/// # Errors
+/// Returns an error if the file could not be read or written, or if its version is not supported.
+#[inline]
+fn rename_in_file(path: &Path, prev: &str, new: &str) -> Result<bool, &'static str>
+{
+    let mut file = BufReader::new(File::open(path).map_err(|_| "Could not open the file")?);
+    let version = version_number(&mut file)?;
+
+    if version != FILE_VERSION && version != PREVIOUS_FILE_VERSION
+    {
+        return Err("Unsupported file version");
+    }
+
+    let mut data = Vec::new();
+    write(version.as_str(), &mut data)?;
+
+    let renamed = match path.extension().and_then(|ext| ext.to_str())
+    {
+        Some(ANIMATIONS_EXTENSION) =>
+        {
+            let len = read::<usize>(&mut file)?;
+            write(&len, &mut data)?;
+            copy_sections(&mut file, &mut data, len, |animation| {
+                rename_in_animation(animation, prev, new)
+            })?
+        },
+        Some(PROPS_EXTENSION) =>
+        {
+            let len = read::<usize>(&mut file)?;
+            write(&len, &mut data)?;
+            copy_sections(&mut file, &mut data, len, |prop: &mut PropViewer| {
+                prop.rename_texture(prev, new)
+            })?
+        },
+        _ => rename_in_map(&mut file, &mut data, &version, prev, new)?
+    };
+
+    if !renamed
+    {
+        return Ok(false);
+    }
+
+    drop(file);
+    std::fs::write(path, data).map_err(|_| "Error writing the file")?;
+    Ok(true)
+}
+
+//=======================================================================//
+
+/// Replaces the texture `prev` with `new` in the map, animations, and props files contained in
+/// `folder`, except `skip`, which is the map being edited.
+/// Returns the amount of rewritten files and the names of the ones that could not be rewritten,
+/// along with the reason.
+#[inline]
+#[must_use]
+pub(in crate::map::editor::state) fn rename_in_folder(
+    folder: &Path,
+    skip: &Path,
+    prev: &str,
+    new: &str
+) -> (usize, Vec<String>)
+{
+    let mut rewritten = 0;
+    let mut errors = Vec::new();
+
+    for path in std::fs::read_dir(folder)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| {
+                [FILE_EXTENSION, ANIMATIONS_EXTENSION, PROPS_EXTENSION].contains(&ext)
+            })
+        })
+        .filter(|path| !same_file(path, skip))
+    {
+        match rename_in_file(&path, prev, new)
+        {
+            Ok(true) => rewritten += 1,
+            Ok(false) => (),
+            Err(err) =>
+            {
+                errors.push(format!(
+                    "{}: {err}",
+                    path.file_name().unwrap().to_str().unwrap_or_default()
+                ));
+            }
+        };
+    }
+
+    (rewritten, errors)
+}
+
+//=======================================================================//
+
+/// Whether `a` and `b` are the path of the same file.
+#[inline]
+#[must_use]
+fn same_file(a: &Path, b: &Path) -> bool
+{
+    /// Returns the canonical form of `path`, if possible.
+    #[inline]
+    #[must_use]
+    fn canonical(path: &Path) -> PathBuf
+    {
+        std::fs::canonicalize(path).unwrap_or_else(|_| path.to_owned())
+    }
+
+    canonical(a) == canonical(b)
+}
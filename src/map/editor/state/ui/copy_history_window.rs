@@ -0,0 +1,125 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::utils::misc::Toggle;
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The size of the previews of the copied entities.
+const PREVIEW_SIZE: egui::Vec2 = egui::Vec2::splat(96f32);
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window to paste the entities stored in the copy history.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct CopyHistoryWindow
+{
+    /// The window.
+    window: Window
+}
+
+impl Toggle for CopyHistoryWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for CopyHistoryWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut CopyHistoryWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::CopyHistory(id, close as fn(&mut Self)))
+    }
+}
+
+impl CopyHistoryWindow
+{
+    /// Opens the window.
+    #[inline]
+    pub fn open(&mut self) { self.window.open(); }
+
+    /// Shows the window, stores in `command` the request to paste the clicked entry of the copy
+    /// history.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let clipboard = &*bundle.clipboard;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Paste from history"))
+                    .id("copy_history_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    if !clipboard.has_copy_history()
+                    {
+                        ui.label(locale.tr("No copied entities"));
+                        return;
+                    }
+
+                    ui.add_enabled_ui(enabled, |ui| {
+                        ui.horizontal_wrapped(|ui| {
+                            for (i, (index, screenshot)) in
+                                clipboard.copy_history_iter().enumerate()
+                            {
+                                let response = match screenshot
+                                {
+                                    Some(tex_id) =>
+                                    {
+                                        ui.add(egui::ImageButton::new((tex_id, PREVIEW_SIZE)))
+                                    },
+                                    None =>
+                                    {
+                                        ui.add_sized(
+                                            PREVIEW_SIZE,
+                                            egui::Button::new((i + 1).to_string())
+                                        )
+                                    },
+                                };
+
+                                if response.clicked()
+                                {
+                                    *command = Command::PasteFromHistory(index);
+                                }
+                            }
+                        });
+                    });
+                }
+            )
+            .unwrap_or_default()
+    }
+}
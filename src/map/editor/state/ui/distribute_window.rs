@@ -0,0 +1,344 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use glam::Vec2;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::editor::state::manager::EntitiesManager,
+    utils::misc::Toggle,
+    Id
+};
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The guide along which the copies are placed.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(in crate::map::editor::state) enum DistributionGuide
+{
+    /// The [`Path`](crate::map::path::Path) of the entity with the [`Id`].
+    Path(Id),
+    /// The line going from the first point to the second.
+    Line(Vec2, Vec2)
+}
+
+//=======================================================================//
+
+/// How the copies are spaced along the guide.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(in crate::map::editor::state) enum DistributionSpacing
+{
+    /// The amount of copies, evenly spaced from the start to the end of the guide.
+    Count(u16),
+    /// The distance between two consecutive copies.
+    Distance(f32)
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The request to distribute copies of the selected entities along a guide.
+#[must_use]
+#[derive(Clone, Copy)]
+pub(in crate::map::editor::state) struct Distribution
+{
+    /// The guide.
+    guide:   DistributionGuide,
+    /// The spacing.
+    spacing: DistributionSpacing
+}
+
+impl Distribution
+{
+    /// Returns the positions of the centers of the copies.
+    #[inline]
+    #[must_use]
+    pub fn positions(self, manager: &EntitiesManager) -> Vec<Vec2>
+    {
+        /// Returns the point of `guide` at `distance` from its start.
+        #[inline]
+        #[must_use]
+        fn point_at_distance(guide: &[Vec2], mut distance: f32) -> Vec2
+        {
+            for side in guide.windows(2)
+            {
+                let len = side[0].distance(side[1]);
+
+                if distance <= len
+                {
+                    return side[0] + (side[1] - side[0]).normalize_or_zero() * distance;
+                }
+
+                distance -= len;
+            }
+
+            *guide.last().unwrap()
+        }
+
+        let guide = match self.guide
+        {
+            DistributionGuide::Path(id) =>
+            {
+                let entity = manager.moving(id);
+                let center = entity.center();
+                let path = entity.path().unwrap();
+
+                (0..path.len())
+                    .map(|i| path.node_at_index_pos(i) + center)
+                    .collect::<Vec<_>>()
+            },
+            DistributionGuide::Line(start, end) => vec![start, end]
+        };
+
+        let length = guide.windows(2).map(|side| side[0].distance(side[1])).sum::<f32>();
+
+        match self.spacing
+        {
+            DistributionSpacing::Count(1) => vec![guide[0]],
+            DistributionSpacing::Count(count) =>
+            {
+                let step = length / f32::from(count - 1);
+
+                (0..count)
+                    .map(|i| point_at_distance(&guide, step * f32::from(i)))
+                    .collect()
+            },
+            DistributionSpacing::Distance(distance) =>
+            {
+                std::iter::successors(Some(0f32), |d| Some(d + distance))
+                    .take_while(|d| *d <= length)
+                    .map(|d| point_at_distance(&guide, d))
+                    .collect()
+            },
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The window to distribute copies of the selected entities along a [`Path`] or a line.
+///
+/// [`Path`]: crate::map::path::Path
+pub(in crate::map::editor::state::ui) struct DistributeWindow
+{
+    /// The window.
+    window:     Window,
+    /// Whether the copies are distributed along the path of an entity rather than a line.
+    along_path: bool,
+    /// The typed [`Id`] of the entity owning the path.
+    path_owner: String,
+    /// The start and end of the line.
+    line:       [Vec2; 2],
+    /// Whether the copies are placed by amount rather than by distance.
+    by_count:   bool,
+    /// The amount of copies.
+    count:      u16,
+    /// The distance between the copies.
+    distance:   f32
+}
+
+impl Default for DistributeWindow
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:     Window::default(),
+            along_path: true,
+            path_owner: String::new(),
+            line:       [Vec2::ZERO; 2],
+            by_count:   true,
+            count:      2,
+            distance:   64f32
+        }
+    }
+}
+
+impl Toggle for DistributeWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for DistributeWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut DistributeWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Distribute(id, close as fn(&mut Self)))
+    }
+}
+
+impl DistributeWindow
+{
+    /// Opens the window.
+    #[inline]
+    pub fn open(&mut self) { self.window.open(); }
+
+    /// Returns the [`Id`] of the entity with a path typed in the window, if valid.
+    #[inline]
+    #[must_use]
+    fn path_owner(&self, manager: &EntitiesManager) -> Option<Id>
+    {
+        let id = Id::new(self.path_owner.trim().parse::<usize>().ok()?);
+        (manager.entity_exists(id) && manager.moving(id).has_path()).then_some(id)
+    }
+
+    /// Shows the window, stores in `command` the request to distribute the selected entities.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let path_owner = self.path_owner(bundle.manager);
+        let mut distribution = None;
+
+        let Self {
+            window,
+            along_path,
+            path_owner: typed_id,
+            line,
+            by_count,
+            count,
+            distance
+        } = self;
+
+        let focused = window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Distribute"))
+                    .id("distribute_window".into())
+                    .collapsible(false)
+                    .resizable(false),
+                |ui| {
+                    /// Adds the fields to edit `point`.
+                    #[inline]
+                    fn point(ui: &mut egui::Ui, label: &str, point: &mut Vec2)
+                    {
+                        ui.label(label);
+                        ui.horizontal(|ui| {
+                            ui.add(egui::DragValue::new(&mut point.x).prefix("x: "));
+                            ui.add(egui::DragValue::new(&mut point.y).prefix("y: "));
+                        });
+                        ui.end_row();
+                    }
+
+                    egui::Grid::new("distribute_grid")
+                        .num_columns(2)
+                        .spacing([40f32, 4f32])
+                        .show(ui, |ui| {
+                            ui.label(locale.tr("Guide"));
+                            ui.horizontal(|ui| {
+                                ui.radio_value(along_path, true, locale.tr("Path"));
+                                ui.radio_value(along_path, false, locale.tr("Line"));
+                            });
+                            ui.end_row();
+
+                            if *along_path
+                            {
+                                ui.label("Id");
+                                ui.horizontal(|ui| {
+                                    ui.text_edit_singleline(typed_id);
+
+                                    if !typed_id.is_empty() && path_owner.is_none()
+                                    {
+                                        ui.label(locale.tr("No entity with a path"));
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                            else
+                            {
+                                point(ui, locale.tr("Start"), &mut line[0]);
+                                point(ui, locale.tr("End"), &mut line[1]);
+                            }
+
+                            ui.label(locale.tr("Spacing"));
+                            ui.horizontal(|ui| {
+                                ui.radio_value(by_count, true, locale.tr("Count"));
+                                ui.radio_value(by_count, false, locale.tr("Distance"));
+                            });
+                            ui.end_row();
+
+                            if *by_count
+                            {
+                                ui.label(locale.tr("Count"));
+                                ui.add(egui::DragValue::new(count).range(1..=1024));
+                            }
+                            else
+                            {
+                                ui.label(locale.tr("Distance"));
+                                ui.add(egui::DragValue::new(distance).range(1f32..=f32::MAX));
+                            }
+                            ui.end_row();
+                        });
+
+                    let guide = if *along_path
+                    {
+                        path_owner.map(DistributionGuide::Path)
+                    }
+                    else
+                    {
+                        (line[0] != line[1]).then_some(DistributionGuide::Line(line[0], line[1]))
+                    };
+
+                    if ui
+                        .add_enabled(
+                            enabled && guide.is_some(),
+                            egui::Button::new(locale.tr("Distribute"))
+                        )
+                        .clicked()
+                    {
+                        distribution = Distribution {
+                            guide:   guide.unwrap(),
+                            spacing: if *by_count
+                            {
+                                DistributionSpacing::Count(*count)
+                            }
+                            else
+                            {
+                                DistributionSpacing::Distance(*distance)
+                            }
+                        }
+                        .into();
+                    }
+                }
+            )
+            .unwrap_or_default();
+
+        if let Some(distribution) = distribution
+        {
+            *command = Command::Distribute(distribution);
+        }
+
+        focused
+    }
+}
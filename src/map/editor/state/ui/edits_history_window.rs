@@ -17,14 +17,20 @@ use crate::{
 //
 //=======================================================================//
 
-/// The manual window.
+/// The edits history window.
 #[derive(Default)]
-pub(in crate::map::editor::state::ui) struct EditsHistoryWindow(Window);
+pub(in crate::map::editor::state::ui) struct EditsHistoryWindow
+{
+    /// The window.
+    window:  Window,
+    /// The index of the hovered entry, if any.
+    hovered: Option<usize>
+}
 
 impl Toggle for EditsHistoryWindow
 {
     #[inline]
-    fn toggle(&mut self) { self.0.toggle() }
+    fn toggle(&mut self) { self.window.toggle() }
 }
 
 impl WindowCloserInfo for EditsHistoryWindow
@@ -34,9 +40,9 @@ impl WindowCloserInfo for EditsHistoryWindow
     {
         /// Calls the close function.
         #[inline]
-        fn close(window: &mut EditsHistoryWindow) { window.0.close() }
+        fn close(window: &mut EditsHistoryWindow) { window.window.close() }
 
-        self.0
+        self.window
             .layer_id()
             .map(|id| WindowCloser::EditsHistory(id, close as fn(&mut Self)))
     }
@@ -44,6 +50,12 @@ impl WindowCloserInfo for EditsHistoryWindow
 
 impl EditsHistoryWindow
 {
+    /// Returns the index of the hovered entry, if any.
+    #[inline]
+    #[must_use]
+    pub const fn hovered(&self) -> Option<usize> { self.hovered }
+
+    /// Shows the window. Returns the index of the clicked entry, if any.
     #[inline]
     pub fn show(
         &mut self,
@@ -59,17 +71,21 @@ impl EditsHistoryWindow
             ..
         } = bundle;
 
-        if !self.0.check_open(Bind::EditsHistory.just_pressed(key_inputs, binds))
+        self.hovered = None;
+
+        if !self
+            .window
+            .check_open(Bind::EditsHistory.just_pressed(key_inputs, binds))
         {
             return None;
         }
 
         let mut clicked = None;
 
-        self.0
+        self.window
             .show(egui_context, egui::Window::new("Edits History").vscroll(true), |ui| {
                 ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
-                    clicked = edits_history.show(ui, core);
+                    (clicked, self.hovered) = edits_history.show(ui, core);
                 });
             });
 
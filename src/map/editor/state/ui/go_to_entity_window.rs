@@ -0,0 +1,119 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{utils::misc::Toggle, Id};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window to type the [`Id`] of the entity to select and frame.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct GoToEntityWindow
+{
+    /// The window.
+    window: Window,
+    /// The typed [`Id`].
+    id:     String
+}
+
+impl Toggle for GoToEntityWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for GoToEntityWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut GoToEntityWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::GoToEntity(id, close as fn(&mut Self)))
+    }
+}
+
+impl GoToEntityWindow
+{
+    /// Opens the window.
+    #[inline]
+    pub fn open(&mut self) { self.window.open(); }
+
+    /// Shows the window, stores in `command` the request to go to the typed entity.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let mut close = false;
+        let id = &mut self.id;
+
+        let focused = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Go to entity"))
+                    .id("go_to_entity_window".into())
+                    .collapsible(false)
+                    .resizable(false),
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Id");
+
+                        let response = ui.text_edit_singleline(id);
+                        let value = id.trim().parse::<usize>().ok();
+                        let valid =
+                            value.is_some_and(|value| bundle.manager.entity_exists(Id::new(value)));
+
+                        if !id.is_empty() && !valid
+                        {
+                            ui.label(locale.tr("No such entity"));
+                        }
+
+                        let confirmed = response.lost_focus() &&
+                            ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+                        if ui
+                            .add_enabled(enabled && valid, egui::Button::new(locale.tr("Go")))
+                            .clicked() ||
+                            (enabled && valid && confirmed)
+                        {
+                            *command = Command::GoToEntity(Id::new(value.unwrap()));
+                            close = true;
+                        }
+                    });
+                }
+            )
+            .unwrap_or_default();
+
+        if close
+        {
+            self.window.close();
+        }
+
+        focused
+    }
+}
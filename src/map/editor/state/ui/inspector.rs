@@ -0,0 +1,259 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use glam::Vec2;
+
+use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    config::{controls::bind::Bind, Config},
+    map::{
+        editor::cursor::Cursor,
+        path::Moving,
+        properties::{DefaultProperties, Properties},
+        thing::ThingInterface
+    },
+    utils::{identifiers::EntityId, misc::Toggle},
+    Id,
+    TextureInterface
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window showing the information of the entity beneath the cursor.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct Inspector
+{
+    /// The window.
+    window: Window,
+    /// The inspected entity, if any.
+    entity: Option<Id>,
+    /// Whether the inspected entity should not change when hovering other entities.
+    pinned: bool
+}
+
+impl Toggle for Inspector
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for Inspector
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(inspector: &mut Inspector) { inspector.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Inspector(id, close as fn(&mut Self)))
+    }
+}
+
+impl Inspector
+{
+    /// Returns the [`Id`] of the topmost entity beneath `cursor_pos`, if any.
+    /// Things are drawn on top of brushes, so they have priority.
+    #[inline]
+    #[must_use]
+    fn entity_beneath_cursor(bundle: &UiBundle, cursor_pos: Vec2) -> Option<Id>
+    {
+        bundle
+            .manager
+            .things_at_pos(cursor_pos, None)
+            .iter()
+            .filter(|thing| thing.contains_point(bundle.things_catalog, cursor_pos))
+            .max_by_key(|thing| thing.draw_height())
+            .map(EntityId::id)
+            .or_else(|| {
+                bundle
+                    .manager
+                    .brushes_at_pos(cursor_pos, None)
+                    .iter()
+                    .filter(|brush| brush.contains_point(cursor_pos))
+                    .max_by_key(|brush| {
+                        brush.texture_settings().map_or(i8::MIN, TextureInterface::height)
+                    })
+                    .map(EntityId::id)
+            })
+    }
+
+    /// Shows the inspector window.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &mut UiBundle,
+        cursor: &Cursor
+    ) -> bool
+    {
+        if !self
+            .window
+            .check_open(Bind::Inspector.just_pressed(bundle.key_inputs, &bundle.config.binds))
+        {
+            return false;
+        }
+
+        if self.entity.is_some_and(|id| !bundle.manager.entity_exists(id))
+        {
+            self.entity = None;
+        }
+
+        // Do not update the entity while the cursor is on top of the UI.
+        if !self.pinned && !egui_context.is_pointer_over_area()
+        {
+            self.entity = Self::entity_beneath_cursor(bundle, cursor.world());
+        }
+
+        let UiBundle {
+            config: Config { locale, .. },
+            things_catalog,
+            default_brush_properties,
+            default_thing_properties,
+            manager,
+            ..
+        } = bundle;
+
+        let entity = self.entity;
+        let pinned = &mut self.pinned;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Inspector"))
+                    .id("inspector_window".into())
+                    .collapsible(true)
+                    .resizable(false)
+                    .min_width(220f32),
+                |ui| {
+                    /// Adds a row to the grid.
+                    #[inline]
+                    fn row(ui: &mut egui::Ui, label: &str, value: impl Into<egui::WidgetText>)
+                    {
+                        ui.label(label);
+                        ui.label(value);
+                        ui.end_row();
+                    }
+
+                    /// Returns the amount of nodes of the path of `entity`, or `none` if it has no
+                    /// path.
+                    #[inline]
+                    #[must_use]
+                    fn path(entity: &impl Moving, none: &str) -> String
+                    {
+                        entity
+                            .path()
+                            .map_or_else(|| none.to_string(), |path| path.len().to_string())
+                    }
+
+                    ui.checkbox(pinned, locale.tr("Pin"));
+                    ui.separator();
+
+                    let id = match entity
+                    {
+                        Some(id) => id,
+                        None =>
+                        {
+                            ui.label(locale.tr("No entity beneath the cursor"));
+                            return;
+                        }
+                    };
+
+                    egui::Grid::new("inspector_grid")
+                        .num_columns(2)
+                        .spacing([40f32, 4f32])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            row(ui, "Id", id.value().to_string());
+
+                            if manager.is_thing(id)
+                            {
+                                let thing = manager.thing(id);
+
+                                row(
+                                    ui,
+                                    locale.tr("Thing"),
+                                    things_catalog
+                                        .thing(thing.thing_id())
+                                        .map_or("Unknown", |thing| thing.name())
+                                );
+                                row(ui, locale.tr("Path"), path(thing, locale.tr("None")));
+
+                                ui.separator();
+                                ui.end_row();
+
+                                let properties = thing.properties();
+
+                                for (k, _) in default_thing_properties.iter()
+                                {
+                                    row(ui, k, properties.get(k).to_string());
+                                }
+
+                                for (k, v) in properties.custom()
+                                {
+                                    row(ui, k, v.to_string());
+                                }
+
+                                return;
+                            }
+
+                            let brush = manager.brush(id);
+
+                            match brush.texture_settings()
+                            {
+                                Some(texture) =>
+                                {
+                                    row(ui, locale.tr("Texture"), texture.name());
+                                    row(ui, locale.tr("Height"), texture.height().to_string());
+                                    row(ui, locale.tr("Sprite"), brush.has_sprite().to_string());
+                                },
+                                None => row(ui, locale.tr("Texture"), locale.tr("None"))
+                            };
+
+                            let group = match (brush.attached(), brush.attachments_iter())
+                            {
+                                (Some(owner), _) =>
+                                {
+                                    format!("{} {}", locale.tr("Attached to"), owner.value())
+                                },
+                                (None, Some(attachments)) =>
+                                {
+                                    format!("{} {}", locale.tr("Attachments"), attachments.len())
+                                },
+                                (None, None) => locale.tr("None").to_string()
+                            };
+
+                            row(ui, locale.tr("Group"), group);
+                            row(ui, locale.tr("Editor only"), brush.editor_only().to_string());
+                            row(ui, locale.tr("Path"), path(brush, locale.tr("None")));
+
+                            ui.separator();
+                            ui.end_row();
+
+                            let properties = brush.properties_as_ref();
+
+                            for (k, _) in default_brush_properties.iter()
+                            {
+                                row(ui, k, properties.get(k).to_string());
+                            }
+
+                            for (k, v) in properties.custom()
+                            {
+                                row(ui, k, v.to_string());
+                            }
+                        });
+                }
+            )
+            .unwrap_or_default()
+    }
+}
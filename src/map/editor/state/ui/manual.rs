@@ -3,11 +3,42 @@
 //
 //=======================================================================//
 
-use bevy::{input::ButtonInput, prelude::KeyCode};
-use bevy_egui::egui;
+use bevy::{
+    asset::{AssetServer, Assets},
+    image::Image,
+    input::ButtonInput,
+    prelude::KeyCode
+};
+use bevy_egui::{egui, EguiUserTextures};
+use hill_vacuum_shared::return_if_none;
 
-use super::{window::Window, ToolsButtons, WindowCloser, WindowCloserInfo};
-use crate::{utils::misc::Toggle, HardcodedActions};
+use super::{markdown::Markdown, window::Window, ToolsButtons, WindowCloser, WindowCloserInfo};
+use crate::{
+    config::language::{Language, Locale},
+    map::{
+        editor::state::core::tool::{Tool, ToolInterface},
+        UserManualSections
+    },
+    utils::misc::Toggle,
+    HardcodedActions
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The markdown text of the English manual, generated from the files in `docs/manual/` by the
+/// `docs_generator`.
+const MANUAL: &str = include_str!("../../../../../MANUAL.md");
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The title and content of the sections of a manual.
+type Sections = Vec<(&'static str, Markdown)>;
 
 //=======================================================================//
 // STRUCTS
@@ -16,12 +47,18 @@ use crate::{utils::misc::Toggle, HardcodedActions};
 
 /// The manual window.
 #[derive(Default)]
-pub(in crate::map::editor::state::ui) struct Manual(Window);
+pub(in crate::map::editor::state::ui) struct Manual
+{
+    /// The window.
+    window:   Window,
+    /// The sections of the manual of each [`Language`] that has one, the English one first.
+    sections: Vec<(Language, Sections)>
+}
 
 impl Toggle for Manual
 {
     #[inline]
-    fn toggle(&mut self) { self.0.toggle() }
+    fn toggle(&mut self) { self.window.toggle() }
 }
 
 impl WindowCloserInfo for Manual
@@ -31,9 +68,9 @@ impl WindowCloserInfo for Manual
     {
         /// Calls the close function.
         #[inline]
-        fn close(manual: &mut Manual) { manual.0.close() }
+        fn close(manual: &mut Manual) { manual.window.close() }
 
-        self.0
+        self.window
             .layer_id()
             .map(|id| WindowCloser::Manual(id, close as fn(&mut Self)))
     }
@@ -41,23 +78,84 @@ impl WindowCloserInfo for Manual
 
 impl Manual
 {
+    /// Returns a new [`Manual`] containing, for each [`Language`] with a manual, the built-in
+    /// sections followed by `user_sections`. The sections of the tools are titled with their
+    /// headers, and the tool icons are retrieved from `tools_buttons`.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        asset_server: &AssetServer,
+        user_textures: &mut EguiUserTextures,
+        tools_buttons: &ToolsButtons,
+        user_sections: &UserManualSections
+    ) -> Self
+    {
+        let sections = Language::iter()
+            .filter_map(|language| {
+                let manual = match language
+                {
+                    Language::English => MANUAL,
+                    _ => language.manual()?
+                };
+
+                let sections = manual
+                    .strip_prefix("## ")
+                    .unwrap_or(manual)
+                    .split("\n## ")
+                    .filter_map(|section| section.split_once('\n'))
+                    .map(|(title, text)| {
+                        let title = title.trim();
+                        let title = Tool::iter()
+                            .find(|tool| tool.header().eq_ignore_ascii_case(title))
+                            .map_or(title, Tool::header);
+
+                        (title, text)
+                    })
+                    .chain(user_sections.0.iter().copied())
+                    .map(|(title, text)| {
+                        (title, Markdown::new(text, asset_server, user_textures, tools_buttons))
+                    })
+                    .collect();
+
+                (language, sections).into()
+            })
+            .collect();
+
+        Self {
+            window: Window::default(),
+            sections
+        }
+    }
+
     /// Shows the manual window.
     #[inline]
     pub fn show(
         &mut self,
         egui_context: &egui::Context,
         key_inputs: &ButtonInput<KeyCode>,
-        tools_buttons: &ToolsButtons
+        images: &Assets<Image>,
+        locale: &Locale
     )
     {
-        if !self.0.check_open(HardcodedActions::ToggleManual.pressed(key_inputs))
+        if !self
+            .window
+            .check_open(HardcodedActions::ToggleManual.pressed(key_inputs))
         {
             return;
         }
 
-        self.0.show(
+        // Fall back to the English manual if the language has none.
+        let sections = &return_if_none!(self
+            .sections
+            .iter()
+            .find(|(language, _)| *language == locale.language())
+            .or_else(|| self.sections.first()))
+        .1;
+
+        self.window.show(
             egui_context,
-            egui::Window::new("Manual")
+            egui::Window::new(locale.tr("Manual"))
+                .id("manual_window".into())
                 .vscroll(true)
                 .min_width(400f32)
                 .default_width(800f32)
@@ -67,7 +165,13 @@ impl Manual
                 ui.spacing_mut().item_spacing = egui::vec2(0f32, 12f32);
                 ui.add_space(8f32);
 
-                hill_vacuum_proc_macros::generate_manual!();
+                for (title, text) in sections
+                {
+                    egui::CollapsingHeader::new(*title).show(ui, |ui| {
+                        ui.spacing_mut().item_spacing.y = 4f32;
+                        text.show(ui, images);
+                    });
+                }
 
                 // You would think this does nothing, but it actually does something
                 ui.add_space(0f32);
@@ -0,0 +1,148 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::utils::misc::Toggle;
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window to edit the title, author, description and user defined key/value pairs of the map.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct MapPropertiesWindow
+{
+    /// The window.
+    window:  Window,
+    /// The key of the key/value pair to be added.
+    new_key: String
+}
+
+impl Toggle for MapPropertiesWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for MapPropertiesWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut MapPropertiesWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::MapProperties(id, close as fn(&mut Self)))
+    }
+}
+
+impl MapPropertiesWindow
+{
+    /// Shows the map properties window.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(&mut self, egui_context: &egui::Context, bundle: &mut UiBundle) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let UiBundle {
+            config, manager, ..
+        } = bundle;
+
+        let locale = &config.locale;
+        let mut metadata = manager.metadata().clone();
+        let new_key = &mut self.new_key;
+
+        let focused = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Map properties"))
+                    .id("map_properties_window".into())
+                    .collapsible(true)
+                    .min_width(300f32),
+                |ui| {
+                    egui::Grid::new("map_properties_grid")
+                        .num_columns(2)
+                        .spacing([40f32, 4f32])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(locale.tr("Title"));
+                            ui.text_edit_singleline(&mut metadata.title);
+                            ui.end_row();
+
+                            ui.label(locale.tr("Author"));
+                            ui.text_edit_singleline(&mut metadata.author);
+                            ui.end_row();
+
+                            ui.label(locale.tr("Description"));
+                            ui.text_edit_multiline(&mut metadata.description);
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+
+                    // Key/value pairs, sorted so that they don't shuffle around.
+                    let mut keys = metadata.custom.keys().cloned().collect::<Vec<_>>();
+                    keys.sort_unstable();
+                    let mut removed = None;
+
+                    egui::Grid::new("map_properties_custom_grid")
+                        .num_columns(3)
+                        .spacing([8f32, 4f32])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for key in keys
+                            {
+                                ui.label(key.as_str());
+                                ui.text_edit_singleline(metadata.custom.get_mut(&key).unwrap());
+
+                                if ui.button("-").clicked()
+                                {
+                                    removed = key.into();
+                                }
+
+                                ui.end_row();
+                            }
+
+                            ui.text_edit_singleline(new_key);
+                            ui.label("");
+
+                            if ui
+                                .add_enabled(
+                                    !new_key.is_empty() &&
+                                        !metadata.custom.contains_key(new_key.as_str()),
+                                    egui::Button::new("+")
+                                )
+                                .clicked()
+                            {
+                                metadata.custom.insert(std::mem::take(new_key), String::new());
+                            }
+
+                            ui.end_row();
+                        });
+
+                    if let Some(key) = removed
+                    {
+                        metadata.custom.remove(&key);
+                    }
+                }
+            )
+            .unwrap_or_default();
+
+        manager.set_metadata(metadata);
+        focused
+    }
+}
@@ -0,0 +1,246 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy::{
+    asset::{AssetServer, Assets, Handle},
+    image::Image
+};
+use bevy_egui::{egui, EguiUserTextures};
+use hill_vacuum_shared::continue_if_none;
+
+use super::{ToolsButtons, ICON_DRAW_SIZE};
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// A block of a markdown document.
+enum Block
+{
+    /// A `###` heading.
+    Heading(&'static str),
+    /// A line of text, which may contain `code` spans.
+    Text(&'static str),
+    /// An item of a list.
+    ListItem(&'static str),
+    /// A line starting with `!!!`.
+    Warning(&'static str),
+    /// A fenced code block.
+    Code(String),
+    /// The icon of a tool.
+    Icon(egui::TextureId),
+    /// An image.
+    Image
+    {
+        /// The handle of the image.
+        handle: Handle<Image>,
+        /// The id of the image in the UI.
+        id:     egui::TextureId
+    },
+    /// Vertical space.
+    Space
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A markdown document that can be drawn in the UI.
+/// Supports `###` headings, `-` list items, `code` spans, fenced code blocks, `!!!` warnings,
+/// and images embedded either as `![description](path)` lines, where `path` is relative to the
+/// `assets/` folder, or as the `<img src="images/name.svg"/>` lines of the manual, which are drawn
+/// using the icons of the tools buttons.
+pub(in crate::map::editor::state::ui) struct Markdown(Vec<Block>);
+
+impl Markdown
+{
+    /// Parses `text` into a new [`Markdown`], loading the embedded images. The tool icons are
+    /// retrieved from `tools_buttons`.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        text: &'static str,
+        asset_server: &AssetServer,
+        user_textures: &mut EguiUserTextures,
+        tools_buttons: &ToolsButtons
+    ) -> Self
+    {
+        let mut blocks = Vec::new();
+        let mut code = None::<String>;
+
+        for line in text.lines()
+        {
+            if let Some(c) = &mut code
+            {
+                if line.starts_with("```")
+                {
+                    blocks.push(Block::Code(std::mem::take(c)));
+                    code = None;
+                }
+                else
+                {
+                    c.push_str(line);
+                    c.push('\n');
+                }
+
+                continue;
+            }
+
+            let line = line.trim_end();
+
+            if line.starts_with("```")
+            {
+                code = String::new().into();
+                continue;
+            }
+
+            if line.is_empty() || line == "&nbsp;"
+            {
+                if !matches!(blocks.last(), Some(Block::Space) | None)
+                {
+                    blocks.push(Block::Space);
+                }
+
+                continue;
+            }
+
+            if let Some(id) = Self::icon_name(line).and_then(|name| tools_buttons.icon(name))
+            {
+                blocks.push(Block::Icon(id));
+                continue;
+            }
+
+            if let Some(path) = Self::image_path(line)
+            {
+                let handle = asset_server.load(path);
+                let id = user_textures.add_image(handle.clone());
+                blocks.push(Block::Image { handle, id });
+                continue;
+            }
+
+            blocks.push(
+                if let Some(heading) = line.strip_prefix("### ")
+                {
+                    Block::Heading(heading)
+                }
+                else if let Some(item) = line.strip_prefix("- ")
+                {
+                    Block::ListItem(item)
+                }
+                else if let Some(warning) = line.strip_prefix("!!!")
+                {
+                    Block::Warning(warning.trim_start())
+                }
+                else
+                {
+                    Block::Text(line)
+                }
+            );
+        }
+
+        if let Some(c) = code
+        {
+            blocks.push(Block::Code(c));
+        }
+
+        Self(blocks)
+    }
+
+    /// Returns the name of the tool icon embedded in `line`, if any.
+    #[inline]
+    #[must_use]
+    fn icon_name(line: &str) -> Option<&str>
+    {
+        line.strip_prefix("<img src=\"images/")?
+            .split_once('"')?
+            .0
+            .strip_suffix(".svg")
+    }
+
+    /// Returns the asset path of the image embedded in `line`, if any.
+    #[inline]
+    #[must_use]
+    fn image_path(line: &str) -> Option<String>
+    {
+        line.strip_prefix("![")
+            .and_then(|line| line.split_once("]("))
+            .and_then(|(_, path)| path.strip_suffix(')'))
+            .map(str::to_owned)
+    }
+
+    /// Draws the text of a line, drawing the spans between backticks as code.
+    #[inline]
+    fn text(ui: &mut egui::Ui, text: &str, color: Option<egui::Color32>)
+    {
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 0f32;
+
+            for (i, span) in text.split('`').enumerate()
+            {
+                if span.is_empty()
+                {
+                    continue;
+                }
+
+                if i % 2 == 1
+                {
+                    ui.code(span);
+                    continue;
+                }
+
+                let mut span = egui::RichText::new(span);
+
+                if let Some(color) = color
+                {
+                    span = span.color(color);
+                }
+
+                ui.label(span);
+            }
+        });
+    }
+
+    /// Draws the document.
+    #[inline]
+    pub fn show(&self, ui: &mut egui::Ui, images: &Assets<Image>)
+    {
+        for block in &self.0
+        {
+            match block
+            {
+                Block::Heading(text) =>
+                {
+                    ui.label(egui::RichText::new(*text).strong().size(16f32));
+                },
+                Block::Text(text) => Self::text(ui, text, None),
+                Block::ListItem(text) =>
+                {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label("•");
+                        Self::text(ui, text, None);
+                    });
+                },
+                Block::Warning(text) => Self::text(ui, text, egui::Color32::YELLOW.into()),
+                Block::Code(code) =>
+                {
+                    ui.code(code.as_str());
+                },
+                Block::Icon(id) =>
+                {
+                    ui.image((*id, ICON_DRAW_SIZE));
+                },
+                Block::Image { handle, id } =>
+                {
+                    // Skipped until loaded.
+                    let size = continue_if_none!(images.get(handle)).size_f32();
+                    ui.image((*id, egui::vec2(size.x, size.y)));
+                },
+                Block::Space => ui.add_space(4f32)
+            };
+        }
+    }
+}
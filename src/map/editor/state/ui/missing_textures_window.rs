@@ -0,0 +1,184 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{drawer::drawing_resources::TextureMaterials, editor::state::manager::TextureResult},
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window listing the textures used by the map which are not loaded, and allowing to replace
+/// them with loaded ones.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct MissingTexturesWindow
+{
+    /// The window.
+    window:  Window,
+    /// The names of the missing textures and of the textures to replace them with.
+    missing: Vec<(String, Option<String>)>
+}
+
+impl Toggle for MissingTexturesWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for MissingTexturesWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut MissingTexturesWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::MissingTextures(id, close as fn(&mut Self)))
+    }
+}
+
+impl MissingTexturesWindow
+{
+    /// Opens the window listing the textures in `missing`.
+    #[inline]
+    pub fn open(&mut self, missing: Vec<String>)
+    {
+        self.missing = missing.into_iter().map(|name| (name, None)).collect();
+        self.window.open();
+    }
+
+    /// Shows the window, replacing the textures of the brushes when requested.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &mut UiBundle,
+        enabled: bool
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let UiBundle {
+            config,
+            drawing_resources,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+        let locale = &config.locale;
+        let missing = &mut self.missing;
+        let mut remap = false;
+
+        let focused = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Missing textures"))
+                    .id("missing_textures_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    ui.label(locale.tr("The map uses textures which are not loaded"));
+                    ui.separator();
+
+                    egui::ScrollArea::vertical().max_height(320f32).show(ui, |ui| {
+                        egui::Grid::new("missing_textures_grid")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                for (name, replacement) in missing.iter_mut()
+                                {
+                                    ui.label(name.as_str());
+
+                                    egui::ComboBox::from_id_salt(name.as_str())
+                                        .selected_text(
+                                            replacement
+                                                .as_deref()
+                                                .unwrap_or_else(|| locale.tr("None"))
+                                        )
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(
+                                                &mut *replacement,
+                                                None,
+                                                locale.tr("None")
+                                            );
+
+                                            for texture in drawing_resources
+                                                .ui_textures(None::<fn(&&TextureMaterials) -> bool>)
+                                            {
+                                                let texture = texture.texture().name();
+
+                                                ui.selectable_value(
+                                                    &mut *replacement,
+                                                    Some(texture.to_owned()),
+                                                    texture
+                                                );
+                                            }
+                                        });
+
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                    ui.separator();
+
+                    remap = ui
+                        .add_enabled(
+                            enabled && missing.iter().any(|(_, replacement)| replacement.is_some()),
+                            egui::Button::new(locale.tr("Remap"))
+                        )
+                        .clicked();
+                }
+            )
+            .unwrap_or_default();
+
+        if !remap
+        {
+            return focused;
+        }
+
+        let pairs = self
+            .missing
+            .iter()
+            .filter_map(|(name, replacement)| {
+                replacement
+                    .as_ref()
+                    .map(|replacement| (name.clone(), replacement.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        match edits_history.with_group("Textures Remap", |edits_history| {
+            manager.remap_textures(drawing_resources, edits_history, grid, &pairs)
+        })
+        {
+            TextureResult::Invalid => return focused,
+            TextureResult::Valid => (),
+            TextureResult::ValidRefreshOutline => manager.schedule_outline_update()
+        };
+        self.missing.retain(|(_, replacement)| replacement.is_none());
+
+        if self.missing.is_empty()
+        {
+            self.window.close();
+        }
+
+        focused
+    }
+}
@@ -1,12 +1,25 @@
 pub(in crate::map::editor) mod checkbox;
+mod copy_history_window;
+mod distribute_window;
 mod edits_history_window;
+mod go_to_entity_window;
+mod inspector;
 mod manual;
+mod map_properties_window;
+mod markdown;
 mod minus_plus_buttons;
+mod missing_textures_window;
 pub(in crate::map::editor::state) mod overall_value_field;
 mod properties_window;
+mod rename_texture_window;
+mod script_console;
+mod search_window;
 mod settings_window;
+mod start_screen;
+mod statistics_window;
 mod texture_editor;
 mod tooltip;
+mod validation_window;
 mod window;
 
 //=======================================================================//
@@ -14,7 +27,7 @@ mod window;
 //
 //=======================================================================//
 
-use std::ops::{BitOrAssign, Range};
+use std::{ops::BitOrAssign, path::PathBuf};
 
 use arrayvec::ArrayVec;
 use bevy::{
@@ -32,11 +45,23 @@ use glam::Vec2;
 use hill_vacuum_shared::{return_if_none, NextValue};
 
 use self::{
+    copy_history_window::CopyHistoryWindow,
+    distribute_window::{DistributeWindow, Distribution},
+    go_to_entity_window::GoToEntityWindow,
+    inspector::Inspector,
     manual::Manual,
+    map_properties_window::MapPropertiesWindow,
+    missing_textures_window::MissingTexturesWindow,
     properties_window::PropertiesWindow,
+    rename_texture_window::RenameTextureWindow,
+    script_console::ScriptConsole,
+    search_window::SearchWindow,
     settings_window::SettingsWindow,
+    start_screen::StartScreen,
+    statistics_window::StatisticsWindow,
     texture_editor::TextureEditor,
-    tooltip::Tooltip
+    tooltip::Tooltip,
+    validation_window::ValidationWindow
 };
 use super::{
     clipboard::Clipboard,
@@ -46,21 +71,27 @@ use super::{
     },
     editor_state::ToolsSettings,
     edits_history::EditsHistory,
-    grid::Grid,
+    grid::{Grid, GridSlice},
     inputs_presses::InputsPresses,
-    manager::EntitiesManager
+    manager::EntitiesManager,
+    tabs::Tabs,
+    validation::ValidationReport
 };
 use crate::{
     config::{controls::bind::Bind, Config},
     embedded_assets::embedded_asset_path,
     map::{
+        camera::{zoom_scale, ZOOM_PRESETS},
         drawer::drawing_resources::DrawingResources,
         editor::{cursor::Cursor, Placeholder, PropCamerasMut, StateUpdateBundle},
         properties::{DefaultBrushProperties, DefaultThingProperties},
-        thing::catalog::ThingsCatalog
+        thing::catalog::ThingsCatalog,
+        UserManualSections
     },
     utils::misc::{Camera, FromToStr, Toggle},
-    HardcodedActions
+    HardcodedActions,
+    Id,
+    PropertySchemas
 };
 
 //=======================================================================//
@@ -142,6 +173,26 @@ impl Interacting for egui::Response
 
 //=======================================================================//
 
+/// A trait to assign to an UI element a name that can be read by assistive technologies.
+pub(in crate::map::editor::state) trait AccessibleName
+{
+    /// Assigns the name returned by `name` to the image button.
+    fn image_button_name(&self, enabled: bool, name: impl Fn() -> String);
+}
+
+impl AccessibleName for egui::Response
+{
+    #[inline]
+    fn image_button_name(&self, enabled: bool, name: impl Fn() -> String)
+    {
+        self.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::ImageButton, enabled, name())
+        });
+    }
+}
+
+//=======================================================================//
+
 /// A trait to return the info to close a window.
 pub(in crate::map::editor::state::ui) trait WindowCloserInfo
 {
@@ -176,14 +227,40 @@ pub(in crate::map::editor::state) enum Command
     ChangeTool(Tool),
     /// Open new map.
     New,
+    /// Open new map with the specified grid.
+    NewWithGrid(Grid),
+    /// Open new map with the content of the template at the index.
+    NewFromTemplate(usize),
     /// Save current map.
     Save,
     /// Save current map to new path.
     SaveAs,
     /// Open map.
     Open,
+    /// Open the recently opened map at the index.
+    OpenRecent(usize),
+    /// Open a new tab with an empty map.
+    NewTab,
+    /// Open map in a new tab.
+    OpenInNewTab,
+    /// Switch to the map of the tab at the index.
+    SelectTab(usize),
+    /// Close the tab at the index.
+    CloseTab(usize),
     /// Export map.
     Export,
+    /// Export map with the exporter profile at the index.
+    ExportWith(usize),
+    /// Export the selected entities to a standalone map file.
+    ExportSelection,
+    /// Export the brushes of the map as triangulated meshes.
+    ExportMeshes,
+    /// Export the map as a glTF scene.
+    ExportGltf,
+    /// Export the map as a Tiled map.
+    ExportTmx,
+    /// Run the checks on the map and list the problems found.
+    CheckMap,
     /// Export the map's animations to a .anms file.
     ExportAnimations,
     /// Import an .anms file.
@@ -192,16 +269,34 @@ pub(in crate::map::editor::state) enum Command
     ExportProps,
     /// Import a .prps file.
     ImportProps,
+    /// Import the brushes of a Quake .map file.
+    ImportQuakeMap,
     /// Select all entities.
     SelectAll,
+    /// Exclusively select the entity with the [`Id`] and frame it.
+    GoToEntity(Id),
     /// Copy the selected entities.
     Copy,
     /// Paste the copied entities.
     Paste,
+    /// Paste the entities stored in the copy history at the index.
+    PasteFromHistory(usize),
     /// Cut the selected entities.
     Cut,
     /// Duplicate the selected entities.
     Duplicate,
+    /// Distribute copies of the selected entities along a guide.
+    Distribute(Distribution),
+    /// Spawn brushes matching the footprint of the selected things.
+    ThingsFootprint,
+    /// Round the corners of the selected brushes.
+    RoundCorners,
+    /// Slice the selected brushes along the grid lines.
+    SliceToGrid(GridSlice),
+    /// Group the selected entities.
+    Group,
+    /// Dissolve the outermost groups of the selected entities.
+    Ungroup,
     /// Undo.
     Undo,
     /// Redo.
@@ -218,10 +313,18 @@ pub(in crate::map::editor::state) enum Command
     ToggleTooltips,
     /// Toggle the cursor grid snap.
     ToggleCursorSnap,
+    /// Toggle the zoom dependent cursor snap.
+    ToggleAdaptiveSnap,
     /// Toggles the map preview.
     ToggleMapPreview,
     /// Toggles the collision of the selected brushes.
     ToggleCollision,
+    /// Toggles the editor only flag of the selected brushes.
+    ToggleEditorOnly,
+    /// Toggles the visibility of the editor only brushes.
+    ToggleEditorOnlyVisibility,
+    /// Toggles the visibility of the links between the things.
+    ToggleLinks,
     /// Reload the textures.
     ReloadTextures,
     /// Reload the things.
@@ -230,6 +333,8 @@ pub(in crate::map::editor::state) enum Command
     QuickZoom,
     /// Snap the vertexes of the selected brushes.
     QuickSnap,
+    /// Runs the script written in the script console.
+    RunScript,
     /// Quits the application
     Quit
 }
@@ -245,11 +350,20 @@ impl Command
             self,
             Self::ChangeTool(_) |
                 Self::Paste |
+                Self::PasteFromHistory(_) |
                 Self::Cut |
                 Self::Duplicate |
+                Self::Distribute(_) |
+                Self::ThingsFootprint |
+                Self::RoundCorners |
+                Self::SliceToGrid(_) |
+                Self::Group |
+                Self::Ungroup |
                 Self::Undo |
                 Self::Redo |
-                Self::QuickSnap
+                Self::QuickSnap |
+                Self::ToggleEditorOnly |
+                Self::RunScript
         )
     }
 }
@@ -270,7 +384,31 @@ pub(in crate::map::editor::state::ui) enum WindowCloser
     Properties(egui::LayerId, fn(&mut PropertiesWindow)),
     EditsHistory(egui::LayerId, fn(&mut EditsHistoryWindow)),
     /// Manual window.
-    Manual(egui::LayerId, fn(&mut Manual))
+    Manual(egui::LayerId, fn(&mut Manual)),
+    /// Start screen.
+    StartScreen(egui::LayerId, fn(&mut StartScreen)),
+    /// Map properties window.
+    MapProperties(egui::LayerId, fn(&mut MapPropertiesWindow)),
+    /// Inspector window.
+    Inspector(egui::LayerId, fn(&mut Inspector)),
+    /// Go to entity window.
+    GoToEntity(egui::LayerId, fn(&mut GoToEntityWindow)),
+    /// Distribute window.
+    Distribute(egui::LayerId, fn(&mut DistributeWindow)),
+    /// Copy history window.
+    CopyHistory(egui::LayerId, fn(&mut CopyHistoryWindow)),
+    /// Statistics window.
+    Statistics(egui::LayerId, fn(&mut StatisticsWindow)),
+    /// Map check window.
+    Validation(egui::LayerId, fn(&mut ValidationWindow)),
+    /// Search window.
+    Search(egui::LayerId, fn(&mut SearchWindow)),
+    /// Missing textures window.
+    MissingTextures(egui::LayerId, fn(&mut MissingTexturesWindow)),
+    /// Rename texture window.
+    RenameTexture(egui::LayerId, fn(&mut RenameTextureWindow)),
+    /// Script console.
+    ScriptConsole(egui::LayerId, fn(&mut ScriptConsole))
 }
 
 impl WindowCloser
@@ -284,7 +422,19 @@ impl WindowCloser
         Self::Settings(id, _) |
         Self::Properties(id, _) |
         Self::EditsHistory(id, _) |
-        Self::Manual(id, _)) = self;
+        Self::Manual(id, _) |
+        Self::StartScreen(id, _) |
+        Self::MapProperties(id, _) |
+        Self::Inspector(id, _) |
+        Self::GoToEntity(id, _) |
+        Self::Distribute(id, _) |
+        Self::CopyHistory(id, _) |
+        Self::Statistics(id, _) |
+        Self::Validation(id, _) |
+        Self::Search(id, _) |
+        Self::MissingTextures(id, _) |
+        Self::RenameTexture(id, _) |
+        Self::ScriptConsole(id, _)) = self;
         id
     }
 
@@ -306,11 +456,23 @@ impl WindowCloser
             ui.settings_window.window_closer(),
             ui.properties_window.window_closer(),
             ui.edits_history_window.window_closer(),
-            ui.manual.window_closer()
+            ui.manual.window_closer(),
+            ui.start_screen.window_closer(),
+            ui.map_properties_window.window_closer(),
+            ui.inspector.window_closer(),
+            ui.go_to_entity_window.window_closer(),
+            ui.distribute_window.window_closer(),
+            ui.copy_history_window.window_closer(),
+            ui.statistics_window.window_closer(),
+            ui.validation_window.window_closer(),
+            ui.search_window.window_closer(),
+            ui.missing_textures_window.window_closer(),
+            ui.rename_texture_window.window_closer(),
+            ui.script_console.window_closer()
         ]
         .into_iter()
         .flatten()
-        .collect::<ArrayVec<_, 5>>();
+        .collect::<ArrayVec<_, 17>>();
 
         if windows.is_empty()
         {
@@ -348,13 +510,48 @@ impl WindowCloser
             Self::TextureEditor(_, closer) => closer(&mut ui.texture_editor),
             Self::Properties(_, closer) => closer(&mut ui.properties_window),
             Self::EditsHistory(_, closer) => closer(&mut ui.edits_history_window),
-            Self::Manual(_, closer) => closer(&mut ui.manual)
+            Self::Manual(_, closer) => closer(&mut ui.manual),
+            Self::StartScreen(_, closer) => closer(&mut ui.start_screen),
+            Self::MapProperties(_, closer) => closer(&mut ui.map_properties_window),
+            Self::Inspector(_, closer) => closer(&mut ui.inspector),
+            Self::GoToEntity(_, closer) => closer(&mut ui.go_to_entity_window),
+            Self::Distribute(_, closer) => closer(&mut ui.distribute_window),
+            Self::CopyHistory(_, closer) => closer(&mut ui.copy_history_window),
+            Self::Statistics(_, closer) => closer(&mut ui.statistics_window),
+            Self::Validation(_, closer) => closer(&mut ui.validation_window),
+            Self::Search(_, closer) => closer(&mut ui.search_window),
+            Self::MissingTextures(_, closer) => closer(&mut ui.missing_textures_window),
+            Self::RenameTexture(_, closer) => closer(&mut ui.rename_texture_window),
+            Self::ScriptConsole(_, closer) => closer(&mut ui.script_console)
         };
     }
 }
 
 //=======================================================================//
 
+/// The content of the right half of the map area.
+#[must_use]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub(in crate::map) enum SplitView
+{
+    /// The map area is not split.
+    #[default]
+    None,
+    /// The live map preview.
+    Preview,
+    /// A second editing viewport with its own camera.
+    Viewport
+}
+
+impl SplitView
+{
+    /// Enables `value`, or disables the split view if `value` is already enabled.
+    #[inline]
+    fn toggle(&mut self, value: Self) { *self = if *self == value { Self::None } else { value }; }
+}
+
+//=======================================================================//
+
 #[must_use]
 #[derive(Default, Clone, Copy)]
 pub(in crate::map) enum UiFocus
@@ -415,6 +612,7 @@ pub(in crate::map::editor::state) struct UiBundle<'world, 'state, 'a, 'c>
     pub things_catalog:           &'a mut ThingsCatalog,
     pub default_brush_properties: &'a DefaultBrushProperties,
     pub default_thing_properties: &'a DefaultThingProperties,
+    pub property_schemas:         &'a PropertySchemas,
     pub manager:                  &'a mut EntitiesManager,
     pub edits_history:            &'a mut EditsHistory,
     pub clipboard:                &'a mut Clipboard,
@@ -453,6 +651,28 @@ impl ToolsButtons
         }
     }
 
+    /// Returns the icon of the tool or subtool whose icon file is named `name`, without the
+    /// extension, if any.
+    #[inline]
+    #[must_use]
+    fn icon(&self, name: &str) -> Option<egui::TextureId>
+    {
+        /// Whether `file` is the icon file named `name`.
+        #[inline]
+        #[must_use]
+        fn matches(file: &str, name: &str) -> bool { file.strip_suffix(".png") == Some(name) }
+
+        Tool::iter()
+            .find(|tool| matches(tool.icon_file_name(), name))
+            .map(Self::index)
+            .or_else(|| {
+                SubTool::iter()
+                    .find(|tool| matches(tool.icon_file_name(), name))
+                    .map(Self::index)
+            })
+            .map(|index| self.icons[index])
+    }
+
     /// The index of `tool`.
     #[inline]
     #[must_use]
@@ -476,14 +696,19 @@ impl ToolsButtons
         T: ToolInterface,
         E: EnabledTool<Item = T>
     {
+        let enabled_button = tool.change_conditions_met(bundle.tool_change_conditions);
         let response = ui.add_enabled(
-            tool.change_conditions_met(bundle.tool_change_conditions),
+            enabled_button,
             egui::ImageButton::new(egui::Image::new((
                 self.icons[Self::index(tool)],
                 ICON_DRAW_SIZE
             )))
         );
 
+        // The tooltip doubles as the name read by assistive technologies.
+        response.image_button_name(enabled_button, || {
+            tool.localized_tooltip_label(&bundle.config.binds, &bundle.config.locale)
+        });
         self.tooltip.show(bundle, tool, &response);
         let clicked = response.clicked();
 
@@ -494,13 +719,6 @@ impl ToolsButtons
 
         clicked
     }
-
-    /// Draws the image representing `tool`.
-    #[inline]
-    fn image(&self, ui: &mut egui::Ui, tool: impl ToolInterface)
-    {
-        ui.image((self.icons[Self::index(tool)], ICON_DRAW_SIZE));
-    }
 }
 
 //=======================================================================//
@@ -521,21 +739,49 @@ pub(in crate::map::editor::state) struct Interaction
 pub(in crate::map::editor::state) struct Ui
 {
     /// The buttons to enable the tools.
-    tools_buttons:        ToolsButtons,
+    tools_buttons:           ToolsButtons,
     /// The id of the left panel
-    left_panel_layer_id:  egui::LayerId,
+    left_panel_layer_id:     egui::LayerId,
     /// The id of the right panel.
-    right_panel_layer_id: egui::LayerId,
+    right_panel_layer_id:    egui::LayerId,
     /// The settings window.
-    settings_window:      SettingsWindow,
+    settings_window:         SettingsWindow,
     /// The parameters window.
-    properties_window:    PropertiesWindow,
-    edits_history_window: EditsHistoryWindow,
+    properties_window:       PropertiesWindow,
+    edits_history_window:    EditsHistoryWindow,
     /// The texture editor.
-    texture_editor:       TextureEditor,
+    texture_editor:          TextureEditor,
     /// The manual.
-    manual:               Manual,
-    focus:                UiFocus
+    manual:                  Manual,
+    /// The start screen.
+    start_screen:            StartScreen,
+    /// The map properties window.
+    map_properties_window:   MapPropertiesWindow,
+    /// The entity inspector.
+    inspector:               Inspector,
+    /// The window to go to an entity.
+    go_to_entity_window:     GoToEntityWindow,
+    /// The window to distribute the selected entities.
+    distribute_window:       DistributeWindow,
+    /// The window to paste the entities stored in the copy history.
+    copy_history_window:     CopyHistoryWindow,
+    /// The window showing the statistics of the map.
+    statistics_window:       StatisticsWindow,
+    /// The window listing the problems found by the map checks.
+    validation_window:       ValidationWindow,
+    /// The window to search the entities.
+    search_window:           SearchWindow,
+    /// The window listing the missing textures.
+    missing_textures_window: MissingTexturesWindow,
+    /// The window to rename a texture.
+    rename_texture_window:   RenameTextureWindow,
+    /// The console to run the scripts.
+    script_console:          ScriptConsole,
+    /// The content of the right half of the map area.
+    split_view:              SplitView,
+    /// The area of the window taken by the right half of the split view, if shown.
+    split_view_rect:         Option<egui::Rect>,
+    focus:                   UiFocus
 }
 
 impl Placeholder for Ui
@@ -544,18 +790,32 @@ impl Placeholder for Ui
     unsafe fn placeholder() -> Self
     {
         Self {
-            tools_buttons:        ToolsButtons {
+            tools_buttons:           ToolsButtons {
                 icons:   [egui::TextureId::default(); Tool::SIZE + SubTool::SIZE],
                 tooltip: Tooltip::new()
             },
-            left_panel_layer_id:  egui::LayerId::background(),
-            right_panel_layer_id: egui::LayerId::background(),
-            settings_window:      SettingsWindow::default(),
-            properties_window:    PropertiesWindow::placeholder(),
-            edits_history_window: EditsHistoryWindow::default(),
-            texture_editor:       TextureEditor::default(),
-            manual:               Manual::default(),
-            focus:                UiFocus::default()
+            left_panel_layer_id:     egui::LayerId::background(),
+            right_panel_layer_id:    egui::LayerId::background(),
+            settings_window:         SettingsWindow::default(),
+            properties_window:       PropertiesWindow::placeholder(),
+            edits_history_window:    EditsHistoryWindow::default(),
+            texture_editor:          TextureEditor::default(),
+            manual:                  Manual::default(),
+            start_screen:            StartScreen::default(),
+            map_properties_window:   MapPropertiesWindow::default(),
+            inspector:               Inspector::default(),
+            go_to_entity_window:     GoToEntityWindow::default(),
+            distribute_window:       DistributeWindow::default(),
+            copy_history_window:     CopyHistoryWindow::default(),
+            statistics_window:       StatisticsWindow::default(),
+            validation_window:       ValidationWindow::default(),
+            search_window:           SearchWindow::default(),
+            missing_textures_window: MissingTexturesWindow::default(),
+            rename_texture_window:   RenameTextureWindow::default(),
+            script_console:          ScriptConsole::default(),
+            split_view:              SplitView::None,
+            split_view_rect:         None,
+            focus:                   UiFocus::default()
         }
     }
 }
@@ -569,22 +829,45 @@ impl Ui
         asset_server: &AssetServer,
         user_textures: &mut EguiUserTextures,
         default_brush_properties: &DefaultBrushProperties,
-        default_thing_properties: &DefaultThingProperties
+        default_thing_properties: &DefaultThingProperties,
+        manual_sections: &UserManualSections
     ) -> Self
     {
+        // The manual draws the icons of the tools buttons.
+        let buttons = ToolsButtons::new(asset_server, user_textures);
+
         Self {
-            tools_buttons:        ToolsButtons::new(asset_server, user_textures),
-            left_panel_layer_id:  egui::LayerId::background(),
-            right_panel_layer_id: egui::LayerId::background(),
-            properties_window:    PropertiesWindow::new(
+            manual:                  Manual::new(
+                asset_server,
+                user_textures,
+                &buttons,
+                manual_sections
+            ),
+            tools_buttons:           buttons,
+            left_panel_layer_id:     egui::LayerId::background(),
+            right_panel_layer_id:    egui::LayerId::background(),
+            properties_window:       PropertiesWindow::new(
                 default_brush_properties,
                 default_thing_properties
             ),
-            settings_window:      SettingsWindow::default(),
-            edits_history_window: EditsHistoryWindow::default(),
-            texture_editor:       TextureEditor::default(),
-            manual:               Manual::default(),
-            focus:                UiFocus::default()
+            settings_window:         SettingsWindow::default(),
+            edits_history_window:    EditsHistoryWindow::default(),
+            texture_editor:          TextureEditor::default(),
+            start_screen:            StartScreen::default(),
+            map_properties_window:   MapPropertiesWindow::default(),
+            inspector:               Inspector::default(),
+            go_to_entity_window:     GoToEntityWindow::default(),
+            distribute_window:       DistributeWindow::default(),
+            copy_history_window:     CopyHistoryWindow::default(),
+            statistics_window:       StatisticsWindow::default(),
+            validation_window:       ValidationWindow::default(),
+            search_window:           SearchWindow::default(),
+            missing_textures_window: MissingTexturesWindow::default(),
+            rename_texture_window:   RenameTextureWindow::default(),
+            script_console:          ScriptConsole::default(),
+            split_view:              SplitView::None,
+            split_view_rect:         None,
+            focus:                   UiFocus::default()
         }
     }
 
@@ -602,6 +885,56 @@ impl Ui
     #[inline]
     pub const fn is_focused(&self) -> UiFocus { self.focus }
 
+    /// Returns the content of the right half of the map area and the area of the window it takes,
+    /// if shown.
+    #[inline]
+    #[must_use]
+    pub fn split_view(&self) -> Option<(SplitView, egui::Rect)>
+    {
+        self.split_view_rect.map(|rect| (self.split_view, rect))
+    }
+
+    /// Returns the source of the script written in the script console.
+    #[inline]
+    #[must_use]
+    pub fn script(&self) -> &str { self.script_console.source() }
+
+    /// Shows `output` as the result of the last script run in the script console.
+    #[inline]
+    pub fn set_script_output(&mut self, output: String) { self.script_console.set_output(output); }
+
+    /// Returns the index of the entry of the edits history window being hovered, if any.
+    #[inline]
+    #[must_use]
+    pub const fn hovered_edit(&self) -> Option<usize> { self.edits_history_window.hovered() }
+
+    /// Opens the start screen.
+    #[inline]
+    pub fn open_start_screen(&mut self) { self.start_screen.open(); }
+
+    /// Opens the window to go to an entity.
+    #[inline]
+    pub fn open_go_to_entity(&mut self) { self.go_to_entity_window.open(); }
+
+    /// Opens the window listing the `missing` textures.
+    #[inline]
+    pub fn open_missing_textures(&mut self, missing: Vec<String>)
+    {
+        self.missing_textures_window.open(missing);
+    }
+
+    /// Opens the window listing the problems of `report`.
+    #[inline]
+    pub fn open_validation(&mut self, report: ValidationReport)
+    {
+        self.validation_window.open(report);
+    }
+
+    /// Returns the path of the new map template at `index`, if any.
+    #[inline]
+    #[must_use]
+    pub fn template(&self, index: usize) -> Option<&PathBuf> { self.start_screen.template(index) }
+
     /// Updates the UI.
     #[inline]
     pub fn frame_start_update(
@@ -609,6 +942,7 @@ impl Ui
         bundle: &mut StateUpdateBundle,
         core: &mut Core,
         settings: &mut ToolsSettings,
+        tabs: &Tabs,
         tool_change_conditions: &ChangeConditions
     ) -> Interaction
     {
@@ -649,6 +983,7 @@ impl Ui
             things_catalog,
             drawing_resources,
             default_properties,
+            property_schemas,
             cursor,
             manager,
             clipboard,
@@ -673,6 +1008,7 @@ impl Ui
             things_catalog,
             default_brush_properties: default_properties.map_brushes,
             default_thing_properties: default_properties.map_things,
+            property_schemas,
             manager,
             edits_history,
             clipboard,
@@ -683,14 +1019,116 @@ impl Ui
         };
 
         // Top bar.
-        let mut command = self.menu_bar(egui_context, bundle, core);
+        let mut command = self.menu_bar(egui_context, bundle, core, tabs);
+
+        // Start screen.
+        let start_screen_focused =
+            self.start_screen
+                .show(egui_context, bundle, &mut self.manual, &mut command);
+
+        if start_screen_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Go to entity.
+        let go_to_entity_focused = self.go_to_entity_window.show(
+            egui_context,
+            bundle,
+            core.select_all_available(),
+            &mut command
+        );
+
+        if go_to_entity_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Distribute.
+        let distribute_focused = self.distribute_window.show(
+            egui_context,
+            bundle,
+            core.copy_paste_available() && bundle.manager.any_selected_entities(),
+            &mut command
+        );
+
+        if distribute_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Copy history.
+        let copy_history_focused = self.copy_history_window.show(
+            egui_context,
+            bundle,
+            core.copy_paste_available(),
+            &mut command
+        );
+
+        if copy_history_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Map check.
+        let validation_focused = self.validation_window.show(
+            egui_context,
+            bundle,
+            core.select_all_available(),
+            &mut command
+        );
+
+        if validation_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Search.
+        let search_focused = self.search_window.show(
+            egui_context,
+            bundle,
+            core.select_all_available(),
+            &mut command
+        );
+
+        if search_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Script console.
+        let script_console_focused = self.script_console.show(
+            egui_context,
+            bundle,
+            core.copy_paste_available(),
+            &mut command
+        );
+
+        if script_console_focused
+        {
+            clear_inputs(bundle);
+        }
+
+        // Missing textures.
+        let missing_textures_focused = show_and_clear_inputs(bundle, |bundle| {
+            self.missing_textures_window
+                .show(egui_context, bundle, core.select_all_available())
+        });
+
+        // Rename texture.
+        let rename_texture_focused = show_and_clear_inputs(bundle, |bundle| {
+            self.rename_texture_window
+                .show(egui_context, bundle, !core.map_preview())
+        });
 
         // Manual menu.
-        self.manual.show(egui_context, bundle.key_inputs, &self.tools_buttons);
+        self.manual
+            .show(egui_context, bundle.key_inputs, bundle.images, &bundle.config.locale);
 
         // Floating windows.
         self.focus = if core.map_preview()
         {
+            bundle.drawing_resources.set_texture_preview(None);
             false
         }
         else
@@ -699,11 +1137,30 @@ impl Ui
         }
         .into();
 
-        self.focus |= show_and_clear_inputs(bundle, |bundle| {
-            self.settings_window.show(egui_context, bundle)
-        }) | show_and_clear_inputs(bundle, |bundle| {
-            self.properties_window.show(egui_context, bundle)
-        });
+        self.focus |= start_screen_focused |
+            go_to_entity_focused |
+            distribute_focused |
+            copy_history_focused |
+            validation_focused |
+            search_focused |
+            script_console_focused |
+            missing_textures_focused |
+            rename_texture_focused |
+            show_and_clear_inputs(bundle, |bundle| {
+                self.settings_window.show(egui_context, bundle)
+            }) |
+            show_and_clear_inputs(bundle, |bundle| {
+                self.properties_window.show(egui_context, bundle)
+            }) |
+            show_and_clear_inputs(bundle, |bundle| {
+                self.map_properties_window.show(egui_context, bundle)
+            }) |
+            show_and_clear_inputs(bundle, |bundle| {
+                self.inspector.show(egui_context, bundle, cursor)
+            }) |
+            show_and_clear_inputs(bundle, |bundle| {
+                self.statistics_window.show(egui_context, bundle)
+            });
 
         if let Some(clicked) = self.edits_history_window.show(egui_context, bundle, core)
         {
@@ -758,6 +1215,26 @@ impl Ui
             .response
             .layer_id;
 
+        // Split view, the area is left empty so that the map drawn below it can be seen.
+        let mut split_view_hovered = false;
+        self.split_view_rect =
+            (self.split_view != SplitView::None && !core.map_preview()).then(|| {
+                let response = egui::SidePanel::right("split_view")
+                    .resizable(false)
+                    .frame(egui::Frame::none())
+                    .exact_width(
+                        ((egui_context.available_rect().width() - LEFT_SIDE_PANEL_WIDTH) / 2f32)
+                            .max(0f32)
+                    )
+                    .show(egui_context, |_| {})
+                    .response;
+
+                // The second viewport can be interacted with like the main one.
+                split_view_hovered =
+                    self.split_view == SplitView::Viewport && response.contains_pointer();
+                response.rect
+            });
+
         // Left Side Panel.
         self.left_panel_layer_id = egui::SidePanel::left("tools")
             .resizable(false)
@@ -779,6 +1256,9 @@ impl Ui
                     // Camera info.
                     Self::camera_info(ui, bundle);
 
+                    // Brushes info.
+                    Self::brushes_info(ui, cursor, bundle.manager);
+
                     // Extra tool info.
                     core.tool_ui(ui, bundle);
                 });
@@ -789,6 +1269,27 @@ impl Ui
         // Bottom panel
         core.bottom_panel(egui_context, bundle);
 
+        // Map preview banner.
+        if core.map_preview()
+        {
+            egui::Area::new("map_preview_banner".into())
+                .anchor(egui::Align2::CENTER_TOP, [0f32, MENU_BAR_HEIGHT + ICONS_PADDING.x])
+                .interactable(false)
+                .show(egui_context, |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(
+                                bundle
+                                    .config
+                                    .locale
+                                    .tr("Map preview, editing is disabled. Press Esc to exit.")
+                            )
+                            .color(ui.visuals().warn_fg_color)
+                        );
+                    });
+                });
+        }
+
         // Close windows.
         egui_context.memory(|mem| {
             WindowCloser::check_window_close(mem.layer_ids(), bundle.inputs, self);
@@ -807,7 +1308,8 @@ impl Ui
                 .frame(egui::Frame::none())
                 .show(egui_context, |_| {})
                 .response
-                .contains_pointer(),
+                .contains_pointer() &&
+                !split_view_hovered,
             command
         }
     }
@@ -873,6 +1375,21 @@ impl Ui
         self.texture_editor.schedule_texture_animation_update();
     }
 
+    /// Schedules the update of the references to the texture `prev`, renamed to `new`, outside of
+    /// the map being edited.
+    #[inline]
+    pub fn schedule_texture_rename(&mut self, prev: &str, new: &str)
+    {
+        self.rename_texture_window.schedule_rename(prev, new);
+    }
+
+    /// Returns the textures renamed since the last call, along with their new name.
+    #[inline]
+    pub fn take_renamed_textures(&mut self) -> Vec<(String, String)>
+    {
+        self.rename_texture_window.take_renamed()
+    }
+
     /// Draws the menu bar.
     #[inline]
     #[must_use]
@@ -880,7 +1397,8 @@ impl Ui
         &mut self,
         egui_context: &egui::Context,
         bundle: &mut UiBundle,
-        core: &mut Core
+        core: &mut Core,
+        tabs: &Tabs
     ) -> Command
     {
         let mut command = Command::None;
@@ -894,15 +1412,21 @@ impl Ui
                     spacing.item_spacing = [2f32; 2].into();
                     ui.visuals_mut().menu_rounding = 0f32.into();
 
-                    let UiBundle { window, camera, config: Config { binds, exporter, .. }, manager, .. } = bundle;
+                    let UiBundle { window, camera, config: Config { binds, exporters, locale, open_file, .. }, manager, .. } = bundle;
 
                     let select_all = core.select_all_available();
                     let copy_paste = core.copy_paste_available();
                     let undo_redo = core.undo_redo_available();
                     let reload = !core.map_preview();
-                    let export = exporter.is_some();
+                    let export = !exporters.is_empty();
                     let quick_snap = manager.any_selected_brushes();
                     let quick_zoom = manager.any_selected_entities();
+                    let export_selection = quick_zoom;
+                    let distribute = copy_paste && quick_zoom;
+                    let things_footprint = copy_paste && manager.any_selected_things();
+                    let group = core.entity_tool() && select_all && quick_zoom;
+                    let ungroup = group && manager.any_selected_grouped_entities();
+                    let close_tab = tabs.len() > 1;
 
                     /// Draws a menu button.
                     macro_rules! menu_button {
@@ -912,7 +1436,7 @@ impl Ui
                             $action:block
                             $(, $shortcut:expr)?
                         ) => {
-                            if $ui.add(egui::Button::new($label)$(.shortcut_text($shortcut))?).clicked()
+                            if $ui.add(egui::Button::new(locale.tr($label))$(.shortcut_text($shortcut))?).clicked()
                             {
                                 $action
                                 $ui.close_menu();
@@ -921,12 +1445,23 @@ impl Ui
 
                         (
                             $ui:ident,
+                            $label:literal,
                             $enabled:ident,
+                            |$inner:ident| $body:block
+                        ) => {
+                            $ui.add_enabled_ui($enabled, |ui| {
+                                ui.menu_button(locale.tr($label), |$inner| $body);
+                            });
+                        };
+
+                        (
+                            $ui:ident,
                             $label:literal,
+                            $enabled:ident,
                             $action:block
                             $(, $shortcut:expr)?
                         ) => {
-                            if $ui.add_enabled($enabled, egui::Button::new($label)$(.shortcut_text($shortcut))?).clicked()
+                            if $ui.add_enabled($enabled, egui::Button::new(locale.tr($label))$(.shortcut_text($shortcut))?).clicked()
                             {
                                 $action
                                 $ui.close_menu();
@@ -939,14 +1474,9 @@ impl Ui
                         (
                             $ui:ident,
                             $label:literal,
-                            $((
-                                $tag:literal,
-                                $($enabled:ident, )?
-                                $action:block
-                                $(, $shortcut:expr)?
-                            )),
+                            $(($($item:tt)+)),
                         +) => {
-                            egui::menu::menu_button($ui, $label, |ui| {
+                            egui::menu::menu_button($ui, locale.tr($label), |ui| {
                                 ui.set_min_width(200f32);
                                 let spacing = ui.spacing_mut();
                                 spacing.button_padding = [6f32; 2].into();
@@ -954,7 +1484,7 @@ impl Ui
                                 ui.visuals_mut().menu_rounding = 0f32.into();
 
                                 $(
-                                    menu_button!(ui, $($enabled, )? $tag, $action $(, $shortcut)?);
+                                    menu_button!(ui, $($item)+);
                                 )+
                             })
                             .response
@@ -971,6 +1501,21 @@ impl Ui
                         ("Open", {
                             command = Command::Open;
                         }, HardcodedActions::Open.key_combo()),
+                        ("New tab", {
+                            command = Command::NewTab;
+                        }),
+                        ("Open in new tab", {
+                            command = Command::OpenInNewTab;
+                        }),
+                        ("Close tab", close_tab, {
+                            command = Command::CloseTab(tabs.active());
+                        }),
+                        ("Start screen", {
+                            self.start_screen.open();
+                        }),
+                        ("Map properties", {
+                            self.map_properties_window.toggle();
+                        }),
                         ("Save", {
                             command = Command::Save;
                         }, HardcodedActions::Save.key_combo()),
@@ -980,6 +1525,31 @@ impl Ui
                         ("Export", export, {
                             command = Command::Export;
                         }, HardcodedActions::Export.key_combo()),
+                        ("Export to", export, |ui| {
+                            for (i, profile) in exporters.iter().enumerate()
+                            {
+                                if ui.button(&profile.name).clicked()
+                                {
+                                    command = Command::ExportWith(i);
+                                    ui.close_menu();
+                                }
+                            }
+                        }),
+                        ("Export selection", export_selection, {
+                            command = Command::ExportSelection;
+                        }),
+                        ("Export meshes", {
+                            command = Command::ExportMeshes;
+                        }),
+                        ("Export glTF", {
+                            command = Command::ExportGltf;
+                        }),
+                        ("Export TMX", {
+                            command = Command::ExportTmx;
+                        }),
+                        ("Check map", {
+                            command = Command::CheckMap;
+                        }),
                         ("Import animations", {
                             command = Command::ImportAnimations;
                         }),
@@ -992,6 +1562,9 @@ impl Ui
                         ("Export props", {
                             command = Command::ExportProps;
                         }),
+                        ("Import Quake map", {
+                            command = Command::ImportQuakeMap;
+                        }),
                         ("Quit", {
                             command = Command::Quit;
                         }, HardcodedActions::Quit.key_combo())
@@ -1003,6 +1576,12 @@ impl Ui
                         ("Select all", select_all, {
                             command = Command::SelectAll;
                         }, HardcodedActions::SelectAll.key_combo()),
+                        ("Go to entity", select_all, {
+                            self.go_to_entity_window.open();
+                        }, HardcodedActions::GoToEntity.key_combo()),
+                        ("Search entities", select_all, {
+                            self.search_window.open();
+                        }),
                         ("Copy", copy_paste, {
                             command = Command::Copy;
                         }, HardcodedActions::Copy.key_combo()),
@@ -1012,9 +1591,41 @@ impl Ui
                         ("Paste", copy_paste, {
                             command = Command::Paste;
                         }, HardcodedActions::Paste.key_combo()),
+                        ("Paste from history", copy_paste, {
+                            self.copy_history_window.open();
+                        }),
                         ("Duplicate", copy_paste, {
                             command = Command::Duplicate;
                         }, HardcodedActions::Duplicate.key_combo()),
+                        ("Distribute", distribute, {
+                            self.distribute_window.open();
+                        }),
+                        ("Things footprint", things_footprint, {
+                            command = Command::ThingsFootprint;
+                        }),
+                        ("Round corners", quick_snap, {
+                            command = Command::RoundCorners;
+                        }),
+                        ("Slice to grid", quick_snap, |ui| {
+                            for (label, slice) in [
+                                ("Cells", GridSlice::Cells),
+                                ("Columns", GridSlice::Columns),
+                                ("Rows", GridSlice::Rows)
+                            ]
+                            {
+                                if ui.button(locale.tr(label)).clicked()
+                                {
+                                    command = Command::SliceToGrid(slice);
+                                    ui.close_menu();
+                                }
+                            }
+                        }),
+                        ("Group", group, {
+                            command = Command::Group;
+                        }),
+                        ("Ungroup", ungroup, {
+                            command = Command::Ungroup;
+                        }),
                         ("Undo", undo_redo, {
                             command = Command::Undo;
                         }, HardcodedActions::Undo.key_combo()),
@@ -1024,6 +1635,9 @@ impl Ui
                         ("Quick snap", quick_snap, {
                             command = Command::QuickSnap;
                         }, format!("Alt+{}", Tool::Snap.keycode_str(binds))),
+                        ("Toggle editor only", quick_snap, {
+                            command = Command::ToggleEditorOnly;
+                        }),
                         ("Texture editor", {
                             self.texture_editor.toggle();
                         }, binds.get(Bind::TextureEditor).map_or("", FromToStr::to_str)),
@@ -1032,7 +1646,10 @@ impl Ui
                         }, binds.get(Bind::PropertiesEditor).map_or("", FromToStr::to_str)),
                         ("Edits history", {
                             self.edits_history_window.toggle();
-                        }, binds.get(Bind::EditsHistory).map_or("", FromToStr::to_str))
+                        }, binds.get(Bind::EditsHistory).map_or("", FromToStr::to_str)),
+                        ("Script console", {
+                            self.script_console.toggle();
+                        })
                     );
 
                     submenu!(
@@ -1052,6 +1669,18 @@ impl Ui
                         }, HardcodedActions::Fullscreen.key_combo()),
                         ("Toggle map preview", {
                             command = Command::ToggleMapPreview;
+                        }),
+                        ("Split view", {
+                            self.split_view.toggle(SplitView::Preview);
+                        }),
+                        ("Second viewport", {
+                            self.split_view.toggle(SplitView::Viewport);
+                        }),
+                        ("Inspector", {
+                            self.inspector.toggle();
+                        }, Bind::Inspector.keycode_str(binds)),
+                        ("Statistics", {
+                            self.statistics_window.toggle();
                         })
                     );
 
@@ -1076,15 +1705,27 @@ impl Ui
                         ("Toggle cursor snap", {
                             command = Command::ToggleCursorSnap;
                         }, Bind::ToggleCursorSnap.keycode_str(binds)),
+                        ("Toggle adaptive snap", {
+                            command = Command::ToggleAdaptiveSnap;
+                        }),
                         ("Toggle collision overlay", {
                             command = Command::ToggleCollision;
                         }, Bind::ToggleCollision.keycode_str(binds)),
+                        ("Toggle editor only brushes", {
+                            command = Command::ToggleEditorOnlyVisibility;
+                        }),
+                        ("Toggle links", {
+                            command = Command::ToggleLinks;
+                        }),
                         ("Settings", {
                             self.settings_window.toggle();
                         }, Bind::Settings.keycode_str(binds)),
                         ("Reload textures", reload, {
                             command = Command::ReloadTextures;
                         }),
+                        ("Rename texture", reload, {
+                            self.rename_texture_window.toggle();
+                        }),
                         ("Reload things", reload, {
                             command = Command::ReloadThings;
                         })
@@ -1097,6 +1738,28 @@ impl Ui
                             self.manual.toggle();
                         }, HardcodedActions::ToggleManual.key_combo())
                     );
+
+                    if !close_tab
+                    {
+                        return;
+                    }
+
+                    ui.separator();
+
+                    for (i, name) in tabs.names(open_file.path()).enumerate()
+                    {
+                        let name = name.as_deref().unwrap_or_else(|| locale.tr("Untitled"));
+
+                        if ui.selectable_label(i == tabs.active(), name).clicked()
+                        {
+                            command = Command::SelectTab(i);
+                        }
+
+                        if ui.small_button("x").clicked()
+                        {
+                            command = Command::CloseTab(i);
+                        }
+                    }
                 });
             });
 
@@ -1119,20 +1782,18 @@ impl Ui
             egui_ui: &mut egui::Ui,
             bundle: &mut UiBundle,
             tool_to_enable: &mut Option<Tool>,
-            range: Range<usize>,
+            tools: &[Tool],
             row_padding: f32
         )
         {
             egui_ui.spacing_mut().item_spacing = ICONS_PADDING;
             egui_ui.add_space(row_padding);
 
-            for i in range
+            for tool in tools
             {
-                let tool = Into::<Tool>::into(i);
-
-                if ui.tools_buttons.draw(egui_ui, bundle, tool, core)
+                if ui.tools_buttons.draw(egui_ui, bundle, *tool, core)
                 {
-                    *tool_to_enable = tool.into();
+                    *tool_to_enable = (*tool).into();
                 }
             }
         }
@@ -1145,35 +1806,21 @@ impl Ui
 
         let mut tool_to_enable = None;
 
-        for i in 0..Tool::SIZE / ICONS_PER_ROW
+        for row in Tool::toolbar(&bundle.config.toolbar).chunks(ICONS_PER_ROW)
         {
             ui.horizontal(|ui| {
-                let i = i * 3;
-
                 tool_image_buttons_row(
                     self,
                     core,
                     ui,
                     bundle,
                     &mut tool_to_enable,
-                    i..i + 3,
+                    row,
                     row_padding
                 );
             });
         }
 
-        ui.horizontal(|ui| {
-            tool_image_buttons_row(
-                self,
-                core,
-                ui,
-                bundle,
-                &mut tool_to_enable,
-                (Tool::SIZE / ICONS_PER_ROW) * ICONS_PER_ROW..Tool::SIZE,
-                row_padding
-            );
-        });
-
         tool_to_enable
     }
 
@@ -1186,10 +1833,11 @@ impl Ui
         let pos = cursor.world_snapped();
 
         ui.label(egui::RichText::new(format!(
-            "CURSOR\nX: {:.2}\nY: {:.2}\nSnapped: {}",
+            "CURSOR\nX: {:.2}\nY: {:.2}\nSnapped: {}\nSnap size: {}",
             pos.x,
             pos.y,
-            cursor.snap()
+            cursor.snap(),
+            cursor.snap_size()
         )));
     }
 
@@ -1208,6 +1856,38 @@ impl Ui
         )));
     }
 
+    /// The info concerning the area and perimeter of the selected brushes, or of the brush beneath
+    /// the cursor if there are none.
+    #[inline]
+    fn brushes_info(ui: &mut egui::Ui, cursor: &Cursor, manager: &EntitiesManager)
+    {
+        let text = if manager.selected_brushes_amount() != 0
+        {
+            let (area, perimeter) = manager
+                .selected_brushes()
+                .fold((0f32, 0f32), |(area, perimeter), brush| {
+                    (area + brush.area(), perimeter + brush.perimeter())
+                });
+
+            format!(
+                "SELECTED BRUSHES\nAmount: {}\nArea: {area:.2}\nPerimeter: {perimeter:.2}",
+                manager.selected_brushes_amount()
+            )
+        }
+        else
+        {
+            let cursor_pos = cursor.world();
+            let brushes = manager.brushes_at_pos(cursor_pos, None);
+            let brush =
+                return_if_none!(brushes.iter().find(|brush| brush.contains_point(cursor_pos)));
+
+            format!("HOVERED BRUSH\nArea: {:.2}\nPerimeter: {:.2}", brush.area(), brush.perimeter())
+        };
+
+        ui.separator();
+        ui.label(egui::RichText::new(text));
+    }
+
     /// The info concerning the camera.
     #[inline]
     fn camera_info(ui: &mut egui::Ui, bundle: &mut UiBundle)
@@ -1224,6 +1904,22 @@ impl Ui
             pos.y,
             bundle.camera.scale()
         )));
+
+        ui.horizontal(|ui| {
+            ui.label(bundle.config.locale.tr("Zoom"));
+
+            egui::ComboBox::from_id_salt("zoom_presets")
+                .selected_text(format!("{:.0}%", 100f32 / bundle.camera.scale()))
+                .show_ui(ui, |ui| {
+                    for zoom in ZOOM_PRESETS
+                    {
+                        if ui.selectable_label(false, format!("{zoom}%")).clicked()
+                        {
+                            bundle.camera.set_scale(zoom_scale(zoom));
+                        }
+                    }
+                });
+        });
     }
 }
 
@@ -68,6 +68,22 @@ impl<T: ToString + FromStr + PartialEq> OverallValueField<T>
         enabled: bool,
         f: F
     ) -> bool
+    {
+        Self::show_focusable(ui, clipboard, inputs, value, enabled, false, f)
+    }
+
+    /// Shows the [`OverallValueField`] enabled depending on the `enabled` parameter, requesting
+    /// the keyboard focus if `focus` is true.
+    #[inline]
+    pub fn show_focusable<F: FnOnce(T) -> Option<T>>(
+        ui: &mut egui::Ui,
+        clipboard: &mut Clipboard,
+        inputs: &InputsPresses,
+        value: &mut UiOverallValue<T>,
+        enabled: bool,
+        focus: bool,
+        f: F
+    ) -> bool
     {
         if value.is_none() || !enabled
         {
@@ -75,7 +91,16 @@ impl<T: ToString + FromStr + PartialEq> OverallValueField<T>
             return false;
         }
 
-        Self::show_always_enabled(ui, clipboard, inputs, value, f)
+        let response =
+            clipboard.copy_paste_text_editor(inputs, ui, value.buffer_mut(), f32::INFINITY);
+
+        if focus
+        {
+            response.request_focus();
+        }
+
+        value.update(response.gained_focus(), response.actually_lost_focus(), f);
+        response.interacting()
     }
 
     /// Always shows the [`OverallValueField`] enabled.
@@ -0,0 +1,314 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+use hill_vacuum_shared::match_or_panic;
+
+use super::overall_properties::color_edit;
+use crate::{
+    map::{
+        drawer::drawing_resources::DrawingResources,
+        editor::state::{
+            clipboard::Clipboard,
+            grid::Grid,
+            inputs_presses::InputsPresses,
+            ui::{checkbox::CheckBox, overall_value_field::OverallValueField}
+        },
+        properties::SetProperty
+    },
+    utils::overall_value::{OverallValue, OverallValueToUi, UiOverallValue},
+    Rgba,
+    Value
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The values, with default value, of the types a new custom property can have.
+const NEW_PROPERTY_VALUES: [Value; 5] = [
+    Value::Bool(false),
+    Value::I32(0),
+    Value::F32(0f32),
+    Value::String(String::new()),
+    Value::Color(Rgba([255; 4]))
+];
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The info concerning an overall custom property of the selected entities.
+struct CustomProperty
+{
+    /// The key.
+    key:        String,
+    /// A [`Value`] of the type of the property, if all the entities share it.
+    value_type: Option<Value>,
+    /// The overall [`Value`]. Non uniform if any entity does not have the property.
+    value:      OverallValue<Value>,
+    /// The UI representation of the overall [`Value`].
+    ui:         UiOverallValue<Value>
+}
+
+impl CustomProperty
+{
+    /// Returns a new [`CustomProperty`] with [`Value`] `value`.
+    #[inline]
+    fn new(key: &str, value: &Value) -> Self
+    {
+        let overall = OverallValue::from(value.clone());
+
+        Self {
+            key:        key.to_string(),
+            value_type: value.clone().into(),
+            ui:         overall.clone().ui(),
+            value:      overall
+        }
+    }
+
+    /// Stacks `value` on the overall value.
+    /// [`Value`]s are compared by type first, since comparing [`Value`]s of different types
+    /// panics.
+    #[inline]
+    fn stack(&mut self, value: &Value)
+    {
+        if self.value_type.as_ref().is_some_and(|v| !v.same_type(value))
+        {
+            self.value_type = None;
+        }
+
+        if let OverallValue::Uniform(v) = &self.value
+        {
+            if !v.same_value(value)
+            {
+                self.value = OverallValue::NonUniform;
+            }
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The UI elements to edit the custom properties of the selected entities.
+#[must_use]
+#[derive(Default)]
+pub(in crate::map::editor::state::ui::properties_window) struct UiCustomProperties
+{
+    /// The overall custom properties, in order of appearance.
+    properties: Vec<CustomProperty>,
+    /// The key of the custom property to be added.
+    new_key:    String,
+    /// The index in [`NEW_PROPERTY_VALUES`] of the type of the custom property to be added.
+    new_type:   usize
+}
+
+impl UiCustomProperties
+{
+    /// Overwrites the overall custom properties with the ones returned by `iter`, one iterator
+    /// for each entity.
+    #[inline]
+    pub fn overwrite<'a, I>(&mut self, iter: impl Iterator<Item = I>)
+    where
+        I: Iterator<Item = (&'a str, &'a Value)>
+    {
+        self.properties.clear();
+
+        let mut amounts = Vec::new();
+        let mut entities = 0;
+
+        for custom in iter
+        {
+            for (k, v) in custom
+            {
+                match self.properties.iter().position(|p| p.key == k)
+                {
+                    Some(index) =>
+                    {
+                        self.properties[index].stack(v);
+                        amounts[index] += 1;
+                    },
+                    None =>
+                    {
+                        let mut property = CustomProperty::new(k, v);
+
+                        if entities != 0
+                        {
+                            property.value = OverallValue::NonUniform;
+                        }
+
+                        self.properties.push(property);
+                        amounts.push(1);
+                    }
+                };
+            }
+
+            entities += 1;
+        }
+
+        for (p, amount) in self.properties.iter_mut().zip(amounts)
+        {
+            if amount != entities
+            {
+                p.value = OverallValue::NonUniform;
+            }
+
+            p.ui = p.value.clone().ui();
+        }
+    }
+
+    /// Shows the custom properties fields and the row to add a new one.
+    /// `is_default` returns whether a key belongs to a default property, which custom properties
+    /// cannot shadow.
+    #[inline]
+    pub fn show<S: SetProperty>(
+        &mut self,
+        ui: &mut egui::Ui,
+        drawing_resources: &DrawingResources,
+        clipboard: &mut Clipboard,
+        inputs: &InputsPresses,
+        grid: &Grid,
+        is_default: impl Fn(&str) -> bool,
+        value_setter: &mut S
+    )
+    {
+        let mut removed = None;
+
+        egui::Grid::new("custom_properties")
+            .num_columns(4)
+            .spacing([8f32, 4f32])
+            .striped(true)
+            .show(ui, |ui| {
+                for p in &mut self.properties
+                {
+                    ui.label(p.key.as_str());
+
+                    let value_type = match &p.value_type
+                    {
+                        Some(value_type) => value_type,
+                        None =>
+                        {
+                            ui.label("Mixed");
+                            ui.label("");
+
+                            if ui.button("-").clicked()
+                            {
+                                removed = p.key.clone().into();
+                            }
+
+                            ui.end_row();
+                            continue;
+                        }
+                    };
+
+                    ui.label(value_type.type_str());
+
+                    match value_type
+                    {
+                        Value::Bool(_) =>
+                        {
+                            if let Some(value) = CheckBox::show(ui, &p.value, |v| {
+                                match_or_panic!(v, Value::Bool(value), *value)
+                            })
+                            {
+                                let value = Value::Bool(value);
+                                value_setter.set_custom_property(
+                                    drawing_resources,
+                                    grid,
+                                    &p.key,
+                                    Some(&value)
+                                );
+                                p.value = value.into();
+                                p.ui = p.value.clone().ui();
+                            }
+                        },
+                        Value::Color(_) =>
+                        {
+                            if let Some(rgba) = color_edit(ui, &p.value)
+                            {
+                                let value = Value::Color(rgba);
+                                value_setter.set_custom_property(
+                                    drawing_resources,
+                                    grid,
+                                    &p.key,
+                                    Some(&value)
+                                );
+                                p.value = value.into();
+                                p.ui = p.value.clone().ui();
+                            }
+                        },
+                        _ =>
+                        {
+                            let key = p.key.as_str();
+
+                            OverallValueField::show_always_enabled(
+                                ui,
+                                clipboard,
+                                inputs,
+                                &mut p.ui,
+                                |new_value| {
+                                    let new_value = value_type.parse(&new_value)?;
+                                    value_setter.set_custom_property(
+                                        drawing_resources,
+                                        grid,
+                                        key,
+                                        Some(&new_value)
+                                    );
+                                    new_value.into()
+                                }
+                            );
+                        }
+                    };
+
+                    if ui.button("-").clicked()
+                    {
+                        removed = p.key.clone().into();
+                    }
+
+                    ui.end_row();
+                }
+
+                _ = clipboard.copy_paste_text_editor(inputs, ui, &mut self.new_key, f32::INFINITY);
+
+                egui::ComboBox::from_id_salt("custom_property_type")
+                    .selected_text(NEW_PROPERTY_VALUES[self.new_type].type_str())
+                    .show_ui(ui, |ui| {
+                        for (i, value) in NEW_PROPERTY_VALUES.iter().enumerate()
+                        {
+                            ui.selectable_value(&mut self.new_type, i, value.type_str());
+                        }
+                    });
+
+                ui.label("");
+
+                let key = self.new_key.trim();
+
+                if ui
+                    .add_enabled(
+                        !key.is_empty() &&
+                            !is_default(key) &&
+                            !self.properties.iter().any(|p| p.key == key),
+                        egui::Button::new("+")
+                    )
+                    .clicked()
+                {
+                    let value = &NEW_PROPERTY_VALUES[self.new_type];
+                    value_setter.insert_custom_property(drawing_resources, grid, key, value);
+                    self.properties.push(CustomProperty::new(key, value));
+                    self.new_key.clear();
+                }
+
+                ui.end_row();
+            });
+
+        if let Some(key) = removed
+        {
+            value_setter.set_custom_property(drawing_resources, grid, &key, None);
+            self.properties.retain(|p| p.key != key);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod custom_properties;
 mod overall_properties;
 
 //=======================================================================//
@@ -6,9 +7,9 @@ mod overall_properties;
 //=======================================================================//
 
 use bevy_egui::egui;
-use hill_vacuum_shared::{match_or_panic, TEXTURE_HEIGHT_RANGE};
+use hill_vacuum_shared::{match_or_panic, return_if_none, TEXTURE_HEIGHT_RANGE};
 
-use self::overall_properties::UiOverallProperties;
+use self::{custom_properties::UiCustomProperties, overall_properties::UiOverallProperties};
 use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
 use crate::{
     config::controls::bind::Bind,
@@ -60,6 +61,183 @@ enum Target
 //
 //=======================================================================//
 
+/// The struct that updates the properties of the brushes.
+#[allow(clippy::missing_docs_in_private_items)]
+struct BrushesPropertySetter<'a>
+{
+    manager:       &'a mut EntitiesManager,
+    edits_history: &'a mut EditsHistory
+}
+
+impl SetProperty for BrushesPropertySetter<'_>
+{
+    #[inline]
+    fn set_property(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        grid: &Grid,
+        key: &str,
+        value: &mut Value
+    )
+    {
+        self.edits_history.property(
+            key,
+            self.manager
+                .selected_brushes_mut(drawing_resources, grid)
+                .filter_map(|mut brush| {
+                    brush.set_property(key, value).map(|value| (brush.id(), value))
+                })
+        );
+    }
+
+    #[inline]
+    fn set_custom_property(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        grid: &Grid,
+        key: &str,
+        value: Option<&Value>
+    )
+    {
+        let edits = self
+            .manager
+            .selected_brushes_mut(drawing_resources, grid)
+            .filter_map(|mut brush| {
+                brush
+                    .set_custom_property(key, value.cloned())
+                    .map(|value| (brush.id(), value))
+            })
+            .collect::<Vec<_>>();
+
+        if !edits.is_empty()
+        {
+            self.edits_history.custom_property(key, edits);
+        }
+    }
+
+    #[inline]
+    fn insert_custom_property(
+        &mut self,
+        drawing_resources: &DrawingResources,
+        grid: &Grid,
+        key: &str,
+        value: &Value
+    )
+    {
+        let edits = self
+            .manager
+            .selected_brushes_mut(drawing_resources, grid)
+            .filter_map(|mut brush| {
+                if brush.properties_as_ref().custom_get(key).is_some()
+                {
+                    return None;
+                }
+
+                brush
+                    .set_custom_property(key, value.clone().into())
+                    .map(|value| (brush.id(), value))
+            })
+            .collect::<Vec<_>>();
+
+        if !edits.is_empty()
+        {
+            self.edits_history.custom_property(key, edits);
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The struct that updates the properties of the [`ThingInstance`]s.
+#[allow(clippy::missing_docs_in_private_items)]
+struct ThingsPropertySetter<'a>
+{
+    things_catalog: &'a ThingsCatalog,
+    manager:        &'a mut EntitiesManager,
+    edits_history:  &'a mut EditsHistory
+}
+
+impl SetProperty for ThingsPropertySetter<'_>
+{
+    #[inline]
+    fn set_property(&mut self, _: &DrawingResources, _: &Grid, key: &str, value: &mut Value)
+    {
+        macro_rules! set {
+            ($($ty:ident, $min:expr, $max:expr)?) => {{
+                $(*value = Value::$ty(match_or_panic!(value, Value::$ty(value), *value).clamp($min, $max));)?
+
+                self.edits_history.property(
+                    key,
+                    self.manager.selected_things_mut(self.things_catalog).filter_map(|mut thing| {
+                        thing.set_property(key, value).map(|value| (thing.id(), value))
+                    })
+                );
+            }};
+        }
+
+        match key
+        {
+            ANGLE_LABEL => set!(I16, 0, 359),
+            HEIGHT_LABEL =>
+            {
+                set!(I8, *TEXTURE_HEIGHT_RANGE.start(), *TEXTURE_HEIGHT_RANGE.end());
+            },
+            _ => set!()
+        };
+    }
+
+    #[inline]
+    fn set_custom_property(
+        &mut self,
+        _: &DrawingResources,
+        _: &Grid,
+        key: &str,
+        value: Option<&Value>
+    )
+    {
+        let edits = self
+            .manager
+            .selected_things_mut(self.things_catalog)
+            .filter_map(|mut thing| {
+                thing
+                    .set_custom_property(key, value.cloned())
+                    .map(|value| (thing.id(), value))
+            })
+            .collect::<Vec<_>>();
+
+        if !edits.is_empty()
+        {
+            self.edits_history.custom_property(key, edits);
+        }
+    }
+
+    #[inline]
+    fn insert_custom_property(&mut self, _: &DrawingResources, _: &Grid, key: &str, value: &Value)
+    {
+        let edits = self
+            .manager
+            .selected_things_mut(self.things_catalog)
+            .filter_map(|mut thing| {
+                if thing.properties().custom_get(key).is_some()
+                {
+                    return None;
+                }
+
+                thing
+                    .set_custom_property(key, value.clone().into())
+                    .map(|value| (thing.id(), value))
+            })
+            .collect::<Vec<_>>();
+
+        if !edits.is_empty()
+        {
+            self.edits_history.custom_property(key, edits);
+        }
+    }
+}
+
+//=======================================================================//
+
 /// The core of the editor.
 struct Innards
 {
@@ -67,12 +245,16 @@ struct Innards
     target:                     Target,
     /// The overall brushes properties.
     overall_brushes_properties: UiOverallProperties,
+    /// The overall brushes custom properties.
+    custom_brushes_properties:  UiCustomProperties,
     /// The overall draw height of the [`ThingInstance`]s.
     overall_things_draw_height: UiOverallValue<i8>,
     /// The overall angle of the [`ThingInstance`]s.
     overall_things_angle:       UiOverallValue<i16>,
     /// The overall [`ThingInstance`]s properties.
     overall_things_properties:  UiOverallProperties,
+    /// The overall [`ThingInstance`]s custom properties.
+    custom_things_properties:   UiCustomProperties,
     /// The maximum amount of rows of the grid.
     max_rows:                   usize,
     /// The filler rows of the brushes grid.
@@ -126,6 +308,8 @@ impl Innards
             };
         });
 
+        Self::outline_color(&self.target, ui, bundle);
+
         #[allow(clippy::cast_precision_loss)]
         egui::Grid::new("properties")
             .num_columns(COLUMNS)
@@ -136,89 +320,174 @@ impl Innards
             .show(ui, |ui| {
                 self.grid(ui, bundle);
             });
+
+        self.custom_properties(ui, bundle);
     }
 
-    /// The grid of the properties.
+    /// The editor of the outline color of the selected entities of `target`.
     #[inline]
-    fn grid(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
+    fn outline_color(target: &Target, ui: &mut egui::Ui, bundle: &mut UiBundle)
     {
-        /// Fills the grid in case of few or unuven properties.
+        /// The color shown in the picker if the entities have no shared outline color.
+        const DEFAULT_RGB: [u8; 3] = [250, 235, 215];
+
+        /// Returns the outline color shared by all the entities, if any.
         #[inline]
-        fn filler(ui: &mut egui::Ui, length: usize)
+        fn shared(mut colors: impl Iterator<Item = Option<[u8; 3]>>) -> Option<[u8; 3]>
         {
-            for _ in 0..length
-            {
-                ui.label("");
-                ui.label("");
-                ui.label("");
-                ui.end_row();
-            }
+            let first = colors.next()??;
+            colors.all(|color| color == Some(first)).then_some(first)
         }
 
-        /// The struct that updates the properties of the brushes.
-        #[allow(clippy::missing_docs_in_private_items)]
-        struct BrushesPropertySetter<'a>
+        let UiBundle {
+            config,
+            drawing_resources,
+            things_catalog,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+
+        let (shared, any) = match target
         {
-            manager:       &'a mut EntitiesManager,
-            edits_history: &'a mut EditsHistory
-        }
+            Target::None => return,
+            Target::Brushes =>
+            {
+                (
+                    shared(manager.selected_brushes().map(Brush::color)),
+                    manager.selected_brushes().any(|brush| brush.color().is_some())
+                )
+            },
+            Target::Things =>
+            {
+                (
+                    shared(manager.selected_things().map(ThingInstance::color)),
+                    manager.selected_things().any(|thing| thing.color().is_some())
+                )
+            },
+        };
 
-        impl SetProperty for BrushesPropertySetter<'_>
+        let mut rgb = shared.unwrap_or(DEFAULT_RGB);
+        let mut color = None;
+
+        ui.horizontal(|ui| {
+            ui.label(config.locale.tr("Outline color"));
+
+            if egui::color_picker::color_edit_button_srgb(ui, &mut rgb).changed()
+            {
+                color = Some(Some(rgb));
+            }
+
+            if ui
+                .add_enabled(any, egui::Button::new(config.locale.tr("Clear")))
+                .clicked()
+            {
+                color = Some(None);
+            }
+        });
+
+        let color = return_if_none!(color);
+
+        match target
         {
-            #[inline]
-            fn set_property(
-                &mut self,
-                drawing_resources: &DrawingResources,
-                grid: &Grid,
-                key: &str,
-                value: &mut Value
-            )
+            Target::None => unreachable!(),
+            Target::Brushes =>
             {
-                self.edits_history.property(
-                    key,
-                    self.manager.selected_brushes_mut(drawing_resources, grid).filter_map(
-                        |mut brush| brush.set_property(key, value).map(|value| (brush.id(), value))
+                edits_history.entity_color_cluster(
+                    manager.selected_brushes_mut(drawing_resources, grid).filter_map(
+                        |mut brush| brush.set_color(color).map(|prev| (brush.id(), prev))
                     )
                 );
+            },
+            Target::Things =>
+            {
+                edits_history.entity_color_cluster(
+                    manager.selected_things_mut(things_catalog).filter_map(|mut thing| {
+                        thing.set_color(color).map(|prev| (thing.id(), prev))
+                    })
+                );
             }
-        }
+        };
+    }
 
-        /// The struct that updates the properties of the [`ThingInstance`]s.
-        #[allow(clippy::missing_docs_in_private_items)]
-        struct ThingsPropertySetter<'a>
+    /// The editor of the custom properties of the selected entities of the target.
+    #[inline]
+    fn custom_properties(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
+    {
+        let UiBundle {
+            config,
+            drawing_resources,
+            things_catalog,
+            default_brush_properties,
+            default_thing_properties,
+            manager,
+            edits_history,
+            clipboard,
+            inputs,
+            grid,
+            ..
+        } = bundle;
+
+        if matches!(self.target, Target::None)
         {
-            things_catalog: &'a ThingsCatalog,
-            manager:        &'a mut EntitiesManager,
-            edits_history:  &'a mut EditsHistory
+            return;
         }
 
-        impl SetProperty for ThingsPropertySetter<'_>
+        ui.separator();
+        ui.label(config.locale.tr("Custom properties"));
+
+        match self.target
         {
-            #[inline]
-            fn set_property(&mut self, _: &DrawingResources, _: &Grid, key: &str, value: &mut Value)
+            Target::None => unreachable!(),
+            Target::Brushes =>
             {
-                macro_rules! set {
-                    ($($ty:ident, $min:expr, $max:expr)?) => {{
-                        $(*value = Value::$ty(match_or_panic!(value, Value::$ty(value), *value).clamp($min, $max));)?
-
-                        self.edits_history.property(
-                            key,
-                            self.manager.selected_things_mut(self.things_catalog).filter_map(|mut thing| {
-                                thing.set_property(key, value).map(|value| (thing.id(), value))
-                            })
-                        );
-                    }};
-                }
+                self.custom_brushes_properties.show(
+                    ui,
+                    drawing_resources,
+                    clipboard,
+                    inputs,
+                    grid,
+                    |k| default_brush_properties.contains(k),
+                    &mut BrushesPropertySetter {
+                        manager,
+                        edits_history
+                    }
+                );
+            },
+            Target::Things =>
+            {
+                self.custom_things_properties.show(
+                    ui,
+                    drawing_resources,
+                    clipboard,
+                    inputs,
+                    grid,
+                    |k| default_thing_properties.contains(k),
+                    &mut ThingsPropertySetter {
+                        things_catalog,
+                        manager,
+                        edits_history
+                    }
+                );
+            }
+        };
+    }
 
-                match key
-                {
-                    ANGLE_LABEL => set!(I16, 0, 359),
-                    HEIGHT_LABEL =>
-                    {
-                        set!(I8, *TEXTURE_HEIGHT_RANGE.start(), *TEXTURE_HEIGHT_RANGE.end());
-                    },
-                    _ => set!()
-                };
+    /// The grid of the properties.
+    #[inline]
+    fn grid(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
+    {
+        /// Fills the grid in case of few or unuven properties.
+        #[inline]
+        fn filler(ui: &mut egui::Ui, length: usize)
+        {
+            for _ in 0..length
+            {
+                ui.label("");
+                ui.label("");
+                ui.label("");
+                ui.end_row();
             }
         }
 
@@ -227,6 +496,7 @@ impl Innards
             things_catalog,
             default_brush_properties,
             default_thing_properties,
+            property_schemas,
             manager,
             edits_history,
             clipboard,
@@ -252,6 +522,7 @@ impl Innards
                     clipboard,
                     inputs,
                     grid,
+                    |k| property_schemas.brush_schema(k),
                     &mut BrushesPropertySetter {
                         manager,
                         edits_history
@@ -269,6 +540,7 @@ impl Innards
                     clipboard,
                     inputs,
                     grid,
+                    |k| property_schemas.thing_schema(k),
                     &mut ThingsPropertySetter {
                         things_catalog,
                         manager,
@@ -325,9 +597,11 @@ impl Placeholder for PropertiesWindow
             innards: Innards {
                 target:                     Target::default(),
                 overall_brushes_properties: UiOverallProperties::placeholder(),
+                custom_brushes_properties:  UiCustomProperties::default(),
                 overall_things_draw_height: UiOverallValue::none(),
                 overall_things_angle:       UiOverallValue::none(),
                 overall_things_properties:  UiOverallProperties::placeholder(),
+                custom_things_properties:   UiCustomProperties::default(),
                 max_rows:                   0,
                 brushes_filler:             0,
                 things_filler:              0
@@ -354,9 +628,11 @@ impl PropertiesWindow
             innards: Innards {
                 target: Target::default(),
                 overall_brushes_properties: UiOverallProperties::new(default_brush_properties),
+                custom_brushes_properties: UiCustomProperties::default(),
                 overall_things_draw_height: UiOverallValue::none(),
                 overall_things_angle: UiOverallValue::none(),
                 overall_things_properties: UiOverallProperties::new(default_thing_properties),
+                custom_things_properties: UiCustomProperties::default(),
                 max_rows,
                 brushes_filler: max_rows - b_len,
                 things_filler: max_rows - t_len
@@ -376,9 +652,15 @@ impl PropertiesWindow
         self.innards
             .overall_brushes_properties
             .total_overwrite(manager.selected_brushes().map(Brush::properties_as_ref));
+        self.innards.custom_brushes_properties.overwrite(
+            manager
+                .selected_brushes()
+                .map(|brush| brush.properties_as_ref().custom())
+        );
     }
 
-    /// Update the overall brushes property with key `k`.
+    /// Update the overall brushes property with key `k`, or the custom ones if `k` is not the key
+    /// of a default property.
     #[inline]
     pub fn update_overall_brushes_property(&mut self, manager: &EntitiesManager, k: &str)
     {
@@ -387,6 +669,16 @@ impl PropertiesWindow
             return;
         }
 
+        if !self.innards.overall_brushes_properties.contains(k)
+        {
+            self.innards.custom_brushes_properties.overwrite(
+                manager
+                    .selected_brushes()
+                    .map(|brush| brush.properties_as_ref().custom())
+            );
+            return;
+        }
+
         self.innards
             .overall_brushes_properties
             .overwrite(k, manager.selected_brushes().map(Brush::properties_as_ref));
@@ -424,9 +716,13 @@ impl PropertiesWindow
         self.innards
             .overall_things_properties
             .total_overwrite(manager.selected_things().map(ThingInstance::properties));
+        self.innards
+            .custom_things_properties
+            .overwrite(manager.selected_things().map(|thing| thing.properties().custom()));
     }
 
-    /// Update the overall [`ThingInstance`]s property with key `k`.
+    /// Update the overall [`ThingInstance`]s property with key `k`, or the custom ones if `k` is
+    /// not the key of a default property.
     #[inline]
     pub fn update_overall_things_property(&mut self, manager: &EntitiesManager, k: &str)
     {
@@ -435,6 +731,14 @@ impl PropertiesWindow
             return;
         }
 
+        if !self.innards.overall_things_properties.contains(k)
+        {
+            self.innards
+                .custom_things_properties
+                .overwrite(manager.selected_things().map(|thing| thing.properties().custom()));
+            return;
+        }
+
         self.innards
             .overall_things_properties
             .overwrite(k, manager.selected_things().map(ThingInstance::properties));
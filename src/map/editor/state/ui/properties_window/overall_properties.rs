@@ -24,9 +24,33 @@ use crate::{
         collections::IndexMap,
         overall_value::{OverallValue, OverallValueInterface, OverallValueToUi, UiOverallValue}
     },
+    PropertySchema,
+    Rgba,
     Value
 };
 
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Shows a color picker for the overall [`Value::Color`] `value`, white if not uniform.
+/// Returns the picked color, if it was changed.
+#[inline]
+#[must_use]
+pub(super) fn color_edit(ui: &mut egui::Ui, value: &OverallValue<Value>) -> Option<Rgba>
+{
+    let mut rgba = match value
+    {
+        OverallValue::Uniform(value) => match_or_panic!(value, Value::Color(rgba), rgba.0),
+        OverallValue::None | OverallValue::NonUniform => Rgba::default().0
+    };
+
+    ui.color_edit_button_srgba_unmultiplied(&mut rgba)
+        .changed()
+        .then_some(Rgba(rgba))
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -36,11 +60,51 @@ use crate::{
 struct OverallProperty
 {
     /// The discriminant of the [`Value`].
-    tag:   u8,
+    tag:      u8,
     /// The overall [`Value`].
-    value: OverallValue<Value>,
+    value:    OverallValue<Value>,
     /// The UI representation of the overall [`Value`].
-    ui:    UiOverallValue<Value>
+    ui:       UiOverallValue<Value>,
+    /// The reason the last edit was rejected, if it was.
+    rejected: Option<String>
+}
+
+impl OverallProperty
+{
+    /// Returns whether `value` satisfies `schema`, or `schema` is lenient. Otherwise the reason
+    /// it does not is stored in `rejected`.
+    #[inline]
+    #[must_use]
+    fn accept(rejected: &mut Option<String>, schema: Option<&PropertySchema>, value: &Value)
+        -> bool
+    {
+        *rejected = schema
+            .filter(|schema| !schema.is_lenient())
+            .and_then(|schema| schema.validate(value).err());
+        rejected.is_none()
+    }
+
+    /// Returns the error to be shown next to the property, and whether it is a warning.
+    #[inline]
+    #[must_use]
+    fn error(&self, schema: Option<&PropertySchema>) -> Option<(String, bool)>
+    {
+        if let Some(error) = &self.rejected
+        {
+            return (error.clone(), false).into();
+        }
+
+        let schema = schema?;
+
+        match &self.value
+        {
+            OverallValue::Uniform(value) =>
+            {
+                schema.validate(value).err().map(|error| (error, schema.is_lenient()))
+            },
+            _ => None
+        }
+    }
 }
 
 //=======================================================================//
@@ -71,7 +135,8 @@ impl UiOverallProperties
                     (k.to_string(), OverallProperty {
                         tag: d_v.tag(),
                         value,
-                        ui
+                        ui,
+                        rejected: None
                     })
                 })
                 .collect()
@@ -83,6 +148,11 @@ impl UiOverallProperties
     #[must_use]
     pub fn len(&self) -> usize { self.0.len() }
 
+    /// Whether there is an overall property with key `k`.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, k: &str) -> bool { self.0.contains_key(k) }
+
     /// Overwrites all the overall properties.
     #[inline]
     pub fn total_overwrite<'a, P: Properties + 'a>(&mut self, mut iter: impl Iterator<Item = &'a P>)
@@ -96,6 +166,7 @@ impl UiOverallProperties
                 let b = properties.get(k);
                 assert!(o.tag == b.tag(), "Mismatching discriminants.");
                 o.value = b.clone().into();
+                o.rejected = None;
             }
         }
 
@@ -140,6 +211,7 @@ impl UiOverallProperties
             let b = properties.get(k);
             assert!(o.tag == b.tag(), "Mismatching discriminants.");
             o.value = b.clone().into();
+            o.rejected = None;
         }
 
         _ = iter.any(|properties| self.0.get_mut(k).unwrap().value.stack(properties.get(k)));
@@ -148,9 +220,11 @@ impl UiOverallProperties
         o.ui = o.value.clone().ui();
     }
 
-    /// Shows the [`Properties`] fields.
+    /// Shows the [`Properties`] fields. The edited values are validated against the
+    /// [`PropertySchema`] returned by `schema`, and the keys of the properties whose values do not
+    /// satisfy it are highlighted.
     #[inline]
-    pub fn show<D: DefaultProperties, S: SetProperty>(
+    pub fn show<'a, D: DefaultProperties, S: SetProperty>(
         &mut self,
         ui: &mut egui::Ui,
         drawing_resources: &DrawingResources,
@@ -158,6 +232,7 @@ impl UiOverallProperties
         clipboard: &mut Clipboard,
         inputs: &InputsPresses,
         grid: &Grid,
+        schema: impl Fn(&str) -> Option<&'a PropertySchema>,
         value_setter: &mut S
     )
     {
@@ -168,22 +243,61 @@ impl UiOverallProperties
             let d_v = default_properties.get(k);
             assert!(o.tag == d_v.tag(), "Mismatching discriminants.");
 
-            ui.label(k);
+            let schema = schema(k);
+
+            match o.error(schema)
+            {
+                Some((error, warning)) =>
+                {
+                    let color = if warning
+                    {
+                        ui.visuals().warn_fg_color
+                    }
+                    else
+                    {
+                        ui.visuals().error_fg_color
+                    };
+
+                    ui.label(egui::RichText::new(k).color(color)).on_hover_text(error);
+                },
+                None => _ = ui.label(k)
+            };
+
             ui.label(d_v.type_str());
 
-            if Value::BOOL_TAG == o.tag
+            if Value::COLOR_TAG == o.tag
+            {
+                if let Some(rgba) = color_edit(ui, &o.value)
+                {
+                    let mut value = Value::Color(rgba);
+
+                    if OverallProperty::accept(&mut o.rejected, schema, &value)
+                    {
+                        value_setter.set_property(drawing_resources, grid, k, &mut value);
+                        o.value = value.into();
+                        o.ui = o.value.clone().ui();
+                    }
+                }
+            }
+            else if Value::BOOL_TAG == o.tag
             {
                 if let Some(value) =
                     CheckBox::show(ui, &o.value, |v| match_or_panic!(v, Value::Bool(value), *value))
                 {
                     let mut value = Value::Bool(value);
-                    value_setter.set_property(drawing_resources, grid, k, &mut value);
-                    o.value = value.into();
-                    o.ui = o.value.clone().ui();
+
+                    if OverallProperty::accept(&mut o.rejected, schema, &value)
+                    {
+                        value_setter.set_property(drawing_resources, grid, k, &mut value);
+                        o.value = value.into();
+                        o.ui = o.value.clone().ui();
+                    }
                 }
             }
             else
             {
+                let rejected = &mut o.rejected;
+
                 OverallValueField::show_always_enabled(
                     ui,
                     clipboard,
@@ -191,6 +305,12 @@ impl UiOverallProperties
                     &mut o.ui,
                     |new_value| {
                         let mut new_value = d_v.parse(&new_value)?;
+
+                        if !OverallProperty::accept(rejected, schema, &new_value)
+                        {
+                            return None;
+                        }
+
                         value_setter.set_property(drawing_resources, grid, k, &mut new_value);
                         new_value.into()
                     }
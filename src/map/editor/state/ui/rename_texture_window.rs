@@ -0,0 +1,211 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    error_message,
+    map::{drawer::drawing_resources::TextureMaterials, editor::state::texture_rename},
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window to rename the file of a texture along with all the references to it in the map and,
+/// optionally, in the other map files of its folder.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct RenameTextureWindow
+{
+    /// The window.
+    window:     Window,
+    /// The name of the texture to rename, if any.
+    texture:    Option<String>,
+    /// The new name of the texture.
+    name:       String,
+    /// Whether the texture should also be renamed in the other map files in the folder of the
+    /// map being edited.
+    other_maps: bool,
+    /// The outcome of the last rename.
+    report:     String,
+    /// The textures renamed since the last frame, along with their new name, whose references
+    /// outside of the map being edited are yet to be updated.
+    renamed:    Vec<(String, String)>
+}
+
+impl Toggle for RenameTextureWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for RenameTextureWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut RenameTextureWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::RenameTexture(id, close as fn(&mut Self)))
+    }
+}
+
+impl RenameTextureWindow
+{
+    /// Schedules the update of the references to the texture `prev`, renamed to `new`, outside of
+    /// the map being edited.
+    #[inline]
+    pub fn schedule_rename(&mut self, prev: &str, new: &str)
+    {
+        self.renamed.push((prev.to_owned(), new.to_owned()));
+    }
+
+    /// Returns the textures renamed since the last call, along with their new name.
+    #[inline]
+    pub fn take_renamed(&mut self) -> Vec<(String, String)> { std::mem::take(&mut self.renamed) }
+
+    /// Shows the window, renaming the texture when requested.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &mut UiBundle,
+        enabled: bool
+    ) -> bool
+    {
+        /// Whether `name` can be used as the name of a texture file.
+        #[inline]
+        #[must_use]
+        fn valid_name(name: &str) -> bool
+        {
+            !name.is_empty() && name.trim() == name && !name.contains(['.', '/', '\\'])
+        }
+
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let UiBundle {
+            config,
+            drawing_resources,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+        let locale = &config.locale;
+        let map_path = config.open_file.path();
+        let Self {
+            window,
+            texture,
+            name,
+            other_maps,
+            report,
+            renamed
+        } = self;
+        let mut rename = false;
+
+        let focused = window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Rename texture"))
+                    .id("rename_texture_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    egui::Grid::new("rename_texture_grid").num_columns(2).show(ui, |ui| {
+                        ui.label(locale.tr("Texture"));
+
+                        egui::ComboBox::from_id_salt("rename_texture_combo")
+                            .selected_text(texture.as_deref().unwrap_or_else(|| locale.tr("None")))
+                            .show_ui(ui, |ui| {
+                                for t in drawing_resources
+                                    .ui_textures(None::<fn(&&TextureMaterials) -> bool>)
+                                {
+                                    let t = t.texture().name();
+                                    ui.selectable_value(&mut *texture, Some(t.to_owned()), t);
+                                }
+                            });
+
+                        ui.end_row();
+
+                        ui.label(locale.tr("New name"));
+                        ui.text_edit_singleline(name);
+                        ui.end_row();
+
+                        ui.label(locale.tr("Rename in other maps"));
+                        ui.add_enabled(map_path.is_some(), egui::Checkbox::new(other_maps, ""));
+                        ui.end_row();
+                    });
+
+                    ui.separator();
+
+                    rename = ui
+                        .add_enabled(
+                            enabled &&
+                                texture.is_some() &&
+                                valid_name(name) &&
+                                drawing_resources.texture(name).is_none(),
+                            egui::Button::new(locale.tr("Rename"))
+                        )
+                        .clicked();
+
+                    if !report.is_empty()
+                    {
+                        ui.separator();
+                        ui.label(report.as_str());
+                    }
+                }
+            )
+            .unwrap_or_default();
+
+        if !rename
+        {
+            return focused;
+        }
+
+        let prev = texture.as_deref().unwrap();
+
+        if let Err(err) =
+            texture_rename::rename_texture(drawing_resources, manager, grid, prev, name)
+        {
+            error_message(err);
+            return focused;
+        }
+
+        edits_history.with_group("Texture Rename", |edits_history| {
+            edits_history.texture_rename(prev, name);
+        });
+        renamed.push((prev.to_owned(), name.clone()));
+
+        report.clear();
+
+        if let Some(path) = map_path.filter(|_| *other_maps)
+        {
+            let (rewritten, errors) =
+                texture_rename::rename_in_folder(path.parent().unwrap(), path, prev, name);
+            *report = format!("{} {rewritten}", locale.tr("Rewritten files:"));
+
+            for error in errors
+            {
+                report.push('\n');
+                report.push_str(&error);
+            }
+        }
+
+        *texture = Some(std::mem::take(name));
+        focused
+    }
+}
@@ -0,0 +1,138 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::utils::misc::Toggle;
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The console to write and run the scripts that edit the map.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct ScriptConsole
+{
+    /// The window.
+    window: Window,
+    /// The source of the script.
+    source: String,
+    /// The output of the last run.
+    output: String
+}
+
+impl Toggle for ScriptConsole
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for ScriptConsole
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut ScriptConsole) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::ScriptConsole(id, close as fn(&mut Self)))
+    }
+}
+
+impl ScriptConsole
+{
+    /// Returns the source of the script.
+    #[inline]
+    #[must_use]
+    pub fn source(&self) -> &str { &self.source }
+
+    /// Sets the output of the last run.
+    #[inline]
+    pub fn set_output(&mut self, output: String) { self.output = output; }
+
+    /// Shows the console, stores in `command` the request to run the script.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let mut run = false;
+        let Self {
+            window,
+            source,
+            output
+        } = self;
+
+        let focused = window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Script console"))
+                    .id("script_console".into())
+                    .collapsible(true)
+                    .min_width(400f32),
+                |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(source)
+                            .code_editor()
+                            .desired_rows(12)
+                            .desired_width(f32::INFINITY)
+                    );
+
+                    ui.horizontal(|ui| {
+                        run = ui
+                            .add_enabled(
+                                enabled && !source.trim().is_empty(),
+                                egui::Button::new(locale.tr("Run"))
+                            )
+                            .clicked();
+
+                        if ui.button(locale.tr("Clear output")).clicked()
+                        {
+                            output.clear();
+                        }
+                    });
+
+                    if output.is_empty()
+                    {
+                        return;
+                    }
+
+                    ui.separator();
+
+                    egui::ScrollArea::vertical().max_height(200f32).show(ui, |ui| {
+                        ui.add(
+                            egui::Label::new(egui::RichText::new(output.as_str()).monospace())
+                                .wrap()
+                        );
+                    });
+                }
+            )
+            .unwrap_or_default();
+
+        if run
+        {
+            *command = Command::RunScript;
+        }
+
+        focused
+    }
+}
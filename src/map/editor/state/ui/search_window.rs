@@ -0,0 +1,228 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::thing::ThingInterface,
+    utils::{identifiers::EntityId, misc::Toggle},
+    Id,
+    TextureInterface
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window to search the entities by texture or thing name, and cycle through the results.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct SearchWindow
+{
+    /// The window.
+    window:   Window,
+    /// The typed query.
+    query:    String,
+    /// The query the results refer to.
+    searched: String,
+    /// The [`Id`]s of the found entities and their descriptions.
+    results:  Vec<(Id, String)>,
+    /// The index of the next result to go to.
+    index:    usize
+}
+
+impl Toggle for SearchWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for SearchWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut SearchWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Search(id, close as fn(&mut Self)))
+    }
+}
+
+impl SearchWindow
+{
+    /// Opens the window.
+    #[inline]
+    pub fn open(&mut self) { self.window.open(); }
+
+    /// Stores the entities whose texture or thing name contains the query.
+    #[inline]
+    fn search(&mut self, bundle: &UiBundle)
+    {
+        self.results.clear();
+        self.index = 0;
+        self.searched.clone_from(&self.query);
+
+        let query = self.query.trim().to_lowercase();
+
+        if query.is_empty()
+        {
+            return;
+        }
+
+        for brush in bundle.manager.brushes().iter()
+        {
+            if let Some(texture) = brush.texture_settings()
+            {
+                let name = texture.name();
+
+                if name.to_lowercase().contains(&query)
+                {
+                    self.results.push((brush.id(), name.to_owned()));
+                }
+            }
+        }
+
+        for thing in bundle.manager.things()
+        {
+            let name = bundle.things_catalog.thing_or_error(thing.thing_id()).name();
+
+            if name.to_lowercase().contains(&query)
+            {
+                self.results.push((thing.id(), name.to_owned()));
+            }
+        }
+
+        self.results.sort_unstable_by_key(|(id, _)| id.value());
+    }
+
+    /// Shows the window, stores in `command` the request to go to the selected result.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let mut search = false;
+        let mut next = false;
+        let mut clicked = None;
+        let Self {
+            window,
+            query,
+            searched,
+            results,
+            index
+        } = self;
+
+        let focused = window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Search entities"))
+                    .id("search_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    ui.horizontal(|ui| {
+                        let response = ui.text_edit_singleline(query);
+
+                        if response.lost_focus() &&
+                            ui.input(|input| input.key_pressed(egui::Key::Enter))
+                        {
+                            if query != searched
+                            {
+                                search = true;
+                            }
+                            else
+                            {
+                                next = true;
+                            }
+
+                            response.request_focus();
+                        }
+
+                        if ui.button(locale.tr("Search")).clicked()
+                        {
+                            search = true;
+                        }
+                    });
+
+                    if searched.trim().is_empty()
+                    {
+                        return;
+                    }
+
+                    ui.separator();
+
+                    if results.is_empty()
+                    {
+                        ui.label(locale.tr("No results"));
+                        return;
+                    }
+
+                    ui.label(format!("{} {}", locale.tr("Results"), results.len()));
+
+                    egui::ScrollArea::vertical().max_height(320f32).show(ui, |ui| {
+                        ui.add_enabled_ui(enabled, |ui| {
+                            let current = (*index + results.len() - 1) % results.len();
+
+                            for (i, (id, name)) in results.iter().enumerate()
+                            {
+                                if ui
+                                    .selectable_label(
+                                        i == current,
+                                        format!("{}: {name}", id.value())
+                                    )
+                                    .clicked()
+                                {
+                                    clicked = i.into();
+                                }
+                            }
+                        });
+                    });
+                }
+            )
+            .unwrap_or_default();
+
+        if search
+        {
+            self.search(bundle);
+            next = true;
+        }
+
+        if !enabled || self.results.is_empty()
+        {
+            return focused;
+        }
+
+        if let Some(i) = clicked
+        {
+            self.index = i;
+            next = true;
+        }
+
+        if next
+        {
+            *command = Command::GoToEntity(self.results[self.index].0);
+            self.index = (self.index + 1) % self.results.len();
+        }
+
+        focused
+    }
+}
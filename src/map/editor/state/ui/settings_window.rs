@@ -6,12 +6,18 @@
 use bevy::input::keyboard::KeyCode;
 use bevy_egui::egui;
 use hill_vacuum_shared::return_if_no_match;
-use is_executable::IsExecutable;
 
 use super::{window::Window, UiBundle, WindowCloserInfo};
 use crate::{
-    config::{controls::bind::Bind, Config},
-    map::editor::state::{grid::Grid, ui::WindowCloser},
+    config::{
+        controls::bind::Bind,
+        Config,
+        CURSOR_STEP_RANGE,
+        MAP_AUTOSAVE_INTERVAL_RANGE,
+        TEXTURE_WATCH_INTERVAL_RANGE,
+        ZOOM_LIMITS_RANGE
+    },
+    map::editor::state::{core::tool::SubTool, grid::Grid, ui::WindowCloser},
     utils::misc::{Blinker, Toggle}
 };
 
@@ -74,9 +80,11 @@ impl BindEdit
 pub(in crate::map::editor::state::ui) struct SettingsWindow
 {
     /// The window data.
-    window:    Window,
+    window:      Window,
     /// Data concerning the bind being edited.
-    bind_edit: BindEdit
+    bind_edit:   BindEdit,
+    /// The name of the tools settings preset to be saved.
+    preset_name: String
 }
 
 impl Toggle for SettingsWindow
@@ -128,8 +136,19 @@ impl SettingsWindow
             config:
                 Config {
                     binds,
+                    mouse_binds,
                     colors,
-                    exporter,
+                    exporters,
+                    tools_presets,
+                    marching_ants,
+                    locale,
+                    map_autosave_interval,
+                    texture_watch_interval,
+                    min_zoom,
+                    max_zoom,
+                    cursor_step,
+                    navigation,
+                    double_click,
                     ..
                 },
             drawing_resources,
@@ -138,6 +157,7 @@ impl SettingsWindow
             clipboard,
             inputs,
             grid,
+            settings,
             ..
         } = bundle;
 
@@ -171,7 +191,8 @@ impl SettingsWindow
 
         self.window.show(
             egui_context,
-            egui::Window::new("Settings")
+            egui::Window::new(locale.tr("Settings"))
+                .id("settings_window".into())
                 .vscroll(true)
                 .collapsible(true)
                 .max_width(250f32),
@@ -198,10 +219,10 @@ impl SettingsWindow
                     .striped(true)
                     .show(ui, |ui| {
                         // Grid.
-                        ui.label("GRID");
+                        ui.label(locale.tr("GRID"));
                         ui.end_row();
 
-                        ui.label("Skew");
+                        ui.label(locale.tr("Skew"));
                         let mut skew = grid.skew();
 
                         if ui.add(egui::Slider::new(&mut skew, Grid::SKEW_RANGE)).changed()
@@ -211,7 +232,7 @@ impl SettingsWindow
 
                         ui.end_row();
 
-                        ui.label("Angle");
+                        ui.label(locale.tr("Angle"));
                         let mut angle = grid.angle();
 
                         if ui.add(egui::Slider::new(&mut angle, Grid::ANGLE_RANGE)).changed()
@@ -221,8 +242,139 @@ impl SettingsWindow
 
                         ui.end_row();
 
+                        ui.label(locale.tr("Paste snap"));
+                        settings.paste_snap.ui(ui);
+                        ui.end_row();
+
+                        ui.label(locale.tr("Footprint margin"));
+                        ui.add(
+                            egui::DragValue::new(&mut settings.footprint_margin)
+                                .range(0f32..=f32::MAX)
+                        );
+                        ui.end_row();
+
+                        ui.label(locale.tr("Merge rule"));
+                        settings.merge_rule.ui(ui);
+                        ui.end_row();
+
+                        ui.label(locale.tr("Adaptive snap"));
+                        ui.checkbox(&mut settings.adaptive_snap, "");
+                        ui.end_row();
+
+                        ui.label(locale.tr("Adaptive snap pixels"));
+                        ui.add(
+                            egui::DragValue::new(&mut settings.adaptive_snap_pixels).range(4..=128)
+                        );
+                        ui.end_row();
+
+                        ui.label(locale.tr("Path snap"));
+                        settings.path_snap.ui(ui, grid.size());
+                        ui.end_row();
+
+                        ui.label(locale.tr("Snap to paths"));
+                        ui.checkbox(&mut settings.snap_to_paths, "");
+                        ui.end_row();
+
+                        ui.label(locale.tr("Corner radius"));
+                        ui.add(
+                            egui::DragValue::new(&mut settings.corner_radius)
+                                .range(0f32..=f32::MAX)
+                        );
+                        ui.end_row();
+
+                        ui.label(locale.tr("Corner resolution"));
+                        ui.add(egui::DragValue::new(&mut settings.corner_resolution).range(1..=16));
+                        ui.end_row();
+
+                        ui.label(locale.tr("Parallax calibration"));
+                        ui.checkbox(&mut settings.parallax_calibration, "");
+                        ui.end_row();
+
+                        ui.label(locale.tr("Parallax calibration factor"));
+                        ui.add(
+                            egui::DragValue::new(&mut settings.parallax_calibration_factor)
+                                .range(1f32..=16f32)
+                                .speed(0.1)
+                        );
+                        ui.end_row();
+
+                        ui.label(locale.tr("Animate things"));
+                        ui.checkbox(&mut settings.animate_things, "");
+                        ui.end_row();
+
+                        ui.label(locale.tr("Continue duplicated textures"));
+                        ui.checkbox(&mut settings.continue_duplicated_textures, "");
+                        ui.end_row();
+
+                        ui.label(locale.tr("Quake import height"));
+                        ui.add(egui::DragValue::new(&mut settings.quake_slice_height));
+                        ui.end_row();
+
+                        ui.label(locale.tr("Quake import layer thickness"));
+                        ui.add(
+                            egui::DragValue::new(&mut settings.quake_layer_thickness)
+                                .range(0f32..=f32::MAX)
+                        );
+                        ui.end_row();
+
+                        for (subtool, label) in [
+                            (SubTool::VertexInsert, "Sticky vertex insert"),
+                            (SubTool::PathFreeDraw, "Sticky path free draw"),
+                            (SubTool::PathInsertNode, "Sticky path node insert")
+                        ]
+                        {
+                            let mut sticky = settings.is_sticky(subtool);
+                            ui.label(locale.tr(label));
+
+                            if ui.checkbox(&mut sticky, "").changed()
+                            {
+                                settings.set_sticky(subtool, sticky);
+                            }
+
+                            ui.end_row();
+                        }
+
+                        // Tools settings presets.
+                        ui.label(locale.tr("TOOLS PRESETS"));
+                        ui.end_row();
+
+                        let mut removed = None;
+
+                        for (i, preset) in tools_presets.iter().enumerate()
+                        {
+                            if ui.button(preset.name()).clicked()
+                            {
+                                settings.apply_preset(preset);
+                            }
+
+                            if ui.button("-").clicked()
+                            {
+                                removed = i.into();
+                            }
+                            ui.end_row();
+                        }
+
+                        if let Some(index) = removed
+                        {
+                            tools_presets.remove(index);
+                        }
+
+                        ui.text_edit_singleline(&mut self.preset_name);
+
+                        if ui
+                            .add_enabled(
+                                !self.preset_name.trim().is_empty(),
+                                egui::Button::new(locale.tr("Save preset"))
+                            )
+                            .clicked()
+                        {
+                            tools_presets.insert(&self.preset_name, settings.preset_values());
+                            self.preset_name.clear();
+                        }
+                        ui.end_row();
+
                         // Keyboard binds.
-                        ui.label("CONTROLS");
+                        ui.label(locale.tr("CONTROLS"));
                         ui.end_row();
 
                         match self.bind_edit.update(*delta_time)
@@ -244,24 +396,35 @@ impl SettingsWindow
                                             }
                                         }
 
-                                        bind_button(ui, bind.label(), blink);
+                                        bind_button(ui, locale.tr(bind.label()), blink);
                                         break;
                                     }
 
-                                    bind_button(ui, bind.label(), bind.keycode_str(binds));
+                                    bind_button(
+                                        ui,
+                                        locale.tr(bind.label()),
+                                        bind.keycode_str(binds)
+                                    );
                                 }
 
                                 for bind in iter
                                 {
-                                    bind_button(ui, bind.label(), bind.keycode_str(binds));
+                                    bind_button(
+                                        ui,
+                                        locale.tr(bind.label()),
+                                        bind.keycode_str(binds)
+                                    );
                                 }
                             },
                             None =>
                             {
                                 for bind in Bind::iter()
                                 {
-                                    let response =
-                                        bind_button(ui, bind.label(), bind.keycode_str(binds));
+                                    let response = bind_button(
+                                        ui,
+                                        locale.tr(bind.label()),
+                                        bind.keycode_str(binds)
+                                    );
 
                                     if response.clicked()
                                     {
@@ -276,9 +439,12 @@ impl SettingsWindow
                             }
                         };
 
-                        if ui.button("Reset to default").clicked()
+                        mouse_binds.show(ui, locale);
+
+                        if ui.button(locale.tr("Reset to default")).clicked()
                         {
                             binds.reset();
+                            mouse_binds.reset();
                         }
                         ui.end_row();
 
@@ -286,44 +452,119 @@ impl SettingsWindow
                         ui.end_row();
 
                         // Colors.
-                        ui.label("COLORS");
+                        ui.label(locale.tr("COLORS"));
                         ui.end_row();
 
                         colors.show(bundle.materials, ui);
 
-                        if ui.button("Reset to default").clicked()
+                        if ui.button(locale.tr("Reset to default")).clicked()
                         {
                             colors.reset(bundle.materials);
                         }
                         ui.end_row();
 
+                        ui.label(locale.tr("Marching ants"));
+                        ui.checkbox(marching_ants, "");
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Camera.
+                        ui.label(locale.tr("CAMERA"));
+                        ui.end_row();
+
+                        ui.label(locale.tr("Minimum zoom"));
+                        ui.add(
+                            egui::DragValue::new(min_zoom)
+                                .range(*ZOOM_LIMITS_RANGE.start()..=*max_zoom)
+                                .suffix("%")
+                        );
+                        ui.end_row();
+
+                        ui.label(locale.tr("Maximum zoom"));
+                        ui.add(
+                            egui::DragValue::new(max_zoom)
+                                .range(*min_zoom..=*ZOOM_LIMITS_RANGE.end())
+                                .suffix("%")
+                        );
+                        ui.end_row();
+
+                        navigation.show(ui, locale);
+
                         ui.label("");
                         ui.end_row();
 
-                        // Exporter.
-                        ui.label("EXPORTER");
+                        // Double-click.
+                        ui.label(locale.tr("DOUBLE-CLICK"));
                         ui.end_row();
 
-                        if ui.button("Pick exporter").clicked()
+                        double_click.show(ui, locale);
+
+                        if ui.button(locale.tr("Reset to default")).clicked()
                         {
-                            match rfd::FileDialog::new()
-                                .set_directory(std::env::current_dir().unwrap())
-                                .set_title("Pick exporter")
-                                .pick_file()
-                            {
-                                Some(file) if file.is_executable() => *exporter = file.into(),
-                                _ => ()
-                            }
+                            double_click.reset();
                         }
+                        ui.end_row();
 
-                        let label = match exporter
-                        {
-                            Some(path) => path.file_stem().unwrap().to_str().unwrap(),
-                            None => ""
-                        };
+                        ui.label("");
+                        ui.end_row();
+
+                        // Cursor.
+                        ui.label(locale.tr("CURSOR"));
+                        ui.end_row();
 
-                        ui.label(label);
+                        ui.label(locale.tr("Cursor step"));
+                        ui.add(egui::DragValue::new(cursor_step).range(CURSOR_STEP_RANGE));
                         ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Autosave.
+                        ui.label(locale.tr("AUTOSAVE"));
+                        ui.end_row();
+
+                        ui.label(locale.tr("Snapshots interval"));
+                        ui.add(
+                            egui::DragValue::new(map_autosave_interval)
+                                .range(MAP_AUTOSAVE_INTERVAL_RANGE)
+                                .suffix(" s")
+                        );
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Textures.
+                        ui.label(locale.tr("TEXTURES"));
+                        ui.end_row();
+
+                        ui.label(locale.tr("Hot reload interval"));
+                        ui.add(
+                            egui::DragValue::new(texture_watch_interval)
+                                .range(TEXTURE_WATCH_INTERVAL_RANGE)
+                                .suffix(" s")
+                        );
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Exporters.
+                        ui.label(locale.tr("EXPORTERS"));
+                        ui.end_row();
+
+                        exporters.show(ui, locale);
+
+                        ui.label("");
+                        ui.end_row();
+
+                        // Language.
+                        ui.label(locale.tr("LANGUAGE"));
+                        ui.end_row();
+
+                        locale.show(ui);
                     });
             }
         );
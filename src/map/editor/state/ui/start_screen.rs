@@ -0,0 +1,329 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::path::{Path, PathBuf};
+
+use bevy_egui::egui;
+use hill_vacuum_shared::FILE_EXTENSION;
+
+use super::{manual::Manual, window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::{editor::state::grid::Grid, GridSettings},
+    utils::misc::Toggle,
+    NAME
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The grid sizes that can be picked for a new map.
+const GRID_SIZES: [i16; 5] = [16, 32, 64, 128, 256];
+/// The directory where the new map templates are located.
+const TEMPLATES_DIR: &str = "assets/templates/";
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window shown on startup when no map is opened, which allows to open a recent map, create a
+/// new one, or read the manual.
+pub(in crate::map::editor::state::ui) struct StartScreen
+{
+    /// The window.
+    window:    Window,
+    /// The size of the grid of the new map.
+    grid_size: i16,
+    /// The skew of the grid of the new map.
+    skew:      i8,
+    /// The angle of the grid of the new map.
+    angle:     i16,
+    /// The map files that can be used as templates for a new map.
+    templates: Vec<PathBuf>,
+    /// The index of the template of the new map, if any.
+    template:  Option<usize>
+}
+
+impl Default for StartScreen
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            window:    Window::default(),
+            grid_size: Grid::default().size(),
+            skew:      0,
+            angle:     0,
+            templates: Vec::new(),
+            template:  None
+        }
+    }
+}
+
+impl Toggle for StartScreen
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for StartScreen
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(start_screen: &mut StartScreen) { start_screen.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::StartScreen(id, close as fn(&mut Self)))
+    }
+}
+
+impl StartScreen
+{
+    /// Opens the window and gathers the new map templates.
+    #[inline]
+    pub fn open(&mut self)
+    {
+        self.window.open();
+        self.templates = Self::templates();
+        self.template = None;
+    }
+
+    /// Returns the map files stored in the templates folder.
+    #[inline]
+    #[must_use]
+    fn templates() -> Vec<PathBuf>
+    {
+        /// Gathers all map files.
+        #[inline]
+        fn recurse(path: &Path, templates: &mut Vec<PathBuf>)
+        {
+            if path.is_file()
+            {
+                if path.extension().is_some_and(|ext| ext == FILE_EXTENSION)
+                {
+                    templates.push(path.to_path_buf());
+                }
+
+                return;
+            }
+
+            let Ok(entries) = std::fs::read_dir(path)
+            else
+            {
+                return;
+            };
+
+            for entry in entries.flatten()
+            {
+                recurse(&entry.path(), templates);
+            }
+        }
+
+        let mut templates = Vec::new();
+        recurse(Path::new(TEMPLATES_DIR), &mut templates);
+        templates.sort();
+        templates
+    }
+
+    /// Returns the path of the template at `index`, if any.
+    #[inline]
+    #[must_use]
+    pub fn template(&self, index: usize) -> Option<&PathBuf> { self.templates.get(index) }
+
+    /// Returns the grid of the new map.
+    #[inline]
+    fn grid(size: i16, skew: i8, angle: i16) -> Grid
+    {
+        let mut grid = Grid::new(GridSettings::default()).with_size(size);
+        grid.set_skew(skew);
+        grid.set_angle(angle);
+        grid.reset_changed();
+        grid
+    }
+
+    /// Shows the start screen, stores in `command` the action picked by the user.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        manual: &mut Manual,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let mut close = false;
+
+        let focused = self
+            .window
+            .show(
+                egui_context,
+                egui::Window::new(NAME)
+                    .id("start_screen".into())
+                    .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+                    .collapsible(false)
+                    .resizable(false)
+                    .min_width(300f32),
+                |ui| {
+                    ui.spacing_mut().item_spacing.y = 6f32;
+
+                    // Recent files.
+                    ui.label(egui::RichText::new(locale.tr("RECENT FILES")).strong());
+
+                    if bundle.config.open_file.recent().len() == 0
+                    {
+                        ui.label(locale.tr("No recent files"));
+                    }
+
+                    for (i, path) in bundle.config.open_file.recent().enumerate()
+                    {
+                        let name = path.file_stem().unwrap().to_str().unwrap();
+
+                        if ui.button(name).on_hover_text(path.to_str().unwrap()).clicked()
+                        {
+                            *command = Command::OpenRecent(i);
+                            close = true;
+                        }
+                    }
+
+                    if ui.button(locale.tr("Open")).clicked()
+                    {
+                        *command = Command::Open;
+                        close = true;
+                    }
+
+                    ui.separator();
+
+                    // New map.
+                    ui.label(egui::RichText::new(locale.tr("NEW MAP")).strong());
+
+                    egui::Grid::new("start_screen_grid")
+                        .num_columns(2)
+                        .spacing([40f32, 4f32])
+                        .show(ui, |ui| {
+                            /// The name of the template at `index`.
+                            #[inline]
+                            fn template_name(templates: &[PathBuf], index: usize) -> &str
+                            {
+                                templates[index].file_stem().unwrap().to_str().unwrap()
+                            }
+
+                            ui.label(locale.tr("Template"));
+                            egui::ComboBox::from_id_salt("start_screen_template")
+                                .selected_text(match self.template
+                                {
+                                    Some(index) => template_name(&self.templates, index),
+                                    None => locale.tr("None")
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(
+                                        &mut self.template,
+                                        None,
+                                        locale.tr("None")
+                                    );
+
+                                    for i in 0..self.templates.len()
+                                    {
+                                        ui.selectable_value(
+                                            &mut self.template,
+                                            i.into(),
+                                            template_name(&self.templates, i)
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+
+                            // The grid is stored in the template.
+                            let grid_editable = self.template.is_none();
+
+                            ui.label(locale.tr("Grid size"));
+                            ui.add_enabled_ui(grid_editable, |ui| {
+                                egui::ComboBox::from_id_salt("start_screen_grid_size")
+                                    .selected_text(self.grid_size.to_string())
+                                    .show_ui(ui, |ui| {
+                                        for size in GRID_SIZES
+                                        {
+                                            ui.selectable_value(
+                                                &mut self.grid_size,
+                                                size,
+                                                size.to_string()
+                                            );
+                                        }
+                                    });
+                            });
+                            ui.end_row();
+
+                            ui.label(locale.tr("Skew"));
+                            ui.add_enabled(
+                                grid_editable,
+                                egui::Slider::new(&mut self.skew, Grid::SKEW_RANGE)
+                            );
+                            ui.end_row();
+
+                            ui.label(locale.tr("Angle"));
+                            ui.add_enabled(
+                                grid_editable,
+                                egui::Slider::new(&mut self.angle, Grid::ANGLE_RANGE)
+                            );
+                            ui.end_row();
+                        });
+
+                    if ui.button(locale.tr("New")).clicked()
+                    {
+                        *command = match self.template
+                        {
+                            Some(index) => Command::NewFromTemplate(index),
+                            None =>
+                            {
+                                Command::NewWithGrid(Self::grid(
+                                    self.grid_size,
+                                    self.skew,
+                                    self.angle
+                                ))
+                            },
+                        };
+
+                        close = true;
+                    }
+
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        if ui.button(locale.tr("Manual")).clicked()
+                        {
+                            manual.toggle();
+                            close = true;
+                        }
+
+                        if ui.button(locale.tr("Continue")).clicked()
+                        {
+                            close = true;
+                        }
+                    });
+                }
+            )
+            .unwrap_or_default();
+
+        if close
+        {
+            self.window.close();
+        }
+
+        focused
+    }
+}
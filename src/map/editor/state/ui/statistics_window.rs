@@ -0,0 +1,119 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{map::editor::state::edits_history::statistics::MapStatistics, utils::misc::Toggle};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window showing the statistics of the work done on the map.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct StatisticsWindow
+{
+    /// The window.
+    window: Window
+}
+
+impl Toggle for StatisticsWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for StatisticsWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut StatisticsWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Statistics(id, close as fn(&mut Self)))
+    }
+}
+
+impl StatisticsWindow
+{
+    /// Shows the window.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(&mut self, egui_context: &egui::Context, bundle: &UiBundle) -> bool
+    {
+        /// Returns `seconds` formatted as hours, minutes, and seconds.
+        #[inline]
+        #[must_use]
+        fn time(seconds: f64) -> String
+        {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let seconds = seconds as u64;
+            format!("{}:{:02}:{:02}", seconds / 3600, (seconds / 60) % 60, seconds % 60)
+        }
+
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let statistics = bundle.edits_history.statistics();
+        let session = statistics.session();
+        let total = statistics.total();
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Statistics"))
+                    .id("statistics_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    /// Adds a row to the grid.
+                    #[inline]
+                    fn row(
+                        ui: &mut egui::Ui,
+                        label: &str,
+                        session: &MapStatistics,
+                        total: &MapStatistics,
+                        value: impl Fn(&MapStatistics) -> String
+                    )
+                    {
+                        ui.label(label);
+                        ui.label(value(session));
+                        ui.label(value(total));
+                        ui.end_row();
+                    }
+
+                    egui::Grid::new("statistics_grid")
+                        .num_columns(3)
+                        .spacing([40f32, 4f32])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("");
+                            ui.label(locale.tr("Session"));
+                            ui.label(locale.tr("Total"));
+                            ui.end_row();
+
+                            row(ui, locale.tr("Time"), &session, &total, |s| time(s.time_spent()));
+                            row(ui, locale.tr("Edits"), &session, &total, |s| {
+                                s.edits().to_string()
+                            });
+                            row(ui, locale.tr("Brushes created"), &session, &total, |s| {
+                                s.brushes_created().to_string()
+                            });
+                        });
+                }
+            )
+            .unwrap_or_default()
+    }
+}
@@ -5,8 +5,10 @@ mod animation_editor;
 //
 //=======================================================================//
 
+use std::ops::RangeInclusive;
+
 use bevy_egui::egui;
-use hill_vacuum_shared::{return_if_none, TEXTURE_HEIGHT_RANGE};
+use hill_vacuum_shared::{continue_if_none, return_if_none, TEXTURE_HEIGHT_RANGE};
 
 use self::animation_editor::{AnimationEditor, Target};
 use super::{
@@ -19,26 +21,28 @@ use super::{
     WindowCloserInfo
 };
 use crate::{
-    config::controls::bind::Bind,
+    config::{controls::bind::Bind, favorite_textures::FavoriteTextures},
     map::{
         drawer::{
             drawing_resources::{DrawingResources, TextureMaterials},
             overall_values::{OverallTextureSettings, UiOverallTextureSettings},
-            texture::Texture
+            texture::{Texture, TEXTURE_OPACITY_RANGE}
         },
         editor::state::{
             edits_history::EditsHistory,
             format_texture_preview,
             grid::Grid,
             manager::{EntitiesManager, TextureResult},
-            ui::{minus_plus_buttons::MinusPlusButtons, texture_per_row}
+            ui::{minus_plus_buttons::MinusPlusButtons, texture_per_row, AccessibleName}
         }
     },
     utils::{
+        collections::HashMap,
         identifiers::EntityId,
         misc::Toggle,
         overall_value::{OverallValue, OverallValueInterface, OverallValueToUi, UiOverallValue}
-    }
+    },
+    TextureInterface
 };
 
 //=======================================================================//
@@ -50,6 +54,8 @@ use crate::{
 const SETTING_HEIGHT: f32 = 25f32;
 /// The size of the side of the texture previews in the texture list.
 const TEXTURE_GALLERY_PREVIEW_FRAME_SIDE: f32 = 128f32;
+/// The size of the longest side of the previews of the recently applied textures.
+const RECENT_TEXTURE_PREVIEW_SIDE: f32 = 48f32;
 /// The width of the name of the field.
 const FIELD_NAME_WIDTH: f32 = 70f32;
 /// The slider width.
@@ -62,6 +68,8 @@ const MINUS_PLUS_TOTAL_WIDTH: f32 = MINUS_PLUS_WIDTH * 2f32;
 const MINUS_PLUS_HEIGHT: f32 = 19f32;
 /// The width of a delete button.
 const DELETE_BUTTON_WIDTH: f32 = 12f32;
+/// The range of the values of the uniform scale slider.
+const UNIFORM_SCALE_RANGE: RangeInclusive<f32> = 0.125..=8f32;
 
 //=======================================================================//
 // MACROS
@@ -143,6 +151,7 @@ macro_rules! scale_offset_scroll_parallax {
         $step:literal,
         $clamp:expr
         $(, $default_if_none:literal)?
+        $(; link: $link:ident)?
     )),+) => { paste::paste! { $(
         #[inline]
         fn [< set_ $value >](
@@ -159,6 +168,8 @@ macro_rules! scale_offset_scroll_parallax {
             /// The padding before the y label.
             const Y_LABEL_PADDING: f32 = 8f32;
 
+            $(let $link = self.$link;)?
+
             strip
                 .size(egui_extras::Size::exact(FIELD_NAME_WIDTH))
                 .size(egui_extras::Size::exact(field_width))
@@ -169,6 +180,31 @@ macro_rules! scale_offset_scroll_parallax {
                 .horizontal(|mut strip| {
                     strip.cell(|ui| { ui.label(X_LABEL); });
 
+                    let setter = Self::[< $value _x_setter >];
+                    $(
+                        let setter = |
+                            drawing_resources: &DrawingResources,
+                            manager: &mut EntitiesManager,
+                            edits_history: &mut EditsHistory,
+                            grid: &Grid,
+                            value: f32
+                        | {
+                            if $link
+                            {
+                                return Self::[< linked_ $value _setter >](
+                                    drawing_resources,
+                                    manager,
+                                    edits_history,
+                                    grid,
+                                    value,
+                                    true
+                                );
+                            }
+
+                            setter(drawing_resources, manager, edits_history, grid, value)
+                        };
+                    )?
+
                     let value = &mut self.overall_texture.[< $value _x >];
                     $(
                         let _ = $default_if_none;
@@ -185,7 +221,7 @@ macro_rules! scale_offset_scroll_parallax {
                         value,
                         $step,
                         $clamp,
-                        Self::[< $value _x_setter >]
+                        setter
                     );
 
                     strip.cell(|ui| {
@@ -193,6 +229,31 @@ macro_rules! scale_offset_scroll_parallax {
                         ui.label(Y_LABEL);
                     });
 
+                    let setter = Self::[< $value _y_setter >];
+                    $(
+                        let setter = |
+                            drawing_resources: &DrawingResources,
+                            manager: &mut EntitiesManager,
+                            edits_history: &mut EditsHistory,
+                            grid: &Grid,
+                            value: f32
+                        | {
+                            if $link
+                            {
+                                return Self::[< linked_ $value _setter >](
+                                    drawing_resources,
+                                    manager,
+                                    edits_history,
+                                    grid,
+                                    value,
+                                    false
+                                );
+                            }
+
+                            setter(drawing_resources, manager, edits_history, grid, value)
+                        };
+                    )?
+
                     let value = &mut self.overall_texture.[< $value _y >];
                     $(
                         let _ = $default_if_none;
@@ -209,7 +270,7 @@ macro_rules! scale_offset_scroll_parallax {
                         value,
                         $step,
                         $clamp,
-                        Self::[< $value _y_setter >]
+                        setter
                     );
                 });
         }
@@ -257,6 +318,66 @@ macro_rules! angle_height {
     )+}};
 }
 
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The set of textures the randomizer picks from.
+#[derive(Default, Clone, Copy, PartialEq)]
+enum RandomTextures
+{
+    /// The textures whose name matches the name filter.
+    #[default]
+    NameFilter,
+    /// The recently applied textures.
+    Recent
+}
+
+impl RandomTextures
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::NameFilter => "Name filter",
+            Self::Recent => "Recent"
+        }
+    }
+}
+
+//=======================================================================//
+
+/// The order of the textures shown in the gallery.
+#[derive(Default, Clone, Copy, PartialEq)]
+enum TextureSort
+{
+    /// Alphabetical.
+    #[default]
+    Name,
+    /// From the most recently assigned, followed by the never assigned ones in alphabetical
+    /// order.
+    RecentlyUsed
+}
+
+impl TextureSort
+{
+    /// A string representation of `self`.
+    #[inline]
+    #[must_use]
+    const fn tag(self) -> &'static str
+    {
+        match self
+        {
+            Self::Name => "Name",
+            Self::RecentlyUsed => "Recently used"
+        }
+    }
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -356,6 +477,21 @@ struct Innards
     name_filter:      String,
     width_filter:     SizeFilter,
     height_filter:    SizeFilter,
+    /// The folder whose textures, and the ones of its subfolders, are shown in the gallery, if
+    /// any.
+    folder_filter:    Option<String>,
+    /// Whether only the favorite textures are shown in the gallery.
+    favorites_only:   bool,
+    /// The order of the textures of the gallery.
+    texture_sort:     TextureSort,
+    /// Whether editing the scale along one axis scales the other one by the same factor.
+    scale_link:       bool,
+    /// The value of the uniform scale slider.
+    uniform_scale:    f32,
+    /// The set of textures the randomizer picks from.
+    random_textures:  RandomTextures,
+    /// The seed of the randomizer.
+    random_seed:      u64,
     /// The overall texture.
     overall_texture:  UiOverallTextureSettings,
     /// The editor of the texture animation.
@@ -378,7 +514,7 @@ impl Innards
             }
 
             scale
-        }),
+        }; link: scale_link),
         (offset, "Offset", 1f32, no_clamp),
         (scroll, "Scroll", 1f32, no_clamp, 0),
         (parallax, "Parallax", 0.05, no_clamp, 0)
@@ -388,20 +524,211 @@ impl Innards
         (angle, "Angle", f32, |angle, _| angle.rem_euclid(360f32)),
         (height, "Height", i8, |height, _| {
             height.clamp(*TEXTURE_HEIGHT_RANGE.start(), *TEXTURE_HEIGHT_RANGE.end())
+        }),
+        (opacity, "Opacity (%)", i8, |opacity, _| {
+            opacity.clamp(0, i8::try_from(*TEXTURE_OPACITY_RANGE.end()).unwrap())
         })
     );
 
+    /// Sets the scales of the textures of the selected brushes to the values returned by `f`, which
+    /// receives their current scales.
+    #[inline]
+    fn scales_setter<F>(
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        f: F
+    ) -> bool
+    where
+        F: Fn(f32, f32) -> (f32, f32)
+    {
+        let scales = manager
+            .selected_textured_brushes()
+            .map(|brush| {
+                let settings = brush.texture_settings().unwrap();
+                (brush.id(), f(settings.scale_x(), settings.scale_y()))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let valid = manager.test_operation_validity(|manager| {
+            manager
+                .selected_textured_brushes_mut(drawing_resources, grid)
+                .find_map(|mut brush| {
+                    let (x, y) = scales[&brush.id()];
+
+                    (!(brush.check_texture_scale_x(drawing_resources, grid, x) &&
+                        brush.check_texture_scale_y(drawing_resources, grid, y)))
+                    .then_some(brush.id())
+                })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        edits_history.with_group("Texture scale", |edits_history| {
+            edits_history.texture_scale_x_cluster(
+                manager
+                    .selected_textured_brushes_mut(drawing_resources, grid)
+                    .filter_map(|mut brush| {
+                        let id = brush.id();
+                        brush.set_texture_scale_x(scales[&id].0).map(|prev| (id, prev))
+                    })
+            );
+
+            edits_history.texture_scale_y_cluster(
+                manager
+                    .selected_textured_brushes_mut(drawing_resources, grid)
+                    .filter_map(|mut brush| {
+                        let id = brush.id();
+                        brush.set_texture_scale_y(scales[&id].1).map(|prev| (id, prev))
+                    })
+            );
+        });
+
+        manager.schedule_outline_update();
+        true
+    }
+
+    /// Sets the scale along the x axis, if `x`, or the y axis of the textures of the selected
+    /// brushes to `value`, and scales the other axis by the same factor.
+    #[inline]
+    fn linked_scale_setter(
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        value: f32,
+        x: bool
+    ) -> bool
+    {
+        Self::scales_setter(drawing_resources, manager, edits_history, grid, |scale_x, scale_y| {
+            // The factor is unsigned so that flipping one axis does not flip the other.
+            if x
+            {
+                (value, scale_y * (value / scale_x).abs())
+            }
+            else
+            {
+                (scale_x * (value / scale_y).abs(), value)
+            }
+        })
+    }
+
+    /// Sets the scale along both axis of the textures of the selected brushes to `value`,
+    /// retaining their sign.
+    #[inline]
+    fn uniform_scale_setter(
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        value: f32
+    ) -> bool
+    {
+        Self::scales_setter(drawing_resources, manager, edits_history, grid, |scale_x, scale_y| {
+            (value.copysign(scale_x), value.copysign(scale_y))
+        })
+    }
+
+    /// Draws the uniform scale slider and the toggle to link the scale along the two axis.
+    #[inline]
+    fn set_uniform_scale(
+        &mut self,
+        strip: egui_extras::StripBuilder,
+        bundle: &mut UiBundle,
+        field_width: f32
+    )
+    {
+        let UiBundle {
+            drawing_resources,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+
+        strip
+            .size(egui_extras::Size::exact(FIELD_NAME_WIDTH))
+            .size(egui_extras::Size::exact(field_width + MINUS_PLUS_TOTAL_WIDTH))
+            .size(egui_extras::Size::remainder())
+            .horizontal(|mut strip| {
+                strip.cell(|ui| {
+                    ui.label("Uniform");
+                });
+
+                strip.cell(|ui| {
+                    // Leave room for the value.
+                    ui.spacing_mut().slider_width = field_width + MINUS_PLUS_TOTAL_WIDTH - 60f32;
+
+                    let response = ui.add_enabled(
+                        !self.overall_texture.scale_x.is_none(),
+                        egui::Slider::new(&mut self.uniform_scale, UNIFORM_SCALE_RANGE)
+                            .logarithmic(true)
+                    );
+
+                    // Applied once the drag ends so that a single edit is stored in the history.
+                    if response.drag_stopped() || (response.changed() && !response.dragged())
+                    {
+                        _ = Self::uniform_scale_setter(
+                            drawing_resources,
+                            manager,
+                            edits_history,
+                            grid,
+                            self.uniform_scale
+                        );
+                    }
+                });
+
+                strip.cell(|ui| {
+                    ui.add_space(8f32);
+                    ui.checkbox(&mut self.scale_link, "Link scale X and Y");
+                });
+            });
+    }
+
+    /// Sets the opacity of the textures of the selected brushes to `value`, which is edited as an
+    /// [`i8`] in the UI since the minus and plus field requires a signed value.
+    #[inline]
+    fn opacity_setter(
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid,
+        value: i8
+    ) -> bool
+    {
+        let value = u8::try_from(value).unwrap();
+
+        edits_history.texture_opacity_cluster(
+            manager
+                .selected_textured_brushes_mut(drawing_resources, grid)
+                .filter_map(|mut brush| {
+                    brush.set_texture_opacity(value).map(|prev| (brush.id(), prev))
+                })
+        );
+
+        true
+    }
+
     /// Assigns a texture to the selected brushes, if possible.
     #[inline]
     fn assign_texture(
-        drawing_resources: &DrawingResources,
+        drawing_resources: &mut DrawingResources,
         manager: &mut EntitiesManager,
         edits_history: &mut EditsHistory,
         grid: &Grid,
         texture: &str
     ) -> bool
     {
-        match manager.set_selected_brushes_texture(drawing_resources, edits_history, grid, texture)
+        let valid = match manager.set_selected_brushes_texture(
+            drawing_resources,
+            edits_history,
+            grid,
+            texture
+        )
         {
             TextureResult::Invalid => false,
             TextureResult::Valid => true,
@@ -410,7 +737,82 @@ impl Innards
                 manager.schedule_outline_update();
                 true
             }
+        };
+
+        if valid
+        {
+            manager.push_recent_texture(texture);
+            drawing_resources.push_used_texture(texture);
+        }
+
+        valid
+    }
+
+    /// Draws the textures randomizer, which assigns to each selected brush a texture picked from
+    /// the chosen set.
+    #[inline]
+    fn randomizer(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
+    {
+        let UiBundle {
+            drawing_resources,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+
+        ui.label("Randomize from");
+        ui.add_space(2f32);
+
+        egui::ComboBox::from_id_salt("random_textures")
+            .selected_text(self.random_textures.tag())
+            .show_ui(ui, |ui| {
+                for source in [RandomTextures::NameFilter, RandomTextures::Recent]
+                {
+                    ui.selectable_value(&mut self.random_textures, source, source.tag());
+                }
+            });
+
+        ui.add_space(2f32);
+        ui.label("Seed");
+        ui.add_space(2f32);
+        ui.add(egui::DragValue::new(&mut self.random_seed));
+        ui.add_space(2f32);
+
+        if !ui
+            .add_enabled(manager.any_selected_brushes(), egui::Button::new("Randomize"))
+            .clicked()
+        {
+            return;
         }
+
+        let textures = match self.random_textures
+        {
+            RandomTextures::NameFilter =>
+            {
+                drawing_resources
+                    .ui_textures(Some(|texture: &&TextureMaterials| {
+                        fuzzy_match(texture, &self.name_filter)
+                    }))
+                    .map(|texture| texture.texture().name().to_owned())
+                    .collect::<Vec<_>>()
+            },
+            RandomTextures::Recent => manager.recent_textures().to_vec()
+        };
+
+        match edits_history.with_group("Texture Randomization", |edits_history| {
+            manager.randomize_selected_brushes_texture(
+                drawing_resources,
+                edits_history,
+                grid,
+                &textures,
+                self.random_seed
+            )
+        })
+        {
+            TextureResult::Invalid | TextureResult::Valid => (),
+            TextureResult::ValidRefreshOutline => manager.schedule_outline_update()
+        };
     }
 
     /// The name of the texture being edited, if any.
@@ -477,7 +879,7 @@ impl Innards
     fn texture_settings(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle, available_width: f32)
     {
         egui_extras::StripBuilder::new(ui)
-            .sizes(egui_extras::Size::exact(SETTING_HEIGHT), 9)
+            .sizes(egui_extras::Size::exact(SETTING_HEIGHT), 11)
             .vertical(|mut strip| {
                 let plus_minus_field_width =
                     available_width / 2f32 - 11.5 - (FIELD_NAME_WIDTH + MINUS_PLUS_TOTAL_WIDTH);
@@ -486,7 +888,7 @@ impl Innards
                     self.set_texture(strip, bundle, available_width);
                 });
 
-                for func in [Self::set_offset, Self::set_scale]
+                for func in [Self::set_offset, Self::set_scale, Self::set_uniform_scale]
                 {
                     strip.strip(|strip| {
                         func(self, strip, bundle, plus_minus_field_width);
@@ -505,6 +907,10 @@ impl Innards
                     self.set_height(strip, bundle, plus_minus_field_width);
                 });
 
+                strip.strip(|strip| {
+                    self.set_opacity(strip, bundle, plus_minus_field_width);
+                });
+
                 strip.strip(|strip| {
                     self.set_sprite(strip, bundle);
                 });
@@ -535,7 +941,9 @@ impl Innards
             strip.cell(|ui| {
                 for (label, setting) in [
                     ("Show scroll  ", &mut settings.scroll_enabled),
-                    ("Show parallax  ", &mut settings.parallax_enabled)
+                    ("Show parallax  ", &mut settings.parallax_enabled),
+                    ("Scroll arrows  ", &mut settings.scroll_arrows),
+                    ("Texture grid  ", &mut settings.texture_grid)
                 ]
                 {
                     ui.label(label);
@@ -551,6 +959,28 @@ impl Innards
                             .map(|mut brush| (brush.id(), brush.reset_texture()))
                     );
                 }
+
+                if ui.button("Reset offset").clicked() &&
+                    Self::offset_x_setter(drawing_resources, manager, edits_history, grid, 0f32)
+                {
+                    _ = Self::offset_y_setter(
+                        drawing_resources,
+                        manager,
+                        edits_history,
+                        grid,
+                        0f32
+                    );
+                }
+
+                if ui.button("Reset angle").clicked()
+                {
+                    _ = Self::angle_setter(drawing_resources, manager, edits_history, grid, 0f32);
+                }
+
+                if ui.button("Grid align").clicked()
+                {
+                    _ = Self::align_offset_to_grid(drawing_resources, manager, edits_history, grid);
+                }
             });
         });
     }
@@ -597,21 +1027,60 @@ impl Innards
         });
     }
 
+    /// Returns the textures to be shown in the gallery, filtered and sorted according to the
+    /// gallery settings.
+    #[inline]
+    #[must_use]
+    fn gallery_textures<'a>(
+        &self,
+        drawing_resources: &'a DrawingResources,
+        favorites: &FavoriteTextures
+    ) -> Vec<&'a TextureMaterials>
+    {
+        let name_filter = self.name_filter.as_str();
+        let folder_filter = self.folder_filter.as_deref();
+        let w_filter = self.width_filter.value;
+        let h_filter = self.height_filter.value;
+
+        let mut textures = drawing_resources
+            .ui_textures(Some(|texture: &&TextureMaterials| {
+                let size = texture.texture().size();
+
+                fuzzy_match(texture, name_filter) &&
+                    w_filter.is_none_or(|w| size.x == w) &&
+                    h_filter.is_none_or(|h| size.y == h) &&
+                    folder_filter.is_none_or(|folder| in_folder(texture.folder(), folder)) &&
+                    (!self.favorites_only || favorites.contains(texture.texture().name()))
+            }))
+            .collect::<Vec<_>>();
+
+        if self.texture_sort == TextureSort::RecentlyUsed
+        {
+            textures.sort_by_key(|texture| {
+                drawing_resources.texture_use_rank(texture.texture().name())
+            });
+        }
+
+        textures
+    }
+
+    /// Draws the gallery of the textures. Clicked textures are assigned to the selected brushes,
+    /// or added to the list animation being edited, and middle clicked ones are marked or unmarked
+    /// as favorite.
     #[inline]
     fn textures_gallery(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
     {
         #[inline]
-        fn gallery<'a, F, G>(
+        fn gallery<G>(
             ui: &mut egui::Ui,
-            drawing_resources: &'a DrawingResources,
+            textures: Vec<&TextureMaterials>,
+            favorites: &FavoriteTextures,
             textures_per_row: usize,
-            filter: Option<F>,
             mut click_func: G
         ) where
-            F: Fn(&&'a TextureMaterials) -> bool,
             G: FnMut(&Texture, &egui::Response)
         {
-            let mut textures = drawing_resources.ui_textures(filter);
+            let mut textures = textures.into_iter();
 
             while ui
                 .horizontal(|ui| {
@@ -638,11 +1107,19 @@ impl Innards
                                 texture.size(),
                                 TEXTURE_GALLERY_PREVIEW_FRAME_SIDE
                             );
+                            response.image_button_name(true, || texture.label().to_owned());
 
                             click_func(texture, &response);
 
                             ui.vertical_centered(|ui| {
-                                ui.add(egui::Label::new(texture.label()).wrap());
+                                let mut label = egui::RichText::new(texture.label());
+
+                                if favorites.contains(texture.name())
+                                {
+                                    label = label.color(ui.visuals().warn_fg_color);
+                                }
+
+                                ui.add(egui::Label::new(label).wrap());
                             });
                         });
                     }
@@ -654,60 +1131,140 @@ impl Innards
             {}
         }
 
-        #[inline]
-        #[must_use]
-        fn name_filter(
-            texture: &TextureMaterials,
-            n: Option<&str>,
-            _: Option<u32>,
-            _: Option<u32>
-        ) -> bool
+        let UiBundle {
+            config,
+            drawing_resources,
+            manager,
+            edits_history,
+            grid,
+            ..
+        } = bundle;
+
+        let textures_per_row = texture_per_row(ui, TEXTURE_GALLERY_PREVIEW_FRAME_SIDE);
+        let mut hovered_texture = None;
+        let mut clicked_texture = None;
+        let mut toggled_favorite = None;
+
+        gallery(
+            ui,
+            self.gallery_textures(drawing_resources, &config.favorite_textures),
+            &config.favorite_textures,
+            textures_per_row,
+            |texture, response| {
+                if response.hovered()
+                {
+                    hovered_texture = texture.name().to_owned().into();
+                }
+
+                if response.clicked()
+                {
+                    clicked_texture = texture.name().to_owned().into();
+                }
+                else if response.secondary_clicked()
+                {
+                    self.animation_editor.set_texture_override(texture);
+                }
+                else if response.middle_clicked()
+                {
+                    toggled_favorite = texture.name().to_owned().into();
+                }
+            }
+        );
+
+        if let Some(texture) = toggled_favorite
         {
-            texture.texture().name().contains(n.unwrap())
+            config.favorite_textures.toggle(&texture);
         }
 
-        #[inline]
-        #[must_use]
-        fn width_filter(
-            texture: &TextureMaterials,
-            _: Option<&str>,
-            w: Option<u32>,
-            _: Option<u32>
-        ) -> bool
+        if self
+            .animation_editor
+            .can_add_textures_to_list(&self.overall_texture.animation)
+        {
+            drawing_resources.set_texture_preview(None);
+
+            self.animation_editor.push_list_animation_frame(
+                drawing_resources,
+                manager,
+                edits_history,
+                grid,
+                &self.overall_texture,
+                return_if_none!(clicked_texture).as_str()
+            );
+
+            return;
+        }
+
+        if let Some(texture) = clicked_texture
         {
-            texture.texture().size().x == w.unwrap()
+            _ = Self::assign_texture(drawing_resources, manager, edits_history, grid, &texture);
         }
 
-        #[inline]
-        #[must_use]
-        fn height_filter(
-            texture: &TextureMaterials,
-            _: Option<&str>,
-            _: Option<u32>,
-            h: Option<u32>
-        ) -> bool
+        // Show the hovered texture on the selected brushes until the cursor leaves it.
+        drawing_resources.set_texture_preview(
+            hovered_texture.as_deref().filter(|_| manager.any_selected_brushes())
+        );
+    }
+
+    /// Draws the settings of the gallery: the folder filter, the favorites filter, and the sorting.
+    #[inline]
+    fn gallery_settings(&mut self, ui: &mut egui::Ui, drawing_resources: &DrawingResources)
+    {
+        let folders = drawing_resources.texture_folders();
+
+        if self
+            .folder_filter
+            .as_ref()
+            .is_some_and(|folder| !folders.contains(folder))
         {
-            texture.texture().size().y == h.unwrap()
+            self.folder_filter = None;
         }
 
-        macro_rules! filter_gen {
-            ($(($($f:ident),+)),+) => { paste::paste! {$(
-                #[inline]
-                #[must_use]
-                fn [< $($f)_+_filter >](
-                    texture: &TextureMaterials,
-                    n: Option<&str>,
-                    w: Option<u32>,
-                    h: Option<u32>
-                ) -> bool
+        ui.label("Folder");
+        ui.add_space(2f32);
+
+        egui::ComboBox::from_id_salt("texture_folder")
+            .selected_text(self.folder_filter.as_deref().unwrap_or("All"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut self.folder_filter, None, "All");
+
+                // Indent the subfolders to show the tree.
+                for folder in folders
                 {
-                    $([< $f _filter >](texture, n, w, h)) && +
+                    let (depth, name) = folder
+                        .rsplit_once('/')
+                        .map_or((0, folder.as_str()), |(parent, name)| {
+                            (parent.split('/').count(), name)
+                        });
+
+                    ui.selectable_value(
+                        &mut self.folder_filter,
+                        Some(folder.clone()),
+                        format!("{}{name}", "    ".repeat(depth))
+                    );
                 }
-            )+}};
-        }
+            });
+
+        ui.add_space(2f32);
+        ui.checkbox(&mut self.favorites_only, "Favorites only");
+        ui.add_space(2f32);
+        ui.label("Sort by");
+        ui.add_space(2f32);
 
-        filter_gen!((width, height), (name, width), (name, height), (name, width, height));
+        egui::ComboBox::from_id_salt("texture_sort")
+            .selected_text(self.texture_sort.tag())
+            .show_ui(ui, |ui| {
+                for sort in [TextureSort::Name, TextureSort::RecentlyUsed]
+                {
+                    ui.selectable_value(&mut self.texture_sort, sort, sort.tag());
+                }
+            });
+    }
 
+    /// Draws the strip of the most recently applied textures, which can be clicked like the ones
+    /// of the gallery.
+    #[inline]
+    fn recent_textures(&mut self, ui: &mut egui::Ui, bundle: &mut UiBundle)
+    {
         let UiBundle {
             drawing_resources,
             manager,
@@ -716,41 +1273,28 @@ impl Innards
             ..
         } = bundle;
 
-        let n_filter = (!self.name_filter.is_empty()).then_some(self.name_filter.as_str());
-        let w_filter = self.width_filter.value;
-        let h_filter = self.height_filter.value;
-
-        let filter = if n_filter.is_none() && h_filter.is_none() && w_filter.is_none()
+        if manager.recent_textures().is_empty()
         {
-            None
+            return;
         }
-        else
-        {
-            let filter = match (n_filter, w_filter, h_filter)
-            {
-                (None, None, None) => unreachable!(),
-                (None, None, Some(_)) => height_filter,
-                (None, Some(_), None) => width_filter,
-                (None, Some(_), Some(_)) => width_height_filter,
-                (Some(_), None, None) => name_filter,
-                (Some(_), None, Some(_)) => name_height_filter,
-                (Some(_), Some(_), None) => name_width_filter,
-                (Some(_), Some(_), Some(_)) => name_width_height_filter
-            };
-
-            (move |texture: &&TextureMaterials| filter(texture, n_filter, w_filter, h_filter))
-                .into()
-        };
 
-        let textures_per_row = texture_per_row(ui, TEXTURE_GALLERY_PREVIEW_FRAME_SIDE);
+        let mut clicked_texture = None;
 
-        if self
-            .animation_editor
-            .can_add_textures_to_list(&self.overall_texture.animation)
-        {
-            let mut clicked_texture = None;
+        ui.horizontal(|ui| {
+            ui.label("Recent");
+
+            for name in manager.recent_textures()
+            {
+                let texture = continue_if_none!(drawing_resources.texture(name));
+                let (egui_id, size, _) = drawing_resources.egui_texture(name);
+                let size = size.as_vec2();
+                let size = size * (RECENT_TEXTURE_PREVIEW_SIDE / size.max_element());
+
+                let response =
+                    ui.add(egui::ImageButton::new((egui_id, egui::vec2(size.x, size.y))));
+                response.image_button_name(true, || texture.label().to_owned());
+                let response = response.on_hover_text(texture.label());
 
-            gallery(ui, drawing_resources, textures_per_row, filter, |texture, response| {
                 if response.clicked()
                 {
                     clicked_texture = texture.name().to_owned().into();
@@ -759,36 +1303,28 @@ impl Innards
                 {
                     self.animation_editor.set_texture_override(texture);
                 }
-            });
+            }
+        });
 
+        let clicked_texture = return_if_none!(clicked_texture);
+
+        if self
+            .animation_editor
+            .can_add_textures_to_list(&self.overall_texture.animation)
+        {
             self.animation_editor.push_list_animation_frame(
                 drawing_resources,
                 manager,
                 edits_history,
                 grid,
                 &self.overall_texture,
-                return_if_none!(clicked_texture).as_str()
+                &clicked_texture
             );
 
             return;
         }
 
-        gallery(ui, drawing_resources, textures_per_row, filter, |texture, response| {
-            if response.clicked()
-            {
-                _ = Innards::assign_texture(
-                    drawing_resources,
-                    manager,
-                    edits_history,
-                    grid,
-                    texture.name()
-                );
-            }
-            else if response.secondary_clicked()
-            {
-                self.animation_editor.set_texture_override(texture);
-            }
-        });
+        _ = Self::assign_texture(drawing_resources, manager, edits_history, grid, &clicked_texture);
     }
 
     /// Shows the texture editor.
@@ -812,7 +1348,7 @@ impl Innards
         line_section(ui, |ui| self.mode_selector(ui, bundle.manager));
 
         ui.horizontal(|ui| {
-            ui.set_height(250f32);
+            ui.set_height(303f32);
 
             ui.vertical(|ui| {
                 self.selected_texture(ui, bundle);
@@ -870,6 +1406,22 @@ impl Innards
             });
         });
 
+        line_section(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = X_SPACING;
+                self.gallery_settings(ui, bundle.drawing_resources);
+            });
+        });
+
+        line_section(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = X_SPACING;
+                self.randomizer(ui, bundle);
+            });
+        });
+
+        self.recent_textures(ui, bundle);
+
         ui.vertical(|ui| {
             egui::ScrollArea::vertical().show(ui, |ui| self.textures_gallery(ui, bundle));
         });
@@ -922,14 +1474,14 @@ impl Innards
                             ..
                         } = bundle;
 
-                        if let Some(texture) = drawing_resources.texture(&value)
+                        if drawing_resources.texture(&value).is_some()
                         {
                             Self::assign_texture(
                                 drawing_resources,
                                 manager,
                                 edits_history,
                                 grid,
-                                texture.name()
+                                &value
                             )
                             .then_some(value);
                         }
@@ -999,6 +1551,59 @@ impl Innards
     }
 
     #[allow(unused_mut)]
+    /// Snaps the texture offsets of the selected brushes to the closest multiple of the grid size,
+    /// so that the textures tile from the world grid.
+    #[inline]
+    fn align_offset_to_grid(
+        drawing_resources: &DrawingResources,
+        manager: &mut EntitiesManager,
+        edits_history: &mut EditsHistory,
+        grid: &Grid
+    ) -> bool
+    {
+        let size = grid.size_f32();
+        let snap = |value: f32| (value / size).round() * size;
+
+        let valid = manager.test_operation_validity(|manager| {
+            manager
+                .selected_textured_brushes_mut(drawing_resources, grid)
+                .find_map(|mut brush| {
+                    let settings = brush.texture_settings().unwrap();
+                    let (x, y) = (snap(settings.offset_x()), snap(settings.offset_y()));
+
+                    (!brush.check_texture_offset_x(drawing_resources, grid, x) ||
+                        !brush.check_texture_offset_y(drawing_resources, grid, y))
+                    .then_some(brush.id())
+                })
+        });
+
+        if !valid
+        {
+            return false;
+        }
+
+        edits_history.texture_offset_x_cluster(
+            manager
+                .selected_textured_brushes_mut(drawing_resources, grid)
+                .filter_map(|mut brush| {
+                    let value = snap(brush.texture_settings().unwrap().offset_x());
+                    brush.set_texture_offset_x(value).map(|prev| (brush.id(), prev))
+                })
+        );
+
+        edits_history.texture_offset_y_cluster(
+            manager
+                .selected_textured_brushes_mut(drawing_resources, grid)
+                .filter_map(|mut brush| {
+                    let value = snap(brush.texture_settings().unwrap().offset_y());
+                    brush.set_texture_offset_y(value).map(|prev| (brush.id(), prev))
+                })
+        );
+
+        manager.schedule_outline_update();
+        true
+    }
+
     #[inline]
     fn angle_setter(
         drawing_resources: &DrawingResources,
@@ -1115,6 +1720,17 @@ impl TextureEditor
         }
         .ui();
 
+        let overall = &self.innards.overall_texture;
+
+        if let (Some(x), Some(y)) =
+            (overall.scale_x.uniform_value(), overall.scale_y.uniform_value())
+        {
+            if x.abs() == y.abs()
+            {
+                self.innards.uniform_scale = x.abs();
+            }
+        }
+
         self.innards
             .animation_editor
             .update_from_overall_texture(drawing_resources, &self.innards.overall_texture);
@@ -1133,7 +1749,7 @@ impl TextureEditor
     pub fn show(&mut self, egui_context: &egui::Context, bundle: &mut UiBundle) -> bool
     {
         /// The minimum texture editor size.
-        const WINDOW_MIN_SIZE: f32 = 742f32;
+        const WINDOW_MIN_SIZE: f32 = 770f32;
 
         if !self.window.check_open(
             !bundle.inputs.alt_pressed() &&
@@ -1141,10 +1757,14 @@ impl TextureEditor
                 Bind::TextureEditor.just_pressed(bundle.key_inputs, &bundle.config.binds)
         )
         {
+            bundle.drawing_resources.set_texture_preview(None);
             return false;
         }
 
-        self.window
+        let mut shown = false;
+
+        let focused = self
+            .window
             .show(
                 egui_context,
                 egui::Window::new("Texture Editor")
@@ -1153,9 +1773,17 @@ impl TextureEditor
                     .default_height(WINDOW_MIN_SIZE),
                 |ui| {
                     self.innards.show(ui, bundle);
+                    shown = true;
                 }
             )
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        if !shown
+        {
+            bundle.drawing_resources.set_texture_preview(None);
+        }
+
+        focused
     }
 }
 
@@ -1171,6 +1799,40 @@ const fn no_clamp(value: f32, _: f32) -> f32 { value }
 
 //=======================================================================//
 
+/// Whether the characters of `query` appear, case insensitively and in the same order, in the path
+/// of `texture` relative to the textures folder, i.e. `bric` and `wlbrk` both match
+/// `walls/brick`.
+#[inline]
+#[must_use]
+fn fuzzy_match(texture: &TextureMaterials, query: &str) -> bool
+{
+    let mut path = texture
+        .folder()
+        .chars()
+        .chain(std::iter::once('/'))
+        .chain(texture.texture().name().chars())
+        .flat_map(char::to_lowercase);
+
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| path.any(|p| p == c))
+}
+
+//=======================================================================//
+
+/// Whether `texture_folder` is `folder` or one of its subfolders.
+#[inline]
+#[must_use]
+fn in_folder(texture_folder: &str, folder: &str) -> bool
+{
+    texture_folder
+        .strip_prefix(folder)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+//=======================================================================//
+
 /// Shows a delete button and returns whether it was pressed.
 #[inline]
 #[must_use]
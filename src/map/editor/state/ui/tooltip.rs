@@ -76,7 +76,8 @@ impl Tooltip
             {
                 if bundle.elapsed_time >= time
                 {
-                    self.text = tool.tooltip_label(&bundle.config.binds);
+                    self.text =
+                        tool.localized_tooltip_label(&bundle.config.binds, &bundle.config.locale);
                     self.spawn_time = None;
                 }
             }
@@ -0,0 +1,168 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use bevy_egui::egui;
+
+use super::{window::Window, Command, UiBundle, WindowCloser, WindowCloserInfo};
+use crate::{
+    map::editor::state::validation::{ProblemKind, Severity, ValidationReport},
+    utils::misc::Toggle
+};
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The window listing the problems found by the map checks.
+#[derive(Default)]
+pub(in crate::map::editor::state::ui) struct ValidationWindow
+{
+    /// The window.
+    window: Window,
+    /// The shown report.
+    report: ValidationReport
+}
+
+impl Toggle for ValidationWindow
+{
+    #[inline]
+    fn toggle(&mut self) { self.window.toggle() }
+}
+
+impl WindowCloserInfo for ValidationWindow
+{
+    #[inline]
+    fn window_closer(&self) -> Option<WindowCloser>
+    {
+        /// Calls the close function.
+        #[inline]
+        fn close(window: &mut ValidationWindow) { window.window.close() }
+
+        self.window
+            .layer_id()
+            .map(|id| WindowCloser::Validation(id, close as fn(&mut Self)))
+    }
+}
+
+impl ValidationWindow
+{
+    /// Opens the window showing `report`.
+    #[inline]
+    pub fn open(&mut self, report: ValidationReport)
+    {
+        self.report = report;
+        self.window.open();
+    }
+
+    /// Shows the window, stores in `command` the request to go to the entity of the clicked
+    /// problem, or to run the checks again.
+    /// Returns whether the window is focused.
+    #[inline]
+    #[must_use]
+    pub fn show(
+        &mut self,
+        egui_context: &egui::Context,
+        bundle: &UiBundle,
+        enabled: bool,
+        command: &mut Command
+    ) -> bool
+    {
+        if !self.window.is_open()
+        {
+            return false;
+        }
+
+        let locale = &bundle.config.locale;
+        let report = &self.report;
+
+        self.window
+            .show(
+                egui_context,
+                egui::Window::new(locale.tr("Map check"))
+                    .id("validation_window".into())
+                    .collapsible(true)
+                    .resizable(false),
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(format!(
+                            "{} {}, {} {}",
+                            locale.tr("Errors"),
+                            report.count(Severity::Error),
+                            locale.tr("Warnings"),
+                            report.count(Severity::Warning)
+                        ));
+
+                        if ui
+                            .add_enabled(enabled, egui::Button::new(locale.tr("Check")))
+                            .clicked()
+                        {
+                            *command = Command::CheckMap;
+                        }
+                    });
+
+                    ui.separator();
+
+                    if report.is_empty()
+                    {
+                        ui.label(locale.tr("No problems found"));
+                        return;
+                    }
+
+                    egui::ScrollArea::vertical().max_height(320f32).show(ui, |ui| {
+                        ui.add_enabled_ui(enabled, |ui| {
+                            for problem in report.iter()
+                            {
+                                let description = match &problem.kind
+                                {
+                                    ProblemKind::DegenerateBrush =>
+                                    {
+                                        locale.tr("Degenerate brush").to_owned()
+                                    },
+                                    ProblemKind::MissingTexture(name) =>
+                                    {
+                                        format!("{} {name}", locale.tr("Missing texture"))
+                                    },
+                                    ProblemKind::UnknownThing(thing) =>
+                                    {
+                                        format!("{} {}", locale.tr("Unknown thing"), thing.value())
+                                    },
+                                    ProblemKind::Overlap(other) =>
+                                    {
+                                        format!("{} {}", locale.tr("Overlaps"), other.value())
+                                    },
+                                    ProblemKind::InvalidProperty { key, error, .. } =>
+                                    {
+                                        format!("{} {key}: {error}", locale.tr("Invalid property"))
+                                    }
+                                };
+
+                                let color = match problem.kind.severity()
+                                {
+                                    Severity::Error => ui.visuals().error_fg_color,
+                                    Severity::Warning => ui.visuals().warn_fg_color
+                                };
+
+                                if ui
+                                    .selectable_label(
+                                        false,
+                                        egui::RichText::new(format!(
+                                            "{}: {description}",
+                                            problem.id.value()
+                                        ))
+                                        .color(color)
+                                    )
+                                    .clicked()
+                                {
+                                    *command = Command::GoToEntity(problem.id);
+                                }
+                            }
+                        });
+                    });
+                }
+            )
+            .unwrap_or_default()
+    }
+}
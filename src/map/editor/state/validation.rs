@@ -0,0 +1,272 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use super::manager::EntitiesManager;
+use crate::{
+    map::{
+        drawer::drawing_resources::DrawingResources,
+        editor::AllDefaultProperties,
+        properties::Properties,
+        thing::{catalog::ThingsCatalog, ThingId, ThingInterface}
+    },
+    utils::identifiers::EntityId,
+    Id,
+    PropertySchemas,
+    TextureInterface
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The area below which a brush is considered degenerate.
+const DEGENERATE_AREA: f32 = 1f32;
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// How serious a [`Problem`] is.
+#[must_use]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(in crate::map::editor::state) enum Severity
+{
+    /// Prevents the export of the map.
+    Error,
+    /// The map can be exported after confirmation.
+    Warning
+}
+
+//=======================================================================//
+
+/// The kind of a [`Problem`].
+#[must_use]
+#[derive(Clone)]
+pub(in crate::map::editor::state) enum ProblemKind
+{
+    /// The brush has a negligible area.
+    DegenerateBrush,
+    /// The brush has a texture that is not loaded.
+    MissingTexture(String),
+    /// The thing has an id that is not in the catalog.
+    UnknownThing(ThingId),
+    /// The collision of the brush overlaps the one of the brush with the [`Id`].
+    Overlap(Id),
+    /// The value of a property does not satisfy its schema.
+    InvalidProperty
+    {
+        /// The key of the property.
+        key:     &'static str,
+        /// The description of the unsatisfied constraint.
+        error:   String,
+        /// Whether the schema is lenient.
+        lenient: bool
+    }
+}
+
+impl ProblemKind
+{
+    /// Returns the [`Severity`] of `self`.
+    #[inline]
+    pub const fn severity(&self) -> Severity
+    {
+        match self
+        {
+            Self::DegenerateBrush | Self::MissingTexture(_) | Self::UnknownThing(_) =>
+            {
+                Severity::Error
+            },
+            Self::Overlap(_) => Severity::Warning,
+            Self::InvalidProperty { lenient, .. } =>
+            {
+                if *lenient
+                {
+                    Severity::Warning
+                }
+                else
+                {
+                    Severity::Error
+                }
+            }
+        }
+    }
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A problem of an entity of the map.
+#[must_use]
+#[derive(Clone)]
+pub(in crate::map::editor::state) struct Problem
+{
+    /// The [`Id`] of the entity.
+    pub id:   Id,
+    /// The kind of problem.
+    pub kind: ProblemKind
+}
+
+//=======================================================================//
+
+/// The problems found by the checks run on the map before it is exported.
+#[must_use]
+#[derive(Clone, Default)]
+pub(in crate::map::editor::state) struct ValidationReport
+{
+    /// The problems, sorted by [`Severity`] and [`Id`].
+    problems: Vec<Problem>
+}
+
+impl ValidationReport
+{
+    /// Runs the checks on the entities of `manager`. Overlapping brushes with collision are only
+    /// reported if `check_overlaps` is true.
+    #[inline]
+    pub fn new(
+        manager: &EntitiesManager,
+        drawing_resources: &DrawingResources,
+        things_catalog: &ThingsCatalog,
+        default_properties: &AllDefaultProperties,
+        property_schemas: &PropertySchemas,
+        check_overlaps: bool
+    ) -> Self
+    {
+        let mut problems = Vec::new();
+        let brushes = manager.brushes();
+
+        for brush in brushes.iter()
+        {
+            let id = brush.id();
+            let properties = brush.properties_as_ref();
+
+            property_schemas.check_brush(
+                |key| {
+                    default_properties
+                        .map_brushes
+                        .contains(key)
+                        .then(|| properties.get(key))
+                },
+                |key, schema, error| {
+                    problems.push(Problem {
+                        id,
+                        kind: ProblemKind::InvalidProperty {
+                            key,
+                            error,
+                            lenient: schema.is_lenient()
+                        }
+                    });
+                }
+            );
+
+            if brush.area() < DEGENERATE_AREA
+            {
+                problems.push(Problem {
+                    id,
+                    kind: ProblemKind::DegenerateBrush
+                });
+            }
+
+            if let Some(texture) = brush.texture_settings()
+            {
+                if drawing_resources.texture(texture.name()).is_none()
+                {
+                    problems.push(Problem {
+                        id,
+                        kind: ProblemKind::MissingTexture(texture.name().to_owned())
+                    });
+                }
+            }
+
+            if !check_overlaps || !brush.collision()
+            {
+                continue;
+            }
+
+            for other in &manager.brushes_in_range(&brush.polygon_hull())
+            {
+                // Each pair is only checked once.
+                if other.value() <= id.value()
+                {
+                    continue;
+                }
+
+                let other = brushes.get(*other);
+
+                if other.collision() && brush.intersection(other).is_some()
+                {
+                    problems.push(Problem {
+                        id,
+                        kind: ProblemKind::Overlap(other.id())
+                    });
+                }
+            }
+        }
+
+        for thing in manager.things()
+        {
+            let properties = thing.properties();
+
+            property_schemas.check_thing(
+                |key| {
+                    default_properties
+                        .map_things
+                        .contains(key)
+                        .then(|| properties.get(key))
+                },
+                |key, schema, error| {
+                    problems.push(Problem {
+                        id:   thing.id(),
+                        kind: ProblemKind::InvalidProperty {
+                            key,
+                            error,
+                            lenient: schema.is_lenient()
+                        }
+                    });
+                }
+            );
+
+            if things_catalog.thing(thing.thing_id()).is_none()
+            {
+                problems.push(Problem {
+                    id:   thing.id(),
+                    kind: ProblemKind::UnknownThing(thing.thing_id())
+                });
+            }
+        }
+
+        problems.sort_by(|a, b| {
+            a.kind
+                .severity()
+                .cmp(&b.kind.severity())
+                .then(a.id.value().cmp(&b.id.value()))
+        });
+
+        Self { problems }
+    }
+
+    /// Whether no problems were found.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.problems.is_empty() }
+
+    /// Returns the amount of problems with [`Severity`] `severity`.
+    #[inline]
+    #[must_use]
+    pub fn count(&self, severity: Severity) -> usize
+    {
+        self.problems
+            .iter()
+            .filter(|problem| problem.kind.severity() == severity)
+            .count()
+    }
+
+    /// Returns an iterator to the problems.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Problem> { self.problems.iter() }
+}
@@ -1,4 +1,5 @@
 pub mod brush;
+pub mod builder;
 #[cfg(feature = "ui")]
 mod camera;
 pub mod drawer;
@@ -6,6 +7,8 @@ pub mod drawer;
 pub mod editor;
 pub mod path;
 pub mod properties;
+#[cfg(feature = "ui")]
+mod quake;
 mod selectable_vector;
 pub mod thing;
 
@@ -14,8 +17,16 @@ pub mod thing;
 //
 //=======================================================================//
 
-use std::{fs::File, io::BufReader, path::PathBuf};
+use std::{
+    fmt::Write as FmtWrite,
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    ops::Range,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH}
+};
 
+use glam::{UVec2, Vec2};
 use hill_vacuum_proc_macros::EnumIter;
 use hill_vacuum_shared::{continue_if_none, return_if_none, NextValue};
 use properties::DefaultPropertiesViewer;
@@ -23,14 +34,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     utils::{
-        collections::{hash_map, HashMap},
+        collections::{hash_map, HashMap, Ids},
+        math::points::rotate_point_around_origin,
         misc::AssertedInsertRemove
     },
     Id,
-    TextureInterface
+    PropertySchemas,
+    PropertyViolation,
+    Rgba,
+    TextureInterface,
+    Value
 };
 #[allow(unused_imports)]
-use crate::{Brush, ThingInstance};
+use crate::{Brush, ThingId, ThingInstance};
 
 //=======================================================================//
 // CONSTANTS
@@ -38,7 +54,31 @@ use crate::{Brush, ThingInstance};
 //=======================================================================//
 
 /// The version of the saved files.
-const FILE_VERSION: &str = "0.10";
+const FILE_VERSION: &str = "0.11";
+/// The version of the saved files preceding [`FILE_VERSION`], which can still be read because it
+/// only lacks data that has a default: the [`MapMetadata`], the quick props, the recently used
+/// textures, and the statistics.
+const PREVIOUS_FILE_VERSION: &str = "0.10";
+/// The extension of the manifest files written along the exported maps.
+const MANIFEST_EXTENSION: &str = "manifest";
+/// The magic number at the start of the tessellated meshes files.
+const MESHES_MAGIC: &[u8; 4] = b"HVMS";
+/// The version of the tessellated meshes files.
+const MESHES_VERSION: u32 = 1;
+/// The extension of the binary buffer files written along the glTF files.
+const GLTF_BUFFER_EXTENSION: &str = "bin";
+/// The glTF buffer view target of the vertex attributes.
+const GLTF_ARRAY_BUFFER: u32 = 34962;
+/// The glTF buffer view target of the indexes.
+const GLTF_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+/// The glTF component type of the [`f32`]s.
+const GLTF_FLOAT: u32 = 5126;
+/// The glTF component type of the [`u32`]s.
+const GLTF_UNSIGNED_INT: u32 = 5125;
+/// The version of the TMX format of the Tiled maps.
+const TMX_VERSION: &str = "1.10";
+/// The size of half of the map square.
+pub(in crate::map) const MAP_HALF_SIZE: f32 = 16384f32;
 
 //=======================================================================//
 // ENUMS
@@ -56,7 +96,10 @@ enum FileStructure
     Properties,
     Brushes,
     Things,
-    Props
+    Metadata,
+    Props,
+    RecentTextures,
+    Statistics
 }
 
 impl FileStructure
@@ -126,13 +169,49 @@ impl GridSettings
 struct MapHeader
 {
     /// The amount of brushes.
-    pub brushes:    usize,
+    pub brushes:     usize,
     /// The amount of things.
-    pub things:     usize,
+    pub things:      usize,
     /// The amount of animations.
-    pub animations: usize,
+    pub animations:  usize,
     /// The amount of props.
-    pub props:      usize
+    pub props:       usize,
+    /// The amount of non empty quick props.
+    #[serde(default)]
+    pub quick_props: usize
+}
+
+//=======================================================================//
+
+/// The map-level information stored in the map files, such as its title and author, along with
+/// arbitrary key/value pairs which can be read by the game loaders.
+#[must_use]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MapMetadata
+{
+    /// The title of the map.
+    pub title:       String,
+    /// The author of the map.
+    pub author:      String,
+    /// The description of the map.
+    pub description: String,
+    /// The user defined key/value pairs.
+    pub custom:      HashMap<String, String>
+}
+
+impl MapMetadata
+{
+    /// Reads the [`MapMetadata`] from `file`, written with the version `version`.
+    #[inline]
+    fn from_file(file: &mut BufReader<File>, version: &str) -> Result<Self, &'static str>
+    {
+        if version == PREVIOUS_FILE_VERSION
+        {
+            return Ok(Self::default());
+        }
+
+        ciborium::from_reader(file).map_err(|_| "Error reading map metadata.")
+    }
 }
 
 //=======================================================================//
@@ -150,6 +229,8 @@ pub struct Exporter
     pub grid_angle: i16,
     /// The skew angle of the grid.
     pub grid_skew:  i8,
+    /// The title, author, description, and user defined key/value pairs of the map.
+    pub metadata:   MapMetadata,
     /// The [`Brush`]es inside the map.
     pub brushes:    HashMap<Id, crate::Brush>,
     /// The [`ThingInstance`]s inside the map.
@@ -159,11 +240,69 @@ pub struct Exporter
 impl Exporter
 {
     /// Returns a new [`Exporter`] generated from the requested `path`, unless there was an error.
+    /// The editor only outline colors of the entities are discarded.
+    /// # Errors
+    /// Returns an error if there was an issue reading the requested file.
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, &'static str> { Self::read(path, false) }
+
+    /// Returns a new [`Exporter`] generated from the requested `path`, unless there was an error.
+    /// The editor only outline colors of the entities are retained.
     /// # Errors
     /// Returns an error if there was an issue reading the requested file.
+    #[inline]
+    pub fn with_colors(path: impl Into<PathBuf>) -> Result<Self, &'static str>
+    {
+        Self::read(path, true)
+    }
+
+    /// Returns the properties of the brushes and things whose values do not satisfy their schema
+    /// in `schemas`, sorted by entity [`Id`].
+    #[inline]
+    #[must_use]
+    pub fn validate(&self, schemas: &PropertySchemas) -> Vec<PropertyViolation>
+    {
+        let mut violations = Vec::new();
+
+        for brush in self.brushes.values()
+        {
+            schemas.check_brush(
+                |key| brush.properties.get(key),
+                |key, schema, error| {
+                    violations.push(PropertyViolation {
+                        id: brush.id,
+                        key,
+                        error,
+                        lenient: schema.is_lenient()
+                    });
+                }
+            );
+        }
+
+        for thing in self.things.values()
+        {
+            schemas.check_thing(
+                |key| thing.properties.get(key),
+                |key, schema, error| {
+                    violations.push(PropertyViolation {
+                        id: thing.id,
+                        key,
+                        error,
+                        lenient: schema.is_lenient()
+                    });
+                }
+            );
+        }
+
+        violations.sort_by_key(|violation| violation.id.value());
+        violations
+    }
+
+    /// Returns a new [`Exporter`] generated from the requested `path`, unless there was an error.
+    /// The outline colors of the entities are retained if `colors` is true.
     #[allow(clippy::missing_panics_doc)]
     #[inline]
-    pub fn new(path: impl Into<PathBuf>) -> Result<Self, &'static str>
+    fn read(path: impl Into<PathBuf>, colors: bool) -> Result<Self, &'static str>
     {
         let file =
             File::open(Into::<PathBuf>::into(path)).map_err(|_| "Could not open the file")?;
@@ -174,7 +313,9 @@ impl Exporter
         // Version.
         steps.next_value().assert(FileStructure::Version);
 
-        if version_number(&mut file)? != FILE_VERSION
+        let version = version_number(&mut file)?;
+
+        if version != FILE_VERSION && version != PREVIOUS_FILE_VERSION
         {
             return Err("Cannot export previous map versions, save the file to upgrade it to the \
                         latest version.");
@@ -247,6 +388,39 @@ impl Exporter
             things.asserted_insert((thing.id, thing));
         }
 
+        // Metadata.
+        steps.next_value().assert(FileStructure::Metadata);
+        let metadata = MapMetadata::from_file(&mut file, &version)?;
+
+        // Editor only brushes are not exported.
+        let editor_only = brushes
+            .iter()
+            .filter_map(|brush| brush.editor_only.then_some(brush.id))
+            .collect::<Ids>();
+
+        if !editor_only.is_empty()
+        {
+            brushes.retain(|brush| !brush.editor_only);
+
+            for brush in &mut brushes
+            {
+                brush.group.remove_brushes(&editor_only);
+            }
+        }
+
+        if !colors
+        {
+            for brush in &mut brushes
+            {
+                brush.color = None;
+            }
+
+            for thing in things.values_mut()
+            {
+                thing.color = None;
+            }
+        }
+
         let mut brushes_map = hash_map![];
 
         for brush in brushes
@@ -257,10 +431,912 @@ impl Exporter
         Ok(Self {
             grid_angle: grid_settings.angle(),
             grid_skew: grid_settings.skew(),
+            metadata,
             brushes: brushes_map,
             things
         })
     }
+
+    /// Writes the map to a glTF 2.0 file at `path`, with the binary buffer stored in a file with
+    /// the same name and the `.bin` extension.
+    /// Each texture batch of the [`TessellatedMap`] of the map is exported as a mesh placed on the
+    /// XY plane, with a material named after the texture and the `extras` listing the [`Id`],
+    /// vertexes and indexes ranges, texture opacity percentage, properties, and path of the
+    /// brushes it contains.
+    /// The UV coordinates are in texels, so they must be divided by the size of the texture.
+    /// Each [`ThingInstance`] is exported as a node with its position as translation, and its
+    /// [`Id`], [`ThingId`], spawn flags, properties, and path stored in the `extras`.
+    /// The [`MapMetadata`] is stored in the `extras` of the scene.
+    /// # Errors
+    /// Returns an error if there was an issue writing the files.
+    #[inline]
+    pub fn save_gltf(&self, path: impl AsRef<Path>) -> Result<(), &'static str>
+    {
+        /// Appends `bytes` to `buffer` and pushes the buffer view containing them in `views`.
+        /// Returns the index of the buffer view.
+        #[inline]
+        fn push_buffer_view(
+            buffer: &mut Vec<u8>,
+            views: &mut Vec<String>,
+            target: u32,
+            bytes: impl Iterator<Item = u8>
+        ) -> usize
+        {
+            let offset = buffer.len();
+            buffer.extend(bytes);
+            views.push(format!(
+                r#"{{"buffer":0,"byteOffset":{offset},"byteLength":{},"target":{target}}}"#,
+                buffer.len() - offset
+            ));
+            views.len() - 1
+        }
+
+        let path = path.as_ref();
+        let bin_path = path.with_extension(GLTF_BUFFER_EXTENSION);
+        let bin_name = bin_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or("Invalid glTF file name")?;
+
+        let tessellation = TessellatedMap::new(self);
+        let mut buffer = Vec::<u8>::new();
+        let mut views = Vec::new();
+        let mut accessors = Vec::new();
+        let mut materials = Vec::new();
+        let mut meshes = Vec::new();
+        let mut nodes = Vec::new();
+
+        for (i, batch) in tessellation.batches.iter().enumerate()
+        {
+            let (min, max) = batch
+                .vertexes
+                .iter()
+                .fold((Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)), |(min, max), vx| {
+                    (min.min(*vx), max.max(*vx))
+                });
+
+            let view = push_buffer_view(
+                &mut buffer,
+                &mut views,
+                GLTF_ARRAY_BUFFER,
+                batch
+                    .vertexes
+                    .iter()
+                    .flat_map(|vx| [vx.x, vx.y, 0f32])
+                    .flat_map(f32::to_le_bytes)
+            );
+            accessors.push(format!(
+                r#"{{"bufferView":{view},"componentType":{GLTF_FLOAT},"count":{},"type":"VEC3","min":[{},{},0],"max":[{},{},0]}}"#,
+                batch.vertexes.len(),
+                min.x,
+                min.y,
+                max.x,
+                max.y
+            ));
+
+            let view = push_buffer_view(
+                &mut buffer,
+                &mut views,
+                GLTF_ARRAY_BUFFER,
+                batch
+                    .uvs
+                    .iter()
+                    .flat_map(|uv| [uv.x, uv.y])
+                    .flat_map(f32::to_le_bytes)
+            );
+            accessors.push(format!(
+                r#"{{"bufferView":{view},"componentType":{GLTF_FLOAT},"count":{},"type":"VEC2"}}"#,
+                batch.uvs.len()
+            ));
+
+            let view = push_buffer_view(
+                &mut buffer,
+                &mut views,
+                GLTF_ELEMENT_ARRAY_BUFFER,
+                batch.indexes.iter().flat_map(|idx| idx.to_le_bytes())
+            );
+            accessors.push(format!(
+                r#"{{"bufferView":{view},"componentType":{GLTF_UNSIGNED_INT},"count":{},"type":"SCALAR"}}"#,
+                batch.indexes.len()
+            ));
+
+            let accessor = accessors.len() - 3;
+            let mut primitive = format!(
+                r#"{{"attributes":{{"POSITION":{},"TEXCOORD_0":{}}},"indices":{}"#,
+                accessor,
+                accessor + 1,
+                accessor + 2
+            );
+
+            let name = match &batch.texture
+            {
+                Some(texture) =>
+                {
+                    primitive.push_str(&format!(r#","material":{}}}"#, materials.len()));
+                    materials.push(format!(r#"{{"name":{}}}"#, json_string(texture)));
+                    json_string(texture)
+                },
+                None =>
+                {
+                    primitive.push('}');
+                    json_string("untextured")
+                }
+            };
+
+            let brushes = tessellation
+                .brushes
+                .iter()
+                .filter(|mesh| mesh.batch == i)
+                .map(|mesh| {
+                    let brush = &self.brushes[&mesh.id];
+                    let mut extras = format!(
+                        r#"{{"id":{},"vertexes":[{},{}],"indexes":[{},{}],"properties":{},"custom_properties":{}"#,
+                        mesh.id.value(),
+                        mesh.vertexes.start,
+                        mesh.vertexes.len(),
+                        mesh.indexes.start,
+                        mesh.indexes.len(),
+                        json_properties(&brush.properties),
+                        json_properties(brush.custom_properties.iter().map(|(k, v)| (k, v)))
+                    );
+
+                    if let Some(texture) = &brush.texture
+                    {
+                        extras.push_str(&format!(r#","opacity":{}"#, texture.opacity()));
+                    }
+
+                    if let crate::Group::Path { path, .. } = &brush.group
+                    {
+                        extras.push_str(&format!(r#","path":{}"#, json_path(path)));
+                    }
+
+                    extras.push('}');
+                    extras
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            meshes.push(format!(
+                r#"{{"name":{name},"primitives":[{primitive}],"extras":{{"brushes":[{brushes}]}}}}"#
+            ));
+            nodes.push(format!(r#"{{"name":{name},"mesh":{i}}}"#));
+        }
+
+        let mut things = self.things.values().collect::<Vec<_>>();
+        things.sort_unstable_by_key(|thing| thing.id.value());
+
+        for thing in things
+        {
+            let mut extras = format!(
+                r#"{{"id":{},"thing_id":{},"flags":{},"properties":{},"custom_properties":{}"#,
+                thing.id.value(),
+                thing.thing_id.value(),
+                thing.flags,
+                json_properties(&thing.properties),
+                json_properties(thing.custom_properties.iter().map(|(k, v)| (k, v)))
+            );
+
+            if let Some(path) = &thing.path
+            {
+                extras.push_str(&format!(r#","path":{}"#, json_path(path)));
+            }
+
+            nodes.push(format!(
+                r#"{{"name":"thing_{}","translation":[{},{},0],"extras":{extras}}}}}"#,
+                thing.id.value(),
+                thing.pos.x,
+                thing.pos.y
+            ));
+        }
+
+        let mut custom = self.metadata.custom.iter().collect::<Vec<_>>();
+        custom.sort_unstable_by_key(|(key, _)| *key);
+        let custom = custom
+            .into_iter()
+            .map(|(key, value)| format!("{}:{}", json_string(key), json_string(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let json = format!(
+            r#"{{"asset":{{"version":"2.0","generator":"HillVacuum"}},"scene":0,"scenes":[{{"name":{},"nodes":[{}],"extras":{{"author":{},"description":{},"custom":{{{custom}}}}}}}],"nodes":[{}],"meshes":[{}],"materials":[{}],"accessors":[{}],"bufferViews":[{}],"buffers":[{{"byteLength":{},"uri":{}}}]}}"#,
+            json_string(&self.metadata.title),
+            (0..nodes.len()).map(|i| i.to_string()).collect::<Vec<_>>().join(","),
+            json_string(&self.metadata.author),
+            json_string(&self.metadata.description),
+            nodes.join(","),
+            meshes.join(","),
+            materials.join(","),
+            accessors.join(","),
+            views.join(","),
+            buffer.len(),
+            json_string(&uri_encode(bin_name))
+        );
+
+        std::fs::write(&bin_path, buffer).map_err(|_| "Error writing the glTF buffer file")?;
+        std::fs::write(path, json).map_err(|_| "Error writing the glTF file")
+    }
+
+    /// Writes the map to a Tiled `.tmx` file at `path`, with tiles of size `tile_size`.
+    /// The textures used by the brushes and things which are listed in `textures` are stored in
+    /// an image collection tileset embedded in the map. `thing_texture` returns the name of the
+    /// texture representing a [`ThingInstance`], if any.
+    /// The brushes are exported as polygon objects in the `brushes` object layer, with their
+    /// [`Id`], texture settings, properties, and path stored as custom properties.
+    /// The things are exported in the `things` object layer, as tile objects if their texture is
+    /// in the tileset, otherwise as point objects, with their [`Id`], [`ThingId`], spawn flags,
+    /// properties, and path stored as custom properties.
+    /// Since the Y axis of Tiled points down and the coordinates of the objects should be
+    /// positive, the map is flipped vertically and moved so that the top left corner of its
+    /// bounding box is the origin. The position of the origin in the map coordinates is stored in
+    /// the `origin_x` and `origin_y` properties of the map, along with the [`MapMetadata`].
+    /// # Errors
+    /// Returns an error if there was an issue writing the file.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[inline]
+    pub fn save_tmx<'a>(
+        &self,
+        path: impl AsRef<Path>,
+        tile_size: u16,
+        textures: &[TmxTexture],
+        thing_texture: impl Fn(ThingId) -> Option<&'a str>
+    ) -> Result<(), &'static str>
+    {
+        /// Writes the `<properties>` element containing `properties` into `xml`, indented by
+        /// `indent` spaces.
+        #[inline]
+        fn write_properties<'a>(
+            xml: &mut String,
+            indent: usize,
+            properties: impl IntoIterator<Item = (&'a str, &'static str, String)>
+        )
+        {
+            let mut properties = properties.into_iter().peekable();
+
+            if properties.peek().is_none()
+            {
+                return;
+            }
+
+            _ = writeln!(xml, "{:indent$}<properties>", "");
+
+            for (name, ty, value) in properties
+            {
+                _ = writeln!(
+                    xml,
+                    r#"{:indent$} <property name="{}" type="{ty}" value="{}"/>"#,
+                    "",
+                    xml_escape(name),
+                    xml_escape(&value)
+                );
+            }
+
+            _ = writeln!(xml, "{:indent$}</properties>", "");
+        }
+
+        /// Returns the properties of an entity, sorted alphabetically, as Tiled custom properties.
+        #[inline]
+        fn entity_properties<'a>(
+            properties: impl IntoIterator<Item = (&'a String, &'a Value)>
+        ) -> impl Iterator<Item = (&'a str, &'static str, String)>
+        {
+            let mut properties = properties.into_iter().collect::<Vec<_>>();
+            properties.sort_unstable_by_key(|(key, _)| *key);
+
+            properties.into_iter().map(|(key, value)| {
+                let ty = match value
+                {
+                    Value::Bool(_) => "bool",
+                    Value::U8(_) | Value::U16(_) | Value::I8(_) | Value::I16(_) | Value::I32(_) =>
+                    {
+                        "int"
+                    },
+                    Value::F32(_) | Value::F64(_) => "float",
+                    // Tiled colors are written as #AARRGGBB.
+                    Value::Color(Rgba([r, g, b, a])) =>
+                    {
+                        return (key.as_str(), "color", format!("#{a:02x}{r:02x}{g:02x}{b:02x}"));
+                    },
+                    _ => "string"
+                };
+
+                (key.as_str(), ty, value.to_string())
+            })
+        }
+
+        // Tileset.
+        let mut used = self
+            .brushes
+            .values()
+            .filter_map(|brush| brush.texture.as_ref().map(TextureInterface::name))
+            .chain(self.things.values().filter_map(|thing| thing_texture(thing.thing_id)))
+            .collect::<Vec<_>>();
+        used.sort_unstable();
+        used.dedup();
+
+        let tiles = used
+            .into_iter()
+            .filter_map(|name| textures.iter().find(|texture| texture.name == name))
+            .collect::<Vec<_>>();
+        let gids = tiles
+            .iter()
+            .enumerate()
+            .map(|(i, texture)| (texture.name.as_str(), (i + 1, texture.size)))
+            .collect::<HashMap<_, _>>();
+
+        // Bounds.
+        let (min, max) = self
+            .brushes
+            .values()
+            .flat_map(|brush| brush.vertexes.iter().copied())
+            .chain(self.things.values().map(|thing| thing.pos))
+            .fold((Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)), |(min, max), vx| {
+                (min.min(vx), max.max(vx))
+            });
+        let (min, max) = if min.x > max.x { (Vec2::ZERO, Vec2::ZERO) } else { (min, max) };
+        let tiled = |pos: Vec2| Vec2::new(pos.x - min.x, max.y - pos.y);
+        let tile = f32::from(tile_size.max(1));
+        let mut next_id = 1;
+
+        let mut xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="{TMX_VERSION}" orientation="orthogonal" renderorder="right-down" width="{}" height="{}" tilewidth="{tile_size}" tileheight="{tile_size}" infinite="0" nextlayerid="3" nextobjectid="{}">
+"#,
+            ((max.x - min.x) / tile).ceil().max(1f32) as u32,
+            ((max.y - min.y) / tile).ceil().max(1f32) as u32,
+            self.brushes.len() + self.things.len() + 1
+        );
+
+        let mut custom = self.metadata.custom.iter().collect::<Vec<_>>();
+        custom.sort_unstable_by_key(|(key, _)| *key);
+
+        write_properties(
+            &mut xml,
+            1,
+            [
+                ("title", "string", self.metadata.title.clone()),
+                ("author", "string", self.metadata.author.clone()),
+                ("description", "string", self.metadata.description.clone()),
+                ("origin_x", "float", min.x.to_string()),
+                ("origin_y", "float", max.y.to_string())
+            ]
+            .into_iter()
+            .chain(
+                custom
+                    .into_iter()
+                    .map(|(key, value)| (key.as_str(), "string", value.clone()))
+            )
+        );
+
+        if !tiles.is_empty()
+        {
+            let tile_max = tiles.iter().fold(UVec2::ONE, |size, texture| size.max(texture.size));
+
+            _ = writeln!(
+                xml,
+                r#" <tileset firstgid="1" name="textures" tilewidth="{}" tileheight="{}" tilecount="{}" columns="0">"#,
+                tile_max.x,
+                tile_max.y,
+                tiles.len()
+            );
+            xml.push_str("  <grid orientation=\"orthogonal\" width=\"1\" height=\"1\"/>\n");
+
+            for (i, texture) in tiles.iter().enumerate()
+            {
+                _ = writeln!(
+                    xml,
+                    r#"  <tile id="{i}" type="{}">
+   <image width="{}" height="{}" source="{}"/>
+  </tile>"#,
+                    xml_escape(&texture.name),
+                    texture.size.x,
+                    texture.size.y,
+                    xml_escape(&texture.source.to_string_lossy())
+                );
+            }
+
+            xml.push_str(" </tileset>\n");
+        }
+
+        // Brushes.
+        let mut brushes = self.brushes.values().collect::<Vec<_>>();
+        brushes.sort_unstable_by_key(|brush| brush.id.value());
+
+        xml.push_str(" <objectgroup id=\"1\" name=\"brushes\">\n");
+
+        for brush in brushes
+        {
+            let origin = tiled(brush.vertexes[0]);
+
+            _ = writeln!(
+                xml,
+                r#"  <object id="{next_id}" name="brush_{}" type="brush" x="{}" y="{}">"#,
+                brush.id.value(),
+                origin.x,
+                origin.y
+            );
+            next_id += 1;
+
+            let mut properties = vec![("id", "int", brush.id.value().to_string())];
+
+            if let Some(texture) = &brush.texture
+            {
+                properties.extend([
+                    ("texture", "string", texture.name().to_owned()),
+                    ("texture_offset_x", "float", texture.offset_x().to_string()),
+                    ("texture_offset_y", "float", texture.offset_y().to_string()),
+                    ("texture_scale_x", "float", texture.scale_x().to_string()),
+                    ("texture_scale_y", "float", texture.scale_y().to_string()),
+                    ("texture_angle", "float", texture.angle().to_string()),
+                    ("texture_opacity", "int", texture.opacity().to_string())
+                ]);
+            }
+
+            if let crate::Group::Path { path, .. } = &brush.group
+            {
+                properties.push(("path", "string", json_path(path)));
+            }
+
+            write_properties(
+                &mut xml,
+                3,
+                properties
+                    .into_iter()
+                    .chain(entity_properties(&brush.properties))
+                    .chain(entity_properties(brush.custom_properties.iter().map(|(k, v)| (k, v))))
+            );
+
+            _ = writeln!(
+                xml,
+                r#"   <polygon points="{}"/>
+  </object>"#,
+                brush
+                    .vertexes
+                    .iter()
+                    .map(|vx| {
+                        let vx = tiled(*vx) - origin;
+                        format!("{},{}", vx.x, vx.y)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+
+        xml.push_str(" </objectgroup>\n");
+
+        // Things.
+        let mut things = self.things.values().collect::<Vec<_>>();
+        things.sort_unstable_by_key(|thing| thing.id.value());
+
+        xml.push_str(" <objectgroup id=\"2\" name=\"things\">\n");
+
+        for thing in things
+        {
+            let pos = tiled(thing.pos);
+            let image = thing_texture(thing.thing_id).and_then(|texture| gids.get(texture));
+
+            match image
+            {
+                Some((gid, size)) =>
+                {
+                    // Tile objects are anchored to their bottom left corner.
+                    let size = size.as_vec2();
+
+                    _ = writeln!(
+                        xml,
+                        r#"  <object id="{next_id}" name="thing_{}" type="thing" gid="{gid}" x="{}" y="{}" width="{}" height="{}">"#,
+                        thing.id.value(),
+                        pos.x - size.x / 2f32,
+                        pos.y + size.y / 2f32,
+                        size.x,
+                        size.y
+                    );
+                },
+                None =>
+                {
+                    _ = writeln!(
+                        xml,
+                        r#"  <object id="{next_id}" name="thing_{}" type="thing" x="{}" y="{}">"#,
+                        thing.id.value(),
+                        pos.x,
+                        pos.y
+                    );
+                }
+            };
+
+            next_id += 1;
+
+            let mut properties = vec![
+                ("id", "int", thing.id.value().to_string()),
+                ("thing_id", "int", thing.thing_id.value().to_string()),
+                ("flags", "int", thing.flags.to_string()),
+            ];
+
+            if let Some(path) = &thing.path
+            {
+                properties.push(("path", "string", json_path(path)));
+            }
+
+            write_properties(
+                &mut xml,
+                3,
+                properties
+                    .into_iter()
+                    .chain(entity_properties(&thing.properties))
+                    .chain(entity_properties(thing.custom_properties.iter().map(|(k, v)| (k, v))))
+            );
+
+            if image.is_none()
+            {
+                xml.push_str("   <point/>\n");
+            }
+
+            xml.push_str("  </object>\n");
+        }
+
+        xml.push_str(" </objectgroup>\n</map>\n");
+
+        std::fs::write(path, xml).map_err(|_| "Error writing the TMX file")
+    }
+}
+
+//=======================================================================//
+
+/// A texture of the tileset of the Tiled maps written by [`Exporter::save_tmx`].
+#[must_use]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TmxTexture
+{
+    /// The name of the texture.
+    pub name:   String,
+    /// The path of the image file of the texture.
+    pub source: PathBuf,
+    /// The size of the image, in pixels.
+    pub size:   UVec2
+}
+
+//=======================================================================//
+
+/// A summary of an exported map, to be written along the output of the exporter so that asset
+/// pipelines can detect stale exports.
+/// The manifest is written to a file with the same name as the map and the `.manifest` extension,
+/// formatted as a list of `key = value` lines followed by the names of the used textures:
+/// ```ini
+/// [manifest]
+/// name = map
+/// version = 0.11
+/// hash = 8c3a52b6f1e0d947
+/// timestamp = 1700000000
+/// [textures]
+/// brick
+/// grass
+/// ```
+#[must_use]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportManifest
+{
+    /// The name of the map, its title if specified, otherwise the name of the file.
+    pub name:      String,
+    /// The version of the map file format.
+    pub version:   String,
+    /// The FNV-1a hash of the content of the map file.
+    pub hash:      u64,
+    /// The export time, in seconds since the UNIX epoch.
+    pub timestamp: u64,
+    /// The names of the textures used by the brushes of the map, sorted and deduplicated.
+    pub textures:  Vec<String>
+}
+
+impl ExportManifest
+{
+    /// Returns a new [`ExportManifest`] describing the map file at `path`, unless there was an
+    /// error.
+    /// # Errors
+    /// Returns an error if there was an issue reading the requested file.
+    #[inline]
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, &'static str>
+    {
+        /// The FNV-1a hash of `bytes`, stable between executions and platforms.
+        #[inline]
+        #[must_use]
+        fn fnv1a(bytes: &[u8]) -> u64
+        {
+            bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+        }
+
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|_| "Could not open the file")?;
+        let version = version_number(&mut BufReader::new(bytes.as_slice()))?;
+        let exporter = Exporter::new(path)?;
+
+        let name = if exporter.metadata.title.is_empty()
+        {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_owned()
+        }
+        else
+        {
+            exporter.metadata.title
+        };
+
+        let mut textures = exporter
+            .brushes
+            .values()
+            .filter_map(|brush| brush.texture.as_ref().map(|texture| texture.name().to_owned()))
+            .collect::<Vec<_>>();
+        textures.sort_unstable();
+        textures.dedup();
+
+        Ok(Self {
+            name,
+            version,
+            hash: fnv1a(&bytes),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs())
+                .unwrap_or_default(),
+            textures
+        })
+    }
+
+    /// Writes the manifest next to the map file at `path`, with the same name and the
+    /// `.manifest` extension.
+    /// # Errors
+    /// Returns an error if there was an issue writing the file.
+    #[inline]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), &'static str>
+    {
+        let mut file = File::create(path.as_ref().with_extension(MANIFEST_EXTENSION))
+            .map_err(|_| "Could not create the manifest file")?;
+
+        let mut content = format!(
+            "[manifest]\nname = {}\nversion = {}\nhash = {:016x}\ntimestamp = {}\n[textures]\n",
+            self.name, self.version, self.hash, self.timestamp
+        );
+
+        for texture in &self.textures
+        {
+            content.push_str(texture);
+            content.push('\n');
+        }
+
+        file.write_all(content.as_bytes())
+            .map_err(|_| "Error writing the manifest file")
+    }
+}
+
+//=======================================================================//
+
+/// The triangles of the brushes sharing the same texture.
+#[must_use]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextureBatch
+{
+    /// The name of the texture, `None` for the untextured brushes.
+    pub texture:  Option<String>,
+    /// The positions of the vertexes.
+    pub vertexes: Vec<Vec2>,
+    /// The UV coordinates of the vertexes, in texels. They must be divided by the size of the
+    /// texture to be normalized. They are all zero for the untextured brushes.
+    pub uvs:      Vec<Vec2>,
+    /// The indexes of the vertexes of the triangles, three per triangle.
+    pub indexes:  Vec<u32>
+}
+
+//=======================================================================//
+
+/// The portion of a [`TextureBatch`] generated from a brush.
+#[must_use]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrushMesh
+{
+    /// The [`Id`] of the brush.
+    pub id:       Id,
+    /// The index of the [`TextureBatch`] containing the triangles of the brush.
+    pub batch:    usize,
+    /// The range of the vertexes of the brush in the [`TextureBatch`].
+    pub vertexes: Range<u32>,
+    /// The range of the indexes of the brush in the [`TextureBatch`].
+    pub indexes:  Range<u32>
+}
+
+//=======================================================================//
+
+/// The brushes of a map triangulated into vertex and index buffers grouped by texture, so that they
+/// can be directly uploaded to the GPU without triangulating the convex polygons at load time.
+/// The texture scale, offset, and angle are baked into the UV coordinates, while scroll and
+/// parallax are left to the engine.
+///
+/// The meshes are saved in a little endian binary file with the following layout:
+/// ```text
+/// "HVMS"                   magic number
+/// u32                      version
+/// u32                      batches amount
+/// for each batch:
+///     u32                  texture name length, 0 if untextured
+///     [u8]                 texture name
+///     u32                  vertexes amount
+///     [f32; 4]             x, y, u, v of each vertex
+///     u32                  indexes amount
+///     [u32]                indexes
+/// u32                      brushes amount
+/// for each brush:
+///     u64                  id
+///     u32                  batch index
+///     u32, u32             first vertex, vertexes amount
+///     u32, u32             first index, indexes amount
+/// ```
+#[must_use]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TessellatedMap
+{
+    /// The triangles of the brushes grouped by texture, sorted by texture name with the untextured
+    /// brushes first.
+    pub batches: Vec<TextureBatch>,
+    /// The meshes of the brushes, sorted by [`Id`].
+    pub brushes: Vec<BrushMesh>
+}
+
+impl TessellatedMap
+{
+    /// Returns a new [`TessellatedMap`] generated from the brushes of `exporter`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn new(exporter: &Exporter) -> Self
+    {
+        let mut brushes = exporter.brushes.values().collect::<Vec<_>>();
+        brushes.sort_unstable_by_key(|brush| brush.id.value());
+
+        let mut textures = brushes
+            .iter()
+            .map(|brush| brush.texture.as_ref().map(TextureInterface::name))
+            .collect::<Vec<_>>();
+        textures.sort_unstable();
+        textures.dedup();
+
+        let mut batches = textures
+            .iter()
+            .map(|texture| {
+                TextureBatch {
+                    texture: texture.map(str::to_owned),
+                    ..Default::default()
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut meshes = Vec::with_capacity(brushes.len());
+
+        for brush in brushes
+        {
+            let texture = brush.texture.as_ref();
+            let batch = textures.binary_search(&texture.map(TextureInterface::name)).unwrap();
+            let TextureBatch {
+                vertexes,
+                uvs,
+                indexes,
+                ..
+            } = &mut batches[batch];
+
+            let first_vx = vertexes.len() as u32;
+            let first_idx = indexes.len() as u32;
+
+            vertexes.extend(brush.vertexes.iter().copied());
+
+            match texture
+            {
+                Some(texture) =>
+                {
+                    let offset = texture.draw_offset();
+                    let scale = Vec2::new(texture.scale_x(), texture.scale_y());
+                    let angle = texture.angle().to_radians();
+
+                    uvs.extend(brush.vertexes.iter().map(|vx| {
+                        let vx = if angle == 0f32
+                        {
+                            *vx
+                        }
+                        else
+                        {
+                            rotate_point_around_origin(*vx, angle)
+                        };
+                        Vec2::new(vx.x + offset.x, -(vx.y + offset.y)) / scale
+                    }));
+                },
+                None => uvs.extend(std::iter::repeat(Vec2::ZERO).take(brush.vertexes.len()))
+            };
+
+            // Convex polygons can be split into a fan of triangles.
+            for i in 1..(brush.vertexes.len() as u32).saturating_sub(1)
+            {
+                indexes.extend([first_vx, first_vx + i, first_vx + i + 1]);
+            }
+
+            meshes.push(BrushMesh {
+                id: brush.id,
+                batch,
+                vertexes: first_vx..vertexes.len() as u32,
+                indexes: first_idx..indexes.len() as u32
+            });
+        }
+
+        Self {
+            batches,
+            brushes: meshes
+        }
+    }
+
+    /// Writes the meshes to the file at `path`.
+    /// # Errors
+    /// Returns an error if there was an issue writing the file.
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), &'static str>
+    {
+        /// Writes `value` as a little endian [`u32`].
+        #[inline]
+        fn write_u32(file: &mut impl Write, value: usize) -> std::io::Result<()>
+        {
+            file.write_all(&(value as u32).to_le_bytes())
+        }
+
+        let file = File::create(path.as_ref()).map_err(|_| "Could not create the meshes file")?;
+        let mut file = BufWriter::new(file);
+
+        let mut write = || -> std::io::Result<()> {
+            file.write_all(MESHES_MAGIC)?;
+            file.write_all(&MESHES_VERSION.to_le_bytes())?;
+            write_u32(&mut file, self.batches.len())?;
+
+            for batch in &self.batches
+            {
+                let name = batch.texture.as_deref().unwrap_or_default();
+                write_u32(&mut file, name.len())?;
+                file.write_all(name.as_bytes())?;
+
+                write_u32(&mut file, batch.vertexes.len())?;
+
+                for (vx, uv) in batch.vertexes.iter().zip(&batch.uvs)
+                {
+                    for value in [vx.x, vx.y, uv.x, uv.y]
+                    {
+                        file.write_all(&value.to_le_bytes())?;
+                    }
+                }
+
+                write_u32(&mut file, batch.indexes.len())?;
+
+                for idx in &batch.indexes
+                {
+                    file.write_all(&idx.to_le_bytes())?;
+                }
+            }
+
+            write_u32(&mut file, self.brushes.len())?;
+
+            for brush in &self.brushes
+            {
+                file.write_all(&(brush.id.value() as u64).to_le_bytes())?;
+                write_u32(&mut file, brush.batch)?;
+
+                for value in [
+                    brush.vertexes.start,
+                    brush.vertexes.len() as u32,
+                    brush.indexes.start,
+                    brush.indexes.len() as u32
+                ]
+                {
+                    file.write_all(&value.to_le_bytes())?;
+                }
+            }
+
+            file.flush()
+        };
+
+        write().map_err(|_| "Error writing the meshes file")
+    }
 }
 
 //=======================================================================//
@@ -270,11 +1346,165 @@ impl Exporter
 
 /// Reads the version number from `file`.
 #[inline]
-fn version_number(file: &mut BufReader<File>) -> Result<String, &'static str>
+fn version_number<R: std::io::Read>(file: &mut BufReader<R>) -> Result<String, &'static str>
 {
     ciborium::from_reader(&mut *file).map_err(|_| "Error reading file version")
 }
 
+//=======================================================================//
+
+/// Returns `value` as a JSON string literal.
+#[inline]
+#[must_use]
+fn json_string(value: &str) -> String
+{
+    let mut string = String::with_capacity(value.len() + 2);
+    string.push('"');
+
+    for c in value.chars()
+    {
+        match c
+        {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            c if c.is_control() => _ = write!(string, "\\u{:04x}", u32::from(c)),
+            c => string.push(c)
+        }
+    }
+
+    string.push('"');
+    string
+}
+
+//=======================================================================//
+
+/// Returns `value` as a JSON value.
+#[inline]
+#[must_use]
+fn json_value(value: &Value) -> String
+{
+    match value
+    {
+        Value::F32(v) if !v.is_finite() => "null".to_owned(),
+        Value::F64(v) if !v.is_finite() => "null".to_owned(),
+        Value::String(v) => json_string(v),
+        Value::Color(v) => json_string(&v.to_string()),
+        _ => value.to_string()
+    }
+}
+
+//=======================================================================//
+
+/// Returns `properties` as a JSON object with the keys sorted alphabetically.
+#[inline]
+#[must_use]
+fn json_properties<'a>(properties: impl IntoIterator<Item = (&'a String, &'a Value)>) -> String
+{
+    let mut properties = properties.into_iter().collect::<Vec<_>>();
+    properties.sort_unstable_by_key(|(key, _)| *key);
+
+    format!(
+        "{{{}}}",
+        properties
+            .into_iter()
+            .map(|(key, value)| format!("{}:{}", json_string(key), json_value(value)))
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+//=======================================================================//
+
+/// Returns the nodes of `path` as a JSON array.
+#[inline]
+#[must_use]
+fn json_path(path: &[crate::Node]) -> String
+{
+    format!(
+        "[{}]",
+        path.iter()
+            .zip(path.iter().cycle().skip(1))
+            .map(|(node, next)| {
+                let movement = &node.movement;
+
+                format!(
+                    r#"{{"pos":[{},{}],"max_speed":{},"min_speed":{},"accel_travel_percentage":{},"decel_travel_percentage":{},"standby_time":{},"easing":"{}","easing_controls":[{}],"curve":[{}]}}"#,
+                    node.pos.x,
+                    node.pos.y,
+                    movement.max_speed(),
+                    movement.min_speed(),
+                    movement.accel_travel_percentage(),
+                    movement.decel_travel_percentage(),
+                    movement.standby_time(),
+                    movement.easing().tag(),
+                    match movement.easing()
+                    {
+                        crate::Easing::Custom(a, b) => format!("{a},{b}"),
+                        _ => String::new()
+                    },
+                    node.curve
+                        .control_points(node.pos, next.pos)
+                        .map(|point| format!("[{},{}]", point.x, point.y))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+//=======================================================================//
+
+/// Returns `value` with the characters reserved by XML replaced by their entities.
+#[inline]
+#[must_use]
+fn xml_escape(value: &str) -> String
+{
+    let mut xml = String::with_capacity(value.len());
+
+    for c in value.chars()
+    {
+        match c
+        {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '"' => xml.push_str("&quot;"),
+            '\'' => xml.push_str("&apos;"),
+            '\n' => xml.push_str("&#10;"),
+            c if c.is_control() => _ = write!(xml, "&#{};", u32::from(c)),
+            c => xml.push(c)
+        }
+    }
+
+    xml
+}
+
+//=======================================================================//
+
+/// Returns `value` with the characters not allowed in a relative URI percent-encoded.
+#[inline]
+#[must_use]
+fn uri_encode(value: &str) -> String
+{
+    let mut uri = String::with_capacity(value.len());
+
+    for byte in value.bytes()
+    {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte)
+        {
+            uri.push(char::from(byte));
+        }
+        else
+        {
+            _ = write!(uri, "%{byte:02X}");
+        }
+    }
+
+    uri
+}
+
 //=======================================================================//
 // UI
 //
@@ -293,7 +1523,7 @@ pub(in crate::map) mod ui_mod
     use bevy::{
         input::mouse::MouseWheel,
         prelude::*,
-        render::{camera::RenderTarget, render_resource::Extent3d},
+        render::{camera::RenderTarget, render_resource::Extent3d, view::RenderLayers},
         window::{PrimaryWindow, WindowCloseRequested},
         winit::WinitSettings
     };
@@ -313,7 +1543,8 @@ pub(in crate::map) mod ui_mod
     use super::{
         editor::state::{grid::Grid, ui::UiFocus},
         thing::HardcodedThings,
-        GridSettings
+        GridSettings,
+        MAP_HALF_SIZE
     };
     use crate::{
         config::Config,
@@ -325,6 +1556,7 @@ pub(in crate::map) mod ui_mod
                 texture_loader::{TextureLoader, TextureLoadingProgress}
             },
             editor::{
+                events::{EditCommitted, EntityDespawned, EntitySpawned, HillVacuumSet, MapSaved},
                 state::clipboard::{
                     prop::Prop,
                     PaintToolPropCamera,
@@ -339,7 +1571,8 @@ pub(in crate::map) mod ui_mod
         },
         utils::hull::Hull,
         warning_message,
-        EditorState
+        EditorState,
+        PropertySchemas
     };
 
     //=======================================================================//
@@ -347,10 +1580,10 @@ pub(in crate::map) mod ui_mod
     //
     //=======================================================================//
 
-    /// The size of half of the map square.
-    pub(in crate::map) const MAP_HALF_SIZE: f32 = 16384f32;
     /// The size of the map square.
     pub(in crate::map) const MAP_SIZE: f32 = MAP_HALF_SIZE * 2f32;
+    /// The render layer of the [`Mesh`]es drawn by the camera of the split view.
+    pub(in crate::map) const SPLIT_VIEW_LAYER: usize = 1;
     /// The range of the map dimensions.
     pub(in crate::map) const MAP_RANGE: RangeInclusive<f32> = -MAP_HALF_SIZE..=MAP_HALF_SIZE;
     /// The [`Hull`] representing the map's area.
@@ -566,7 +1799,12 @@ pub(in crate::map) mod ui_mod
         'world,
         'state,
         &'a Transform,
-        (With<Camera>, Without<PropCamera>, Without<PaintToolPropCamera>)
+        (
+            With<Camera>,
+            Without<PropCamera>,
+            Without<PaintToolPropCamera>,
+            Without<SplitViewCamera>
+        )
     >;
 
     //=======================================================================//
@@ -576,7 +1814,22 @@ pub(in crate::map) mod ui_mod
         'world,
         'state,
         &'a mut Transform,
-        (With<Camera>, Without<PropCamera>, Without<PaintToolPropCamera>)
+        (
+            With<Camera>,
+            Without<PropCamera>,
+            Without<PaintToolPropCamera>,
+            Without<SplitViewCamera>
+        )
+    >;
+
+    //=======================================================================//
+
+    /// The query of the mutable camera drawing the live map preview of the split view.
+    type SplitViewCameraQueryMut<'world, 'state, 'a> = Query<
+        'world,
+        'state,
+        (&'a mut Camera, &'a mut Transform),
+        (With<SplitViewCamera>, Without<PropCamera>, Without<PaintToolPropCamera>)
     >;
 
     //=======================================================================//
@@ -597,6 +1850,27 @@ pub(in crate::map) mod ui_mod
 
     //=======================================================================//
 
+    /// The marker of the camera drawing the live map preview of the split view.
+    #[derive(Component, Default)]
+    struct SplitViewCamera;
+
+    //=======================================================================//
+
+    /// The manual sections added through the `HillVacuumPlugin`, as pairs of title and markdown
+    /// text.
+    #[must_use]
+    #[derive(Resource, Default)]
+    pub(crate) struct UserManualSections(pub Vec<(&'static str, &'static str)>);
+
+    //=======================================================================//
+
+    /// The [`PropertySchemas`] set through the `HillVacuumPlugin`.
+    #[must_use]
+    #[derive(Resource, Default)]
+    pub(crate) struct UserPropertySchemas(pub PropertySchemas);
+
+    //=======================================================================//
+
     /// The plugin that builds the map editor.
     pub(crate) struct MapEditorPlugin;
 
@@ -628,14 +1902,26 @@ pub(in crate::map) mod ui_mod
                 OnEnter(TextureLoadingProgress::Complete),
                 store_loaded_textures
             )
-            // Handle editor
-            .add_systems(First, alt_f4_quit)
             .add_systems(
                 Update,
-                (update,draw)
+                (watch_textures, watch_things)
+                    .run_if(in_state(EditorState::Run))
+                    .run_if(in_state(TextureLoadingProgress::Complete))
+            )
+            // Handle editor
+            .add_event::<EntitySpawned>()
+            .add_event::<EntityDespawned>()
+            .add_event::<EditCommitted>()
+            .add_event::<MapSaved>()
+            .configure_sets(
+                Update,
+                (HillVacuumSet::Update, HillVacuumSet::Draw)
                     .chain()
                     .run_if(in_state(EditorState::Run))
             )
+            .add_systems(First, alt_f4_quit)
+            .add_systems(Update, (update, send_events).chain().in_set(HillVacuumSet::Update))
+            .add_systems(Update, draw.in_set(HillVacuumSet::Draw))
             // Shutdown
             .add_systems(OnEnter(EditorState::ShutDown), cleanup);
         }
@@ -685,6 +1971,18 @@ pub(in crate::map) mod ui_mod
 
         // Cameras.
         commands.spawn((Camera2d, init_camera_transform(), OrthographicProjection::default_2d()));
+        commands.spawn((
+            Camera2d,
+            Camera {
+                is_active: false,
+                order: 1,
+                ..Default::default()
+            },
+            init_camera_transform(),
+            OrthographicProjection::default_2d(),
+            RenderLayers::layer(SPLIT_VIEW_LAYER),
+            SplitViewCamera
+        ));
 
         let mut prop_cameras_amount = 0;
         let mut y = 0f32;
@@ -825,8 +2123,12 @@ pub(in crate::map) mod ui_mod
         mut config: ResMut<Config>,
         mut texture_loader: ResMut<TextureLoader>,
         mut hardcoded_things: ResMut<HardcodedThings>,
-        mut brush_properties: ResMut<BrushUserProperties>,
-        mut thing_properties: ResMut<ThingUserProperties>,
+        (mut brush_properties, mut thing_properties, mut property_schemas): (
+            ResMut<BrushUserProperties>,
+            ResMut<ThingUserProperties>,
+            ResMut<UserPropertySchemas>
+        ),
+        manual_sections: Res<UserManualSections>,
         state: Res<State<EditorState>>,
         mut next_state: ResMut<NextState<EditorState>>
     )
@@ -851,28 +2153,110 @@ pub(in crate::map) mod ui_mod
                 &mut texture_loader,
                 &mut hardcoded_things,
                 &mut brush_properties,
-                &mut thing_properties
+                &mut thing_properties,
+                &manual_sections,
+                &mut property_schemas
             );
 
             next_state.set(EditorState::Run);
             return;
         }
 
+        let partial = texture_loader.partial_load();
+
         editor.reload_textures(
             &mut prop_cameras,
             &mut images,
             &mut materials,
             &mut user_textures,
-            texture_loader.loaded_textures()
+            texture_loader.loaded_textures(),
+            partial
         );
     }
 
     //=======================================================================//
 
+    /// Periodically checks whether image files were added to, or modified in, the textures folder
+    /// and, if so, starts their load.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn watch_textures(
+        mut elapsed: Local<f32>,
+        time: Res<Time>,
+        config: Res<Config>,
+        mut editor: ResMut<Editor>,
+        mut texture_loader: ResMut<TextureLoader>,
+        mut next_tex_load: ResMut<NextState<TextureLoadingProgress>>
+    )
+    {
+        if config.texture_watch_interval == 0f32
+        {
+            return;
+        }
+
+        *elapsed += time.delta_secs();
+
+        if *elapsed < config.texture_watch_interval
+        {
+            return;
+        }
+
+        *elapsed = 0f32;
+
+        if editor.reloading_textures() || !texture_loader.check_changed_files()
+        {
+            return;
+        }
+
+        editor.start_texture_hot_reload();
+        next_tex_load.set(TextureLoadingProgress::Initiated);
+    }
+
+    //=======================================================================//
+
+    /// Periodically checks whether files were added to, modified in, or removed from the things
+    /// folder and, if so, reloads the things.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn watch_things(
+        mut elapsed: Local<f32>,
+        time: Res<Time>,
+        mut prop_cameras: PropCamerasMut,
+        mut images: ResMut<Assets<Image>>,
+        mut user_textures: ResMut<EguiUserTextures>,
+        config: Res<Config>,
+        mut editor: ResMut<Editor>
+    )
+    {
+        if config.texture_watch_interval == 0f32
+        {
+            return;
+        }
+
+        *elapsed += time.delta_secs();
+
+        if *elapsed < config.texture_watch_interval
+        {
+            return;
+        }
+
+        *elapsed = 0f32;
+
+        if editor.reloading_textures()
+        {
+            return;
+        }
+
+        editor.hot_reload_things(&mut prop_cameras, &mut images, &mut user_textures);
+    }
+
+    //=======================================================================//
+
     /// Handle `Alt+F4` shutdown.
     #[inline]
     fn alt_f4_quit(
         mut window: Query<&mut Window, With<PrimaryWindow>>,
+        mut camera: MainCameraQueryMut,
         mut close_events: ResMut<Events<WindowCloseRequested>>,
         mut config: ResMut<Config>,
         mut editor: ResMut<Editor>,
@@ -886,7 +2270,7 @@ pub(in crate::map) mod ui_mod
 
         let mut window = return_if_err!(window.get_single_mut());
 
-        if !editor.quit(&mut window, &mut config, &mut next_editor_state)
+        if !editor.quit(&mut window, &mut camera.single_mut(), &mut config, &mut next_editor_state)
         {
             close_events.clear();
         }
@@ -903,7 +2287,10 @@ pub(in crate::map) mod ui_mod
         mut images: ResMut<Assets<Image>>,
         mut materials: ResMut<Assets<ColorMaterial>>,
         mut camera: MainCameraQueryMut,
-        mut paint_tool_camera: PaintToolCameraQueryMut,
+        (mut paint_tool_camera, mut split_view_camera): (
+            PaintToolCameraQueryMut,
+            SplitViewCameraQueryMut
+        ),
         mut prop_cameras: PropCamerasMut,
         mouse_buttons: Res<ButtonInput<MouseButton>>,
         mut mouse_wheel: EventReader<MouseWheel>,
@@ -921,6 +2308,7 @@ pub(in crate::map) mod ui_mod
         let mut egui_context = egui_context.single_mut();
         let egui_context = egui_context.get_mut();
         let mut camera = camera.single_mut();
+        editor.begin_viewport_update(&window, &mut camera, &mouse_buttons);
 
         editor.update(
             &mut window,
@@ -950,6 +2338,31 @@ pub(in crate::map) mod ui_mod
             &time,
             &mut user_textures
         );
+
+        editor.end_viewport_update(&mut camera);
+        let mut split_view_camera = split_view_camera.single_mut();
+
+        editor.update_split_view_camera(
+            &window,
+            &camera,
+            (split_view_camera.0.as_mut(), split_view_camera.1.as_mut())
+        );
+    }
+
+    //=======================================================================//
+
+    /// Sends the events concerning the map being edited.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn send_events(
+        mut editor: ResMut<Editor>,
+        mut spawned: EventWriter<EntitySpawned>,
+        mut despawned: EventWriter<EntityDespawned>,
+        mut edits: EventWriter<EditCommitted>,
+        mut saves: EventWriter<MapSaved>
+    )
+    {
+        editor.send_events(&mut spawned, &mut despawned, &mut edits, &mut saves);
     }
 
     //=======================================================================//
@@ -982,7 +2395,8 @@ pub(in crate::map) mod ui_mod
             &mut meshes,
             egui_context.single_mut().get_mut(),
             &meshes_query,
-            &config.colors
+            &config.colors,
+            config.marching_ants
         );
     }
 
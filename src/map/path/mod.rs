@@ -35,6 +35,8 @@ pub(in crate::map) mod ui_mod
             hash_map,
             path::{
                 nodes::{
+                    Curve,
+                    Easing,
                     Node,
                     NodeViewer,
                     NodeWorld,
@@ -57,6 +59,7 @@ pub(in crate::map) mod ui_mod
             iterators::{FilterSet, PairIterator, SkipIndexIterator, TripletIterator},
             math::{
                 lines_and_segments::line_point_product,
+                points::rotate_point_around_origin,
                 AroundEqual,
                 FastNormalize,
                 HashVec2,
@@ -79,6 +82,14 @@ pub(in crate::map) mod ui_mod
         INDEXES
     };
 
+    //=======================================================================//
+    // CONSTANTS
+    //
+    //=======================================================================//
+
+    /// The resolution of the circles representing the handles of the curves control points.
+    const CURVE_HANDLE_RESOLUTION: u8 = 8;
+
     //=======================================================================//
     // MACROS
     //
@@ -86,7 +97,30 @@ pub(in crate::map) mod ui_mod
 
     /// Implements the functions to draw the [`Node`]s.
     macro_rules! draw_nodes {
-        ($(($func:ident, $square:ident, $line:ident)),+) => { paste::paste! { $(
+        ($(($func:ident, $square:ident, $plain:ident, $line:ident)),+) => { paste::paste! { $(
+            #[inline]
+            fn [< curved_ $line >](
+                drawer: &mut EditDrawer,
+                curve: &Curve,
+                start: Vec2,
+                end: Vec2,
+                color: Color
+            )
+            {
+                let mut points = curve.points(start, end);
+                let mut prev = points.next().unwrap();
+                let mut last = points.next().unwrap();
+
+                for point in points
+                {
+                    drawer.$plain(prev, last, color);
+                    prev = last;
+                    last = point;
+                }
+
+                drawer.$line(prev, last, color);
+            }
+
             #[inline]
             fn [< nodes_ $line >](
                 &self,
@@ -97,11 +131,18 @@ pub(in crate::map) mod ui_mod
                 color: Color
             )
             {
-                let bucket_j = self.buckets.get(node_j.pos()).unwrap();
-                let bucket_i = self.buckets.get(node_i.pos()).unwrap();
                 let start = node_j.world_pos(center);
                 let end = node_i.world_pos(center);
 
+                if !node_j.curve.is_straight()
+                {
+                    Self::[< curved_ $line >](drawer, &node_j.curve, start, end, color);
+                    return;
+                }
+
+                let bucket_j = self.buckets.get(node_j.pos()).unwrap();
+                let bucket_i = self.buckets.get(node_i.pos()).unwrap();
+
                 if bucket_i.len() == 1 && bucket_j.len() == 1
                 {
                     drawer.$line(start, end, color);
@@ -144,10 +185,21 @@ pub(in crate::map) mod ui_mod
             {
                 if self.len() == 2
                 {
-                    let start = self.nodes[0].world_pos(center);
-                    let end = self.nodes[1].world_pos(center);
-                    Self::[< shifted_ $line >](drawer, start, end, color);
-                    Self::[< shifted_ $line >](drawer, end, start, color);
+                    for [node_j, node_i] in self.nodes.pair_iter().unwrap()
+                    {
+                        let start = node_j.world_pos(center);
+                        let end = node_i.world_pos(center);
+
+                        if node_j.curve.is_straight()
+                        {
+                            Self::[< shifted_ $line >](drawer, start, end, color);
+                        }
+                        else
+                        {
+                            Self::[< curved_ $line >](drawer, &node_j.curve, start, end, color);
+                        }
+                    }
+
                     return;
                 }
 
@@ -368,6 +420,43 @@ pub(in crate::map) mod ui_mod
                 self.path_mut().snap_selected_nodes(grid, center)
             }
 
+            #[inline]
+            fn set_selected_path_nodes_curve(&mut self, tag: &str) -> Option<Vec<(u8, crate::map::path::nodes::Curve)>>
+            {
+                self.path_mut().set_selected_nodes_curve(tag)
+            }
+
+            #[inline]
+            fn set_path_curve_handle(&mut self, index: usize, at_end: bool, pos: Vec2) -> bool
+            {
+                let center = self.center();
+                self.path_mut().set_curve_handle(index, at_end, pos, center)
+            }
+
+            #[inline]
+            fn swap_path_nodes_curves(&mut self, curves: &mut [(u8, crate::map::path::nodes::Curve)])
+            {
+                self.path_mut().swap_nodes_curves(curves);
+            }
+
+            #[inline]
+            fn set_selected_path_nodes_easing(&mut self, tag: &str) -> Option<Vec<(u8, crate::map::path::nodes::Easing)>>
+            {
+                self.path_mut().set_selected_nodes_easing(tag)
+            }
+
+            #[inline]
+            fn set_selected_path_nodes_easing_control(&mut self, index: usize, value: f32) -> Option<Vec<(u8, crate::map::path::nodes::Easing)>>
+            {
+                self.path_mut().set_selected_nodes_easing_control(index, value)
+            }
+
+            #[inline]
+            fn swap_path_nodes_easings(&mut self, easings: &mut [(u8, crate::map::path::nodes::Easing)])
+            {
+                self.path_mut().swap_nodes_easings(easings);
+            }
+
             common_edit_path!(
                 (standby_time, crate::map::path::StandbyValueEdit),
                 (max_speed, crate::map::path::MovementValueEdit),
@@ -436,6 +525,35 @@ pub(in crate::map) mod ui_mod
             self.path().unwrap().overall_selected_nodes_movement()
         }
 
+        /// Returns the index of the [`Node`] whose segment has a control point handle beneath
+        /// `cursor_pos` and whether the handle is attached to the end [`Node`] of the segment, if
+        /// any.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[inline]
+        #[must_use]
+        fn path_curve_handle_beneath_cursor(
+            &self,
+            cursor_pos: Vec2,
+            camera_scale: f32
+        ) -> Option<(u8, bool)>
+        {
+            self.path().unwrap().curve_handle_beneath_cursor(
+                cursor_pos,
+                self.center(),
+                camera_scale
+            )
+        }
+
+        /// Returns the [`Curve`] of the segment starting at the [`Path`]'s [`Node`] at `index`.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[inline]
+        fn path_curve_at_index(&self, index: usize) -> Curve
+        {
+            self.path().unwrap().curve_at_index(index)
+        }
+
         /// Whether the selected nodes of the [`Path`] can be legally moved by `delta`.
         /// # Panics
         /// Panics if the entity has no [`Path`].
@@ -483,6 +601,15 @@ pub(in crate::map) mod ui_mod
             self.path().unwrap().draw(window, camera, drawer, self.center());
         }
 
+        /// Draws the handles of the control points of the curved segments of the [`Path`].
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[inline]
+        fn draw_path_curve_handles(&self, drawer: &mut EditDrawer)
+        {
+            self.path().unwrap().draw_curve_handles(drawer, self.center());
+        }
+
         /// Draws the [`Path`] with semitransparent materials.
         /// # Panics
         /// Panics if the entity has no [`Path`].
@@ -677,6 +804,52 @@ pub(in crate::map) mod ui_mod
         #[must_use]
         fn snap_selected_path_nodes(&mut self, grid: &Grid) -> Option<Vec<(Vec<u8>, Vec2)>>;
 
+        /// Sets the shape of the segments starting at the selected [`Path`]'s [`Node`]s to the one
+        /// named `tag`. Returns the indexes of the [`Node`]s whose segment changed and their
+        /// previous [`Curve`]s.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[must_use]
+        fn set_selected_path_nodes_curve(&mut self, tag: &str) -> Option<Vec<(u8, Curve)>>;
+
+        /// Moves the handle of the control point of the segment starting at the [`Path`]'s
+        /// [`Node`] at `index` to `pos`. Returns whether the handle was moved.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[must_use]
+        fn set_path_curve_handle(&mut self, index: usize, at_end: bool, pos: Vec2) -> bool;
+
+        /// Swaps the [`Curve`]s of the segments starting at the [`Path`]'s [`Node`]s with the ones
+        /// contained in `curves`.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        fn swap_path_nodes_curves(&mut self, curves: &mut [(u8, Curve)]);
+
+        /// Sets the [`Easing`] of the selected [`Path`]'s [`Node`]s to the one named `tag`.
+        /// Returns the indexes of the [`Node`]s whose [`Easing`] changed and their previous ones.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[must_use]
+        fn set_selected_path_nodes_easing(&mut self, tag: &str) -> Option<Vec<(u8, Easing)>>;
+
+        /// Sets the control value at `index` of the custom [`Easing`]s of the selected [`Path`]'s
+        /// [`Node`]s to `value`. Returns the indexes of the [`Node`]s whose [`Easing`] changed
+        /// and their previous ones.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        #[must_use]
+        fn set_selected_path_nodes_easing_control(
+            &mut self,
+            index: usize,
+            value: f32
+        ) -> Option<Vec<(u8, Easing)>>;
+
+        /// Swaps the [`Easing`]s of the [`Path`]'s [`Node`]s with the ones contained in
+        /// `easings`.
+        /// # Panics
+        /// Panics if the entity has no [`Path`].
+        fn swap_path_nodes_easings(&mut self, easings: &mut [(u8, Easing)]);
+
         /// Sets the standby time of the selected [`Path`]'s [`Node`]s to `value`, returns a
         /// [`StandbyValueEdit`] describing the outcome.
         /// # Panics
@@ -1073,6 +1246,8 @@ pub(in crate::map) mod ui_mod
     {
         /// The acceleration.
         acceleration: f32,
+        /// The time the acceleration lasts.
+        duration:     f32,
         /// The segment describing the finish line of the acceleration.
         end:          [Vec2; 2]
     }
@@ -1086,6 +1261,8 @@ pub(in crate::map) mod ui_mod
     {
         /// The deceleration.
         deceleration: f32,
+        /// The time the deceleration lasts.
+        duration:     f32,
         /// The segment describing the start line of the deceleration.
         start:        [Vec2; 2],
         /// The segment describing the finish line of the deceleration.
@@ -1154,13 +1331,16 @@ pub(in crate::map) mod ui_mod
         current_node:    Node,
         /// The Node the entity is currently traveling to.
         target_node:     Node,
-        /// The distance that separates the Nodes the entity is traveling to-from.
+        /// The distance the entity travels along the segment connecting the Nodes it is traveling
+        /// to-from.
         travel_distance: f32,
         /// The time that has to pass before the entity can start moving from the current start
         /// Node.
         standby:         f32,
         /// The current move speed.
         current_speed:   f32,
+        /// The time elapsed since the start of the current eased acceleration or deceleration.
+        phase_time:      f32,
         /// The acceleration values.
         acceleration:    Option<AccelerationInfo>,
         /// The deceleration values.
@@ -1189,6 +1369,8 @@ pub(in crate::map) mod ui_mod
         // a = 0.5 * (v^2 - v0^2) / (x - x0)
 
         /// Returns the values relative to the travel from one [`Node`] to the next.
+        /// The travel is simulated along a straight line as long as the segment connecting the
+        /// [`Node`]s, which is then mapped onto the segment.
         #[inline]
         #[must_use]
         fn distance_accel_decel(
@@ -1204,20 +1386,30 @@ pub(in crate::map) mod ui_mod
                 0.5 * ((end_speed - start_speed) / distance)
             }
 
+            /// Returns the time required to travel `distance` while the speed goes from
+            /// `start_speed` to `end_speed` following `easing`.
+            #[inline]
+            #[must_use]
+            fn duration(easing: Easing, start_speed: f32, end_speed: f32, distance: f32) -> f32
+            {
+                distance / (start_speed + (end_speed - start_speed) * easing.area(1f32))
+            }
+
             let start = current_node.pos();
-            let end = target_node.pos();
-            let distance = end - start;
-            let dir = distance.normalize();
+            let dir = (target_node.pos() - start).normalize();
             let perp = dir.perp();
-            let length = distance.length();
+            let length = current_node.curve.length(start, target_node.pos());
+            let distance = dir * length;
+            let end = start + distance;
 
             let max_squared = current_node.movement.max_speed() * current_node.movement.max_speed();
             let min_squared = current_node.movement.min_speed() * current_node.movement.min_speed();
             let accel_percentage = current_node.movement.scaled_accel_travel_percentage();
             let decel_percentage = current_node.movement.scaled_decel_travel_percentage();
+            let easing = current_node.movement.easing();
 
             (
-                distance.normalize(),
+                dir,
                 length,
                 (accel_percentage != 0f32).then(|| {
                     let acceleration =
@@ -1226,17 +1418,28 @@ pub(in crate::map) mod ui_mod
 
                     AccelerationInfo {
                         acceleration,
+                        duration: duration(
+                            easing,
+                            current_node.movement.min_speed(),
+                            current_node.movement.max_speed(),
+                            length * accel_percentage
+                        ),
                         end: [end, end + perp]
                     }
                 }),
                 (decel_percentage != 0f32).then(|| {
-                    let end = target_node.pos();
                     let percent = current_node.movement.scaled_decel_travel_percentage();
                     let distance = distance * percent;
                     let decel_start = end - distance;
 
                     DecelerationInfo {
                         deceleration: xceleration(min_squared, max_squared, length * percent),
+                        duration:     duration(
+                            easing,
+                            current_node.movement.max_speed(),
+                            current_node.movement.min_speed(),
+                            length * percent
+                        ),
                         start:        [decel_start, decel_start + perp],
                         end:          [end, end + perp]
                     }
@@ -1266,6 +1469,7 @@ pub(in crate::map) mod ui_mod
                 travel_distance,
                 standby: 0f32,
                 current_speed: current_node.movement.start_speed(),
+                phase_time: 0f32,
                 acceleration,
                 deceleration
             }
@@ -1273,7 +1477,22 @@ pub(in crate::map) mod ui_mod
 
         /// Returns the distance between the position of the first [`Node`] and the current
         /// position.
-        pub(in crate::map) fn movement_vec(&self) -> Vec2 { self.pos - self.start }
+        pub(in crate::map) fn movement_vec(&self) -> Vec2
+        {
+            let start = self.current_node.pos();
+
+            self.current_node.curve.point_at_distance(
+                start,
+                self.target_node.pos(),
+                (self.pos - start).length()
+            ) - self.start
+        }
+
+        /// Returns the end of the straight line along which the travel to the target [`Node`] is
+        /// simulated.
+        #[inline]
+        #[must_use]
+        fn segment_end(&self) -> Vec2 { self.current_node.pos() + self.dir * self.travel_distance }
 
         /// How much more time must pass before the current xceleration phase is over.
         #[inline]
@@ -1287,6 +1506,45 @@ pub(in crate::map) mod ui_mod
             delta_time - ((delta - self.current_speed) / xceleration)
         }
 
+        /// Updates an acceleration or deceleration phase whose speed goes from `start_speed` to
+        /// `end_speed` in `duration` following the [`Easing`] of the current [`Node`], and that
+        /// finishes at `end`. Returns an [`XcelerationPhase`] describing the update status.
+        #[inline]
+        fn eased_phase(
+            &mut self,
+            start_speed: f32,
+            end_speed: f32,
+            duration: f32,
+            end: Vec2,
+            delta_time: f32
+        ) -> XcelerationPhase
+        {
+            let easing = self.current_node.movement.easing();
+            let delta_speed = end_speed - start_speed;
+            let distance = |time: f32| {
+                start_speed * time + delta_speed * duration * easing.area(time / duration)
+            };
+            let time = self.phase_time + delta_time;
+
+            if time >= duration
+            {
+                self.pos = end;
+                self.current_speed = end_speed;
+                self.phase_time = 0f32;
+                return XcelerationPhase::Reupdate(time - duration);
+            }
+
+            if delta_time == 0f32
+            {
+                return XcelerationPhase::Ongoing(self.current_speed);
+            }
+
+            let average = (distance(time) - distance(self.phase_time)) / delta_time;
+            self.phase_time = time;
+            self.current_speed = start_speed + delta_speed * easing.value(time / duration);
+            XcelerationPhase::Ongoing(average)
+        }
+
         /// Updates the acceleration phase and returns an [`XcelerationPhase`] describing the update
         /// status.
         #[inline]
@@ -1301,8 +1559,21 @@ pub(in crate::map) mod ui_mod
                 return XcelerationPhase::Passed;
             }
 
+            let movement = self.current_node.movement;
+
+            if !movement.easing().is_linear()
+            {
+                return self.eased_phase(
+                    movement.min_speed(),
+                    movement.max_speed(),
+                    info.duration,
+                    info.end[0],
+                    delta_time
+                );
+            }
+
             let final_speed = self.current_speed + info.acceleration * delta_time;
-            let max_speed = self.current_node.movement.max_speed();
+            let max_speed = movement.max_speed();
 
             match final_speed.total_cmp(&max_speed)
             {
@@ -1342,8 +1613,21 @@ pub(in crate::map) mod ui_mod
                 return XcelerationPhase::Passed;
             }
 
+            let movement = self.current_node.movement;
+
+            if !movement.easing().is_linear()
+            {
+                return self.eased_phase(
+                    movement.max_speed(),
+                    movement.min_speed(),
+                    info.duration,
+                    info.end[0],
+                    delta_time
+                );
+            }
+
             let final_speed = self.current_speed + info.deceleration * delta_time;
-            let min_speed = self.current_node.movement.min_speed();
+            let min_speed = movement.min_speed();
 
             match final_speed.total_cmp(&min_speed)
             {
@@ -1465,11 +1749,7 @@ pub(in crate::map) mod ui_mod
             {
                 Some(average_speed) =>
                 {
-                    match self.residual_delta_time(
-                        average_speed,
-                        self.target_node.pos(),
-                        delta_time
-                    )
+                    match self.residual_delta_time(average_speed, self.segment_end(), delta_time)
                     {
                         Some(delta_time) => delta_time,
                         None =>
@@ -1493,6 +1773,7 @@ pub(in crate::map) mod ui_mod
             self.target_index = next(self.target_index, nodes.len());
             self.current_node = self.target_node.replace_value(nodes[self.target_index]);
             self.current_speed = self.current_node.movement.start_speed();
+            self.phase_time = 0f32;
 
             (self.dir, self.travel_distance, self.acceleration, self.deceleration) =
                 Self::distance_accel_decel(&self.current_node, &self.target_node);
@@ -1602,10 +1883,9 @@ pub(in crate::map) mod ui_mod
         fn eq(&self, other: &Self) -> bool
         {
             self.len() == other.len() &&
-                self.nodes
-                    .iter()
-                    .zip(&other.nodes)
-                    .all(|(a, b)| a.selectable_vector == b.selectable_vector)
+                self.nodes.iter().zip(&other.nodes).all(|(a, b)| {
+                    a.selectable_vector == b.selectable_vector && a.curve == b.curve
+                })
         }
     }
 
@@ -1614,16 +1894,17 @@ pub(in crate::map) mod ui_mod
         #[inline]
         fn from(value: I) -> Self
         {
-            let hull = Self::nodes_hull(value.clone());
+            let nodes = value.copied().collect::<Vec<_>>();
+            let hull = Self::nodes_hull(&nodes);
             let mut buckets = Buckets::new();
 
-            for (i, node) in value.clone().enumerate()
+            for (i, node) in nodes.iter().enumerate()
             {
                 buckets.insert(i, node.pos());
             }
 
             let path = Self {
-                nodes: value.into_iter().copied().collect(),
+                nodes,
                 hull,
                 buckets
             };
@@ -1646,11 +1927,12 @@ pub(in crate::map) mod ui_mod
                 .map(|node| {
                     Node {
                         selectable_vector: SelectableVector::new(node.pos),
-                        movement:          node.movement
+                        movement:          node.movement,
+                        curve:             node.curve
                     }
                 })
                 .collect::<Vec<_>>();
-            let hull = Path::nodes_hull(nodes.iter());
+            let hull = Path::nodes_hull(&nodes);
             let mut buckets = Buckets::new();
 
             for (i, node) in nodes.iter().enumerate()
@@ -1674,7 +1956,8 @@ pub(in crate::map) mod ui_mod
                 .map(|node| {
                     NodeViewer {
                         pos:      node.pos(),
-                        movement: node.movement
+                        movement: node.movement,
+                        curve:    node.curve
                     }
                 })
                 .collect()
@@ -1684,10 +1967,11 @@ pub(in crate::map) mod ui_mod
     impl Path
     {
         draw_nodes!(
-            (draw_nodes, square_highlight, arrowed_line),
+            (draw_nodes, square_highlight, line, arrowed_line),
             (
                 draw_semitransparent_nodes,
                 semitransparent_square_highlight,
+                semitransparent_line,
                 semitransparent_arrowed_line
             )
         );
@@ -1729,11 +2013,19 @@ pub(in crate::map) mod ui_mod
         //==============================================================
         // Info
 
-        /// Returns the [`Hull`] encompassing all the [`Node`]s.
+        /// Returns the [`Hull`] encompassing all the [`Node`]s and the control points of the curved
+        /// segments.
         #[inline]
-        fn nodes_hull<'a, I: ExactSizeIterator<Item = &'a Node>>(nodes: I) -> Hull
+        fn nodes_hull(nodes: &[Node]) -> Hull
         {
-            Hull::from_points(nodes.map(Node::pos))
+            Hull::from_points(
+                nodes.iter().map(Node::pos).chain(
+                    nodes
+                        .pair_iter()
+                        .unwrap()
+                        .flat_map(|[a, b]| a.curve.control_points(a.pos(), b.pos()))
+                )
+            )
         }
 
         #[inline]
@@ -1771,7 +2063,7 @@ pub(in crate::map) mod ui_mod
         #[must_use]
         fn valid(&self) -> bool
         {
-            self.hull.around_equal(&Self::nodes_hull(self.nodes().iter())) &&
+            self.hull.around_equal(&Self::nodes_hull(self.nodes())) &&
                 self.nodes().pair_iter().unwrap().enumerate().all(|([_, i], [a, b])| {
                     !a.pos().around_equal_narrow(&b.pos()) &&
                         return_if_none!(self.buckets.get(b.pos()), false).contains(&i)
@@ -1814,12 +2106,26 @@ pub(in crate::map) mod ui_mod
             self.nodes[index].selectable_vector.vec
         }
 
+        /// Returns an iterator to the positions in world coordinates of the non selected
+        /// [`Node`]s. `center` is the center of the entity the [`Path`] belongs to.
+        #[inline]
+        pub(in crate::map) fn unselected_nodes_world_pos(
+            &self,
+            center: Vec2
+        ) -> impl Iterator<Item = Vec2> + '_
+        {
+            self.nodes()
+                .iter()
+                .filter(|node| !node.selectable_vector.selected)
+                .map(move |node| node.world_pos(center))
+        }
+
         //==============================================================
         // Update
 
         /// Updates the value of the cached [`Hull`].
         #[inline]
-        fn update_hull(&mut self) { self.hull = Self::nodes_hull(self.nodes().iter()); }
+        fn update_hull(&mut self) { self.hull = Self::nodes_hull(self.nodes()); }
 
         /// Snaps the selected [`Node`]s to the Grid.
         /// Returns a vector of the indexes and positions of the nodes that were snapped, if it was
@@ -2325,6 +2631,23 @@ pub(in crate::map) mod ui_mod
             assert!(self.valid(), "translate generated an invalid Path.");
         }
 
+        /// Rotates the [`Path`] around the center of the entity by `angle` radians.
+        /// # Panics
+        /// Panics if the generated [`Path`] is invalid.
+        #[inline]
+        pub(in crate::map) fn rotate(&mut self, angle: f32)
+        {
+            for i in 0..self.len()
+            {
+                let pos = self.nodes[i].pos();
+                self.move_node(i, rotate_point_around_origin(pos, angle) - pos);
+                self.nodes[i].curve.rotate(angle);
+            }
+
+            self.update_hull();
+            assert!(self.valid(), "rotate generated an invalid Path.");
+        }
+
         /// Moves the [`Node`]s at indexes `idxs` by `delta`.
         /// # Panic
         /// Panics if the resulting path is invalid, or if any of the indexes is out of bounds.
@@ -2454,7 +2777,7 @@ pub(in crate::map) mod ui_mod
                 .nodes()
                 .iter()
                 .filter(|n| n.selectable_vector.selected)
-                .any(|node| overall.stack(&node.movement));
+                .any(|node| overall.stack(node));
 
             overall
         }
@@ -2511,6 +2834,199 @@ pub(in crate::map) mod ui_mod
             }
         }
 
+        //==============================================================
+        // Curve
+
+        /// Returns the [`Curve`] of the segment starting at the [`Node`] at `index`.
+        #[inline]
+        pub(in crate::map) fn curve_at_index(&self, index: usize) -> Curve
+        {
+            self.nodes[index].curve
+        }
+
+        /// Returns the index of the [`Node`] whose segment has a control point handle beneath
+        /// `cursor_pos` and whether the handle is attached to the end [`Node`] of the segment, if
+        /// any.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn curve_handle_beneath_cursor(
+            &self,
+            cursor_pos: Vec2,
+            center: Vec2,
+            camera_scale: f32
+        ) -> Option<(u8, bool)>
+        {
+            let len = self.len();
+
+            self.nodes.iter().enumerate().find_map(|(i, node)| {
+                node.curve
+                    .handles(node.world_pos(center), self.nodes[next(i, len)].world_pos(center))
+                    .find_map(|(at_end, _, handle)| {
+                        handle
+                            .is_point_inside_ui_highlight(cursor_pos, camera_scale)
+                            .then(|| (u8::try_from(i).unwrap(), at_end))
+                    })
+            })
+        }
+
+        /// Sets the shape of the segments starting at the selected [`Node`]s to the one named
+        /// `tag`. Returns the indexes of the [`Node`]s whose segment changed and their previous
+        /// [`Curve`]s, if any.
+        #[inline]
+        pub(in crate::map) fn set_selected_nodes_curve(
+            &mut self,
+            tag: &str
+        ) -> Option<Vec<(u8, Curve)>>
+        {
+            let len = self.len();
+            let mut edit = Vec::new();
+
+            for i in 0..len
+            {
+                let node = &self.nodes[i];
+
+                if !node.selectable_vector.selected || node.curve.tag() == tag
+                {
+                    continue;
+                }
+
+                let curve = Curve::from_tag(tag, node.pos(), self.nodes[next(i, len)].pos());
+                edit.push((u8::try_from(i).unwrap(), self.nodes[i].curve.replace_value(curve)));
+            }
+
+            if edit.is_empty()
+            {
+                return None;
+            }
+
+            self.update_hull();
+            edit.into()
+        }
+
+        /// Moves the handle of the segment starting at the [`Node`] at `index` attached to the end
+        /// [`Node`], if `at_end` is true, or to the start one, otherwise, to `pos`.
+        /// Returns whether the handle was moved.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn set_curve_handle(
+            &mut self,
+            index: usize,
+            at_end: bool,
+            pos: Vec2,
+            center: Vec2
+        ) -> bool
+        {
+            if pos.out_of_bounds()
+            {
+                return false;
+            }
+
+            let end = self.nodes[next(index, self.len())].pos();
+            let node = &mut self.nodes[index];
+
+            if !node
+                .curve
+                .set_handle(at_end, node.selectable_vector.vec, end, pos - center)
+            {
+                return false;
+            }
+
+            self.update_hull();
+            true
+        }
+
+        /// Swaps the [`Curve`]s of the segments starting at the [`Node`]s at the indexes contained
+        /// in `curves` with the associated ones.
+        #[inline]
+        pub(in crate::map) fn swap_nodes_curves(&mut self, curves: &mut [(u8, Curve)])
+        {
+            for (idx, curve) in curves
+            {
+                std::mem::swap(&mut self.nodes[usize::from(*idx)].curve, curve);
+            }
+
+            self.update_hull();
+        }
+
+        //==============================================================
+        // Easing
+
+        /// Sets the [`Easing`] of the selected [`Node`]s to the one named `tag`. Returns the
+        /// indexes of the [`Node`]s whose [`Easing`] changed and their previous ones, if any.
+        #[inline]
+        pub(in crate::map) fn set_selected_nodes_easing(
+            &mut self,
+            tag: &str
+        ) -> Option<Vec<(u8, Easing)>>
+        {
+            let easing = Easing::from_tag(tag);
+
+            self.edit_selected_nodes_easing(|prev| {
+                if prev.tag() == tag
+                {
+                    return false;
+                }
+
+                *prev = easing;
+                true
+            })
+        }
+
+        /// Sets the control value at `index` of the custom [`Easing`]s of the selected [`Node`]s
+        /// to `value`. Returns the indexes of the [`Node`]s whose [`Easing`] changed and their
+        /// previous ones, if any.
+        #[inline]
+        pub(in crate::map) fn set_selected_nodes_easing_control(
+            &mut self,
+            index: usize,
+            value: f32
+        ) -> Option<Vec<(u8, Easing)>>
+        {
+            self.edit_selected_nodes_easing(|easing| easing.set_control(index, value))
+        }
+
+        /// Edits the [`Easing`] of the selected [`Node`]s with `f`, which returns whether the
+        /// [`Easing`] was changed. Returns the indexes of the [`Node`]s whose [`Easing`]
+        /// changed and their previous ones, if any.
+        #[inline]
+        fn edit_selected_nodes_easing<F>(&mut self, mut f: F) -> Option<Vec<(u8, Easing)>>
+        where
+            F: FnMut(&mut Easing) -> bool
+        {
+            let mut edit = Vec::new();
+
+            for (i, node) in self.nodes.iter_mut().enumerate()
+            {
+                if !node.selectable_vector.selected
+                {
+                    continue;
+                }
+
+                let mut easing = node.movement.easing();
+
+                if !f(&mut easing)
+                {
+                    continue;
+                }
+
+                edit.push((u8::try_from(i).unwrap(), node.movement.set_easing(easing).unwrap()));
+            }
+
+            (!edit.is_empty()).then_some(edit)
+        }
+
+        /// Swaps the [`Easing`]s of the [`Node`]s at the indexes contained in `easings` with the
+        /// associated ones.
+        #[inline]
+        pub(in crate::map) fn swap_nodes_easings(&mut self, easings: &mut [(u8, Easing)])
+        {
+            for (idx, easing) in easings
+            {
+                let movement = &mut self.nodes[usize::from(*idx)].movement;
+                *easing = movement.set_easing(*easing).unwrap_or(*easing);
+            }
+        }
+
         //==============================================================
         // Draw
 
@@ -2600,6 +3116,24 @@ pub(in crate::map) mod ui_mod
             self.draw_nodes(drawer, center, Color::PathNode);
         }
 
+        /// Draws the handles of the control points of the curved segments.
+        #[inline]
+        pub(in crate::map) fn draw_curve_handles(&self, drawer: &mut EditDrawer, center: Vec2)
+        {
+            let radius = VX_HGL_SIDE / 2f32 * drawer.camera_scale();
+
+            for [node_j, node_i] in self.nodes.pair_iter().unwrap()
+            {
+                for (_, anchor, handle) in node_j
+                    .curve
+                    .handles(node_j.world_pos(center), node_i.world_pos(center))
+                {
+                    drawer.line(anchor, handle, Color::PathCurveHandle);
+                    drawer.circle(handle, CURVE_HANDLE_RESOLUTION, radius, Color::PathCurveHandle);
+                }
+            }
+        }
+
         /// Draws the [`Path`] for the [`Prop`] screenshot.
         #[inline]
         pub(in crate::map) fn draw_prop(&self, drawer: &mut EditDrawer, center: Vec2)
@@ -6,6 +6,252 @@
 use glam::Vec2;
 use serde::{Deserialize, Serialize};
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The amount of straight segments used to approximate a curved [`Curve`].
+const CURVE_SEGMENTS: u8 = 24;
+/// The amount of intervals used to integrate an [`Easing`].
+const EASING_INTERVALS: u8 = 16;
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// The shape of the segment going from a [`crate::Node`] to the next one.
+/// The control points of the curves are relative to the [`crate::Node`]s they are attached to, so
+/// that they follow them when moved.
+#[must_use]
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Curve
+{
+    /// A straight line.
+    #[default]
+    Straight,
+    /// A quadratic Bezier curve, with the control point relative to the start [`crate::Node`].
+    Quadratic(Vec2),
+    /// A cubic Bezier curve, with the first control point relative to the start [`crate::Node`]
+    /// and the second one relative to the end [`crate::Node`].
+    Cubic(Vec2, Vec2)
+}
+
+impl Curve
+{
+    /// Whether the segment is a straight line.
+    #[inline]
+    #[must_use]
+    pub const fn is_straight(&self) -> bool { matches!(self, Self::Straight) }
+
+    /// Returns an iterator to the control points of the segment going from `start` to `end`,
+    /// expressed in the same coordinates as the two.
+    #[inline]
+    pub fn control_points(&self, start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2>
+    {
+        match self
+        {
+            Self::Straight => [None, None],
+            Self::Quadratic(control) => [Some(start + *control), None],
+            Self::Cubic(control_start, control_end) =>
+            {
+                [Some(start + *control_start), Some(end + *control_end)]
+            },
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// Returns the point of the segment going from `start` to `end` at `t`, a value between 0
+    /// and 1.
+    #[inline]
+    #[must_use]
+    pub fn point(&self, start: Vec2, end: Vec2, t: f32) -> Vec2
+    {
+        let u = 1f32 - t;
+
+        match self
+        {
+            Self::Straight => start.lerp(end, t),
+            Self::Quadratic(control) =>
+            {
+                u * u * start + 2f32 * u * t * (start + *control) + t * t * end
+            },
+            Self::Cubic(control_start, control_end) =>
+            {
+                u * u * u * start +
+                    3f32 * u * u * t * (start + *control_start) +
+                    3f32 * u * t * t * (end + *control_end) +
+                    t * t * t * end
+            },
+        }
+    }
+
+    /// Returns an iterator to the points of the polyline approximating the segment going from
+    /// `start` to `end`, `start` and `end` included.
+    #[inline]
+    pub fn points(&self, start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> + '_
+    {
+        let segments = if self.is_straight() { 1 } else { CURVE_SEGMENTS };
+
+        (0..=segments).map(move |i| self.point(start, end, f32::from(i) / f32::from(segments)))
+    }
+
+    /// Returns the length of the segment going from `start` to `end`.
+    #[inline]
+    #[must_use]
+    pub fn length(&self, start: Vec2, end: Vec2) -> f32
+    {
+        let mut prev = start;
+
+        self.points(start, end)
+            .skip(1)
+            .map(|point| {
+                let length = prev.distance(point);
+                prev = point;
+                length
+            })
+            .sum()
+    }
+
+    /// Returns the point of the segment going from `start` to `end` that is `distance` away from
+    /// `start` along it.
+    #[inline]
+    #[must_use]
+    pub fn point_at_distance(&self, start: Vec2, end: Vec2, mut distance: f32) -> Vec2
+    {
+        let mut prev = start;
+
+        for point in self.points(start, end).skip(1)
+        {
+            let length = prev.distance(point);
+
+            if distance <= length
+            {
+                return prev.lerp(point, distance / length);
+            }
+
+            distance -= length;
+            prev = point;
+        }
+
+        end
+    }
+}
+
+//=======================================================================//
+
+/// The function describing how the speed of an entity changes while it accelerates from the
+/// minimum to the maximum speed, or decelerates from the maximum to the minimum speed, traveling
+/// from a [`crate::Node`] to the next one.
+/// Each function maps the elapsed fraction of the acceleration or deceleration time to the
+/// fraction of the speed change, both values between 0 and 1.
+#[must_use]
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Easing
+{
+    /// The speed changes at a constant rate.
+    #[default]
+    Linear,
+    /// The speed changes slowly at first and quickly at the end.
+    EaseIn,
+    /// The speed changes quickly at first and slowly at the end.
+    EaseOut,
+    /// The speed changes slowly at the start and at the end, following a sine wave.
+    Sine,
+    /// The speed changes slowly at the start and at the end, more steeply than [`Easing::Sine`]
+    /// in the middle.
+    Cubic,
+    /// A cubic Bezier curve going from 0 to 1, whose control points are placed at one third and
+    /// two thirds of the time and have the contained values, between 0 and 1.
+    Custom(f32, f32)
+}
+
+impl Easing
+{
+    /// Whether the speed changes at a constant rate.
+    #[inline]
+    #[must_use]
+    pub const fn is_linear(&self) -> bool { matches!(self, Self::Linear) }
+
+    /// Returns the name of the function.
+    #[inline]
+    #[must_use]
+    pub const fn tag(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Linear => "Linear",
+            Self::EaseIn => "Ease in",
+            Self::EaseOut => "Ease out",
+            Self::Sine => "Sine",
+            Self::Cubic => "Cubic",
+            Self::Custom(..) => "Custom"
+        }
+    }
+
+    /// Whether the control values of a [`Easing::Custom`] are between 0 and 1.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool
+    {
+        match self
+        {
+            Self::Custom(a, b) => (0f32..=1f32).contains(a) && (0f32..=1f32).contains(b),
+            _ => true
+        }
+    }
+
+    /// Returns the fraction of the speed change reached at `t`, the elapsed fraction of the
+    /// time.
+    #[inline]
+    #[must_use]
+    pub fn value(&self, t: f32) -> f32
+    {
+        let t = t.clamp(0f32, 1f32);
+
+        match self
+        {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2f32 - t),
+            Self::Sine => (1f32 - (std::f32::consts::PI * t).cos()) / 2f32,
+            Self::Cubic =>
+            {
+                if t < 0.5
+                {
+                    4f32 * t * t * t
+                }
+                else
+                {
+                    1f32 - (2f32 - 2f32 * t).powi(3) / 2f32
+                }
+            },
+            Self::Custom(a, b) =>
+            {
+                let u = 1f32 - t;
+                3f32 * u * u * t * *a + 3f32 * u * t * t * *b + t * t * t
+            }
+        }
+    }
+
+    /// Returns the integral of the function from 0 to `t`, the elapsed fraction of the time.
+    #[inline]
+    #[must_use]
+    pub fn area(&self, t: f32) -> f32
+    {
+        let step = t / f32::from(EASING_INTERVALS);
+
+        // Simpson's rule.
+        (1..EASING_INTERVALS).fold(self.value(0f32) + self.value(t), |sum, i| {
+            let weight = if i % 2 == 0 { 2f32 } else { 4f32 };
+            sum + weight * self.value(step * f32::from(i))
+        }) * step /
+            3f32
+    }
+}
+
 //=======================================================================//
 // STRUCTS
 //
@@ -31,7 +277,10 @@ pub struct Movement
     /// - `accel_travel_percentage`).
     decel_travel_percentage: f32,
     /// The time that has to pass before the entity should start moving.
-    standby_time:            f32
+    standby_time:            f32,
+    /// How the speed changes during the acceleration and deceleration.
+    #[serde(default)]
+    easing:                  Easing
 }
 
 impl Movement
@@ -75,6 +324,89 @@ impl Movement
     #[inline]
     #[must_use]
     pub const fn standby_time(&self) -> f32 { self.standby_time }
+
+    /// Returns the [`Easing`] of the acceleration and deceleration.
+    #[inline]
+    pub const fn easing(&self) -> Easing { self.easing }
+}
+
+impl Default for Movement
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self {
+            max_speed:               60f32,
+            min_speed:               0f32,
+            accel_travel_percentage: 0f32,
+            decel_travel_percentage: 0f32,
+            standby_time:            0f32,
+            easing:                  Easing::Linear
+        }
+    }
+}
+
+impl Movement
+{
+    /// Returns a new [`Movement`], unless the values are not acceptable.
+    /// # Errors
+    /// Returns an error if `max_speed` is not higher than 0, `min_speed` is negative or higher
+    /// than `max_speed`, `accel_travel_percentage` and `decel_travel_percentage` are not values
+    /// between 0 and 100 or their sum is higher than 100, or `standby_time` is negative.
+    #[inline]
+    pub fn try_new(
+        max_speed: f32,
+        min_speed: f32,
+        accel_travel_percentage: f32,
+        decel_travel_percentage: f32,
+        standby_time: f32
+    ) -> Result<Self, &'static str>
+    {
+        if !max_speed.is_finite() || max_speed <= 0f32
+        {
+            return Err("Max speed is not higher than 0.");
+        }
+
+        if !(0f32..=max_speed).contains(&min_speed)
+        {
+            return Err("Min speed is negative or higher than max speed.");
+        }
+
+        if !(0f32..=100f32).contains(&accel_travel_percentage) ||
+            !(0f32..=100f32).contains(&decel_travel_percentage) ||
+            accel_travel_percentage + decel_travel_percentage > 100f32
+        {
+            return Err("Accel and decel percentages are not within 0 and 100.");
+        }
+
+        if !standby_time.is_finite() || standby_time < 0f32
+        {
+            return Err("Standby time is a negative value.");
+        }
+
+        Ok(Self {
+            max_speed,
+            min_speed,
+            accel_travel_percentage,
+            decel_travel_percentage,
+            standby_time,
+            easing: Easing::Linear
+        })
+    }
+
+    /// Returns a [`Movement`] with the same values as `self` and `easing` as [`Easing`].
+    /// # Errors
+    /// Returns an error if `easing` is [`Easing::Custom`] and its values are not between 0 and 1.
+    #[inline]
+    pub fn with_easing(self, easing: Easing) -> Result<Self, &'static str>
+    {
+        if !easing.is_valid()
+        {
+            return Err("Custom easing values are not within 0 and 1.");
+        }
+
+        Ok(Self { easing, ..self })
+    }
 }
 
 //=======================================================================//
@@ -88,7 +420,10 @@ pub struct NodeViewer
     /// The position in 2D space with respect to the center of the entity.
     pub pos:      Vec2,
     /// The data concerning how the moving entity should travel to the next node.
-    pub movement: Movement
+    pub movement: Movement,
+    /// The shape of the segment going to the next node.
+    #[serde(default)]
+    pub curve:    Curve
 }
 
 //=======================================================================//
@@ -110,7 +445,12 @@ pub(in crate::map) mod ui_mod
 
     use crate::{
         map::selectable_vector::SelectableVector,
-        utils::{math::AroundEqual, misc::ReplaceValue},
+        utils::{
+            math::{points::rotate_point_around_origin, AroundEqual},
+            misc::ReplaceValue
+        },
+        Curve,
+        Easing,
         Movement
     };
 
@@ -119,55 +459,8 @@ pub(in crate::map) mod ui_mod
     //
     //=======================================================================//
 
-    impl Default for Movement
-    {
-        #[inline]
-        fn default() -> Self { Self::new(60f32, 0f32, 0f32, 0f32, 0f32) }
-    }
-
     impl Movement
     {
-        /// Creates a new [`Movement`].
-        /// # Panics
-        /// Panics if at least one among the values is not acceptable.
-        /// `min_speed` must be >= 0 and less than `max_speed`, `max_speed` must be > 0,
-        /// `accel_travel_percentage` and `decel_travel_percentage` must be values between
-        /// 0 and 1 and their sum must be <= 1.
-        #[inline]
-        pub(in crate::map) fn new(
-            max_speed: f32,
-            accel_travel_percentage: f32,
-            decel_travel_percentage: f32,
-            min_speed: f32,
-            standby_time: f32
-        ) -> Self
-        {
-            assert!(min_speed >= 0f32, "Min speed is a negative value.");
-            assert!(max_speed > 0f32, "Max speed is not higher than 0.");
-            assert!(min_speed <= max_speed, "Max speed is lower than min speed.");
-            assert!(
-                (0f32..=1f32).contains(&accel_travel_percentage),
-                "Accel travel percentage is not within 0 and 1."
-            );
-            assert!(
-                (0f32..=1f32).contains(&decel_travel_percentage),
-                "Accel travel percentage is not within 0 and 1."
-            );
-            assert!(
-                accel_travel_percentage + decel_travel_percentage <= 1f32,
-                "Accel and decel percentages added are higher than 1."
-            );
-            assert!(standby_time >= 0f32, "Standby time is a negative value.");
-
-            Self {
-                max_speed,
-                min_speed,
-                accel_travel_percentage,
-                decel_travel_percentage,
-                standby_time
-            }
-        }
-
         /// Sets the maximum speed.
         #[inline]
         pub(in crate::map) fn set_max_speed(&mut self, value: f32) -> Option<Vec2>
@@ -260,6 +553,15 @@ pub(in crate::map) mod ui_mod
             (value - self.standby_time.replace_value(value)).into()
         }
 
+        /// Sets the [`Easing`], returns the previous value if different.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn set_easing(&mut self, easing: Easing) -> Option<Easing>
+        {
+            assert!(easing.is_valid(), "Custom easing values are not within 0 and 1.");
+            (self.easing != easing).then(|| self.easing.replace_value(easing))
+        }
+
         /// The speed the entity should start moving. If there is no speed up it is the maximum
         /// speed, otherwise the minimum speed.
         #[inline]
@@ -279,6 +581,172 @@ pub(in crate::map) mod ui_mod
 
     //=======================================================================//
 
+    impl Curve
+    {
+        /// Returns the name of the shape of the segment.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) const fn tag(&self) -> &'static str
+        {
+            match self
+            {
+                Self::Straight => "Straight",
+                Self::Quadratic(_) => "Quadratic",
+                Self::Cubic(..) => "Cubic"
+            }
+        }
+
+        /// Returns a [`Curve`] with the same shape as `tag` and the control points spread evenly
+        /// on the segment going from `start` to `end`.
+        /// # Panics
+        /// Panics if `tag` is not the name of a shape.
+        #[inline]
+        pub(in crate::map) fn from_tag(tag: &str, start: Vec2, end: Vec2) -> Self
+        {
+            let third = (end - start) / 3f32;
+
+            match tag
+            {
+                "Straight" => Self::Straight,
+                "Quadratic" => Self::Quadratic((end - start) / 2f32),
+                "Cubic" => Self::Cubic(third, -third),
+                _ => panic!("Unknown curve {tag}.")
+            }
+        }
+
+        /// Returns an iterator to the handles of the control points of the segment going from
+        /// `start` to `end`. Each element contains whether the handle is attached to the end
+        /// [`Node`], the position of the [`Node`] the handle is attached to, and the position of
+        /// the handle.
+        #[inline]
+        pub(in crate::map) fn handles(
+            &self,
+            start: Vec2,
+            end: Vec2
+        ) -> impl Iterator<Item = (bool, Vec2, Vec2)>
+        {
+            match self
+            {
+                Self::Straight => [None, None],
+                Self::Quadratic(control) => [Some((false, start, start + *control)), None],
+                Self::Cubic(control_start, control_end) =>
+                {
+                    [
+                        Some((false, start, start + *control_start)),
+                        Some((true, end, end + *control_end))
+                    ]
+                },
+            }
+            .into_iter()
+            .flatten()
+        }
+
+        /// Moves the handle of the segment going from `start` to `end` attached to the end
+        /// [`Node`], if `at_end` is true, or to the start one, otherwise, to `pos`.
+        /// Returns whether the handle was moved.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn set_handle(
+            &mut self,
+            at_end: bool,
+            start: Vec2,
+            end: Vec2,
+            pos: Vec2
+        ) -> bool
+        {
+            let (control, anchor) = match (self, at_end)
+            {
+                (Self::Quadratic(control) | Self::Cubic(control, _), false) => (control, start),
+                (Self::Cubic(_, control), true) => (control, end),
+                _ => return false
+            };
+
+            if (*control + anchor).around_equal_narrow(&pos)
+            {
+                return false;
+            }
+
+            *control = pos - anchor;
+            true
+        }
+
+        /// Rotates the control points around the [`Node`]s they are attached to by `angle`
+        /// radians.
+        #[inline]
+        pub(in crate::map) fn rotate(&mut self, angle: f32)
+        {
+            match self
+            {
+                Self::Straight => (),
+                Self::Quadratic(control) => *control = rotate_point_around_origin(*control, angle),
+                Self::Cubic(control_start, control_end) =>
+                {
+                    *control_start = rotate_point_around_origin(*control_start, angle);
+                    *control_end = rotate_point_around_origin(*control_end, angle);
+                }
+            };
+        }
+    }
+
+    //=======================================================================//
+
+    impl Easing
+    {
+        /// Returns the [`Easing`] named `tag`. The control values of [`Easing::Custom`] describe
+        /// a curve that changes the speed slowly at the start and at the end.
+        /// # Panics
+        /// Panics if `tag` is not the name of an [`Easing`].
+        #[inline]
+        pub(in crate::map) fn from_tag(tag: &str) -> Self
+        {
+            match tag
+            {
+                "Linear" => Self::Linear,
+                "Ease in" => Self::EaseIn,
+                "Ease out" => Self::EaseOut,
+                "Sine" => Self::Sine,
+                "Cubic" => Self::Cubic,
+                "Custom" => Self::Custom(0f32, 1f32),
+                _ => panic!("Unknown easing {tag}.")
+            }
+        }
+
+        /// Returns the control value at `index` of a [`Easing::Custom`], if it is one.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) const fn control(&self, index: usize) -> Option<f32>
+        {
+            match (self, index)
+            {
+                (Self::Custom(value, _), 0) | (Self::Custom(_, value), 1) => Some(*value),
+                _ => None
+            }
+        }
+
+        /// Sets the control value at `index` of a [`Easing::Custom`] to `value`.
+        /// Returns whether the value was changed.
+        #[inline]
+        #[must_use]
+        pub(in crate::map) fn set_control(&mut self, index: usize, value: f32) -> bool
+        {
+            let control = match (self, index)
+            {
+                (Self::Custom(control, _), 0) | (Self::Custom(_, control), 1) => control,
+                _ => return false
+            };
+
+            if control.around_equal_narrow(&value)
+            {
+                return false;
+            }
+
+            *control = value;
+            true
+        }
+    }
+
+    //=======================================================================//
+
     /// A node of the travel path of a moving entity.
     /// The position of the node is relative to the center of the entity.
     #[derive(Clone, Copy)]
@@ -287,7 +755,9 @@ pub(in crate::map) mod ui_mod
         /// The position in 2D space with respect to the center of the entity.
         pub(in crate::map::path) selectable_vector: SelectableVector,
         /// The data concerning how the moving entity should travel to the next [`Node`].
-        pub(in crate::map::path) movement:          Movement
+        pub(in crate::map::path) movement:          Movement,
+        /// The shape of the segment going to the next [`Node`].
+        pub(in crate::map::path) curve:             Curve
     }
 
     impl AddAssign<Vec2> for Node
@@ -309,7 +779,8 @@ pub(in crate::map) mod ui_mod
         {
             Self {
                 selectable_vector: SelectableVector::with_selected(vec, selected),
-                movement:          Movement::default()
+                movement:          Movement::default(),
+                curve:             Curve::Straight
             }
         }
 
@@ -554,7 +1025,7 @@ pub(in crate::map) mod ui_mod
         /// Creates a new [`NodesInsertionIter`].
         #[inline]
         #[must_use]
-        pub fn new(slice: &'a [Node], pos: Vec2, index: usize, center: Vec2) -> Self
+        pub const fn new(slice: &'a [Node], pos: Vec2, index: usize, center: Vec2) -> Self
         {
             Self {
                 slice,
@@ -3,7 +3,7 @@
 //
 //=======================================================================//
 
-use super::nodes::Movement;
+use super::nodes::{Movement, Node};
 use crate::utils::overall_value::{OverallValue, OverallValueInterface, UiOverallValue};
 
 //=======================================================================//
@@ -25,31 +25,48 @@ pub(in crate::map) struct OverallMovement
     /// The overall deceleration.
     pub decel_travel_percentage: OverallValue<f32>,
     /// The overall standby time.
-    pub standby_time:            OverallValue<f32>
+    pub standby_time:            OverallValue<f32>,
+    /// The overall shape of the segments.
+    pub curve:                   OverallValue<&'static str>,
+    /// The overall easing.
+    pub easing:                  OverallValue<&'static str>,
+    /// The overall control values of the custom easings.
+    pub easing_controls:         [OverallValue<f32>; 2]
 }
 
-impl From<&Movement> for OverallMovement
+impl From<&Node> for OverallMovement
 {
     #[inline]
-    fn from(value: &Movement) -> Self { Self::from_movement(value) }
+    fn from(value: &Node) -> Self
+    {
+        Self {
+            curve: value.curve.tag().into(),
+            ..Self::from_movement(&value.movement)
+        }
+    }
 }
 
-impl OverallValueInterface<Movement> for OverallMovement
+impl OverallValueInterface<Node> for OverallMovement
 {
     #[inline]
-    fn stack(&mut self, movement: &Movement) -> bool { self.merge(Self::from(movement)) }
+    fn stack(&mut self, node: &Node) -> bool { self.merge(Self::from(node)) }
 
     #[inline]
     fn merge(&mut self, other: Self) -> bool
     {
-        let mut uniform = false;
+        let mut uniform = !self.curve.merge(other.curve);
+        uniform |= !self.easing.merge(other.easing);
+        let [control_0, control_1] = &mut self.easing_controls;
+        let [other_control_0, other_control_1] = other.easing_controls;
 
         for (v_0, v_1) in [
             (&mut self.max_speed, other.max_speed),
             (&mut self.min_speed, other.min_speed),
             (&mut self.accel_travel_percentage, other.accel_travel_percentage),
             (&mut self.decel_travel_percentage, other.decel_travel_percentage),
-            (&mut self.standby_time, other.standby_time)
+            (&mut self.standby_time, other.standby_time),
+            (control_0, other_control_0),
+            (control_1, other_control_1)
         ]
         {
             uniform |= !v_0.merge(v_1);
@@ -65,7 +82,10 @@ impl OverallValueInterface<Movement> for OverallMovement
             self.min_speed.is_not_uniform() &&
             self.accel_travel_percentage.is_not_uniform() &&
             self.decel_travel_percentage.is_not_uniform() &&
-            self.standby_time.is_not_uniform()
+            self.standby_time.is_not_uniform() &&
+            self.curve.is_not_uniform() &&
+            self.easing.is_not_uniform() &&
+            self.easing_controls.iter().all(OverallValueInterface::is_not_uniform)
     }
 }
 
@@ -84,7 +104,15 @@ impl OverallMovement
             min_speed:               movement.min_speed().into(),
             accel_travel_percentage: (movement.accel_travel_percentage()).round().into(),
             decel_travel_percentage: (movement.decel_travel_percentage()).round().into(),
-            standby_time:            movement.standby_time().into()
+            standby_time:            movement.standby_time().into(),
+            curve:                   OverallValue::None,
+            easing:                  movement.easing().tag().into(),
+            easing_controls:         std::array::from_fn(|i| {
+                movement
+                    .easing()
+                    .control(i)
+                    .map_or(OverallValue::None, OverallValue::from)
+            })
         }
     }
 
@@ -110,7 +138,13 @@ pub(in crate::map) struct UiOverallMovement
     /// The overall deceleration.
     pub decel_travel_percentage: UiOverallValue<f32>,
     /// The overall standby time.
-    pub standby_time:            UiOverallValue<f32>
+    pub standby_time:            UiOverallValue<f32>,
+    /// The overall shape of the segments.
+    pub curve:                   OverallValue<&'static str>,
+    /// The overall easing.
+    pub easing:                  OverallValue<&'static str>,
+    /// The overall control values of the custom easings.
+    pub easing_controls:         [UiOverallValue<f32>; 2]
 }
 
 impl From<OverallMovement> for UiOverallMovement
@@ -123,7 +157,10 @@ impl From<OverallMovement> for UiOverallMovement
             accel_travel_percentage: value.accel_travel_percentage.into(),
             decel_travel_percentage: value.decel_travel_percentage.into(),
             min_speed:               value.min_speed.into(),
-            standby_time:            value.standby_time.into()
+            standby_time:            value.standby_time.into(),
+            curve:                   value.curve,
+            easing:                  value.easing,
+            easing_controls:         value.easing_controls.map(Into::into)
         }
     }
 }
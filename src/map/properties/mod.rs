@@ -1,3 +1,4 @@
+pub mod schema;
 pub mod value;
 
 //=======================================================================//
@@ -8,6 +9,23 @@ pub mod value;
 use serde::{Deserialize, Serialize};
 use value::Value;
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+pub(in crate::map) const COLLISION_LABEL: &str = "collision";
+pub(in crate::map) const COLLISION_DEFAULT: Value = Value::Bool(true);
+
+pub(in crate::map) const ANGLE_LABEL: &str = "angle";
+pub(in crate::map) const ANGLE_DEFAULT: Value = Value::I16(0);
+
+pub(in crate::map) const HEIGHT_LABEL: &str = "height";
+pub(in crate::map) const HEIGHT_DEFAULT: Value = Value::I8(0);
+
+pub(in crate::map) const TARGET_LABEL: &str = "target";
+pub(in crate::map) const TARGETNAME_LABEL: &str = "targetname";
+
 //=======================================================================//
 // STRUCTS
 //
@@ -33,7 +51,15 @@ pub(in crate::map) mod ui_mod
     use bevy::prelude::Resource;
     use hill_vacuum_shared::{return_if_none, NextValue};
 
-    use super::DefaultPropertiesViewer;
+    use super::{
+        DefaultPropertiesViewer,
+        ANGLE_DEFAULT,
+        ANGLE_LABEL,
+        COLLISION_DEFAULT,
+        COLLISION_LABEL,
+        HEIGHT_DEFAULT,
+        HEIGHT_LABEL
+    };
     use crate::{
         map::{
             drawer::drawing_resources::DrawingResources,
@@ -315,7 +341,9 @@ pub(in crate::map) mod ui_mod
             pub(in crate::map) struct [< $entity Properties >]
             {
                 $($property_name: Value,)+
-                user:   HashMap<String, Value>
+                user:   HashMap<String, Value>,
+                /// The properties specific to the entity, not associated with any default one.
+                custom: IndexMap<String, Value>
             }
 
             impl Default for [< $entity Properties >]
@@ -325,7 +353,8 @@ pub(in crate::map) mod ui_mod
                 {
                     Self {
                         $($property_name: $default,)+
-                        user:  HashMap::default()
+                        user:   HashMap::default(),
+                        custom: IndexMap::default()
                     }
                 }
             }
@@ -351,12 +380,85 @@ pub(in crate::map) mod ui_mod
 
             impl [< $entity Properties >]
             {
+                /// Returns a reference to the [`Value`] associated with `k`, if any.
+                #[allow(dead_code)]
+                #[inline]
+                #[must_use]
+                pub fn try_get(&self, k: &str) -> Option<&Value>
+                {
+                    $(
+                        if k == $property
+                        {
+                            return Some(&self.$property_name);
+                        }
+                    )+
+
+                    self.user.get(k).or_else(|| self.custom.get(k))
+                }
+
                 #[inline]
                 pub fn from_parts(mut map: HashMap<String, Value>) -> Self
                 {
                     Self {
                         $($property_name: map.remove($property).unwrap_or($default),)+
-                        user: map
+                        user: map,
+                        custom: IndexMap::default()
+                    }
+                }
+
+                /// Sets the custom properties to `custom`, skipping the ones with the same key as
+                /// a default property.
+                #[inline]
+                pub fn with_custom(mut self, custom: impl IntoIterator<Item = (String, Value)>) -> Self
+                {
+                    self.custom = custom.into_iter().filter(|(k, _)| !self.is_default(k)).collect();
+                    self
+                }
+
+                /// Whether `k` is the key of a default property.
+                #[inline]
+                #[must_use]
+                pub fn is_default(&self, k: &str) -> bool
+                {
+                    $(k == $property ||)+ self.user.contains_key(k)
+                }
+
+                /// Returns an iterator to the custom properties, in insertion order.
+                #[inline]
+                pub fn custom(&self) -> impl ExactSizeIterator<Item = (&str, &Value)>
+                {
+                    self.custom.iter().map(|(k, v)| (k.as_str(), v))
+                }
+
+                /// Returns a reference to the custom property with key `k`, if any.
+                #[inline]
+                #[must_use]
+                pub fn custom_get(&self, k: &str) -> Option<&Value> { self.custom.get(k) }
+
+                /// Sets the custom property with key `k` to `value`, or removes it if `value` is
+                /// `None`. Returns the previous value if different.
+                /// # Panics
+                /// Panics if `k` is the key of a default property.
+                #[inline]
+                pub fn set_custom(&mut self, k: &str, value: Option<Value>) -> Option<Option<Value>>
+                {
+                    assert!(!self.is_default(k), "Custom property has the key of a default one.");
+
+                    let value = match value
+                    {
+                        Some(value) => value,
+                        None => return self.custom.shift_remove(k).map(Some)
+                    };
+
+                    match self.custom.get_mut(k)
+                    {
+                        Some(prev) if prev.same_value(&value) => None,
+                        Some(prev) => Some(Some(std::mem::replace(prev, value))),
+                        None =>
+                        {
+                            _ = self.custom.insert(k.to_string(), value);
+                            Some(None)
+                        }
                     }
                 }
 
@@ -376,13 +478,14 @@ pub(in crate::map) mod ui_mod
                     self.user.get_mut(k).unwrap().set(value)
                 }
 
-                /// Consumes `self` and returns the underlying hashmap of values.
+                /// Consumes `self` and returns the underlying hashmap of values, and the custom
+                /// properties.
                 #[inline]
-                pub fn take(self) -> HashMap<String, Value>
+                pub fn take(self) -> (HashMap<String, Value>, Vec<(String, Value)>)
                 {
                     let mut map = self.user;
                     $(map.asserted_insert(($property.to_string(), self.$property_name.clone()));)+
-                    map
+                    (map, self.custom.into_iter().collect())
                 }
 
                 /// Refactors `self` based on `refactor`.
@@ -396,30 +499,22 @@ pub(in crate::map) mod ui_mod
 
                     for k in &refactor.insert
                     {
-                        self.user.asserted_insert((
-                            (*k).to_string(),
-                            refactor.engine_default_properties.0.get(k).clone()
-                        ));
+                        let default = refactor.engine_default_properties.0.get(k);
+
+                        // A custom property with the same key is promoted if it has the same type.
+                        let value = self
+                            .custom
+                            .shift_remove(*k)
+                            .filter(|value| value.eq_tag(default))
+                            .unwrap_or_else(|| default.clone());
+
+                        self.user.asserted_insert(((*k).to_string(), value));
                     }
                 }
             }
         }};
     }
 
-    //=======================================================================//
-    // CONSTANTS
-    //
-    //=======================================================================//
-
-    pub(in crate::map) const COLLISION_LABEL: &str = "collision";
-    const COLLISION_DEFAULT: Value = Value::Bool(true);
-
-    pub(in crate::map) const ANGLE_LABEL: &str = "angle";
-    const ANGLE_DEFAULT: Value = Value::I16(0);
-
-    pub(in crate::map) const HEIGHT_LABEL: &str = "height";
-    const HEIGHT_DEFAULT: Value = Value::I8(0);
-
     //=======================================================================//
     // TRAITS
     //
@@ -436,6 +531,24 @@ pub(in crate::map) mod ui_mod
             key: &str,
             value: &mut Value
         );
+
+        /// Sets the custom property `key` to `value`, or removes it if `value` is `None`.
+        fn set_custom_property(
+            &mut self,
+            drawing_resources: &DrawingResources,
+            grid: &Grid,
+            key: &str,
+            value: Option<&Value>
+        );
+
+        /// Adds the custom property `key` with value `value` where it is not already present.
+        fn insert_custom_property(
+            &mut self,
+            drawing_resources: &DrawingResources,
+            grid: &Grid,
+            key: &str,
+            value: &Value
+        );
     }
 
     //=======================================================================//
@@ -0,0 +1,265 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use hill_vacuum_shared::continue_if_none;
+
+use super::value::Value;
+use crate::Id;
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// The constraints the values of a property must satisfy.
+/// A schema without constraints accepts any value.
+#[must_use]
+#[derive(Clone, Debug, Default)]
+pub struct PropertySchema
+{
+    /// A [`Value`] of the type the values must have, if any.
+    value_type: Option<Value>,
+    /// The inclusive range numeric values must be in, if any.
+    range:      Option<(f64, f64)>,
+    /// The values allowed, if not empty.
+    values:     Vec<Value>,
+    /// Whether the values not satisfying the schema should be reported as warnings instead of
+    /// errors.
+    lenient:    bool
+}
+
+impl PropertySchema
+{
+    /// Returns a new [`PropertySchema`] without constraints.
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Requires the values to be of the same type as `value`.
+    #[inline]
+    pub fn value_type(mut self, value: impl Into<Value>) -> Self
+    {
+        self.value_type = Some(value.into());
+        self
+    }
+
+    /// Requires the values to be numbers between `min` and `max`, inclusive.
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    #[inline]
+    pub fn range(mut self, min: f64, max: f64) -> Self
+    {
+        assert!(min <= max, "Min is greater than max.");
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Requires the values to be equal to one of `values`.
+    #[inline]
+    pub fn one_of<I, V>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Value>
+    {
+        self.values = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Reports the values not satisfying the schema as warnings instead of errors, which means
+    /// the editor will not reject them.
+    #[inline]
+    pub const fn lenient(mut self) -> Self
+    {
+        self.lenient = true;
+        self
+    }
+
+    /// Whether the values not satisfying the schema are reported as warnings instead of errors.
+    #[inline]
+    #[must_use]
+    pub const fn is_lenient(&self) -> bool { self.lenient }
+
+    /// Checks whether `value` satisfies the schema.
+    /// # Errors
+    /// Returns a description of the first unsatisfied constraint.
+    #[inline]
+    pub fn validate(&self, value: &Value) -> Result<(), String>
+    {
+        if let Some(value_type) = &self.value_type
+        {
+            if !value_type.same_type(value)
+            {
+                return Err(format!(
+                    "{value} is not of type {}, but {}",
+                    value_type.type_str(),
+                    value.type_str()
+                ));
+            }
+        }
+
+        if let Some((min, max)) = self.range
+        {
+            let number = match value.as_f64()
+            {
+                Some(number) => number,
+                None => return Err(format!("{value} is not a number"))
+            };
+
+            if number < min || number > max
+            {
+                return Err(format!("{value} is outside the range [{min}, {max}]"));
+            }
+        }
+
+        if !self.values.is_empty() && !self.values.iter().any(|v| v.same_value(value))
+        {
+            let mut values = String::new();
+
+            for v in &self.values
+            {
+                values.push_str(&v.to_string());
+                values.push_str(", ");
+            }
+
+            values.truncate(values.len() - 2);
+            return Err(format!("{value} is not one of {values}"));
+        }
+
+        Ok(())
+    }
+}
+
+//=======================================================================//
+
+/// The [`PropertySchema`]s of the properties of the brushes and things.
+#[must_use]
+#[derive(Clone, Debug, Default)]
+pub struct PropertySchemas
+{
+    /// The schemas of the brush properties.
+    brushes: Vec<(&'static str, PropertySchema)>,
+    /// The schemas of the thing properties.
+    things:  Vec<(&'static str, PropertySchema)>
+}
+
+impl PropertySchemas
+{
+    /// Returns a new empty [`PropertySchemas`].
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Inserts `schema` in `schemas` for the property `key`, replacing the previous one, if any.
+    #[inline]
+    fn insert(
+        schemas: &mut Vec<(&'static str, PropertySchema)>,
+        key: &'static str,
+        schema: PropertySchema
+    )
+    {
+        match schemas.iter_mut().find(|(k, _)| *k == key)
+        {
+            Some((_, s)) => *s = schema,
+            None => schemas.push((key, schema))
+        };
+    }
+
+    /// Returns the schema associated with `key` in `schemas`, if any.
+    #[inline]
+    fn get<'a>(
+        schemas: &'a [(&'static str, PropertySchema)],
+        key: &str
+    ) -> Option<&'a PropertySchema>
+    {
+        schemas.iter().find_map(|(k, s)| (*k == key).then_some(s))
+    }
+
+    /// Calls `f` for each property whose value, returned by `get`, does not satisfy its schema in
+    /// `schemas`.
+    #[inline]
+    fn check<'a>(
+        schemas: &'a [(&'static str, PropertySchema)],
+        get: impl Fn(&str) -> Option<&'a Value>,
+        mut f: impl FnMut(&'static str, &'a PropertySchema, String)
+    )
+    {
+        for (key, schema) in schemas
+        {
+            if let Err(err) = schema.validate(continue_if_none!(get(key)))
+            {
+                f(key, schema, err);
+            }
+        }
+    }
+
+    /// Sets `schema` as the schema of the brush property `key`.
+    #[inline]
+    pub fn brush(mut self, key: &'static str, schema: PropertySchema) -> Self
+    {
+        Self::insert(&mut self.brushes, key, schema);
+        self
+    }
+
+    /// Sets `schema` as the schema of the thing property `key`.
+    #[inline]
+    pub fn thing(mut self, key: &'static str, schema: PropertySchema) -> Self
+    {
+        Self::insert(&mut self.things, key, schema);
+        self
+    }
+
+    /// Returns the schema of the brush property `key`, if any.
+    #[inline]
+    #[must_use]
+    pub fn brush_schema(&self, key: &str) -> Option<&PropertySchema>
+    {
+        Self::get(&self.brushes, key)
+    }
+
+    /// Returns the schema of the thing property `key`, if any.
+    #[inline]
+    #[must_use]
+    pub fn thing_schema(&self, key: &str) -> Option<&PropertySchema>
+    {
+        Self::get(&self.things, key)
+    }
+
+    /// Calls `f` for each brush property, returned by `get`, that does not satisfy its schema.
+    #[inline]
+    pub(in crate::map) fn check_brush<'a>(
+        &'a self,
+        get: impl Fn(&str) -> Option<&'a Value>,
+        f: impl FnMut(&'static str, &'a PropertySchema, String)
+    )
+    {
+        Self::check(&self.brushes, get, f);
+    }
+
+    /// Calls `f` for each thing property, returned by `get`, that does not satisfy its schema.
+    #[inline]
+    pub(in crate::map) fn check_thing<'a>(
+        &'a self,
+        get: impl Fn(&str) -> Option<&'a Value>,
+        f: impl FnMut(&'static str, &'a PropertySchema, String)
+    )
+    {
+        Self::check(&self.things, get, f);
+    }
+}
+
+//=======================================================================//
+
+/// A property of an entity whose value does not satisfy its [`PropertySchema`].
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct PropertyViolation
+{
+    /// The [`Id`] of the entity.
+    pub id:      Id,
+    /// The key of the property.
+    pub key:     &'static str,
+    /// The description of the unsatisfied constraint.
+    pub error:   String,
+    /// Whether the [`PropertySchema`] is lenient, so the violation is a warning.
+    pub lenient: bool
+}
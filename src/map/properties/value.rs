@@ -3,6 +3,8 @@
 //
 //=======================================================================//
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 //=======================================================================//
@@ -27,7 +29,8 @@ macro_rules! for_each_value {
             I128, i128, "i128", 0,
             F32, f32, "f32", 0f32,
             F64, f64, "f64", 0f64,
-            String, String, "String", String::new()
+            String, String, "String", String::new(),
+            Color, Rgba, "Color", Rgba::default()
         );
     };
 
@@ -46,7 +49,8 @@ macro_rules! for_each_value {
             I128, i128, "i128", 0,
             F32, f32, "f32", 0f32,
             F64, f64, "f64", 0f64,
-            String, String, "String", String::new()
+            String, String, "String", String::new(),
+            Color, Rgba, "Color", Rgba::default()
         )
     }
 }
@@ -69,6 +73,59 @@ macro_rules! to_value {
     )+};
 }
 
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// An RGBA color, written as a `#rrggbbaa` hexadecimal string.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rgba(pub [u8; 4]);
+
+impl Default for Rgba
+{
+    #[inline]
+    fn default() -> Self { Self([255; 4]) }
+}
+
+impl std::fmt::Display for Rgba
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let [r, g, b, a] = self.0;
+        write!(f, "#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+impl FromStr for Rgba
+{
+    type Err = ();
+
+    /// Parses a `#rrggbb` or `#rrggbbaa` hexadecimal string, with optional `#`.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        let s = s.strip_prefix('#').unwrap_or(s);
+
+        if !matches!(s.len(), 6 | 8) || !s.is_ascii()
+        {
+            return Err(());
+        }
+
+        let mut rgba = [255; 4];
+
+        for (i, c) in rgba.iter_mut().enumerate().take(s.len() / 2)
+        {
+            *c = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| ())?;
+        }
+
+        Ok(Self(rgba))
+    }
+}
+
 //=======================================================================//
 // ENUMS
 //
@@ -76,7 +133,7 @@ macro_rules! to_value {
 
 #[must_use]
 #[derive(Clone, Serialize, Deserialize)]
-/// A primitive value or a [`String`].
+/// A primitive value, a [`String`], or an [`Rgba`] color.
 pub enum Value
 {
     /// Bool.
@@ -106,7 +163,9 @@ pub enum Value
     /// f64.
     F64(f64),
     /// String.
-    String(String)
+    String(String),
+    /// RGBA color.
+    Color(Rgba)
 }
 
 for_each_value!(to_value);
@@ -149,6 +208,76 @@ impl std::fmt::Display for Value
     }
 }
 
+impl Value
+{
+    /// Returns the name of the type of `self`.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) const fn type_str(&self) -> &'static str
+    {
+        macro_rules! value {
+            ($($value:ident, $t:ty, $str:literal, $default:expr),+) => {
+                match self
+                {
+                    $(Self::$value(_) => $str,)+
+                }
+            }
+        }
+
+        for_each_value!(ret, value)
+    }
+
+    /// Whether `self` and `other` are of the same type.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn same_type(&self, other: &Self) -> bool
+    {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Whether `self` and `other` are of the same type and have the same value.
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn same_value(&self, other: &Self) -> bool
+    {
+        macro_rules! cmp {
+            ($($value:ident, $t:ty, $str:literal, $default:expr),+) => {
+                match (self, other)
+                {
+                    $((Self::$value(l0), Self::$value(r0)) => l0 == r0,)+
+                    _ => false
+                }
+            }
+        }
+
+        for_each_value!(ret, cmp)
+    }
+
+    /// Returns the value of `self` as a [`f64`], if it is a number.
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    #[must_use]
+    pub(in crate::map) fn as_f64(&self) -> Option<f64>
+    {
+        Some(match self
+        {
+            Self::Bool(_) | Self::String(_) | Self::Color(_) => return None,
+            Self::U8(value) => f64::from(*value),
+            Self::U16(value) => f64::from(*value),
+            Self::U32(value) => f64::from(*value),
+            Self::U64(value) => *value as f64,
+            Self::U128(value) => *value as f64,
+            Self::I8(value) => f64::from(*value),
+            Self::I16(value) => f64::from(*value),
+            Self::I32(value) => f64::from(*value),
+            Self::I64(value) => *value as f64,
+            Self::I128(value) => *value as f64,
+            Self::F32(value) => f64::from(*value),
+            Self::F64(value) => *value
+        })
+    }
+}
+
 //=======================================================================//
 // UI
 //
@@ -166,7 +295,7 @@ pub(in crate::map) mod ui_mod
 
     use hill_vacuum_shared::match_or_panic;
 
-    use super::Value;
+    use super::{Rgba, Value};
     use crate::utils::misc::ReplaceValue;
 
     //=======================================================================//
@@ -204,10 +333,11 @@ pub(in crate::map) mod ui_mod
     impl Value
     {
         pub(in crate::map) const BOOL_TAG: u8 = 0;
+        pub(in crate::map) const COLOR_TAG: u8 = 14;
 
         #[inline]
         #[must_use]
-        pub(in crate::map) fn tag(&self) -> u8
+        pub(in crate::map) const fn tag(&self) -> u8
         {
             match self
             {
@@ -224,30 +354,15 @@ pub(in crate::map) mod ui_mod
                 Value::I128(_) => 10,
                 Value::F32(_) => 11,
                 Value::F64(_) => 12,
-                Value::String(_) => 13
+                Value::String(_) => 13,
+                Value::Color(_) => Self::COLOR_TAG
             }
         }
 
         /// Whether `self` and `other` have the same [`Discriminant`].
         #[inline]
         #[must_use]
-        pub(in crate::map) fn eq_tag(&self, other: &Self) -> bool { self.tag() == other.tag() }
-
-        #[inline]
-        #[must_use]
-        pub(in crate::map) fn type_str(&self) -> &'static str
-        {
-            macro_rules! value {
-                ($($value:ident, $t:ty, $str:literal, $default:expr),+) => {
-                    match self
-                    {
-                        $(Self::$value(_) => $str,)+
-                    }
-                }
-            }
-
-            for_each_value!(ret, value)
-        }
+        pub(in crate::map) const fn eq_tag(&self, other: &Self) -> bool { self.tag() == other.tag() }
 
         /// Sets `self` to `value`. Returns the previous value if different.
         #[inline]
@@ -0,0 +1,592 @@
+//=======================================================================//
+// IMPORTS
+//
+//=======================================================================//
+
+use std::{iter::Peekable, path::Path};
+
+use glam::{DVec2, DVec3, Vec2};
+use hill_vacuum_shared::return_if_none;
+
+use crate::{
+    map::{drawer::texture::TextureSettings, MAP_HALF_SIZE},
+    utils::math::{points::are_vxs_ccw, AroundEqual}
+};
+
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The tolerance used to check whether a point is inside the half-spaces of a brush.
+const EPSILON: f64 = 1e-3;
+
+//=======================================================================//
+// ENUMS
+//
+//=======================================================================//
+
+/// How the 3D brushes of a Quake map are converted into 2D polygons.
+#[derive(Clone, Copy)]
+pub(in crate::map) enum QuakeSlice
+{
+    /// The brushes are sliced by the horizontal plane at the contained height.
+    Height(f32),
+    /// The map is cut into layers of the same thickness and each brush is sliced by the middle
+    /// plane of every layer it spans. The index of the layer is used as the draw height of the
+    /// texture.
+    Layers
+    {
+        /// The height of the bottom of the layer with index 0.
+        height:    f32,
+        /// The thickness of the layers.
+        thickness: f32
+    }
+}
+
+//=======================================================================//
+
+/// A token of a Quake map file.
+#[derive(Clone, Copy, PartialEq)]
+enum Token<'a>
+{
+    /// A string enclosed in quotes.
+    Quoted(&'a str),
+    /// A sequence of non whitespace characters.
+    Word(&'a str)
+}
+
+//=======================================================================//
+// STRUCTS
+//
+//=======================================================================//
+
+/// A 2D brush generated from a brush of a Quake map.
+pub(in crate::map) struct QuakeBrush
+{
+    /// The vertexes, in counterclockwise order.
+    pub vertexes: Vec<Vec2>,
+    /// The texture of the face of the brush pointing upward.
+    pub texture:  TextureSettings
+}
+
+//=======================================================================//
+
+/// The iterator returning the [`Token`]s of a Quake map file, skipping the comments.
+struct Tokens<'a>(&'a str);
+
+impl<'a> Iterator for Tokens<'a>
+{
+    type Item = Token<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop
+        {
+            self.0 = self.0.trim_start();
+
+            if !self.0.starts_with("//")
+            {
+                break;
+            }
+
+            self.0 = self.0.find('\n').map_or("", |i| &self.0[i..]);
+        }
+
+        if self.0.is_empty()
+        {
+            return None;
+        }
+
+        if let Some(rest) = self.0.strip_prefix('"')
+        {
+            let end = rest.find('"').unwrap_or(rest.len());
+            self.0 = rest.get(end + 1..).unwrap_or_default();
+            return Token::Quoted(&rest[..end]).into();
+        }
+
+        let end = self.0.find(char::is_whitespace).unwrap_or(self.0.len());
+        let (word, rest) = self.0.split_at(end);
+        self.0 = rest;
+        Token::Word(word).into()
+    }
+}
+
+//=======================================================================//
+
+/// A face of a brush of a Quake map.
+struct Face
+{
+    /// The normal of the plane, pointing outside the brush.
+    normal:   DVec3,
+    /// The distance of the plane from the origin along the normal.
+    distance: f64,
+    /// The name of the texture.
+    texture:  String,
+    /// The offset of the texture, in texels.
+    offset:   Vec2,
+    /// The rotation of the texture, in degrees.
+    angle:    f32,
+    /// The scale of the texture.
+    scale:    Vec2
+}
+
+impl Face
+{
+    /// Whether `point` is inside the half-space of the face.
+    #[inline]
+    #[must_use]
+    fn contains(&self, point: DVec3) -> bool { self.normal.dot(point) - self.distance <= EPSILON }
+
+    /// Returns the [`TextureSettings`] of the face seen from above, with draw height `height`.
+    #[inline]
+    fn texture_settings(&self, height: i8) -> TextureSettings
+    {
+        let angle = (-self.angle).rem_euclid(360f32);
+
+        TextureSettings::from_parts(
+            self.texture.clone(),
+            Vec2::new(self.offset.x * self.scale.x, -self.offset.y * self.scale.y),
+            self.scale,
+            if angle < 360f32 { angle } else { 0f32 },
+            height
+        )
+    }
+}
+
+//=======================================================================//
+// FUNCTIONS
+//
+//=======================================================================//
+
+/// Reads the Quake or Valve 220 `.map` file at `path` and converts its brushes into 2D polygons
+/// as described by `slice`, textured with the texture of their face pointing upward.
+/// Returns the generated brushes and the amount of brushes that could not be converted because
+/// they are not supported, invalid, or out of the map bounds.
+/// # Errors
+/// Returns an error if the file could not be read or is not properly formatted.
+#[inline]
+pub(in crate::map) fn read_quake_map(
+    path: &Path,
+    slice: QuakeSlice
+) -> Result<(Vec<QuakeBrush>, usize), &'static str>
+{
+    let text = std::fs::read_to_string(path).map_err(|_| "Could not read the file")?;
+    let mut tokens = Tokens(&text).peekable();
+    let mut brushes = Vec::new();
+    let mut skipped = 0;
+
+    // Entities.
+    while let Some(token) = tokens.next()
+    {
+        if token != Token::Word("{")
+        {
+            return Err("Invalid Quake map entity");
+        }
+
+        loop
+        {
+            match tokens.next().ok_or("Unexpected end of the Quake map")?
+            {
+                Token::Word("}") => break,
+                Token::Word("{") =>
+                {
+                    match brush_faces(&mut tokens)?
+                    {
+                        Some(faces) if convert_brush(&faces, slice, &mut brushes) => (),
+                        _ => skipped += 1
+                    };
+                },
+                Token::Quoted(_) =>
+                {
+                    // Key/value pair.
+                    _ = tokens.next().ok_or("Unexpected end of the Quake map")?;
+                },
+                Token::Word(_) => return Err("Invalid Quake map entity")
+            };
+        }
+    }
+
+    Ok((brushes, skipped))
+}
+
+//=======================================================================//
+
+/// Reads the faces of a brush whose opening brace was already read. Returns `None` if the brush
+/// is not made of planes, such as a patch, or if it has degenerate planes.
+#[inline]
+fn brush_faces(tokens: &mut Peekable<Tokens>) -> Result<Option<Vec<Face>>, &'static str>
+{
+    let mut faces = Vec::new();
+    let mut valid = true;
+
+    loop
+    {
+        match tokens.next().ok_or("Unexpected end of the Quake map")?
+        {
+            Token::Word("}") => break,
+            Token::Word("(") =>
+            {
+                match face(tokens)?
+                {
+                    Some(face) => faces.push(face),
+                    None => valid = false
+                };
+            },
+            _ =>
+            {
+                // Unsupported brush format, skip the whole block.
+                skip_block(tokens)?;
+                valid = false;
+            }
+        };
+    }
+
+    Ok((valid && faces.len() >= 4).then_some(faces))
+}
+
+//=======================================================================//
+
+/// Skips the tokens until the closing brace of the block containing them.
+#[inline]
+fn skip_block(tokens: &mut Peekable<Tokens>) -> Result<(), &'static str>
+{
+    let mut depth = 0usize;
+
+    loop
+    {
+        match tokens.next().ok_or("Unexpected end of the Quake map")?
+        {
+            Token::Word("{") => depth += 1,
+            Token::Word("}") =>
+            {
+                if depth == 1
+                {
+                    return Ok(());
+                }
+
+                depth = depth.saturating_sub(1);
+            },
+            _ => ()
+        };
+    }
+}
+
+//=======================================================================//
+
+/// Reads a face of a brush whose first opening parenthesis was already read. Returns `None` if the
+/// plane of the face is degenerate.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn face(tokens: &mut Peekable<Tokens>) -> Result<Option<Face>, &'static str>
+{
+    let p0 = point_coordinates(tokens)?;
+    expect(tokens, "(")?;
+    let p1 = point_coordinates(tokens)?;
+    expect(tokens, "(")?;
+    let p2 = point_coordinates(tokens)?;
+
+    let texture = match tokens.next().ok_or("Unexpected end of the Quake map")?
+    {
+        Token::Quoted(name) | Token::Word(name) => name.to_owned()
+    };
+
+    let (offset, angle) = if tokens.peek() == Some(&Token::Word("["))
+    {
+        // Valve 220 format, the angle is deduced from the horizontal texture axis.
+        let mut axes = [DVec3::ZERO; 2];
+        let mut offset = Vec2::ZERO;
+
+        for (axis, offset) in axes.iter_mut().zip([&mut offset.x, &mut offset.y])
+        {
+            expect(tokens, "[")?;
+            *axis = DVec3::new(number(tokens)?, number(tokens)?, number(tokens)?);
+            *offset = number(tokens)? as f32;
+            expect(tokens, "]")?;
+        }
+
+        _ = number(tokens)?;
+        (offset, axes[0].y.atan2(axes[0].x).to_degrees() as f32)
+    }
+    else
+    {
+        (
+            Vec2::new(number(tokens)? as f32, number(tokens)? as f32),
+            number(tokens)? as f32
+        )
+    };
+
+    let mut scale = Vec2::new(number(tokens)? as f32, number(tokens)? as f32);
+
+    for value in [&mut scale.x, &mut scale.y]
+    {
+        if value.around_equal_narrow(&0f32)
+        {
+            *value = 1f32;
+        }
+    }
+
+    // Quake 2 and 3 contents, flags, and value.
+    while matches!(tokens.peek(), Some(Token::Word(word)) if *word != "(" && *word != "}")
+    {
+        _ = tokens.next();
+    }
+
+    let normal = (p0 - p1).cross(p2 - p1);
+
+    if normal.length_squared() < f64::EPSILON
+    {
+        return Ok(None);
+    }
+
+    let normal = normal.normalize();
+
+    Ok(Some(Face {
+        normal,
+        distance: normal.dot(p0),
+        texture,
+        offset,
+        angle,
+        scale
+    }))
+}
+
+//=======================================================================//
+
+/// Reads the coordinates of a point and its closing parenthesis.
+#[inline]
+fn point_coordinates(tokens: &mut Peekable<Tokens>) -> Result<DVec3, &'static str>
+{
+    let point = DVec3::new(number(tokens)?, number(tokens)?, number(tokens)?);
+    expect(tokens, ")")?;
+    Ok(point)
+}
+
+//=======================================================================//
+
+/// Reads a number.
+#[inline]
+fn number(tokens: &mut Peekable<Tokens>) -> Result<f64, &'static str>
+{
+    match tokens.next().ok_or("Unexpected end of the Quake map")?
+    {
+        Token::Word(word) => word.parse().map_err(|_| "Invalid number in the Quake map"),
+        Token::Quoted(_) => Err("Invalid number in the Quake map")
+    }
+}
+
+//=======================================================================//
+
+/// Reads the token `word`.
+#[inline]
+fn expect(tokens: &mut Peekable<Tokens>, word: &str) -> Result<(), &'static str>
+{
+    match tokens.next().ok_or("Unexpected end of the Quake map")?
+    {
+        Token::Word(w) if w == word => Ok(()),
+        _ => Err("Invalid Quake map brush")
+    }
+}
+
+//=======================================================================//
+
+/// Converts the brush made of `faces` into 2D brushes as described by `slice`, and pushes them
+/// into `brushes`. Returns false if the brush is invalid or out of the map bounds.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+#[must_use]
+fn convert_brush(faces: &[Face], slice: QuakeSlice, brushes: &mut Vec<QuakeBrush>) -> bool
+{
+    let top = faces.iter().max_by(|a, b| a.normal.z.total_cmp(&b.normal.z)).unwrap();
+    let (min, max) = return_if_none!(height_range(faces), false);
+
+    let mut push = |z: f64, height: i8| {
+        let vertexes = return_if_none!(slice_brush(faces, z), true);
+
+        if vertexes
+            .iter()
+            .any(|vx| vx.x.abs() > MAP_HALF_SIZE || vx.y.abs() > MAP_HALF_SIZE)
+        {
+            return false;
+        }
+
+        brushes.push(QuakeBrush {
+            vertexes,
+            texture: top.texture_settings(height)
+        });
+        true
+    };
+
+    match slice
+    {
+        QuakeSlice::Height(height) =>
+        {
+            let height = f64::from(height);
+
+            if height < min - EPSILON || height > max + EPSILON
+            {
+                return true;
+            }
+
+            push(height, 0)
+        },
+        QuakeSlice::Layers { height, thickness } =>
+        {
+            let (height, thickness) = (f64::from(height), f64::from(thickness.max(1f32)));
+            let first = ((min - height) / thickness).floor() as i64;
+            let last = (((max - height) / thickness).ceil() as i64 - 1).max(first);
+
+            (first..=last).all(|layer| {
+                push(
+                    height + (layer as f64 + 0.5) * thickness,
+                    layer.clamp(i64::from(i8::MIN), i64::from(i8::MAX)) as i8
+                )
+            })
+        }
+    }
+}
+
+//=======================================================================//
+
+/// Returns the minimum and maximum height of the vertexes of the brush made of `faces`, or `None`
+/// if the brush has no volume.
+#[inline]
+#[must_use]
+fn height_range(faces: &[Face]) -> Option<(f64, f64)>
+{
+    let mut range = None::<(f64, f64)>;
+
+    for (i, a) in faces.iter().enumerate()
+    {
+        for (j, b) in faces.iter().enumerate().skip(i + 1)
+        {
+            for c in faces.iter().skip(j + 1)
+            {
+                let denominator = a.normal.dot(b.normal.cross(c.normal));
+
+                if denominator.abs() < EPSILON
+                {
+                    continue;
+                }
+
+                let point = (b.normal.cross(c.normal) * a.distance +
+                    c.normal.cross(a.normal) * b.distance +
+                    a.normal.cross(b.normal) * c.distance) /
+                    denominator;
+
+                if !faces.iter().all(|face| face.contains(point))
+                {
+                    continue;
+                }
+
+                range = Some(match range
+                {
+                    Some((min, max)) => (min.min(point.z), max.max(point.z)),
+                    None => (point.z, point.z)
+                });
+            }
+        }
+    }
+
+    range.filter(|(min, max)| max - min > EPSILON)
+}
+
+//=======================================================================//
+
+/// Returns the counterclockwise vertexes of the section of the brush made of `faces` cut by the
+/// horizontal plane at height `z`, if any.
+#[inline]
+#[must_use]
+fn slice_brush(faces: &[Face], z: f64) -> Option<Vec<Vec2>>
+{
+    /// Returns the portion of `polygon` on the inner side of the line with normal `normal` at
+    /// distance `distance` from the origin.
+    #[inline]
+    #[must_use]
+    fn clip(polygon: &[DVec2], normal: DVec2, distance: f64) -> Vec<DVec2>
+    {
+        let mut output = Vec::with_capacity(polygon.len() + 1);
+
+        for (i, a) in polygon.iter().enumerate()
+        {
+            let b = polygon[(i + 1) % polygon.len()];
+            let (da, db) = (normal.dot(*a) - distance, normal.dot(b) - distance);
+
+            if da <= 0f64
+            {
+                output.push(*a);
+            }
+
+            if (da < 0f64 && db > 0f64) || (da > 0f64 && db < 0f64)
+            {
+                output.push(*a + (b - *a) * (da / (da - db)));
+            }
+        }
+
+        output
+    }
+
+    let limit = f64::from(MAP_HALF_SIZE) * 2f64;
+    let mut polygon = vec![
+        DVec2::new(-limit, -limit),
+        DVec2::new(limit, -limit),
+        DVec2::new(limit, limit),
+        DVec2::new(-limit, limit),
+    ];
+
+    for face in faces
+    {
+        let normal = face.normal.truncate();
+        let distance = face.distance - face.normal.z * z;
+
+        if normal.length_squared() < EPSILON * EPSILON
+        {
+            // Horizontal face.
+            if distance < -EPSILON
+            {
+                return None;
+            }
+
+            continue;
+        }
+
+        polygon = clip(&polygon, normal, distance);
+
+        if polygon.len() < 3
+        {
+            return None;
+        }
+    }
+
+    let mut vertexes = polygon.into_iter().map(|vx| vx.as_vec2()).collect::<Vec<_>>();
+    vertexes.dedup_by(|a, b| a.around_equal(b));
+
+    while vertexes.len() > 1 && vertexes[0].around_equal(&vertexes[vertexes.len() - 1])
+    {
+        vertexes.pop();
+    }
+
+    // Remove the vertexes which do not describe a corner.
+    loop
+    {
+        let len = vertexes.len();
+
+        if len < 3
+        {
+            return None;
+        }
+
+        match (0..len).find(|i| {
+            !are_vxs_ccw(&[
+                vertexes[(i + len - 1) % len],
+                vertexes[*i],
+                vertexes[(i + 1) % len]
+            ])
+        })
+        {
+            Some(i) => _ = vertexes.remove(i),
+            None => return vertexes.into()
+        };
+    }
+}
@@ -3,22 +3,39 @@
 //
 //=======================================================================//
 
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    time::SystemTime
+};
 
 use bevy_egui::egui;
 use configparser::ini::Ini;
 use glam::UVec2;
-use hill_vacuum_shared::{continue_if_err, continue_if_none};
+use hill_vacuum_shared::{continue_if_err, continue_if_none, return_if_none};
 
 use super::{HardcodedThings, Thing, ThingId};
 use crate::{
+    error_message,
     map::{drawer::drawing_resources::DrawingResources, hash_map},
     utils::{
         collections::{index_map, HashMap, IndexMap},
         misc::TakeValue
-    }
+    },
+    Value
 };
 
+//=======================================================================//
+// CONSTANTS
+//
+//=======================================================================//
+
+/// The directory where the files defining things are located.
+const THINGS_DIR: &str = "assets/things/";
+/// The extension of the JSON files defining things.
+const JSON_EXTENSION: &str = "json";
+/// The prefix of the keys of the properties in the ini files defining things.
+const PROPERTY_PREFIX: &str = "property.";
+
 //=======================================================================//
 // STRUCTS
 //
@@ -30,7 +47,10 @@ pub(in crate::map) struct UiThing<'a>
     pub index:    usize,
     pub name:     &'a str,
     pub tex_id:   egui::TextureId,
-    pub tex_size: UVec2
+    pub tex_size: UVec2,
+    pub width:    f32,
+    pub height:   f32,
+    pub category: Option<&'a str>
 }
 
 //=======================================================================//
@@ -46,7 +66,10 @@ pub(in crate::map) struct ThingsCatalog
     /// The [`Thing`] selected in the UI gallery, if any.
     selected_thing:   Option<usize>,
     ///The [`Thing`] used to display errors.
-    error:            Thing
+    error:            Thing,
+    /// The paths of the files in the things folder when the [`Thing`]s were last loaded,
+    /// associated with their modification time.
+    files:            HashMap<PathBuf, SystemTime>
 }
 
 impl Default for ThingsCatalog
@@ -58,7 +81,8 @@ impl Default for ThingsCatalog
             hardcoded_things: hash_map![],
             things:           index_map![],
             selected_thing:   None,
-            error:            Self::error_thing()
+            error:            Self::error_thing(),
+            files:            hash_map![]
         }
     }
 }
@@ -81,6 +105,7 @@ impl ThingsCatalog
             .into_iter()
             .map(|thing| (thing.id(), thing))
             .collect();
+        let files = Self::files_modification_times();
         let things = Self::loaded_things(&h_things);
         let selected_thing = (!things.is_empty()).then_some(0);
 
@@ -88,7 +113,8 @@ impl ThingsCatalog
             hardcoded_things: h_things,
             things,
             selected_thing,
-            error: Self::error_thing()
+            error: Self::error_thing(),
+            files
         }
     }
 
@@ -98,87 +124,253 @@ impl ThingsCatalog
 
     /// Combines the hardcoded and file loaded things into a single [`IndexedMap`].
     /// If a thing loaded from file has the same [`ThingId`] as an hardcoded one the latter will be
-    /// overwritten. Things files are searched in the `assets/things/` folder.
+    /// overwritten. Things files are searched in the `assets/things/` folder, the `.json` ones
+    /// are read as JSON files and all the others as ini files.
     #[inline]
     fn loaded_things(hardcoded_things: &HashMap<ThingId, Thing>) -> IndexMap<ThingId, Thing>
     {
-        /// The directory where ini defined things are located.
-        const THINGS_DIR: &str = "assets/things/";
-
-        /// Gathers all ini files.
+        /// Gathers the paths of all the files.
         #[inline]
-        fn recurse(path: &Path, inis: &mut Vec<Ini>)
+        fn recurse(path: &Path, paths: &mut Vec<PathBuf>)
         {
             if path.is_file()
             {
-                let mut ini = Ini::new_cs();
-
-                if ini.load(path).is_ok()
-                {
-                    inis.push(ini);
-                }
-
+                paths.push(path.to_owned());
                 return;
             }
 
-            for entry in std::fs::read_dir(path).unwrap()
+            for entry in std::fs::read_dir(path).into_iter().flatten()
             {
-                recurse(&entry.unwrap().path(), inis);
+                recurse(&continue_if_err!(entry).path(), paths);
             }
         }
 
         std::fs::create_dir_all(THINGS_DIR).ok();
-        let mut configs = Vec::new();
-        recurse(Path::new(THINGS_DIR), &mut configs);
+        let mut paths = Vec::new();
+        recurse(Path::new(THINGS_DIR), &mut paths);
+        paths.sort();
 
         let mut things = hardcoded_things.values().cloned().collect::<Vec<_>>();
 
-        for ini in configs
+        for path in paths
         {
-            'outer: for (name, values) in ini.get_map_ref()
+            let file_things = if path.extension().is_some_and(|ext| ext == JSON_EXTENSION)
             {
-                /// Returns the value associated to `key` of type `t`, it it exists.
-                /// Otherwise the thing loading is aborted.
-                macro_rules! value {
-                    ($key:literal, $t:ty) => {
-                        continue_if_err!(continue_if_none!(values.get($key))
-                            .as_ref()
-                            .unwrap()
-                            .parse::<$t>())
-                    };
-                }
+                Self::json_things(&path)
+            }
+            else
+            {
+                Self::ini_things(&path)
+            };
+
+            for new_thing in file_things
+            {
+                match things.iter_mut().find(|thing| thing.id() == new_thing.id())
+                {
+                    Some(thing) => *thing = new_thing,
+                    None => things.push(new_thing)
+                };
+            }
+        }
+
+        things.sort_by(|a, b| a.category().cmp(&b.category()).then_with(|| a.name().cmp(b.name())));
+        things.into_iter().map(|thing| (thing.id, thing)).collect()
+    }
+
+    /// Returns the [`Thing`]s defined in the ini file at `path`, one per section. The things with
+    /// invalid or missing values are skipped.
+    #[inline]
+    #[must_use]
+    fn ini_things(path: &Path) -> Vec<Thing>
+    {
+        let mut ini = Ini::new_cs();
+
+        if ini.load(path).is_err()
+        {
+            return Vec::new();
+        }
+
+        let mut things = Vec::new();
+
+        for (name, values) in ini.get_map_ref()
+        {
+            /// Returns the value associated to `key` of type `t`, it it exists.
+            /// Otherwise the thing loading is aborted.
+            macro_rules! value {
+                ($key:literal, $t:ty) => {
+                    continue_if_err!(continue_if_none!(values.get($key))
+                        .as_ref()
+                        .unwrap()
+                        .parse::<$t>())
+                };
+            }
 
-                let id = value!("id", u16);
+            let id = value!("id", u16);
+            let width = value!("width", f32);
+            let height = value!("height", f32);
 
-                if id == Self::ERROR_ID
+            if id == Self::ERROR_ID || width <= 0f32 || height <= 0f32
+            {
+                continue;
+            }
+
+            let mut thing = Thing::new(
+                name,
+                id,
+                width,
+                height,
+                continue_if_none!(values.get("preview")).as_ref().unwrap()
+            );
+
+            // The optional spawn flags, as a comma separated list of names.
+            if let Some(Some(flags)) = values.get("flags")
+            {
+                let flags = flags
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|flag| !flag.is_empty())
+                    .collect::<Vec<_>>();
+
+                if flags.len() > Thing::MAX_FLAGS
                 {
                     continue;
                 }
 
-                let new_thing = Thing::new(
-                    name,
-                    id,
-                    value!("width", f32),
-                    value!("height", f32),
-                    continue_if_none!(values.get("preview")).as_ref().unwrap()
-                );
-                let id = new_thing.id();
+                thing = thing.with_flags(flags);
+            }
 
-                for thing in &mut things
+            if let Some(Some(category)) = values.get("category")
+            {
+                thing = thing.with_category(category);
+            }
+
+            // The optional properties, as `property.name = value` pairs.
+            thing = thing.with_properties(values.iter().filter_map(|(key, value)| {
+                Some((key.strip_prefix(PROPERTY_PREFIX)?, Value::String(value.as_ref()?.clone())))
+            }));
+
+            things.push(thing);
+        }
+
+        things
+    }
+
+    /// Returns the [`Thing`]s defined in the JSON file at `path`, which must contain an array of
+    /// objects. The things with invalid or missing values are skipped.
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    #[must_use]
+    fn json_things(path: &Path) -> Vec<Thing>
+    {
+        use serde_json::Value as Json;
+
+        /// Returns the [`Thing`] described by `definition`, if valid.
+        #[inline]
+        fn thing(definition: &Json) -> Option<Thing>
+        {
+            let definition = definition.as_object()?;
+            let id = u16::try_from(definition.get("id")?.as_u64()?)
+                .ok()
+                .filter(|id| *id != ThingsCatalog::ERROR_ID)?;
+            let width = definition.get("width")?.as_f64()? as f32;
+            let height = definition.get("height")?.as_f64()? as f32;
+
+            if width <= 0f32 || height <= 0f32
+            {
+                return None;
+            }
+
+            let mut thing = Thing::new(
+                definition.get("name")?.as_str()?,
+                id,
+                width,
+                height,
+                definition.get("preview")?.as_str()?
+            );
+
+            if let Some(flags) = definition.get("flags")
+            {
+                let flags = flags
+                    .as_array()?
+                    .iter()
+                    .map(Json::as_str)
+                    .collect::<Option<Vec<_>>>()?;
+
+                if flags.len() > Thing::MAX_FLAGS
                 {
-                    if thing.id() == id
-                    {
-                        *thing = new_thing;
-                        continue 'outer;
+                    return None;
+                }
+
+                thing = thing.with_flags(flags);
+            }
+
+            if let Some(category) = definition.get("category")
+            {
+                thing = thing.with_category(category.as_str()?);
+            }
+
+            if let Some(properties) = definition.get("properties")
+            {
+                thing = thing.with_properties(properties.as_object()?.iter().filter_map(
+                    |(key, value)| {
+                        let value = match value
+                        {
+                            Json::Bool(value) => value.to_string(),
+                            Json::Number(value) => value.to_string(),
+                            Json::String(value) => value.clone(),
+                            _ => return None
+                        };
+
+                        Some((key.as_str(), Value::String(value)))
                     }
+                ));
+            }
+
+            Some(thing)
+        }
+
+        let definitions = match std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Json>(&text).ok())
+        {
+            Some(Json::Array(definitions)) => definitions,
+            _ => return Vec::new()
+        };
+
+        definitions.iter().filter_map(thing).collect()
+    }
+
+    /// Returns the paths of the files in the things folder and its subfolders, associated with
+    /// their modification time.
+    #[inline]
+    #[must_use]
+    fn files_modification_times() -> HashMap<PathBuf, SystemTime>
+    {
+        /// Iterates through the subfolders collecting the modification times of the files.
+        #[inline]
+        fn collect_times_recursive(path: &Path, times: &mut HashMap<PathBuf, SystemTime>)
+        {
+            for child_path in std::fs::read_dir(path)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            {
+                if child_path.is_dir()
+                {
+                    collect_times_recursive(&child_path, times);
+                    continue;
                 }
 
-                things.push(new_thing);
+                let time = continue_if_none!(std::fs::metadata(&child_path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok());
+                times.insert(child_path, time);
             }
         }
 
-        things.sort_by(|a, b| a.name().cmp(b.name()));
-        things.into_iter().map(|thing| (thing.id, thing)).collect()
+        let mut times = hash_map![];
+        collect_times_recursive(Path::new(THINGS_DIR), &mut times);
+        times
     }
 
     //==============================================================
@@ -189,6 +381,11 @@ impl ThingsCatalog
     #[must_use]
     pub fn is_empty(&self) -> bool { self.things.is_empty() }
 
+    /// Returns the amount of [`Thing`]s in the [`ThingsCatalog`].
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize { self.things.len() }
+
     /// The [`Thing`] associated with `thing`, if any.
     #[inline]
     pub fn thing(&self, thing: ThingId) -> Option<&Thing> { self.things.get(&thing) }
@@ -236,10 +433,122 @@ impl ThingsCatalog
         self.selected_thing = index.into();
     }
 
+    /// Checks whether files were added to, modified in, or removed from the things folder since
+    /// the [`Thing`]s were last loaded.
+    /// Returns whether that is the case.
+    #[inline]
+    #[must_use]
+    pub fn check_changed_files(&mut self) -> bool
+    {
+        let files = Self::files_modification_times();
+
+        if files == self.files
+        {
+            return false;
+        }
+
+        self.files = files;
+        true
+    }
+
+    /// Replaces the preview texture `prev` with `new` in the [`Thing`]s and in the files defining
+    /// them.
+    #[inline]
+    pub fn rename_texture(&mut self, prev: &str, new: &str)
+    {
+        for thing in self
+            .hardcoded_things
+            .values_mut()
+            .chain(self.things.values_mut())
+            .filter(|thing| thing.preview == prev)
+        {
+            new.clone_into(&mut thing.preview);
+        }
+
+        for path in self.files.keys()
+        {
+            if let Err(err) = Self::rename_texture_in_file(path, prev, new)
+            {
+                error_message(err);
+            }
+        }
+
+        // The rewritten files must not trigger a reload.
+        self.files = Self::files_modification_times();
+    }
+
+    /// Replaces the preview texture `prev` with `new` in the things defined in the file at
+    /// `path`, which is rewritten only if it references `prev`.
+    /// # Errors
+    /// Returns an error if the file could not be rewritten.
+    #[inline]
+    fn rename_texture_in_file(path: &Path, prev: &str, new: &str) -> Result<(), &'static str>
+    {
+        use serde_json::Value as Json;
+
+        let text = return_if_none!(std::fs::read_to_string(path).ok(), Ok(()));
+
+        let text = if path.extension().is_some_and(|ext| ext == JSON_EXTENSION)
+        {
+            let mut definitions = match serde_json::from_str::<Json>(&text)
+            {
+                Ok(Json::Array(definitions)) => definitions,
+                _ => return Ok(())
+            };
+            let mut renamed = false;
+
+            for preview in definitions
+                .iter_mut()
+                .filter_map(|definition| definition.get_mut("preview"))
+                .filter(|preview| preview.as_str() == Some(prev))
+            {
+                *preview = Json::String(new.to_owned());
+                renamed = true;
+            }
+
+            if !renamed
+            {
+                return Ok(());
+            }
+
+            serde_json::to_string_pretty(&definitions)
+                .map_err(|_| "Error writing things definitions")?
+        }
+        else
+        {
+            // The lines are edited in place to retain the comments and the formatting.
+            let mut renamed = false;
+            let text = text
+                .split_inclusive('\n')
+                .map(|line| {
+                    match line.split_once(['=', ':'])
+                    {
+                        Some((key, value)) if key.trim() == "preview" && value.trim() == prev =>
+                        {
+                            renamed = true;
+                            format!("{}{}", &line[..=key.len()], value.replacen(prev, new, 1))
+                        },
+                        _ => line.to_owned()
+                    }
+                })
+                .collect::<String>();
+
+            if !renamed
+            {
+                return Ok(());
+            }
+
+            text
+        };
+
+        std::fs::write(path, text).map_err(|_| "Error writing things definitions")
+    }
+
     /// Reloads the [`Thing`]s from the files.
     #[inline]
     pub fn reload_things(&mut self)
     {
+        self.files = Self::files_modification_times();
         self.things = Self::loaded_things(&self.hardcoded_things);
         self.selected_thing = (!self.things.is_empty()).then_some(0);
     }
@@ -262,7 +571,10 @@ impl ThingsCatalog
                 index,
                 name: thing.name(),
                 tex_id: texture.0,
-                tex_size: texture.1
+                tex_size: texture.1,
+                width: thing.width(),
+                height: thing.height(),
+                category: thing.category()
             }
         })
     }
@@ -43,19 +43,29 @@ impl ThingId
 pub struct Thing
 {
     /// The name.
-    name:    String,
+    name:       String,
     /// The id.
-    id:      ThingId,
+    id:         ThingId,
     /// The width of the bounding box.
-    width:   f32,
+    width:      f32,
     /// The height of the bounding box.
-    height:  f32,
+    height:     f32,
     /// The name of the texture used to draw a preview.
-    preview: String
+    preview:    String,
+    /// The names of the spawn flags, the index of each one is the index of its bit.
+    flags:      Vec<String>,
+    /// The category the [`Thing`] is listed under in the editor, if any.
+    category:   Option<String>,
+    /// The values the properties of the new [`ThingInstance`](crate::ThingInstance)s are set to,
+    /// instead of the default ones.
+    properties: HashMap<String, Value>
 }
 
 impl Thing
 {
+    /// The maximum amount of spawn flags a [`Thing`] can have.
+    pub const MAX_FLAGS: usize = u32::BITS as usize;
+
     /// Returns a new [`Thing`] with the requested properties.
     /// # Panics
     /// Panics if width and/or height are equal or less than zero.
@@ -70,10 +80,56 @@ impl Thing
             id: ThingId::new(id),
             width,
             height,
-            preview: preview.to_string()
+            preview: preview.to_string(),
+            flags: Vec::new(),
+            category: None,
+            properties: HashMap::default()
         }
     }
 
+    /// Sets the names of the spawn flags of `self`. The first one is associated with the least
+    /// significant bit of the bitfield.
+    /// # Panics
+    /// Panics if there are more than [`Thing::MAX_FLAGS`] flags.
+    #[inline]
+    pub fn with_flags<'a, I: IntoIterator<Item = &'a str>>(mut self, flags: I) -> Self
+    {
+        self.flags = flags.into_iter().map(str::to_string).collect();
+        assert!(
+            self.flags.len() <= Self::MAX_FLAGS,
+            "Thing named {} with id {} has more than {} flags.",
+            self.name,
+            self.id.0,
+            Self::MAX_FLAGS
+        );
+        self
+    }
+
+    /// Sets the category `self` is listed under in the editor, the [`Thing`]s are sorted by
+    /// category and then by name.
+    #[inline]
+    pub fn with_category(mut self, category: &str) -> Self
+    {
+        self.category = category.to_string().into();
+        self
+    }
+
+    /// Sets the values the properties of the new [`ThingInstance`](crate::ThingInstance)s of
+    /// `self` are set to, instead of the default ones. The properties that are not among the
+    /// default ones are added to the instances as custom properties. [`Value::String`]s are
+    /// converted to the type of the default property if it differs, values of any other different
+    /// type are ignored.
+    #[inline]
+    pub fn with_properties<'a, I: IntoIterator<Item = (&'a str, Value)>>(
+        mut self,
+        properties: I
+    ) -> Self
+    {
+        self.properties
+            .extend(properties.into_iter().map(|(key, value)| (key.to_string(), value)));
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn name(&self) -> &str { &self.name }
@@ -96,6 +152,22 @@ impl Thing
     #[inline]
     #[must_use]
     pub fn preview(&self) -> &str { &self.preview }
+
+    /// Returns the names of the spawn flags.
+    #[inline]
+    #[must_use]
+    pub fn flags(&self) -> &[String] { &self.flags }
+
+    /// Returns the category, if any.
+    #[inline]
+    #[must_use]
+    pub fn category(&self) -> Option<&str> { self.category.as_deref() }
+
+    /// Returns the values the properties of the new [`ThingInstance`](crate::ThingInstance)s are
+    /// set to.
+    #[inline]
+    #[must_use]
+    pub const fn properties(&self) -> &HashMap<String, Value> { &self.properties }
 }
 
 //=======================================================================//
@@ -106,15 +178,29 @@ impl Thing
 pub struct ThingViewer
 {
     /// The unique id.
-    pub id:         Id,
+    pub id:                Id,
     /// The id of the [`Thing`].
-    pub thing_id:   ThingId,
+    pub thing_id:          ThingId,
     /// The position of the center.
-    pub pos:        Vec2,
+    pub pos:               Vec2,
     /// The optional associated path.
-    pub path:       Option<Vec<Node>>,
+    pub path:              Option<Vec<Node>>,
     /// The associated properties.
-    pub properties: HashMap<String, Value>
+    pub properties:        HashMap<String, Value>,
+    /// The spawn flags, as a bitfield.
+    #[serde(default)]
+    pub flags:             u32,
+    /// The RGB color of the outline drawn in the editor instead of the standard one, if any.
+    /// Ignored by the `Exporter` unless requested.
+    #[serde(default)]
+    pub color:             Option<[u8; 3]>,
+    /// The identifiers of the groups the thing belongs to, from the innermost to the outermost.
+    #[serde(default)]
+    pub groups:            Vec<u32>,
+    /// The properties specific to the thing, not associated with any default one, in insertion
+    /// order.
+    #[serde(default)]
+    pub custom_properties: Vec<(String, Value)>
 }
 
 //=======================================================================//
@@ -133,7 +219,7 @@ pub mod ui_mod
     use bevy::{prelude::Resource, transform::components::Transform, window::Window};
     use bevy_egui::egui;
     use glam::Vec2;
-    use hill_vacuum_shared::{match_or_panic, return_if_none};
+    use hill_vacuum_shared::{continue_if_none, match_or_panic, return_if_none};
     use serde::{Deserialize, Serialize};
 
     use super::{catalog::ThingsCatalog, Thing, ThingViewer};
@@ -141,7 +227,7 @@ pub mod ui_mod
         map::{
             drawer::{
                 color::Color,
-                drawers::{EditDrawer, MapPreviewDrawer}
+                drawers::{thing_fitted_texture_size, EditDrawer, MapPreviewDrawer}
             },
             editor::state::{
                 clipboard::{ClipboardData, CopyToClipboard},
@@ -156,7 +242,9 @@ pub mod ui_mod
                 PropertiesRefactor,
                 ThingProperties,
                 ANGLE_LABEL,
-                HEIGHT_LABEL
+                HEIGHT_LABEL,
+                TARGETNAME_LABEL,
+                TARGET_LABEL
             },
             OutOfBounds,
             Viewer,
@@ -210,10 +298,18 @@ pub mod ui_mod
     #[derive(Serialize, Deserialize)]
     pub(in crate::map) struct ThingInstanceDataViewer
     {
-        pub thing_id:   ThingId,
-        pub pos:        Vec2,
-        pub path:       Option<Vec<Node>>,
-        pub properties: HashMap<String, Value>
+        pub thing_id:          ThingId,
+        pub pos:               Vec2,
+        pub path:              Option<Vec<Node>>,
+        pub properties:        HashMap<String, Value>,
+        #[serde(default)]
+        pub flags:             u32,
+        #[serde(default)]
+        pub color:             Option<[u8; 3]>,
+        #[serde(default)]
+        pub groups:            Vec<u32>,
+        #[serde(default)]
+        pub custom_properties: Vec<(String, Value)>
     }
 
     //=======================================================================//
@@ -230,7 +326,13 @@ pub mod ui_mod
         /// The path describing the [`ThingInstance`] movement, if any.
         path:       Option<Path>,
         /// The associated properties.
-        properties: ThingProperties
+        properties: ThingProperties,
+        /// The spawn flags, as a bitfield.
+        flags:      u32,
+        /// The color of the outline drawn instead of the standard non selected one, if any.
+        color:      Option<[u8; 3]>,
+        /// The groups the [`ThingInstance`] belongs to, from the innermost to the outermost.
+        groups:     Vec<u32>
     }
 
     impl Viewer for ThingInstanceData
@@ -244,14 +346,21 @@ pub mod ui_mod
                 thing_id,
                 pos,
                 path,
-                properties
+                properties,
+                flags,
+                color,
+                groups,
+                custom_properties
             } = value;
 
             Self {
                 thing_id,
                 pos,
                 path: path.map(Path::from_viewer),
-                properties: ThingProperties::from_parts(properties)
+                properties: ThingProperties::from_parts(properties).with_custom(custom_properties),
+                flags,
+                color,
+                groups
             }
         }
 
@@ -263,14 +372,22 @@ pub mod ui_mod
                 pos,
                 path,
                 properties,
-                ..
+                flags,
+                color,
+                groups
             } = self;
 
+            let (properties, custom_properties) = properties.take();
+
             Self::Item {
                 thing_id: thing,
                 pos,
                 path: path.map(Path::to_viewer),
-                properties: properties.take()
+                properties,
+                flags,
+                color,
+                groups,
+                custom_properties
             }
         }
     }
@@ -359,6 +476,70 @@ pub mod ui_mod
             self.thing_id.replace_value(thing_id).into()
         }
 
+        /// Returns the spawn flags bitfield.
+        #[inline]
+        #[must_use]
+        pub const fn flags(&self) -> u32 { self.flags }
+
+        /// Sets the spawn flags bitfield to `flags`.
+        /// Returns the previous bitfield if different.
+        #[inline]
+        #[must_use]
+        pub fn set_flags(&mut self, flags: u32) -> Option<u32>
+        {
+            if flags == self.flags
+            {
+                return None;
+            }
+
+            self.flags.replace_value(flags).into()
+        }
+
+        /// Returns the color of the outline drawn instead of the standard non selected one, if
+        /// any.
+        #[inline]
+        #[must_use]
+        pub const fn color(&self) -> Option<[u8; 3]> { self.color }
+
+        /// Sets the color of the outline drawn instead of the standard non selected one to
+        /// `color`. Returns the previous value, if different.
+        #[inline]
+        #[must_use]
+        pub fn set_color(&mut self, color: Option<[u8; 3]>) -> Option<Option<[u8; 3]>>
+        {
+            if color == self.color
+            {
+                return None;
+            }
+
+            self.color.replace_value(color).into()
+        }
+
+        /// Returns the identifiers of the groups `self` belongs to, from the innermost to the
+        /// outermost.
+        #[inline]
+        #[must_use]
+        pub fn groups(&self) -> &[u32] { &self.groups }
+
+        /// Sets the groups `self` belongs to to `groups`, returning the previous ones.
+        #[inline]
+        #[must_use]
+        pub fn set_groups(&mut self, groups: Vec<u32>) -> Vec<u32>
+        {
+            std::mem::replace(&mut self.groups, groups)
+        }
+
+        /// Replaces the identifiers of the groups `self` belongs to with the values returned by
+        /// `f`.
+        #[inline]
+        pub fn remap_groups<F: FnMut(u32) -> u32>(&mut self, mut f: F)
+        {
+            for group in &mut self.groups
+            {
+                *group = f(*group);
+            }
+        }
+
         /// Draw `self` displaced by `delta` for a prop screenshot.
         #[inline]
         pub fn draw_prop(&self, drawer: &mut EditDrawer, catalog: &ThingsCatalog, delta: Vec2)
@@ -397,7 +578,11 @@ pub mod ui_mod
                 thing_id,
                 pos,
                 path,
-                properties
+                properties,
+                flags,
+                color,
+                groups,
+                custom_properties
             } = value;
 
             Self {
@@ -406,7 +591,11 @@ pub mod ui_mod
                     thing_id,
                     pos,
                     path,
-                    properties
+                    properties,
+                    flags,
+                    color,
+                    groups,
+                    custom_properties
                 })
             }
         }
@@ -419,7 +608,11 @@ pub mod ui_mod
                 thing_id,
                 pos,
                 path,
-                properties
+                properties,
+                flags,
+                color,
+                groups,
+                custom_properties
             } = self.data.to_viewer();
 
             Self::Item {
@@ -427,7 +620,11 @@ pub mod ui_mod
                 thing_id,
                 pos,
                 path,
-                properties
+                properties,
+                flags,
+                color,
+                groups,
+                custom_properties
             }
         }
     }
@@ -620,22 +817,65 @@ pub mod ui_mod
 
     impl ThingInstance
     {
-        /// Returns a new [`ThingInstance`].
+        /// Returns a new [`ThingInstance`] of `thing`, whose properties have the default values
+        /// unless `thing` specifies otherwise. The properties of `thing` that are not among the
+        /// default ones are added as custom properties, sorted by key.
         #[inline]
         pub fn new(
             id: Id,
-            thing_id: ThingId,
+            thing: &Thing,
             pos: Vec2,
             default_properties: &DefaultThingProperties
         ) -> Self
         {
+            let mut properties = default_properties.instance();
+            let mut custom = Vec::new();
+
+            for (key, value) in thing.properties()
+            {
+                let default = match properties.try_get(key)
+                {
+                    Some(default) => default,
+                    None =>
+                    {
+                        custom.push((key, value));
+                        continue;
+                    }
+                };
+
+                let value = if default.same_type(value)
+                {
+                    value.clone()
+                }
+                else if matches!(value, Value::String(_))
+                {
+                    continue_if_none!(default.parse(value))
+                }
+                else
+                {
+                    continue;
+                };
+
+                _ = properties.set(key, &value);
+            }
+
+            custom.sort_unstable_by_key(|(key, _)| *key);
+
+            for (key, value) in custom
+            {
+                _ = properties.set_custom(key, value.clone().into());
+            }
+
             Self {
                 id,
                 data: ThingInstanceData {
-                    thing_id,
+                    thing_id: thing.id(),
                     pos,
                     path: None,
-                    properties: default_properties.instance()
+                    properties,
+                    flags: 0,
+                    color: None,
+                    groups: Vec::new()
                 }
             }
         }
@@ -671,6 +911,40 @@ pub mod ui_mod
         #[inline]
         pub const fn properties(&self) -> &ThingProperties { &self.data.properties }
 
+        /// Returns the value of the [`String`] property with key `k`, if it exists and is not
+        /// empty.
+        #[inline]
+        #[must_use]
+        fn link_name(&self, k: &str) -> Option<&str>
+        {
+            match self.data.properties.try_get(k)?
+            {
+                Value::String(name) if !name.is_empty() => Some(name),
+                _ => None
+            }
+        }
+
+        /// Whether `self` has both the target and targetname [`String`] properties, and can
+        /// therefore be linked to other things.
+        #[inline]
+        #[must_use]
+        pub fn linkable(&self) -> bool
+        {
+            [TARGET_LABEL, TARGETNAME_LABEL]
+                .into_iter()
+                .all(|k| matches!(self.data.properties.try_get(k), Some(Value::String(_))))
+        }
+
+        /// Returns the name of the things `self` targets, if any.
+        #[inline]
+        #[must_use]
+        pub fn target(&self) -> Option<&str> { self.link_name(TARGET_LABEL) }
+
+        /// Returns the name other things can target `self` with, if any.
+        #[inline]
+        #[must_use]
+        pub fn targetname(&self) -> Option<&str> { self.link_name(TARGETNAME_LABEL) }
+
         /// Returns the overall [`Hull`] of both the thing and the [`Path`].
         #[inline]
         pub fn hull(&self, things_catalog: &ThingsCatalog) -> Hull
@@ -711,6 +985,43 @@ pub mod ui_mod
             self.data.set_thing(thing_id)
         }
 
+        /// Returns the spawn flags bitfield.
+        #[inline]
+        #[must_use]
+        pub const fn flags(&self) -> u32 { self.data.flags() }
+
+        /// Sets the spawn flags bitfield to `flags`.
+        /// Returns the previous bitfield if different.
+        #[inline]
+        #[must_use]
+        pub fn set_flags(&mut self, flags: u32) -> Option<u32> { self.data.set_flags(flags) }
+
+        /// Returns the color of the outline drawn instead of the standard non selected one, if
+        /// any.
+        #[inline]
+        #[must_use]
+        pub const fn color(&self) -> Option<[u8; 3]> { self.data.color() }
+
+        /// Sets the color of the outline drawn instead of the standard non selected one to
+        /// `color`. Returns the previous value, if different.
+        #[inline]
+        #[must_use]
+        pub fn set_color(&mut self, color: Option<[u8; 3]>) -> Option<Option<[u8; 3]>>
+        {
+            self.data.set_color(color)
+        }
+
+        /// Returns the identifiers of the groups `self` belongs to, from the innermost to the
+        /// outermost.
+        #[inline]
+        #[must_use]
+        pub fn groups(&self) -> &[u32] { self.data.groups() }
+
+        /// Sets the groups `self` belongs to to `groups`, returning the previous ones.
+        #[inline]
+        #[must_use]
+        pub fn set_groups(&mut self, groups: Vec<u32>) -> Vec<u32> { self.data.set_groups(groups) }
+
         /// Check whether `self` can be moved without being out of bounds.
         #[inline]
         #[must_use]
@@ -738,6 +1049,19 @@ pub mod ui_mod
             self.data.properties.set(key, value)
         }
 
+        /// Sets the custom property `key` to `value`, or removes it if `value` is `None`.
+        /// Returns the previous value, if different.
+        #[inline]
+        #[must_use]
+        pub fn set_custom_property(
+            &mut self,
+            key: &str,
+            value: Option<Value>
+        ) -> Option<Option<Value>>
+        {
+            self.data.properties.set_custom(key, value)
+        }
+
         /// Refactors the [`Peoperties`] based on `refactor`.
         #[inline]
         pub fn refactor_properties(
@@ -758,7 +1082,12 @@ pub mod ui_mod
             catalog: &ThingsCatalog
         )
         {
-            drawer.thing(catalog, self, Color::NonSelectedEntity);
+            match self.data.color
+            {
+                Some(rgb) => drawer.colored_thing(catalog, self, rgb),
+                None => drawer.thing(catalog, self, Color::NonSelectedEntity)
+            };
+
             self.tooltip(window, camera, catalog, drawer);
         }
 
@@ -830,7 +1159,6 @@ pub mod ui_mod
             drawer.thing(catalog, self, animators);
         }
 
-        #[allow(clippy::cast_precision_loss)]
         #[inline]
         fn tooltip(
             &self,
@@ -846,7 +1174,7 @@ pub mod ui_mod
 
             let offset = if grid.isometric()
             {
-                drawer.resources().texture_or_error(thing.preview()).size().y as f32
+                thing_fitted_texture_size(drawer.resources(), catalog, self).y
             }
             else
             {
@@ -20,6 +20,11 @@ impl Id
     #[inline]
     #[must_use]
     pub const fn value(self) -> usize { self.0 }
+
+    /// Returns a new [`Id`] wrapping `value`.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(value: usize) -> Self { Self(value) }
 }
 
 //=======================================================================//
@@ -226,6 +226,63 @@ pub(crate) mod ui_mod
 
         are_vxs_ccw(&[vxs[i], vxs[j], vxs[0]])
     }
+
+    //=======================================================================//
+
+    /// Returns the vertexes of the convex polygon described by the counterclockwise sorted `vxs`
+    /// with each corner replaced by an arc of radius `radius` made of `resolution` segments.
+    /// The radius of a corner is reduced if the arc does not fit in half of its sides.
+    #[inline]
+    #[must_use]
+    pub fn round_corners(vxs: &[Vec2], radius: f32, resolution: u8) -> Vec<Vec2>
+    {
+        let len = vxs.len();
+        let resolution = resolution.max(1);
+        let mut rounded = Vec::with_capacity(len * (usize::from(resolution) + 1));
+
+        for (i, vx) in vxs.iter().enumerate()
+        {
+            let to_prev = vxs[(i + len - 1) % len] - *vx;
+            let to_next = vxs[(i + 1) % len] - *vx;
+            let half_angle =
+                to_prev.normalize().dot(to_next.normalize()).clamp(-1f32, 1f32).acos() / 2f32;
+            let tangent =
+                (radius / half_angle.tan()).min(to_prev.length().min(to_next.length()) / 2f32);
+
+            if !tangent.is_finite() || tangent.around_equal_narrow(&0f32)
+            {
+                rounded.push(*vx);
+                continue;
+            }
+
+            let center = *vx +
+                (to_prev.normalize() + to_next.normalize()).normalize() *
+                    (tangent / half_angle.cos());
+            let start = *vx + to_prev.normalize() * tangent - center;
+            let end = *vx + to_next.normalize() * tangent - center;
+            let sweep = start.perp_dot(end).atan2(start.dot(end));
+
+            for step in 0..=resolution
+            {
+                rounded.push(
+                    center +
+                        rotate_point_around_origin(
+                            start,
+                            sweep * f32::from(step) / f32::from(resolution)
+                        )
+                );
+            }
+        }
+
+        rounded.dedup_by(|a, b| a.around_equal_narrow(b));
+
+        if rounded.len() > 1 && rounded[0].around_equal_narrow(&rounded[rounded.len() - 1])
+        {
+            rounded.pop();
+        }
+
+        rounded
+    }
 }
 
 #[cfg(feature = "ui")]
@@ -6,10 +6,11 @@
 use std::cmp::Ordering;
 
 use glam::Vec2;
+use hill_vacuum_shared::continue_if_none;
 
 use super::{
     angles::vector_angle_cosine,
-    points::{vertexes_orientation, VertexesOrientation},
+    points::{are_vxs_ccw, vertexes_orientation, VertexesOrientation},
     HashVec2
 };
 use crate::utils::{
@@ -18,7 +19,7 @@ use crate::utils::{
         lines_and_segments::{is_point_inside_clip_edge, lines_intersection},
         AroundEqual
     },
-    misc::prev
+    misc::{next, next_n_steps, prev}
 };
 
 //=======================================================================//
@@ -137,6 +138,158 @@ pub fn convex_hull(vertexes: HashSet<HashVec2>) -> Option<impl Iterator<Item = V
 
 //=======================================================================//
 
+/// Decomposes the simple polygon described by `vertexes` into a set of convex polygons with
+/// counterclockwise sorted vertexes.
+/// Returns `None` if the vertexes do not describe a valid simple polygon.
+#[inline]
+#[must_use]
+pub fn convex_decomposition(vertexes: &[Vec2]) -> Option<Vec<Vec<Vec2>>>
+{
+    /// Removes the consecutive duplicated vertexes and the ones collinear with their neighbors.
+    #[inline]
+    fn remove_redundant_vertexes(vxs: &mut Vec<Vec2>)
+    {
+        let mut i = 0;
+
+        while i < vxs.len() && vxs.len() >= 3
+        {
+            let j = next(i, vxs.len());
+            let k = next(j, vxs.len());
+
+            if vxs[i].around_equal_narrow(&vxs[j]) ||
+                vertexes_orientation(&[vxs[i], vxs[j], vxs[k]]) == VertexesOrientation::Collinear
+            {
+                vxs.remove(j);
+                i = i.saturating_sub(1);
+                continue;
+            }
+
+            i += 1;
+        }
+    }
+
+    /// Whether `p` is inside or on the boundary of the counterclockwise triangle `tri`.
+    #[inline]
+    #[must_use]
+    fn triangle_contains(tri: &[Vec2; 3], p: Vec2) -> bool
+    {
+        !(0..3).any(|i| {
+            vertexes_orientation(&[tri[i], tri[(i + 1) % 3], p]) == VertexesOrientation::Clockwise
+        })
+    }
+
+    /// Whether the counterclockwise polygon `vxs` is convex.
+    #[inline]
+    #[must_use]
+    fn is_convex(vxs: &[Vec2]) -> bool
+    {
+        let len = vxs.len();
+        (0..len).all(|i| are_vxs_ccw(&[vxs[i], vxs[next(i, len)], vxs[next_n_steps(i, 2, len)]]))
+    }
+
+    /// Returns the polygon resulting from merging `a` and `b`, if they share a side and the result
+    /// is convex.
+    #[inline]
+    #[must_use]
+    fn merge(a: &[Vec2], b: &[Vec2]) -> Option<Vec<Vec2>>
+    {
+        for i in 0..a.len()
+        {
+            let (start, end) = (a[i], a[next(i, a.len())]);
+            let j = continue_if_none!(b.iter().position(|vx| *vx == end));
+
+            if b[next(j, b.len())] != start
+            {
+                continue;
+            }
+
+            let mut merged = Vec::with_capacity(a.len() + b.len() - 2);
+            merged.extend((0..a.len()).map(|k| a[next_n_steps(i, k + 1, a.len())]));
+            merged.extend((2..b.len()).map(|k| b[next_n_steps(j, k, b.len())]));
+            remove_redundant_vertexes(&mut merged);
+
+            return is_convex(&merged).then_some(merged);
+        }
+
+        None
+    }
+
+    let mut vxs = vertexes.to_vec();
+    remove_redundant_vertexes(&mut vxs);
+
+    if vxs.len() < 3
+    {
+        return None;
+    }
+
+    // Make sure the vertexes are sorted counterclockwise.
+    let len = vxs.len();
+    let area = (0..len).fold(0f32, |area, i| area + vxs[i].perp_dot(vxs[next(i, len)]));
+
+    if area.around_equal_narrow(&0f32)
+    {
+        return None;
+    }
+
+    if area < 0f32
+    {
+        vxs.reverse();
+    }
+
+    if is_convex(&vxs)
+    {
+        return vec![vxs].into();
+    }
+
+    // Ear clipping.
+    let mut polygons = Vec::with_capacity(vxs.len() - 2);
+
+    while vxs.len() > 3
+    {
+        let len = vxs.len();
+        let ear = (0..len).find(|i| {
+            let tri = [vxs[prev(*i, len)], vxs[*i], vxs[next(*i, len)]];
+
+            are_vxs_ccw(&tri) &&
+                !vxs.iter()
+                    .filter(|vx| !tri.contains(vx))
+                    .any(|vx| triangle_contains(&tri, *vx))
+        })?;
+
+        polygons.push(vec![vxs[prev(ear, len)], vxs[ear], vxs[next(ear, len)]]);
+        vxs.remove(ear);
+    }
+
+    polygons.push(vxs);
+
+    // Merge the triangles as long as the resulting polygons are convex.
+    let mut i = 0;
+
+    while i < polygons.len()
+    {
+        let mut j = i + 1;
+
+        while j < polygons.len()
+        {
+            if let Some(merged) = merge(&polygons[i], &polygons[j])
+            {
+                polygons[i] = merged;
+                polygons.swap_remove(j);
+                j = i + 1;
+                continue;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    polygons.into()
+}
+
+//=======================================================================//
+
 /// Returns the left half, if any, of the polygon represented by `input` cut by `clip_segment`.
 /// # Panics
 /// May panic in some extreme conditions due to poor floating numbers rounding.
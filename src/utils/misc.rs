@@ -180,6 +180,10 @@ pub(crate) mod ui_mod
         /// Zooms in/out by `units`.
         fn zoom(&mut self, units: f32);
 
+        /// Sets the scale of the camera, keeping the center of the portion of the window where
+        /// the map is visible in place.
+        fn set_scale(&mut self, scale: f32);
+
         /// Zooms in.
         #[inline]
         fn zoom_in(&mut self) { self.zoom(1f32); }
@@ -571,6 +575,44 @@ pub(crate) mod ui_mod
         }
     }
 
+    //=======================================================================//
+
+    /// A seeded pseudo-random numbers generator, which always generates the same sequence of
+    /// numbers given the same seed.
+    #[must_use]
+    #[derive(Clone, Copy)]
+    pub(crate) struct SeededRandom(u64);
+
+    impl SeededRandom
+    {
+        /// Returns a new [`SeededRandom`] initialized with `seed`.
+        #[inline]
+        pub const fn new(seed: u64) -> Self { Self(seed) }
+
+        /// Returns the next pseudo-random number of the sequence.
+        #[inline]
+        #[must_use]
+        pub fn next_u64(&mut self) -> u64
+        {
+            // SplitMix64.
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        /// Returns a pseudo-random index lower than `len`.
+        #[allow(clippy::cast_possible_truncation)]
+        #[inline]
+        #[must_use]
+        pub fn index(&mut self, len: usize) -> usize
+        {
+            assert!(len != 0, "Cannot generate an index for an empty collection.");
+            (self.next_u64() % len as u64) as usize
+        }
+    }
+
     //=======================================================================//
     // FUNCTIONS
     //